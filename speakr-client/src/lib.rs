@@ -0,0 +1,151 @@
+// ============================================================================
+//! Speakr client library.
+//!
+//! A small async client for the Speakr local API, so other Rust tools can
+//! trigger dictation or pull transcripts programmatically. The API must be
+//! enabled in Speakr's settings; it listens on the loopback interface only.
+//!
+//! The request and response types live in `speakr-types` and are re-exported
+//! here, so this crate never drifts from the schema the app speaks.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use speakr_client::SpeakrClient;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), speakr_client::ClientError> {
+//! let client = SpeakrClient::new();
+//! client.start_dictation().await?;
+//! if let Some(text) = client.last_transcript().await? {
+//!     println!("Last dictation: {text}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+// ============================================================================
+
+use std::net::{Ipv4Addr, SocketAddr};
+
+use thiserror::Error;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+
+pub use speakr_types::{ApiRequest, ApiResponse, ApiStatus, AppError, DEFAULT_API_PORT};
+
+/// Errors returned by [`SpeakrClient`].
+#[derive(Debug, Error)]
+pub enum ClientError {
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("malformed message: {0}")]
+    Protocol(#[from] serde_json::Error),
+
+    #[error("connection closed before a response was received")]
+    ConnectionClosed,
+
+    #[error("speakr returned an error: {0}")]
+    Server(AppError),
+
+    #[error("unexpected response: {0:?}")]
+    UnexpectedResponse(ApiResponse),
+}
+
+/// Client for a running Speakr instance.
+///
+/// Each call opens a short-lived connection, so a client can be kept around
+/// while Speakr restarts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpeakrClient {
+    addr: SocketAddr,
+}
+
+impl SpeakrClient {
+    /// Create a client for the local API on its default port.
+    pub fn new() -> Self {
+        Self::with_addr(SocketAddr::from((Ipv4Addr::LOCALHOST, DEFAULT_API_PORT)))
+    }
+
+    /// Create a client for an API served at a custom address – mainly useful
+    /// for testing.
+    pub fn with_addr(addr: SocketAddr) -> Self {
+        Self { addr }
+    }
+
+    /// Return the address this client connects to.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Start a dictation, as if the hot-key had been pressed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClientError::Server`] if a dictation is already running.
+    pub async fn start_dictation(&self) -> Result<(), ClientError> {
+        match self.send(&ApiRequest::StartDictation).await? {
+            ApiResponse::DictationStarted => Ok(()),
+            other => Err(unexpected(other)),
+        }
+    }
+
+    /// Cancel the dictation in flight, returning whether one was running.
+    pub async fn cancel_dictation(&self) -> Result<bool, ClientError> {
+        match self.send(&ApiRequest::CancelDictation).await? {
+            ApiResponse::DictationCancelled(cancelled) => Ok(cancelled),
+            other => Err(unexpected(other)),
+        }
+    }
+
+    /// Fetch the text produced by the most recent completed dictation.
+    pub async fn last_transcript(&self) -> Result<Option<String>, ClientError> {
+        match self.send(&ApiRequest::LastTranscript).await? {
+            ApiResponse::Transcript(text) => Ok(text),
+            other => Err(unexpected(other)),
+        }
+    }
+
+    /// Fetch a read-only snapshot of what Speakr is doing: service status,
+    /// the active profile and model, and the last dictation.
+    pub async fn status(&self) -> Result<ApiStatus, ClientError> {
+        match self.send(&ApiRequest::Status).await? {
+            ApiResponse::Status(status) => Ok(*status),
+            other => Err(unexpected(other)),
+        }
+    }
+
+    /// Send a raw request and wait for its response.
+    ///
+    /// Error responses are returned as-is; the typed helpers above turn them
+    /// into [`ClientError::Server`].
+    pub async fn send(&self, request: &ApiRequest) -> Result<ApiResponse, ClientError> {
+        let stream = TcpStream::connect(self.addr).await?;
+        let (reader, mut writer) = stream.into_split();
+
+        let mut line = serde_json::to_string(request)?;
+        line.push('\n');
+        writer.write_all(line.as_bytes()).await?;
+
+        let response = BufReader::new(reader)
+            .lines()
+            .next_line()
+            .await?
+            .ok_or(ClientError::ConnectionClosed)?;
+        Ok(serde_json::from_str(&response)?)
+    }
+}
+
+impl Default for SpeakrClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Map a response that does not answer the request to an error.
+fn unexpected(response: ApiResponse) -> ClientError {
+    match response {
+        ApiResponse::Error(e) => ClientError::Server(e),
+        other => ClientError::UnexpectedResponse(other),
+    }
+}
@@ -0,0 +1,93 @@
+//! Integration tests for `SpeakrClient`
+//!
+//! Each test serves canned responses from an in-process TCP listener, so no
+//! running Speakr instance is needed.
+
+use speakr_client::{ApiRequest, ApiResponse, ApiStatus, AppError, ClientError, SpeakrClient};
+use speakr_types::{BackendStatus, ModelId};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+/// Serve a single connection, answering one request with `response` and
+/// returning the request that was received.
+async fn serve_once(response: ApiResponse) -> (SpeakrClient, JoinHandle<ApiRequest>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+    let client = SpeakrClient::with_addr(listener.local_addr().expect("local addr"));
+
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.expect("accept");
+        let (reader, mut writer) = stream.into_split();
+        let line = BufReader::new(reader)
+            .lines()
+            .next_line()
+            .await
+            .expect("read request")
+            .expect("request line");
+
+        let mut reply = serde_json::to_string(&response).expect("serialize");
+        reply.push('\n');
+        writer.write_all(reply.as_bytes()).await.expect("write");
+
+        serde_json::from_str(&line).expect("parse request")
+    });
+
+    (client, server)
+}
+
+/// Test that the latest transcript is requested and returned
+#[tokio::test(flavor = "multi_thread")]
+async fn fetches_last_transcript() {
+    // Arrange
+    let (client, server) =
+        serve_once(ApiResponse::Transcript(Some("hello world".to_string()))).await;
+
+    // Act
+    let transcript = client.last_transcript().await.expect("request succeeds");
+
+    // Assert
+    assert_eq!(transcript.as_deref(), Some("hello world"));
+    assert_eq!(server.await.unwrap(), ApiRequest::LastTranscript);
+}
+
+/// Test that error responses surface as `ClientError::Server`
+#[tokio::test(flavor = "multi_thread")]
+async fn surfaces_server_errors() {
+    // Arrange
+    let (client, server) = serve_once(ApiResponse::Error(AppError::Command(
+        "A dictation is already in progress".to_string(),
+    )))
+    .await;
+
+    // Act
+    let result = client.start_dictation().await;
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(ClientError::Server(AppError::Command(_)))
+    ));
+    assert_eq!(server.await.unwrap(), ApiRequest::StartDictation);
+}
+
+/// Test that the status snapshot is requested and returned
+#[tokio::test(flavor = "multi_thread")]
+async fn fetches_status() {
+    // Arrange
+    let status = ApiStatus {
+        backend: BackendStatus::new_ready(),
+        dictating: true,
+        paused: false,
+        active_profile: None,
+        model: ModelId::new("base.en"),
+        last_dictation: None,
+    };
+    let (client, server) = serve_once(ApiResponse::Status(Box::new(status.clone()))).await;
+
+    // Act
+    let fetched = client.status().await.expect("request succeeds");
+
+    // Assert
+    assert_eq!(fetched, status);
+    assert_eq!(server.await.unwrap(), ApiRequest::Status);
+}
@@ -0,0 +1,248 @@
+// ============================================================================
+//! Audio File Decoding
+//!
+//! Existing recordings can be transcribed offline, without the microphone.
+//! Files are decoded with [`symphonia`], downmixed to mono and converted to
+//! Whisper's 16 kHz mono `i16` format with [`resample_to_whisper_format`], so
+//! they go through the engine exactly like captured audio.
+//!
+//! Supported containers are WAV, MP3 and M4A (AAC). [`list_audio_files`]
+//! finds them in a folder for batch transcription.
+// ============================================================================
+
+use super::resample::resample_to_whisper_format;
+use super::AudioCaptureError;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::{DecoderOptions, CODEC_TYPE_NULL};
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+use tracing::{debug, warn};
+
+/// File extensions that can be decoded, in lower case.
+pub const SUPPORTED_EXTENSIONS: [&str; 3] = ["wav", "mp3", "m4a"];
+
+/// Returns `true` if the file's extension is one of [`SUPPORTED_EXTENSIONS`].
+pub fn is_supported_audio_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| {
+            SUPPORTED_EXTENSIONS.contains(&extension.to_ascii_lowercase().as_str())
+        })
+}
+
+/// Lists the supported audio files directly inside a folder.
+///
+/// Subfolders are not searched.
+///
+/// # Returns
+///
+/// The files, sorted by path so batches are processed in a stable order.
+///
+/// # Errors
+///
+/// Returns an I/O error if the folder cannot be read.
+pub fn list_audio_files(folder: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(folder)? {
+        let path = entry?.path();
+        if path.is_file() && is_supported_audio_file(&path) {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Decodes an audio file into 16 kHz mono `i16` samples.
+///
+/// Only the first audio track is decoded. Packets that fail to decode are
+/// skipped, as players do, rather than failing the whole file.
+///
+/// # Arguments
+///
+/// * `path` - A WAV, MP3 or M4A file
+///
+/// # Errors
+///
+/// Returns `AudioCaptureError::DecodeFailed` if the file cannot be read, has
+/// an unsupported format or contains no audio, or
+/// `AudioCaptureError::StreamError` if resampling fails.
+pub fn decode_audio_file(path: &Path) -> Result<Vec<i16>, AudioCaptureError> {
+    if !is_supported_audio_file(path) {
+        return Err(AudioCaptureError::DecodeFailed(format!(
+            "Unsupported file type; expected one of: {}",
+            SUPPORTED_EXTENSIONS.join(", ")
+        )));
+    }
+
+    let file = File::open(path).map_err(|e| {
+        AudioCaptureError::DecodeFailed(format!("Failed to open {}: {e}", path.display()))
+    })?;
+    let stream = MediaSourceStream::new(Box::new(file), Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(extension) = path.extension().and_then(|extension| extension.to_str()) {
+        hint.with_extension(extension);
+    }
+
+    let probed = symphonia::default::get_probe()
+        .format(
+            &hint,
+            stream,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .map_err(decode_error)?;
+    let mut format = probed.format;
+
+    let track = format
+        .tracks()
+        .iter()
+        .find(|track| track.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or_else(|| AudioCaptureError::DecodeFailed("File has no audio track".to_string()))?;
+    let track_id = track.id;
+    let sample_rate = track
+        .codec_params
+        .sample_rate
+        .ok_or_else(|| AudioCaptureError::DecodeFailed("Unknown sample rate".to_string()))?;
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(decode_error)?;
+
+    let mut mono = Vec::new();
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
+                break
+            }
+            Err(e) => return Err(decode_error(e)),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            Err(SymphoniaError::DecodeError(e)) => {
+                warn!("Skipping undecodable packet: {}", e);
+                continue;
+            }
+            Err(e) => return Err(decode_error(e)),
+        };
+
+        let spec = *decoded.spec();
+        let channels = spec.channels.count().max(1);
+        let mut buffer = SampleBuffer::<f32>::new(decoded.capacity() as u64, spec);
+        buffer.copy_interleaved_ref(decoded);
+        mono.extend(
+            buffer
+                .samples()
+                .chunks(channels)
+                .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32),
+        );
+    }
+
+    if mono.is_empty() {
+        return Err(AudioCaptureError::DecodeFailed(
+            "File contains no audio".to_string(),
+        ));
+    }
+
+    debug!(
+        "Decoded {} samples at {} Hz from {}",
+        mono.len(),
+        sample_rate,
+        path.display()
+    );
+    resample_to_whisper_format(&mono, sample_rate)
+}
+
+fn decode_error(error: SymphoniaError) -> AudioCaptureError {
+    AudioCaptureError::DecodeFailed(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::SAMPLE_RATE_HZ;
+    use tempfile::TempDir;
+
+    /// Writes a 16-bit PCM WAV file.
+    fn write_wav(path: &Path, rate: u32, channels: u16, samples: &[i16]) {
+        let data_len = (samples.len() * 2) as u32;
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"RIFF");
+        bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+        bytes.extend_from_slice(b"WAVEfmt ");
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&channels.to_le_bytes());
+        bytes.extend_from_slice(&rate.to_le_bytes());
+        bytes.extend_from_slice(&(rate * u32::from(channels) * 2).to_le_bytes());
+        bytes.extend_from_slice(&(channels * 2).to_le_bytes());
+        bytes.extend_from_slice(&16u16.to_le_bytes());
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&data_len.to_le_bytes());
+        for sample in samples {
+            bytes.extend_from_slice(&sample.to_le_bytes());
+        }
+        std::fs::write(path, bytes).unwrap();
+    }
+
+    #[test]
+    fn decodes_stereo_wav_to_16khz_mono() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("Recording.WAV");
+        // One second of stereo audio at 48 kHz, left and right averaging to 1000
+        let frames: Vec<i16> = (0..48_000).flat_map(|_| [500, 1_500]).collect();
+        write_wav(&path, 48_000, 2, &frames);
+
+        let samples = decode_audio_file(&path).unwrap();
+
+        assert_eq!(samples.len(), SAMPLE_RATE_HZ as usize);
+        let middle = samples[samples.len() / 2];
+        assert!((i32::from(middle) - 1_000).abs() < 20, "{middle}");
+    }
+
+    #[test]
+    fn rejects_unsupported_and_invalid_files() {
+        let dir = TempDir::new().unwrap();
+        let text = dir.path().join("notes.txt");
+        std::fs::write(&text, "hello").unwrap();
+        let corrupt = dir.path().join("broken.mp3");
+        std::fs::write(&corrupt, "not audio").unwrap();
+
+        assert!(!is_supported_audio_file(&text));
+        assert!(is_supported_audio_file(Path::new("memo.M4A")));
+        assert!(matches!(
+            decode_audio_file(&text),
+            Err(AudioCaptureError::DecodeFailed(_))
+        ));
+        assert!(matches!(
+            decode_audio_file(&corrupt),
+            Err(AudioCaptureError::DecodeFailed(_))
+        ));
+    }
+
+    #[test]
+    fn lists_supported_files_in_order() {
+        let dir = TempDir::new().unwrap();
+        for name in ["b.mp3", "a.WAV", "notes.txt", "c.m4a"] {
+            std::fs::write(dir.path().join(name), "").unwrap();
+        }
+        std::fs::create_dir(dir.path().join("nested.wav")).unwrap();
+
+        let names: Vec<_> = list_audio_files(dir.path())
+            .unwrap()
+            .into_iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names, ["a.WAV", "b.mp3", "c.m4a"]);
+    }
+}
@@ -0,0 +1,133 @@
+// ============================================================================
+//! Background Noise Suppression
+//!
+//! Optional DSP stage run on captured audio before transcription, using
+//! [RNNoise](https://jmvalin.ca/demo/rnnoise/): a small recurrent network
+//! that attenuates fans, keyboards and street noise while keeping speech.
+//! Its weights are downloaded into the model cache from
+//! [`DENOISE_MODEL_URL`] by the same [`ModelManager`] that manages Whisper
+//! models, so nothing is bundled with the app for users who never enable it.
+//!
+//! RNNoise works on 10 ms frames; at Whisper's 16 kHz that is
+//! [`FRAME_SAMPLES`] samples. The network itself is not wired up yet (as
+//! with Whisper inference, see [`crate::transcription::engine`]), so frames
+//! currently pass through unchanged.
+// ============================================================================
+
+use super::{AudioCaptureError, SAMPLE_RATE_HZ};
+use crate::transcription::models::ModelManager;
+use speakr_types::DENOISE_MODEL_FILENAME;
+use std::path::PathBuf;
+use tracing::debug;
+
+/// Where the RNNoise weights are downloaded from.
+///
+/// The file name at the end of the URL is [`DENOISE_MODEL_FILENAME`].
+pub const DENOISE_MODEL_URL: &str =
+    "https://raw.githubusercontent.com/GregorR/rnnoise-models/master/somnolent-hogwash-2018-09-01/sh.rnn";
+
+/// Samples in one 10 ms RNNoise frame at [`SAMPLE_RATE_HZ`].
+pub const FRAME_SAMPLES: usize = (SAMPLE_RATE_HZ / 100) as usize;
+
+/// Returns where the RNNoise weights are stored in the model cache.
+pub fn denoise_model_path(manager: &ModelManager) -> PathBuf {
+    manager.cache_dir().join(DENOISE_MODEL_FILENAME)
+}
+
+/// Returns `true` if the RNNoise weights have been downloaded.
+pub fn is_denoise_model_installed(manager: &ModelManager) -> bool {
+    denoise_model_path(manager).is_file()
+}
+
+/// RNNoise noise suppressor with its weights loaded.
+#[derive(Debug, Clone)]
+pub struct Denoiser {
+    /// Serialized RNNoise weights, handed to the network once it is wired up.
+    weights: Vec<u8>,
+}
+
+impl Denoiser {
+    /// Loads the RNNoise weights from the model cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AudioCaptureError::DenoiseFailed` if the weights have not
+    /// been downloaded or cannot be read.
+    pub fn load(manager: &ModelManager) -> Result<Self, AudioCaptureError> {
+        let path = denoise_model_path(manager);
+        let weights = std::fs::read(&path).map_err(|e| {
+            AudioCaptureError::DenoiseFailed(format!(
+                "Failed to read noise model {}: {e}",
+                path.display()
+            ))
+        })?;
+        if weights.is_empty() {
+            return Err(AudioCaptureError::DenoiseFailed(format!(
+                "Noise model {} is empty",
+                path.display()
+            )));
+        }
+
+        debug!(
+            "Loaded {} bytes of RNNoise weights from {}",
+            weights.len(),
+            path.display()
+        );
+        Ok(Self { weights })
+    }
+
+    /// Suppresses background noise in 16 kHz mono samples.
+    ///
+    /// # Returns
+    ///
+    /// The cleaned samples, the same length as the input.
+    pub fn denoise(&self, samples: &[i16]) -> Vec<i16> {
+        let mut cleaned = Vec::with_capacity(samples.len());
+        for frame in samples.chunks(FRAME_SAMPLES) {
+            self.process_frame(frame, &mut cleaned);
+        }
+        cleaned
+    }
+
+    /// Runs one frame (the last may be short) through the network.
+    fn process_frame(&self, frame: &[i16], cleaned: &mut Vec<i16>) {
+        // Placeholder until the RNNoise network is integrated: the frame is
+        // passed through unchanged.
+        debug_assert!(!self.weights.is_empty());
+        cleaned.extend_from_slice(frame);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn load_requires_downloaded_weights() {
+        let dir = TempDir::new().unwrap();
+        let manager = ModelManager::with_cache_dir(dir.path().to_path_buf());
+
+        assert!(!is_denoise_model_installed(&manager));
+        assert!(matches!(
+            Denoiser::load(&manager),
+            Err(AudioCaptureError::DenoiseFailed(_))
+        ));
+
+        std::fs::write(denoise_model_path(&manager), [1, 2, 3]).unwrap();
+        assert!(is_denoise_model_installed(&manager));
+        assert!(Denoiser::load(&manager).is_ok());
+    }
+
+    #[test]
+    fn denoise_keeps_every_sample_including_a_short_last_frame() {
+        let dir = TempDir::new().unwrap();
+        let manager = ModelManager::with_cache_dir(dir.path().to_path_buf());
+        std::fs::write(denoise_model_path(&manager), [1]).unwrap();
+        let denoiser = Denoiser::load(&manager).unwrap();
+
+        let samples: Vec<i16> = (0..FRAME_SAMPLES as i16 * 2 + 7).collect();
+        assert_eq!(denoiser.denoise(&samples).len(), samples.len());
+        assert_eq!(denoiser.denoise(&[]), Vec::<i16>::new());
+    }
+}
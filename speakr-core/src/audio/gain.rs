@@ -0,0 +1,239 @@
+// ============================================================================
+//! Automatic Gain Control
+//!
+//! Quiet microphones produce poor transcriptions, so captured audio can be
+//! normalised to a target loudness before it reaches Whisper. Loudness is
+//! measured over the speech in a recording only: frames quieter than
+//! [`NOISE_GATE_DBFS`] are ignored so pauses do not drag the level down and
+//! inflate the gain.
+//!
+//! The gain applied is limited to [`MAX_GAIN_DB`], so near-silent recordings
+//! are not turned into amplified noise, and to whatever keeps the loudest
+//! sample below [`PEAK_CEILING_DBFS`], so nothing clips.
+//!
+//! Separately, a fixed software gain can be set for each input device (see
+//! [`speakr_types::InputGain`]). It is applied by [`apply_input_gain`] as
+//! audio is captured, before conversion to 16-bit samples, and eased into
+//! full scale above [`LIMITER_THRESHOLD`] rather than clipped.
+// ============================================================================
+
+use super::SAMPLE_RATE_HZ;
+use speakr_types::{dbfs_to_level, level_to_dbfs};
+use tracing::debug;
+
+/// Length of the frames loudness is measured over, in milliseconds.
+const FRAME_MS: u32 = 20;
+
+/// Frames quieter than this (in dBFS) are treated as silence.
+pub const NOISE_GATE_DBFS: f32 = -50.0;
+
+/// Largest boost applied to a recording, in dB.
+pub const MAX_GAIN_DB: f32 = 30.0;
+
+/// Loudest peak allowed after the gain is applied, in dBFS.
+pub const PEAK_CEILING_DBFS: f32 = -1.0;
+
+/// Level (of full scale) above which input gain is limited instead of
+/// applied linearly.
+pub const LIMITER_THRESHOLD: f32 = 0.9;
+
+/// RMS level of `samples` in dBFS.
+///
+/// # Returns
+///
+/// The level, no quieter than [`speakr_types::SILENCE_DBFS`].
+pub fn level_dbfs(samples: &[i16]) -> f32 {
+    level_to_dbfs(rms(samples))
+}
+
+/// Peak level of `samples` in dBFS.
+///
+/// # Returns
+///
+/// The level of the loudest sample, no quieter than
+/// [`speakr_types::SILENCE_DBFS`].
+pub fn peak_dbfs(samples: &[i16]) -> f32 {
+    level_to_dbfs(peak(samples))
+}
+
+/// Normalises speech in `samples` to `target_dbfs`.
+///
+/// # Arguments
+///
+/// * `samples` - 16 kHz mono samples
+/// * `target_dbfs` - Loudness to bring speech to
+///
+/// # Returns
+///
+/// The adjusted samples, or a copy of the input if it contains no speech.
+pub fn normalize(samples: &[i16], target_dbfs: f32) -> Vec<i16> {
+    let gain = gain_for(samples, target_dbfs);
+    if gain == 1.0 {
+        return samples.to_vec();
+    }
+    samples
+        .iter()
+        .map(|&sample| (f32::from(sample) * gain).round() as i16)
+        .collect()
+}
+
+/// Applies a device's input gain to one captured sample.
+///
+/// # Arguments
+///
+/// * `sample` - Sample in the range `-1.0..=1.0`
+/// * `gain` - Multiplier to apply
+///
+/// # Returns
+///
+/// The amplified sample, unchanged at unity gain. Above
+/// [`LIMITER_THRESHOLD`] it approaches full scale smoothly, so it never
+/// clips.
+pub fn apply_input_gain(sample: f32, gain: f32) -> f32 {
+    if gain == 1.0 {
+        return sample;
+    }
+    let amplified = sample * gain;
+    let magnitude = amplified.abs();
+    if magnitude <= LIMITER_THRESHOLD {
+        return amplified;
+    }
+    let headroom = 1.0 - LIMITER_THRESHOLD;
+    let limited =
+        LIMITER_THRESHOLD + headroom * ((magnitude - LIMITER_THRESHOLD) / headroom).tanh();
+    limited.copysign(amplified)
+}
+
+/// Linear gain that brings the speech in `samples` to `target_dbfs`.
+fn gain_for(samples: &[i16], target_dbfs: f32) -> f32 {
+    let frame = (SAMPLE_RATE_HZ * FRAME_MS / 1000) as usize;
+    let gate = dbfs_to_level(NOISE_GATE_DBFS);
+    let speech: Vec<i16> = samples
+        .chunks(frame)
+        .filter(|chunk| rms(chunk) >= gate)
+        .flatten()
+        .copied()
+        .collect();
+    if speech.is_empty() {
+        debug!("No speech above the noise gate, leaving gain unchanged");
+        return 1.0;
+    }
+
+    let wanted = dbfs_to_level(target_dbfs) / rms(&speech);
+    let headroom = dbfs_to_level(PEAK_CEILING_DBFS) / peak(samples);
+    let gain = wanted.min(headroom).min(10f32.powf(MAX_GAIN_DB / 20.0));
+    debug!(
+        "Speech at {:.1} dBFS, applying {:.1} dB of gain",
+        level_dbfs(&speech),
+        level_to_dbfs(gain)
+    );
+    gain
+}
+
+/// Loudest sample in `samples`, in the range `0.0..=1.0`.
+fn peak(samples: &[i16]) -> f32 {
+    samples
+        .iter()
+        .map(|&sample| (f32::from(sample).abs() / f32::from(i16::MAX)).min(1.0))
+        .fold(0.0, f32::max)
+}
+
+/// Root-mean-square level of `samples`, in the range `0.0..=1.0`.
+fn rms(samples: &[i16]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let sum_of_squares: f64 = samples
+        .iter()
+        .map(|&sample| {
+            let sample = f64::from(sample) / f64::from(i16::MAX);
+            sample * sample
+        })
+        .sum();
+    ((sum_of_squares / samples.len() as f64).sqrt() as f32).min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tone(amplitude: f32, len: usize) -> Vec<i16> {
+        (0..len)
+            .map(|i| {
+                let phase = i as f32 * 440.0 * std::f32::consts::TAU / SAMPLE_RATE_HZ as f32;
+                (phase.sin() * amplitude * f32::from(i16::MAX)) as i16
+            })
+            .collect()
+    }
+
+    #[test]
+    fn quiet_speech_is_raised_to_the_target() {
+        // A quiet tone between two stretches of silence
+        let mut samples = vec![0; 8_000];
+        samples.extend(tone(0.01, 16_000));
+        samples.extend(vec![0; 8_000]);
+
+        let normalized = normalize(&samples, -20.0);
+
+        let speech = &normalized[8_000..24_000];
+        assert!(
+            (level_dbfs(speech) + 20.0).abs() < 0.5,
+            "{}",
+            level_dbfs(speech)
+        );
+        assert!(normalized[..8_000].iter().all(|&sample| sample == 0));
+    }
+
+    #[test]
+    fn gain_is_limited_by_headroom_and_maximum() {
+        // A square wave's peak equals its RMS, so this target would clip
+        let loud: Vec<i16> = (0..16_000)
+            .map(|i| if i % 2 == 0 { 16_000 } else { -16_000 })
+            .collect();
+        let normalized = normalize(&loud, -0.5);
+        let peak = normalized
+            .iter()
+            .map(|&s| i32::from(s).abs())
+            .max()
+            .unwrap();
+        assert!(peak <= i32::from(i16::MAX));
+        assert!(level_to_dbfs(peak as f32 / f32::from(i16::MAX)) <= PEAK_CEILING_DBFS + 0.01);
+
+        // Barely audible input is boosted by at most MAX_GAIN_DB
+        let faint = tone(0.006, 16_000);
+        let boosted = normalize(&faint, -6.0);
+        let boost = level_dbfs(&boosted) - level_dbfs(&faint);
+        assert!((boost - MAX_GAIN_DB).abs() < 0.5, "{boost}");
+    }
+
+    #[test]
+    fn peak_is_the_loudest_sample() {
+        assert_eq!(
+            peak_dbfs(&[0, -16_384, 100]),
+            level_to_dbfs(16_384.0 / 32_767.0)
+        );
+        assert_eq!(peak_dbfs(&[i16::MIN]), 0.0);
+        assert_eq!(peak_dbfs(&[]), speakr_types::SILENCE_DBFS);
+    }
+
+    #[test]
+    fn input_gain_is_limited_instead_of_clipping() {
+        assert_eq!(apply_input_gain(0.7, 1.0), 0.7);
+        assert_eq!(apply_input_gain(0.2, 2.0), 0.4);
+        assert_eq!(apply_input_gain(-0.2, 0.5), -0.1);
+
+        let loud = apply_input_gain(0.25, 4.0);
+        assert!(loud > LIMITER_THRESHOLD && loud < 1.0, "{loud}");
+        assert_eq!(apply_input_gain(-0.25, 4.0), -loud);
+        // Louder input stays louder, up to full scale
+        assert!(apply_input_gain(0.3, 4.0) > loud);
+        assert!(apply_input_gain(1.0, 4.0) <= 1.0);
+    }
+
+    #[test]
+    fn silence_is_left_alone() {
+        let silence = vec![3; 16_000];
+        assert_eq!(normalize(&silence, -20.0), silence);
+        assert_eq!(normalize(&[], -20.0), Vec::<i16>::new());
+    }
+}
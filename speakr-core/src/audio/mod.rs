@@ -9,7 +9,23 @@
 //! - 16 kHz sample-rate (Whisper’s required sampling frequency)
 //! - 1 channel (mono)
 //! - 16-bit signed integer samples
-//! - Hard duration limit (configurable, 1–30 seconds)
+//! - Duration limit (configurable, 1–30 seconds, or minutes for long-form
+//!   dictation via [`RecordingConfig::with_time_limit`])
+//!
+//! Each recording uses the highest-priority connected device from the
+//! user's ordered preferences (see [`select_input_device`]), falling back to
+//! the system default. Devices are opened at their native sample rate,
+//! channel count and sample format (many only expose 44.1 or 48 kHz, stereo
+//! or 24-bit streams). Frames are converted and downmixed to mono as they
+//! arrive and resampled to 16 kHz when the samples are read (see
+//! [`resample`]). A software gain set for the device (see
+//! [`RecordingConfig::with_input_gains`]) is applied in the same step.
+//!
+//! An optional pre-record mode keeps the last few seconds of audio in a
+//! fixed-size, memory-only [`ring_buffer::RingBuffer`] while idle, so the
+//! first words spoken as the hot-key is pressed are not lost. After capture,
+//! an optional [`denoise`] stage suppresses background noise and optional
+//! [`gain`] control brings quiet speech up to a consistent level.
 //!
 //! The public surface exposed by this module is intentionally small so that it
 //! can be mocked from **unit-tests** and swapped out for alternative
@@ -32,18 +48,34 @@
 // =========================
 use cpal::{
     traits::{DeviceTrait, HostTrait, StreamTrait},
-    SampleFormat, StreamConfig,
+    FromSample, Sample, SampleFormat, SizedSample, StreamConfig, SupportedStreamConfig,
 };
+use speakr_types::{input_gain_for, AudioDuration, InputGain};
 use std::{
     sync::{
         atomic::{AtomicBool, Ordering},
-        Arc, Mutex,
+        mpsc, Arc, Mutex, PoisonError,
     },
+    thread::{self, JoinHandle},
     time::{Duration, Instant},
 };
 use thiserror::Error;
 use tokio::sync::oneshot;
 use tracing::{debug, error, info, instrument, warn};
+use zeroize::Zeroize;
+
+/// Decoding of existing recordings (WAV, MP3, M4A) into Whisper's format.
+pub mod decode;
+/// Optional RNNoise background noise suppression before transcription.
+pub mod denoise;
+/// Automatic gain control that normalises quiet input before transcription.
+pub mod gain;
+/// Conversion from the device's native sample rate to Whisper's format.
+pub mod resample;
+/// Fixed-capacity buffer backing the pre-record mode.
+pub mod ring_buffer;
+
+use ring_buffer::RingBuffer;
 
 // ============================================================================
 // Constants
@@ -55,11 +87,21 @@ pub const SAMPLE_RATE_HZ: u32 = 16_000;
 /// Number of audio channels (mono).
 pub const CHANNELS: u16 = 1;
 
-/// Default maximum recording duration in seconds.
-pub const DEFAULT_MAX_DURATION_SECS: u32 = 10;
+/// Maximum length of the pre-record buffer in seconds.
+pub const MAX_PRE_BUFFER_SECS: u32 = 10;
+
+/// Length of audio (in milliseconds) used to measure the input level.
+pub const LEVEL_WINDOW_MS: u32 = 50;
 
-/// Maximum allowed recording duration in seconds.
-pub const MAX_ALLOWED_DURATION_SECS: u32 = 30;
+/// Root-mean-square level of `samples`, in the range `0.0..=1.0`.
+fn rms(samples: impl ExactSizeIterator<Item = f32>) -> f32 {
+    let count = samples.len();
+    if count == 0 {
+        return 0.0;
+    }
+    let sum_of_squares: f32 = samples.map(|sample| sample * sample).sum();
+    (sum_of_squares / count as f32).sqrt().min(1.0)
+}
 
 /// Information about an audio input device.
 #[derive(Debug, Clone, PartialEq)]
@@ -72,6 +114,29 @@ pub struct AudioDevice {
     pub is_default: bool,
 }
 
+/// Picks the input device to record from.
+///
+/// # Arguments
+///
+/// * `preferred` - Device names in order of preference, highest first
+/// * `available` - The devices currently connected
+///
+/// # Returns
+///
+/// The highest-priority preferred device that is connected, falling back to
+/// the system default and then to the first device; `None` if no devices are
+/// connected.
+pub fn select_input_device<'a>(
+    preferred: &[String],
+    available: &'a [AudioDevice],
+) -> Option<&'a AudioDevice> {
+    preferred
+        .iter()
+        .find_map(|name| available.iter().find(|device| &device.name == name))
+        .or_else(|| available.iter().find(|device| device.is_default))
+        .or_else(|| available.first())
+}
+
 /// Errors that can occur during audio capture.
 #[derive(Debug, Error, Clone, PartialEq)]
 pub enum AudioCaptureError {
@@ -98,12 +163,22 @@ pub enum AudioCaptureError {
 
     #[error("Invalid recording configuration: {0}")]
     InvalidConfiguration(String),
+
+    #[error("Audio file could not be decoded: {0}")]
+    DecodeFailed(String),
+
+    #[error("Noise suppression failed: {0}")]
+    DenoiseFailed(String),
 }
 
 /// Configuration for audio recording sessions.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct RecordingConfig {
-    max_duration_secs: u32,
+    max_duration: AudioDuration,
+    /// Replaces `max_duration` for recordings longer than it allows.
+    time_limit: Option<Duration>,
+    preferred_devices: Vec<String>,
+    input_gains: Vec<InputGain>,
 }
 
 impl RecordingConfig {
@@ -111,43 +186,68 @@ impl RecordingConfig {
     ///
     /// # Arguments
     ///
-    /// * `max_duration_secs` - Maximum recording duration in seconds (1-30)
+    /// * `max_duration` - Maximum recording duration
+    pub fn new(max_duration: AudioDuration) -> Self {
+        Self {
+            max_duration,
+            time_limit: None,
+            preferred_devices: Vec::new(),
+            input_gains: Vec::new(),
+        }
+    }
+
+    /// Lets the recording run for `limit` instead of its maximum duration.
+    ///
+    /// Long-form dictations use this to record for longer than
+    /// [`AudioDuration::MAX`].
+    pub fn with_time_limit(mut self, limit: Duration) -> Self {
+        self.time_limit = Some(limit);
+        self
+    }
+
+    /// Sets the input devices to record from, in order of preference.
     ///
-    /// # Returns
+    /// The highest-priority device connected when recording starts is used;
+    /// with none connected (or an empty list) the system default is used.
+    pub fn with_preferred_devices(mut self, devices: Vec<String>) -> Self {
+        self.preferred_devices = devices;
+        self
+    }
+
+    /// Returns the preferred input device names, highest priority first.
+    pub fn preferred_devices(&self) -> &[String] {
+        &self.preferred_devices
+    }
+
+    /// Sets the software gain of individual input devices.
     ///
-    /// A recording configuration, with duration clamped to valid range
-    pub fn new(max_duration_secs: u32) -> Self {
-        let clamped_duration = max_duration_secs.clamp(1, MAX_ALLOWED_DURATION_SECS);
+    /// Whichever device the recording uses is amplified by its gain as
+    /// audio is captured; devices without one are recorded at unity gain.
+    pub fn with_input_gains(mut self, input_gains: Vec<InputGain>) -> Self {
+        self.input_gains = input_gains;
+        self
+    }
 
-        if clamped_duration != max_duration_secs {
-            warn!(
-                requested = max_duration_secs,
-                clamped = clamped_duration,
-                "Recording duration clamped to valid range (1-30 seconds)"
-            );
-        }
+    /// Returns the software gain of individual input devices.
+    pub fn input_gains(&self) -> &[InputGain] {
+        &self.input_gains
+    }
 
-        Self {
-            max_duration_secs: clamped_duration,
-        }
+    /// Returns the maximum recording duration.
+    pub fn max_duration(&self) -> AudioDuration {
+        self.max_duration
     }
 
-    /// Returns the maximum recording duration in seconds.
-    pub fn max_duration_secs(&self) -> u32 {
-        self.max_duration_secs
+    /// Returns how long the recording may run: the time limit if one is
+    /// set, otherwise the maximum duration.
+    pub fn time_limit(&self) -> Duration {
+        self.time_limit
+            .unwrap_or_else(|| self.max_duration.as_duration())
     }
 
     /// Returns the maximum number of samples for this configuration.
     pub fn max_samples(&self) -> usize {
-        (self.max_duration_secs as usize) * (SAMPLE_RATE_HZ as usize)
-    }
-}
-
-impl Default for RecordingConfig {
-    fn default() -> Self {
-        Self {
-            max_duration_secs: DEFAULT_MAX_DURATION_SECS,
-        }
+        (self.time_limit().as_secs() * u64::from(SAMPLE_RATE_HZ)) as usize
     }
 }
 
@@ -220,6 +320,27 @@ pub trait AudioSystem: Send + Sync {
     /// Returns `AudioCaptureError::MicrophoneNotAvailable` if no input devices are found,
     /// or `AudioCaptureError::DeviceError` if device enumeration fails.
     fn list_input_devices(&self) -> Result<Vec<AudioDevice>, AudioCaptureError>;
+
+    /// Start a stream that only keeps the most recent `duration_secs` of audio.
+    ///
+    /// The stream opens the device `config` prefers at its input gain, like
+    /// [`AudioSystem::start_recording`]. Its [`AudioStream::get_samples`]
+    /// yields the rolling window. Audio systems that cannot pre-buffer keep
+    /// this default, which reports the mode as unsupported.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AudioCaptureError::InvalidConfiguration` if pre-buffering is not
+    /// supported, or the same errors as [`AudioSystem::start_recording`].
+    fn start_pre_buffer(
+        &self,
+        _duration_secs: u32,
+        _config: &RecordingConfig,
+    ) -> Result<Box<dyn AudioStream>, AudioCaptureError> {
+        Err(AudioCaptureError::InvalidConfiguration(
+            "Pre-buffering is not supported by this audio system".to_string(),
+        ))
+    }
 }
 
 /// Trait for audio stream management.
@@ -227,36 +348,500 @@ pub trait AudioStream: Send + Sync {
     /// Get samples that have been recorded so far.
     fn get_samples(&self) -> Vec<i16>;
 
+    /// Get the samples recorded from the 16 kHz sample `start` onwards.
+    ///
+    /// The default slices [`AudioStream::get_samples`]; streams that can
+    /// read part of their raw buffer more cheaply should override it.
+    fn get_samples_from(&self, start: usize) -> Vec<i16> {
+        let mut samples = self.get_samples();
+        let tail = samples.get(start..).unwrap_or_default().to_vec();
+        samples.zeroize();
+        tail
+    }
+
     /// Stop the audio stream.
     fn stop(&self);
 
+    /// Zero the audio captured so far, e.g. when a recording is cancelled.
+    ///
+    /// Streams that keep no audio of their own leave this default, which
+    /// does nothing.
+    fn wipe(&self) {}
+
     /// Check if the stream is still active.
     fn is_active(&self) -> bool;
+
+    /// Name of the input device the stream records from, if known.
+    fn device_name(&self) -> Option<String> {
+        None
+    }
+
+    /// Current input level (RMS over the last [`LEVEL_WINDOW_MS`]), in the
+    /// range `0.0..=1.0`.
+    ///
+    /// The default derives it from [`AudioStream::get_samples`]; streams that
+    /// can read their raw buffer more cheaply should override it.
+    fn level(&self) -> f32 {
+        let samples = self.get_samples();
+        let window = (SAMPLE_RATE_HZ * LEVEL_WINDOW_MS / 1000) as usize;
+        rms(samples[samples.len().saturating_sub(window)..]
+            .iter()
+            .map(|&sample| f32::from(sample) / f32::from(i16::MAX)))
+    }
+}
+
+/// Storage for the mono, native-rate samples a stream captures.
+#[derive(Debug)]
+enum CaptureBuffer {
+    /// Every sample since the stream started (a normal recording).
+    Linear(Vec<f32>),
+    /// Only the most recent `seconds` of audio (pre-record mode).
+    Rolling { seconds: u32, ring: RingBuffer<f32> },
+}
+
+impl CaptureBuffer {
+    /// Creates an empty rolling buffer; it is sized by [`Self::prepare`].
+    fn rolling(seconds: u32) -> Self {
+        Self::Rolling {
+            seconds,
+            ring: RingBuffer::with_capacity(0),
+        }
+    }
+
+    /// Sizes a rolling buffer for the device's sample rate.
+    fn prepare(&mut self, sample_rate: u32) {
+        if let Self::Rolling { seconds, ring } = self {
+            *ring = RingBuffer::with_capacity(sample_rate as usize * *seconds as usize);
+        }
+    }
+
+    fn extend(&mut self, samples: impl IntoIterator<Item = f32>) {
+        match self {
+            Self::Linear(buffer) => buffer.extend(samples),
+            Self::Rolling { ring, .. } => ring.extend(samples),
+        }
+    }
+
+    /// Copies the samples from the native-rate sample `start` onwards.
+    fn snapshot_from(&self, start: usize) -> Vec<f32> {
+        match self {
+            Self::Linear(buffer) => buffer.get(start..).unwrap_or_default().to_vec(),
+            Self::Rolling { ring, .. } => {
+                let mut samples = ring.to_vec();
+                let tail = samples.get(start..).unwrap_or_default().to_vec();
+                samples.zeroize();
+                tail
+            }
+        }
+    }
+
+    /// Zeroes every sample captured so far.
+    fn wipe(&mut self) {
+        match self {
+            Self::Linear(buffer) => buffer.zeroize(),
+            Self::Rolling { ring, .. } => ring.clear(),
+        }
+    }
+
+    /// RMS level of the last `count` samples.
+    fn tail_level(&self, count: usize) -> f32 {
+        match self {
+            Self::Linear(buffer) => {
+                rms(buffer[buffer.len().saturating_sub(count)..].iter().copied())
+            }
+            Self::Rolling { ring, .. } => {
+                let samples = ring.to_vec();
+                rms(samples[samples.len().saturating_sub(count)..]
+                    .iter()
+                    .copied())
+            }
+        }
+    }
+}
+
+impl Drop for CaptureBuffer {
+    fn drop(&mut self) {
+        // The rolling buffer zeroes itself
+        if let Self::Linear(buffer) = self {
+            buffer.zeroize();
+        }
+    }
+}
+
+/// Commands sent to the thread that owns a live `cpal` stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StreamCommand {
+    /// Pause and drop the stream, releasing the input device.
+    Stop,
 }
 
 /// Real audio stream implementation.
+///
+/// `cpal` streams are neither `Send` nor `Sync`, so the stream is created on
+/// and owned by a dedicated audio thread. This handle talks to that thread
+/// over a command channel: [`AudioStream::stop`] tells it to drop the stream
+/// and waits for it to exit, which releases the device so the next session
+/// can open it afresh (possibly with a different configuration).
 pub struct CpalAudioStream {
-    samples: Arc<Mutex<Vec<i16>>>,
+    /// Mono samples at the device's native rate, in the range `-1.0..=1.0`.
+    samples: Arc<Mutex<CaptureBuffer>>,
+    /// Native sample rate of the device in Hz.
+    sample_rate: u32,
+    /// Name of the device being recorded from.
+    device_name: Option<String>,
     is_recording: Arc<AtomicBool>,
+    command_sender: Mutex<Option<mpsc::Sender<StreamCommand>>>,
+    audio_thread: Mutex<Option<JoinHandle<()>>>,
 }
 
-// SAFETY: CpalAudioStream only contains thread-safe types (Arc<Mutex<_>> and Arc<AtomicBool>)
-unsafe impl Send for CpalAudioStream {}
-unsafe impl Sync for CpalAudioStream {}
+impl CpalAudioStream {
+    /// Spawns the audio thread and waits for the stream to start.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - Buffer the stream callback appends to
+    /// * `is_recording` - Flag the stream callback checks before storing samples
+    /// * `build_stream` - Builds and starts the stream on the audio thread,
+    ///   returning it with the sample rate it captures at; the stream is kept
+    ///   alive until it is stopped
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from `build_stream`, or `AudioCaptureError::StreamError`
+    /// if the audio thread cannot be spawned or exits before reporting back.
+    fn spawn<S, F>(
+        samples: Arc<Mutex<CaptureBuffer>>,
+        is_recording: Arc<AtomicBool>,
+        build_stream: F,
+    ) -> Result<Self, AudioCaptureError>
+    where
+        F: FnOnce() -> Result<(S, u32), AudioCaptureError> + Send + 'static,
+        S: 'static,
+    {
+        let (command_sender, command_receiver) = mpsc::channel::<StreamCommand>();
+        let (ready_sender, ready_receiver) =
+            mpsc::sync_channel::<Result<u32, AudioCaptureError>>(1);
+
+        let audio_thread = thread::Builder::new()
+            .name("speakr-audio-capture".to_string())
+            .spawn(move || {
+                let stream = match build_stream() {
+                    Ok((stream, sample_rate)) => {
+                        let _ = ready_sender.send(Ok(sample_rate));
+                        stream
+                    }
+                    Err(e) => {
+                        let _ = ready_sender.send(Err(e));
+                        return;
+                    }
+                };
+
+                // Block until asked to stop, or until the handle is dropped
+                match command_receiver.recv() {
+                    Ok(StreamCommand::Stop) => debug!("Audio stream stop requested"),
+                    Err(_) => debug!("Audio stream handle dropped"),
+                }
+
+                drop(stream);
+                debug!("Audio stream released");
+            })
+            .map_err(|e| {
+                AudioCaptureError::StreamError(format!("Failed to spawn audio thread: {e}"))
+            })?;
+
+        match ready_receiver.recv() {
+            Ok(Ok(sample_rate)) => Ok(Self {
+                samples,
+                sample_rate,
+                device_name: None,
+                is_recording,
+                command_sender: Mutex::new(Some(command_sender)),
+                audio_thread: Mutex::new(Some(audio_thread)),
+            }),
+            Ok(Err(e)) => {
+                let _ = audio_thread.join();
+                Err(e)
+            }
+            Err(_) => {
+                let _ = audio_thread.join();
+                Err(AudioCaptureError::StreamError(
+                    "Audio thread exited before the stream started".to_string(),
+                ))
+            }
+        }
+    }
+}
 
 impl AudioStream for CpalAudioStream {
     fn get_samples(&self) -> Vec<i16> {
-        let samples_guard = self.samples.lock().unwrap();
-        samples_guard.clone()
+        self.get_samples_from(0)
+    }
+
+    fn get_samples_from(&self, start: usize) -> Vec<i16> {
+        // Only the native samples from `start` on are copied and resampled
+        let native_start =
+            (start as u64 * u64::from(self.sample_rate) / u64::from(SAMPLE_RATE_HZ)) as usize;
+        let mut native = self
+            .samples
+            .lock()
+            .map(|samples| samples.snapshot_from(native_start))
+            .unwrap_or_default();
+
+        let samples = resample::resample_to_whisper_format(&native, self.sample_rate)
+            .unwrap_or_else(|e| {
+                error!("Failed to convert captured audio: {}", e);
+                Vec::new()
+            });
+        native.zeroize();
+        samples
     }
 
     fn stop(&self) {
         self.is_recording.store(false, Ordering::Release);
+
+        if let Some(sender) = self.command_sender.lock().ok().and_then(|mut s| s.take()) {
+            let _ = sender.send(StreamCommand::Stop);
+        }
+
+        if let Some(handle) = self.audio_thread.lock().ok().and_then(|mut h| h.take()) {
+            if handle.join().is_err() {
+                error!("Audio thread panicked while stopping");
+            }
+        }
+    }
+
+    fn wipe(&self) {
+        if let Ok(mut samples) = self.samples.lock() {
+            samples.wipe();
+        }
     }
 
     fn is_active(&self) -> bool {
         self.is_recording.load(Ordering::Acquire)
     }
+
+    fn device_name(&self) -> Option<String> {
+        self.device_name.clone()
+    }
+
+    fn level(&self) -> f32 {
+        // Measured at the native rate, so no resampling is needed
+        let window = (self.sample_rate * LEVEL_WINDOW_MS / 1000) as usize;
+        self.samples
+            .lock()
+            .map(|samples| samples.tail_level(window))
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for CpalAudioStream {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Converts interleaved frames of any sample format to `f32`, downmixes them
+/// to mono, applies the device's input gain and appends them to the shared
+/// buffer while recording is active.
+fn push_samples<T>(
+    samples: &Mutex<CaptureBuffer>,
+    is_recording: &AtomicBool,
+    data: &[T],
+    channels: usize,
+    input_gain: f32,
+) where
+    T: Sample,
+    f32: FromSample<T>,
+{
+    if !is_recording.load(Ordering::Acquire) {
+        return;
+    }
+    if let Ok(mut samples_guard) = samples.lock() {
+        samples_guard.extend(data.chunks(channels).map(|frame| {
+            let mono = frame
+                .iter()
+                .map(|&sample| sample.to_sample::<f32>())
+                .sum::<f32>()
+                / frame.len() as f32;
+            gain::apply_input_gain(mono, input_gain)
+        }));
+    }
+}
+
+/// Whether [`push_samples`] can convert samples of `format`.
+///
+/// Covers every format `cpal` captures in; 24-bit devices deliver their
+/// samples padded to `I32`.
+fn is_convertible(format: SampleFormat) -> bool {
+    matches!(
+        format,
+        SampleFormat::I8
+            | SampleFormat::I16
+            | SampleFormat::I32
+            | SampleFormat::I64
+            | SampleFormat::U8
+            | SampleFormat::U16
+            | SampleFormat::U32
+            | SampleFormat::U64
+            | SampleFormat::F32
+            | SampleFormat::F64
+    )
+}
+
+/// Chooses the configuration to capture from `device` with: its default,
+/// unless that is in a sample format that cannot be converted, in which
+/// case the first convertible configuration it offers, as close to the
+/// default sample rate as it allows.
+fn capture_config(device: &cpal::Device) -> Result<SupportedStreamConfig, AudioCaptureError> {
+    let default_config = device
+        .default_input_config()
+        .map_err(|e| AudioCaptureError::DeviceError(e.to_string()))?;
+    if is_convertible(default_config.sample_format()) {
+        return Ok(default_config);
+    }
+
+    let sample_rate = default_config.sample_rate();
+    let range = device
+        .supported_input_configs()
+        .map_err(|e| AudioCaptureError::DeviceError(e.to_string()))?
+        .find(|range| is_convertible(range.sample_format()))
+        .ok_or_else(|| {
+            AudioCaptureError::DeviceError(format!(
+                "The device only offers {:?} samples",
+                default_config.sample_format()
+            ))
+        })?;
+    let sample_rate = sample_rate.clamp(range.min_sample_rate(), range.max_sample_rate());
+    warn!(
+        "Capturing {:?} samples instead of the default {:?}",
+        range.sample_format(),
+        default_config.sample_format()
+    );
+    Ok(range.with_sample_rate(sample_rate))
+}
+
+/// Builds an input stream delivering samples of type `T`.
+fn build_converting_stream<T>(
+    device: &cpal::Device,
+    stream_config: &StreamConfig,
+    samples: Arc<Mutex<CaptureBuffer>>,
+    is_recording: Arc<AtomicBool>,
+    input_gain: f32,
+) -> Result<cpal::Stream, cpal::BuildStreamError>
+where
+    T: SizedSample,
+    f32: FromSample<T>,
+{
+    let channels = usize::from(stream_config.channels.max(1));
+    device.build_input_stream(
+        stream_config,
+        move |data: &[T], _: &cpal::InputCallbackInfo| {
+            push_samples(&samples, &is_recording, data, channels, input_gain);
+        },
+        |err| error!("Audio stream error: {}", err),
+        None,
+    )
+}
+
+/// Opens an input device and starts an input stream at the device's native
+/// sample rate, channel count and sample format.
+///
+/// Runs on the audio thread; the returned stream must stay on that thread.
+///
+/// # Arguments
+///
+/// * `device_name` - The device to open; the default device is used if this
+///   is `None` or the device has been disconnected
+/// * `input_gains` - Software gain of individual devices; the opened device
+///   is amplified by its own
+///
+/// # Returns
+///
+/// The running stream and the sample rate it captures at.
+fn build_input_stream(
+    samples: Arc<Mutex<CaptureBuffer>>,
+    is_recording: Arc<AtomicBool>,
+    device_name: Option<String>,
+    input_gains: &[InputGain],
+) -> Result<(cpal::Stream, u32), AudioCaptureError> {
+    let host = cpal::default_host();
+
+    let named_device = device_name.and_then(|name| {
+        let device = host
+            .input_devices()
+            .ok()?
+            .find(|device| device.name().is_ok_and(|device_name| device_name == name));
+        if device.is_none() {
+            warn!("Input device '{}' disappeared, using the default", name);
+        }
+        device
+    });
+    let device = match named_device {
+        Some(device) => device,
+        None => host
+            .default_input_device()
+            .ok_or(AudioCaptureError::MicrophoneNotAvailable)?,
+    };
+
+    // Query the device afresh for every session so configuration changes
+    // between recordings are picked up
+    let supported_config = capture_config(&device)?;
+
+    // Capture in the device's native format; forcing 16 kHz mono i16 fails
+    // on devices that only expose 44.1/48 kHz, stereo or 24-bit streams
+    let stream_config: StreamConfig = supported_config.config();
+    let sample_rate = stream_config.sample_rate.0;
+    let sample_format = supported_config.sample_format();
+    let input_gain = device
+        .name()
+        .map_or(1.0, |name| input_gain_for(input_gains, &name));
+    debug!(
+        "Opening input stream at {} Hz with {} channel(s) of {:?} samples, gain {}",
+        sample_rate, stream_config.channels, sample_format, input_gain
+    );
+    if let Ok(mut buffer) = samples.lock() {
+        buffer.prepare(sample_rate);
+    }
+
+    let (config, buffer, recording, gain) = (&stream_config, samples, is_recording, input_gain);
+    let stream = match sample_format {
+        SampleFormat::I8 => build_converting_stream::<i8>(&device, config, buffer, recording, gain),
+        SampleFormat::I16 => {
+            build_converting_stream::<i16>(&device, config, buffer, recording, gain)
+        }
+        SampleFormat::I32 => {
+            build_converting_stream::<i32>(&device, config, buffer, recording, gain)
+        }
+        SampleFormat::I64 => {
+            build_converting_stream::<i64>(&device, config, buffer, recording, gain)
+        }
+        SampleFormat::U8 => build_converting_stream::<u8>(&device, config, buffer, recording, gain),
+        SampleFormat::U16 => {
+            build_converting_stream::<u16>(&device, config, buffer, recording, gain)
+        }
+        SampleFormat::U32 => {
+            build_converting_stream::<u32>(&device, config, buffer, recording, gain)
+        }
+        SampleFormat::U64 => {
+            build_converting_stream::<u64>(&device, config, buffer, recording, gain)
+        }
+        SampleFormat::F32 => {
+            build_converting_stream::<f32>(&device, config, buffer, recording, gain)
+        }
+        SampleFormat::F64 => {
+            build_converting_stream::<f64>(&device, config, buffer, recording, gain)
+        }
+        // `capture_config` only picks formats `is_convertible` accepts
+        _ => Err(cpal::BuildStreamError::StreamConfigNotSupported),
+    }
+    .map_err(|e| AudioCaptureError::StreamError(e.to_string()))?;
+
+    // Start the stream
+    stream
+        .play()
+        .map_err(|e| AudioCaptureError::StreamError(e.to_string()))?;
+
+    Ok((stream, sample_rate))
 }
 
 /// Real audio system implementation using cpal.
@@ -279,112 +864,80 @@ impl CpalAudioSystem {
     }
 }
 
-impl AudioSystem for CpalAudioSystem {
-    fn start_recording(
+impl CpalAudioSystem {
+    /// Opens the preferred input device, collecting samples into `buffer`.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - Where captured samples are stored
+    /// * `preferred` - Device names in order of preference (see
+    ///   [`select_input_device`])
+    /// * `input_gains` - Software gain of individual devices
+    fn start_stream(
         &self,
-        _config: &RecordingConfig,
+        buffer: CaptureBuffer,
+        preferred: &[String],
+        input_gains: &[InputGain],
     ) -> Result<Box<dyn AudioStream>, AudioCaptureError> {
-        // Get the default input device
-        let device = self
-            .host
-            .default_input_device()
-            .ok_or(AudioCaptureError::MicrophoneNotAvailable)?;
-
-        // Get supported input configs
-        let supported_config = device
-            .default_input_config()
-            .map_err(|e| AudioCaptureError::DeviceError(e.to_string()))?;
-
-        // Create stream config with our requirements
-        let stream_config = StreamConfig {
-            channels: CHANNELS,
-            sample_rate: cpal::SampleRate(SAMPLE_RATE_HZ),
-            buffer_size: cpal::BufferSize::Default,
+        let device_name = match self.list_input_devices() {
+            Ok(devices) => {
+                select_input_device(preferred, &devices).map(|device| device.name.clone())
+            }
+            Err(e) => {
+                warn!("Could not list input devices, using the default: {}", e);
+                None
+            }
         };
+        info!(
+            device = device_name.as_deref().unwrap_or("default"),
+            "Opening input device"
+        );
 
         // Create shared state for the recording
-        let samples = Arc::new(Mutex::new(Vec::new()));
+        let samples = Arc::new(Mutex::new(buffer));
         let is_recording = Arc::new(AtomicBool::new(true));
 
-        // Clone for the stream callback
-        let samples_clone = Arc::clone(&samples);
-        let is_recording_clone = Arc::clone(&is_recording);
-
-        // Create the input stream based on sample format
-        let stream = match supported_config.sample_format() {
-            SampleFormat::F32 => {
-                device
-                    .build_input_stream(
-                        &stream_config,
-                        move |data: &[f32], _: &cpal::InputCallbackInfo| {
-                            if is_recording_clone.load(Ordering::Acquire) {
-                                let mut samples_guard = samples_clone.lock().unwrap();
-                                for &sample in data {
-                                    // Convert f32 to i16 and store
-                                    let sample_i16 = (sample * (i16::MAX as f32)) as i16;
-                                    samples_guard.push(sample_i16);
-                                }
-                            }
-                        },
-                        |err| error!("Audio stream error: {}", err),
-                        None,
-                    )
-                    .map_err(|e| AudioCaptureError::StreamError(e.to_string()))?
-            }
-            SampleFormat::I16 => device
-                .build_input_stream(
-                    &stream_config,
-                    move |data: &[i16], _: &cpal::InputCallbackInfo| {
-                        if is_recording_clone.load(Ordering::Acquire) {
-                            let mut samples_guard = samples_clone.lock().unwrap();
-                            samples_guard.extend_from_slice(data);
-                        }
-                    },
-                    |err| error!("Audio stream error: {}", err),
-                    None,
-                )
-                .map_err(|e| AudioCaptureError::StreamError(e.to_string()))?,
-            SampleFormat::U16 => {
-                device
-                    .build_input_stream(
-                        &stream_config,
-                        move |data: &[u16], _: &cpal::InputCallbackInfo| {
-                            if is_recording_clone.load(Ordering::Acquire) {
-                                let mut samples_guard = samples_clone.lock().unwrap();
-                                for &sample in data {
-                                    // Convert u16 to i16
-                                    let sample_i16 = ((sample as i32) - 32768) as i16;
-                                    samples_guard.push(sample_i16);
-                                }
-                            }
-                        },
-                        |err| error!("Audio stream error: {}", err),
-                        None,
-                    )
-                    .map_err(|e| AudioCaptureError::StreamError(e.to_string()))?
-            }
-            format => {
-                return Err(AudioCaptureError::DeviceError(format!(
-                    "Unsupported sample format: {format:?}"
-                )));
-            }
-        };
+        let stream_samples = Arc::clone(&samples);
+        let stream_is_recording = Arc::clone(&is_recording);
+        let stream_device_name = device_name.clone();
+        let stream_input_gains = input_gains.to_vec();
+
+        let mut stream = CpalAudioStream::spawn(samples, is_recording, move || {
+            build_input_stream(
+                stream_samples,
+                stream_is_recording,
+                stream_device_name,
+                &stream_input_gains,
+            )
+        })?;
+        stream.device_name = device_name;
 
-        // Start the stream
-        stream
-            .play()
-            .map_err(|e| AudioCaptureError::StreamError(e.to_string()))?;
+        Ok(Box::new(stream))
+    }
+}
 
-        // Keep the stream alive by leaking it - this is necessary because cpal streams
-        // are not Send/Sync and we can't store them in our thread-safe wrapper.
-        // TODO: allow stream to be stopped
-        // In a production system, you'd want a more sophisticated approach to stream lifecycle.
-        std::mem::forget(stream);
+impl AudioSystem for CpalAudioSystem {
+    fn start_recording(
+        &self,
+        config: &RecordingConfig,
+    ) -> Result<Box<dyn AudioStream>, AudioCaptureError> {
+        self.start_stream(
+            CaptureBuffer::Linear(Vec::new()),
+            config.preferred_devices(),
+            config.input_gains(),
+        )
+    }
 
-        Ok(Box::new(CpalAudioStream {
-            samples,
-            is_recording,
-        }))
+    fn start_pre_buffer(
+        &self,
+        duration_secs: u32,
+        config: &RecordingConfig,
+    ) -> Result<Box<dyn AudioStream>, AudioCaptureError> {
+        self.start_stream(
+            CaptureBuffer::rolling(duration_secs),
+            config.preferred_devices(),
+            config.input_gains(),
+        )
     }
 
     fn list_input_devices(&self) -> Result<Vec<AudioDevice>, AudioCaptureError> {
@@ -428,6 +981,8 @@ impl AudioSystem for CpalAudioSystem {
 /// Internal recording state.
 struct RecordingState {
     stream: Box<dyn AudioStream>,
+    /// Audio from the pre-record buffer, captured just before this session.
+    pre_roll: Vec<i16>,
     start_time: Instant,
     config: RecordingConfig,
     stop_sender: Option<oneshot::Sender<()>>,
@@ -437,6 +992,7 @@ impl std::fmt::Debug for RecordingState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("RecordingState")
             .field("stream", &"<AudioStream>")
+            .field("pre_roll_samples", &self.pre_roll.len())
             .field("start_time", &self.start_time)
             .field("config", &self.config)
             .field("stop_sender", &"<Option<oneshot::Sender<()>>>")
@@ -444,10 +1000,24 @@ impl std::fmt::Debug for RecordingState {
     }
 }
 
+/// Rolling stream kept open while pre-record mode is enabled.
+struct PreBuffer {
+    stream: Box<dyn AudioStream>,
+    /// Length of the rolling window, kept to reopen the stream.
+    duration_secs: u32,
+}
+
 /// Main audio recorder that handles microphone capture.
 pub struct AudioRecorder {
     state: Arc<Mutex<Option<RecordingState>>>,
     audio_system: Box<dyn AudioSystem>,
+    pre_buffer: Mutex<Option<PreBuffer>>,
+    /// Input device names to record from, highest priority first.
+    preferred_devices: Mutex<Vec<String>>,
+    /// How long later recordings may run, if longer than the default.
+    time_limit: Mutex<Option<Duration>>,
+    /// Software gain of individual input devices.
+    input_gains: Mutex<Vec<InputGain>>,
 }
 
 impl std::fmt::Debug for AudioRecorder {
@@ -455,6 +1025,7 @@ impl std::fmt::Debug for AudioRecorder {
         f.debug_struct("AudioRecorder")
             .field("state", &"<RecordingState>")
             .field("audio_system", &"<AudioSystem>")
+            .field("pre_buffering", &self.is_pre_buffering())
             .finish()
     }
 }
@@ -477,7 +1048,7 @@ impl AudioRecorder {
     #[instrument(level = "debug")]
     pub async fn new(config: RecordingConfig) -> Result<Self, AudioCaptureError> {
         debug!(
-            max_duration = config.max_duration_secs,
+            max_duration = %config.max_duration,
             "Creating new AudioRecorder"
         );
 
@@ -488,6 +1059,10 @@ impl AudioRecorder {
         Ok(Self {
             state: Arc::new(Mutex::new(None)),
             audio_system,
+            pre_buffer: Mutex::new(None),
+            preferred_devices: Mutex::new(config.preferred_devices),
+            time_limit: Mutex::new(config.time_limit),
+            input_gains: Mutex::new(config.input_gains),
         })
     }
 
@@ -497,7 +1072,64 @@ impl AudioRecorder {
         Self {
             state: Arc::new(Mutex::new(None)),
             audio_system,
+            pre_buffer: Mutex::new(None),
+            preferred_devices: Mutex::new(Vec::new()),
+            time_limit: Mutex::new(None),
+            input_gains: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Sets the input devices later recordings use, highest priority first.
+    ///
+    /// A long-lived recorder (e.g. the pre-record one) picks up preference
+    /// changes this way; a recording in progress keeps its device. If the
+    /// preferences change while pre-record mode is enabled, its stream is
+    /// reopened on the new device and the audio buffered so far is dropped.
+    pub fn set_preferred_devices(&self, devices: Vec<String>) {
+        {
+            let mut preferred = self
+                .preferred_devices
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            if *preferred == devices {
+                return;
+            }
+            *preferred = devices;
+        }
+        self.restart_pre_buffer();
+    }
+
+    /// Sets how long later recordings may run before they stop by themselves.
+    ///
+    /// Like [`set_preferred_devices`](Self::set_preferred_devices), this
+    /// lets a long-lived recorder follow the settings; a recording in
+    /// progress keeps its limit.
+    pub fn set_time_limit(&self, limit: Duration) {
+        *self
+            .time_limit
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(limit);
+    }
+
+    /// Sets the software gain of individual input devices for later
+    /// recordings.
+    ///
+    /// Like [`set_preferred_devices`](Self::set_preferred_devices), this
+    /// lets a long-lived recorder follow the settings; a recording in
+    /// progress keeps its gain, and the pre-record stream is reopened if
+    /// the gains change.
+    pub fn set_input_gains(&self, input_gains: Vec<InputGain>) {
+        {
+            let mut gains = self
+                .input_gains
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            if *gains == input_gains {
+                return;
+            }
+            *gains = input_gains;
         }
+        self.restart_pre_buffer();
     }
 
     /// Starts recording audio from the default microphone.
@@ -524,18 +1156,29 @@ impl AudioRecorder {
 
         debug!("Starting audio recording");
 
-        let config = RecordingConfig::default();
+        let config = self.recording_config();
+
+        // Snapshot the pre-record buffer so speech from just before the
+        // hot-key press is kept
+        let pre_roll = self
+            .pre_buffer
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .map(|pre_buffer| pre_buffer.stream.get_samples())
+            .unwrap_or_default();
 
         // Start the audio stream
         let stream = self.audio_system.start_recording(&config)?;
 
-        let (stop_sender, _stop_receiver) = oneshot::channel::<()>();
+        let (stop_sender, stop_receiver) = oneshot::channel::<()>();
 
         // Store the recording state
         {
             let mut state_guard = self.state.lock().unwrap();
             *state_guard = Some(RecordingState {
                 stream,
+                pre_roll,
                 start_time,
                 config: config.clone(),
                 stop_sender: Some(stop_sender),
@@ -544,15 +1187,19 @@ impl AudioRecorder {
 
         // Spawn timeout task
         let state_for_timeout = Arc::clone(&self.state);
-        let timeout_duration = Duration::from_secs(config.max_duration_secs as u64);
+        let timeout_duration = config.time_limit();
 
         tokio::spawn(async move {
-            tokio::time::sleep(timeout_duration).await;
-
-            // Stop the stream when timeout is reached
-            if let Some(state) = state_for_timeout.lock().unwrap().as_ref() {
-                state.stream.stop();
-                debug!("Recording stopped due to timeout");
+            tokio::select! {
+                _ = tokio::time::sleep(timeout_duration) => {
+                    // Stop the stream when timeout is reached
+                    if let Some(state) = state_for_timeout.lock().unwrap().as_ref() {
+                        state.stream.stop();
+                        debug!("Recording stopped due to timeout");
+                    }
+                }
+                // Stopped manually; never touch a later session's stream
+                _ = stop_receiver => {}
             }
         });
 
@@ -594,11 +1241,14 @@ impl AudioRecorder {
         // Wait a brief moment for any in-flight samples to be processed
         tokio::time::sleep(Duration::from_millis(10)).await;
 
-        // Extract the samples
-        let samples = state.stream.get_samples();
+        // Extract the samples, preceded by any pre-recorded audio
+        let mut samples = std::mem::take(&mut state.pre_roll);
+        let mut captured = state.stream.get_samples();
+        samples.extend_from_slice(&captured);
+        captured.zeroize();
 
         let recording_duration = state.start_time.elapsed();
-        let expected_duration = Duration::from_secs(state.config.max_duration_secs as u64);
+        let expected_duration = state.config.time_limit();
 
         info!(
             sample_count = samples.len(),
@@ -621,6 +1271,98 @@ impl AudioRecorder {
         Ok(result)
     }
 
+    /// Abandons the current recording, zeroing its audio.
+    ///
+    /// Unlike [`AudioRecorder::stop_recording`] this is synchronous, so it can
+    /// run from `Drop` when a dictation is cancelled mid-recording. A
+    /// pre-record stream, if enabled, keeps running.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a recording was active and has been cancelled.
+    pub fn cancel_recording(&self) -> bool {
+        let Some(mut state) = self
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take()
+        else {
+            return false;
+        };
+
+        if let Some(stop_sender) = state.stop_sender.take() {
+            let _ = stop_sender.send(());
+        }
+        state.stream.stop();
+        state.pre_roll.zeroize();
+        state.stream.wipe();
+        info!("Recording cancelled");
+        true
+    }
+
+    /// Returns the audio captured so far by the current recording.
+    ///
+    /// The recording keeps running; this is used to inspect speech while it
+    /// is still being captured (e.g. to listen for a spoken stop phrase).
+    ///
+    /// # Returns
+    ///
+    /// `None` if no recording is active or its stream has stopped.
+    pub fn peek_samples(&self) -> Option<Vec<i16>> {
+        self.peek_samples_from(0)
+    }
+
+    /// Returns the audio captured by the current recording from the 16 kHz
+    /// sample `start` onwards.
+    ///
+    /// Like [`peek_samples`](Self::peek_samples), but only the requested
+    /// part is copied, so a long recording can be followed cheaply (e.g. to
+    /// transcribe it in chunks as it grows).
+    ///
+    /// # Returns
+    ///
+    /// `None` if no recording is active or its stream has stopped.
+    pub fn peek_samples_from(&self, start: usize) -> Option<Vec<i16>> {
+        let state_guard = self.state.lock().unwrap();
+        let state = state_guard
+            .as_ref()
+            .filter(|state| state.stream.is_active())?;
+
+        let mut samples = state.pre_roll.get(start..).unwrap_or_default().to_vec();
+        samples.extend(
+            state
+                .stream
+                .get_samples_from(start.saturating_sub(state.pre_roll.len())),
+        );
+        Some(samples)
+    }
+
+    /// Returns the current input level of the active recording.
+    ///
+    /// # Returns
+    ///
+    /// The RMS level over the last [`LEVEL_WINDOW_MS`] (`0.0..=1.0`), or
+    /// `None` if no recording is active.
+    pub fn input_level(&self) -> Option<f32> {
+        let state_guard = self.state.lock().unwrap();
+        state_guard
+            .as_ref()
+            .filter(|state| state.stream.is_active())
+            .map(|state| state.stream.level())
+    }
+
+    /// Returns the name of the device the active recording captures from.
+    ///
+    /// # Returns
+    ///
+    /// `None` if no recording is active or the device is not known.
+    pub fn input_device(&self) -> Option<String> {
+        let state_guard = self.state.lock().unwrap();
+        state_guard
+            .as_ref()
+            .and_then(|state| state.stream.device_name())
+    }
+
     /// Returns whether a recording is currently in progress.
     pub fn is_recording(&self) -> bool {
         let state_guard = self.state.lock().unwrap();
@@ -630,6 +1372,120 @@ impl AudioRecorder {
         }
     }
 
+    /// Enables pre-record mode, keeping the last `duration_secs` of audio.
+    ///
+    /// While enabled, the microphone stays open and a rolling window of audio
+    /// is kept in memory only; each recording starts with that window. Any
+    /// existing pre-record stream is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration_secs` - Length of the rolling window (1 to [`MAX_PRE_BUFFER_SECS`])
+    ///
+    /// # Errors
+    ///
+    /// Returns `AudioCaptureError::InvalidConfiguration` if the duration is out
+    /// of range or the audio system cannot pre-buffer, or an error if the
+    /// stream cannot be started.
+    #[instrument(level = "info", skip(self))]
+    pub fn enable_pre_buffer(&self, duration_secs: u32) -> Result<(), AudioCaptureError> {
+        if !(1..=MAX_PRE_BUFFER_SECS).contains(&duration_secs) {
+            return Err(AudioCaptureError::InvalidConfiguration(format!(
+                "Pre-record buffer must be between 1 and {MAX_PRE_BUFFER_SECS} seconds"
+            )));
+        }
+
+        self.disable_pre_buffer();
+        let stream = self
+            .audio_system
+            .start_pre_buffer(duration_secs, &self.recording_config())?;
+        *self
+            .pre_buffer
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(PreBuffer {
+            stream,
+            duration_secs,
+        });
+
+        info!(duration_secs, "Pre-record buffer enabled");
+        Ok(())
+    }
+
+    /// Reopens the pre-record stream with the current device preferences
+    /// and input gains.
+    ///
+    /// Does nothing unless pre-record mode is enabled. If the new stream
+    /// cannot be opened, the old one keeps running.
+    fn restart_pre_buffer(&self) {
+        let mut pre_buffer = self
+            .pre_buffer
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let Some(current) = pre_buffer.as_mut() else {
+            return;
+        };
+
+        match self
+            .audio_system
+            .start_pre_buffer(current.duration_secs, &self.recording_config())
+        {
+            Ok(stream) => {
+                std::mem::replace(&mut current.stream, stream).stop();
+                info!("Pre-record buffer reopened with the new input settings");
+            }
+            Err(e) => warn!("Could not reopen the pre-record buffer: {}", e),
+        }
+    }
+
+    /// Builds the configuration of the next stream from the preferences
+    /// set on this recorder.
+    fn recording_config(&self) -> RecordingConfig {
+        let preferred_devices = self
+            .preferred_devices
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        let input_gains = self
+            .input_gains
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        let config = RecordingConfig::default()
+            .with_preferred_devices(preferred_devices)
+            .with_input_gains(input_gains);
+        match *self
+            .time_limit
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+        {
+            Some(limit) => config.with_time_limit(limit),
+            None => config,
+        }
+    }
+
+    /// Disables pre-record mode, closing the stream and discarding its audio.
+    pub fn disable_pre_buffer(&self) {
+        let stream = self
+            .pre_buffer
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+
+        if let Some(PreBuffer { stream, .. }) = stream {
+            stream.stop();
+            info!("Pre-record buffer disabled");
+        }
+    }
+
+    /// Returns whether pre-record mode is enabled.
+    pub fn is_pre_buffering(&self) -> bool {
+        self.pre_buffer
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .is_some_and(|pre_buffer| pre_buffer.stream.is_active())
+    }
+
     /// Lists all available audio input devices.
     ///
     /// # Returns
@@ -648,3 +1504,154 @@ impl AudioRecorder {
         Ok(devices)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Stand-in for a `cpal::Stream` that records when it is dropped.
+    struct FakeStream(Arc<AtomicBool>);
+
+    impl Drop for FakeStream {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::Release);
+        }
+    }
+
+    fn spawn_fake_stream() -> (CpalAudioStream, Arc<AtomicBool>) {
+        let dropped = Arc::new(AtomicBool::new(false));
+        let stream_dropped = Arc::clone(&dropped);
+        let stream = CpalAudioStream::spawn(
+            Arc::new(Mutex::new(CaptureBuffer::Linear(Vec::new()))),
+            Arc::new(AtomicBool::new(true)),
+            move || Ok((FakeStream(stream_dropped), SAMPLE_RATE_HZ)),
+        )
+        .expect("fake stream should start");
+        (stream, dropped)
+    }
+
+    #[test]
+    fn stop_releases_the_underlying_stream() {
+        let (stream, dropped) = spawn_fake_stream();
+        assert!(stream.is_active());
+        assert!(!dropped.load(Ordering::Acquire));
+
+        stream.stop();
+
+        assert!(!stream.is_active());
+        assert!(dropped.load(Ordering::Acquire));
+        // Stopping twice is harmless
+        stream.stop();
+    }
+
+    #[test]
+    fn dropping_the_handle_releases_the_underlying_stream() {
+        let (stream, dropped) = spawn_fake_stream();
+        drop(stream);
+        assert!(dropped.load(Ordering::Acquire));
+    }
+
+    #[test]
+    fn samples_are_resampled_from_the_native_rate() {
+        let samples = Arc::new(Mutex::new(CaptureBuffer::Linear(vec![0.25_f32; 4_800])));
+        let stream = CpalAudioStream::spawn(
+            Arc::clone(&samples),
+            Arc::new(AtomicBool::new(true)),
+            || Ok((FakeStream(Arc::new(AtomicBool::new(false))), 48_000)),
+        )
+        .expect("fake stream should start");
+
+        // 100 ms at 48 kHz becomes 100 ms at 16 kHz
+        assert_eq!(stream.get_samples().len(), 1_600);
+    }
+
+    #[test]
+    fn samples_of_any_format_are_downmixed_to_mono() {
+        let samples = Mutex::new(CaptureBuffer::Linear(Vec::new()));
+        let is_recording = AtomicBool::new(true);
+
+        // Stereo frames: the channels are averaged
+        push_samples(&samples, &is_recording, &[0.5_f32, -0.5, 1.0, 0.0], 2, 1.0);
+        push_samples(
+            &samples,
+            &is_recording,
+            &[i16::MIN, 0, 16_384, 16_384],
+            2,
+            1.0,
+        );
+        push_samples(&samples, &is_recording, &[128_u8, 128, 0, 0], 2, 1.0);
+        // 24-bit samples padded to 32 bits
+        push_samples(&samples, &is_recording, &[i32::MAX / 2, 0], 1, 1.0);
+        push_samples(&samples, &is_recording, &[0.5_f64, 0.5, 0.5, 0.5], 4, 1.0);
+
+        let captured = samples.lock().unwrap().snapshot_from(0);
+        let expected = [0.0, 0.5, -0.5, 0.5, 0.0, -1.0, 0.5, 0.0, 0.5];
+        assert_eq!(captured.len(), expected.len());
+        for (sample, expected) in captured.iter().zip(expected) {
+            assert!((sample - expected).abs() < 1e-3, "{sample} != {expected}");
+        }
+    }
+
+    #[test]
+    fn input_gain_is_applied_after_downmixing() {
+        let samples = Mutex::new(CaptureBuffer::Linear(Vec::new()));
+        let is_recording = AtomicBool::new(true);
+
+        push_samples(&samples, &is_recording, &[0.1_f32, 0.3, -0.2, -0.2], 2, 2.0);
+
+        let captured = samples.lock().unwrap().snapshot_from(0);
+        assert!((captured[0] - 0.4).abs() < 1e-6, "{captured:?}");
+        assert!((captured[1] + 0.4).abs() < 1e-6, "{captured:?}");
+    }
+
+    #[test]
+    fn rolling_buffer_keeps_only_the_latest_window() {
+        let mut buffer = CaptureBuffer::rolling(1);
+        buffer.prepare(4);
+        buffer.extend([0.1, 0.2, 0.3, 0.4, 0.5, 0.6]);
+
+        assert_eq!(buffer.snapshot_from(0), vec![0.3, 0.4, 0.5, 0.6]);
+        assert_eq!(buffer.snapshot_from(2), vec![0.5, 0.6]);
+    }
+
+    #[test]
+    fn highest_priority_connected_device_is_selected() {
+        let device = |name: &str, is_default| AudioDevice {
+            id: name.to_string(),
+            name: name.to_string(),
+            is_default,
+        };
+        let available = [
+            device("MacBook Microphone", true),
+            device("Desk Mic", false),
+        ];
+        let preferred = ["Headset".to_string(), "Desk Mic".to_string()];
+
+        assert_eq!(
+            select_input_device(&preferred, &available).map(|d| d.name.as_str()),
+            Some("Desk Mic")
+        );
+        assert_eq!(
+            select_input_device(&preferred[..1], &available).map(|d| d.name.as_str()),
+            Some("MacBook Microphone")
+        );
+        assert_eq!(
+            select_input_device(&[], &available[1..]).map(|d| d.name.as_str()),
+            Some("Desk Mic")
+        );
+        assert!(select_input_device(&preferred, &[]).is_none());
+    }
+
+    #[test]
+    fn build_errors_are_reported_to_the_caller() {
+        let result = CpalAudioStream::spawn::<FakeStream, _>(
+            Arc::new(Mutex::new(CaptureBuffer::Linear(Vec::new()))),
+            Arc::new(AtomicBool::new(true)),
+            || Err(AudioCaptureError::MicrophoneNotAvailable),
+        );
+        assert!(matches!(
+            result,
+            Err(AudioCaptureError::MicrophoneNotAvailable)
+        ));
+    }
+}
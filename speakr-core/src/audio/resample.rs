@@ -0,0 +1,158 @@
+// ============================================================================
+//! Sample-Rate Conversion
+//!
+//! Many input devices only expose 44.1 kHz or 48 kHz. Audio is therefore
+//! captured at the device's native rate (downmixed to mono) and converted to
+//! Whisper's 16 kHz mono `i16` format here, using an FFT-based resampler from
+//! [`rubato`]. The intermediate `f32` buffers are zeroed once converted, so
+//! only the returned samples hold the audio.
+// ============================================================================
+
+use super::{AudioCaptureError, SAMPLE_RATE_HZ};
+use rubato::{FftFixedIn, Resampler};
+use zeroize::Zeroize;
+
+/// Number of input frames processed per resampler chunk.
+const CHUNK_SIZE: usize = 1024;
+
+/// Converts native-rate mono samples into 16 kHz mono `i16` samples.
+///
+/// # Arguments
+///
+/// * `samples` - Mono samples in the range `-1.0..=1.0`
+/// * `input_rate` - Sample rate of `samples` in Hz
+///
+/// # Returns
+///
+/// The converted samples. Input that is already at 16 kHz is only converted
+/// to `i16`.
+///
+/// # Errors
+///
+/// Returns `AudioCaptureError::InvalidConfiguration` if `input_rate` is zero,
+/// or `AudioCaptureError::StreamError` if resampling fails.
+pub fn resample_to_whisper_format(
+    samples: &[f32],
+    input_rate: u32,
+) -> Result<Vec<i16>, AudioCaptureError> {
+    if input_rate == 0 {
+        return Err(AudioCaptureError::InvalidConfiguration(
+            "Input sample rate must be greater than zero".to_string(),
+        ));
+    }
+
+    if input_rate == SAMPLE_RATE_HZ {
+        return Ok(samples.iter().copied().map(f32_to_i16).collect());
+    }
+
+    let mut resampled = resample(samples, input_rate, SAMPLE_RATE_HZ)
+        .map_err(|e| AudioCaptureError::StreamError(format!("Resampling failed: {e}")))?;
+
+    let converted = resampled.iter().copied().map(f32_to_i16).collect();
+    resampled.zeroize();
+    Ok(converted)
+}
+
+/// Resamples a complete mono buffer, compensating for the resampler delay.
+fn resample(
+    samples: &[f32],
+    input_rate: u32,
+    output_rate: u32,
+) -> Result<Vec<f32>, Box<dyn std::error::Error>> {
+    let mut resampler =
+        FftFixedIn::<f32>::new(input_rate as usize, output_rate as usize, CHUNK_SIZE, 2, 1)?;
+
+    let delay = resampler.output_delay();
+    let expected_len =
+        (samples.len() as u64 * u64::from(output_rate) / u64::from(input_rate)) as usize;
+    let mut output = Vec::with_capacity(expected_len + delay + CHUNK_SIZE);
+
+    let mut position = 0;
+    while samples.len() - position >= resampler.input_frames_next() {
+        let frames = resampler.input_frames_next();
+        let mut chunk = resampler.process(&[&samples[position..position + frames]], None)?;
+        output.extend_from_slice(&chunk[0]);
+        chunk.zeroize();
+        position += frames;
+    }
+
+    if position < samples.len() {
+        let mut chunk = resampler.process_partial(Some(&[&samples[position..]]), None)?;
+        output.extend_from_slice(&chunk[0]);
+        chunk.zeroize();
+    }
+
+    // Flush the samples still held back by the resampler's delay
+    while output.len() < expected_len + delay {
+        let mut chunk = resampler.process_partial::<&[f32]>(None, None)?;
+        if chunk[0].is_empty() {
+            break;
+        }
+        output.extend_from_slice(&chunk[0]);
+        chunk.zeroize();
+    }
+
+    output.drain(..delay.min(output.len()));
+    output.truncate(expected_len);
+    Ok(output)
+}
+
+/// Converts a sample in `-1.0..=1.0` to `i16`, clamping out-of-range values.
+fn f32_to_i16(sample: f32) -> i16 {
+    (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sine(frequency: f32, rate: u32, seconds: f32) -> Vec<f32> {
+        let count = (rate as f32 * seconds) as usize;
+        (0..count)
+            .map(|i| 0.5 * (2.0 * std::f32::consts::PI * frequency * i as f32 / rate as f32).sin())
+            .collect()
+    }
+
+    fn zero_crossings(samples: &[i16]) -> usize {
+        samples
+            .windows(2)
+            .filter(|pair| (pair[0] < 0) != (pair[1] < 0))
+            .count()
+    }
+
+    #[test]
+    fn passes_through_16khz_audio() {
+        let input = sine(440.0, SAMPLE_RATE_HZ, 0.1);
+        let output = resample_to_whisper_format(&input, SAMPLE_RATE_HZ).unwrap();
+        assert_eq!(output.len(), input.len());
+    }
+
+    #[test]
+    fn resamples_common_device_rates_to_16khz() {
+        for rate in [44_100, 48_000] {
+            let output = resample_to_whisper_format(&sine(440.0, rate, 1.0), rate).unwrap();
+
+            assert_eq!(output.len(), SAMPLE_RATE_HZ as usize, "rate {rate}");
+            // A 440 Hz tone crosses zero ~880 times per second
+            let crossings = zero_crossings(&output);
+            assert!((860..=900).contains(&crossings), "rate {rate}: {crossings}");
+            // Amplitude is preserved (0.5 full scale)
+            let peak = output.iter().map(|s| s.unsigned_abs()).max().unwrap();
+            assert!((15_000..=17_500).contains(&peak), "rate {rate}: {peak}");
+        }
+    }
+
+    #[test]
+    fn handles_buffers_shorter_than_one_chunk() {
+        let output = resample_to_whisper_format(&sine(440.0, 48_000, 0.01), 48_000).unwrap();
+        assert_eq!(output.len(), 160);
+    }
+
+    #[test]
+    fn rejects_zero_sample_rate() {
+        assert!(matches!(
+            resample_to_whisper_format(&[0.0; 16], 0),
+            Err(AudioCaptureError::InvalidConfiguration(_))
+        ));
+    }
+}
@@ -0,0 +1,149 @@
+// ============================================================================
+//! Fixed-Capacity Ring Buffer
+//!
+//! Backs the optional pre-record ("always listening") mode: the newest samples
+//! overwrite the oldest, so memory use stays fixed however long the app has
+//! been listening. The buffer only ever lives in memory: it is not
+//! serialisable, its `Debug` output never includes samples, and its storage
+//! is zeroed when cleared or dropped.
+// ============================================================================
+
+use std::sync::atomic::{compiler_fence, Ordering};
+
+/// A fixed-capacity buffer that keeps the most recent items.
+pub struct RingBuffer<T: Copy + Default> {
+    data: Vec<T>,
+    start: usize,
+    len: usize,
+}
+
+impl<T: Copy + Default> RingBuffer<T> {
+    /// Creates an empty buffer holding at most `capacity` items.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            data: vec![T::default(); capacity],
+            start: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the maximum number of items the buffer holds.
+    pub fn capacity(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the number of items currently stored.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the buffer holds no items.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends an item, overwriting the oldest one when full.
+    pub fn push(&mut self, item: T) {
+        let capacity = self.capacity();
+        if capacity == 0 {
+            return;
+        }
+
+        let end = (self.start + self.len) % capacity;
+        self.data[end] = item;
+        if self.len < capacity {
+            self.len += 1;
+        } else {
+            self.start = (self.start + 1) % capacity;
+        }
+    }
+
+    /// Appends every item in `items`, oldest first.
+    pub fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        for item in items {
+            self.push(item);
+        }
+    }
+
+    /// Returns the stored items in the order they were pushed.
+    pub fn to_vec(&self) -> Vec<T> {
+        let capacity = self.capacity();
+        (0..self.len)
+            .map(|offset| self.data[(self.start + offset) % capacity])
+            .collect()
+    }
+
+    /// Removes all items and zeroes the underlying storage.
+    pub fn clear(&mut self) {
+        self.data.fill(T::default());
+        // Keep the zeroing from being optimised away before the memory is reused
+        compiler_fence(Ordering::SeqCst);
+        self.start = 0;
+        self.len = 0;
+    }
+}
+
+impl<T: Copy + Default> Drop for RingBuffer<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Copy + Default> std::fmt::Debug for RingBuffer<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print buffered audio
+        f.debug_struct("RingBuffer")
+            .field("len", &self.len)
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_items_in_push_order_until_full() {
+        let mut buffer = RingBuffer::with_capacity(4);
+        buffer.extend([1, 2, 3]);
+
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.to_vec(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn overwrites_oldest_items_when_full() {
+        let mut buffer = RingBuffer::with_capacity(3);
+        buffer.extend(1..=7);
+
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.to_vec(), vec![5, 6, 7]);
+    }
+
+    #[test]
+    fn clear_empties_the_buffer() {
+        let mut buffer = RingBuffer::with_capacity(2);
+        buffer.extend([1.0_f32, 2.0, 3.0]);
+        buffer.clear();
+
+        assert!(buffer.is_empty());
+        assert!(buffer.to_vec().is_empty());
+        buffer.push(4.0);
+        assert_eq!(buffer.to_vec(), vec![4.0]);
+    }
+
+    #[test]
+    fn zero_capacity_buffer_ignores_pushes() {
+        let mut buffer = RingBuffer::<i16>::with_capacity(0);
+        buffer.push(1);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn debug_output_omits_samples() {
+        let mut buffer = RingBuffer::with_capacity(2);
+        buffer.push(12345);
+        assert!(!format!("{buffer:?}").contains("12345"));
+    }
+}
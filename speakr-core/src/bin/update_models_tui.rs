@@ -10,6 +10,10 @@
 //! on colours, Unicode box-drawing characters and minimal emoji usage so that
 //! the output stays readable on monochrome terminals and in CI logs.
 //!
+//! With `--cached` the repository is not cloned: the clone an earlier run left
+//! in `--workspace-dir` is reused, provided it is still at the commit that
+//! run recorded, and the UI labels the data with the clone's age.
+//!
 //! Architecture overview:
 //! - **AppState** – central state container (simple struct, no global mutables!)
 //! - **Step** – enum representing the finite-state-machine of the workflow
@@ -17,9 +21,12 @@
 // ============================================================================
 use std::fs;
 use std::path::PathBuf;
+use std::time::Duration;
 
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{bail, Result};
 
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::{format_ident, quote};
 use ratatui::crossterm::{
     self, cursor,
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
@@ -34,6 +41,7 @@ use ratatui::{
     Terminal,
 };
 use size::Size;
+use syn::LitFloat;
 use tempfile::TempDir;
 use tracing::{debug, error, info, instrument, warn};
 
@@ -61,6 +69,8 @@ pub struct AppState {
     /// Detailed messages that provide additional context in the TUI
     /// These replace stdout prints and are shown in the appropriate UI sections
     pub detailed_messages: Vec<String>,
+    /// Where the model data comes from and how old it is, once known
+    pub data_label: Option<String>,
 }
 
 /// Represents the different processing steps in the model update workflow
@@ -103,6 +113,7 @@ impl Default for AppState {
             error_message: None,
             should_quit: false,
             detailed_messages: Vec::new(),
+            data_label: None,
         }
     }
 }
@@ -326,13 +337,20 @@ impl TuiApp {
                 )
                 .split(size);
 
-            // Title
-            let title = Paragraph::new("Speakr Model Updater")
-                .style(
-                    Style::default()
-                        .fg(Color::Cyan)
-                        .add_modifier(Modifier::BOLD),
-                )
+            // Title, with the age of the data once known
+            let mut title_spans = vec![Span::styled(
+                "Speakr Model Updater",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )];
+            if let Some(label) = &state.data_label {
+                title_spans.push(Span::styled(
+                    format!("  ·  {label}"),
+                    Style::default().fg(Color::Yellow),
+                ));
+            }
+            let title = Paragraph::new(Line::from(title_spans))
                 .block(Block::default().borders(Borders::ALL));
             f.render_widget(title, chunks[0]);
 
@@ -469,6 +487,9 @@ impl TuiApp {
                     "Model update completed successfully!\n\nProcessed {} models\nGenerated code ready for integration",
                     state.models_processed.len()
                 );
+                if let Some(label) = &state.data_label {
+                    success_text.push_str(&format!("\nData: {label}"));
+                }
 
                 // Add exit instructions
                 if state.should_show_exit_instructions() {
@@ -556,6 +577,7 @@ impl TuiApp {
     /// * `repo_name` - Name of the Git repository to clone (e.g., "ggerganov/whisper.cpp")
     /// * `workspace_dir` - Optional existing workspace directory to use
     /// * `output_file` - Path where the generated Rust code should be written
+    /// * `cached` - Reuse the clone in `workspace_dir` instead of cloning
     ///
     /// # Returns
     ///
@@ -575,6 +597,7 @@ impl TuiApp {
         repo_name: &str,
         workspace_dir: Option<PathBuf>,
         output_file: &PathBuf,
+        cached: bool,
     ) -> Result<()> {
         info!("Starting TUI application main loop");
 
@@ -584,17 +607,21 @@ impl TuiApp {
         // Step 1: Initialize
         self.state.current_step = Step::CloningRepository;
         self.state.progress = 0.1;
-        self.state.status_message = format!("Cloning repository {repo_name}");
+        self.state.status_message = if cached {
+            format!("Loading cached snapshot of {repo_name}")
+        } else {
+            format!("Cloning repository {repo_name}")
+        };
         self.render()?;
 
         // Clone repository
         let result = if let Some(workspace_path) = workspace_dir {
             let updater = ModelListUpdater::with_repo(workspace_path.clone(), repo_name);
-            self.process_models(&updater, output_file).await
+            self.process_models(&updater, output_file, cached).await
         } else {
             let temp_dir = TempDir::new()?;
             let updater = ModelListUpdater::with_repo(temp_dir.path().to_path_buf(), repo_name);
-            self.process_models(&updater, output_file).await
+            self.process_models(&updater, output_file, cached).await
         };
 
         match result {
@@ -628,7 +655,8 @@ impl TuiApp {
     /// Processes model metadata through the complete workflow
     ///
     /// This private method handles the core model processing logic:
-    /// 1. Clones the Git repository (without LFS files)
+    /// 1. Clones the Git repository (without LFS files), or reuses the
+    ///    cached clone
     /// 2. Extracts metadata from LFS pointer files
     /// 3. Generates Rust code from the metadata
     /// 4. Writes the generated code to the output file
@@ -640,6 +668,7 @@ impl TuiApp {
     ///
     /// * `updater` - The ModelListUpdater instance configured for this repository
     /// * `output_file` - Path where the generated code should be written
+    /// * `cached` - Reuse the recorded clone instead of cloning
     ///
     /// # Returns
     ///
@@ -656,23 +685,44 @@ impl TuiApp {
         &mut self,
         updater: &ModelListUpdater,
         output_file: &PathBuf,
+        cached: bool,
     ) -> Result<usize> {
-        // Add detailed messages for repository cloning
-        self.state
-            .add_detailed_message("🌐 Preparing to clone repository...".to_string());
-        self.state
-            .add_detailed_message(format!("🔗 Repository URL: {}", updater.repo_url()));
-        self.render()?;
-
-        // Clone repository
-        self.state
-            .add_detailed_message("⬇️ Downloading repository files (without LFS)...".to_string());
-        self.render()?;
-
-        updater.clone_repository().await?;
-
-        self.state
-            .add_detailed_message("✅ Repository cloned successfully".to_string());
+        if cached {
+            self.state
+                .add_detailed_message("📦 Checking the cached repository (offline)...".to_string());
+            self.render()?;
+
+            let snapshot = updater.cached_snapshot().await?;
+            let age = format_age(snapshot.age());
+            self.state.data_label = Some(format!(
+                "Cached snapshot {} from {age}",
+                snapshot.short_commit()
+            ));
+            self.state.add_detailed_message(format!(
+                "✅ Reusing the clone at {}, fetched {age}",
+                snapshot.short_commit()
+            ));
+        } else {
+            // Add detailed messages for repository cloning
+            self.state
+                .add_detailed_message("🌐 Preparing to clone repository...".to_string());
+            self.state
+                .add_detailed_message(format!("🔗 Repository URL: {}", updater.repo_url()));
+            self.render()?;
+
+            // Clone repository
+            self.state.add_detailed_message(
+                "⬇️ Downloading repository files (without LFS)...".to_string(),
+            );
+            self.render()?;
+
+            updater.clone_repository().await?;
+            let snapshot = updater.cached_snapshot().await?;
+            self.state.data_label = Some(format!("Live clone at {}", snapshot.short_commit()));
+
+            self.state
+                .add_detailed_message("✅ Repository cloned successfully".to_string());
+        }
 
         self.state.current_step = Step::ExtractingMetadata;
         self.state.progress = 0.4;
@@ -755,10 +805,14 @@ impl Drop for TuiApp {
 ///
 /// This function creates a complete Rust module containing an enum of all
 /// available models and their associated metadata. The generated code includes:
-/// - Comprehensive header documentation with a table of all models
+/// - Comprehensive module documentation with a table of all models
 /// - A Model enum with variants for each model
 /// - Implementation methods for filename, filesize, SHA hash, and download URL
 ///
+/// The code is built as a syntax tree with `quote` and parsed with `syn`, so
+/// malformed output is caught here rather than when `list.rs` is compiled,
+/// and is then formatted with `prettyplease` so the layout never drifts.
+///
 /// # Arguments
 ///
 /// * `metadata` - Slice of ModelMetadata structs containing information about each model
@@ -767,6 +821,11 @@ impl Drop for TuiApp {
 ///
 /// A `String` containing the complete generated Rust code
 ///
+/// # Panics
+///
+/// Panics if the generated tokens do not parse as a Rust file, which would
+/// be a bug in the generator.
+///
 /// # Examples
 ///
 /// ```rust
@@ -775,29 +834,56 @@ impl Drop for TuiApp {
 /// std::fs::write("models.rs", code).expect("Failed to write code");
 /// ```
 fn generate_models_code(metadata: &[ModelMetadata]) -> String {
-    let mut code = String::new();
+    let docs = generate_header_docs(metadata);
+    let model_enum = generate_model_enum(metadata);
+    let impl_block = generate_impl_block(metadata);
 
-    // Add the header documentation with model table
-    code.push_str(&generate_header_docs(metadata));
-    code.push_str("\n\n");
+    let tokens = quote! {
+        #(#![doc = #docs])*
 
-    // Add imports
-    code.push_str("use size::Size;\n\n");
+        use size::Size;
 
-    // Add the enum definition
-    code.push_str(&generate_model_enum(metadata));
-    code.push_str("\n\n");
+        #model_enum
 
-    // Add implementation block
-    code.push_str(&generate_impl_block(metadata));
-    code.push_str("\n\n");
+        #impl_block
+    };
 
-    code
+    let file: syn::File = syn::parse2(tokens).expect("generated model list should be valid Rust");
+    prettyplease::unparse(&file)
+}
+
+/// Extracts the `owner/repo` part of a HuggingFace download URL
+///
+/// Falls back to `ggerganov/whisper.cpp` if the URL is not a HuggingFace
+/// `resolve` link.
+fn repo_name_from_url(download_url: &str) -> String {
+    download_url
+        .find("huggingface.co/")
+        .map(|start| &download_url[start + 15..])
+        .and_then(|after_hf| {
+            after_hf
+                .find("/resolve/")
+                .map(|resolve_pos| after_hf[..resolve_pos].to_string())
+        })
+        .unwrap_or_else(|| "ggerganov/whisper.cpp".to_string())
+}
+
+/// Formats a model size for the documentation table
+fn format_model_size(size_bytes: u64) -> String {
+    let size = Size::from_bytes(size_bytes);
+    if size.bytes() >= 1_000_000_000 {
+        format!(
+            "{:.1} GiB",
+            (size.bytes() as f64) / (1024.0 * 1024.0 * 1024.0)
+        )
+    } else {
+        format!("{} MiB", size.bytes() / (1024 * 1024))
+    }
 }
 
 /// Generates header documentation with a table of all available models
 ///
-/// Creates comprehensive rustdoc documentation that includes:
+/// Creates the module documentation, which includes:
 /// - Description of what the models are (OpenAI Whisper models in ggml format)
 /// - Link to the repository containing the models
 /// - Formatted table showing model names, file sizes, and SHA hashes
@@ -811,63 +897,41 @@ fn generate_models_code(metadata: &[ModelMetadata]) -> String {
 ///
 /// # Returns
 ///
-/// A `String` containing the formatted header documentation
-fn generate_header_docs(metadata: &[ModelMetadata]) -> String {
-    let mut docs = String::new();
-    docs.push_str(
-        "/// OpenAI's Whisper models converted to ggml format for use with whisper.cpp\n",
-    );
-    docs.push_str("///\n");
-
-    let repo_link = if let Some(first_meta) = metadata.first() {
-        if let Some(repo_start) = first_meta.download_url.find("huggingface.co/") {
-            let after_hf = &first_meta.download_url[repo_start + 15..];
-            if let Some(resolve_pos) = after_hf.find("/resolve/") {
-                let repo_name = &after_hf[..resolve_pos];
-                format!("https://huggingface.co/{repo_name}/tree/main")
-            } else {
-                "https://huggingface.co/ggerganov/whisper.cpp/tree/main".to_string()
-            }
-        } else {
-            "https://huggingface.co/ggerganov/whisper.cpp/tree/main".to_string()
-        }
-    } else {
-        "https://huggingface.co/ggerganov/whisper.cpp/tree/main".to_string()
-    };
-
-    docs.push_str(&format!("/// [Available models]({repo_link})\n"));
-    docs.push_str("///\n");
-    docs.push_str(
-        "/// | Model               | Disk    | SHA                                        |\n",
-    );
-    docs.push_str(
-        "/// | ------------------- | ------- | ------------------------------------------ |\n",
-    );
+/// One string per documentation line, each starting with the space that
+/// follows `//!`
+fn generate_header_docs(metadata: &[ModelMetadata]) -> Vec<String> {
+    let repo_name = metadata
+        .first()
+        .map(|meta| repo_name_from_url(&meta.download_url))
+        .unwrap_or_else(|| "ggerganov/whisper.cpp".to_string());
+
+    let mut docs = vec![
+        " OpenAI's Whisper models converted to ggml format for use with whisper.cpp".to_string(),
+        String::new(),
+        format!(" [Available models](https://huggingface.co/{repo_name}/tree/main)"),
+        String::new(),
+        " | Model               | Disk    | SHA                                        |"
+            .to_string(),
+        " | ------------------- | ------- | ------------------------------------------ |"
+            .to_string(),
+    ];
 
     for meta in metadata {
         let model_name = meta
             .filename
             .trim_start_matches("ggml-")
             .trim_end_matches(".bin");
-        let size = Size::from_bytes(meta.size_bytes);
-        let formatted_size = if size.bytes() >= 1_000_000_000 {
-            format!(
-                "{:.1} GiB",
-                (size.bytes() as f64) / (1024.0 * 1024.0 * 1024.0)
-            )
-        } else {
-            format!("{} MiB", size.bytes() / (1024 * 1024))
-        };
-
-        docs.push_str(&format!(
-            "/// | {:<19} | {:<7} | `{}` |\n",
-            model_name, formatted_size, meta.sha256
+        docs.push(format!(
+            " | {:<19} | {:<7} | `{}` |",
+            model_name,
+            format_model_size(meta.size_bytes),
+            meta.sha256
         ));
     }
 
-    docs.push_str("///\n");
-    docs.push_str(&format!(
-        "/// Example: {}\n",
+    docs.push(String::new());
+    docs.push(format!(
+        " Example: {}",
         metadata
             .first()
             .map(|m| m.download_url.as_str())
@@ -893,20 +957,22 @@ fn generate_header_docs(metadata: &[ModelMetadata]) -> String {
 ///
 /// # Returns
 ///
-/// A `String` containing the complete enum definition
-fn generate_model_enum(metadata: &[ModelMetadata]) -> String {
-    let mut enum_code = String::new();
-    enum_code.push_str("#[allow(dead_code)]\n");
-    enum_code.push_str("#[derive(Debug)]\n");
-    enum_code.push_str("pub enum Model {\n");
-
-    for meta in metadata {
-        let variant_name = filename_to_variant_name(&meta.filename);
-        enum_code.push_str(&format!("    {variant_name},\n"));
+/// The tokens of the complete enum definition
+fn generate_model_enum(metadata: &[ModelMetadata]) -> TokenStream {
+    let variants = metadata.iter().map(variant_ident);
+
+    quote! {
+        #[allow(dead_code)]
+        #[derive(Debug)]
+        pub enum Model {
+            #(#variants,)*
+        }
     }
+}
 
-    enum_code.push_str("}\n");
-    enum_code
+/// The enum variant identifier for a model
+fn variant_ident(meta: &ModelMetadata) -> Ident {
+    format_ident!("{}", filename_to_variant_name(&meta.filename))
 }
 
 /// Generates the implementation block for the Model enum
@@ -927,90 +993,62 @@ fn generate_model_enum(metadata: &[ModelMetadata]) -> String {
 ///
 /// # Returns
 ///
-/// A `String` containing the complete implementation block
-fn generate_impl_block(metadata: &[ModelMetadata]) -> String {
-    let mut impl_code = String::new();
-    impl_code.push_str("impl Model {\n");
-
-    // Generate filename method
-    impl_code.push_str("    pub fn filename(&self) -> &'static str {\n");
-    impl_code.push_str("        match self {\n");
-    for meta in metadata {
-        let variant_name = filename_to_variant_name(&meta.filename);
-        let base_name = meta
-            .filename
+/// The tokens of the complete implementation block
+fn generate_impl_block(metadata: &[ModelMetadata]) -> TokenStream {
+    let variants: Vec<Ident> = metadata.iter().map(variant_ident).collect();
+
+    let base_names = metadata.iter().map(|meta| {
+        meta.filename
             .trim_start_matches("ggml-")
-            .trim_end_matches(".bin");
-        impl_code.push_str(&format!(
-            "            Model::{variant_name} => \"{base_name}\",\n"
-        ));
-    }
-    impl_code.push_str("        }\n");
-    impl_code.push_str("    }\n\n");
+            .trim_end_matches(".bin")
+            .to_string()
+    });
 
-    // Generate filesize method
-    impl_code.push_str("    pub fn filesize(&self) -> Size {\n");
-    impl_code.push_str("        match self {\n");
-    for meta in metadata {
-        let variant_name = filename_to_variant_name(&meta.filename);
+    let sizes = metadata.iter().map(|meta| {
         let size = Size::from_bytes(meta.size_bytes);
-        let size_expr = if size.bytes() >= 1_000_000_000 {
-            format!(
-                "Size::from_gib({:.1})",
-                (size.bytes() as f64) / (1024.0 * 1024.0 * 1024.0)
-            )
+        if size.bytes() >= 1_000_000_000 {
+            let gib = (size.bytes() as f64) / (1024.0 * 1024.0 * 1024.0);
+            let gib = LitFloat::new(&format!("{gib:.1}"), proc_macro2::Span::call_site());
+            quote!(Size::from_gib(#gib))
         } else {
-            format!("Size::from_mib({})", size.bytes() / (1024 * 1024))
-        };
-        impl_code.push_str(&format!(
-            "            Model::{variant_name} => {size_expr},\n"
-        ));
-    }
-    impl_code.push_str("        }\n");
-    impl_code.push_str("    }\n\n");
+            let mib = Literal::i64_unsuffixed(size.bytes() / (1024 * 1024));
+            quote!(Size::from_mib(#mib))
+        }
+    });
 
-    // Generate sha method
-    impl_code.push_str("    pub fn sha(&self) -> &'static str {\n");
-    impl_code.push_str("        match self {\n");
-    for meta in metadata {
-        let variant_name = filename_to_variant_name(&meta.filename);
-        impl_code.push_str(&format!(
-            "            Model::{} => \"{}\",\n",
-            variant_name, meta.sha256
-        ));
-    }
-    impl_code.push_str("        }\n");
-    impl_code.push_str("    }\n\n");
-
-    // Generate url method
-    impl_code.push_str("    pub fn url(&self) -> String {\n");
-    impl_code.push_str("        format!(\n");
-
-    let (repo_name, git_ref) = if let Some(first_meta) = metadata.first() {
-        let repo = if let Some(repo_start) = first_meta.download_url.find("huggingface.co/") {
-            let after_hf = &first_meta.download_url[repo_start + 15..];
-            if let Some(resolve_pos) = after_hf.find("/resolve/") {
-                after_hf[..resolve_pos].to_string()
-            } else {
-                "ggerganov/whisper.cpp".to_string()
+    let shas = metadata.iter().map(|meta| meta.sha256.as_str());
+
+    let (repo_name, git_ref) = metadata.first().map_or_else(
+        || ("ggerganov/whisper.cpp".to_string(), "main".to_string()),
+        |meta| (repo_name_from_url(&meta.download_url), meta.git_ref.clone()),
+    );
+    let url_format = format!("https://huggingface.co/{repo_name}/resolve/{git_ref}/ggml-{{}}.bin");
+
+    quote! {
+        impl Model {
+            pub fn filename(&self) -> &'static str {
+                match self {
+                    #(Model::#variants => #base_names,)*
+                }
             }
-        } else {
-            "ggerganov/whisper.cpp".to_string()
-        };
-        (repo, first_meta.git_ref.clone())
-    } else {
-        ("ggerganov/whisper.cpp".to_string(), "main".to_string())
-    };
 
-    impl_code.push_str(&format!(
-        "            \"https://huggingface.co/{repo_name}/resolve/{git_ref}/ggml-{{}}.bin\",\n"
-    ));
-    impl_code.push_str("            self.filename()\n");
-    impl_code.push_str("        )\n");
-    impl_code.push_str("    }\n");
+            pub fn filesize(&self) -> Size {
+                match self {
+                    #(Model::#variants => #sizes,)*
+                }
+            }
 
-    impl_code.push_str("}\n");
-    impl_code
+            pub fn sha(&self) -> &'static str {
+                match self {
+                    #(Model::#variants => #shas,)*
+                }
+            }
+
+            pub fn url(&self) -> String {
+                format!(#url_format, self.filename())
+            }
+        }
+    }
 }
 
 /// Initialize logging system following the pattern from ratatui documentation
@@ -1109,6 +1147,38 @@ fn get_arg_value(args: &[String], flag: &str) -> Option<String> {
         .cloned()
 }
 
+/// Returns true if a flag without a value, such as `--cached`, was passed
+///
+/// # Arguments
+///
+/// * `args` - Slice of command-line argument strings
+/// * `flag` - The flag to look for
+fn has_flag(args: &[String], flag: &str) -> bool {
+    args.iter().any(|arg| arg == flag)
+}
+
+/// Describes how long ago cached data was fetched, e.g. "3 days ago"
+///
+/// # Arguments
+///
+/// * `age` - Time since the data was fetched
+///
+/// # Returns
+///
+/// The age in its largest whole unit, or "just now" under a minute
+fn format_age(age: Duration) -> String {
+    const UNITS: [(u64, &str); 3] = [(86_400, "day"), (3_600, "hour"), (60, "minute")];
+    let secs = age.as_secs();
+    match UNITS.iter().find(|(unit, _)| secs >= *unit) {
+        Some(&(unit, name)) => {
+            let count = secs / unit;
+            let plural = if count == 1 { "" } else { "s" };
+            format!("{count} {name}{plural} ago")
+        }
+        None => "just now".to_string(),
+    }
+}
+
 // ================================================
 //
 // ==========            MAIN            ==========
@@ -1128,6 +1198,8 @@ fn get_arg_value(args: &[String], flag: &str) -> Option<String> {
 /// - `--repo <name>`: Git repository name (default: "ggerganov/whisper.cpp")
 /// - `--workspace-dir <path>`: Existing workspace directory to use
 /// - `--output <path>`: Output file path (default: "updated_list.rs")
+/// - `--cached`: Reuse the clone left in `--workspace-dir` by an earlier run
+///   instead of cloning, e.g. on a flaky network
 ///
 /// # Returns
 ///
@@ -1161,10 +1233,17 @@ async fn main() -> Result<()> {
         .map(PathBuf::from)
         .unwrap_or_else(|| PathBuf::from("updated_list.rs"));
 
+    // A cache only survives in a workspace the user keeps
+    let cached = has_flag(&args, "--cached");
+    if cached && workspace_dir.is_none() {
+        bail!("--cached needs --workspace-dir pointing at a workspace cloned by an earlier run");
+    }
+
     info!(
-        "Configuration: repo={}, output={}",
+        "Configuration: repo={}, output={}, cached={}",
         repo_name,
-        output_file.display()
+        output_file.display(),
+        cached
     );
 
     let mut app = TuiApp::new()?;
@@ -1173,7 +1252,9 @@ async fn main() -> Result<()> {
     app.enter()?;
 
     // Run the application and ensure cleanup happens
-    let result = app.run(&repo_name, workspace_dir, &output_file).await;
+    let result = app
+        .run(&repo_name, workspace_dir, &output_file, cached)
+        .await;
 
     // Exit terminal mode
     app.exit()?;
@@ -1205,6 +1286,7 @@ mod tests {
         assert!(state.models_processed.is_empty());
         assert!(state.error_message.is_none());
         assert!(!state.should_quit);
+        assert!(state.data_label.is_none());
     }
 
     #[test]
@@ -1243,6 +1325,35 @@ mod tests {
         assert!(code.contains("pub fn url(&self)"));
     }
 
+    /// Metadata covering MiB and GiB sizes and a quantized variant
+    fn golden_metadata() -> Vec<ModelMetadata> {
+        [
+            ("ggml-base.bin", 147_951_465, "465707469ff3a37a2b9b8d8f89f2f99de7299dac"),
+            ("ggml-small.en-q5_1.bin", 190_085_487, "20f54878d608f94e4a8ee3ae56016571d47cba34"),
+            ("ggml-medium.bin", 1_533_763_059, "fd9727b6e1217c2f614f9b698455c4ffd82463b4"),
+        ]
+        .into_iter()
+        .map(|(filename, size_bytes, sha)| ModelMetadata {
+            filename: filename.to_string(),
+            size_bytes,
+            sha256: sha.to_string(),
+            git_ref: "f281eb45af861ab5e5297d23694b7d46e090c02c".to_string(),
+            download_url: format!(
+                "https://huggingface.co/ggerganov/whisper.cpp/resolve/f281eb45af861ab5e5297d23694b7d46e090c02c/{filename}"
+            ),
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_generate_models_code_matches_golden_file() {
+        // The golden file is also compiled by tests/generated_model_list.rs
+        let code = generate_models_code(&golden_metadata());
+
+        syn::parse_file(&code).expect("Generated code should parse");
+        assert_eq!(code, include_str!("../../tests/fixtures/generated_list.rs"));
+    }
+
     #[test]
     fn test_get_arg_value_parses_correctly() {
         // RED: Test argument parsing
@@ -1265,6 +1376,30 @@ mod tests {
         assert_eq!(get_arg_value(&args, "--missing"), None);
     }
 
+    #[test]
+    fn test_has_flag_detects_cached_mode() {
+        let args = vec![
+            "program".to_string(),
+            "--workspace-dir".to_string(),
+            "/tmp/cache".to_string(),
+            "--cached".to_string(),
+        ];
+
+        assert!(has_flag(&args, "--cached"));
+        assert!(!has_flag(&args, "--repo"));
+    }
+
+    #[test]
+    fn test_format_age_uses_the_largest_unit() {
+        assert_eq!(format_age(Duration::from_secs(20)), "just now");
+        assert_eq!(format_age(Duration::from_secs(60)), "1 minute ago");
+        assert_eq!(
+            format_age(Duration::from_secs(3 * 3_600 + 59)),
+            "3 hours ago"
+        );
+        assert_eq!(format_age(Duration::from_secs(2 * 86_400)), "2 days ago");
+    }
+
     // RED: Tests for TUI output capture and exit handling issues
     #[test]
     fn test_app_state_should_capture_detailed_messages() {
@@ -1296,6 +1431,7 @@ mod tests {
             error_message: None,
             should_quit: false,
             detailed_messages: Vec::new(),
+            data_label: None,
         };
 
         // We should have a way to determine if exit instructions should be shown
@@ -1339,6 +1475,7 @@ mod tests {
             error_message: None,
             should_quit: false,
             detailed_messages: Vec::new(),
+            data_label: None,
         };
 
         // The actual rendering test would require mocking the terminal
@@ -1366,6 +1503,7 @@ mod tests {
             error_message: None,
             should_quit: false,
             detailed_messages: Vec::new(),
+            data_label: None,
         };
         assert!(state.should_show_exit_instructions());
     }
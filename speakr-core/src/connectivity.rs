@@ -0,0 +1,105 @@
+// =============================================================================
+//! Network connectivity detection
+//!
+//! Model downloads need the network. Rather than letting a request hang and
+//! surfacing a raw transport error, callers check connectivity up front with
+//! a [`ConnectivityWatcher`], show an offline state, and wait for the network
+//! to come back before retrying.
+//!
+//! Connectivity is judged by opening a TCP connection to the model host;
+//! nothing is sent over it.
+// =============================================================================
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::sync::watch;
+
+/// Host probed to decide whether downloads can reach the model server.
+pub const DEFAULT_PROBE_ADDR: &str = "huggingface.co:443";
+
+/// How long a single probe may take before the network counts as down.
+pub const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Time between probes while waiting for the network.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Tries to open a TCP connection to `addr`.
+///
+/// # Returns
+///
+/// `true` if the connection succeeded within [`PROBE_TIMEOUT`].
+pub async fn probe(addr: &str) -> bool {
+    matches!(
+        tokio::time::timeout(PROBE_TIMEOUT, TcpStream::connect(addr)).await,
+        Ok(Ok(_))
+    )
+}
+
+/// Tracks whether the model host is reachable.
+///
+/// Clones share the same state, so subscribers see the result of every
+/// probe made through any clone. Probes only happen when asked for (no
+/// background polling); the network is assumed to be up until a probe says
+/// otherwise.
+#[derive(Debug, Clone)]
+pub struct ConnectivityWatcher {
+    probe_addr: String,
+    poll_interval: Duration,
+    online: Arc<watch::Sender<bool>>,
+}
+
+impl ConnectivityWatcher {
+    /// Create a watcher probing [`DEFAULT_PROBE_ADDR`].
+    pub fn new() -> Self {
+        Self::with_probe(DEFAULT_PROBE_ADDR, DEFAULT_POLL_INTERVAL)
+    }
+
+    /// Create a watcher probing a custom address (useful for tests).
+    pub fn with_probe(probe_addr: impl Into<String>, poll_interval: Duration) -> Self {
+        let (online, _) = watch::channel(true);
+        Self {
+            probe_addr: probe_addr.into(),
+            poll_interval,
+            online: Arc::new(online),
+        }
+    }
+
+    /// The last known connectivity state.
+    pub fn is_online(&self) -> bool {
+        *self.online.borrow()
+    }
+
+    /// Subscribe to connectivity changes.
+    pub fn subscribe(&self) -> watch::Receiver<bool> {
+        self.online.subscribe()
+    }
+
+    /// Probe now and record the result.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the model host is reachable.
+    pub async fn check(&self) -> bool {
+        let online = probe(&self.probe_addr).await;
+        self.online.send_if_modified(|state| {
+            let changed = *state != online;
+            *state = online;
+            changed
+        });
+        online
+    }
+
+    /// Resolve once the model host is reachable, probing every poll interval.
+    pub async fn wait_until_online(&self) {
+        while !self.check().await {
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+}
+
+impl Default for ConnectivityWatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,241 @@
+// =============================================================================
+//! Output formatting
+//!
+//! The last step before a transcript is injected or copied. Applies the
+//! user's [`NewlineOptions`]: the spoken "new paragraph" command is turned
+//! into a break (or kept as text), and paragraph breaks in the transcript
+//! are rendered as a single newline, a blank line, or a space.
+//! [`join_dictation`] joins a dictation onto the one before it when both
+//! belong to the same append-mode session.
+//!
+//! # Usage
+//!
+//! ```no_run
+//! use speakr_core::formatting::format_paragraphs;
+//! use speakr_types::{NewlineOptions, ParagraphBreak};
+//!
+//! let options = NewlineOptions {
+//!     paragraph_break: ParagraphBreak::SingleNewline,
+//!     ..NewlineOptions::default()
+//! };
+//!
+//! assert_eq!(
+//!     format_paragraphs("Dear Sam, new paragraph, thanks.", &options),
+//!     "Dear Sam\nthanks."
+//! );
+//! ```
+// =============================================================================
+
+use regex::Regex;
+use speakr_types::{NewParagraphCommand, NewlineOptions};
+use std::sync::LazyLock;
+
+/// The spoken "new paragraph" command with the separators around it.
+///
+/// Commas and spaces before the command are dropped, as is any punctuation
+/// Whisper attached after it; a full stop ending the previous sentence is
+/// kept.
+static NEW_PARAGRAPH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)[ \t,;:]*\bnew paragraph\b[.,;:!?]*[ \t]*").expect("valid regex")
+});
+
+/// A whitespace run containing at least one blank line.
+static PARAGRAPH_BREAK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"[ \t]*\n[ \t]*\n\s*").expect("valid regex"));
+
+/// Applies newline handling to text about to be output.
+///
+/// # Arguments
+///
+/// * `text` - The post-processed transcript
+/// * `options` - How paragraph breaks and the "new paragraph" command are output
+///
+/// # Returns
+///
+/// The text with voice commands resolved and paragraph breaks rendered.
+pub fn format_paragraphs(text: &str, options: &NewlineOptions) -> String {
+    let commanded = match options.new_paragraph_command {
+        NewParagraphCommand::ParagraphBreak => NEW_PARAGRAPH.replace_all(text, "\n\n"),
+        NewParagraphCommand::LineBreak => NEW_PARAGRAPH.replace_all(text, "\n"),
+        NewParagraphCommand::Literal => text.into(),
+    };
+
+    PARAGRAPH_BREAK
+        .replace_all(&commanded, options.paragraph_break.separator())
+        .into_owned()
+}
+
+/// Characters that end a sentence, after which the next word is capitalised.
+const SENTENCE_ENDS: &[char] = &['.', '!', '?', '…'];
+
+/// Punctuation that attaches to the word before it, without a space.
+const CLOSING_PUNCTUATION: &[char] = &[',', '.', ';', ':', '!', '?', '…', ')', ']', '}', '%'];
+
+/// Brackets that attach to the word after them, without a space.
+const OPENING_BRACKETS: &[char] = &['(', '[', '{'];
+
+/// Joins a dictation onto the text delivered before it in the same session.
+///
+/// Whisper transcribes every dictation as if it were a new sentence, so the
+/// text is adjusted to read on from the previous one: a space is added
+/// unless the previous text ended in whitespace or an opening bracket, or
+/// the dictation starts with punctuation; and the first word is capitalised
+/// after a sentence end or line break and lower-cased otherwise. The
+/// pronoun "I" and words with further capitals, such as acronyms, keep
+/// their case; a name that starts a dictation mid-sentence does not.
+///
+/// # Arguments
+///
+/// * `previous` - The last character delivered in the session
+/// * `next` - The formatted text of the new dictation
+///
+/// # Returns
+///
+/// The text to deliver, starting with any separator it needs.
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_core::formatting::join_dictation;
+///
+/// assert_eq!(join_dictation('.', "then we ship."), " Then we ship.");
+/// assert_eq!(join_dictation(',', "And then we ship."), " and then we ship.");
+/// assert_eq!(join_dictation('d', ", then we ship."), ", then we ship.");
+/// ```
+pub fn join_dictation(previous: char, next: &str) -> String {
+    let next = next.trim_start();
+    let Some(first) = next.chars().next() else {
+        return String::new();
+    };
+    if CLOSING_PUNCTUATION.contains(&first) {
+        return next.to_string();
+    }
+
+    let sentence_start = SENTENCE_ENDS.contains(&previous) || previous == '\n';
+    let separator = if previous.is_whitespace() || OPENING_BRACKETS.contains(&previous) {
+        ""
+    } else {
+        " "
+    };
+    let word_len = next
+        .find(|c: char| !c.is_alphanumeric())
+        .unwrap_or(next.len());
+    let (word, rest) = next.split_at(word_len);
+    let word = if sentence_start {
+        capitalise(word)
+    } else if word != "I" && word.chars().skip(1).all(|c| !c.is_uppercase()) {
+        word.to_lowercase()
+    } else {
+        word.to_string()
+    };
+    format!("{separator}{word}{rest}")
+}
+
+/// Upper-cases the first character of `word`.
+fn capitalise(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use speakr_types::ParagraphBreak;
+
+    fn options(
+        paragraph_break: ParagraphBreak,
+        new_paragraph_command: NewParagraphCommand,
+    ) -> NewlineOptions {
+        NewlineOptions {
+            paragraph_break,
+            new_paragraph_command,
+        }
+    }
+
+    #[test]
+    fn renders_paragraph_breaks_with_the_configured_separator() {
+        let text = "First paragraph.\n\n\nSecond paragraph.\nSame paragraph.";
+        let render = |paragraph_break| {
+            format_paragraphs(
+                text,
+                &options(paragraph_break, NewParagraphCommand::Literal),
+            )
+        };
+
+        assert_eq!(
+            render(ParagraphBreak::DoubleNewline),
+            "First paragraph.\n\nSecond paragraph.\nSame paragraph."
+        );
+        assert_eq!(
+            render(ParagraphBreak::SingleNewline),
+            "First paragraph.\nSecond paragraph.\nSame paragraph."
+        );
+        assert_eq!(
+            render(ParagraphBreak::NoBreak),
+            "First paragraph. Second paragraph.\nSame paragraph."
+        );
+    }
+
+    #[test]
+    fn new_paragraph_command_follows_its_setting() {
+        let text = "Hello team. New paragraph. The build is green, new paragraph thanks";
+
+        assert_eq!(
+            format_paragraphs(text, &NewlineOptions::default()),
+            "Hello team.\n\nThe build is green\n\nthanks"
+        );
+        assert_eq!(
+            format_paragraphs(
+                text,
+                &options(ParagraphBreak::NoBreak, NewParagraphCommand::ParagraphBreak)
+            ),
+            "Hello team. The build is green thanks"
+        );
+        assert_eq!(
+            format_paragraphs(
+                text,
+                &options(ParagraphBreak::NoBreak, NewParagraphCommand::LineBreak)
+            ),
+            "Hello team.\nThe build is green\nthanks"
+        );
+        assert_eq!(
+            format_paragraphs(
+                text,
+                &options(ParagraphBreak::DoubleNewline, NewParagraphCommand::Literal)
+            ),
+            text
+        );
+    }
+
+    #[test]
+    fn does_not_match_inside_other_words() {
+        let text = "Renew paragraphs weekly";
+        assert_eq!(format_paragraphs(text, &NewlineOptions::default()), text);
+    }
+
+    #[test]
+    fn joins_dictations_with_sentence_aware_case() {
+        assert_eq!(
+            join_dictation('.', "the build is green."),
+            " The build is green."
+        );
+        assert_eq!(join_dictation('?', " Yes."), " Yes.");
+        assert_eq!(join_dictation('\n', "next line"), "Next line");
+        assert_eq!(join_dictation(',', "And the tests."), " and the tests.");
+        assert_eq!(join_dictation('d', "I think so."), " I think so.");
+        assert_eq!(join_dictation('e', "I'm done."), " I'm done.");
+        assert_eq!(join_dictation('e', "NASA and gRPC."), " NASA and gRPC.");
+    }
+
+    #[test]
+    fn joins_dictations_with_the_right_spacing() {
+        assert_eq!(join_dictation(' ', "And then."), "and then.");
+        assert_eq!(join_dictation('(', "See above)."), "see above).");
+        assert_eq!(join_dictation('d', ", and then."), ", and then.");
+        assert_eq!(join_dictation('d', "."), ".");
+        assert_eq!(join_dictation('.', "  "), "");
+    }
+}
@@ -5,7 +5,8 @@
 //! functionality on macOS, with support for:
 //!
 //! - **High-quality audio capture**: 16kHz mono sampling optimised for Whisper models
-//! - **Configurable recording duration**: 1-30 seconds with intelligent timeout handling
+//! - **Configurable recording duration**: 1-30 seconds with intelligent timeout handling, or
+//!   minutes in long-form mode, transcribed in chunks while recording
 //! - **In-memory buffering**: No temporary files for enhanced privacy
 //! - **Comprehensive error handling**: Robust error propagation with custom error types
 //! - **Async/await support**: Built on tokio for non-blocking operations
@@ -24,11 +25,12 @@
 //!
 //! ```no_run
 //! use speakr_core::audio::{AudioRecorder, RecordingConfig};
+//! use speakr_types::AudioDuration;
 //!
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
 //!     // Create recorder with 5-second limit
-//!     let config = RecordingConfig::new(5);
+//!     let config = RecordingConfig::new(AudioDuration::new(5)?);
 //!     let recorder = AudioRecorder::new(config).await?;
 //!
 //!     // Start recording
@@ -89,12 +91,54 @@ pub mod model;
 /// performance monitoring, and model management.
 pub mod transcription;
 
+/// Network connectivity detection for model downloads.
+///
+/// Probes the model host so downloads can show an offline state up front
+/// and resume automatically once the network returns.
+pub mod connectivity;
+
 /// Pipeline orchestration utilities – glue between audio capture and
 /// transcription processing.  Currently only exposes
 /// [`pipeline::transcription_pipeline`], which accepts raw `Vec<i16>` audio
 /// samples (16 kHz mono) and asynchronously returns a transcription result.
 pub mod pipeline;
 
+/// Text post-processing applied to transcripts before injection.
+///
+/// Runs the user's configured chain of transforms (whitespace trimming,
+/// sentence capitalisation, filler-word removal, regex replacements).
+pub mod post_processing;
+
+/// Sandboxed WebAssembly plugins that extend post-processing.
+///
+/// Defines the plugin interface and the manager that installs and loads them.
+pub mod plugins;
+
+/// Spoken commands recognised while dictating.
+///
+/// The optional stop phrase that ends a recording hands-free, and spoken
+/// punctuation and editing commands resolved in the transcript.
+pub mod voice_command;
+
+/// Final formatting of text before it is output.
+///
+/// Resolves the "new paragraph" voice command and renders paragraph breaks
+/// according to the user's newline settings.
+pub mod formatting;
+
+/// Word-level streaming of text while the user is still speaking.
+///
+/// Tracks what was typed so revised words can be corrected with backspace.
+pub mod streaming;
+
+pub mod long_form;
+
+/// Subtitle export of transcription results.
+///
+/// Renders segments as SubRip (`.srt`) or WebVTT (`.vtt`) cues for captions
+/// and batch transcription.
+pub mod subtitles;
+
 // ===========================================================================
 
 #[cfg(test)]
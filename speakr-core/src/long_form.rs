@@ -0,0 +1,266 @@
+// =============================================================================
+//! Long-form dictation
+//!
+//! Whisper works on windows of up to 30 seconds, and transcribing several
+//! minutes of audio only once the user stops speaking would leave them
+//! waiting for as long again. A long-form dictation is instead transcribed
+//! in chunks while it is being recorded: every [`LONG_FORM_CHUNK_SECS`] of
+//! audio is transcribed as soon as it has been captured, and when the
+//! recording ends only the audio after the last chunk is left to transcribe.
+//!
+//! Consecutive chunks overlap by [`LONG_FORM_OVERLAP_SECS`], so a word cut
+//! in half at the end of one chunk is heard whole at the start of the next.
+//! [`stitch`] joins their texts, dropping the words the overlap produced
+//! twice.
+//!
+//! # Usage
+//!
+//! ```no_run
+//! use speakr_core::long_form::ChunkedTranscript;
+//!
+//! let mut transcript = ChunkedTranscript::default();
+//! let recorded = 30 * 16_000;
+//!
+//! if let Some(chunk) = transcript.next_chunk(recorded) {
+//!     // Transcribe `samples[chunk]`, then:
+//!     transcript.push("The first part of a long note about the");
+//! }
+//!
+//! // Once recording stops, transcribe `samples[transcript.remaining(recorded)]`
+//! let text = transcript.finish("note about the budget.");
+//! assert_eq!(text, "The first part of a long note about the budget.");
+//! ```
+// =============================================================================
+
+use crate::audio::SAMPLE_RATE_HZ;
+use std::ops::Range;
+use std::time::Duration;
+
+/// Length of each chunk transcribed while recording, within Whisper's
+/// 30-second window.
+pub const LONG_FORM_CHUNK_SECS: u32 = 25;
+
+/// Audio shared by consecutive chunks.
+pub const LONG_FORM_OVERLAP_SECS: u32 = 3;
+
+/// How often a long-form recording is checked for a chunk to transcribe.
+pub const LONG_FORM_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Fewest words two chunks must share to be treated as overlapping.
+const MIN_OVERLAP_WORDS: usize = 2;
+
+/// Most words the overlap can produce twice.
+const MAX_OVERLAP_WORDS: usize = 16;
+
+/// Words at the edge of a chunk that may be garbled by the cut, and are
+/// skipped when looking for the overlap.
+const MAX_EDGE_WORDS: usize = 2;
+
+const CHUNK_SAMPLES: usize = (LONG_FORM_CHUNK_SECS * SAMPLE_RATE_HZ) as usize;
+const OVERLAP_SAMPLES: usize = (LONG_FORM_OVERLAP_SECS * SAMPLE_RATE_HZ) as usize;
+
+/// The text of the chunks of a long-form recording transcribed so far.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChunkedTranscript {
+    text: String,
+    /// Offset, in 16 kHz samples, of the next chunk.
+    next_start: usize,
+}
+
+impl ChunkedTranscript {
+    /// Returns the stitched text of the chunks transcribed so far.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the offset, in 16 kHz samples, where the next chunk starts;
+    /// the audio before it is no longer needed while recording.
+    pub fn next_start(&self) -> usize {
+        self.next_start
+    }
+
+    /// Returns the next chunk to transcribe, once it has been recorded.
+    ///
+    /// # Arguments
+    ///
+    /// * `recorded` - Number of 16 kHz samples recorded so far
+    ///
+    /// # Returns
+    ///
+    /// The chunk's sample range, or `None` while it is still being recorded.
+    pub fn next_chunk(&self, recorded: usize) -> Option<Range<usize>> {
+        let end = self.next_start + CHUNK_SAMPLES;
+        (recorded >= end).then_some(self.next_start..end)
+    }
+
+    /// Adds the text of the chunk last returned by
+    /// [`next_chunk`](Self::next_chunk).
+    pub fn push(&mut self, chunk_text: &str) {
+        self.text = stitch(&self.text, chunk_text);
+        self.next_start += CHUNK_SAMPLES - OVERLAP_SAMPLES;
+    }
+
+    /// Returns the audio still to transcribe once recording has stopped:
+    /// everything after the chunks already transcribed, overlap included.
+    ///
+    /// # Arguments
+    ///
+    /// * `recorded` - Number of 16 kHz samples in the whole recording
+    pub fn remaining(&self, recorded: usize) -> Range<usize> {
+        self.next_start.min(recorded)..recorded
+    }
+
+    /// Returns the complete text, given the transcript of the
+    /// [`remaining`](Self::remaining) audio.
+    pub fn finish(&self, remaining_text: &str) -> String {
+        stitch(&self.text, remaining_text)
+    }
+}
+
+/// Joins the texts of two overlapping chunks.
+///
+/// Looks for the longest run of words, ignoring case and punctuation, that
+/// ends `before` and starts `after`, allowing for a couple of garbled words
+/// at either cut. The run is kept once; garbled words at the cut are
+/// dropped. Texts without a clear overlap are joined with a space.
+///
+/// # Arguments
+///
+/// * `before` - Text of the earlier chunk (or chunks)
+/// * `after` - Text of the chunk that follows it
+///
+/// # Returns
+///
+/// The joined text.
+pub fn stitch(before: &str, after: &str) -> String {
+    let before = before.trim_end();
+    let after = after.trim();
+    if before.is_empty() || after.is_empty() {
+        return format!("{before}{after}");
+    }
+
+    let before_words: Vec<String> = before.split_whitespace().map(normalise).collect();
+    let after_words: Vec<String> = after.split_whitespace().map(normalise).collect();
+    let overlap = find_overlap(&before_words, &after_words);
+    let (before, after) = match overlap {
+        // Keep the shared words from `before`, then continue after them
+        Some((trailing, leading, shared)) => (
+            drop_last_words(before, trailing),
+            drop_first_words(after, leading + shared),
+        ),
+        None => (before, after),
+    };
+    match after.trim_start() {
+        "" => before.to_string(),
+        after => format!("{before} {after}"),
+    }
+}
+
+/// Finds the overlap between the end of `before` and the start of `after`.
+///
+/// # Returns
+///
+/// The number of garbled words after the overlap in `before`, before it in
+/// `after`, and the number of shared words; the longest overlap wins, then
+/// the one skipping the fewest words.
+fn find_overlap(before: &[String], after: &[String]) -> Option<(usize, usize, usize)> {
+    let mut best: Option<(usize, usize, usize)> = None;
+    for trailing in 0..=MAX_EDGE_WORDS.min(before.len()) {
+        let end = before.len() - trailing;
+        for leading in 0..=MAX_EDGE_WORDS.min(after.len()) {
+            let longest = MAX_OVERLAP_WORDS.min(end).min(after.len() - leading);
+            let shared = (MIN_OVERLAP_WORDS..=longest).rev().find(|&n| {
+                before[end - n..end]
+                    .iter()
+                    .zip(&after[leading..leading + n])
+                    .all(|(a, b)| !a.is_empty() && a == b)
+            });
+            if let Some(shared) = shared {
+                let better = best.is_none_or(|(t, l, s)| {
+                    shared > s || (shared == s && trailing + leading < t + l)
+                });
+                if better {
+                    best = Some((trailing, leading, shared));
+                }
+            }
+        }
+    }
+    best
+}
+
+/// A word lower-cased and stripped of punctuation, for comparison.
+fn normalise(word: &str) -> String {
+    word.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// `text` without its last `count` words.
+fn drop_last_words(text: &str, count: usize) -> &str {
+    let mut text = text.trim_end();
+    for _ in 0..count {
+        text = text[..text.rfind(char::is_whitespace).unwrap_or(0)].trim_end();
+    }
+    text
+}
+
+/// `text` without its first `count` words.
+fn drop_first_words(text: &str, count: usize) -> &str {
+    let mut text = text.trim_start();
+    for _ in 0..count {
+        text = text[text.find(char::is_whitespace).unwrap_or(text.len())..].trim_start();
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_overlap_and_cover_the_recording() {
+        let mut transcript = ChunkedTranscript::default();
+
+        assert_eq!(transcript.next_chunk(CHUNK_SAMPLES - 1), None);
+        assert_eq!(transcript.next_chunk(CHUNK_SAMPLES), Some(0..CHUNK_SAMPLES));
+        transcript.push("first");
+
+        let step = CHUNK_SAMPLES - OVERLAP_SAMPLES;
+        assert_eq!(transcript.next_chunk(CHUNK_SAMPLES), None);
+        assert_eq!(
+            transcript.next_chunk(step + CHUNK_SAMPLES),
+            Some(step..step + CHUNK_SAMPLES)
+        );
+        assert_eq!(transcript.remaining(step + 100), step..step + 100);
+        assert_eq!(transcript.remaining(10), 10..10);
+    }
+
+    #[test]
+    fn stitching_drops_words_heard_twice() {
+        assert_eq!(
+            stitch(
+                "We met on Monday to discuss",
+                "Monday, to discuss the plan."
+            ),
+            "We met on Monday to discuss the plan."
+        );
+        // Words cut in half at either edge are dropped
+        assert_eq!(
+            stitch("the quick brown fox jumps ov", "wn fox jumps over the dog."),
+            "the quick brown fox jumps over the dog."
+        );
+    }
+
+    #[test]
+    fn stitching_without_overlap_joins_with_a_space() {
+        assert_eq!(
+            stitch("First part.", "Second part."),
+            "First part. Second part."
+        );
+        assert_eq!(stitch("", " Only this."), "Only this.");
+        assert_eq!(stitch("Only this. ", ""), "Only this.");
+        // A single shared word may be a coincidence
+        assert_eq!(stitch("and the", "the end"), "and the the end");
+    }
+}
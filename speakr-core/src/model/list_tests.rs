@@ -86,4 +86,14 @@ mod tests {
             "All models should use the same git reference"
         );
     }
+
+    #[test]
+    fn test_metadata_for_catalogue_model() {
+        let metadata = ModelMetadata::for_model(&Model::Tiny);
+        assert_eq!(metadata.filename, "ggml-tiny.bin");
+        assert_eq!(metadata.sha256, Model::Tiny.sha());
+        assert_eq!(metadata.size_bytes, Size::from_mib(75).bytes() as u64);
+        assert_eq!(metadata.git_ref.len(), 40);
+        assert!(metadata.download_url.contains(&metadata.git_ref));
+    }
 }
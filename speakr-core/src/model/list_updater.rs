@@ -19,8 +19,17 @@
 //! The resulting metadata is consumed by build-scripts as well as the
 //! `ModelListUpdater` CLI so that end-users always see the **latest** model
 //! catalogue without having to update the application.
+//!
+//! Every clone is recorded next to it (commit hash and time), so a later run
+//! on a flaky network can reuse the workspace instead of cloning again – see
+//! [`ModelListUpdater::cached_snapshot`].
 // ============================================================================
-use std::{fs, path::PathBuf, process::Command};
+use std::{
+    fs,
+    path::PathBuf,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 use thiserror::Error;
 
 use super::metadata::ModelMetadata;
@@ -39,6 +48,29 @@ pub enum ModelUpdateError {
     NetworkError(String),
 }
 
+/// A clone recorded by an earlier run, reusable without the network
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepositorySnapshot {
+    /// Commit the clone was checked out at when it was made
+    pub commit: String,
+    /// When the clone was made
+    pub fetched_at: SystemTime,
+}
+
+impl RepositorySnapshot {
+    /// How long ago the clone was made
+    pub fn age(&self) -> Duration {
+        SystemTime::now()
+            .duration_since(self.fetched_at)
+            .unwrap_or_default()
+    }
+
+    /// The commit hash shortened for display
+    pub fn short_commit(&self) -> &str {
+        &self.commit[..self.commit.len().min(7)]
+    }
+}
+
 /// Model metadata extraction tool
 pub struct ModelListUpdater {
     repo_path: PathBuf,
@@ -150,9 +182,113 @@ impl ModelListUpdater {
         }
 
         println!("✅ Repository cloned successfully");
+        self.record_snapshot().await?;
         Ok(())
     }
 
+    /// Returns the commit the clone is checked out at
+    ///
+    /// # Errors
+    ///
+    /// Returns `ModelUpdateError::GitError` if git rev-parse fails
+    pub async fn head_commit(&self) -> Result<String, ModelUpdateError> {
+        let output = Command::new("git")
+            .arg("rev-parse")
+            .arg("HEAD")
+            .current_dir(&self.repo_path)
+            .output()
+            .map_err(|e| {
+                ModelUpdateError::GitError(format!("Failed to execute git rev-parse: {e}"))
+            })?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(ModelUpdateError::GitError(format!(
+                "Git rev-parse failed: {stderr}"
+            )));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    /// File next to the clone recording its commit and when it was made
+    fn snapshot_path(&self) -> PathBuf {
+        self.repo_path.with_extension("snapshot")
+    }
+
+    /// Records the clone's commit and the current time, so later runs can
+    /// reuse it through [`cached_snapshot`](Self::cached_snapshot)
+    ///
+    /// # Errors
+    ///
+    /// Returns `ModelUpdateError` if the commit cannot be read or the record
+    /// cannot be written
+    pub async fn record_snapshot(&self) -> Result<RepositorySnapshot, ModelUpdateError> {
+        let snapshot = RepositorySnapshot {
+            commit: self.head_commit().await?,
+            fetched_at: SystemTime::now(),
+        };
+        let fetched_secs = snapshot
+            .fetched_at
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        fs::write(
+            self.snapshot_path(),
+            format!("{}\n{fetched_secs}\n", snapshot.commit),
+        )
+        .map_err(|e| {
+            ModelUpdateError::FileSystemError(format!("Failed to record snapshot: {e}"))
+        })?;
+        Ok(snapshot)
+    }
+
+    /// Returns the clone recorded by an earlier run, without touching the
+    /// network
+    ///
+    /// The clone is only trusted while it is still at the recorded commit:
+    /// one that was changed or interrupted mid-update since is rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ModelUpdateError::FileSystemError` if there is no recorded
+    /// clone, `ModelUpdateError::ParseError` if the record is damaged, and
+    /// `ModelUpdateError::GitError` if the clone no longer matches it
+    pub async fn cached_snapshot(&self) -> Result<RepositorySnapshot, ModelUpdateError> {
+        if !self.repo_path.exists() {
+            return Err(ModelUpdateError::FileSystemError(format!(
+                "No cached repository at {}",
+                self.repo_path.display()
+            )));
+        }
+        let record = fs::read_to_string(self.snapshot_path()).map_err(|e| {
+            ModelUpdateError::FileSystemError(format!("No snapshot record for the cache: {e}"))
+        })?;
+        let mut lines = record.lines();
+        let commit = lines
+            .next()
+            .filter(|commit| !commit.is_empty())
+            .ok_or_else(|| ModelUpdateError::ParseError("Snapshot record is empty".to_string()))?;
+        let fetched_secs = lines
+            .next()
+            .and_then(|secs| secs.parse::<u64>().ok())
+            .ok_or_else(|| {
+                ModelUpdateError::ParseError("Snapshot record has no fetch time".to_string())
+            })?;
+
+        let head = self.head_commit().await?;
+        if head != commit {
+            return Err(ModelUpdateError::GitError(format!(
+                "Cached repository is at {head}, not the recorded {commit}; run without --cached to clone it again"
+            )));
+        }
+
+        Ok(RepositorySnapshot {
+            commit: head,
+            fetched_at: UNIX_EPOCH + Duration::from_secs(fetched_secs),
+        })
+    }
+
     /// Extracts metadata for a specific model file
     ///
     /// # Arguments
@@ -368,6 +504,64 @@ mod tests {
     use tempfile::TempDir;
     use tokio;
 
+    /// Makes `updater`'s clone a local repository with one empty commit
+    fn init_local_repo(updater: &ModelListUpdater) {
+        fs::create_dir_all(&updater.repo_path).expect("Should create repo dir");
+        for args in [
+            &["init", "-q"][..],
+            &[
+                "-c",
+                "user.name=Speakr",
+                "-c",
+                "user.email=speakr@example.com",
+                "commit",
+                "-q",
+                "--allow-empty",
+                "-m",
+                "snapshot",
+            ],
+        ] {
+            let status = Command::new("git")
+                .args(args)
+                .current_dir(&updater.repo_path)
+                .status()
+                .expect("Should run git");
+            assert!(status.success());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cached_snapshot_reuses_a_recorded_clone() {
+        let temp_dir = TempDir::new().expect("Should create temp dir");
+        let updater = ModelListUpdater::new(temp_dir.path().to_path_buf());
+        assert!(matches!(
+            updater.cached_snapshot().await,
+            Err(ModelUpdateError::FileSystemError(_))
+        ));
+
+        init_local_repo(&updater);
+        let recorded = updater.record_snapshot().await.expect("Should record");
+        let cached = updater.cached_snapshot().await.expect("Should reuse");
+
+        assert_eq!(cached.commit, recorded.commit);
+        assert!(cached.age() < Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn test_cached_snapshot_rejects_a_changed_clone() {
+        let temp_dir = TempDir::new().expect("Should create temp dir");
+        let updater = ModelListUpdater::new(temp_dir.path().to_path_buf());
+        init_local_repo(&updater);
+        updater.record_snapshot().await.expect("Should record");
+
+        fs::write(updater.snapshot_path(), "0000000\n0\n").expect("Should write");
+
+        assert!(matches!(
+            updater.cached_snapshot().await,
+            Err(ModelUpdateError::GitError(_))
+        ));
+    }
+
     #[tokio::test]
     async fn test_model_updater_new_creates_correct_paths() {
         // Arrange
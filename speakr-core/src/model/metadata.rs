@@ -2,6 +2,9 @@
 //! Model Metadata Helpers
 // ============================================================================
 
+use super::list::Model;
+use speakr_types::ModelId;
+
 /// Strongly-typed metadata for a single `ggml-*.bin` model artefact.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ModelMetadata {
@@ -12,6 +15,44 @@ pub struct ModelMetadata {
     pub download_url: String,
 }
 
+impl ModelMetadata {
+    /// Builds the metadata of a model in the built-in catalogue.
+    ///
+    /// The git reference is taken from the model's download URL
+    /// (`…/resolve/<git_ref>/<file>`); it is empty when the URL has been
+    /// overridden with `SPEAKR_MODEL_BASE_URL`. `sha256` holds the
+    /// catalogue checksum, which is SHA-1.
+    pub fn for_model(model: &Model) -> Self {
+        let download_url = model.url();
+        let git_ref = download_url
+            .split_once("/resolve/")
+            .and_then(|(_, rest)| rest.split_once('/'))
+            .map(|(git_ref, _)| git_ref.to_string())
+            .unwrap_or_default();
+        Self {
+            filename: format!("ggml-{}.bin", model.filename()),
+            sha256: model.sha().to_string(),
+            size_bytes: model.filesize().bytes() as u64,
+            git_ref,
+            download_url,
+        }
+    }
+}
+
+impl Model {
+    /// Returns the identifier settings use for this model.
+    pub fn id(&self) -> ModelId {
+        ModelId::new(self.filename())
+    }
+
+    /// Finds the catalogue model with the given identifier.
+    ///
+    /// Returns `None` for models that are not in the catalogue.
+    pub fn from_id(id: &ModelId) -> Option<Self> {
+        Self::iter().find(|model| model.filename() == id.as_str())
+    }
+}
+
 /// Converts a model filename to a valid Rust enum variant name
 ///
 /// This function transforms model filenames (like "ggml-base.en.bin") into
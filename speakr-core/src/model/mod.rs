@@ -32,7 +32,7 @@ mod metadata;
 // Re-exports
 //
 pub use list::Model;
-pub use list_updater::ModelListUpdater;
+pub use list_updater::{ModelListUpdater, RepositorySnapshot};
 pub use metadata::{filename_to_variant_name, ModelMetadata};
 
 // Only load the test file during testing
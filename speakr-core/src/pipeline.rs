@@ -28,7 +28,7 @@
 //!
 //! // 2. Transcribe
 //! let mut cfg = TranscriptionConfig::default();
-//! cfg.model_size = speakr_types::ModelSize::Small;
+//! cfg.model = speakr_types::ModelId::new("small");
 //! let result = pipeline::transcription_pipeline(samples, cfg).await.unwrap();
 //! println!("{}", result.text);
 //! # });
@@ -88,6 +88,42 @@ pub async fn transcription_pipeline(
     engine.transcribe_async(samples).await
 }
 
+/// Like [`transcription_pipeline`], but reuses an already-loaded engine.
+///
+/// Callers that keep a model resident between dictations use this to skip
+/// the model load; per-dictation options should already be applied with
+/// [`TranscriptionEngine::apply_options`](transcription::engine::TranscriptionEngine::apply_options).
+///
+/// # Errors
+///
+/// Returns [`TranscriptionError::InvalidAudioFormat`] when sample validation
+/// fails or any error propagated by the engine.
+#[instrument(level = "debug", skip(samples, engine))]
+pub async fn transcription_pipeline_with_engine(
+    samples: Vec<i16>,
+    engine: &transcription::engine::TranscriptionEngine,
+) -> Result<TranscriptionResult, TranscriptionError> {
+    validate_audio_format(&samples)?;
+    engine.transcribe_async(samples).await
+}
+
+/// Like [`transcription_pipeline_with_engine`], but abortable through
+/// `handle`.
+///
+/// # Errors
+///
+/// Returns [`TranscriptionError::Aborted`] if `handle` is aborted before
+/// inference finishes, otherwise as [`transcription_pipeline_with_engine`].
+#[instrument(level = "debug", skip(samples, engine, handle))]
+pub async fn transcription_pipeline_with_handle(
+    samples: Vec<i16>,
+    engine: &transcription::engine::TranscriptionEngine,
+    handle: transcription::engine::TranscriptionHandle,
+) -> Result<TranscriptionResult, TranscriptionError> {
+    validate_audio_format(&samples)?;
+    engine.transcribe_async_with_handle(samples, handle).await
+}
+
 // -----------------------------------------------------------------------------
 // Tests
 // -----------------------------------------------------------------------------
@@ -117,7 +153,7 @@ mod tests {
 
         let samples = vec![0i16; audio::SAMPLE_RATE_HZ as usize]; // 1 second of silence
         let cfg = speakr_types::TranscriptionConfig {
-            model_size: speakr_types::ModelSize::Small,
+            model: speakr_types::ModelId::new("small"),
             ..Default::default()
         };
 
@@ -134,10 +170,47 @@ mod tests {
         assert!(text.contains("stub"));
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn pipeline_reuses_loaded_engine() {
+        // ---------------------------------------------------------------------
+        // Arrange
+        let tmp = TempDir::new().unwrap();
+        create_dummy_model(&tmp, &Model::Small);
+        let cfg = speakr_types::TranscriptionConfig {
+            model: speakr_types::ModelId::new("small"),
+            ..Default::default()
+        };
+        let manager =
+            crate::transcription::models::ModelManager::with_cache_dir(tmp.path().to_path_buf());
+        let mut engine =
+            transcription::engine::TranscriptionEngine::with_config_and_manager(cfg, manager)
+                .expect("engine init");
+
+        // ---------------------------------------------------------------------
+        // Act
+        engine.apply_options(&speakr_types::TranscriptionConfig {
+            language: Some("en".to_string()),
+            auto_detect_language: false,
+            ..Default::default()
+        });
+        let samples = vec![0i16; audio::SAMPLE_RATE_HZ as usize];
+        let result = transcription_pipeline_with_engine(samples, &engine).await;
+
+        // ---------------------------------------------------------------------
+        // Assert
+        let result = result.expect("transcription should succeed");
+        assert_eq!(result.language.as_deref(), Some("en"));
+        assert_eq!(result.model_used.as_str(), "small");
+        assert!(matches!(
+            transcription_pipeline_with_engine(Vec::new(), &engine).await,
+            Err(TranscriptionError::InvalidAudioFormat(_))
+        ));
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn pipeline_rejects_empty_buffer() {
         let cfg = speakr_types::TranscriptionConfig {
-            model_size: speakr_types::ModelSize::Small,
+            model: speakr_types::ModelId::new("small"),
             ..Default::default()
         };
         let err = transcription_pipeline(Vec::new(), cfg).await.unwrap_err();
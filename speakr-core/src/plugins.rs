@@ -0,0 +1,502 @@
+// ============================================================================
+//! Post-processing plugins
+//!
+//! Plugins let users add their own transcript transformations without forking
+//! Speakr. A plugin is a WebAssembly module that runs after the built-in
+//! [`TextTransform`](speakr_types::TextTransform) chain, inside a sandbox: it
+//! cannot import any host function (so it has no file, network or clock
+//! access), and every call is bounded by the fuel and memory limits in
+//! [`PluginLimits`].
+//!
+//! # Plugin interface (ABI version 1)
+//!
+//! A plugin module must export:
+//!
+//! | Export                | Signature            | Purpose                                   |
+//! | --------------------- | -------------------- | ----------------------------------------- |
+//! | `memory`              | linear memory        | Shared buffer for input and output        |
+//! | `speakr_abi_version`  | `() -> i32`          | Must return [`PLUGIN_ABI_VERSION`]        |
+//! | `speakr_alloc`        | `(len: i32) -> i32`  | Reserve `len` bytes, returning a pointer  |
+//! | `speakr_process`      | `(ptr, len) -> i64`  | Transform the input at `ptr`/`len`        |
+//!
+//! The host writes a JSON-encoded [`PluginInput`] into memory obtained from
+//! `speakr_alloc` and calls `speakr_process`. The result packs the output's
+//! pointer into the high 32 bits and its length into the low 32 bits; the
+//! output is the new transcript as UTF-8 text. Each call gets a fresh
+//! instance, so plugins cannot keep state between dictations.
+//!
+//! Native libraries are deliberately not supported: a dylib runs with the
+//! full privileges of the app, so none of the limits above could be enforced.
+//!
+//! # Usage
+//!
+//! ```no_run
+//! use speakr_core::plugins::PluginManager;
+//! use speakr_types::PluginLimits;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let manager = PluginManager::new();
+//! let plugins = manager
+//!     .load_enabled(&["title-case".to_string()], PluginLimits::default())
+//!     .await;
+//! # }
+//! ```
+// ============================================================================
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use directories::ProjectDirs;
+use speakr_types::{
+    is_valid_plugin_name, PluginInfo, PluginInput, PluginLimits, PLUGIN_ABI_VERSION,
+};
+use thiserror::Error;
+use tokio::fs;
+use wasmi::core::TrapCode;
+use wasmi::{Config, Engine, ExternType, Linker, Module, Store, StoreLimits, StoreLimitsBuilder};
+
+/// File extension of installed plugin modules.
+const PLUGIN_EXTENSION: &str = "wasm";
+
+/// Exports every plugin must provide.
+const REQUIRED_EXPORTS: [&str; 4] = [
+    "memory",
+    "speakr_abi_version",
+    "speakr_alloc",
+    "speakr_process",
+];
+
+/// Errors returned when installing, loading or running a plugin.
+#[derive(Debug, Error)]
+pub enum PluginError {
+    #[error("invalid plugin name '{0}'")]
+    InvalidName(String),
+
+    #[error("plugin '{name}' is not a valid post-processor: {reason}")]
+    InvalidModule { name: String, reason: String },
+
+    #[error("plugin '{name}' exceeded its {limit} limit")]
+    LimitExceeded { name: String, limit: &'static str },
+
+    #[error("plugin '{name}' failed: {reason}")]
+    Runtime { name: String, reason: String },
+
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// A validated plugin module, ready to process transcripts.
+#[derive(Clone)]
+pub struct PostProcessorPlugin {
+    name: String,
+    engine: Engine,
+    module: Arc<Module>,
+    limits: PluginLimits,
+}
+
+impl fmt::Debug for PostProcessorPlugin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PostProcessorPlugin")
+            .field("name", &self.name)
+            .field("limits", &self.limits)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PostProcessorPlugin {
+    /// Compiles and validates a plugin module.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PluginError::InvalidModule`] if the bytes are not WebAssembly,
+    /// the module imports anything, lacks a required export or targets a
+    /// different ABI version.
+    pub fn from_bytes(name: &str, wasm: &[u8], limits: PluginLimits) -> Result<Self, PluginError> {
+        let invalid = |reason: String| PluginError::InvalidModule {
+            name: name.to_string(),
+            reason,
+        };
+
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, wasm).map_err(|e| invalid(e.to_string()))?;
+
+        if let Some(import) = module.imports().next() {
+            return Err(invalid(format!(
+                "imports are not allowed (found '{}::{}')",
+                import.module(),
+                import.name()
+            )));
+        }
+
+        for required in REQUIRED_EXPORTS {
+            if !module.exports().any(|export| export.name() == required) {
+                return Err(invalid(format!("missing export '{required}'")));
+            }
+        }
+        if !module
+            .exports()
+            .any(|export| export.name() == "memory" && matches!(export.ty(), ExternType::Memory(_)))
+        {
+            return Err(invalid("'memory' is not a linear memory".to_string()));
+        }
+
+        let plugin = Self {
+            name: name.to_string(),
+            engine,
+            module: Arc::new(module),
+            limits,
+        };
+
+        let (mut store, instance) = plugin.instantiate()?;
+        let version = instance
+            .get_typed_func::<(), i32>(&store, "speakr_abi_version")
+            .and_then(|func| func.call(&mut store, ()))
+            .map_err(|e| plugin.runtime_error(e))?;
+        if u32::try_from(version).ok() != Some(PLUGIN_ABI_VERSION) {
+            return Err(invalid(format!(
+                "ABI version {version} is not supported (expected {PLUGIN_ABI_VERSION})"
+            )));
+        }
+
+        Ok(plugin)
+    }
+
+    /// Returns the plugin's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Runs the plugin over `input`, returning the transformed transcript.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PluginError::LimitExceeded`] if the call runs out of fuel and
+    /// [`PluginError::Runtime`] if the plugin traps or returns invalid output.
+    pub fn process(&self, input: &PluginInput) -> Result<String, PluginError> {
+        let payload = serde_json::to_vec(input).map_err(|e| self.failure(e.to_string()))?;
+        let payload_len =
+            i32::try_from(payload.len()).map_err(|_| self.failure("input too large".into()))?;
+
+        let (mut store, instance) = self.instantiate()?;
+        let memory = instance
+            .get_memory(&store, "memory")
+            .ok_or_else(|| self.failure("missing memory".into()))?;
+        let alloc = instance
+            .get_typed_func::<i32, i32>(&store, "speakr_alloc")
+            .map_err(|e| self.runtime_error(e))?;
+        let process = instance
+            .get_typed_func::<(i32, i32), i64>(&store, "speakr_process")
+            .map_err(|e| self.runtime_error(e))?;
+
+        let input_ptr = alloc
+            .call(&mut store, payload_len)
+            .map_err(|e| self.runtime_error(e))?;
+        memory
+            .write(&mut store, input_ptr as u32 as usize, &payload)
+            .map_err(|e| self.failure(e.to_string()))?;
+
+        let packed = process
+            .call(&mut store, (input_ptr, payload_len))
+            .map_err(|e| self.runtime_error(e))? as u64;
+        let output_ptr = (packed >> 32) as usize;
+        let output_len = (packed & 0xffff_ffff) as usize;
+
+        let mut output = vec![0; output_len];
+        memory
+            .read(&store, output_ptr, &mut output)
+            .map_err(|e| self.failure(format!("output out of bounds: {e}")))?;
+        String::from_utf8(output).map_err(|_| self.failure("output is not UTF-8".into()))
+    }
+
+    /// Creates a fresh, limited instance of the module.
+    fn instantiate(&self) -> Result<(Store<StoreLimits>, wasmi::Instance), PluginError> {
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(usize::try_from(self.limits.max_memory_bytes).unwrap_or(usize::MAX))
+            .instances(1)
+            .build();
+        let mut store = Store::new(&self.engine, limits);
+        store.limiter(|limits| limits);
+        store
+            .set_fuel(self.limits.max_fuel)
+            .map_err(|e| self.failure(e.to_string()))?;
+
+        let instance = Linker::new(&self.engine)
+            .instantiate(&mut store, &self.module)
+            .and_then(|pre| pre.start(&mut store))
+            .map_err(|e| self.runtime_error(e))?;
+        Ok((store, instance))
+    }
+
+    fn runtime_error(&self, error: wasmi::Error) -> PluginError {
+        if error.as_trap_code() == Some(TrapCode::OutOfFuel) {
+            return PluginError::LimitExceeded {
+                name: self.name.clone(),
+                limit: "fuel",
+            };
+        }
+        self.failure(error.to_string())
+    }
+
+    fn failure(&self, reason: String) -> PluginError {
+        PluginError::Runtime {
+            name: self.name.clone(),
+            reason,
+        }
+    }
+}
+
+/// Installs, lists and loads plugins from the local plugin directory.
+///
+/// Plugins live in `<data dir>/plugins/<name>.wasm`. A different directory
+/// can be supplied via [`PluginManager::with_dir`] or `$SPEAKR_PLUGINS_DIR`.
+#[derive(Debug, Clone)]
+pub struct PluginManager {
+    dir: PathBuf,
+}
+
+impl PluginManager {
+    /// Create a new [`PluginManager`] using the default plugin directory.
+    pub fn new() -> Self {
+        Self {
+            dir: Self::default_dir(),
+        }
+    }
+
+    /// Create a new [`PluginManager`] with a custom directory – *tests only*.
+    pub fn with_dir(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// Return the directory plugins are installed into.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Copy the module at `source` into the plugin directory.
+    ///
+    /// The plugin is named after the file stem and validated before it is
+    /// copied, so a broken module is never installed. Installing a plugin
+    /// with an existing name replaces it.
+    pub async fn install(&self, source: &Path) -> Result<PluginInfo, PluginError> {
+        let name = source
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default()
+            .to_string();
+        if !is_valid_plugin_name(&name) {
+            return Err(PluginError::InvalidName(name));
+        }
+
+        let wasm = fs::read(source).await?;
+        PostProcessorPlugin::from_bytes(&name, &wasm, PluginLimits::default())?;
+
+        fs::create_dir_all(&self.dir).await?;
+        fs::write(self.path_for(&name), &wasm).await?;
+        tracing::info!(plugin = %name, "Installed post-processing plugin");
+
+        Ok(PluginInfo {
+            name,
+            size_bytes: wasm.len() as u64,
+            error: None,
+        })
+    }
+
+    /// Remove an installed plugin.
+    pub async fn uninstall(&self, name: &str) -> Result<(), PluginError> {
+        if !is_valid_plugin_name(name) {
+            return Err(PluginError::InvalidName(name.to_string()));
+        }
+        fs::remove_file(self.path_for(name)).await?;
+        Ok(())
+    }
+
+    /// List installed plugins, validating each module.
+    ///
+    /// A missing plugin directory yields an empty list.
+    pub async fn list(&self) -> Result<Vec<PluginInfo>, PluginError> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut plugins = Vec::new();
+        let mut entries = fs::read_dir(&self.dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some(PLUGIN_EXTENSION) {
+                continue;
+            }
+            let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            if !is_valid_plugin_name(name) {
+                continue;
+            }
+
+            let wasm = fs::read(&path).await?;
+            let error = PostProcessorPlugin::from_bytes(name, &wasm, PluginLimits::default())
+                .err()
+                .map(|e| e.to_string());
+            plugins.push(PluginInfo {
+                name: name.to_string(),
+                size_bytes: wasm.len() as u64,
+                error,
+            });
+        }
+
+        plugins.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(plugins)
+    }
+
+    /// Load a single installed plugin.
+    pub async fn load(
+        &self,
+        name: &str,
+        limits: PluginLimits,
+    ) -> Result<PostProcessorPlugin, PluginError> {
+        if !is_valid_plugin_name(name) {
+            return Err(PluginError::InvalidName(name.to_string()));
+        }
+        let wasm = fs::read(self.path_for(name)).await?;
+        PostProcessorPlugin::from_bytes(name, &wasm, limits)
+    }
+
+    /// Load the enabled plugins, in order.
+    ///
+    /// Plugins that are missing or invalid are logged and skipped so a broken
+    /// plugin never blocks dictation.
+    pub async fn load_enabled(
+        &self,
+        names: &[String],
+        limits: PluginLimits,
+    ) -> Vec<PostProcessorPlugin> {
+        let mut plugins = Vec::with_capacity(names.len());
+        for name in names {
+            match self.load(name, limits).await {
+                Ok(plugin) => plugins.push(plugin),
+                Err(e) => tracing::warn!(plugin = %name, "Skipping plugin: {}", e),
+            }
+        }
+        plugins
+    }
+
+    fn path_for(&self, name: &str) -> PathBuf {
+        self.dir.join(format!("{name}.{PLUGIN_EXTENSION}"))
+    }
+
+    /// Default plugin directory (platform specific).
+    fn default_dir() -> PathBuf {
+        if let Ok(dir) = std::env::var("SPEAKR_PLUGINS_DIR") {
+            return PathBuf::from(dir);
+        }
+
+        if let Some(dirs) = ProjectDirs::from("com", "Speakr", "Speakr") {
+            return dirs.data_local_dir().join("plugins");
+        }
+
+        std::env::current_dir()
+            .unwrap_or_else(|_| PathBuf::from("."))
+            .join("plugins")
+    }
+}
+
+impl Default for PluginManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the input JSON unchanged, which is enough to exercise the ABI.
+    const ECHO_PLUGIN: &str = r#"
+        (module
+          (memory (export "memory") 1)
+          (global $next (mut i32) (i32.const 1024))
+          (func (export "speakr_abi_version") (result i32) (i32.const 1))
+          (func (export "speakr_alloc") (param $len i32) (result i32)
+            (local $ptr i32)
+            (local.set $ptr (global.get $next))
+            (global.set $next (i32.add (global.get $next) (local.get $len)))
+            (local.get $ptr))
+          (func (export "speakr_process") (param $ptr i32) (param $len i32) (result i64)
+            (i64.or
+              (i64.shl (i64.extend_i32_u (local.get $ptr)) (i64.const 32))
+              (i64.extend_i32_u (local.get $len)))))
+    "#;
+
+    /// Never returns, so it must be stopped by the fuel limit.
+    const SPIN_PLUGIN: &str = r#"
+        (module
+          (memory (export "memory") 1)
+          (func (export "speakr_abi_version") (result i32) (i32.const 1))
+          (func (export "speakr_alloc") (param i32) (result i32) (i32.const 1024))
+          (func (export "speakr_process") (param i32 i32) (result i64)
+            (loop $forever (br $forever))
+            (i64.const 0)))
+    "#;
+
+    fn input(text: &str) -> PluginInput {
+        PluginInput {
+            text: text.to_string(),
+            app_id: None,
+            language: "en".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_plugin_receives_transcript_and_context() {
+        let wasm = wat::parse_str(ECHO_PLUGIN).unwrap();
+        let plugin = PostProcessorPlugin::from_bytes("echo", &wasm, PluginLimits::default())
+            .expect("echo plugin is valid");
+
+        let output = plugin.process(&input("hello")).expect("process succeeds");
+
+        let echoed: PluginInput = serde_json::from_str(&output).unwrap();
+        assert_eq!(echoed, input("hello"));
+    }
+
+    #[test]
+    fn test_fuel_limit_stops_runaway_plugins() {
+        let wasm = wat::parse_str(SPIN_PLUGIN).unwrap();
+        let limits = PluginLimits {
+            max_fuel: 10_000,
+            ..PluginLimits::default()
+        };
+        let plugin = PostProcessorPlugin::from_bytes("spin", &wasm, limits).unwrap();
+
+        assert!(matches!(
+            plugin.process(&input("hello")),
+            Err(PluginError::LimitExceeded { limit: "fuel", .. })
+        ));
+    }
+
+    #[test]
+    fn test_memory_limit_is_enforced() {
+        let wasm = wat::parse_str(ECHO_PLUGIN).unwrap();
+        let limits = PluginLimits {
+            max_memory_bytes: 1024,
+            ..PluginLimits::default()
+        };
+
+        // The module's initial 64 KiB page already exceeds the limit
+        assert!(PostProcessorPlugin::from_bytes("echo", &wasm, limits).is_err());
+    }
+
+    #[test]
+    fn test_modules_with_imports_are_rejected() {
+        let wasm = wat::parse_str(
+            r#"(module (import "env" "read_file" (func)) (memory (export "memory") 1))"#,
+        )
+        .unwrap();
+
+        let result = PostProcessorPlugin::from_bytes("sneaky", &wasm, PluginLimits::default());
+
+        assert!(matches!(result, Err(PluginError::InvalidModule { .. })));
+    }
+}
@@ -0,0 +1,673 @@
+// =============================================================================
+//! Text post-processing
+//!
+//! Transcripts coming out of Whisper are rarely exactly what the user wants
+//! injected: they may carry stray whitespace, hesitation words, or recurring
+//! mis-hearings. [`TextPostProcessor`] applies the user's configured chain of
+//! [`TextTransform`]s, in order, to the transcript before it is injected.
+//!
+//! Regex patterns are compiled once when the processor is built, so invalid
+//! rules are reported up front rather than on every dictation. Enabled
+//! [plugins](crate::plugins) run after the built-in transforms.
+//!
+//! Every stage works on the previous stage's output, so stages run one after
+//! another; [`TextPostProcessor::process_timed`] reports how long each took.
+//! Steps that only ever look within a line (filler words, punctuation and
+//! literal replacements) split long transcripts at line breaks and process
+//! the pieces on several threads at once.
+//!
+//! # Usage
+//!
+//! ```no_run
+//! use speakr_core::post_processing::TextPostProcessor;
+//! use speakr_types::TextTransform;
+//!
+//! let processor = TextPostProcessor::new(&[
+//!     TextTransform::TrimWhitespace,
+//!     TextTransform::default_filler_words(),
+//!     TextTransform::CapitaliseSentences,
+//! ])
+//! .unwrap();
+//!
+//! assert_eq!(processor.process("  um, hello there.  "), "Hello there.");
+//! ```
+// =============================================================================
+
+use crate::plugins::PostProcessorPlugin;
+use crate::voice_command::CommandInterpreter;
+use regex::Regex;
+use speakr_types::{
+    PluginInput, ProcessingStage, TextTransform, VoiceCommandSettings, DEFAULT_LANGUAGE,
+};
+use std::num::NonZeroUsize;
+use std::thread;
+use std::time::Instant;
+use thiserror::Error;
+
+/// Transcripts shorter than this, in bytes, are processed on one thread;
+/// starting threads would cost more than it saves.
+const CONCURRENT_MIN_BYTES: usize = 16 * 1024;
+
+/// Errors returned when building a [`TextPostProcessor`].
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum PostProcessingError {
+    #[error("invalid replacement pattern '{pattern}': {reason}")]
+    InvalidPattern { pattern: String, reason: String },
+}
+
+/// A transform with any regexes already compiled.
+#[derive(Debug, Clone)]
+enum CompiledTransform {
+    TrimWhitespace,
+    CapitaliseSentences,
+    StripPunctuation,
+    StripFillerWords(Regex),
+    RegexReplace {
+        regex: Regex,
+        replacement: String,
+    },
+    Replace {
+        from: String,
+        regex: Regex,
+        to: String,
+    },
+}
+
+impl CompiledTransform {
+    /// Names the transform in stage timings.
+    fn label(&self) -> String {
+        match self {
+            CompiledTransform::TrimWhitespace => "Trim whitespace".to_string(),
+            CompiledTransform::CapitaliseSentences => "Capitalise sentences".to_string(),
+            CompiledTransform::StripPunctuation => "Strip punctuation".to_string(),
+            CompiledTransform::StripFillerWords(_) => "Strip filler words".to_string(),
+            CompiledTransform::RegexReplace { regex, .. } => format!("Regex /{}/", regex.as_str()),
+            CompiledTransform::Replace { from, .. } => format!("Replace \"{from}\""),
+        }
+    }
+
+    /// Whether the transform gives the same result when each line is
+    /// processed on its own, so long text can be split between threads.
+    fn is_line_local(&self) -> bool {
+        match self {
+            CompiledTransform::StripPunctuation => true,
+            // Neither pattern can match a line break unless a word contains one
+            CompiledTransform::StripFillerWords(regex)
+            | CompiledTransform::Replace { regex, .. } => !regex.as_str().contains('\n'),
+            CompiledTransform::TrimWhitespace
+            | CompiledTransform::CapitaliseSentences
+            | CompiledTransform::RegexReplace { .. } => false,
+        }
+    }
+
+    /// Applies the transform to `text`, which may be one segment of a
+    /// longer text.
+    fn apply(&self, text: &str) -> String {
+        match self {
+            CompiledTransform::TrimWhitespace => collapse_spaces(text.trim()),
+            CompiledTransform::CapitaliseSentences => capitalise_sentences(text),
+            CompiledTransform::StripPunctuation => strip_punctuation(text),
+            CompiledTransform::StripFillerWords(regex) => regex.replace_all(text, "").into_owned(),
+            CompiledTransform::RegexReplace { regex, replacement } => {
+                regex.replace_all(text, replacement.as_str()).into_owned()
+            }
+            CompiledTransform::Replace { regex, to, .. } => {
+                regex.replace_all(text, regex::NoExpand(to)).into_owned()
+            }
+        }
+    }
+}
+
+/// Applies an ordered chain of text transforms to transcribed text.
+#[derive(Debug, Clone)]
+pub struct TextPostProcessor {
+    voice_commands: Option<CommandInterpreter>,
+    steps: Vec<CompiledTransform>,
+    plugins: Vec<PostProcessorPlugin>,
+    app_id: Option<String>,
+    language: String,
+    concurrent_min_bytes: usize,
+    threads: usize,
+}
+
+impl Default for TextPostProcessor {
+    /// Creates a processor for [`TextTransform::default_chain`].
+    fn default() -> Self {
+        Self {
+            voice_commands: None,
+            steps: vec![CompiledTransform::TrimWhitespace],
+            plugins: Vec::new(),
+            app_id: None,
+            language: DEFAULT_LANGUAGE.to_string(),
+            concurrent_min_bytes: CONCURRENT_MIN_BYTES,
+            threads: thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        }
+    }
+}
+
+impl TextPostProcessor {
+    /// Builds a processor for the given transform chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `transforms` - The transforms to apply, in order
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PostProcessingError::InvalidPattern`] if a replacement rule
+    /// does not compile.
+    pub fn new(transforms: &[TextTransform]) -> Result<Self, PostProcessingError> {
+        let steps = transforms
+            .iter()
+            .filter_map(|transform| Self::compile(transform).transpose())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            steps,
+            ..Self::default()
+        })
+    }
+
+    /// Resolves spoken commands such as "comma" before the transforms run.
+    ///
+    /// Nothing changes unless `settings.enabled` is set.
+    pub fn with_voice_commands(mut self, settings: &VoiceCommandSettings) -> Self {
+        self.voice_commands = settings.enabled.then(|| CommandInterpreter::new(settings));
+        self
+    }
+
+    /// Adds plugins that run, in order, after the built-in transforms.
+    ///
+    /// # Arguments
+    ///
+    /// * `plugins` - The enabled plugins
+    /// * `app_id` - Identifier of the target application, passed to plugins
+    /// * `language` - Configured transcription language, passed to plugins
+    pub fn with_plugins(
+        mut self,
+        plugins: Vec<PostProcessorPlugin>,
+        app_id: Option<&str>,
+        language: &str,
+    ) -> Self {
+        self.plugins = plugins;
+        self.app_id = app_id.map(str::to_string);
+        self.language = language.to_string();
+        self
+    }
+
+    /// Runs `text` through the voice commands, every configured transform
+    /// and plugin.
+    ///
+    /// A plugin that fails or exceeds its limits is skipped, leaving the text
+    /// as it was before that plugin.
+    ///
+    /// # Returns
+    ///
+    /// The processed text. An empty chain returns `text` unchanged.
+    pub fn process(&self, text: &str) -> String {
+        self.process_timed(text).0
+    }
+
+    /// Like [`process`](Self::process), also returning how long each voice
+    /// command pass, transform and plugin took, in the order they ran.
+    ///
+    /// Line-local transforms run on several threads for long text; their
+    /// stage records how many segments the text was split into.
+    pub fn process_timed(&self, text: &str) -> (String, Vec<ProcessingStage>) {
+        let mut stages = Vec::new();
+        let mut text = text.to_string();
+
+        if let Some(interpreter) = &self.voice_commands {
+            text = run_stage(&mut stages, "Voice commands", text, |t| {
+                interpreter.apply(t)
+            });
+        }
+        for step in &self.steps {
+            let bounds = self.segment_bounds(step, &text);
+            text = time_stage(&mut stages, step.label(), bounds.len(), text, |t| {
+                apply_segmented(step, t, &bounds)
+            });
+        }
+        for plugin in &self.plugins {
+            let name = format!("Plugin {}", plugin.name());
+            text = run_stage(&mut stages, name, text, |t| {
+                let input = PluginInput {
+                    text: t.to_string(),
+                    app_id: self.app_id.clone(),
+                    language: self.language.clone(),
+                };
+                plugin.process(&input).unwrap_or_else(|e| {
+                    tracing::warn!("Post-processing plugin skipped: {}", e);
+                    input.text
+                })
+            });
+        }
+
+        (text, stages)
+    }
+
+    /// Compiles a single transform, returning `None` for no-op transforms.
+    fn compile(
+        transform: &TextTransform,
+    ) -> Result<Option<CompiledTransform>, PostProcessingError> {
+        let compiled = match transform {
+            TextTransform::TrimWhitespace => CompiledTransform::TrimWhitespace,
+            TextTransform::CapitaliseSentences => CompiledTransform::CapitaliseSentences,
+            TextTransform::StripPunctuation => CompiledTransform::StripPunctuation,
+            TextTransform::StripFillerWords { words } => {
+                let alternatives = words
+                    .iter()
+                    .map(|word| word.trim())
+                    .filter(|word| !word.is_empty())
+                    .map(regex::escape)
+                    .collect::<Vec<_>>();
+                if alternatives.is_empty() {
+                    return Ok(None);
+                }
+                // Leading spaces are consumed so "Hello um." becomes "Hello."
+                let pattern = format!(r"(?i)[ \t]*\b(?:{})\b,?", alternatives.join("|"));
+                CompiledTransform::StripFillerWords(compile_pattern(&pattern)?)
+            }
+            TextTransform::RegexReplace {
+                pattern,
+                replacement,
+            } => CompiledTransform::RegexReplace {
+                regex: compile_pattern(pattern)?,
+                replacement: replacement.clone(),
+            },
+            TextTransform::Replace {
+                from,
+                to,
+                case_sensitive,
+            } => {
+                let from = from.trim();
+                if from.is_empty() {
+                    return Ok(None);
+                }
+                CompiledTransform::Replace {
+                    from: from.to_string(),
+                    regex: compile_pattern(&literal_pattern(from, *case_sensitive))?,
+                    to: to.clone(),
+                }
+            }
+        };
+        Ok(Some(compiled))
+    }
+
+    /// Byte offsets at which each segment of `text` ends when `step` runs
+    /// on it, splitting only after line breaks.
+    ///
+    /// Short text, and steps that are not line-local, get one segment.
+    fn segment_bounds(&self, step: &CompiledTransform, text: &str) -> Vec<usize> {
+        if self.threads < 2 || text.len() < self.concurrent_min_bytes || !step.is_line_local() {
+            return vec![text.len()];
+        }
+
+        let target = text.len().div_ceil(self.threads);
+        let mut bounds = Vec::with_capacity(self.threads);
+        let mut start = 0;
+        for (index, _) in text.match_indices('\n') {
+            let end = index + 1;
+            if end - start >= target && end < text.len() {
+                bounds.push(end);
+                start = end;
+            }
+        }
+        bounds.push(text.len());
+        bounds
+    }
+}
+
+/// Applies `step` to each segment of `text` ending at `bounds`, on a thread
+/// per segment when there is more than one.
+fn apply_segmented(step: &CompiledTransform, text: &str, bounds: &[usize]) -> String {
+    let output = if bounds.len() < 2 {
+        step.apply(text)
+    } else {
+        thread::scope(|scope| {
+            let mut start = 0;
+            let workers = bounds
+                .iter()
+                .map(|&end| {
+                    let segment = &text[start..end];
+                    start = end;
+                    scope.spawn(move || step.apply(segment))
+                })
+                .collect::<Vec<_>>();
+            workers
+                .into_iter()
+                .map(|worker| {
+                    worker
+                        .join()
+                        .unwrap_or_else(|e| std::panic::resume_unwind(e))
+                })
+                .collect()
+        })
+    };
+
+    match step {
+        // The space before a leading filler word goes with it
+        CompiledTransform::StripFillerWords(_) if !text.starts_with(char::is_whitespace) => {
+            output.trim_start().to_string()
+        }
+        _ => output,
+    }
+}
+
+/// Runs one processing stage on `text`, recording its timing in `stages`.
+///
+/// # Returns
+///
+/// The stage's output.
+pub fn run_stage(
+    stages: &mut Vec<ProcessingStage>,
+    name: impl Into<String>,
+    text: String,
+    stage: impl FnOnce(&str) -> String,
+) -> String {
+    time_stage(stages, name, 1, text, stage)
+}
+
+/// Like [`run_stage`], for a stage that split the text into `segments`.
+fn time_stage(
+    stages: &mut Vec<ProcessingStage>,
+    name: impl Into<String>,
+    segments: usize,
+    text: String,
+    stage: impl FnOnce(&str) -> String,
+) -> String {
+    let started = Instant::now();
+    let output = stage(&text);
+    stages.push(ProcessingStage {
+        name: name.into(),
+        duration_us: u64::try_from(started.elapsed().as_micros()).unwrap_or(u64::MAX),
+        changed: output != text,
+        segments,
+    });
+    output
+}
+
+fn compile_pattern(pattern: &str) -> Result<Regex, PostProcessingError> {
+    Regex::new(pattern).map_err(|e| PostProcessingError::InvalidPattern {
+        pattern: pattern.to_string(),
+        reason: e.to_string(),
+    })
+}
+
+/// Builds a pattern matching `from` literally, as a whole word or phrase.
+///
+/// Word boundaries are only added next to word characters, so a phrase such
+/// as "--" or "c++" still matches.
+fn literal_pattern(from: &str, case_sensitive: bool) -> String {
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+    let start = if from.starts_with(is_word_char) {
+        r"\b"
+    } else {
+        ""
+    };
+    let end = if from.ends_with(is_word_char) {
+        r"\b"
+    } else {
+        ""
+    };
+    let flags = if case_sensitive { "" } else { "(?i)" };
+    format!("{flags}{start}{}{end}", regex::escape(from))
+}
+
+/// Collapses runs of spaces and tabs into a single space, keeping newlines.
+fn collapse_spaces(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut previous_was_space = false;
+
+    for c in text.chars() {
+        let is_space = c == ' ' || c == '\t';
+        if !(is_space && previous_was_space) {
+            result.push(if is_space { ' ' } else { c });
+        }
+        previous_was_space = is_space;
+    }
+
+    result
+}
+
+/// Upper-cases the first letter of the text and of each sentence.
+///
+/// A sentence starts after `.`, `!` or `?` followed by whitespace, so
+/// decimals such as "3.5" are left alone.
+fn capitalise_sentences(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut at_sentence_start = true;
+    let mut after_terminator = false;
+
+    for c in text.chars() {
+        if at_sentence_start && c.is_alphanumeric() {
+            result.extend(c.to_uppercase());
+            at_sentence_start = false;
+            continue;
+        }
+
+        if matches!(c, '.' | '!' | '?') {
+            after_terminator = true;
+        } else if c.is_whitespace() {
+            if after_terminator {
+                at_sentence_start = true;
+            }
+            after_terminator = false;
+        } else {
+            after_terminator = false;
+        }
+        result.push(c);
+    }
+
+    result
+}
+
+/// Removes `.,!?;:` at the end of words (before whitespace or the end of
+/// the text), so "3.14", "example.com" and "a::b" are left intact.
+fn strip_punctuation(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if matches!(c, '.' | ',' | '!' | '?' | ';' | ':') {
+            // Drop the whole run ("...", "?!") if it ends a word
+            let mut run = String::from(c);
+            while let Some(&next) = chars.peek() {
+                if !matches!(next, '.' | ',' | '!' | '?' | ';' | ':') {
+                    break;
+                }
+                run.push(next);
+                chars.next();
+            }
+            if chars.peek().is_some_and(|next| !next.is_whitespace()) {
+                result.push_str(&run);
+            }
+            continue;
+        }
+        result.push(c);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn processor(transforms: &[TextTransform]) -> TextPostProcessor {
+        TextPostProcessor::new(transforms).expect("transforms should compile")
+    }
+
+    #[test]
+    fn empty_chain_leaves_text_unchanged() {
+        assert_eq!(processor(&[]).process("  as is  "), "  as is  ");
+    }
+
+    #[test]
+    fn trims_and_collapses_whitespace() {
+        let p = processor(&[TextTransform::TrimWhitespace]);
+        assert_eq!(p.process("  hello    world \t"), "hello world");
+        assert_eq!(p.process("line one\n\nline  two"), "line one\n\nline two");
+    }
+
+    #[test]
+    fn default_matches_default_chain() {
+        let text = "  spaced   out  ";
+        assert_eq!(
+            TextPostProcessor::default().process(text),
+            processor(&TextTransform::default_chain()).process(text)
+        );
+    }
+
+    #[test]
+    fn capitalises_each_sentence() {
+        let p = processor(&[TextTransform::CapitaliseSentences]);
+        assert_eq!(
+            p.process("hello. is pi 3.14? yes! ok"),
+            "Hello. Is pi 3.14? Yes! Ok"
+        );
+    }
+
+    #[test]
+    fn strips_trailing_punctuation() {
+        let p = processor(&[TextTransform::StripPunctuation]);
+        assert_eq!(
+            p.process("Run cargo test, then git push. Really?! Yes..."),
+            "Run cargo test then git push Really Yes"
+        );
+        assert_eq!(
+            p.process("pi is 3.14 on example.com: a::b"),
+            "pi is 3.14 on example.com a::b"
+        );
+    }
+
+    #[test]
+    fn strips_filler_words() {
+        let p = processor(&[TextTransform::default_filler_words()]);
+        assert_eq!(
+            p.process("Um, I think uh we should go um."),
+            "I think we should go."
+        );
+        assert_eq!(p.process("umbrella hmmm"), "umbrella hmmm");
+    }
+
+    #[test]
+    fn applies_regex_replacements_in_order() {
+        let p = processor(&[
+            TextTransform::RegexReplace {
+                pattern: r"\bteh\b".to_string(),
+                replacement: "the".to_string(),
+            },
+            TextTransform::RegexReplace {
+                pattern: r"(\w+) dot com".to_string(),
+                replacement: "$1.com".to_string(),
+            },
+        ]);
+        assert_eq!(
+            p.process("teh site is example dot com"),
+            "the site is example.com"
+        );
+    }
+
+    #[test]
+    fn applies_literal_replacements() {
+        let p = processor(&[
+            TextTransform::Replace {
+                from: "speakr".to_string(),
+                to: "Speakr".to_string(),
+                case_sensitive: false,
+            },
+            TextTransform::Replace {
+                from: "new line".to_string(),
+                to: "\n".to_string(),
+                case_sensitive: false,
+            },
+            TextTransform::Replace {
+                from: "Em Dash".to_string(),
+                to: "—".to_string(),
+                case_sensitive: true,
+            },
+        ]);
+        assert_eq!(
+            p.process("SPEAKR works new line speakrs $1 em dash Em Dash"),
+            "Speakr works \n speakrs $1 em dash —"
+        );
+    }
+
+    #[test]
+    fn times_each_stage_in_order() {
+        let p = processor(&[
+            TextTransform::TrimWhitespace,
+            TextTransform::Replace {
+                from: "speakr".to_string(),
+                to: "Speakr".to_string(),
+                case_sensitive: false,
+            },
+            TextTransform::CapitaliseSentences,
+        ]);
+        let (text, stages) = p.process_timed("hello speakr");
+        assert_eq!(text, "Hello Speakr");
+        assert_eq!(
+            stages.iter().map(|s| s.name.as_str()).collect::<Vec<_>>(),
+            [
+                "Trim whitespace",
+                "Replace \"speakr\"",
+                "Capitalise sentences"
+            ]
+        );
+        assert_eq!(
+            stages.iter().map(|s| s.changed).collect::<Vec<_>>(),
+            [false, true, true]
+        );
+    }
+
+    #[test]
+    fn splits_line_local_steps_across_threads() {
+        let transforms = [
+            TextTransform::default_filler_words(),
+            TextTransform::Replace {
+                from: "speakr".to_string(),
+                to: "Speakr".to_string(),
+                case_sensitive: false,
+            },
+            TextTransform::StripPunctuation,
+            TextTransform::CapitaliseSentences,
+        ];
+        let text = "um\nhello speakr, um, this is line two.\n\
+                    uh third line?! um\n  er speakr fourth line...\nlast, um";
+        let sequential = processor(&transforms);
+        let mut concurrent = processor(&transforms);
+        concurrent.concurrent_min_bytes = 0;
+        concurrent.threads = 4;
+
+        let (expected, _) = sequential.process_timed(text);
+        let (actual, stages) = concurrent.process_timed(text);
+
+        assert_eq!(actual, expected);
+        assert_eq!(
+            stages.iter().map(|s| s.segments > 1).collect::<Vec<_>>(),
+            [true, true, true, false]
+        );
+    }
+
+    #[test]
+    fn keeps_short_text_on_one_thread() {
+        let mut p = processor(&[TextTransform::StripPunctuation]);
+        p.threads = 4;
+        let (text, stages) = p.process_timed("one.\ntwo.\nthree.");
+        assert_eq!(text, "one\ntwo\nthree");
+        assert_eq!(stages[0].segments, 1);
+    }
+
+    #[test]
+    fn rejects_invalid_patterns() {
+        let result = TextPostProcessor::new(&[TextTransform::RegexReplace {
+            pattern: "(unclosed".to_string(),
+            replacement: String::new(),
+        }]);
+        assert!(matches!(
+            result,
+            Err(PostProcessingError::InvalidPattern { .. })
+        ));
+    }
+}
@@ -0,0 +1,180 @@
+// =============================================================================
+//! Word-level streaming
+//!
+//! Lets dictated words appear in the target application while the user is
+//! still speaking. The recording so far is re-transcribed at a regular
+//! interval; every word except the last one of each hypothesis is treated as
+//! final and typed straight away (the last word may still be mid-utterance).
+//!
+//! Whisper sometimes revises words it has already produced once it hears
+//! more context. [`LiveTranscript`] remembers exactly what was typed and
+//! turns each new hypothesis into a [`TextEdit`]: how many characters to
+//! erase with backspace and what to type after that. When the recording
+//! ends, [`LiveTranscript::correction`] brings the typed text in line with
+//! the final, post-processed transcript.
+//!
+//! # Usage
+//!
+//! ```no_run
+//! use speakr_core::streaming::LiveTranscript;
+//!
+//! let mut live = LiveTranscript::default();
+//!
+//! // "wor" may still be a partial word, so only "Hello " is typed
+//! let edit = live.update("Hello wor");
+//! assert_eq!((edit.backspaces, edit.text.as_str()), (0, "Hello "));
+//!
+//! // The engine revised "Hello" to "Hello,"
+//! let edit = live.update("Hello, world and");
+//! assert_eq!((edit.backspaces, edit.text.as_str()), (1, ", world "));
+//!
+//! let edit = live.correction("Hello, world and more.");
+//! assert_eq!((edit.backspaces, edit.text.as_str()), (0, "and more."));
+//! ```
+// =============================================================================
+
+use std::time::Duration;
+
+/// How often the recording is re-transcribed while streaming words.
+pub const STREAMING_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A change to text already typed into the target application.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextEdit {
+    /// Characters to erase with backspace, from the end of the typed text.
+    pub backspaces: usize,
+    /// Text to type after erasing.
+    pub text: String,
+}
+
+impl TextEdit {
+    /// Returns `true` if the edit changes nothing.
+    pub fn is_empty(&self) -> bool {
+        self.backspaces == 0 && self.text.is_empty()
+    }
+}
+
+/// Tracks the text typed during a streaming dictation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LiveTranscript {
+    typed: String,
+}
+
+impl LiveTranscript {
+    /// Returns the text typed so far.
+    pub fn typed(&self) -> &str {
+        &self.typed
+    }
+
+    /// Types the finalised words of a new hypothesis.
+    ///
+    /// All words except the last are final and are followed by a space so
+    /// the next word can be appended. A hypothesis that only drops words
+    /// from the end of the typed text is ignored rather than erasing them;
+    /// the engine usually brings them back once it hears more.
+    ///
+    /// # Arguments
+    ///
+    /// * `hypothesis` - The transcript of the recording so far
+    ///
+    /// # Returns
+    ///
+    /// The edit to apply to the target application, which is already
+    /// reflected in [`typed`](Self::typed).
+    pub fn update(&mut self, hypothesis: &str) -> TextEdit {
+        let words: Vec<&str> = hypothesis.split_whitespace().collect();
+        let Some((_, finalised)) = words.split_last() else {
+            return TextEdit::default();
+        };
+        let target: String = finalised.iter().map(|word| format!("{word} ")).collect();
+
+        if self.typed.starts_with(&target) {
+            return TextEdit::default();
+        }
+        self.edit_to(&target)
+    }
+
+    /// Returns the edit that turns the typed text into the final text.
+    ///
+    /// # Arguments
+    ///
+    /// * `final_text` - The complete text that should end up in the target
+    pub fn correction(&self, final_text: &str) -> TextEdit {
+        diff(&self.typed, final_text)
+    }
+
+    /// Records `target` as typed and returns the edit that produced it.
+    fn edit_to(&mut self, target: &str) -> TextEdit {
+        let edit = diff(&self.typed, target);
+        self.typed = target.to_string();
+        edit
+    }
+}
+
+/// The edit turning `typed` into `target`, keeping their common prefix.
+fn diff(typed: &str, target: &str) -> TextEdit {
+    let common = typed
+        .char_indices()
+        .zip(target.chars())
+        .find(|((_, typed_char), target_char)| typed_char != target_char)
+        .map_or_else(|| typed.len().min(target.len()), |((index, _), _)| index);
+
+    TextEdit {
+        backspaces: typed[common..].chars().count(),
+        text: target[common..].to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(backspaces: usize, text: &str) -> TextEdit {
+        TextEdit {
+            backspaces,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn types_all_but_the_last_word() {
+        let mut live = LiveTranscript::default();
+
+        assert!(live.update("  ").is_empty());
+        assert!(live.update("Hel").is_empty());
+        assert_eq!(live.update(" Hello there"), edit(0, "Hello "));
+        assert_eq!(live.update(" Hello there my friend"), edit(0, "there my "));
+        assert_eq!(live.typed(), "Hello there my ");
+    }
+
+    #[test]
+    fn revisions_erase_back_to_the_first_changed_character() {
+        let mut live = LiveTranscript::default();
+        live.update("I scream for ice");
+
+        assert_eq!(
+            live.update("Ice cream for ice cream"),
+            edit(12, "ce cream for ice ")
+        );
+        assert_eq!(live.typed(), "Ice cream for ice ");
+    }
+
+    #[test]
+    fn shorter_hypotheses_do_not_erase_typed_words() {
+        let mut live = LiveTranscript::default();
+        live.update("one two three four");
+
+        assert!(live.update("one two").is_empty());
+        assert_eq!(live.typed(), "one two three ");
+    }
+
+    #[test]
+    fn correction_reaches_the_final_text() {
+        let mut live = LiveTranscript::default();
+        live.update("héllo wörld again");
+
+        assert_eq!(live.correction("héllo wörld again."), edit(0, "again."));
+        assert_eq!(live.correction("Héllo."), edit(12, "Héllo."));
+        assert_eq!(live.correction(""), edit(12, ""));
+    }
+}
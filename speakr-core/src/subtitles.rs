@@ -0,0 +1,219 @@
+// =============================================================================
+//! Subtitle export
+//!
+//! Renders a [`TranscriptionResult`] as SubRip (`.srt`) or WebVTT (`.vtt`)
+//! subtitles, one cue per transcription segment. Results without segments
+//! become a single cue spanning the whole recording.
+//!
+//! The same rendering is available as methods on the result through
+//! [`SubtitleExport`].
+//!
+//! # Usage
+//!
+//! ```no_run
+//! use speakr_core::subtitles::{to_srt, SubtitleExport};
+//! use speakr_types::{ModelId, SubtitleFormat, TranscriptionResult};
+//! use std::time::Duration;
+//!
+//! let result = TranscriptionResult {
+//!     text: "Hello world".to_string(),
+//!     language: None,
+//!     confidence: 0.9,
+//!     avg_logprob: None,
+//!     processing_time: Duration::ZERO,
+//!     memory_delta_bytes: 0,
+//!     model_used: ModelId::new("small"),
+//!     segments: vec![],
+//! };
+//!
+//! assert_eq!(
+//!     to_srt(&result, Duration::from_millis(1_500)),
+//!     "1\n00:00:00,000 --> 00:00:01,500\nHello world\n"
+//! );
+//! assert_eq!(
+//!     result.to_subtitles(SubtitleFormat::WebVtt, Duration::from_millis(1_500)),
+//!     "WEBVTT\n\n00:00:00.000 --> 00:00:01.500\nHello world\n"
+//! );
+//! ```
+// =============================================================================
+
+use speakr_types::{SubtitleFormat, TranscriptionResult};
+use std::time::Duration;
+
+/// Subtitle rendering for transcription results.
+pub trait SubtitleExport {
+    /// Renders the transcription as subtitles in `format`.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The caption format to produce
+    /// * `duration` - Length of the recording, used when there are no segments
+    fn to_subtitles(&self, format: SubtitleFormat, duration: Duration) -> String;
+}
+
+impl SubtitleExport for TranscriptionResult {
+    fn to_subtitles(&self, format: SubtitleFormat, duration: Duration) -> String {
+        match format {
+            SubtitleFormat::Srt => to_srt(self, duration),
+            SubtitleFormat::WebVtt => to_vtt(self, duration),
+        }
+    }
+}
+
+/// Renders a transcription as SubRip subtitles.
+///
+/// # Arguments
+///
+/// * `result` - The transcription to render
+/// * `duration` - Length of the recording, used when there are no segments
+///
+/// # Returns
+///
+/// The subtitles, or an empty string if nothing was transcribed.
+pub fn to_srt(result: &TranscriptionResult, duration: Duration) -> String {
+    cues(result, duration)
+        .into_iter()
+        .enumerate()
+        .map(|(index, (start, end, text))| {
+            format!(
+                "{}\n{} --> {}\n{}\n",
+                index + 1,
+                timestamp(start, ','),
+                timestamp(end, ','),
+                text
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders a transcription as WebVTT subtitles.
+///
+/// # Arguments
+///
+/// * `result` - The transcription to render
+/// * `duration` - Length of the recording, used when there are no segments
+///
+/// # Returns
+///
+/// The subtitles; just the `WEBVTT` header if nothing was transcribed.
+pub fn to_vtt(result: &TranscriptionResult, duration: Duration) -> String {
+    let mut vtt = String::from("WEBVTT\n");
+    for (start, end, text) in cues(result, duration) {
+        vtt.push_str(&format!(
+            "\n{} --> {}\n{}\n",
+            timestamp(start, '.'),
+            timestamp(end, '.'),
+            text
+        ));
+    }
+    vtt
+}
+
+/// Returns the non-empty cues of a transcription as `(start, end, text)`.
+fn cues(result: &TranscriptionResult, duration: Duration) -> Vec<(Duration, Duration, &str)> {
+    let cues: Vec<(Duration, Duration, &str)> = if result.segments.is_empty() {
+        vec![(Duration::ZERO, duration, result.text.trim())]
+    } else {
+        result
+            .segments
+            .iter()
+            .map(|segment| (segment.start_time, segment.end_time, segment.text.trim()))
+            .collect()
+    };
+    cues.into_iter()
+        .filter(|(_, _, text)| !text.is_empty())
+        .collect()
+}
+
+/// Formats a time offset as `HH:MM:SS` followed by `separator` and
+/// milliseconds (`,` for SubRip, `.` for WebVTT).
+fn timestamp(offset: Duration, separator: char) -> String {
+    let millis = offset.as_millis();
+    format!(
+        "{:02}:{:02}:{:02}{separator}{:03}",
+        millis / 3_600_000,
+        millis / 60_000 % 60,
+        millis / 1_000 % 60,
+        millis % 1_000
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use speakr_types::{ModelId, TranscriptionSegment};
+
+    fn segment(start_ms: u64, end_ms: u64, text: &str) -> TranscriptionSegment {
+        TranscriptionSegment {
+            text: text.to_string(),
+            start_time: Duration::from_millis(start_ms),
+            end_time: Duration::from_millis(end_ms),
+            confidence: 0.9,
+            words: vec![],
+        }
+    }
+
+    fn result(text: &str, segments: Vec<TranscriptionSegment>) -> TranscriptionResult {
+        TranscriptionResult {
+            text: text.to_string(),
+            language: None,
+            confidence: 0.9,
+            avg_logprob: None,
+            processing_time: Duration::ZERO,
+            memory_delta_bytes: 0,
+            model_used: ModelId::new("small"),
+            segments,
+        }
+    }
+
+    #[test]
+    fn renders_one_numbered_cue_per_segment() {
+        let result = result(
+            "Hello there. General Kenobi.",
+            vec![
+                segment(0, 1_200, " Hello there."),
+                segment(1_200, 3_723_456, " General Kenobi."),
+            ],
+        );
+
+        assert_eq!(
+            to_srt(&result, Duration::ZERO),
+            "1\n00:00:00,000 --> 00:00:01,200\nHello there.\n\n\
+             2\n00:00:01,200 --> 01:02:03,456\nGeneral Kenobi.\n"
+        );
+    }
+
+    #[test]
+    fn renders_webvtt_with_header_and_dotted_timestamps() {
+        let result = result(
+            "Hello there. General Kenobi.",
+            vec![
+                segment(0, 1_200, " Hello there."),
+                segment(1_200, 3_723_456, " General Kenobi."),
+            ],
+        );
+
+        assert_eq!(
+            result.to_subtitles(SubtitleFormat::WebVtt, Duration::ZERO),
+            "WEBVTT\n\n\
+             00:00:00.000 --> 00:00:01.200\nHello there.\n\n\
+             00:00:01.200 --> 01:02:03.456\nGeneral Kenobi.\n"
+        );
+        assert_eq!(
+            result.to_subtitles(SubtitleFormat::Srt, Duration::ZERO),
+            to_srt(&result, Duration::ZERO)
+        );
+        assert_eq!(result.end_time(), Duration::from_millis(3_723_456));
+    }
+
+    #[test]
+    fn empty_transcripts_have_no_cues() {
+        assert_eq!(to_srt(&result("  ", vec![]), Duration::from_secs(3)), "");
+        assert_eq!(
+            to_srt(&result("", vec![segment(0, 10, "")]), Duration::ZERO),
+            ""
+        );
+        assert_eq!(to_vtt(&result("", vec![]), Duration::ZERO), "WEBVTT\n");
+    }
+}
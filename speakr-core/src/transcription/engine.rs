@@ -7,21 +7,64 @@
 //! integration.
 
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::Instant;
 
-use crate::{model::Model, transcription::models::ModelManager};
+use crate::{
+    model::Model,
+    transcription::{language::LanguageDetector, models::ModelManager},
+};
 use speakr_types::{
-    ModelSize, PerformanceMode, TranscriptionConfig, TranscriptionError, TranscriptionResult,
+    ModelId, PerformanceMode, TranscriptionConfig, TranscriptionError, TranscriptionResult,
 };
 use sysinfo::System;
 use tokio::task;
+use zeroize::Zeroizing;
+
+/// Models tried, in order, when the selected model does not fit in memory.
+const MEMORY_FALLBACK_MODELS: [Model; 2] = [Model::Medium, Model::Small];
+
+/// Look up the catalogue [`Model`] for a configured [`ModelId`].
+fn catalogue_model(id: &ModelId) -> Result<Model, TranscriptionError> {
+    Model::from_id(id).ok_or_else(|| TranscriptionError::ModelNotFound { model: id.clone() })
+}
+
+/// Cancels an in-flight transcription.
+///
+/// Clones share the same flag, so one clone can be handed to the engine and
+/// another kept by whoever may cancel. Once [`abort`](Self::abort) is called
+/// the transcription returns [`TranscriptionError::Aborted`] without waiting
+/// for inference to finish; whisper.cpp polls the flag through its abort
+/// callback (`FullParams::set_abort_callback_safe`).
+#[derive(Debug, Clone, Default)]
+pub struct TranscriptionHandle {
+    aborted: Arc<AtomicBool>,
+}
+
+impl TranscriptionHandle {
+    /// Create a handle that has not been aborted.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Interrupt the transcription this handle was passed to.
+    pub fn abort(&self) {
+        self.aborted.store(true, Ordering::SeqCst);
+    }
 
-/// Map a high-level [`ModelSize`] to a concrete [`Model`] file.
-fn map_size_to_model(size: &ModelSize) -> Model {
-    match size {
-        ModelSize::Small => Model::Small,
-        ModelSize::Medium => Model::Medium,
-        ModelSize::Large => Model::LargeV3Turbo,
+    /// Returns `true` once [`abort`](Self::abort) has been called.
+    pub fn is_aborted(&self) -> bool {
+        self.aborted.load(Ordering::SeqCst)
+    }
+
+    /// Fail with [`TranscriptionError::Aborted`] if the handle was aborted.
+    fn check(&self) -> Result<(), TranscriptionError> {
+        if self.is_aborted() {
+            tracing::debug!("Transcription aborted");
+            return Err(TranscriptionError::Aborted);
+        }
+        Ok(())
     }
 }
 
@@ -32,6 +75,7 @@ pub struct TranscriptionEngine {
     config: TranscriptionConfig,
     model_manager: ModelManager,
     active_model: Model,
+    language_detector: LanguageDetector,
 }
 
 impl TranscriptionEngine {
@@ -54,45 +98,47 @@ impl TranscriptionEngine {
         use tracing::{error, warn};
 
         let mut cfg = config;
-        let mut model = map_size_to_model(&cfg.model_size);
+        let mut model = catalogue_model(&cfg.model)?;
+        let language_detector = LanguageDetector::new();
+
+        // 0. Reject unsupported languages before touching any model files.
+        language_detector.resolve(&cfg, &[])?;
 
         // 1. Ensure the model file is present – log but continue, we may fall back.
-        if let Err(e) = ensure_model_available(&model_manager, &model, &cfg.model_size) {
+        if let Err(e) = ensure_model_available(&model_manager, &model) {
             error!(?e, "Primary model not available – attempting fallback");
         }
 
-        // 2. Check memory budget and downgrade model size if required.
+        // 2. Check memory budget and fall back to a smaller model if required.
         let sys = System::new_all();
         let total_mb = ((sys.total_memory() + sys.total_swap()) / 1024) as u32;
         let budget_mb = ((total_mb as f32) * 0.75) as u32; // leave 25% headroom
 
         if model.memory_usage_mb() > budget_mb {
             warn!(
-                model_size = ?cfg.model_size,
+                model = %cfg.model,
                 required_mb = model.memory_usage_mb(),
                 budget_mb,
-                "Model exceeds memory budget – falling back to smaller size"
+                "Model exceeds memory budget – falling back to smaller model"
             );
 
-            cfg.model_size = match cfg.model_size {
-                ModelSize::Large => ModelSize::Medium,
-                ModelSize::Medium => ModelSize::Small,
-                ModelSize::Small => {
-                    return Err(TranscriptionError::InsufficientMemory {
-                        model_size: cfg.model_size.clone(),
-                    });
-                }
-            };
-            model = map_size_to_model(&cfg.model_size);
+            model = MEMORY_FALLBACK_MODELS
+                .into_iter()
+                .find(|fallback| fallback.memory_usage_mb() < model.memory_usage_mb())
+                .ok_or_else(|| TranscriptionError::InsufficientMemory {
+                    model: cfg.model.clone(),
+                })?;
+            cfg.model = model.id();
         }
 
         // 3. Final availability check for the selected model.
-        ensure_model_available(&model_manager, &model, &cfg.model_size)?;
+        ensure_model_available(&model_manager, &model)?;
 
         Ok(Self {
             config: cfg,
             model_manager,
             active_model: model,
+            language_detector,
         })
     }
 
@@ -102,12 +148,12 @@ impl TranscriptionEngine {
     }
 
     /// Switch the model at runtime (no automatic downloads).
-    pub fn switch_model(&mut self, new_size: ModelSize) -> Result<(), TranscriptionError> {
-        let new_model = map_size_to_model(&new_size);
-        ensure_model_available(&self.model_manager, &new_model, &new_size)?;
+    pub fn switch_model(&mut self, new_model_id: ModelId) -> Result<(), TranscriptionError> {
+        let new_model = catalogue_model(&new_model_id)?;
+        ensure_model_available(&self.model_manager, &new_model)?;
 
         self.active_model = new_model;
-        self.config.model_size = new_size;
+        self.config.model = new_model_id;
         Ok(())
     }
 
@@ -116,21 +162,60 @@ impl TranscriptionEngine {
         self.config.language = language;
     }
 
+    /// Update the initial prompt used to bias recognition (or `None` to clear).
+    pub fn set_initial_prompt(&mut self, prompt: Option<String>) {
+        self.config.initial_prompt = prompt;
+    }
+
     /// Update the performance mode (speed ↔ accuracy trade-off).
     pub fn set_performance_mode(&mut self, mode: PerformanceMode) {
         self.config.performance_mode = mode;
     }
 
+    /// Apply the per-dictation options of `config` (language, initial prompt,
+    /// performance mode) while keeping the loaded model.
+    pub fn apply_options(&mut self, config: &TranscriptionConfig) {
+        self.config.language = config.language.clone();
+        self.config.auto_detect_language = config.auto_detect_language;
+        self.config.initial_prompt = config.initial_prompt.clone();
+        self.config.performance_mode = config.performance_mode.clone();
+    }
+
     /// *Blocking* transcription API – returns once processing is finished.
-    pub fn transcribe(&self, _samples: &[i16]) -> Result<TranscriptionResult, TranscriptionError> {
+    pub fn transcribe(&self, samples: &[i16]) -> Result<TranscriptionResult, TranscriptionError> {
+        self.transcribe_with_handle(samples, &TranscriptionHandle::new())
+    }
+
+    /// Like [`transcribe`](Self::transcribe), but stops early with
+    /// [`TranscriptionError::Aborted`] once `handle` is aborted.
+    pub fn transcribe_with_handle(
+        &self,
+        samples: &[i16],
+        handle: &TranscriptionHandle,
+    ) -> Result<TranscriptionResult, TranscriptionError> {
+        handle.check()?;
+
         // --------------------------- Instrumentation ---------------------------
         let mut sys = System::new();
         sys.refresh_memory();
         let mem_before = sys.used_memory();
         let start = Instant::now();
 
+        // --------------------------- Language ---------------------------------
+        let language = self.language_detector.resolve(&self.config, samples)?;
+
         // --------------------------- Placeholder inference --------------------
+        // The initial prompt is handed to Whisper as decoding context
+        // (`FullParams::set_initial_prompt`) once inference is wired up, and
+        // `handle.is_aborted` becomes its abort callback.
+        if let Some(prompt) = self.config.initial_prompt.as_deref() {
+            tracing::debug!(
+                prompt_chars = prompt.chars().count(),
+                "Using vocabulary initial prompt"
+            );
+        }
         let text_stub = "<stub – transcription engine not yet wired to whisper-rs>".to_string();
+        handle.check()?;
 
         // --------------------------- Metrics ----------------------------------
         let duration = start.elapsed();
@@ -140,11 +225,12 @@ impl TranscriptionEngine {
 
         Ok(TranscriptionResult {
             text: text_stub,
-            language: self.config.language.clone(),
+            language,
             confidence: 0.0,
+            avg_logprob: None,
             processing_time: duration,
             memory_delta_bytes: mem_delta_bytes,
-            model_used: self.config.model_size.clone(),
+            model_used: self.config.model.clone(),
             segments: vec![],
         })
     }
@@ -153,9 +239,25 @@ impl TranscriptionEngine {
     pub async fn transcribe_async(
         &self,
         samples: Vec<i16>,
+    ) -> Result<TranscriptionResult, TranscriptionError> {
+        self.transcribe_async_with_handle(samples, TranscriptionHandle::new())
+            .await
+    }
+
+    /// Like [`transcribe_async`](Self::transcribe_async), but abortable.
+    ///
+    /// Dropping the returned future does not stop the background thread;
+    /// call [`TranscriptionHandle::abort`] to free the CPU immediately. The
+    /// samples are zeroed as soon as the engine is done with them, whether
+    /// transcription succeeded, failed or was aborted.
+    pub async fn transcribe_async_with_handle(
+        &self,
+        samples: Vec<i16>,
+        handle: TranscriptionHandle,
     ) -> Result<TranscriptionResult, TranscriptionError> {
         let engine_clone = self.clone();
-        task::spawn_blocking(move || engine_clone.transcribe(&samples))
+        let samples = Zeroizing::new(samples);
+        task::spawn_blocking(move || engine_clone.transcribe_with_handle(&samples, &handle))
             .await
             .map_err(|e| TranscriptionError::ProcessingFailed(e.to_string()))?
     }
@@ -170,11 +272,7 @@ impl Default for TranscriptionEngine {
 // -----------------------------------------------------------------------------
 // Helpers
 // -----------------------------------------------------------------------------
-fn ensure_model_available(
-    manager: &ModelManager,
-    model: &Model,
-    size: &ModelSize,
-) -> Result<(), TranscriptionError> {
+fn ensure_model_available(manager: &ModelManager, model: &Model) -> Result<(), TranscriptionError> {
     let filename = format!("ggml-{}.bin", model.filename());
     let path: PathBuf = manager.cache_dir().join(filename);
 
@@ -184,40 +282,36 @@ fn ensure_model_available(
     // 1. If the file is missing entirely, surface a ModelNotFound error (UI will guide the user).
     if !path.exists() {
         tracing::error!(missing_model=?path, "Model file not found");
-        return Err(TranscriptionError::ModelNotFound {
-            model_size: size.clone(),
-        });
+        return Err(TranscriptionError::ModelNotFound { model: model.id() });
     }
 
-    // 2. Verify checksum – treat mismatch as corruption and trigger re-download.
+    // 2. Verify the checksum – a mismatch means the file is corrupt and must be
+    //    downloaded again, which the UI offers to do.
     #[cfg(test)]
     {
-        // Skip expensive network access during unit tests – assume model is valid if present.
+        // Skip hashing during unit tests – assume model is valid if present.
         Ok(())
     }
     #[cfg(not(test))]
     {
         let rt = Runtime::new().expect("tokio runtime");
         let is_valid = rt
-            .block_on(async { manager.is_available(model, true).await })
-            .unwrap_or(false);
+            .block_on(async {
+                let mut metadata = crate::model::ModelMetadata::for_model(model);
+                // Verify against the catalogue the file was downloaded from
+                if let Some(source) = manager.model_sources().await.remove(&metadata.filename) {
+                    metadata.sha256 = source.sha;
+                }
+                manager.verify_model(&metadata).await
+            })
+            .map_err(|e| TranscriptionError::ModelLoadingFailed(e.to_string()))?;
 
         if is_valid {
             return Ok(());
         }
 
-        // 3. Attempt automatic re-download for corrupted files (with retry).
-        tracing::warn!(
-            ?path,
-            "Model checksum mismatch – attempting automatic re-download"
-        );
-        match rt.block_on(async { manager.download_model_with_retry(model, 2).await }) {
-            Ok(_) => Ok(()),
-            Err(e) => {
-                tracing::error!(?e, "Automatic model download failed");
-                Err(TranscriptionError::DownloadFailed(e.to_string()))
-            }
-        }
+        tracing::error!(?path, "Model checksum mismatch");
+        Err(TranscriptionError::CorruptModel { model: model.id() })
     }
 }
 
@@ -245,7 +339,7 @@ mod tests {
 
         let manager = ModelManager::with_cache_dir(tmp.path().to_path_buf());
         let cfg = TranscriptionConfig {
-            model_size: ModelSize::Small,
+            model: Model::Small.id(),
             ..Default::default()
         };
 
@@ -258,7 +352,7 @@ mod tests {
         let tmp = TempDir::new().unwrap();
         let manager = ModelManager::with_cache_dir(tmp.path().to_path_buf());
         let cfg = TranscriptionConfig {
-            model_size: ModelSize::Medium,
+            model: Model::Medium.id(),
             ..Default::default()
         };
 
@@ -269,6 +363,30 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn engine_uses_any_catalogue_model() {
+        let tmp = TempDir::new().unwrap();
+        dummy_model_file(&tmp, &Model::BaseEnQuantizedQ5_1);
+
+        let manager = ModelManager::with_cache_dir(tmp.path().to_path_buf());
+        let cfg = TranscriptionConfig {
+            model: ModelId::new("base.en-q5_1"),
+            ..Default::default()
+        };
+        let engine = TranscriptionEngine::with_config_and_manager(cfg, manager).expect("engine");
+        assert_eq!(engine.config().model.as_str(), "base.en-q5_1");
+
+        let manager = ModelManager::with_cache_dir(tmp.path().to_path_buf());
+        let cfg = TranscriptionConfig {
+            model: ModelId::new("not-a-model"),
+            ..Default::default()
+        };
+        assert!(matches!(
+            TranscriptionEngine::with_config_and_manager(cfg, manager),
+            Err(TranscriptionError::ModelNotFound { model }) if model.as_str() == "not-a-model"
+        ));
+    }
+
     #[test]
     fn can_switch_models_at_runtime() {
         let tmp = TempDir::new().unwrap();
@@ -277,15 +395,15 @@ mod tests {
 
         let manager = ModelManager::with_cache_dir(tmp.path().to_path_buf());
         let cfg = TranscriptionConfig {
-            model_size: ModelSize::Small,
+            model: Model::Small.id(),
             ..Default::default()
         };
         let mut engine =
             TranscriptionEngine::with_config_and_manager(cfg, manager).expect("engine init");
 
-        assert_eq!(engine.config().model_size, ModelSize::Small);
-        engine.switch_model(ModelSize::Medium).expect("switch ok");
-        assert_eq!(engine.config().model_size, ModelSize::Medium);
+        assert_eq!(engine.config().model, Model::Small.id());
+        engine.switch_model(Model::Medium.id()).expect("switch ok");
+        assert_eq!(engine.config().model, Model::Medium.id());
     }
 
     #[tokio::test(flavor = "multi_thread")]
@@ -295,7 +413,7 @@ mod tests {
 
         let manager = ModelManager::with_cache_dir(tmp.path().to_path_buf());
         let cfg = TranscriptionConfig {
-            model_size: ModelSize::Small,
+            model: Model::Small.id(),
             ..Default::default()
         };
         let engine =
@@ -308,4 +426,89 @@ mod tests {
             .expect("transcription");
         assert!(result.text.contains("stub"));
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn aborted_handle_stops_transcription() {
+        let tmp = TempDir::new().unwrap();
+        dummy_model_file(&tmp, &Model::Small);
+
+        let manager = ModelManager::with_cache_dir(tmp.path().to_path_buf());
+        let cfg = TranscriptionConfig {
+            model: Model::Small.id(),
+            ..Default::default()
+        };
+        let engine =
+            TranscriptionEngine::with_config_and_manager(cfg, manager).expect("engine init");
+
+        let handle = TranscriptionHandle::new();
+        let shared = handle.clone();
+        assert!(!shared.is_aborted());
+        handle.abort();
+        assert!(shared.is_aborted());
+
+        let result = engine
+            .transcribe_async_with_handle(vec![0_i16; 16000], shared)
+            .await;
+        assert!(matches!(result, Err(TranscriptionError::Aborted)));
+    }
+
+    #[test]
+    fn transcription_reports_selected_language() {
+        let tmp = TempDir::new().unwrap();
+        dummy_model_file(&tmp, &Model::Small);
+
+        let manager = ModelManager::with_cache_dir(tmp.path().to_path_buf());
+        let cfg = TranscriptionConfig {
+            model: Model::Small.id(),
+            language: Some("de".to_string()),
+            auto_detect_language: false,
+            ..Default::default()
+        };
+        let engine =
+            TranscriptionEngine::with_config_and_manager(cfg, manager).expect("engine init");
+
+        let result = engine.transcribe(&[0_i16; 16000]).expect("transcription");
+        assert_eq!(result.language, Some("de".to_string()));
+    }
+
+    #[test]
+    fn engine_rejects_unsupported_language() {
+        let tmp = TempDir::new().unwrap();
+        dummy_model_file(&tmp, &Model::Small);
+
+        let manager = ModelManager::with_cache_dir(tmp.path().to_path_buf());
+        let cfg = TranscriptionConfig {
+            model: Model::Small.id(),
+            language: Some("klingon".to_string()),
+            ..Default::default()
+        };
+
+        let engine = TranscriptionEngine::with_config_and_manager(cfg, manager);
+        assert!(matches!(
+            engine,
+            Err(TranscriptionError::UnsupportedLanguage { .. })
+        ));
+    }
+
+    #[test]
+    fn engine_keeps_initial_prompt() {
+        let tmp = TempDir::new().unwrap();
+        dummy_model_file(&tmp, &Model::Small);
+
+        let manager = ModelManager::with_cache_dir(tmp.path().to_path_buf());
+        let cfg = TranscriptionConfig {
+            model: Model::Small.id(),
+            initial_prompt: Some("Speakr, Tauri".to_string()),
+            ..Default::default()
+        };
+        let mut engine =
+            TranscriptionEngine::with_config_and_manager(cfg, manager).expect("engine init");
+
+        assert_eq!(
+            engine.config().initial_prompt.as_deref(),
+            Some("Speakr, Tauri")
+        );
+        engine.set_initial_prompt(None);
+        assert_eq!(engine.config().initial_prompt, None);
+    }
 }
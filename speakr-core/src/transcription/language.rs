@@ -1,12 +1,161 @@
 //! Language detection and language-specific handling.
 //!
 //! This module provides functionality for detecting the language of
-//! audio content and applying language-specific optimisations.
+//! audio content and applying language-specific optimisations. It also
+//! exposes the list of languages supported by Whisper so that the
+//! frontend can offer a language picker (including `"auto"`).
+
+use speakr_types::{LanguageOption, TranscriptionConfig, TranscriptionError, DEFAULT_LANGUAGE};
+
+/// Setting value that requests automatic language detection.
+pub const AUTO_DETECT: &str = DEFAULT_LANGUAGE;
+
+/// All languages supported by Whisper as `(code, English name)` pairs.
+///
+/// The order matches Whisper's own language table (roughly by amount of
+/// training data), which keeps the most common languages near the top of
+/// any picker built from it.
+pub const SUPPORTED_LANGUAGES: &[(&str, &str)] = &[
+    ("en", "English"),
+    ("zh", "Chinese"),
+    ("de", "German"),
+    ("es", "Spanish"),
+    ("ru", "Russian"),
+    ("ko", "Korean"),
+    ("fr", "French"),
+    ("ja", "Japanese"),
+    ("pt", "Portuguese"),
+    ("tr", "Turkish"),
+    ("pl", "Polish"),
+    ("ca", "Catalan"),
+    ("nl", "Dutch"),
+    ("ar", "Arabic"),
+    ("sv", "Swedish"),
+    ("it", "Italian"),
+    ("id", "Indonesian"),
+    ("hi", "Hindi"),
+    ("fi", "Finnish"),
+    ("vi", "Vietnamese"),
+    ("he", "Hebrew"),
+    ("uk", "Ukrainian"),
+    ("el", "Greek"),
+    ("ms", "Malay"),
+    ("cs", "Czech"),
+    ("ro", "Romanian"),
+    ("da", "Danish"),
+    ("hu", "Hungarian"),
+    ("ta", "Tamil"),
+    ("no", "Norwegian"),
+    ("th", "Thai"),
+    ("ur", "Urdu"),
+    ("hr", "Croatian"),
+    ("bg", "Bulgarian"),
+    ("lt", "Lithuanian"),
+    ("la", "Latin"),
+    ("mi", "Maori"),
+    ("ml", "Malayalam"),
+    ("cy", "Welsh"),
+    ("sk", "Slovak"),
+    ("te", "Telugu"),
+    ("fa", "Persian"),
+    ("lv", "Latvian"),
+    ("bn", "Bengali"),
+    ("sr", "Serbian"),
+    ("az", "Azerbaijani"),
+    ("sl", "Slovenian"),
+    ("kn", "Kannada"),
+    ("et", "Estonian"),
+    ("mk", "Macedonian"),
+    ("br", "Breton"),
+    ("eu", "Basque"),
+    ("is", "Icelandic"),
+    ("hy", "Armenian"),
+    ("ne", "Nepali"),
+    ("mn", "Mongolian"),
+    ("bs", "Bosnian"),
+    ("kk", "Kazakh"),
+    ("sq", "Albanian"),
+    ("sw", "Swahili"),
+    ("gl", "Galician"),
+    ("mr", "Marathi"),
+    ("pa", "Punjabi"),
+    ("si", "Sinhala"),
+    ("km", "Khmer"),
+    ("sn", "Shona"),
+    ("yo", "Yoruba"),
+    ("so", "Somali"),
+    ("af", "Afrikaans"),
+    ("oc", "Occitan"),
+    ("ka", "Georgian"),
+    ("be", "Belarusian"),
+    ("tg", "Tajik"),
+    ("sd", "Sindhi"),
+    ("gu", "Gujarati"),
+    ("am", "Amharic"),
+    ("yi", "Yiddish"),
+    ("lo", "Lao"),
+    ("uz", "Uzbek"),
+    ("fo", "Faroese"),
+    ("ht", "Haitian Creole"),
+    ("ps", "Pashto"),
+    ("tk", "Turkmen"),
+    ("nn", "Nynorsk"),
+    ("mt", "Maltese"),
+    ("sa", "Sanskrit"),
+    ("lb", "Luxembourgish"),
+    ("my", "Myanmar"),
+    ("bo", "Tibetan"),
+    ("tl", "Tagalog"),
+    ("mg", "Malagasy"),
+    ("as", "Assamese"),
+    ("tt", "Tatar"),
+    ("haw", "Hawaiian"),
+    ("ln", "Lingala"),
+    ("ha", "Hausa"),
+    ("ba", "Bashkir"),
+    ("jw", "Javanese"),
+    ("su", "Sundanese"),
+    ("yue", "Cantonese"),
+];
+
+/// Returns every Whisper-supported language as a serialisable option list.
+///
+/// # Returns
+///
+/// The supported languages in [`SUPPORTED_LANGUAGES`] order. The `"auto"`
+/// pseudo-language is *not* included; callers add it where appropriate.
+pub fn supported_languages() -> Vec<LanguageOption> {
+    SUPPORTED_LANGUAGES
+        .iter()
+        .map(|(code, name)| LanguageOption {
+            code: (*code).to_string(),
+            name: (*name).to_string(),
+        })
+        .collect()
+}
+
+/// Checks whether Whisper supports the given language code.
+pub fn is_supported(code: &str) -> bool {
+    SUPPORTED_LANGUAGES.iter().any(|(c, _)| *c == code)
+}
+
+/// Looks up the English display name for a language code.
+///
+/// # Returns
+///
+/// `None` if the code is not supported by Whisper.
+pub fn language_name(code: &str) -> Option<&'static str> {
+    SUPPORTED_LANGUAGES
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, name)| *name)
+}
 
 /// Language detector for automatic language identification.
 ///
 /// Analyses audio content to determine the most likely language,
 /// enabling language-specific processing optimisations.
+#[derive(Debug, Clone, Default)]
 pub struct LanguageDetector {
     // Future implementation will include language models,
     // confidence scoring, and detection algorithms
@@ -23,10 +172,88 @@ impl LanguageDetector {
             // Minimal implementation for now
         }
     }
+
+    /// Resolves the language to report for a transcription.
+    ///
+    /// An explicitly configured language always wins; otherwise the language
+    /// is detected from the samples when auto-detection is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TranscriptionError::UnsupportedLanguage`] if the configured
+    /// language is not supported by Whisper.
+    pub fn resolve(
+        &self,
+        config: &TranscriptionConfig,
+        samples: &[i16],
+    ) -> Result<Option<String>, TranscriptionError> {
+        match config.language.as_deref() {
+            Some(code) if code != AUTO_DETECT => {
+                if !is_supported(code) {
+                    return Err(TranscriptionError::UnsupportedLanguage {
+                        language: code.to_string(),
+                    });
+                }
+                Ok(Some(code.to_string()))
+            }
+            _ if config.auto_detect_language => Ok(self.detect(samples)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Detects the spoken language of the given samples.
+    ///
+    /// Whisper identifies the language as part of inference. Until the engine
+    /// is wired to `whisper-rs` there is no signal to detect from, so this
+    /// returns `None` (unknown).
+    pub fn detect(&self, _samples: &[i16]) -> Option<String> {
+        None
+    }
 }
 
-impl Default for LanguageDetector {
-    fn default() -> Self {
-        Self::new()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_all_whisper_languages() {
+        let languages = supported_languages();
+        assert_eq!(languages.len(), 100);
+        assert_eq!(languages[0].code, "en");
+        assert!(!is_supported(AUTO_DETECT));
+    }
+
+    #[test]
+    fn looks_up_language_names() {
+        assert_eq!(language_name("de"), Some("German"));
+        assert_eq!(language_name("yue"), Some("Cantonese"));
+        assert_eq!(language_name("xx"), None);
+    }
+
+    #[test]
+    fn resolve_prefers_explicit_language() {
+        let detector = LanguageDetector::new();
+        let config = TranscriptionConfig {
+            language: Some("fr".to_string()),
+            auto_detect_language: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            detector.resolve(&config, &[]).unwrap(),
+            Some("fr".to_string())
+        );
+    }
+
+    #[test]
+    fn resolve_rejects_unsupported_language() {
+        let detector = LanguageDetector::new();
+        let config = TranscriptionConfig {
+            language: Some("xx".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            detector.resolve(&config, &[]),
+            Err(TranscriptionError::UnsupportedLanguage { .. })
+        ));
     }
 }
@@ -17,13 +17,19 @@
 //! downloads, progress reporting, etc.
 // =========================================================================
 
+use std::collections::{BTreeMap, VecDeque};
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
+use crate::model::ModelMetadata;
 use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
 use sha2::{Digest, Sha256};
+use speakr_types::{DownloadProgress, InstalledModel, ModelSource, ModelsDiskUsage};
 use thiserror::Error;
 use tokio::fs;
-use tokio::io::AsyncWriteExt;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
 /// Errors returned by [`ModelManager`].
 #[derive(Debug, Error)]
@@ -39,6 +45,146 @@ pub enum ModelManagerError {
 
     #[error("checksum mismatch – expected {expected}, got {actual}")]
     ChecksumMismatch { expected: String, actual: String },
+
+    #[error("invalid model filename: {0}")]
+    InvalidFilename(String),
+}
+
+impl ModelManagerError {
+    /// Whether the model server could not be reached, as opposed to it
+    /// answering with an error status or sending a corrupt file.
+    ///
+    /// Such failures are worth retrying once the network is back.
+    pub fn is_connection_error(&self) -> bool {
+        matches!(self, Self::Network(e) if !e.is_status())
+    }
+}
+
+/// Span of recent samples used to compute download speed and ETA.
+pub const TRANSFER_RATE_WINDOW: Duration = Duration::from_secs(5);
+
+/// Download speed over a sliding time window.
+///
+/// Averaging over the last few seconds keeps the displayed speed and ETA
+/// steady while still reacting to changes in bandwidth.
+#[derive(Debug, Clone)]
+pub struct TransferRate {
+    window: Duration,
+    samples: VecDeque<(Instant, u64)>,
+}
+
+impl TransferRate {
+    /// Create a meter averaging over `window`.
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Record that `total_bytes` had been received at `now`.
+    pub fn record(&mut self, now: Instant, total_bytes: u64) {
+        self.samples.push_back((now, total_bytes));
+        // Keep one sample older than the window so the rate spans all of it
+        while self.samples.len() > 2
+            && self
+                .samples
+                .get(1)
+                .is_some_and(|(time, _)| now.duration_since(*time) >= self.window)
+        {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Bytes per second across the window, or `0` before two samples exist.
+    pub fn bytes_per_sec(&self) -> u64 {
+        let (Some((start, start_bytes)), Some((end, end_bytes))) =
+            (self.samples.front(), self.samples.back())
+        else {
+            return 0;
+        };
+        let elapsed = end.duration_since(*start).as_secs_f64();
+        if elapsed <= 0.0 {
+            return 0;
+        }
+        (end_bytes.saturating_sub(*start_bytes) as f64 / elapsed) as u64
+    }
+
+    /// Seconds needed to receive `remaining_bytes` at the current rate.
+    pub fn eta_secs(&self, remaining_bytes: u64) -> Option<u64> {
+        let rate = self.bytes_per_sec();
+        (rate > 0).then(|| remaining_bytes.div_ceil(rate))
+    }
+}
+
+impl Default for TransferRate {
+    fn default() -> Self {
+        Self::new(TRANSFER_RATE_WINDOW)
+    }
+}
+
+/// Incremental checksum for a download, chosen like
+/// [`ModelManager::verify_checksum`].
+enum ChecksumHasher {
+    Sha1(Sha1),
+    Sha256(Sha256),
+}
+
+impl ChecksumHasher {
+    fn for_expected(expected: &str) -> Self {
+        if expected.len() == 40 {
+            Self::Sha1(Sha1::new())
+        } else {
+            Self::Sha256(Sha256::new())
+        }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        match self {
+            Self::Sha1(hasher) => hasher.update(bytes),
+            Self::Sha256(hasher) => hasher.update(bytes),
+        }
+    }
+
+    fn finalize(self) -> String {
+        match self {
+            Self::Sha1(hasher) => hex::encode(hasher.finalize()),
+            Self::Sha256(hasher) => hex::encode(hasher.finalize()),
+        }
+    }
+}
+
+/// File in the cache directory recording which catalogue each model came from.
+const MODEL_SOURCES_FILE: &str = "model-sources.json";
+
+/// File in the cache directory recording model files that passed verification.
+const MODEL_VERIFICATIONS_FILE: &str = "model-verifications.json";
+
+/// A model file that matched its checksum when last hashed.
+///
+/// The result is reused while the file keeps the same size and modification
+/// time and the expected checksum is unchanged.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ModelVerification {
+    size_bytes: u64,
+    modified_ms: u64,
+    sha: String,
+}
+
+impl ModelVerification {
+    /// Describes the file at `path` as verified against `sha`.
+    async fn of_file(path: &Path, sha: &str) -> Result<Self, std::io::Error> {
+        let metadata = fs::metadata(path).await?;
+        let modified_ms = metadata
+            .modified()?
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis() as u64);
+        Ok(Self {
+            size_bytes: metadata.len(),
+            modified_ms,
+            sha: sha.to_ascii_lowercase(),
+        })
+    }
 }
 
 /// Manages local Whisper GGUF models.
@@ -85,6 +231,22 @@ impl ModelManager {
         &self,
         url: &str,
         expected_sha256: Option<&str>,
+    ) -> Result<PathBuf, ModelManagerError> {
+        self.download_model_with_progress(url, expected_sha256, |_| {})
+            .await
+    }
+
+    /// Download a model like [`ModelManager::download_model`], reporting
+    /// progress after every received chunk.
+    ///
+    /// The body is streamed to a temporary file and hashed as it arrives, so
+    /// large models are never held in memory. `expected_checksum` may be a
+    /// SHA-256 or a catalogue SHA-1 hash.
+    pub async fn download_model_with_progress(
+        &self,
+        url: &str,
+        expected_checksum: Option<&str>,
+        mut on_progress: impl FnMut(&DownloadProgress) + Send,
     ) -> Result<PathBuf, ModelManagerError> {
         // 1. Prepare cache directory ----------------------------------------------------------
         self.ensure_cache_dir().await?;
@@ -98,7 +260,7 @@ impl ModelManager {
 
         // 3. If the file already exists and (optionally) matches the checksum, short-circuit.
         if dest_path.exists() {
-            if let Some(expected) = expected_sha256 {
+            if let Some(expected) = expected_checksum {
                 if Self::verify_checksum(&dest_path, expected).await? {
                     return Ok(dest_path);
                 }
@@ -107,23 +269,54 @@ impl ModelManager {
             }
         }
 
-        // 4. Download the file ---------------------------------------------------------------
-        let bytes = if let Some(path_str) = url.strip_prefix("file://") {
+        // 4. Download the file into a tmp file, hashing as we go ------------------------------
+        let tmp_path = dest_path.with_extension("tmp");
+        let mut tmp_file = fs::File::create(&tmp_path).await?;
+        let mut hasher = expected_checksum.map(ChecksumHasher::for_expected);
+        let mut rate = TransferRate::default();
+        let mut progress = DownloadProgress {
+            filename: filename.to_string(),
+            downloaded_bytes: 0,
+            total_bytes: None,
+            bytes_per_sec: 0,
+            eta_secs: None,
+        };
+        let mut record_chunk = |chunk: &[u8], progress: &mut DownloadProgress| {
+            if let Some(hasher) = hasher.as_mut() {
+                hasher.update(chunk);
+            }
+            progress.downloaded_bytes += chunk.len() as u64;
+            rate.record(Instant::now(), progress.downloaded_bytes);
+            progress.bytes_per_sec = rate.bytes_per_sec();
+            progress.eta_secs = progress
+                .total_bytes
+                .and_then(|total| rate.eta_secs(total.saturating_sub(progress.downloaded_bytes)));
+        };
+
+        if let Some(path_str) = url.strip_prefix("file://") {
             // Local file copy for tests / offline scenarios
-            tokio::fs::read(path_str).await?
+            let bytes = tokio::fs::read(path_str).await?;
+            progress.total_bytes = Some(bytes.len() as u64);
+            record_chunk(&bytes, &mut progress);
+            tmp_file.write_all(&bytes).await?;
+            on_progress(&progress);
         } else {
-            reqwest::get(url)
-                .await?
-                .error_for_status()?
-                .bytes()
-                .await?
-                .to_vec()
-        };
+            let mut response = reqwest::get(url).await?.error_for_status()?;
+            progress.total_bytes = response.content_length();
+            while let Some(chunk) = response.chunk().await? {
+                record_chunk(&chunk, &mut progress);
+                tmp_file.write_all(&chunk).await?;
+                on_progress(&progress);
+            }
+        }
+        tmp_file.flush().await?;
+        drop(tmp_file); // close handle before rename
 
         // 5. Checksum validation -------------------------------------------------------------
-        if let Some(expected) = expected_sha256 {
-            let actual = hex::encode(Sha256::digest(&bytes));
+        if let (Some(expected), Some(hasher)) = (expected_checksum, hasher) {
+            let actual = hasher.finalize();
             if !actual.eq_ignore_ascii_case(expected) {
+                let _ = fs::remove_file(&tmp_path).await;
                 return Err(ModelManagerError::ChecksumMismatch {
                     expected: expected.to_string(),
                     actual,
@@ -131,12 +324,7 @@ impl ModelManager {
             }
         }
 
-        // 6. Persist to disk (atomically via a tmp file, then rename) ------------------------
-        let tmp_path = dest_path.with_extension("tmp");
-        let mut tmp_file = fs::File::create(&tmp_path).await?;
-        tmp_file.write_all(&bytes).await?;
-        tmp_file.flush().await?;
-        drop(tmp_file); // close handle before rename
+        // 6. Move into place atomically -----------------------------------------------------
         fs::rename(&tmp_path, &dest_path).await?;
 
         Ok(dest_path)
@@ -159,6 +347,9 @@ impl ModelManager {
         loop {
             match self.download_model(&url, Some(sha)).await {
                 Ok(path) => {
+                    if let Err(e) = self.record_source(&ModelMetadata::for_model(model)).await {
+                        tracing::warn!(?e, "Failed to record model source");
+                    }
                     return Ok(path);
                 }
                 Err(e) if attempt < retries => {
@@ -195,10 +386,39 @@ impl ModelManager {
             .join("models")
     }
 
-    /// Verify that the SHA-256 checksum of `path` matches `expected`.
+    /// Verify that the checksum of `path` matches `expected`.
+    ///
+    /// The algorithm is inferred from the length of `expected`: 40 hex digits
+    /// are treated as SHA-1 (the format of the built-in [`crate::model::Model`]
+    /// catalogue) and anything else as SHA-256 (the format of
+    /// [`crate::model::ModelMetadata::sha256`]).  The file is hashed in chunks
+    /// so multi-gigabyte models are never loaded into memory at once.
     async fn verify_checksum(path: &Path, expected: &str) -> Result<bool, std::io::Error> {
-        let bytes = fs::read(path).await?;
-        let actual = hex::encode(Sha256::digest(&bytes));
+        let mut file = fs::File::open(path).await?;
+        let mut buffer = vec![0u8; 1024 * 1024];
+
+        let actual = if expected.len() == 40 {
+            let mut hasher = Sha1::new();
+            loop {
+                let read = file.read(&mut buffer).await?;
+                if read == 0 {
+                    break;
+                }
+                hasher.update(&buffer[..read]);
+            }
+            hex::encode(hasher.finalize())
+        } else {
+            let mut hasher = Sha256::new();
+            loop {
+                let read = file.read(&mut buffer).await?;
+                if read == 0 {
+                    break;
+                }
+                hasher.update(&buffer[..read]);
+            }
+            hex::encode(hasher.finalize())
+        };
+
         Ok(actual.eq_ignore_ascii_case(expected))
     }
 
@@ -232,6 +452,40 @@ impl ModelManager {
         Ok(true)
     }
 
+    /// Return `true` if the file described by `metadata` matches its
+    /// `sha256` checksum.
+    ///
+    /// A file that passed is remembered in the cache directory, so it is
+    /// only hashed again once its size or modification time changes.  A
+    /// file that fails loses any earlier record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the file is missing or cannot be read.
+    pub async fn verify_model(&self, metadata: &ModelMetadata) -> Result<bool, std::io::Error> {
+        let path = self.cache_dir.join(&metadata.filename);
+        let current = ModelVerification::of_file(&path, &metadata.sha256).await?;
+        let mut verifications = self.model_verifications().await;
+        if verifications.get(&metadata.filename) == Some(&current) {
+            tracing::debug!(filename = %metadata.filename, "Model checksum already verified");
+            return Ok(true);
+        }
+
+        let valid = Self::verify_checksum(&path, &metadata.sha256).await?;
+        let changed = if valid {
+            verifications.insert(metadata.filename.clone(), current);
+            true
+        } else {
+            verifications.remove(&metadata.filename).is_some()
+        };
+        if changed {
+            if let Err(e) = self.save_model_verifications(&verifications).await {
+                tracing::warn!(?e, "Failed to record model verification");
+            }
+        }
+        Ok(valid)
+    }
+
     /// Return a list of *all* models that are currently cached on disk.
     pub async fn available_models(&self) -> Vec<crate::model::Model> {
         use crate::model::Model;
@@ -248,6 +502,189 @@ impl ModelManager {
         list
     }
 
+    // -------------------------------------------------------------------------
+    // Model storage management
+    // -------------------------------------------------------------------------
+
+    /// List every `ggml-*.bin` file in the cache directory.
+    ///
+    /// When `verify_hash` is `true` each file that belongs to the built-in
+    /// catalogue is hashed and compared against its published checksum; files
+    /// that are not in the catalogue always report `checksum_valid: None`.
+    /// Each file carries the catalogue it was downloaded from, if recorded.
+    /// A missing cache directory yields an empty list.
+    pub async fn installed_models(
+        &self,
+        verify_hash: bool,
+    ) -> Result<Vec<InstalledModel>, std::io::Error> {
+        use crate::model::Model;
+
+        if !self.cache_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let sources = self.model_sources().await;
+        let mut installed = Vec::new();
+        let mut entries = fs::read_dir(&self.cache_dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let filename = entry.file_name().to_string_lossy().into_owned();
+            if !Self::is_model_filename(&filename) {
+                continue;
+            }
+
+            let metadata = entry.metadata().await?;
+            if !metadata.is_file() {
+                continue;
+            }
+
+            let catalogue_model =
+                Model::iter().find(|model| format!("ggml-{}.bin", model.filename()) == filename);
+            let checksum_valid = match &catalogue_model {
+                Some(model) if verify_hash => {
+                    Some(Self::verify_checksum(&entry.path(), model.sha()).await?)
+                }
+                _ => None,
+            };
+
+            installed.push(InstalledModel {
+                source: sources.get(&filename).cloned(),
+                catalogue_sha: catalogue_model.map(|model| model.sha().to_string()),
+                filename,
+                size_bytes: metadata.len(),
+                checksum_valid,
+            });
+        }
+
+        installed.sort_by(|a, b| a.filename.cmp(&b.filename));
+        Ok(installed)
+    }
+
+    /// Delete an installed model file from the cache directory.
+    ///
+    /// `filename` must be a bare `ggml-*.bin` file name – anything containing
+    /// a path separator is rejected so callers cannot escape the cache
+    /// directory.
+    pub async fn delete_model(&self, filename: &str) -> Result<(), ModelManagerError> {
+        let is_bare_name = Path::new(filename)
+            .file_name()
+            .and_then(|name| name.to_str())
+            == Some(filename);
+        if !is_bare_name || !Self::is_model_filename(filename) {
+            return Err(ModelManagerError::InvalidFilename(filename.to_string()));
+        }
+
+        fs::remove_file(self.cache_dir.join(filename)).await?;
+        let mut sources = self.model_sources().await;
+        if sources.remove(filename).is_some() {
+            self.save_model_sources(&sources).await?;
+        }
+        let mut verifications = self.model_verifications().await;
+        if verifications.remove(filename).is_some() {
+            self.save_model_verifications(&verifications).await?;
+        }
+        Ok(())
+    }
+
+    /// Record the catalogue a model file was downloaded from.
+    ///
+    /// Replaces any earlier record for the same file name.
+    pub async fn record_source(&self, metadata: &ModelMetadata) -> Result<(), std::io::Error> {
+        let downloaded_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis() as u64);
+        let mut sources = self.model_sources().await;
+        sources.insert(
+            metadata.filename.clone(),
+            ModelSource {
+                git_ref: metadata.git_ref.clone(),
+                sha: metadata.sha256.clone(),
+                downloaded_at,
+            },
+        );
+        self.save_model_sources(&sources).await
+    }
+
+    /// Return the recorded catalogue of each downloaded model, by file name.
+    ///
+    /// A missing or unreadable record file yields an empty map.
+    pub async fn model_sources(&self) -> BTreeMap<String, ModelSource> {
+        let Ok(content) = fs::read_to_string(self.cache_dir.join(MODEL_SOURCES_FILE)).await else {
+            return BTreeMap::new();
+        };
+        serde_json::from_str(&content).unwrap_or_else(|e| {
+            tracing::warn!(?e, "Ignoring unreadable model source records");
+            BTreeMap::new()
+        })
+    }
+
+    /// Write the model source records to the cache directory.
+    async fn save_model_sources(
+        &self,
+        sources: &BTreeMap<String, ModelSource>,
+    ) -> Result<(), std::io::Error> {
+        self.ensure_cache_dir().await?;
+        let json = serde_json::to_string_pretty(sources)?;
+        fs::write(self.cache_dir.join(MODEL_SOURCES_FILE), json).await
+    }
+
+    /// Read the verification records, ignoring a missing or unreadable file.
+    async fn model_verifications(&self) -> BTreeMap<String, ModelVerification> {
+        let Ok(content) = fs::read_to_string(self.cache_dir.join(MODEL_VERIFICATIONS_FILE)).await
+        else {
+            return BTreeMap::new();
+        };
+        serde_json::from_str(&content).unwrap_or_else(|e| {
+            tracing::warn!(?e, "Ignoring unreadable model verification records");
+            BTreeMap::new()
+        })
+    }
+
+    /// Write the verification records to the cache directory.
+    async fn save_model_verifications(
+        &self,
+        verifications: &BTreeMap<String, ModelVerification>,
+    ) -> Result<(), std::io::Error> {
+        self.ensure_cache_dir().await?;
+        let json = serde_json::to_string_pretty(verifications)?;
+        fs::write(self.cache_dir.join(MODEL_VERIFICATIONS_FILE), json).await
+    }
+
+    /// Summarise how much disk space installed models are using.
+    pub async fn disk_usage(&self) -> Result<ModelsDiskUsage, std::io::Error> {
+        let installed = self.installed_models(false).await?;
+        Ok(ModelsDiskUsage {
+            directory: self.cache_dir.display().to_string(),
+            total_bytes: installed.iter().map(|model| model.size_bytes).sum(),
+            model_count: installed.len(),
+        })
+    }
+
+    /// Return the free space on the disk holding the cache directory.
+    ///
+    /// The directory need not exist yet: its nearest existing ancestor is
+    /// used.  Returns `None` if no mounted disk contains it.
+    pub fn available_space(&self) -> Option<u64> {
+        use sysinfo::Disks;
+
+        let dir = self
+            .cache_dir
+            .ancestors()
+            .find_map(|dir| dir.canonicalize().ok())?;
+        Disks::new_with_refreshed_list()
+            .list()
+            .iter()
+            .filter(|disk| dir.starts_with(disk.mount_point()))
+            .max_by_key(|disk| disk.mount_point().as_os_str().len())
+            .map(|disk| disk.available_space())
+    }
+
+    /// Return `true` if `filename` follows the `ggml-<name>.bin` convention.
+    fn is_model_filename(filename: &str) -> bool {
+        filename.len() > "ggml-.bin".len()
+            && filename.starts_with("ggml-")
+            && filename.ends_with(".bin")
+    }
+
     /// Recommend the most suitable model(s) based on available *system* memory.
     ///
     /// The heuristic is intentionally conservative: we require that a model's
@@ -11,14 +11,20 @@
 //! 3. **Standalone** – no external runtime required; works in `no_std` targets
 //!    that have access to `alloc` (memory readings are skipped in that case).
 //!
+//! [`LatencyTracker`] keeps a rolling window of per-dictation latencies
+//! (hot-key → recording, recording → first text, end to end), and the
+//! post-processing stages of the last dictation, for the debug panel.
+//!
 //! The implementation relies on the [`sysinfo`] crate for memory statistics on
 //! desktop targets.  If memory readings are not available the monitor gracefully
 //! falls back to measuring latency only.
 //!
 //! [`sysinfo`]: https://crates.io/crates/sysinfo
+use std::collections::VecDeque;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+use speakr_types::{LatencyStats, PerformanceMetrics, ProcessingStage};
 use sysinfo::System;
 
 /// A single performance data point recorded by [`PerformanceMonitor`].
@@ -118,3 +124,119 @@ impl PerformanceMonitor {
             .clear();
     }
 }
+
+// ============================================================================
+// Dictation latency
+// ============================================================================
+
+/// Number of dictations [`LatencyTracker::default`] keeps statistics for.
+pub const DEFAULT_LATENCY_WINDOW: usize = 50;
+
+/// Latencies measured for one dictation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DictationTimings {
+    /// From the hot-key press to recording starting.
+    pub hotkey_to_record_start: Duration,
+    /// From recording stopping to the first transcribed text.
+    pub record_stop_to_first_token: Duration,
+    /// From the hot-key press to the text being delivered.
+    pub end_to_end: Duration,
+}
+
+/// Rolling window of dictation latencies.
+///
+/// Keeps the timings of the last `window` dictations and summarises each
+/// latency as [`LatencyStats`].
+///
+/// ## Example
+/// ```
+/// use speakr_core::transcription::performance::{DictationTimings, LatencyTracker};
+/// use std::time::Duration;
+///
+/// let mut tracker = LatencyTracker::new(10);
+/// tracker.record(DictationTimings {
+///     hotkey_to_record_start: Duration::from_millis(40),
+///     record_stop_to_first_token: Duration::from_millis(600),
+///     end_to_end: Duration::from_millis(3_000),
+/// });
+///
+/// let metrics = tracker.metrics();
+/// assert_eq!(metrics.samples, 1);
+/// assert_eq!(metrics.end_to_end.last_ms, 3_000);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LatencyTracker {
+    window: usize,
+    timings: VecDeque<DictationTimings>,
+    processing_stages: Vec<ProcessingStage>,
+}
+
+impl Default for LatencyTracker {
+    fn default() -> Self {
+        Self::new(DEFAULT_LATENCY_WINDOW)
+    }
+}
+
+impl LatencyTracker {
+    /// Create a tracker keeping the last `window` dictations (at least one).
+    pub fn new(window: usize) -> Self {
+        let window = window.max(1);
+        Self {
+            window,
+            timings: VecDeque::with_capacity(window),
+            processing_stages: Vec::new(),
+        }
+    }
+
+    /// Add a dictation, dropping the oldest once the window is full.
+    pub fn record(&mut self, timings: DictationTimings) {
+        if self.timings.len() == self.window {
+            self.timings.pop_front();
+        }
+        self.timings.push_back(timings);
+    }
+
+    /// Replace the post-processing stages with those of the latest dictation.
+    pub fn record_processing_stages(&mut self, stages: Vec<ProcessingStage>) {
+        self.processing_stages = stages;
+    }
+
+    /// Summarise the dictations in the window.
+    pub fn metrics(&self) -> PerformanceMetrics {
+        PerformanceMetrics {
+            window: self.window,
+            samples: self.timings.len(),
+            hotkey_to_record_start: self.stats(|t| t.hotkey_to_record_start),
+            record_stop_to_first_token: self.stats(|t| t.record_stop_to_first_token),
+            end_to_end: self.stats(|t| t.end_to_end),
+            processing_stages: self.processing_stages.clone(),
+        }
+    }
+
+    fn stats(&self, latency: impl Fn(&DictationTimings) -> Duration) -> LatencyStats {
+        let values: Vec<u64> = self.timings.iter().map(|t| millis(latency(t))).collect();
+        latency_stats(&values)
+    }
+}
+
+/// Summarise latencies given in milliseconds, oldest first.
+fn latency_stats(values: &[u64]) -> LatencyStats {
+    let Some(&last_ms) = values.last() else {
+        return LatencyStats::default();
+    };
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    // Nearest-rank percentile
+    let p95_index = (sorted.len() * 95).div_ceil(100).saturating_sub(1);
+    LatencyStats {
+        last_ms,
+        min_ms: sorted[0],
+        mean_ms: sorted.iter().sum::<u64>() / sorted.len() as u64,
+        p95_ms: sorted[p95_index],
+        max_ms: sorted[sorted.len() - 1],
+    }
+}
+
+fn millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
@@ -0,0 +1,443 @@
+// =============================================================================
+//! Spoken voice commands
+//!
+//! Lets users control a dictation by voice while their hands are off the
+//! keyboard:
+//!
+//! - The optional *stop phrase* (e.g. "stop dictation"): while recording,
+//!   the most recent few seconds of audio are transcribed at a regular
+//!   interval and the recording ends as soon as the configured phrase is
+//!   heard with enough confidence. The phrase itself is then removed from
+//!   the final transcript.
+//! - Spoken punctuation and editing commands ("comma", "new paragraph",
+//!   "delete that"), resolved in the transcript by [`CommandInterpreter`].
+//!
+//! # Usage
+//!
+//! ```no_run
+//! # tokio_test::block_on(async {
+//! use speakr_core::audio::{AudioRecorder, RecordingConfig};
+//! use speakr_core::voice_command::{listen_for_stop_phrase, StopPhraseDetector};
+//! use speakr_types::{StopPhrase, TranscriptionConfig};
+//!
+//! let recorder = AudioRecorder::new(RecordingConfig::default()).await.unwrap();
+//! let detector = StopPhraseDetector::new(&StopPhrase::new("stop dictation")).unwrap();
+//!
+//! recorder.start_recording().await.unwrap();
+//! listen_for_stop_phrase(&recorder, &detector, TranscriptionConfig::default()).await;
+//! let samples = recorder.stop_recording().await.unwrap().samples();
+//! # });
+//! ```
+// =============================================================================
+
+use crate::audio::{AudioRecorder, SAMPLE_RATE_HZ};
+use crate::pipeline::transcription_pipeline;
+use speakr_types::{
+    StopPhrase, TranscriptionConfig, TranscriptionResult, VoiceCommand, VoiceCommandAction,
+    VoiceCommandSettings,
+};
+use std::time::Duration;
+use tracing::{debug, info};
+
+/// How often the recent audio is checked for the stop phrase.
+pub const STOP_PHRASE_POLL_INTERVAL: Duration = Duration::from_millis(750);
+
+/// Seconds of recent audio transcribed on each check.
+pub const STOP_PHRASE_WINDOW_SECS: u32 = 3;
+
+/// Matches a configured stop phrase against transcripts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StopPhraseDetector {
+    words: Vec<String>,
+    min_confidence: f32,
+}
+
+impl StopPhraseDetector {
+    /// Creates a detector for the given stop phrase.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the phrase contains no words.
+    pub fn new(stop_phrase: &StopPhrase) -> Option<Self> {
+        let words: Vec<String> = word_spans(&stop_phrase.phrase)
+            .into_iter()
+            .map(|(start, end)| stop_phrase.phrase[start..end].to_lowercase())
+            .collect();
+
+        (!words.is_empty()).then_some(Self {
+            words,
+            min_confidence: stop_phrase.min_confidence,
+        })
+    }
+
+    /// Returns `true` if the transcript ends with the stop phrase and was
+    /// transcribed with at least the configured confidence.
+    ///
+    /// The confidence of the last segment is used when segments are present,
+    /// since that is where the phrase was heard.
+    pub fn matches(&self, result: &TranscriptionResult) -> bool {
+        let confidence = result
+            .segments
+            .last()
+            .map_or(result.confidence, |segment| segment.confidence);
+
+        confidence >= self.min_confidence && self.phrase_start(&result.text).is_some()
+    }
+
+    /// Removes a trailing stop phrase from dictated text.
+    ///
+    /// Punctuation separating the phrase from the preceding text is dropped
+    /// with it; text that does not end with the phrase is returned unchanged.
+    pub fn strip(&self, text: &str) -> String {
+        match self.phrase_start(text) {
+            Some(start) => text[..start]
+                .trim_end_matches(|c: char| c.is_whitespace() || matches!(c, ',' | ';' | ':' | '-'))
+                .to_string(),
+            None => text.to_string(),
+        }
+    }
+
+    /// Byte offset where the trailing stop phrase starts in `text`, if any.
+    fn phrase_start(&self, text: &str) -> Option<usize> {
+        let spans = word_spans(text);
+        let tail = spans.get(spans.len().checked_sub(self.words.len())?..)?;
+
+        tail.iter()
+            .zip(&self.words)
+            .all(|(&(start, end), word)| text[start..end].to_lowercase() == *word)
+            .then(|| tail[0].0)
+    }
+}
+
+/// Listens for the stop phrase until it is heard or the recording ends.
+///
+/// Every [`STOP_PHRASE_POLL_INTERVAL`] the last [`STOP_PHRASE_WINDOW_SECS`]
+/// of audio are transcribed. A failed check is logged and skipped, so a
+/// transient transcription error never ends the dictation.
+///
+/// # Arguments
+///
+/// * `recorder` - The recorder whose active recording is monitored
+/// * `detector` - The stop phrase to listen for
+/// * `config` - Transcription settings used for the checks
+pub async fn listen_for_stop_phrase(
+    recorder: &AudioRecorder,
+    detector: &StopPhraseDetector,
+    config: TranscriptionConfig,
+) {
+    let window = (SAMPLE_RATE_HZ * STOP_PHRASE_WINDOW_SECS) as usize;
+
+    loop {
+        tokio::time::sleep(STOP_PHRASE_POLL_INTERVAL).await;
+
+        let Some(mut samples) = recorder.peek_samples() else {
+            return;
+        };
+        if samples.is_empty() {
+            continue;
+        }
+        let recent = samples.split_off(samples.len().saturating_sub(window));
+
+        match transcription_pipeline(recent, config.clone()).await {
+            Ok(result) if detector.matches(&result) => {
+                info!("Stop phrase detected, ending recording");
+                return;
+            }
+            Ok(_) => {}
+            Err(e) => debug!("Stop phrase check failed: {}", e),
+        }
+    }
+}
+
+/// Punctuation the transcription may put around a spoken command.
+fn is_sentence_punctuation(c: char) -> bool {
+    matches!(c, '.' | ',' | ';' | ':' | '!' | '?')
+}
+
+/// Resolves spoken punctuation and editing commands in a transcript.
+///
+/// Command phrases are matched as whole words, ignoring case and the
+/// punctuation the transcription adds, and the longest phrase wins. Text
+/// that is not part of a command is kept as transcribed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandInterpreter {
+    /// Lower-cased phrase words and actions, longest phrases first.
+    commands: Vec<(Vec<String>, VoiceCommandAction)>,
+}
+
+impl CommandInterpreter {
+    /// Creates an interpreter for the built-in commands and `settings.custom`.
+    ///
+    /// A custom command replaces a built-in one with the same phrase;
+    /// phrases without words are ignored.
+    pub fn new(settings: &VoiceCommandSettings) -> Self {
+        let mut commands: Vec<(Vec<String>, VoiceCommandAction)> = Vec::new();
+        for command in settings.custom.iter().chain(&VoiceCommand::built_in()) {
+            let words: Vec<String> = word_spans(&command.phrase)
+                .into_iter()
+                .map(|(start, end)| command.phrase[start..end].to_lowercase())
+                .collect();
+            if !words.is_empty() && !commands.iter().any(|(existing, _)| *existing == words) {
+                commands.push((words, command.action.clone()));
+            }
+        }
+        // Stable, so custom commands stay ahead of built-ins of equal length
+        commands.sort_by_key(|(words, _)| std::cmp::Reverse(words.len()));
+        Self { commands }
+    }
+
+    /// Applies the commands spoken in `text`.
+    ///
+    /// # Returns
+    ///
+    /// The text with command phrases replaced by their effect.
+    pub fn apply(&self, text: &str) -> String {
+        let spans = word_spans(text);
+        let words: Vec<String> = spans
+            .iter()
+            .map(|&(start, end)| text[start..end].to_lowercase())
+            .collect();
+
+        let mut output = String::with_capacity(text.len());
+        let mut previous_end = 0;
+        let mut after_command = false;
+        let mut all_caps = false;
+        let mut index = 0;
+
+        while index < spans.len() {
+            let (start, end) = spans[index];
+            let gap = &text[previous_end..start];
+
+            let Some((length, action)) = self.command_at(&words[index..]) else {
+                push_gap(&mut output, gap, after_command);
+                if all_caps {
+                    output.push_str(&text[start..end].to_uppercase());
+                } else {
+                    output.push_str(&text[start..end]);
+                }
+                all_caps = false;
+                after_command = false;
+                previous_end = end;
+                index += 1;
+                continue;
+            };
+
+            match action {
+                VoiceCommandAction::Punctuation { text } => {
+                    output.truncate(
+                        output
+                            .trim_end_matches(|c: char| {
+                                c.is_whitespace() || is_sentence_punctuation(c)
+                            })
+                            .len(),
+                    );
+                    output.push_str(text);
+                }
+                VoiceCommandAction::Insert { text } => {
+                    push_gap(&mut output, gap, after_command);
+                    if !output.is_empty() && !output.ends_with(char::is_whitespace) {
+                        output.push(' ');
+                    }
+                    output.push_str(text);
+                }
+                VoiceCommandAction::NewLine | VoiceCommandAction::NewParagraph => {
+                    push_gap(&mut output, gap, after_command);
+                    output.truncate(output.trim_end().len());
+                    output.push_str(if *action == VoiceCommandAction::NewLine {
+                        "\n"
+                    } else {
+                        "\n\n"
+                    });
+                }
+                VoiceCommandAction::DeleteLastPhrase => {
+                    let kept = output.trim_end_matches(|c: char| {
+                        c.is_whitespace() || is_sentence_punctuation(c)
+                    });
+                    let phrase_start = kept
+                        .rfind(|c: char| c == '\n' || is_sentence_punctuation(c))
+                        .map_or(0, |boundary| boundary + 1);
+                    output.truncate(phrase_start);
+                }
+                VoiceCommandAction::AllCapsNextWord => {
+                    push_gap(&mut output, gap, after_command);
+                    all_caps = true;
+                }
+            }
+            after_command = true;
+            previous_end = spans[index + length - 1].1;
+            index += length;
+        }
+
+        push_gap(&mut output, &text[previous_end..], after_command);
+        output
+    }
+
+    /// Finds the longest command starting at the first of `words`.
+    fn command_at(&self, words: &[String]) -> Option<(usize, &VoiceCommandAction)> {
+        self.commands
+            .iter()
+            .find(|(phrase, _)| words.starts_with(phrase))
+            .map(|(phrase, action)| (phrase.len(), action))
+    }
+}
+
+/// Appends the text between two words, dropping the punctuation that
+/// followed a command and never doubling whitespace.
+fn push_gap(output: &mut String, gap: &str, after_command: bool) {
+    let gap: String = if after_command {
+        gap.chars()
+            .filter(|&c| !is_sentence_punctuation(c))
+            .collect()
+    } else {
+        gap.to_string()
+    };
+    if output.is_empty() || output.ends_with(char::is_whitespace) {
+        output.push_str(gap.trim_start());
+    } else {
+        output.push_str(&gap);
+    }
+}
+
+/// Byte ranges of the words (runs of alphanumerics and apostrophes) in `text`.
+fn word_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+
+    for (index, c) in text.char_indices() {
+        let is_word_char = c.is_alphanumeric() || c == '\'';
+        match (is_word_char, start) {
+            (true, None) => start = Some(index),
+            (false, Some(word_start)) => {
+                spans.push((word_start, index));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(word_start) = start {
+        spans.push((word_start, text.len()));
+    }
+
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use speakr_types::{ModelId, TranscriptionSegment};
+
+    fn detector() -> StopPhraseDetector {
+        StopPhraseDetector::new(&StopPhrase::new("Stop dictation")).unwrap()
+    }
+
+    fn result(text: &str, confidence: f32) -> TranscriptionResult {
+        TranscriptionResult {
+            text: text.to_string(),
+            language: None,
+            confidence,
+            avg_logprob: None,
+            processing_time: Duration::ZERO,
+            memory_delta_bytes: 0,
+            model_used: ModelId::new("small"),
+            segments: vec![],
+        }
+    }
+
+    #[test]
+    fn matches_trailing_phrase_ignoring_case_and_punctuation() {
+        assert!(detector().matches(&result("and that's all. STOP, dictation!", 0.9)));
+        assert!(!detector().matches(&result("stop dictation and carry on", 0.9)));
+        assert!(!detector().matches(&result("dictation", 0.9)));
+    }
+
+    #[test]
+    fn ignores_matches_below_the_confidence_threshold() {
+        assert!(!detector().matches(&result("stop dictation", 0.3)));
+
+        let mut segmented = result("stop dictation", 0.3);
+        segmented.segments.push(TranscriptionSegment {
+            start_time: Duration::ZERO,
+            end_time: Duration::from_secs(1),
+            text: "stop dictation".to_string(),
+            confidence: 0.9,
+            words: vec![],
+        });
+        assert!(detector().matches(&segmented));
+    }
+
+    #[test]
+    fn strip_removes_the_phrase_and_its_separator() {
+        assert_eq!(
+            detector().strip("Hello world, stop dictation."),
+            "Hello world"
+        );
+        assert_eq!(
+            detector().strip("Hello world. Stop dictation"),
+            "Hello world."
+        );
+        assert_eq!(detector().strip("Hello world"), "Hello world");
+    }
+
+    fn interpret(text: &str) -> String {
+        CommandInterpreter::new(&VoiceCommandSettings {
+            enabled: true,
+            custom: vec![VoiceCommand::new(
+                "smiley",
+                VoiceCommandAction::Insert {
+                    text: ":-)".to_string(),
+                },
+            )],
+        })
+        .apply(text)
+    }
+
+    #[test]
+    fn interprets_spoken_punctuation() {
+        assert_eq!(
+            interpret("Hello, comma, how are you question mark"),
+            "Hello, how are you?"
+        );
+        assert_eq!(interpret("Done. Full stop."), "Done.");
+        assert_eq!(interpret("No commands here."), "No commands here.");
+    }
+
+    #[test]
+    fn interprets_line_breaks_and_inserts() {
+        assert_eq!(
+            interpret("Dear Sam, new paragraph, thanks smiley"),
+            "Dear Sam,\n\nthanks :-)"
+        );
+        assert_eq!(interpret("one new line two"), "one\ntwo");
+    }
+
+    #[test]
+    fn interprets_editing_commands() {
+        assert_eq!(
+            interpret("Meet at noon. Or at ten, delete that. Bring all caps nasa slides."),
+            "Meet at noon. Bring NASA slides."
+        );
+        assert_eq!(interpret("scratch that hello"), "hello");
+    }
+
+    #[test]
+    fn custom_commands_replace_built_ins() {
+        let interpreter = CommandInterpreter::new(&VoiceCommandSettings {
+            enabled: true,
+            custom: vec![VoiceCommand::new(
+                "Period",
+                VoiceCommandAction::Insert {
+                    text: "period".to_string(),
+                },
+            )],
+        });
+        assert_eq!(
+            interpreter.apply("trial period ended"),
+            "trial period ended"
+        );
+    }
+
+    #[test]
+    fn empty_phrase_has_no_detector() {
+        assert!(StopPhraseDetector::new(&StopPhrase::new(" ... ")).is_none());
+    }
+}
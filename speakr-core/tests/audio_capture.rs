@@ -8,8 +8,10 @@
 //! - Permission dialog appears once and records decision
 
 use speakr_core::audio::{
-    AudioCaptureError, AudioDevice, AudioRecorder, RecordingConfig, CHANNELS, SAMPLE_RATE_HZ,
+    AudioCaptureError, AudioDevice, AudioRecorder, RecordingConfig, CHANNELS, MAX_PRE_BUFFER_SECS,
+    SAMPLE_RATE_HZ,
 };
+use speakr_types::{AudioDuration, InputGain};
 use std::time::{Duration, Instant};
 use tokio_test::assert_ok;
 
@@ -19,13 +21,14 @@ mod unit_tests {
     use speakr_core::audio::{AudioStream, AudioSystem};
     use std::sync::{
         atomic::{AtomicBool, Ordering},
-        Arc,
+        Arc, Mutex,
     };
 
     /// Mock audio stream for testing
     struct MockAudioStream {
         samples: Vec<i16>,
         is_active: Arc<AtomicBool>,
+        wiped: Arc<AtomicBool>,
     }
 
     impl MockAudioStream {
@@ -33,6 +36,7 @@ mod unit_tests {
             Self {
                 samples,
                 is_active: Arc::new(AtomicBool::new(true)),
+                wiped: Arc::default(),
             }
         }
     }
@@ -46,6 +50,10 @@ mod unit_tests {
             self.is_active.store(false, Ordering::Release);
         }
 
+        fn wipe(&self) {
+            self.wiped.store(true, Ordering::Release);
+        }
+
         fn is_active(&self) -> bool {
             self.is_active.load(Ordering::Acquire)
         }
@@ -59,6 +67,8 @@ mod unit_tests {
         should_fail: bool,
         mock_samples: Vec<i16>,
         mock_devices: Vec<AudioDevice>,
+        pre_buffer_configs: Arc<Mutex<Vec<RecordingConfig>>>,
+        streams_wiped: Arc<AtomicBool>,
     }
 
     impl MockAudioSystem {
@@ -78,6 +88,8 @@ mod unit_tests {
                         is_default: false,
                     },
                 ],
+                pre_buffer_configs: Arc::default(),
+                streams_wiped: Arc::default(),
             }
         }
 
@@ -86,6 +98,8 @@ mod unit_tests {
                 should_fail: true,
                 mock_samples: vec![],
                 mock_devices: vec![],
+                pre_buffer_configs: Arc::default(),
+                streams_wiped: Arc::default(),
             }
         }
 
@@ -106,6 +120,8 @@ mod unit_tests {
                 should_fail: false,
                 mock_samples: vec![],
                 mock_devices: vec![],
+                pre_buffer_configs: Arc::default(),
+                streams_wiped: Arc::default(),
             }
         }
 
@@ -114,6 +130,8 @@ mod unit_tests {
                 should_fail: false,
                 mock_samples: vec![],
                 mock_devices: devices,
+                pre_buffer_configs: Arc::default(),
+                streams_wiped: Arc::default(),
             }
         }
     }
@@ -127,7 +145,9 @@ mod unit_tests {
                 return Err(AudioCaptureError::MicrophoneNotAvailable);
             }
 
-            Ok(Box::new(MockAudioStream::new(self.mock_samples.clone())))
+            let mut stream = MockAudioStream::new(self.mock_samples.clone());
+            stream.wiped = Arc::clone(&self.streams_wiped);
+            Ok(Box::new(stream))
         }
 
         fn list_input_devices(&self) -> Result<Vec<AudioDevice>, AudioCaptureError> {
@@ -143,6 +163,19 @@ mod unit_tests {
 
             Ok(self.mock_devices.clone())
         }
+
+        fn start_pre_buffer(
+            &self,
+            _duration_secs: u32,
+            config: &RecordingConfig,
+        ) -> Result<Box<dyn AudioStream>, AudioCaptureError> {
+            if self.should_fail {
+                return Err(AudioCaptureError::MicrophoneNotAvailable);
+            }
+            self.pre_buffer_configs.lock().unwrap().push(config.clone());
+
+            Ok(Box::new(MockAudioStream::new(vec![-2, -1])))
+        }
     }
 
     unsafe impl Send for MockAudioSystem {}
@@ -167,7 +200,7 @@ mod unit_tests {
 
         // Assert
         assert_eq!(
-            config.max_duration_secs(),
+            config.max_duration().as_secs(),
             10,
             "Default duration must be 10 seconds"
         );
@@ -177,11 +210,11 @@ mod unit_tests {
     #[test]
     fn max_recording_duration_can_be_set_up_to_thirty_seconds() {
         // Arrange & Act
-        let config = RecordingConfig::new(30);
+        let config = RecordingConfig::new(AudioDuration::new(30).unwrap());
 
         // Assert
         assert_eq!(
-            config.max_duration_secs(),
+            config.max_duration().as_secs(),
             30,
             "Max duration should be configurable up to 30s"
         );
@@ -191,17 +224,30 @@ mod unit_tests {
     #[test]
     fn recording_duration_cannot_exceed_thirty_seconds() {
         // Arrange & Act
-        let result = RecordingConfig::new(31);
+        let result = RecordingConfig::new(AudioDuration::clamped(31));
 
         // Assert - This should either clamp to 30 or return an error
         // For now, let's assume it clamps to 30
         assert_eq!(
-            result.max_duration_secs(),
+            result.max_duration().as_secs(),
             30,
             "Duration should be clamped to 30 seconds max"
         );
     }
 
+    /// Test that a long-form time limit replaces the 30-second maximum.
+    #[test]
+    fn time_limit_replaces_max_duration_for_long_form() {
+        // Arrange
+        let config = RecordingConfig::new(AudioDuration::new(20).unwrap());
+        let long_form = config.clone().with_time_limit(Duration::from_secs(300));
+
+        // Assert
+        assert_eq!(config.time_limit(), Duration::from_secs(20));
+        assert_eq!(long_form.time_limit(), Duration::from_secs(300));
+        assert_eq!(long_form.max_samples(), 300 * SAMPLE_RATE_HZ as usize);
+    }
+
     /// Test that AudioRecorder can be created with mock audio system.
     #[tokio::test]
     async fn creates_audio_recorder_with_mock_system() {
@@ -242,6 +288,187 @@ mod unit_tests {
         assert_eq!(samples, expected_samples, "Should return the mock samples");
     }
 
+    /// Test that pre-recorded audio is prepended to the recording.
+    #[tokio::test]
+    async fn recording_starts_with_pre_buffered_audio() {
+        // Arrange
+        let mock_system = Box::new(MockAudioSystem::with_samples(vec![1, 2, 3]));
+        let recorder = AudioRecorder::with_audio_system(mock_system);
+        assert_ok!(recorder.enable_pre_buffer(2));
+        assert!(recorder.is_pre_buffering());
+
+        // Act
+        assert_ok!(recorder.start_recording().await);
+        let samples = recorder.stop_recording().await.unwrap().samples();
+
+        // Assert
+        assert_eq!(samples, vec![-2, -1, 1, 2, 3]);
+        assert!(recorder.is_pre_buffering(), "Pre-buffer keeps running");
+
+        recorder.disable_pre_buffer();
+        assert!(!recorder.is_pre_buffering());
+    }
+
+    /// Test that the pre-buffer records from the preferred devices and is
+    /// reopened when they change.
+    #[test]
+    fn pre_buffer_follows_preferred_devices() {
+        // Arrange
+        let mock_system = MockAudioSystem::new();
+        let configs = Arc::clone(&mock_system.pre_buffer_configs);
+        let recorder = AudioRecorder::with_audio_system(Box::new(mock_system));
+        recorder.set_preferred_devices(vec!["External USB Microphone".to_string()]);
+
+        // Act
+        assert_ok!(recorder.enable_pre_buffer(2));
+        recorder.set_preferred_devices(vec!["External USB Microphone".to_string()]);
+        recorder.set_preferred_devices(vec!["Built-in Microphone".to_string()]);
+
+        // Assert
+        let devices: Vec<Vec<String>> = configs
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|config| config.preferred_devices().to_vec())
+            .collect();
+        assert_eq!(
+            devices,
+            vec![
+                vec!["External USB Microphone".to_string()],
+                vec!["Built-in Microphone".to_string()],
+            ],
+            "Only a changed preference reopens the stream"
+        );
+        assert!(recorder.is_pre_buffering());
+    }
+
+    /// Test that the pre-buffer records at the device's input gain and is
+    /// reopened when the gains change.
+    #[test]
+    fn pre_buffer_follows_input_gains() {
+        // Arrange
+        let mock_system = MockAudioSystem::new();
+        let configs = Arc::clone(&mock_system.pre_buffer_configs);
+        let recorder = AudioRecorder::with_audio_system(Box::new(mock_system));
+        let gain = |gain| {
+            vec![InputGain {
+                device: "Built-in Microphone".to_string(),
+                gain,
+            }]
+        };
+        recorder.set_input_gains(gain(2.0));
+
+        // Act
+        assert_ok!(recorder.enable_pre_buffer(2));
+        recorder.set_input_gains(gain(2.0));
+        recorder.set_input_gains(gain(0.5));
+
+        // Assert
+        let gains: Vec<Vec<InputGain>> = configs
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|config| config.input_gains().to_vec())
+            .collect();
+        assert_eq!(gains, vec![gain(2.0), gain(0.5)]);
+        assert!(recorder.is_pre_buffering());
+    }
+
+    /// Test that the pre-buffer duration is validated.
+    #[test]
+    fn pre_buffer_rejects_invalid_duration() {
+        let recorder = AudioRecorder::with_audio_system(Box::new(MockAudioSystem::new()));
+
+        assert!(matches!(
+            recorder.enable_pre_buffer(0),
+            Err(AudioCaptureError::InvalidConfiguration(_))
+        ));
+        assert!(matches!(
+            recorder.enable_pre_buffer(MAX_PRE_BUFFER_SECS + 1),
+            Err(AudioCaptureError::InvalidConfiguration(_))
+        ));
+        assert!(!recorder.is_pre_buffering());
+    }
+
+    /// Test that captured audio can be inspected while recording continues.
+    #[tokio::test]
+    async fn peek_samples_does_not_stop_recording() {
+        // Arrange
+        let mock_system = Box::new(MockAudioSystem::with_samples(vec![4, 5, 6]));
+        let recorder = AudioRecorder::with_audio_system(mock_system);
+        assert!(recorder.peek_samples().is_none());
+
+        // Act
+        assert_ok!(recorder.start_recording().await);
+        let peeked = recorder.peek_samples();
+
+        // Assert
+        assert_eq!(peeked, Some(vec![4, 5, 6]));
+        assert!(recorder.is_recording());
+        assert_eq!(
+            recorder.stop_recording().await.unwrap().samples(),
+            vec![4, 5, 6]
+        );
+        assert!(recorder.peek_samples().is_none());
+    }
+
+    /// Test that only the requested part of the recording is peeked.
+    #[tokio::test]
+    async fn peek_samples_from_returns_the_tail() {
+        let mock_system = Box::new(MockAudioSystem::with_samples(vec![4, 5, 6, 7]));
+        let recorder = AudioRecorder::with_audio_system(mock_system);
+
+        assert_ok!(recorder.start_recording().await);
+
+        assert_eq!(recorder.peek_samples_from(2), Some(vec![6, 7]));
+        assert_eq!(recorder.peek_samples_from(9), Some(vec![]));
+    }
+
+    /// Test that a cancelled recording wipes its audio and frees the recorder.
+    #[tokio::test]
+    async fn cancel_recording_discards_audio() {
+        // Arrange
+        let mock_system = MockAudioSystem::with_samples(vec![7, 8, 9]);
+        let wiped = Arc::clone(&mock_system.streams_wiped);
+        let recorder = AudioRecorder::with_audio_system(Box::new(mock_system));
+        assert!(!recorder.cancel_recording());
+
+        // Act
+        assert_ok!(recorder.start_recording().await);
+        let cancelled = recorder.cancel_recording();
+
+        // Assert
+        assert!(cancelled);
+        assert!(
+            wiped.load(Ordering::Acquire),
+            "The stream's audio is zeroed"
+        );
+        assert!(!recorder.is_recording());
+        assert!(matches!(
+            recorder.stop_recording().await,
+            Err(AudioCaptureError::NoActiveRecording)
+        ));
+        assert_ok!(recorder.start_recording().await);
+    }
+
+    /// Test that the input level reflects the most recent audio.
+    #[tokio::test]
+    async fn input_level_reports_rms_of_recent_audio() {
+        // Arrange
+        let loud = vec![i16::MAX / 2; 1_600];
+        let recorder =
+            AudioRecorder::with_audio_system(Box::new(MockAudioSystem::with_samples(loud)));
+        assert_eq!(recorder.input_level(), None);
+
+        // Act
+        assert_ok!(recorder.start_recording().await);
+        let level = recorder.input_level().expect("recording is active");
+
+        // Assert
+        assert!((level - 0.5).abs() < 0.01, "level {level}");
+        assert_ok!(recorder.stop_recording().await);
+    }
+
     /// Test that recording initializes quickly with mock system.
     #[tokio::test]
     async fn recording_initializes_quickly_with_mock() {
@@ -491,8 +718,8 @@ mod property_tests {
         /// Property test: any valid duration (1-30s) should create valid config.
         #[test]
         fn any_valid_duration_creates_valid_config(duration_secs in 1u32..=30u32) {
-            let config = RecordingConfig::new(duration_secs);
-            assert_eq!(config.max_duration_secs(), duration_secs);
+            let config = RecordingConfig::new(AudioDuration::new(duration_secs).unwrap());
+            assert_eq!(config.max_duration().as_secs(), duration_secs);
         }
 
         /// Property test: sample count should be proportional to recording duration.
@@ -515,7 +742,7 @@ mod integration_tests {
 
     /// Helper function to check if audio hardware is available.
     async fn is_audio_hardware_available() -> bool {
-        let config = RecordingConfig::new(1);
+        let config = RecordingConfig::new(AudioDuration::new(1).unwrap());
         match AudioRecorder::new(config).await {
             Ok(_) => true,
             Err(AudioCaptureError::MicrophoneNotAvailable) => false,
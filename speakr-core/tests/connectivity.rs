@@ -0,0 +1,63 @@
+//! Integration tests for the connectivity watcher used by model downloads.
+
+use speakr_core::connectivity::ConnectivityWatcher;
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+/// Returns a loopback address with nothing listening on it.
+async fn closed_addr() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    listener.local_addr().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn reports_offline_when_probe_host_is_unreachable() {
+    // ---------------------------------------------------------------------
+    // Arrange
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let online = ConnectivityWatcher::with_probe(
+        listener.local_addr().unwrap().to_string(),
+        Duration::from_millis(10),
+    );
+    let offline = ConnectivityWatcher::with_probe(closed_addr().await, Duration::from_millis(10));
+    let mut changes = offline.subscribe();
+
+    // ---------------------------------------------------------------------
+    // Act
+    let online_result = online.check().await;
+    let offline_result = offline.check().await;
+
+    // ---------------------------------------------------------------------
+    // Assert
+    assert!(online_result);
+    assert!(online.is_online());
+    assert!(!offline_result);
+    assert!(!offline.is_online());
+    assert!(changes.has_changed().unwrap());
+    assert!(!*changes.borrow_and_update());
+}
+
+#[tokio::test]
+async fn waits_until_probe_host_is_reachable() {
+    // ---------------------------------------------------------------------
+    // Arrange
+    let addr = closed_addr().await;
+    let watcher = ConnectivityWatcher::with_probe(addr.clone(), Duration::from_millis(10));
+    assert!(!watcher.check().await);
+
+    // ---------------------------------------------------------------------
+    // Act
+    let server = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let listener = TcpListener::bind(&addr).await.unwrap();
+        let _ = listener.accept().await;
+    });
+    tokio::time::timeout(Duration::from_secs(5), watcher.wait_until_online())
+        .await
+        .expect("watcher should see the network come back");
+
+    // ---------------------------------------------------------------------
+    // Assert
+    assert!(watcher.is_online());
+    server.abort();
+}
@@ -0,0 +1,48 @@
+//! OpenAI's Whisper models converted to ggml format for use with whisper.cpp
+//!
+//! [Available models](https://huggingface.co/ggerganov/whisper.cpp/tree/main)
+//!
+//! | Model               | Disk    | SHA                                        |
+//! | ------------------- | ------- | ------------------------------------------ |
+//! | base                | 141 MiB | `465707469ff3a37a2b9b8d8f89f2f99de7299dac` |
+//! | small.en-q5_1       | 181 MiB | `20f54878d608f94e4a8ee3ae56016571d47cba34` |
+//! | medium              | 1.4 GiB | `fd9727b6e1217c2f614f9b698455c4ffd82463b4` |
+//!
+//! Example: https://huggingface.co/ggerganov/whisper.cpp/resolve/f281eb45af861ab5e5297d23694b7d46e090c02c/ggml-base.bin
+use size::Size;
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum Model {
+    Base,
+    SmallEnQuantizedQ5_1,
+    Medium,
+}
+impl Model {
+    pub fn filename(&self) -> &'static str {
+        match self {
+            Model::Base => "base",
+            Model::SmallEnQuantizedQ5_1 => "small.en-q5_1",
+            Model::Medium => "medium",
+        }
+    }
+    pub fn filesize(&self) -> Size {
+        match self {
+            Model::Base => Size::from_mib(141),
+            Model::SmallEnQuantizedQ5_1 => Size::from_mib(181),
+            Model::Medium => Size::from_gib(1.4),
+        }
+    }
+    pub fn sha(&self) -> &'static str {
+        match self {
+            Model::Base => "465707469ff3a37a2b9b8d8f89f2f99de7299dac",
+            Model::SmallEnQuantizedQ5_1 => "20f54878d608f94e4a8ee3ae56016571d47cba34",
+            Model::Medium => "fd9727b6e1217c2f614f9b698455c4ffd82463b4",
+        }
+    }
+    pub fn url(&self) -> String {
+        format!(
+            "https://huggingface.co/ggerganov/whisper.cpp/resolve/f281eb45af861ab5e5297d23694b7d46e090c02c/ggml-{}.bin",
+            self.filename()
+        )
+    }
+}
@@ -0,0 +1,11 @@
+//! Compile check of the model list generated by `update-models-tui`.
+//!
+//! The generator's golden output (`tests/fixtures/generated_list.rs`) is
+//! checked against the generator by a unit test in the binary; this test
+//! makes sure that output also compiles and runs against the `size` crate.
+
+#[test]
+fn test_generated_model_list_compiles() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/generated_list_compiles.rs");
+}
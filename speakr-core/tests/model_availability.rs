@@ -1,6 +1,6 @@
 //! Integration tests for model availability and listing (task 2.2)
 
-use speakr_core::model::Model;
+use speakr_core::model::{Model, ModelMetadata};
 use speakr_core::transcription::models::ModelManager;
 use tempfile::TempDir;
 use tokio::fs;
@@ -31,6 +31,74 @@ async fn lists_available_models_in_cache() {
     assert!(list.contains(&tiny_model));
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn lists_installed_models_with_sizes_and_checksums() {
+    // ---------------------------------------------------------------------
+    // Arrange – one catalogue model, one custom model and an unrelated file
+    // ---------------------------------------------------------------------
+    let tmp_dir = TempDir::new().expect("create temp dir");
+    let manager = ModelManager::with_cache_dir(tmp_dir.path().to_path_buf());
+
+    let tiny_path = tmp_dir
+        .path()
+        .join(format!("ggml-{}.bin", Model::Tiny.filename()));
+    fs::write(&tiny_path, b"not the real tiny model")
+        .await
+        .expect("write tiny model");
+    fs::write(tmp_dir.path().join("ggml-custom.bin"), b"custom")
+        .await
+        .expect("write custom model");
+    fs::write(tmp_dir.path().join("notes.txt"), b"ignored")
+        .await
+        .expect("write unrelated file");
+
+    // ---------------------------------------------------------------------
+    // Act – list with checksum verification
+    // ---------------------------------------------------------------------
+    let installed = manager.installed_models(true).await.expect("list models");
+
+    // ---------------------------------------------------------------------
+    // Assert – only model files, sorted, with honest checksum results
+    // ---------------------------------------------------------------------
+    assert_eq!(installed.len(), 2);
+    assert_eq!(installed[0].filename, "ggml-custom.bin");
+    assert_eq!(installed[0].size_bytes, 6);
+    assert_eq!(installed[0].checksum_valid, None);
+    assert_eq!(installed[1].filename, "ggml-tiny.bin");
+    assert_eq!(installed[1].checksum_valid, Some(false));
+
+    let usage = manager.disk_usage().await.expect("disk usage");
+    assert_eq!(usage.model_count, 2);
+    assert_eq!(
+        usage.total_bytes,
+        6 + b"not the real tiny model".len() as u64
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn deletes_models_but_rejects_paths_outside_cache() {
+    // ---------------------------------------------------------------------
+    // Arrange – a single installed model
+    // ---------------------------------------------------------------------
+    let tmp_dir = TempDir::new().expect("create temp dir");
+    let manager = ModelManager::with_cache_dir(tmp_dir.path().to_path_buf());
+    let path = tmp_dir.path().join("ggml-tiny.bin");
+    fs::write(&path, b"dummy").await.expect("write dummy model");
+
+    // ---------------------------------------------------------------------
+    // Act & Assert – traversal and non-model names are refused
+    // ---------------------------------------------------------------------
+    assert!(manager.delete_model("../ggml-tiny.bin").await.is_err());
+    assert!(manager.delete_model("notes.txt").await.is_err());
+    assert!(path.exists());
+
+    manager
+        .delete_model("ggml-tiny.bin")
+        .await
+        .expect("delete model");
+    assert!(!path.exists());
+}
+
 #[test]
 fn model_memory_usage_ordering() {
     // The Tiny model should require less RAM than the Medium model which in turn
@@ -38,3 +106,89 @@ fn model_memory_usage_ordering() {
     assert!(Model::Tiny.memory_usage_mb() < Model::Medium.memory_usage_mb());
     assert!(Model::Medium.memory_usage_mb() < Model::LargeV1.memory_usage_mb());
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn records_the_catalogue_each_model_came_from() {
+    let tmp_dir = TempDir::new().expect("create temp dir");
+    let manager = ModelManager::with_cache_dir(tmp_dir.path().to_path_buf());
+    let filename = format!("ggml-{}.bin", Model::Tiny.filename());
+    fs::write(tmp_dir.path().join(&filename), b"dummy")
+        .await
+        .expect("write dummy model");
+
+    // Downloaded before sources were recorded
+    let installed = manager.installed_models(false).await.expect("list models");
+    assert_eq!(installed[0].source, None);
+    assert_eq!(
+        installed[0].catalogue_sha.as_deref(),
+        Some(Model::Tiny.sha())
+    );
+
+    let mut metadata = ModelMetadata::for_model(&Model::Tiny);
+    metadata.sha256 = "an older checksum".to_string();
+    manager
+        .record_source(&metadata)
+        .await
+        .expect("record source");
+
+    let installed = manager.installed_models(false).await.expect("list models");
+    assert_eq!(installed.len(), 1, "the record file is not a model");
+    let source = installed[0].source.as_ref().expect("source recorded");
+    assert_eq!(source.git_ref, metadata.git_ref);
+    assert!(installed[0].upstream_changed());
+
+    manager.delete_model(&filename).await.expect("delete model");
+    assert!(manager.model_sources().await.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn verifies_models_once_until_the_file_changes() {
+    let tmp_dir = TempDir::new().expect("create temp dir");
+    let manager = ModelManager::with_cache_dir(tmp_dir.path().to_path_buf());
+    let mut metadata = ModelMetadata::for_model(&Model::Tiny);
+    let path = tmp_dir.path().join(&metadata.filename);
+    fs::write(&path, b"dummy").await.expect("write dummy model");
+    // SHA-256 of "dummy"
+    metadata.sha256 =
+        "b5a2c96250612366ea272ffac6d9744aaf4b45aacd96aa7cfcb931ee3b558259".to_string();
+
+    assert!(manager.verify_model(&metadata).await.expect("verify"));
+    let records = tmp_dir.path().join("model-verifications.json");
+    assert!(records.exists(), "a passing file is remembered");
+
+    // A different expected checksum is not answered from the record
+    let mut other = metadata.clone();
+    other.sha256 = "0".repeat(64);
+    assert!(!manager.verify_model(&other).await.expect("verify"));
+    assert!(manager.verify_model(&metadata).await.expect("verify"));
+
+    // A damaged file is hashed again and fails
+    fs::write(&path, b"dummy, but damaged")
+        .await
+        .expect("damage model");
+    assert!(!manager.verify_model(&metadata).await.expect("verify"));
+
+    assert!(manager
+        .verify_model(&ModelMetadata::for_model(&Model::Base))
+        .await
+        .is_err());
+
+    manager
+        .delete_model(&metadata.filename)
+        .await
+        .expect("delete model");
+    assert_eq!(
+        fs::read_to_string(&records).await.expect("read records"),
+        "{}"
+    );
+}
+
+#[test]
+fn reports_free_space_for_a_cache_dir_that_does_not_exist_yet() {
+    let tmp_dir = TempDir::new().expect("create temp dir");
+    let manager = ModelManager::with_cache_dir(tmp_dir.path().join("not/yet/created"));
+    let available = manager
+        .available_space()
+        .expect("disk holding the temp dir");
+    assert!(available > 0);
+}
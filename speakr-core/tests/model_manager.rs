@@ -55,3 +55,62 @@ async fn downloads_and_validates_model() {
         "file content matches"
     );
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reports_download_progress() {
+    // ---------------------------------------------------------------------
+    // Arrange – mock server with a payload of known size
+    // ---------------------------------------------------------------------
+    let server = MockServer::start().await;
+    let payload = vec![7u8; 64 * 1024];
+
+    Mock::given(method("GET"))
+        .and(path("/ggml-progress.bin"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(payload.clone()))
+        .mount(&server)
+        .await;
+
+    let url = format!("{}/ggml-progress.bin", server.uri());
+    let tmp_dir = TempDir::new().expect("create temp dir");
+    let manager = ModelManager::with_cache_dir(tmp_dir.path().to_path_buf());
+
+    // ---------------------------------------------------------------------
+    // Act – download while collecting progress updates
+    // ---------------------------------------------------------------------
+    let mut updates = Vec::new();
+    manager
+        .download_model_with_progress(&url, None, |progress| updates.push(progress.clone()))
+        .await
+        .expect("download succeeds");
+
+    // ---------------------------------------------------------------------
+    // Assert – progress is monotonic and ends complete
+    // ---------------------------------------------------------------------
+    let last = updates.last().expect("at least one update");
+    assert_eq!(last.filename, "ggml-progress.bin");
+    assert_eq!(last.total_bytes, Some(payload.len() as u64));
+    assert!(last.is_complete());
+    assert!(updates
+        .windows(2)
+        .all(|pair| pair[0].downloaded_bytes <= pair[1].downloaded_bytes));
+}
+
+#[test]
+fn transfer_rate_averages_over_sliding_window() {
+    use speakr_core::transcription::models::TransferRate;
+    use std::time::{Duration, Instant};
+
+    let start = Instant::now();
+    let mut rate = TransferRate::new(Duration::from_secs(2));
+    assert_eq!(rate.bytes_per_sec(), 0);
+
+    // A slow first second falls out of the window once the transfer speeds up
+    rate.record(start, 0);
+    rate.record(start + Duration::from_secs(1), 100);
+    rate.record(start + Duration::from_secs(2), 1_100);
+    rate.record(start + Duration::from_secs(3), 2_100);
+    rate.record(start + Duration::from_secs(4), 3_100);
+
+    assert_eq!(rate.bytes_per_sec(), 1_000);
+    assert_eq!(rate.eta_secs(5_000), Some(5));
+}
@@ -1,4 +1,7 @@
-use speakr_core::transcription::performance::PerformanceMonitor;
+use speakr_core::transcription::performance::{
+    DictationTimings, LatencyTracker, PerformanceMonitor,
+};
+use speakr_types::{LatencyStats, ProcessingStage};
 use std::time::Duration;
 
 #[test]
@@ -13,3 +16,60 @@ fn monitor_records_latency() {
     assert_eq!(metrics.len(), 1);
     assert!(metrics[0].duration >= Duration::from_millis(30));
 }
+
+fn timings(end_to_end_ms: u64) -> DictationTimings {
+    DictationTimings {
+        hotkey_to_record_start: Duration::from_millis(10),
+        record_stop_to_first_token: Duration::from_millis(end_to_end_ms / 2),
+        end_to_end: Duration::from_millis(end_to_end_ms),
+    }
+}
+
+#[test]
+fn latency_tracker_keeps_a_rolling_window() {
+    let mut tracker = LatencyTracker::new(3);
+    assert_eq!(tracker.metrics().samples, 0);
+    assert_eq!(tracker.metrics().end_to_end, LatencyStats::default());
+
+    for ms in [100, 400, 200, 300] {
+        tracker.record(timings(ms));
+    }
+
+    let metrics = tracker.metrics();
+    assert_eq!(metrics.window, 3);
+    assert_eq!(metrics.samples, 3);
+    assert_eq!(
+        metrics.end_to_end,
+        LatencyStats {
+            last_ms: 300,
+            min_ms: 200,
+            mean_ms: 300,
+            p95_ms: 400,
+            max_ms: 400,
+        }
+    );
+    assert_eq!(metrics.record_stop_to_first_token.last_ms, 150);
+    assert_eq!(metrics.hotkey_to_record_start.mean_ms, 10);
+}
+
+#[test]
+fn latency_tracker_keeps_the_last_processing_stages() {
+    let mut tracker = LatencyTracker::new(3);
+    let stage = |name: &str| ProcessingStage {
+        name: name.to_string(),
+        duration_us: 40,
+        changed: true,
+        segments: 1,
+    };
+
+    tracker.record_processing_stages(vec![stage("Trim whitespace")]);
+    tracker.record_processing_stages(vec![stage("Strip filler words"), stage("Paragraphs")]);
+
+    let names = tracker
+        .metrics()
+        .processing_stages
+        .into_iter()
+        .map(|stage| stage.name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["Strip filler words", "Paragraphs"]);
+}
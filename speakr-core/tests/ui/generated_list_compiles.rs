@@ -0,0 +1,11 @@
+// Compiles the golden output of `update-models-tui` as a module and uses it.
+#[path = "../fixtures/generated_list.rs"]
+mod list;
+
+fn main() {
+    assert_eq!(list::Model::Base.filename(), "base");
+    assert_eq!(list::Model::Medium.filesize(), size::Size::from_gib(1.4));
+    assert!(list::Model::SmallEnQuantizedQ5_1
+        .url()
+        .ends_with("/ggml-small.en-q5_1.bin"));
+}
@@ -16,14 +16,33 @@
 //!    filename suitable for recordings.
 //! 2. `save_audio_samples_to_wav_file` – Persists an in-memory slice of `i16`
 //!    samples to an on-disk WAV file using the optimal recording spec.
+//! 3. `load_audio_samples_from_wav_file` – Reads a previously saved WAV file
+//!    back into memory so it can be replayed through the dictation workflow.
+//! 4. `encode_wav_in_memory` – Encodes samples as WAV bytes without touching
+//!    the disk, e.g. for playback in the frontend.
+//! 5. `save_tagged_recording` – Saves a dictation's audio under a name built
+//!    from the user's template, with its date, duration, model and target
+//!    application in the WAV file's `LIST/INFO` tags.
 // ============================================================================
 
 // =========================
 // External Imports
 // =========================
-use hound::{WavSpec, WavWriter};
-use speakr_types::AppError;
-use std::path::PathBuf;
+use chrono::{DateTime, Utc};
+use hound::{WavReader, WavSpec, WavWriter};
+use speakr_types::{AppError, ModelId, DEFAULT_RECORDING_FILENAME_TEMPLATE};
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// WAV format of every recording: 16 kHz, mono, 16-bit signed integers,
+/// which suits speech recognition.
+const RECORDING_WAV_SPEC: WavSpec = WavSpec {
+    channels: 1,
+    sample_rate: 16_000,
+    bits_per_sample: 16,
+    sample_format: hound::SampleFormat::Int,
+};
 
 // ============================================================================
 // Filename Utilities
@@ -95,14 +114,6 @@ pub async fn save_audio_samples_to_wav_file(
     samples: &[i16],
     output_path: &PathBuf,
 ) -> Result<(), AppError> {
-    // WAV specification optimised for speech recognition
-    let spec = WavSpec {
-        channels: 1,         // Mono audio
-        sample_rate: 16_000, // 16 kHz sample rate (common for speech)
-        bits_per_sample: 16, // 16-bit depth
-        sample_format: hound::SampleFormat::Int,
-    };
-
     // Validate output path directory exists
     if let Some(parent) = output_path.parent() {
         if !parent.exists() {
@@ -113,7 +124,7 @@ pub async fn save_audio_samples_to_wav_file(
         }
     }
 
-    let mut writer = WavWriter::create(output_path, spec)
+    let mut writer = WavWriter::create(output_path, RECORDING_WAV_SPEC)
         .map_err(|e| AppError::FileSystem(format!("Failed to create WAV file: {e}")))?;
 
     // Write all samples to the file
@@ -130,6 +141,308 @@ pub async fn save_audio_samples_to_wav_file(
     Ok(())
 }
 
+// --------------------------------------------------------------------------
+/// Encode raw PCM samples (`i16`) as a 16-bit mono WAV file in memory.
+///
+/// # Arguments
+/// * `samples` – The in-memory audio samples to encode.
+///
+/// # Returns
+/// The complete WAV file, header included.
+///
+/// # Errors
+/// Returns an `AppError::FileSystem` if the WAV writer fails.
+pub fn encode_wav_in_memory(samples: &[i16]) -> Result<Vec<u8>, AppError> {
+    let mut buffer = Cursor::new(Vec::new());
+    let mut writer = WavWriter::new(&mut buffer, RECORDING_WAV_SPEC)
+        .map_err(|e| AppError::FileSystem(format!("Failed to create WAV data: {e}")))?;
+    for &sample in samples {
+        writer
+            .write_sample(sample)
+            .map_err(|e| AppError::FileSystem(format!("Failed to write audio sample: {e}")))?;
+    }
+    writer
+        .finalize()
+        .map_err(|e| AppError::FileSystem(format!("Failed to finalize WAV data: {e}")))?;
+    Ok(buffer.into_inner())
+}
+
+// --------------------------------------------------------------------------
+/// Load raw PCM samples (`i16`) from a 16-bit mono 16 kHz WAV file on disk.
+///
+/// This is the inverse of [`save_audio_samples_to_wav_file`] and is used to
+/// replay saved recordings through the dictation workflow.
+///
+/// # Arguments
+/// * `input_path` – Full filesystem path of the WAV file to read.
+///
+/// # Returns
+/// The decoded samples, in file order.
+///
+/// # Errors
+/// Returns an `AppError::FileSystem` if:
+/// * The file does not exist or cannot be opened.
+/// * The file is not a valid WAV file.
+/// * A sample cannot be decoded.
+///
+/// Returns an `AppError::AudioCapture` if the file does not match the
+/// recording spec (mono, 16 kHz, 16-bit signed integers).
+pub async fn load_audio_samples_from_wav_file(input_path: &Path) -> Result<Vec<i16>, AppError> {
+    if !input_path.exists() {
+        return Err(AppError::FileSystem(format!(
+            "WAV file does not exist: {}",
+            input_path.display()
+        )));
+    }
+
+    let reader = WavReader::open(input_path)
+        .map_err(|e| AppError::FileSystem(format!("Failed to open WAV file: {e}")))?;
+
+    let spec = reader.spec();
+    if spec.channels != 1
+        || spec.sample_rate != 16_000
+        || spec.bits_per_sample != 16
+        || spec.sample_format != hound::SampleFormat::Int
+    {
+        return Err(AppError::AudioCapture(format!(
+            "Unsupported WAV format: {} channel(s), {} Hz, {}-bit {:?} (expected mono 16 kHz 16-bit Int)",
+            spec.channels, spec.sample_rate, spec.bits_per_sample, spec.sample_format
+        )));
+    }
+
+    reader
+        .into_samples::<i16>()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| AppError::FileSystem(format!("Failed to read audio sample: {e}")))
+}
+
+// ============================================================================
+// Tagged Dictation Recordings
+// ============================================================================
+
+// --------------------------------------------------------------------------
+/// Details of a dictation stored with its recording.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordingMetadata {
+    /// When the recording was made.
+    pub recorded_at: DateTime<Utc>,
+    /// Length of the recording.
+    pub duration: Duration,
+    /// Model that transcribed the audio.
+    pub model: ModelId,
+    /// Application the text was dictated into, if known.
+    pub target_app: Option<String>,
+}
+
+impl RecordingMetadata {
+    /// The `LIST/INFO` tags written to the WAV file.
+    ///
+    /// Uses the standard `ICRD` (creation date), `ISFT` (software), `ISRC`
+    /// (source) and `ICMT` (comment) chunk ids so common tag readers show
+    /// them.
+    pub fn info_tags(&self) -> Vec<([u8; 4], String)> {
+        let mut tags = vec![
+            (*b"ICRD", self.recorded_at.to_rfc3339()),
+            (*b"ISFT", "Speakr".to_string()),
+            (
+                *b"ICMT",
+                format!(
+                    "Duration: {:.1} s; Model: {}",
+                    self.duration.as_secs_f32(),
+                    self.model
+                ),
+            ),
+        ];
+        if let Some(app) = &self.target_app {
+            tags.push((*b"ISRC", app.clone()));
+        }
+        tags
+    }
+}
+
+// --------------------------------------------------------------------------
+/// Build a recording's file name from a template.
+///
+/// Replaces the placeholders described at
+/// [`DEFAULT_RECORDING_FILENAME_TEMPLATE`], swaps characters that are not
+/// allowed in file names for `_` and adds the `.wav` extension. An empty
+/// template falls back to the default.
+///
+/// # Arguments
+/// * `template` – The user's file name template.
+/// * `metadata` – The recording the name is for.
+///
+/// # Returns
+/// The file name **without** any directory component.
+pub fn render_recording_filename(template: &str, metadata: &RecordingMetadata) -> String {
+    let template = match template.trim() {
+        "" => DEFAULT_RECORDING_FILENAME_TEMPLATE,
+        template => template,
+    };
+    let name = template
+        .replace(
+            "{date}",
+            &metadata.recorded_at.format("%Y-%m-%d").to_string(),
+        )
+        .replace(
+            "{time}",
+            &metadata.recorded_at.format("%H-%M-%S").to_string(),
+        )
+        .replace("{model}", metadata.model.as_str())
+        .replace("{app}", metadata.target_app.as_deref().unwrap_or("unknown"))
+        .replace("{duration}", &metadata.duration.as_secs().to_string());
+    let name: String = name
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+    format!("{}.wav", name.trim_start_matches('.'))
+}
+
+// --------------------------------------------------------------------------
+/// Append a `LIST/INFO` chunk with the given tags to a WAV file.
+///
+/// The chunk follows the audio data, where readers that do not understand
+/// it skip it, and the RIFF header size is updated to include it.
+///
+/// # Arguments
+/// * `path` – The WAV file, as written by [`save_audio_samples_to_wav_file`].
+/// * `tags` – Four-character INFO chunk ids and their text values.
+///
+/// # Errors
+/// Returns an `AppError::FileSystem` if the file cannot be read or written,
+/// or is not a RIFF file.
+pub async fn write_wav_info_tags(path: &Path, tags: &[([u8; 4], String)]) -> Result<(), AppError> {
+    let mut bytes = std::fs::read(path)
+        .map_err(|e| AppError::FileSystem(format!("Failed to read WAV file: {e}")))?;
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" {
+        return Err(AppError::FileSystem(format!(
+            "Not a RIFF file: {}",
+            path.display()
+        )));
+    }
+
+    let mut info = b"INFO".to_vec();
+    for (id, value) in tags {
+        // Values are NUL-terminated and chunks padded to an even length
+        let mut data = value.as_bytes().to_vec();
+        data.push(0);
+        info.extend_from_slice(id);
+        info.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        info.extend_from_slice(&data);
+        if data.len() % 2 == 1 {
+            info.push(0);
+        }
+    }
+    if bytes.len() % 2 == 1 {
+        bytes.push(0);
+    }
+    bytes.extend_from_slice(b"LIST");
+    bytes.extend_from_slice(&(info.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(&info);
+
+    let riff_size = (bytes.len() - 8) as u32;
+    bytes[4..8].copy_from_slice(&riff_size.to_le_bytes());
+    std::fs::write(path, bytes)
+        .map_err(|e| AppError::FileSystem(format!("Failed to write WAV tags: {e}")))
+}
+
+// --------------------------------------------------------------------------
+/// Read the `LIST/INFO` tags of a WAV file.
+///
+/// # Returns
+/// The tags in file order; none if the file has no INFO list.
+///
+/// # Errors
+/// Returns an `AppError::FileSystem` if the file cannot be read or is not a
+/// RIFF file.
+pub async fn read_wav_info_tags(path: &Path) -> Result<Vec<([u8; 4], String)>, AppError> {
+    let bytes = std::fs::read(path)
+        .map_err(|e| AppError::FileSystem(format!("Failed to read WAV file: {e}")))?;
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" {
+        return Err(AppError::FileSystem(format!(
+            "Not a RIFF file: {}",
+            path.display()
+        )));
+    }
+
+    let mut tags = Vec::new();
+    for (id, data) in riff_chunks(&bytes[12..]) {
+        if id != b"LIST" || !data.starts_with(b"INFO") {
+            continue;
+        }
+        for (tag_id, value) in riff_chunks(&data[4..]) {
+            let value = value.split(|&b| b == 0).next().unwrap_or_default();
+            tags.push((
+                [tag_id[0], tag_id[1], tag_id[2], tag_id[3]],
+                String::from_utf8_lossy(value).into_owned(),
+            ));
+        }
+    }
+    Ok(tags)
+}
+
+/// Split a sequence of RIFF chunks into ids and data, stopping at the first
+/// truncated chunk.
+fn riff_chunks(mut bytes: &[u8]) -> Vec<(&[u8], &[u8])> {
+    let mut chunks = Vec::new();
+    while bytes.len() >= 8 {
+        let size = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]) as usize;
+        let Some(data) = bytes.get(8..8 + size) else {
+            break;
+        };
+        chunks.push((&bytes[0..4], data));
+        let next = 8 + size + size % 2;
+        bytes = bytes.get(next..).unwrap_or_default();
+    }
+    chunks
+}
+
+// --------------------------------------------------------------------------
+/// Save a dictation's audio as a tagged WAV file.
+///
+/// The file is named from `template` (see [`render_recording_filename`]);
+/// if that name is taken a counter is added, so earlier recordings are never
+/// overwritten.
+///
+/// # Arguments
+/// * `samples` – The recorded audio, 16 kHz mono.
+/// * `output_dir` – Directory to save into; created if needed.
+/// * `template` – The user's file name template.
+/// * `metadata` – Details stored in the file's tags.
+///
+/// # Returns
+/// The path of the saved file.
+///
+/// # Errors
+/// Returns an `AppError::FileSystem` if the directory or file cannot be
+/// written.
+pub async fn save_tagged_recording(
+    samples: &[i16],
+    output_dir: &Path,
+    template: &str,
+    metadata: &RecordingMetadata,
+) -> Result<PathBuf, AppError> {
+    std::fs::create_dir_all(output_dir)
+        .map_err(|e| AppError::FileSystem(format!("Failed to create recordings directory: {e}")))?;
+
+    let filename = render_recording_filename(template, metadata);
+    let mut output_path = output_dir.join(&filename);
+    let stem = filename.trim_end_matches(".wav");
+    let mut counter = 1;
+    while output_path.exists() {
+        counter += 1;
+        output_path = output_dir.join(format!("{stem}-{counter}.wav"));
+    }
+
+    save_audio_samples_to_wav_file(samples, &output_path).await?;
+    write_wav_info_tags(&output_path, &metadata.info_tags()).await?;
+    Ok(output_path)
+}
+
 // ===========================================================================
 // End of File
 // ===========================================================================
@@ -39,6 +39,7 @@
 // Module Declarations
 // ============================================================================
 pub mod files;
+pub mod pre_buffer;
 pub mod recording;
 
 // ============================================================================
@@ -58,6 +59,12 @@ pub use files::generate_audio_filename_with_timestamp;
 /// See [`files::save_audio_samples_to_wav_file`] for full documentation.
 pub use files::save_audio_samples_to_wav_file;
 
+// --------------------------------------------------------------------------
+/// Read raw `i16` audio samples back from a `.wav` file.
+///
+/// See [`files::load_audio_samples_from_wav_file`] for full documentation.
+pub use files::load_audio_samples_from_wav_file;
+
 // --------------------------------------------------------------------------
 /// Debug-only helper that records a short chunk of audio and writes it to a
 /// temporary file.  Refer to [`recording::debug_record_audio_to_file`] for
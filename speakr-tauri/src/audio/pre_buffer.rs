@@ -0,0 +1,85 @@
+// ============================================================================
+//! Pre-Record Buffer
+// ============================================================================
+//!
+//! Keeps a long-lived [`AudioRecorder`] in pre-record mode while the user has
+//! enabled it, so dictations can start with the audio captured just before
+//! the hot-key was pressed.
+//!
+//! Privacy: the rolling buffer lives only in memory inside the recorder. It
+//! is never written to disk, logged, or sent anywhere; its contents are only
+//! read when a dictation starts, and disabling the setting closes the
+//! microphone and zeroes the buffer.
+
+use speakr_core::audio::{AudioRecorder, RecordingConfig};
+use speakr_types::{AppError, AppSettings, PRE_BUFFER_SECS};
+use std::sync::{Arc, LazyLock, Mutex, PoisonError};
+use tracing::info;
+
+/// Recorder kept open between dictations while pre-recording is enabled.
+static PRE_BUFFER_RECORDER: LazyLock<Mutex<Option<Arc<AudioRecorder>>>> =
+    LazyLock::new(|| Mutex::new(None));
+
+/// Starts or stops pre-record mode to match the user's settings
+///
+/// While pre-recording, the microphone follows the preferred input devices
+/// and their input gains; a change to them reopens it.
+///
+/// # Arguments
+///
+/// * `settings` - The user's settings
+///
+/// # Errors
+///
+/// Returns `AppError::AudioCapture` if the microphone cannot be opened.
+pub async fn apply_pre_buffer_setting(settings: &AppSettings) -> Result<(), AppError> {
+    if !settings.pre_buffer {
+        stop_pre_buffer();
+        return Ok(());
+    }
+
+    if let Some(recorder) = pre_buffering_recorder() {
+        recorder.set_preferred_devices(settings.input_devices.clone());
+        recorder.set_input_gains(settings.input_gains.clone());
+        return Ok(());
+    }
+
+    let config = RecordingConfig::default()
+        .with_preferred_devices(settings.input_devices.clone())
+        .with_input_gains(settings.input_gains.clone());
+    let recorder = AudioRecorder::new(config)
+        .await
+        .map_err(|e| AppError::AudioCapture(format!("Failed to initialize recorder: {e}")))?;
+    recorder
+        .enable_pre_buffer(PRE_BUFFER_SECS)
+        .map_err(|e| AppError::AudioCapture(format!("Failed to start pre-recording: {e}")))?;
+
+    *PRE_BUFFER_RECORDER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(recorder));
+    info!("Pre-recording started ({}s buffer)", PRE_BUFFER_SECS);
+    Ok(())
+}
+
+/// Stops pre-record mode, closing the microphone and zeroing the buffer
+pub fn stop_pre_buffer() {
+    let recorder = PRE_BUFFER_RECORDER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take();
+    if let Some(recorder) = recorder {
+        recorder.disable_pre_buffer();
+        info!("Pre-recording stopped");
+    }
+}
+
+/// Returns the pre-recording recorder, if pre-record mode is active
+///
+/// Dictations should record with this recorder so they start with the
+/// buffered audio.
+pub fn pre_buffering_recorder() -> Option<Arc<AudioRecorder>> {
+    PRE_BUFFER_RECORDER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
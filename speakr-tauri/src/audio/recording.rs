@@ -22,10 +22,11 @@
 //! ```ignore
 //! use std::path::Path;
 //! use crate::debug_record_real_audio_to_file;
+//! use speakr_types::AudioDuration;
 //!
 //! # #[tokio::main]
 //! # async fn main() -> Result<(), speakr_types::AppError> {
-//! let output = debug_record_real_audio_to_file(Path::new("/tmp"), 3).await?;
+//! let output = debug_record_real_audio_to_file(Path::new("/tmp"), AudioDuration::clamped(3)).await?;
 //! println!("Recorded to {}", output.display());
 //! # Ok(())
 //! # }
@@ -38,7 +39,7 @@
 
 use crate::audio::files::{generate_audio_filename_with_timestamp, save_audio_samples_to_wav_file};
 use speakr_core::audio::{AudioRecorder, RecordingConfig};
-use speakr_types::AppError;
+use speakr_types::{AppError, AudioDuration};
 use std::path::{Path, PathBuf};
 
 // ============================================================================
@@ -110,7 +111,7 @@ pub async fn debug_record_audio_to_file(
 ///
 /// # Arguments
 /// * `output_dir` – Directory where the resulting WAV file will be written.
-/// * `duration_secs` – Number of seconds to capture audio.
+/// * `duration` – How long to capture audio for.
 ///
 /// # Returns
 /// The absolute [`PathBuf`] pointing at the newly created WAV file.
@@ -125,12 +126,12 @@ pub async fn debug_record_audio_to_file(
 #[allow(dead_code)]
 pub async fn debug_record_real_audio_to_file(
     output_dir: &Path,
-    duration_secs: u32,
+    duration: AudioDuration,
 ) -> Result<PathBuf, AppError> {
     // --------------------------------------------------
     // Configure and start the recorder
     // --------------------------------------------------
-    let config = RecordingConfig::new(duration_secs);
+    let config = RecordingConfig::new(duration);
     let recorder = AudioRecorder::new(config)
         .await
         .map_err(|e| AppError::Settings(format!("Failed to create audio recorder: {e}")))?;
@@ -142,7 +143,7 @@ pub async fn debug_record_real_audio_to_file(
 
     // Block the current task for the desired duration. The rest of the
     // application continues to run because we're inside an async runtime.
-    tokio::time::sleep(std::time::Duration::from_secs(duration_secs as u64)).await;
+    tokio::time::sleep(duration.as_duration()).await;
 
     // --------------------------------------------------
     // Finalise recording and persist to disk
@@ -0,0 +1,414 @@
+// ============================================================================
+//! Hot-key Conflict Detection
+// ============================================================================
+//!
+//! A global hot-key that is also a system shortcut either fails to register
+//! or, worse, registers and takes the shortcut away from the rest of the
+//! system. Before a hot-key is accepted it is compared with a curated table
+//! of macOS and Windows shortcuts ([`SYSTEM_SHORTCUTS`]) and with the other
+//! hot-keys Speakr has registered.
+//!
+//! Shortcuts are compared after normalising them: modifier order and case do
+//! not matter, `Command`/`Super`/`Win` are the same modifier, and `CmdOrCtrl`
+//! means `Cmd` on macOS and `Ctrl` elsewhere. The table only covers
+//! shortcuts the operating system itself handles, plus the clipboard ones
+//! every application relies on; it is not meant to be exhaustive.
+
+use speakr_types::RegisteredHotkey;
+
+/// Operating system a [`SystemShortcut`] belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShortcutPlatform {
+    MacOs,
+    Windows,
+}
+
+impl ShortcutPlatform {
+    /// The platform Speakr is running on, if it has a shortcut table
+    pub fn current() -> Option<Self> {
+        if cfg!(target_os = "macos") {
+            Some(Self::MacOs)
+        } else if cfg!(target_os = "windows") {
+            Some(Self::Windows)
+        } else {
+            None
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::MacOs => "macOS",
+            Self::Windows => "Windows",
+        }
+    }
+}
+
+/// A shortcut reserved by the operating system
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SystemShortcut {
+    pub platform: ShortcutPlatform,
+    pub shortcut: &'static str,
+    /// What the shortcut does, e.g. "Spotlight search"
+    pub action: &'static str,
+}
+
+const fn mac(shortcut: &'static str, action: &'static str) -> SystemShortcut {
+    SystemShortcut {
+        platform: ShortcutPlatform::MacOs,
+        shortcut,
+        action,
+    }
+}
+
+const fn windows(shortcut: &'static str, action: &'static str) -> SystemShortcut {
+    SystemShortcut {
+        platform: ShortcutPlatform::Windows,
+        shortcut,
+        action,
+    }
+}
+
+/// Shortcuts a hot-key must not take over
+pub const SYSTEM_SHORTCUTS: &[SystemShortcut] = &[
+    mac("Cmd+Space", "Spotlight search"),
+    mac("Ctrl+Space", "switching input sources"),
+    mac("Ctrl+Cmd+Space", "the Character Viewer"),
+    mac("Cmd+Tab", "switching apps"),
+    mac("Cmd+Shift+Tab", "switching apps"),
+    mac("Cmd+`", "switching windows"),
+    mac("Cmd+Q", "quitting the frontmost app"),
+    mac("Cmd+W", "closing windows"),
+    mac("Cmd+H", "hiding the frontmost app"),
+    mac("Cmd+M", "minimising windows"),
+    mac("Cmd+C", "Copy"),
+    mac("Cmd+V", "Paste"),
+    mac("Cmd+X", "Cut"),
+    mac("Cmd+Z", "Undo"),
+    mac("Cmd+Shift+3", "screenshots"),
+    mac("Cmd+Shift+4", "screenshots of a selection"),
+    mac("Cmd+Shift+5", "the Screenshot toolbar"),
+    mac("Cmd+Alt+Escape", "Force Quit"),
+    mac("Ctrl+Cmd+Q", "locking the screen"),
+    mac("Cmd+Shift+Q", "logging out"),
+    mac("Cmd+Alt+D", "showing and hiding the Dock"),
+    mac("Ctrl+ArrowUp", "Mission Control"),
+    mac("Ctrl+ArrowDown", "App Exposé"),
+    mac("Ctrl+ArrowLeft", "moving to the previous Space"),
+    mac("Ctrl+ArrowRight", "moving to the next Space"),
+    windows("Alt+Tab", "switching windows"),
+    windows("Alt+Shift+Tab", "switching windows"),
+    windows("Alt+F4", "closing windows"),
+    windows("Alt+Space", "the window menu"),
+    windows("Ctrl+Alt+Delete", "the security screen"),
+    windows("Ctrl+Shift+Escape", "Task Manager"),
+    windows("Ctrl+Escape", "the Start menu"),
+    windows("Ctrl+C", "Copy"),
+    windows("Ctrl+V", "Paste"),
+    windows("Ctrl+X", "Cut"),
+    windows("Ctrl+Z", "Undo"),
+    windows("Win+D", "showing the desktop"),
+    windows("Win+E", "File Explorer"),
+    windows("Win+H", "voice typing"),
+    windows("Win+L", "locking the PC"),
+    windows("Win+R", "the Run dialog"),
+    windows("Win+Tab", "Task View"),
+    windows("Win+V", "clipboard history"),
+    windows("Win+.", "the emoji panel"),
+    windows("Win+Shift+S", "Snipping Tool"),
+];
+
+/// Hot-keys offered to the user, best first
+///
+/// Function keys come last: many laptop keyboards need `Fn` to press them.
+pub const SUGGESTED_HOTKEYS: &[&str] = &[
+    "CmdOrCtrl+Alt+Space",
+    "CmdOrCtrl+Shift+Space",
+    "Alt+Shift+Space",
+    "CmdOrCtrl+Alt+D",
+    "CmdOrCtrl+Shift+D",
+    "CmdOrCtrl+Alt+R",
+    "CmdOrCtrl+Alt+Shift+Space",
+    "CmdOrCtrl+Alt+F1",
+    "CmdOrCtrl+Alt+F2",
+    "CmdOrCtrl+Alt+F3",
+];
+
+/// Number of hot-keys suggested when the caller does not ask for a count
+pub const DEFAULT_SUGGESTION_COUNT: usize = 3;
+
+/// Modifiers that can be added to a conflicting hot-key to suggest others
+const ALTERNATIVE_MODIFIERS: &[&str] = &["Alt", "Shift", "Alt+Shift"];
+
+/// Most alternatives offered for a conflicting hot-key
+const MAX_ALTERNATIVES: usize = 2;
+
+/// A shortcut reduced to what the keyboard sees
+#[derive(Debug, PartialEq, Eq)]
+struct KeyCombo {
+    meta: bool,
+    ctrl: bool,
+    alt: bool,
+    shift: bool,
+    key: String,
+}
+
+impl KeyCombo {
+    /// Normalises a shortcut such as `"CmdOrCtrl+Shift+Space"`
+    ///
+    /// Returns `None` if the shortcut has no key or an empty part.
+    fn parse(shortcut: &str, platform: ShortcutPlatform) -> Option<Self> {
+        let mut combo = KeyCombo {
+            meta: false,
+            ctrl: false,
+            alt: false,
+            shift: false,
+            key: String::new(),
+        };
+        for part in shortcut.split('+').map(str::trim) {
+            match part.to_uppercase().as_str() {
+                "" => return None,
+                "CMD" | "COMMAND" | "SUPER" | "META" | "WIN" => combo.meta = true,
+                "CMDORCTRL" | "COMMANDORCONTROL" => match platform {
+                    ShortcutPlatform::MacOs => combo.meta = true,
+                    ShortcutPlatform::Windows => combo.ctrl = true,
+                },
+                "CTRL" | "CONTROL" => combo.ctrl = true,
+                "ALT" | "OPTION" => combo.alt = true,
+                "SHIFT" => combo.shift = true,
+                key => combo.key = normalise_key(key),
+            }
+        }
+        (!combo.key.is_empty()).then_some(combo)
+    }
+}
+
+/// Maps key name aliases (`Esc`, `Up`, `KeyA`, ...) to one spelling
+fn normalise_key(key: &str) -> String {
+    let key = match key {
+        "ESC" => "ESCAPE",
+        "UP" => "ARROWUP",
+        "DOWN" => "ARROWDOWN",
+        "LEFT" => "ARROWLEFT",
+        "RIGHT" => "ARROWRIGHT",
+        "SPACEBAR" => "SPACE",
+        "RETURN" => "ENTER",
+        "DEL" => "DELETE",
+        "BACKQUOTE" => "`",
+        "PERIOD" => ".",
+        key => key,
+    };
+    match key
+        .strip_prefix("KEY")
+        .or_else(|| key.strip_prefix("DIGIT"))
+    {
+        Some(rest) if rest.len() == 1 => rest.to_string(),
+        _ => key.to_string(),
+    }
+}
+
+/// Finds what a hot-key collides with
+///
+/// # Arguments
+///
+/// * `hot_key` - The proposed hot-key
+/// * `platform` - The platform whose system shortcuts apply, if any
+/// * `own_hotkeys` - Speakr's other registered hot-keys
+///
+/// # Returns
+///
+/// Returns a description of the collision, e.g. "Cmd+Space is used by macOS
+/// for Spotlight search", or `None` if the hot-key is free.
+pub fn find_conflict(
+    hot_key: &str,
+    platform: Option<ShortcutPlatform>,
+    own_hotkeys: &[RegisteredHotkey],
+) -> Option<String> {
+    // CmdOrCtrl needs a platform to resolve; off macOS it behaves like Windows
+    let resolve = platform.unwrap_or(ShortcutPlatform::Windows);
+    let combo = KeyCombo::parse(hot_key, resolve)?;
+
+    if let Some(platform) = platform {
+        let system = SYSTEM_SHORTCUTS.iter().find(|shortcut| {
+            shortcut.platform == platform
+                && KeyCombo::parse(shortcut.shortcut, platform).as_ref() == Some(&combo)
+        });
+        if let Some(system) = system {
+            return Some(format!(
+                "{hot_key} is used by {} for {}",
+                platform.name(),
+                system.action
+            ));
+        }
+    }
+
+    own_hotkeys
+        .iter()
+        .find(|own| KeyCombo::parse(&own.shortcut, resolve).as_ref() == Some(&combo))
+        .map(|own| format!("{hot_key} is already used by Speakr ({})", own.action))
+}
+
+/// Suggests hot-keys close to a conflicting one that are free
+///
+/// Alternatives keep the modifiers and key of `hot_key` and add `Alt`,
+/// `Shift` or both, skipping any that collide themselves.
+pub fn suggest_alternatives(
+    hot_key: &str,
+    platform: Option<ShortcutPlatform>,
+    own_hotkeys: &[RegisteredHotkey],
+) -> Vec<String> {
+    let Some((modifiers, key)) = hot_key.trim().rsplit_once('+') else {
+        return Vec::new();
+    };
+    let present: Vec<String> = modifiers.split('+').map(str::to_uppercase).collect();
+
+    ALTERNATIVE_MODIFIERS
+        .iter()
+        .filter(|extra| {
+            extra
+                .split('+')
+                .all(|modifier| !present.contains(&modifier.to_uppercase()))
+        })
+        .map(|extra| format!("{modifiers}+{extra}+{key}"))
+        .filter(|candidate| find_conflict(candidate, platform, own_hotkeys).is_none())
+        .take(MAX_ALTERNATIVES)
+        .collect()
+}
+
+/// Picks hot-keys from [`SUGGESTED_HOTKEYS`] that are free to use
+///
+/// Candidates that collide with a system or Speakr shortcut are skipped
+/// without calling `registers_cleanly`, which is only called until `count`
+/// candidates have passed.
+///
+/// # Arguments
+///
+/// * `platform` - The platform whose system shortcuts apply, if any
+/// * `own_hotkeys` - Speakr's other registered hot-keys
+/// * `count` - Most hot-keys to return
+/// * `registers_cleanly` - Whether a candidate can be registered right now
+pub fn suggest_hotkeys(
+    platform: Option<ShortcutPlatform>,
+    own_hotkeys: &[RegisteredHotkey],
+    count: usize,
+    mut registers_cleanly: impl FnMut(&str) -> bool,
+) -> Vec<String> {
+    SUGGESTED_HOTKEYS
+        .iter()
+        .filter(|candidate| find_conflict(candidate, platform, own_hotkeys).is_none())
+        .filter(|candidate| registers_cleanly(candidate))
+        .take(count)
+        .map(|candidate| candidate.to_string())
+        .collect()
+}
+
+/// Describes a conflict and the alternatives to it for the user
+pub fn describe_conflict(conflict: &str, alternatives: &[String]) -> String {
+    match alternatives {
+        [] => format!("{conflict}."),
+        [only] => format!("{conflict}. Try {only} instead."),
+        [rest @ .., last] => format!("{conflict}. Try {} or {last} instead.", rest.join(", ")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn own(shortcut: &str, action: &str) -> RegisteredHotkey {
+        RegisteredHotkey {
+            shortcut: shortcut.to_string(),
+            action: action.to_string(),
+            registered_at: 0,
+            last_triggered_at: None,
+        }
+    }
+
+    #[test]
+    fn test_system_shortcuts_match_regardless_of_spelling() {
+        let mac = Some(ShortcutPlatform::MacOs);
+        assert_eq!(
+            find_conflict("CmdOrCtrl+Space", mac, &[]).as_deref(),
+            Some("CmdOrCtrl+Space is used by macOS for Spotlight search")
+        );
+        assert!(find_conflict("CMD+SPACE", mac, &[]).is_some());
+        assert!(find_conflict("Shift+Cmd+4", mac, &[]).is_some());
+        assert!(find_conflict("Command+KeyC", mac, &[]).is_some());
+        assert!(find_conflict("Cmd+Shift+Space", mac, &[]).is_none());
+    }
+
+    #[test]
+    fn test_system_shortcuts_are_per_platform() {
+        let windows = Some(ShortcutPlatform::Windows);
+        assert!(find_conflict("Alt+Tab", windows, &[]).is_some());
+        assert!(find_conflict("CmdOrCtrl+V", windows, &[]).is_some());
+        assert!(find_conflict("Super+L", windows, &[]).is_some());
+        assert!(find_conflict("CmdOrCtrl+Space", windows, &[]).is_none());
+
+        assert!(find_conflict("Alt+Tab", Some(ShortcutPlatform::MacOs), &[]).is_none());
+        assert!(find_conflict("Alt+Tab", None, &[]).is_none());
+    }
+
+    #[test]
+    fn test_own_hotkeys_conflict() {
+        let hotkeys = [own("CmdOrCtrl+Alt+P", "Toggle privacy mode")];
+        assert_eq!(
+            find_conflict("Cmd+Alt+P", Some(ShortcutPlatform::MacOs), &hotkeys).as_deref(),
+            Some("Cmd+Alt+P is already used by Speakr (Toggle privacy mode)")
+        );
+        assert!(find_conflict("Ctrl+Alt+P", None, &hotkeys).is_some());
+        assert!(find_conflict("Ctrl+Alt+O", None, &hotkeys).is_none());
+    }
+
+    #[test]
+    fn test_alternatives_avoid_further_conflicts() {
+        let hotkeys = [own("Cmd+Alt+Space", "Dictation (de)")];
+        let alternatives =
+            suggest_alternatives("Cmd+Space", Some(ShortcutPlatform::MacOs), &hotkeys);
+        assert_eq!(alternatives, ["Cmd+Shift+Space", "Cmd+Alt+Shift+Space"]);
+
+        let windows = Some(ShortcutPlatform::Windows);
+        assert_eq!(
+            suggest_alternatives("Ctrl+C", windows, &[]),
+            ["Ctrl+Alt+C", "Ctrl+Shift+C"]
+        );
+        assert!(suggest_alternatives("Alt+Tab", windows, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_suggestions_skip_conflicts_and_taken_shortcuts() {
+        let hotkeys = [own("CmdOrCtrl+Shift+Space", "Toggle privacy mode")];
+        let mut tried = Vec::new();
+        let suggestions = suggest_hotkeys(Some(ShortcutPlatform::MacOs), &hotkeys, 2, |c| {
+            tried.push(c.to_string());
+            c != "Alt+Shift+Space"
+        });
+
+        // Cmd+Alt+D shows the Dock, so it is never tried
+        assert_eq!(suggestions, ["CmdOrCtrl+Alt+Space", "CmdOrCtrl+Shift+D"]);
+        assert_eq!(
+            tried,
+            [
+                "CmdOrCtrl+Alt+Space",
+                "Alt+Shift+Space",
+                "CmdOrCtrl+Shift+D"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_suggested_hotkeys_are_valid_everywhere() {
+        for platform in [ShortcutPlatform::MacOs, ShortcutPlatform::Windows] {
+            let suggestions = suggest_hotkeys(Some(platform), &[], usize::MAX, |_| true);
+            assert!(suggestions.len() >= DEFAULT_SUGGESTION_COUNT);
+        }
+    }
+
+    #[test]
+    fn test_describe_conflict_lists_alternatives() {
+        assert_eq!(describe_conflict("X is used", &[]), "X is used.");
+        assert_eq!(
+            describe_conflict("X is used", &["A".to_string(), "B".to_string()]),
+            "X is used. Try A or B instead."
+        );
+    }
+}
@@ -170,11 +170,11 @@ mod tests {
     async fn test_register_hot_key_internal_various_formats() {
         // Test various valid formats
         let valid_hotkeys = vec![
-            "Cmd+Space",
+            "Cmd+Shift+Space",
             "CmdOrCtrl+Alt+V",
             "Shift+F1",
-            "Ctrl+Alt+Delete",
-            "Alt+Tab",
+            "Ctrl+Alt+D",
+            "Alt+T",
         ];
 
         for hotkey in valid_hotkeys {
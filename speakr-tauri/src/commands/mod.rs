@@ -4,7 +4,12 @@
 //!
 //! This module organises all Tauri commands by functional domain:
 //! - `validation` - Input validation commands
+//! - `hotkey_conflicts` - System shortcut table used by hot-key validation
 //! - `system` - System integration commands
+//! - `permissions` - macOS privacy permission checks
+//! - `plugins` - Post-processing plugin management
+//! - `storage` - Disk space used by Speakr's data and its cleanup
+//! - `transcription` - Offline transcription of existing recordings
 //! - `legacy` - Backward-compatibility commands
 //!
 //! # Architecture
@@ -15,6 +20,10 @@
 //!
 //! This separation allows for easy testing of command logic without Tauri overhead.
 //!
+//! Internal functions return `AppError`; wrappers convert it into the
+//! `CommandError` envelope (code, message, recoverability) so every command
+//! rejects with the same shape on the frontend.
+//!
 //! # Adding New Commands
 //!
 //! To add a new command:
@@ -26,7 +35,7 @@
 //! # Example Structure
 //!
 //! ```rust,no_run
-//! use speakr_types::AppError;
+//! use speakr_types::{AppError, CommandError};
 //!
 //! // In commands/validation.rs
 //! pub async fn validate_input_internal(input: String) -> Result<(), AppError> {
@@ -36,8 +45,10 @@
 //!
 //! // In lib.rs
 //! #[tauri::command]
-//! async fn validate_input(input: String) -> Result<(), AppError> {
-//!     validate_input_internal(input).await
+//! async fn validate_input(input: String) -> Result<(), CommandError> {
+//!     validate_input_internal(input)
+//!         .await
+//!         .map_err(CommandError::from)
 //! }
 //! ```
 
@@ -45,15 +56,33 @@
 // Module Declarations
 // =========================
 
+/// Detection of hot-keys that collide with system shortcuts or with
+/// Speakr's own hot-keys, and free alternatives to suggest instead.
+pub mod hotkey_conflicts;
+
 /// Backward-compatibility command implementations that will be removed in a
 /// future major release. Keep additions here minimal and prefer creating new
 /// commands in their own dedicated modules.
 pub mod legacy;
 
+/// Microphone and accessibility permission checks that drive the first-run
+/// onboarding, and deep links to the matching System Settings panes.
+pub mod permissions;
+
+/// Installation and listing of sandboxed post-processing plugins.
+pub mod plugins;
+
+/// Disk space used by models, recordings, logs and settings, and one-click
+/// cleanup of each.
+pub mod storage;
+
 /// Commands that interact with the underlying operating system, for example
 /// reading the file-system or configuring auto-launch settings.
 pub mod system;
 
+/// Transcription of audio files (WAV, MP3, M4A) dropped onto the app.
+pub mod transcription;
+
 /// Pure validation helpers that perform synchronous checks and return
 /// structured `AppError`s without touching the outside world. These helpers
 /// are units of business-logic and can be reused from e.g. settings services.
@@ -0,0 +1,219 @@
+// ============================================================================
+//! Permission Commands
+// ============================================================================
+//!
+//! Dictation needs two macOS privacy permissions: the microphone, to record,
+//! and accessibility, to type into other applications. These commands read
+//! their status from the system's privacy database (TCC) so the first-run
+//! onboarding can walk the user through granting them, and open the matching
+//! System Settings pane.
+//!
+//! A missing permission marks the affected service
+//! [`ServiceStatus::Unavailable`]. Permissions granted later are picked up
+//! without a restart: checking again marks the service ready, and
+//! [`spawn_permission_watcher`] checks missing permissions every few
+//! seconds, emitting [`PERMISSION_GRANTED_EVENT`] once one is granted.
+//! Other platforms have no such permissions, so both checks report
+//! [`ServiceStatus::Ready`] there.
+
+use crate::services::pause::is_dictation_enabled;
+use crate::services::{
+    get_backend_status_internal, update_global_service_status, ServiceComponent,
+};
+use speakr_types::{AppError, PermissionKind, ServiceStatus, PERMISSION_GRANTED_EVENT};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_opener::OpenerExt;
+use tracing::{info, warn};
+
+/// How often missing permissions are checked again.
+const PERMISSION_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Checks whether Speakr may record from the microphone.
+///
+/// # Returns
+///
+/// Returns [`ServiceStatus::Ready`] if access was granted, or
+/// [`ServiceStatus::Unavailable`] if it was denied, restricted, or not asked
+/// for yet.
+pub async fn check_microphone_permission_internal() -> ServiceStatus {
+    check_permission(PermissionKind::Microphone).await
+}
+
+/// Checks whether Speakr may type into other applications.
+///
+/// # Returns
+///
+/// Returns [`ServiceStatus::Ready`] if Speakr is a trusted accessibility
+/// client, or [`ServiceStatus::Unavailable`] otherwise.
+pub async fn check_accessibility_permission_internal() -> ServiceStatus {
+    check_permission(PermissionKind::Accessibility).await
+}
+
+/// Opens the System Settings pane where a permission is granted.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+/// * `kind` - The permission whose pane to open
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if System Settings cannot be opened.
+pub async fn open_permission_settings_internal(
+    app_handle: &AppHandle,
+    kind: PermissionKind,
+) -> Result<(), AppError> {
+    info!(
+        "Opening System Settings for the {} permission",
+        kind.display_name()
+    );
+    app_handle
+        .opener()
+        .open_url(kind.settings_url(), None::<&str>)
+        .map_err(|e| AppError::Settings(format!("Failed to open System Settings: {e}")))
+}
+
+/// Watches for missing permissions being granted while Speakr runs
+///
+/// Every [`PERMISSION_POLL_INTERVAL`], each permission whose service is
+/// unavailable is checked again. Once it has been granted the service is
+/// marked ready and [`PERMISSION_GRANTED_EVENT`] is emitted, so the
+/// onboarding step and status panel update without a restart. Nothing is
+/// checked while dictation is paused, since every service is unavailable
+/// then.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle used to emit events
+pub fn spawn_permission_watcher(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(PERMISSION_POLL_INTERVAL);
+        loop {
+            interval.tick().await;
+            if !is_dictation_enabled() {
+                continue;
+            }
+            for kind in PermissionKind::ALL {
+                if is_granted(kind) && restore_service(&permission_component(kind)).await {
+                    info!("{} permission granted", kind.display_name());
+                    if let Err(e) = app_handle.emit(PERMISSION_GRANTED_EVENT, kind) {
+                        warn!("Failed to emit {}: {}", PERMISSION_GRANTED_EVENT, e);
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Returns the service that cannot work without a permission.
+fn permission_component(kind: PermissionKind) -> ServiceComponent {
+    match kind {
+        PermissionKind::Microphone => ServiceComponent::AudioCapture,
+        PermissionKind::Accessibility => ServiceComponent::TextInjection,
+    }
+}
+
+/// Asks the system whether a permission has been granted.
+fn is_granted(kind: PermissionKind) -> bool {
+    match kind {
+        PermissionKind::Microphone => platform::microphone_authorized(),
+        PermissionKind::Accessibility => platform::accessibility_trusted(),
+    }
+}
+
+/// Maps a permission check to a service status, marking the service that
+/// needs it unavailable when it is missing and ready again once granted.
+async fn check_permission(kind: PermissionKind) -> ServiceStatus {
+    let component = permission_component(kind);
+    if is_granted(kind) {
+        if is_dictation_enabled() && restore_service(&component).await {
+            info!("{} permission granted", kind.display_name());
+        }
+        return ServiceStatus::Ready;
+    }
+
+    warn!("{} permission has not been granted", kind.display_name());
+    update_global_service_status(component, ServiceStatus::Unavailable).await;
+    ServiceStatus::Unavailable
+}
+
+/// Marks a service ready if it is unavailable.
+///
+/// # Returns
+///
+/// Returns `true` if the service was unavailable.
+async fn restore_service(component: &ServiceComponent) -> bool {
+    let unavailable = get_backend_status_internal()
+        .await
+        .is_ok_and(|status| *component.status_in(&status) == ServiceStatus::Unavailable);
+    if unavailable {
+        update_global_service_status(component.clone(), ServiceStatus::Ready).await;
+    }
+    unavailable
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use objc2_application_services::AXIsProcessTrusted;
+    use objc2_av_foundation::{AVAuthorizationStatus, AVCaptureDevice, AVMediaTypeAudio};
+
+    pub fn microphone_authorized() -> bool {
+        // SAFETY: an immutable string constant exported by AVFoundation; it
+        // is never written, so reading it cannot race
+        let Some(audio) = (unsafe { AVMediaTypeAudio }) else {
+            return false;
+        };
+        // SAFETY: `audio` is a valid media type, and the query is thread-safe
+        // and only reads the app's authorisation state
+        let status = unsafe { AVCaptureDevice::authorizationStatusForMediaType(audio) };
+        status == AVAuthorizationStatus::Authorized
+    }
+
+    pub fn accessibility_trusted() -> bool {
+        // SAFETY: takes no arguments and only reads the process's trust state;
+        // it may be called from any thread
+        unsafe { AXIsProcessTrusted() }
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+mod platform {
+    pub fn microphone_authorized() -> bool {
+        true
+    }
+
+    pub fn accessibility_trusted() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(target_os = "macos"))]
+    #[tokio::test]
+    async fn test_permissions_are_granted_without_tcc() {
+        assert_eq!(
+            check_microphone_permission_internal().await,
+            ServiceStatus::Ready
+        );
+        assert_eq!(
+            check_accessibility_permission_internal().await,
+            ServiceStatus::Ready
+        );
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    #[tokio::test]
+    async fn test_granted_permission_makes_the_service_ready_again() {
+        update_global_service_status(ServiceComponent::TextInjection, ServiceStatus::Unavailable)
+            .await;
+        check_accessibility_permission_internal().await;
+
+        let status = get_backend_status_internal().await.unwrap();
+        assert_eq!(status.text_injection, ServiceStatus::Ready);
+        assert!(!restore_service(&ServiceComponent::TextInjection).await);
+    }
+}
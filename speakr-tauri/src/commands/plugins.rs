@@ -0,0 +1,117 @@
+// ============================================================================
+//! Post-processing Plugin Commands
+// ============================================================================
+//!
+//! This module provides commands for managing post-processing plugins:
+//! installing a WebAssembly module into the plugin directory, listing what is
+//! installed, and removing plugins. Enabling a plugin is a settings change
+//! (`AppSettings::plugins`), so it goes through `save_settings`.
+
+use speakr_core::plugins::{PluginError, PluginManager};
+use speakr_types::{AppError, PluginInfo};
+use std::path::PathBuf;
+use tracing::{debug, info};
+
+/// Lists the plugins installed in the plugin directory.
+///
+/// # Returns
+///
+/// Returns the installed plugins sorted by name. Modules that are not valid
+/// plugins are included with their `error` set.
+///
+/// # Errors
+///
+/// Returns `AppError::FileSystem` if the plugin directory cannot be read.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use speakr_lib::commands::plugins::list_plugins_internal;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// for plugin in list_plugins_internal().await.unwrap() {
+///     println!("{} ({} bytes)", plugin.name, plugin.size_bytes);
+/// }
+/// # }
+/// ```
+pub async fn list_plugins_internal() -> Result<Vec<PluginInfo>, AppError> {
+    let manager = PluginManager::new();
+    let plugins = manager.list().await.map_err(plugin_error)?;
+
+    debug!(
+        directory = %manager.dir().display(),
+        count = plugins.len(),
+        "Listed plugins"
+    );
+
+    Ok(plugins)
+}
+
+/// Installs a WebAssembly plugin from a file on disk.
+///
+/// # Arguments
+///
+/// * `path` - Path to the `.wasm` module; its file stem becomes the plugin name
+///
+/// # Returns
+///
+/// Returns the installed plugin. It is not enabled until added to settings.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if the module is not a valid plugin, and
+/// `AppError::FileSystem` if it cannot be read or copied.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use speakr_lib::commands::plugins::install_plugin_internal;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let plugin = install_plugin_internal("/tmp/title-case.wasm".to_string()).await.unwrap();
+/// println!("Installed {}", plugin.name);
+/// # }
+/// ```
+pub async fn install_plugin_internal(path: String) -> Result<PluginInfo, AppError> {
+    let plugin = PluginManager::new()
+        .install(&PathBuf::from(&path))
+        .await
+        .map_err(plugin_error)?;
+
+    info!(plugin = %plugin.name, source = %path, "Installed plugin");
+    Ok(plugin)
+}
+
+/// Removes an installed plugin.
+///
+/// # Arguments
+///
+/// * `name` - Name of the plugin to remove
+///
+/// # Returns
+///
+/// Returns `Ok(())` once the module has been deleted.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` for an invalid name and `AppError::FileSystem`
+/// if the module cannot be removed.
+pub async fn uninstall_plugin_internal(name: String) -> Result<(), AppError> {
+    PluginManager::new()
+        .uninstall(&name)
+        .await
+        .map_err(plugin_error)?;
+
+    info!(plugin = %name, "Uninstalled plugin");
+    Ok(())
+}
+
+/// Maps plugin manager errors onto the application's error type.
+fn plugin_error(error: PluginError) -> AppError {
+    match error {
+        PluginError::Io(e) => AppError::FileSystem(format!("Plugin file error: {e}")),
+        other => AppError::Settings(other.to_string()),
+    }
+}
@@ -0,0 +1,230 @@
+// ============================================================================
+//! Storage Usage Commands
+// ============================================================================
+//!
+//! Reports the disk space Speakr uses in each of its data directories (see
+//! [`StorageCategory`]) and cleans them up on request. Directories are walked
+//! asynchronously, including subdirectories; a directory that does not exist
+//! yet uses no space.
+
+use crate::settings::{get_settings_dir, load_settings_internal};
+use speakr_core::transcription::models::ModelManager;
+use speakr_types::{
+    AppError, StorageCategory, StorageCategoryUsage, StorageUsage, DENOISE_MODEL_FILENAME,
+};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+use tokio::fs;
+use tracing::info;
+
+/// Returns the directory dictation and debug recordings are saved to.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if the Documents directory cannot be found.
+pub fn recordings_dir() -> Result<PathBuf, AppError> {
+    let documents_dir = dirs::document_dir()
+        .ok_or_else(|| AppError::Settings("Could not find Documents directory".to_string()))?;
+    Ok(documents_dir.join("Speakr").join("debug_recordings"))
+}
+
+/// Returns the directory holding a category's data.
+fn category_dir(app_handle: &AppHandle, category: StorageCategory) -> Result<PathBuf, AppError> {
+    match category {
+        StorageCategory::Models => Ok(ModelManager::new().cache_dir().to_path_buf()),
+        StorageCategory::Recordings => recordings_dir(),
+        StorageCategory::Logs => app_handle
+            .path()
+            .app_log_dir()
+            .map_err(|e| AppError::FileSystem(format!("Could not find log directory: {e}"))),
+        StorageCategory::Settings => get_settings_dir(),
+    }
+}
+
+/// Measures the files in a directory and its subdirectories.
+///
+/// # Returns
+///
+/// Returns the combined size in bytes and the number of files; both are 0
+/// if the directory does not exist.
+///
+/// # Errors
+///
+/// Returns `AppError::Io` if a directory cannot be read.
+pub async fn directory_usage(dir: &Path) -> Result<(u64, usize), AppError> {
+    let mut total_bytes = 0;
+    let mut file_count = 0;
+    let mut pending = vec![dir.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        let mut entries = match fs::read_dir(&dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(AppError::io("Failed to read directory", &e, Some(&dir))),
+        };
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| AppError::io("Failed to read directory", &e, Some(&dir)))?
+        {
+            let Ok(metadata) = entry.metadata().await else {
+                continue;
+            };
+            if metadata.is_dir() {
+                pending.push(entry.path());
+            } else if metadata.is_file() {
+                total_bytes += metadata.len();
+                file_count += 1;
+            }
+        }
+    }
+
+    Ok((total_bytes, file_count))
+}
+
+/// Deletes everything inside a directory, keeping the directory itself.
+///
+/// # Errors
+///
+/// Returns `AppError::Io` if an entry cannot be removed.
+pub async fn clear_directory(dir: &Path) -> Result<(), AppError> {
+    let mut entries = match fs::read_dir(dir).await {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(AppError::io("Failed to read directory", &e, Some(dir))),
+    };
+    while let Some(entry) = entries
+        .next_entry()
+        .await
+        .map_err(|e| AppError::io("Failed to read directory", &e, Some(dir)))?
+    {
+        let path = entry.path();
+        let removed = if entry.file_type().await.is_ok_and(|kind| kind.is_dir()) {
+            fs::remove_dir_all(&path).await
+        } else {
+            fs::remove_file(&path).await
+        };
+        removed.map_err(|e| AppError::io("Failed to delete", &e, Some(&path)))?;
+    }
+    Ok(())
+}
+
+/// Reports the disk space used by each kind of data Speakr keeps.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle, used to find the log directory
+///
+/// # Returns
+///
+/// Returns the usage of every [`StorageCategory`], in
+/// [`StorageCategory::ALL`] order.
+///
+/// # Errors
+///
+/// Returns an `AppError` if a directory cannot be found or read.
+pub async fn get_storage_usage_internal(app_handle: &AppHandle) -> Result<StorageUsage, AppError> {
+    let mut categories = Vec::with_capacity(StorageCategory::ALL.len());
+    for category in StorageCategory::ALL {
+        let dir = category_dir(app_handle, category)?;
+        let (total_bytes, file_count) = directory_usage(&dir).await?;
+        categories.push(StorageCategoryUsage {
+            category,
+            directory: dir.display().to_string(),
+            total_bytes,
+            file_count,
+        });
+    }
+    Ok(StorageUsage { categories })
+}
+
+/// Frees the disk space used by a category.
+///
+/// Models other than the selected one and the noise suppression weights are
+/// removed; recordings and logs are deleted. Settings cannot be cleaned up.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle, used to find the log directory
+/// * `category` - The kind of data to clean up
+///
+/// # Returns
+///
+/// Returns the storage usage afterwards.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` for [`StorageCategory::Settings`], or an
+/// `AppError` if files cannot be removed.
+pub async fn clean_up_storage_internal(
+    app_handle: &AppHandle,
+    category: StorageCategory,
+) -> Result<StorageUsage, AppError> {
+    match category {
+        StorageCategory::Models => remove_unused_models().await?,
+        StorageCategory::Recordings | StorageCategory::Logs => {
+            clear_directory(&category_dir(app_handle, category)?).await?
+        }
+        StorageCategory::Settings => {
+            return Err(AppError::Settings(
+                "Settings cannot be cleaned up".to_string(),
+            ))
+        }
+    }
+    info!(category = ?category, "Cleaned up storage");
+    get_storage_usage_internal(app_handle).await
+}
+
+/// Removes installed models other than the selected one and the RNNoise weights.
+async fn remove_unused_models() -> Result<(), AppError> {
+    let selected = load_settings_internal().await?.model.filename();
+    let manager = ModelManager::new();
+    let installed = manager
+        .installed_models(false)
+        .await
+        .map_err(|e| AppError::FileSystem(format!("Failed to read model directory: {e}")))?;
+
+    for model in installed {
+        if model.filename == selected || model.filename == DENOISE_MODEL_FILENAME {
+            continue;
+        }
+        manager.delete_model(&model.filename).await.map_err(|e| {
+            AppError::FileSystem(format!("Failed to delete model {}: {e}", model.filename))
+        })?;
+        info!(filename = %model.filename, "Removed unused model");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_directory_usage_includes_subdirectories() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("a.log"), [0u8; 10]).unwrap();
+        std::fs::create_dir(dir.path().join("nested")).unwrap();
+        std::fs::write(dir.path().join("nested").join("b.log"), [0u8; 5]).unwrap();
+
+        assert_eq!(directory_usage(dir.path()).await.unwrap(), (15, 2));
+        assert_eq!(
+            directory_usage(&dir.path().join("missing")).await.unwrap(),
+            (0, 0)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_clear_directory_keeps_the_directory() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("a.wav"), [0u8; 10]).unwrap();
+        std::fs::create_dir(dir.path().join("nested")).unwrap();
+        std::fs::write(dir.path().join("nested").join("b.wav"), [0u8; 5]).unwrap();
+
+        clear_directory(dir.path()).await.unwrap();
+        assert!(dir.path().exists());
+        assert_eq!(directory_usage(dir.path()).await.unwrap(), (0, 0));
+        clear_directory(&dir.path().join("missing")).await.unwrap();
+    }
+}
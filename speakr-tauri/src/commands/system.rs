@@ -3,16 +3,52 @@
 // ============================================================================
 //!
 //! This module provides commands for system-level functionality including
-//! model file availability checking and auto-launch management.
+//! model file availability checking, model storage management (Whisper
+//! models and the RNNoise denoise weights), supported transcription
+//! languages, connected microphones and the microphone test, and auto-launch
+//! management.
 
-use speakr_types::AppError;
-use tracing::{debug, warn};
+use crate::services::auto_launch::{is_auto_launch_enabled, set_auto_launch};
+use crate::services::connectivity::connectivity;
+use crate::services::event_throttle::{Throttle, ThrottledEmitter};
+use crate::services::tray::set_tray_status;
+use crate::settings::load_settings_internal;
+use crate::workflow::is_dictation_active;
+use speakr_core::audio::denoise::{is_denoise_model_installed, DENOISE_MODEL_URL};
+use speakr_core::audio::{gain, AudioRecorder, RecordingConfig};
+use speakr_core::model::{Model, ModelMetadata};
+use speakr_core::transcription::language::supported_languages;
+use speakr_core::transcription::models::{ModelManager, ModelManagerError};
+use speakr_types::{
+    AppError, AudioDuration, CatalogueModel, DiskSpaceCheck, DownloadProgress, InstalledModel,
+    LanguageOption, MicrophoneTest, ModelId, ModelsDiskUsage, CLIPPING_DBFS,
+    DENOISE_MODEL_FILENAME, MIC_TEST_SECS, MODEL_DOWNLOAD_PROGRESS_EVENT,
+};
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+use tracing::{debug, info, warn};
+use zeroize::Zeroizing;
 
-/// Checks if a model file exists for the given model size.
+/// Minimum time between download progress events sent to the frontend.
+const DOWNLOAD_PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Minimum time between tray tooltip updates during a download.
+const TRAY_STATUS_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Tray status while a download waits for the network.
+const OFFLINE_TRAY_STATUS: &str = "Offline — downloads will resume automatically";
+
+/// Free space that must remain after a download, so the disk is not filled.
+const DOWNLOAD_HEADROOM_BYTES: u64 = 500 * 1024 * 1024;
+
+/// Checks if a catalogue model has been downloaded.
+///
+/// Looks in the directory the transcription engine loads from
+/// (`SPEAKR_MODELS_DIR` or the platform data directory).
 ///
 /// # Arguments
 ///
-/// * `model_size` - The model size to check ("small", "medium", "large")
+/// * `model` - The model to check, e.g. "small.en"
 ///
 /// # Returns
 ///
@@ -20,69 +56,426 @@ use tracing::{debug, warn};
 ///
 /// # Errors
 ///
-/// Returns `AppError::Settings` if the model size is not recognised.
-///
-/// # Supported Model Sizes
-///
-/// - `"small"` - Maps to `ggml-small.bin`
-/// - `"medium"` - Maps to `ggml-medium.bin`
-/// - `"large"` - Maps to `ggml-large.bin`
+/// Returns `AppError::Settings` if the model is not in the catalogue.
 ///
 /// # Examples
 ///
 /// ```rust,no_run
 /// use speakr_lib::commands::system::check_model_availability_internal;
+/// use speakr_types::ModelId;
 ///
 /// # #[tokio::main]
 /// # async fn main() {
-/// let is_available = check_model_availability_internal("small".to_string()).await.unwrap();
+/// let is_available = check_model_availability_internal(ModelId::new("small")).await.unwrap();
 /// println!("Small model available: {}", is_available);
 /// # }
 /// ```
-pub async fn check_model_availability_internal(model_size: String) -> Result<bool, AppError> {
-    let filename = match model_size.as_str() {
-        "small" => "ggml-small.bin",
-        "medium" => "ggml-medium.bin",
-        "large" => "ggml-large.bin",
-        _ => {
-            return Err(AppError::Settings(format!(
-                "Unknown model size: {model_size}"
-            )));
-        }
-    };
-
-    // Check in models directory relative to the app
-    let models_dir = std::env::current_dir()
-        .map_err(|e| AppError::FileSystem(format!("Failed to get current dir: {e}")))?
-        .join("models");
+pub async fn check_model_availability_internal(model: ModelId) -> Result<bool, AppError> {
+    if Model::from_id(&model).is_none() {
+        return Err(AppError::Settings(format!("Unknown model: {model}")));
+    }
 
-    let model_path = models_dir.join(filename);
+    let model_path = ModelManager::new().cache_dir().join(model.filename());
     let exists = model_path.exists();
 
     debug!(
-        model_size = %model_size,
-        filename = %filename,
+        model = %model,
         path = %model_path.display(),
         exists = %exists,
         "Checked model availability"
     );
 
-    // Additional file system checks for better error reporting
-    if !exists {
-        if !models_dir.exists() {
-            debug!(
-                models_dir = %models_dir.display(),
-                "Models directory does not exist"
-            );
-        } else if !model_path.parent().is_some_and(|p| p.exists()) {
+    Ok(exists)
+}
+
+/// Lists every model in the catalogue for the settings model picker.
+///
+/// # Returns
+///
+/// Returns the catalogue models in catalogue order (smallest family first),
+/// each with its published size and checksum and whether it is installed.
+pub async fn list_model_catalogue_internal() -> Result<Vec<CatalogueModel>, AppError> {
+    let manager = ModelManager::new();
+    let catalogue = Model::iter()
+        .map(|model| {
+            let metadata = ModelMetadata::for_model(&model);
+            CatalogueModel {
+                id: model.id(),
+                installed: manager.cache_dir().join(&metadata.filename).exists(),
+                size_bytes: metadata.size_bytes,
+                sha: metadata.sha256,
+                memory_mb: model.memory_usage_mb(),
+            }
+        })
+        .collect();
+    Ok(catalogue)
+}
+
+/// Checks whether the RNNoise weights used by the `denoise` setting have
+/// been downloaded.
+///
+/// # Returns
+///
+/// Returns `Ok(true)` if the weights are in the model directory.
+pub async fn check_denoise_model_internal() -> Result<bool, AppError> {
+    Ok(is_denoise_model_installed(&ModelManager::new()))
+}
+
+/// Lists the model files installed in the configured model directory.
+///
+/// The directory is the one the transcription engine loads from
+/// (`SPEAKR_MODELS_DIR` or the platform data directory).
+///
+/// # Arguments
+///
+/// * `verify_checksums` - Hash each catalogue model and compare it against its
+///   published checksum. This reads every file in full, so it is slow for
+///   large models.
+///
+/// # Returns
+///
+/// Returns the installed models sorted by filename.
+///
+/// # Errors
+///
+/// Returns `AppError::FileSystem` if the directory or a model file cannot be
+/// read.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use speakr_lib::commands::system::list_installed_models_internal;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// for model in list_installed_models_internal(false).await.unwrap() {
+///     println!("{} ({} MB)", model.filename, model.size_mb());
+/// }
+/// # }
+/// ```
+pub async fn list_installed_models_internal(
+    verify_checksums: bool,
+) -> Result<Vec<InstalledModel>, AppError> {
+    let manager = ModelManager::new();
+    let installed = manager
+        .installed_models(verify_checksums)
+        .await
+        .map_err(|e| AppError::FileSystem(format!("Failed to list installed models: {e}")))?;
+
+    debug!(
+        directory = %manager.cache_dir().display(),
+        count = installed.len(),
+        verify_checksums,
+        "Listed installed models"
+    );
+
+    Ok(installed)
+}
+
+/// Deletes an installed model file from the configured model directory.
+///
+/// # Arguments
+///
+/// * `filename` - Bare file name of the model (e.g. `ggml-base.en.bin`)
+///
+/// # Returns
+///
+/// Returns `Ok(())` once the file has been removed.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if `filename` is not a model file name, and
+/// `AppError::FileSystem` if the file cannot be removed.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use speakr_lib::commands::system::delete_model_internal;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// delete_model_internal("ggml-tiny.bin".to_string()).await.unwrap();
+/// # }
+/// ```
+pub async fn delete_model_internal(filename: String) -> Result<(), AppError> {
+    ModelManager::new()
+        .delete_model(&filename)
+        .await
+        .map_err(|e| match e {
+            ModelManagerError::InvalidFilename(_) => AppError::Settings(e.to_string()),
+            _ => AppError::FileSystem(format!("Failed to delete model {filename}: {e}")),
+        })?;
+
+    info!(filename = %filename, "Deleted model");
+    Ok(())
+}
+
+/// Downloads a catalogue model, or the RNNoise weights, into the configured
+/// model directory.
+///
+/// Progress, including speed and ETA over a sliding window, is emitted as
+/// [`DownloadProgress`] on [`MODEL_DOWNLOAD_PROGRESS_EVENT`] and mirrored in
+/// the tray tooltip. While the model host is unreachable the download waits
+/// and starts over once the network returns.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle used to emit progress
+/// * `filename` - Catalogue file name of the model (e.g. `ggml-small.bin`),
+///   or [`DENOISE_MODEL_FILENAME`]
+///
+/// # Returns
+///
+/// Returns `Ok(())` once the model is downloaded and verified. The catalogue
+/// a model was downloaded from is recorded for the Models view.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` for an unknown model and
+/// `AppError::FileSystem` if there is not enough disk space, or the download
+/// or checksum verification fails.
+pub async fn download_model_internal(
+    app_handle: AppHandle,
+    filename: String,
+) -> Result<(), AppError> {
+    let (url, checksum) = download_source(&filename)?;
+    let space = check_disk_space_for_model_internal(&filename)?;
+    if !space.is_sufficient() {
+        return Err(AppError::FileSystem(space.shortfall_message(&filename)));
+    }
+
+    info!(filename = %filename, "Downloading model");
+    let progress_events = ThrottledEmitter::new(
+        &app_handle,
+        MODEL_DOWNLOAD_PROGRESS_EVENT,
+        DOWNLOAD_PROGRESS_INTERVAL,
+    );
+    let mut tray_throttle = Throttle::new(TRAY_STATUS_INTERVAL, false);
+    let connectivity = connectivity();
+
+    // Wait out offline periods instead of failing, both before starting and
+    // when the connection drops mid-download
+    let result = loop {
+        if !connectivity.check().await {
+            info!(filename = %filename, "Offline, download will start when the network returns");
+            set_tray_status(&app_handle, Some(OFFLINE_TRAY_STATUS));
+            connectivity.wait_until_online().await;
+        }
+
+        let result = ModelManager::new()
+            .download_model_with_progress(&url, checksum, |progress| {
+                progress_events.emit(progress.clone());
+                if let Some(progress) = tray_throttle.offer(progress.clone(), Instant::now()) {
+                    set_tray_status(&app_handle, Some(&tray_status(&progress)));
+                }
+            })
+            .await;
+
+        match result {
+            Err(e) if e.is_connection_error() => {
+                if connectivity.check().await {
+                    break Err(e);
+                }
+                warn!(filename = %filename, "Connection lost, retrying download when back online");
+            }
+            result => break result,
+        }
+    };
+
+    progress_events.flush();
+    set_tray_status(&app_handle, None);
+
+    let path =
+        result.map_err(|e| AppError::FileSystem(format!("Failed to download {filename}: {e}")))?;
+    info!(path = %path.display(), "Model downloaded");
+
+    if let Some(model) = catalogue_model(&filename) {
+        let metadata = ModelMetadata::for_model(&model);
+        if let Err(e) = ModelManager::new().record_source(&metadata).await {
+            warn!(filename = %filename, "Failed to record model catalogue: {}", e);
+        }
+    }
+    Ok(())
+}
+
+/// Checks whether the model directory has room to download a model.
+///
+/// The download needs the model's catalogue size plus
+/// [`DOWNLOAD_HEADROOM_BYTES`] free on the disk holding the model directory.
+///
+/// # Arguments
+///
+/// * `filename` - Catalogue file name of the model (e.g. `ggml-small.bin`),
+///   or [`DENOISE_MODEL_FILENAME`]
+///
+/// # Returns
+///
+/// Returns the space required and available; `available_bytes` is `None`
+/// if the disk could not be determined, in which case the download is
+/// allowed.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` for an unknown model.
+pub fn check_disk_space_for_model_internal(filename: &str) -> Result<DiskSpaceCheck, AppError> {
+    // The RNNoise weights are well under a megabyte
+    let download_bytes = if filename == DENOISE_MODEL_FILENAME {
+        0
+    } else {
+        catalogue_model(filename)
+            .map(|model| ModelMetadata::for_model(&model).size_bytes)
+            .ok_or_else(|| AppError::Settings(format!("Unknown model: {filename}")))?
+    };
+    Ok(DiskSpaceCheck {
+        required_bytes: download_bytes + DOWNLOAD_HEADROOM_BYTES,
+        available_bytes: ModelManager::new().available_space(),
+    })
+}
+
+/// Finds the catalogue model stored under `filename`.
+fn catalogue_model(filename: &str) -> Option<Model> {
+    Model::iter().find(|model| format!("ggml-{}.bin", model.filename()) == filename)
+}
+
+/// Returns the URL and expected checksum of a downloadable model file.
+///
+/// The RNNoise weights are not published with a checksum, so they are
+/// downloaded unverified.
+fn download_source(filename: &str) -> Result<(String, Option<&'static str>), AppError> {
+    if filename == DENOISE_MODEL_FILENAME {
+        return Ok((DENOISE_MODEL_URL.to_string(), None));
+    }
+    catalogue_model(filename)
+        .map(|model| (model.url(), Some(model.sha())))
+        .ok_or_else(|| AppError::Settings(format!("Unknown model: {filename}")))
+}
+
+/// Formats a download for the tray tooltip.
+fn tray_status(progress: &DownloadProgress) -> String {
+    format!("Downloading {}: {}", progress.filename, progress.summary())
+}
+
+/// Reports how much disk space installed models are using.
+///
+/// # Returns
+///
+/// Returns the scanned directory, the combined size and the number of models.
+///
+/// # Errors
+///
+/// Returns `AppError::FileSystem` if the model directory cannot be read.
+pub async fn get_models_disk_usage_internal() -> Result<ModelsDiskUsage, AppError> {
+    ModelManager::new()
+        .disk_usage()
+        .await
+        .map_err(|e| AppError::FileSystem(format!("Failed to read model directory: {e}")))
+}
+
+/// Lists the transcription languages supported by Whisper.
+///
+/// # Returns
+///
+/// Returns the supported languages in Whisper's order. The `"auto"` setting
+/// is not part of the list; the frontend offers it as a separate choice.
+///
+/// # Errors
+///
+/// This function currently never fails; it returns `Result` for consistency
+/// with other commands.
+pub async fn get_supported_languages_internal() -> Result<Vec<LanguageOption>, AppError> {
+    Ok(supported_languages())
+}
+
+/// Lists the names of the connected input devices.
+///
+/// The names are what the `input_devices` setting refers to.
+///
+/// # Returns
+///
+/// Returns the device names, with the system default first.
+///
+/// # Errors
+///
+/// Returns `AppError::AudioCapture` if no microphone is connected or the
+/// devices cannot be enumerated.
+pub async fn list_input_devices_internal() -> Result<Vec<String>, AppError> {
+    let recorder = AudioRecorder::new(RecordingConfig::default())
+        .await
+        .map_err(|e| AppError::AudioCapture(e.to_string()))?;
+    let mut devices = recorder
+        .list_input_devices()
+        .await
+        .map_err(|e| AppError::AudioCapture(e.to_string()))?;
+    devices.sort_by_key(|device| !device.is_default);
+    Ok(devices.into_iter().map(|device| device.name).collect())
+}
+
+/// Records a short test from the microphone and measures its levels.
+///
+/// Records from the device a dictation would use (see the `input_devices`
+/// setting), at its input gain. The audio stays in memory and is wiped once
+/// measured.
+///
+/// # Returns
+///
+/// Returns the peak and RMS levels of [`MIC_TEST_SECS`] seconds of audio,
+/// and whether it clipped.
+///
+/// # Errors
+///
+/// Returns `AppError::AudioCapture` if a dictation is in progress, no
+/// microphone is connected, or the recording fails.
+pub async fn test_microphone_internal() -> Result<MicrophoneTest, AppError> {
+    if is_dictation_active() {
+        return Err(AppError::AudioCapture(
+            "A dictation is in progress; try again when it has finished".to_string(),
+        ));
+    }
+    let (input_devices, input_gains) = match load_settings_internal().await {
+        Ok(settings) => (settings.input_devices, settings.input_gains),
+        Err(e) => {
             warn!(
-                parent_dir = %model_path.parent().unwrap().display(),
-                "Model parent directory does not exist"
+                "Failed to load settings, testing the default microphone: {}",
+                e
             );
+            (Vec::new(), Vec::new())
         }
-    }
+    };
+    let config = RecordingConfig::new(AudioDuration::clamped(MIC_TEST_SECS))
+        .with_preferred_devices(input_devices)
+        .with_input_gains(input_gains);
+    let recorder = AudioRecorder::new(config)
+        .await
+        .map_err(|e| AppError::AudioCapture(e.to_string()))?;
+    recorder
+        .start_recording()
+        .await
+        .map_err(|e| AppError::AudioCapture(e.to_string()))?;
+    let device = recorder.input_device();
+    tokio::time::sleep(Duration::from_secs(u64::from(MIC_TEST_SECS))).await;
+    let samples = Zeroizing::new(
+        recorder
+            .stop_recording()
+            .await
+            .map_err(|e| AppError::AudioCapture(e.to_string()))?
+            .samples(),
+    );
 
-    Ok(exists)
+    let peak_dbfs = gain::peak_dbfs(&samples);
+    let test = MicrophoneTest {
+        device,
+        peak_dbfs,
+        rms_dbfs: gain::level_dbfs(&samples),
+        clipping: peak_dbfs >= CLIPPING_DBFS,
+    };
+    info!(
+        device = test.device.as_deref().unwrap_or("default"),
+        "Microphone test: peak {:.1} dBFS, RMS {:.1} dBFS{}",
+        test.peak_dbfs,
+        test.rms_dbfs,
+        if test.clipping { ", clipping" } else { "" }
+    );
+    Ok(test)
 }
 
 /// Sets the auto-launch preference for the application.
@@ -97,15 +490,15 @@ pub async fn check_model_availability_internal(model_size: String) -> Result<boo
 ///
 /// # Errors
 ///
-/// Returns `AppError::System` if the auto-launch configuration fails.
+/// Returns an `AppError` if the login item cannot be registered or removed.
 ///
 /// # Platform Support
 ///
-/// Currently provides a placeholder implementation. Full implementation
-/// will use platform-specific APIs:
-/// - macOS: Launch Services and Login Items
-/// - Windows: Registry startup entries
-/// - Linux: XDG autostart specification
+/// - macOS: LaunchAgent in `~/Library/LaunchAgents`
+/// - Windows: `Run` registry key of the current user
+/// - Linux: XDG autostart entry
+///
+/// See [`crate::services::auto_launch`].
 ///
 /// # Examples
 ///
@@ -123,111 +516,103 @@ pub async fn check_model_availability_internal(model_size: String) -> Result<boo
 /// ```
 pub async fn set_auto_launch_internal(enable: bool) -> Result<(), AppError> {
     debug!(enable = %enable, "Setting auto-launch preference");
+    set_auto_launch(enable)
+}
 
-    // TODO: Implement actual auto-launch registration using system APIs
-    //
-    // Implementation roadmap:
-    // 1. macOS: Use `tauri-plugin-autostart` or native Launch Services
-    // 2. Windows: Registry entries in HKEY_CURRENT_USER\Software\Microsoft\Windows\CurrentVersion\Run
-    // 3. Linux: Create .desktop file in ~/.config/autostart/
-    //
-    // For now, this is a placeholder that accepts the preference but doesn't
-    // actually configure system auto-launch. The setting could be persisted
-    // in app settings for future implementation.
-
-    if enable {
-        debug!("Auto-launch enabled (placeholder implementation)");
-        // Future: Register with system startup
-    } else {
-        debug!("Auto-launch disabled (placeholder implementation)");
-        // Future: Unregister from system startup
-    }
-
-    // TODO: Re-implement error simulation with proper test isolation
-    // The current approach using global environment variables causes
-    // test interference in parallel execution. Need dependency injection.
-    #[cfg(test)]
-    {
-        // Temporarily disabled due to test isolation issues
-        // Will be re-implemented with proper dependency injection in future PR
-        let _unused = std::env::var("SIMULATE_AUTOLAUNCH_ERROR");
-    }
-
-    Ok(())
+/// Reports whether Speakr is actually registered to start at login.
+///
+/// The system state can differ from the `auto_launch` setting, e.g. if the
+/// user removed the login item or moved the app.
+///
+/// # Returns
+///
+/// Returns `true` if a login item starts this copy of Speakr.
+///
+/// # Errors
+///
+/// Returns an `AppError` if the login item cannot be read.
+pub async fn get_auto_launch_status_internal() -> Result<bool, AppError> {
+    is_auto_launch_enabled()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::env;
 
     #[tokio::test]
-    async fn test_check_model_availability_internal_valid_sizes() {
-        // Test valid model sizes
-        let small_result = check_model_availability_internal("small".to_string()).await;
-        assert!(small_result.is_ok());
-
-        let medium_result = check_model_availability_internal("medium".to_string()).await;
-        assert!(medium_result.is_ok());
-
-        let large_result = check_model_availability_internal("large".to_string()).await;
-        assert!(large_result.is_ok());
+    async fn test_check_model_availability_internal_catalogue_models() {
+        for model in ["small", "medium", "large", "base.en-q5_1"] {
+            assert!(check_model_availability_internal(ModelId::new(model))
+                .await
+                .is_ok());
+        }
     }
 
     #[tokio::test]
-    async fn test_check_model_availability_internal_invalid_size() {
-        let result = check_model_availability_internal("invalid".to_string()).await;
+    async fn test_check_model_availability_internal_unknown_model() {
+        let result = check_model_availability_internal(ModelId::new("invalid")).await;
         assert!(result.is_err());
 
         if let Err(AppError::Settings(msg)) = result {
-            assert!(msg.contains("Unknown model size: invalid"));
+            assert!(msg.contains("Unknown model: invalid"));
         } else {
             panic!("Expected AppError::Settings");
         }
     }
 
     #[tokio::test]
-    async fn test_check_model_availability_internal_empty_size() {
-        let result = check_model_availability_internal("".to_string()).await;
+    async fn test_check_model_availability_internal_empty_model() {
+        let result = check_model_availability_internal(ModelId::new("")).await;
         assert!(result.is_err());
     }
 
-    #[tokio::test]
-    async fn test_set_auto_launch_internal_enable() {
-        // Ensure clean test environment
-        env::remove_var("SIMULATE_AUTOLAUNCH_ERROR");
-
-        let result = set_auto_launch_internal(true).await;
-        assert!(result.is_ok());
+    #[test]
+    fn test_check_disk_space_for_model() {
+        let check = check_disk_space_for_model_internal("ggml-tiny.bin").unwrap();
+        assert!(check.required_bytes > DOWNLOAD_HEADROOM_BYTES);
+        assert_eq!(
+            check_disk_space_for_model_internal(DENOISE_MODEL_FILENAME)
+                .unwrap()
+                .required_bytes,
+            DOWNLOAD_HEADROOM_BYTES
+        );
+        assert!(check_disk_space_for_model_internal("ggml-unknown.bin").is_err());
     }
 
     #[tokio::test]
-    async fn test_set_auto_launch_internal_disable() {
-        // Ensure clean test environment
-        env::remove_var("SIMULATE_AUTOLAUNCH_ERROR");
+    async fn test_list_model_catalogue_covers_every_model() {
+        let catalogue = list_model_catalogue_internal().await.unwrap();
+        assert_eq!(catalogue.len(), Model::iter().count());
+        let turbo = catalogue
+            .iter()
+            .find(|model| model.id.as_str() == "large-v3-turbo")
+            .unwrap();
+        assert_eq!(turbo.sha, Model::LargeV3Turbo.sha());
+        assert!(turbo.size_bytes > 0);
+    }
 
-        let result = set_auto_launch_internal(false).await;
-        assert!(result.is_ok());
+    #[test]
+    fn test_download_source_includes_denoise_weights() {
+        let (url, checksum) = download_source(DENOISE_MODEL_FILENAME).unwrap();
+        assert!(url.ends_with(&format!("/{DENOISE_MODEL_FILENAME}")));
+        assert_eq!(checksum, None);
+
+        let (url, checksum) = download_source("ggml-small.bin").unwrap();
+        assert!(url.ends_with("/ggml-small.bin"));
+        assert!(checksum.is_some());
+
+        assert!(matches!(
+            download_source("ggml-unknown.bin"),
+            Err(AppError::Settings(_))
+        ));
     }
 
     #[tokio::test]
-    async fn test_set_auto_launch_internal_simulated_error() {
-        // TODO: This test is temporarily disabled due to test isolation issues
-        // The error simulation using global environment variables causes
-        // interference with other tests running in parallel.
-        // Will be re-implemented with proper dependency injection.
-
-        // For now, just test that the function works normally
-        let result = set_auto_launch_internal(true).await;
-        assert!(
-            result.is_ok(),
-            "Function should work normally when simulation is disabled"
-        );
-
-        let result = set_auto_launch_internal(false).await;
-        assert!(
-            result.is_ok(),
-            "Function should work normally when simulation is disabled"
-        );
+    async fn test_get_supported_languages_internal() {
+        let languages = get_supported_languages_internal().await.unwrap();
+        assert!(languages
+            .iter()
+            .any(|l| l.code == "en" && l.name == "English"));
+        assert!(!languages.iter().any(|l| l.code == "auto"));
     }
 }
@@ -0,0 +1,234 @@
+// ============================================================================
+//! File Transcription Commands
+// ============================================================================
+//!
+//! Transcribes existing recordings dropped onto the app, without the
+//! microphone. The file is decoded and resampled by
+//! [`speakr_core::audio::decode`] and transcribed by the resident Whisper
+//! model, using the same model and language settings as dictation. The text
+//! is returned to the caller rather than typed into another application.
+//!
+//! Whole folders can be queued as a batch (see [`crate::services::batch`]),
+//! which writes the results next to each file instead. A single result can
+//! be exported as SubRip or WebVTT captions.
+
+use crate::services::batch;
+use crate::services::model_manager::resident_engine;
+use crate::settings::GlobalSettingsLoader;
+use crate::workflow::create_transcription_config_with_loader;
+use speakr_core::audio::{
+    self,
+    decode::{decode_audio_file, list_audio_files},
+};
+use speakr_core::pipeline;
+use speakr_core::subtitles::SubtitleExport;
+use speakr_types::{
+    AppError, BatchJob, BatchProgress, SubtitleFormat, TranscriptionResult, BATCH_PROGRESS_EVENT,
+};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use tracing::{info, warn};
+
+/// Transcribes a WAV, MP3 or M4A file.
+///
+/// # Arguments
+///
+/// * `path` - The audio file to transcribe
+///
+/// # Returns
+///
+/// Returns the full [`TranscriptionResult`], including segments and word
+/// timings.
+///
+/// # Errors
+///
+/// Returns `AppError::AudioCapture` if the file cannot be decoded, or
+/// `AppError::Transcription` if the model cannot be loaded or transcription
+/// fails.
+pub async fn transcribe_file_internal(path: PathBuf) -> Result<TranscriptionResult, AppError> {
+    info!("Transcribing audio file {}", path.display());
+
+    let samples = decode_file(path.clone()).await?;
+    let result = transcribe_samples(samples).await?;
+
+    info!(
+        "Transcribed {} in {:?}",
+        path.display(),
+        result.processing_time
+    );
+    Ok(result)
+}
+
+/// Queues the audio files in a folder for batch transcription.
+///
+/// Files are transcribed in the background, `parallelism` at a time, and
+/// each result is written next to its file as `.txt` and `.srt` sidecars.
+/// Progress is emitted on [`BATCH_PROGRESS_EVENT`].
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle, for progress events
+/// * `folder` - The folder whose audio files to transcribe (not recursive)
+/// * `parallelism` - Files to transcribe at once, between 1 and
+///   [`MAX_BATCH_PARALLELISM`]
+///
+/// # Returns
+///
+/// Returns the queued [`BatchJob`] without waiting for it to finish.
+///
+/// # Errors
+///
+/// Returns `AppError::FileSystem` if the folder cannot be read or contains
+/// no supported audio files.
+///
+/// [`BATCH_PROGRESS_EVENT`]: speakr_types::BATCH_PROGRESS_EVENT
+/// [`MAX_BATCH_PARALLELISM`]: speakr_types::MAX_BATCH_PARALLELISM
+pub async fn transcribe_folder_internal(
+    app_handle: AppHandle,
+    folder: PathBuf,
+    parallelism: usize,
+) -> Result<BatchJob, AppError> {
+    let files = list_audio_files(&folder)
+        .map_err(|e| AppError::FileSystem(format!("Failed to read {}: {e}", folder.display())))?;
+    if files.is_empty() {
+        return Err(AppError::FileSystem(format!(
+            "No WAV, MP3 or M4A files in {}",
+            folder.display()
+        )));
+    }
+
+    let job = batch::new_job(&folder, &files);
+    info!(
+        "Queued batch {} with {} files from {}",
+        job.id,
+        files.len(),
+        folder.display()
+    );
+
+    let queued = job.clone();
+    tauri::async_runtime::spawn(async move {
+        let failures = batch::run_batch(
+            &queued,
+            parallelism,
+            transcribe_to_sidecars,
+            |progress: &BatchProgress| {
+                if let Err(e) = app_handle.emit(BATCH_PROGRESS_EVENT, progress) {
+                    warn!("Failed to emit batch progress: {}", e);
+                }
+            },
+        )
+        .await;
+        info!(
+            "Batch {} finished: {} of {} files failed",
+            queued.id,
+            failures,
+            queued.files.len()
+        );
+    });
+
+    Ok(job)
+}
+
+/// Saves a transcription as a subtitle file.
+///
+/// Cues follow the result's segments; a result without segments becomes a
+/// single cue.
+///
+/// # Arguments
+///
+/// * `result` - The transcription to export
+/// * `format` - SubRip or WebVTT
+/// * `path` - The file to write, replaced if it exists
+///
+/// # Errors
+///
+/// Returns `AppError::Io` if the file cannot be written.
+pub async fn export_transcription_internal(
+    result: &TranscriptionResult,
+    format: SubtitleFormat,
+    path: PathBuf,
+) -> Result<(), AppError> {
+    let subtitles = result.to_subtitles(format, result.end_time());
+    tokio::fs::write(&path, subtitles)
+        .await
+        .map_err(|e| AppError::io("Failed to write subtitles", &e, Some(&path)))?;
+    info!("Exported transcription to {}", path.display());
+    Ok(())
+}
+
+/// Transcribes one file of a batch and writes its sidecars.
+async fn transcribe_to_sidecars(file: PathBuf) -> Result<(), AppError> {
+    let samples = decode_file(file.clone()).await?;
+    let duration = Duration::from_secs_f64(samples.len() as f64 / f64::from(audio::SAMPLE_RATE_HZ));
+    let result = transcribe_samples(samples).await?;
+    batch::write_sidecars(&file, &result, duration).await
+}
+
+/// Decodes an audio file off the async runtime.
+async fn decode_file(path: PathBuf) -> Result<Vec<i16>, AppError> {
+    tokio::task::spawn_blocking(move || decode_audio_file(&path))
+        .await
+        .map_err(|e| AppError::AudioCapture(format!("Decoding task failed: {e}")))?
+        .map_err(|e| AppError::AudioCapture(e.to_string()))
+}
+
+/// Transcribes decoded samples with the resident model and current settings.
+pub(crate) async fn transcribe_samples(samples: Vec<i16>) -> Result<TranscriptionResult, AppError> {
+    let config = create_transcription_config_with_loader(Arc::new(GlobalSettingsLoader)).await;
+    let engine = resident_engine(&config)
+        .await
+        .map_err(|e| AppError::Transcription(e.to_string()))?;
+    pipeline::transcription_pipeline_with_engine(samples, &engine)
+        .await
+        .map_err(|e| AppError::Transcription(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use speakr_types::{ModelId, TranscriptionSegment};
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_unsupported_file_is_an_audio_error() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("notes.txt");
+        std::fs::write(&path, "hello").unwrap();
+
+        let result = transcribe_file_internal(path).await;
+        assert!(matches!(result, Err(AppError::AudioCapture(_))));
+    }
+
+    #[tokio::test]
+    async fn test_export_transcription_writes_webvtt() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("memo.vtt");
+        let result = TranscriptionResult {
+            text: "Hello world".to_string(),
+            language: None,
+            confidence: 0.9,
+            avg_logprob: None,
+            processing_time: Duration::ZERO,
+            memory_delta_bytes: 0,
+            model_used: ModelId::new("small"),
+            segments: vec![TranscriptionSegment {
+                text: " Hello world".to_string(),
+                start_time: Duration::from_millis(250),
+                end_time: Duration::from_millis(1_500),
+                confidence: 0.9,
+                words: vec![],
+            }],
+        };
+
+        export_transcription_internal(&result, SubtitleFormat::WebVtt, path.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            "WEBVTT\n\n00:00:00.250 --> 00:00:01.500\nHello world\n"
+        );
+    }
+}
@@ -5,6 +5,10 @@
 //! This module provides validation functions for user input, particularly
 //! for hotkey format validation and input sanitisation.
 
+use crate::commands::hotkey_conflicts::{
+    describe_conflict, find_conflict, suggest_alternatives, ShortcutPlatform,
+};
+use crate::services::hotkey::other_registered_hotkeys;
 use speakr_types::AppError;
 use tracing::warn;
 
@@ -25,6 +29,11 @@ use tracing::warn;
 /// - Required modifiers are missing
 /// - Unsupported key combinations are used
 ///
+/// Returns `AppError::HotKeyConflict` if the hot-key is a system shortcut
+/// (see [`SYSTEM_SHORTCUTS`](crate::commands::hotkey_conflicts::SYSTEM_SHORTCUTS))
+/// or another of Speakr's hot-keys. The message names what it collides with
+/// and suggests free alternatives.
+///
 /// # Supported Formats
 ///
 /// - `Cmd+Key` (macOS Command key)
@@ -113,6 +122,16 @@ pub async fn validate_hot_key_internal(hot_key: String) -> Result<(), AppError>
         );
     }
 
+    let platform = ShortcutPlatform::current();
+    let own_hotkeys = other_registered_hotkeys();
+    if let Some(conflict) = find_conflict(hot_key, platform, &own_hotkeys) {
+        let alternatives = suggest_alternatives(hot_key, platform, &own_hotkeys);
+        return Err(AppError::HotKeyConflict(describe_conflict(
+            &conflict,
+            &alternatives,
+        )));
+    }
+
     Ok(())
 }
 
@@ -123,12 +142,12 @@ mod tests {
     #[tokio::test]
     async fn test_validate_hot_key_internal_valid_combinations() {
         // Valid single modifier combinations
-        assert!(validate_hot_key_internal("CmdOrCtrl+Space".to_string())
-            .await
-            .is_ok());
-        assert!(validate_hot_key_internal("Alt+Tab".to_string())
-            .await
-            .is_ok());
+        assert!(
+            validate_hot_key_internal("CmdOrCtrl+Shift+Space".to_string())
+                .await
+                .is_ok()
+        );
+        assert!(validate_hot_key_internal("Alt+T".to_string()).await.is_ok());
         assert!(validate_hot_key_internal("Shift+F1".to_string())
             .await
             .is_ok());
@@ -140,11 +159,26 @@ mod tests {
         assert!(validate_hot_key_internal("Cmd+Shift+V".to_string())
             .await
             .is_ok());
-        assert!(validate_hot_key_internal("Ctrl+Alt+Delete".to_string())
+        assert!(validate_hot_key_internal("Ctrl+Alt+D".to_string())
             .await
             .is_ok());
     }
 
+    #[tokio::test]
+    async fn test_validate_hot_key_internal_rejects_system_shortcuts() {
+        let Some(platform) = ShortcutPlatform::current() else {
+            return;
+        };
+        let shortcut = match platform {
+            ShortcutPlatform::MacOs => "Cmd+Space",
+            ShortcutPlatform::Windows => "Alt+Tab",
+        };
+        assert!(matches!(
+            validate_hot_key_internal(shortcut.to_string()).await,
+            Err(AppError::HotKeyConflict(_))
+        ));
+    }
+
     #[tokio::test]
     async fn test_validate_hot_key_internal_invalid_format() {
         // Empty or whitespace-only input
@@ -0,0 +1,516 @@
+// ============================================================================
+//! Local Crash Reports
+// ============================================================================
+//!
+//! When Speakr crashes, a report is written to the `crashes/` directory next
+//! to the settings and the user is offered to open it on the next launch.
+//! Reports stay on this Mac; nothing is ever uploaded.
+//!
+//! Two kinds of crash are caught:
+//!
+//! 1. Panics, on any thread, through a panic hook that writes the report
+//!    straight away.
+//! 2. Anything else that ends the process without a clean exit, such as a
+//!    native crash during inference. A session marker is written at startup
+//!    and removed on a clean exit; if it is still there at the next launch,
+//!    an "unexpected exit" report is written from it.
+//!
+//! Reports hold the app version, the platform, the panic location and
+//! backtrace, and the last pipeline stage. They never hold audio or dictated
+//! text: quoted parts of panic messages are redacted (see
+//! [`redact_message`]) and only the stage name of pipeline events is kept.
+//!
+//! The latest crash is recorded as a [`CrashNotice`], emitted on
+//! [`CRASH_REPORTED_EVENT`] at startup and shown until the user dismisses it.
+
+use crate::settings::get_settings_dir;
+use speakr_types::{AppError, CrashNotice, PipelineEvent, CRASH_REPORTED_EVENT};
+use std::backtrace::Backtrace;
+use std::fs;
+use std::panic::{self, PanicHookInfo};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock, PoisonError};
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_opener::OpenerExt;
+use tracing::{error, info, warn};
+
+/// Name of the directory, next to the settings, holding crash reports.
+const CRASHES_DIR: &str = "crashes";
+
+/// Name of the file that exists while Speakr runs.
+const SESSION_MARKER_FILE: &str = "session.running";
+
+/// Name of the file holding the notice until it is dismissed.
+const CRASH_NOTICE_FILE: &str = "crash.notice";
+
+/// Stage recorded before the first dictation.
+const IDLE_STATE: &str = "Idle";
+
+/// Directory crash reports are written to, once the handler is installed.
+static CRASHES_PATH: OnceLock<PathBuf> = OnceLock::new();
+
+/// Stage of the most recent dictation.
+static PIPELINE_STATE: Mutex<Option<String>> = Mutex::new(None);
+
+/// Set once a panic has been reported, so the session marker is not
+/// reported again as an unexpected exit.
+static PANIC_REPORTED: AtomicBool = AtomicBool::new(false);
+
+/// How a crash was detected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrashKind {
+    /// Rust code panicked
+    Panic,
+    /// The previous run ended without a clean exit
+    UnexpectedExit,
+}
+
+/// Redacted details of a crash
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrashReport {
+    /// How the crash was detected
+    pub kind: CrashKind,
+    /// When the crash happened or was detected, in Unix milliseconds
+    pub crashed_at: u64,
+    /// The panic message, with quoted parts redacted
+    pub message: Option<String>,
+    /// Source location of the panic
+    pub location: Option<String>,
+    /// Name of the thread that panicked
+    pub thread: Option<String>,
+    /// Backtrace of the panic
+    pub backtrace: Option<String>,
+    /// Stage of the most recent dictation
+    pub pipeline_state: Option<String>,
+}
+
+impl CrashReport {
+    /// Builds a report for a panic that is happening now
+    fn from_panic(info: &PanicHookInfo<'_>) -> Self {
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned());
+
+        Self {
+            kind: CrashKind::Panic,
+            crashed_at: now_millis(),
+            message: message.as_deref().map(redact_message),
+            location: info
+                .location()
+                .map(|location| format!("{}:{}", location.file(), location.line())),
+            thread: std::thread::current().name().map(str::to_string),
+            backtrace: Some(Backtrace::force_capture().to_string()),
+            pipeline_state: pipeline_state(),
+        }
+    }
+
+    /// One line describing the crash
+    pub fn summary(&self) -> String {
+        match (self.kind, &self.location) {
+            (CrashKind::Panic, Some(location)) => format!("Panic at {location}"),
+            (CrashKind::Panic, None) => "Panic".to_string(),
+            (CrashKind::UnexpectedExit, _) => "Speakr quit unexpectedly".to_string(),
+        }
+    }
+
+    /// Renders the report as plain text
+    pub fn render(&self) -> String {
+        let mut lines = vec![
+            format!("Speakr crash report: {}", self.summary()),
+            format!("Version: {}", env!("CARGO_PKG_VERSION")),
+            format!(
+                "Platform: {} {}",
+                std::env::consts::OS,
+                std::env::consts::ARCH
+            ),
+            format!("Crashed at: {} (Unix ms)", self.crashed_at),
+            format!(
+                "Last pipeline state: {}",
+                self.pipeline_state.as_deref().unwrap_or("unknown")
+            ),
+        ];
+        if let Some(thread) = &self.thread {
+            lines.push(format!("Thread: {thread}"));
+        }
+        if let Some(message) = &self.message {
+            lines.push(format!("Message: {message}"));
+        }
+        if let Some(backtrace) = &self.backtrace {
+            lines.push(String::new());
+            lines.push("Backtrace:".to_string());
+            lines.push(backtrace.clone());
+        }
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+/// Removes quoted text from a panic message
+///
+/// Error values often quote the data they failed on, which may be dictated
+/// text, so anything between matching double or single quotes is replaced
+/// with `<redacted>`. An unmatched quote redacts the rest of the message.
+pub fn redact_message(message: &str) -> String {
+    let mut redacted = String::with_capacity(message.len());
+    let mut quote = None;
+    for c in message.chars() {
+        match quote {
+            None if c == '"' || c == '\'' => {
+                quote = Some(c);
+                redacted.push(c);
+                redacted.push_str("<redacted>");
+            }
+            None => redacted.push(c),
+            Some(open) if c == open => {
+                quote = None;
+                redacted.push(c);
+            }
+            Some(_) => {}
+        }
+    }
+    redacted
+}
+
+/// Writes a crash report and records the notice for the next launch.
+///
+/// # Arguments
+///
+/// * `dir` - The crashes directory
+/// * `report` - The crash to record
+///
+/// # Returns
+///
+/// Returns the notice pointing at the saved report.
+///
+/// # Errors
+///
+/// Returns `AppError::Io` if the report or notice cannot be written.
+pub fn save_crash_report_in_dir(dir: &Path, report: &CrashReport) -> Result<CrashNotice, AppError> {
+    fs::create_dir_all(dir)
+        .map_err(|e| AppError::io("Failed to create crashes directory", &e, Some(dir)))?;
+    let path = dir.join(format!("crash-{}.txt", report.crashed_at));
+    fs::write(&path, report.render())
+        .map_err(|e| AppError::io("Failed to write crash report", &e, Some(&path)))?;
+
+    let notice = CrashNotice {
+        report_path: path.display().to_string(),
+        summary: report.summary(),
+        crashed_at: report.crashed_at,
+    };
+    let notice_path = dir.join(CRASH_NOTICE_FILE);
+    let json = serde_json::to_string_pretty(&notice)
+        .map_err(|e| AppError::Settings(format!("Failed to serialise crash notice: {e}")))?;
+    fs::write(&notice_path, json)
+        .map_err(|e| AppError::io("Failed to write crash notice", &e, Some(&notice_path)))?;
+    Ok(notice)
+}
+
+/// Starts a session, reporting the previous one if it did not exit cleanly.
+///
+/// # Arguments
+///
+/// * `dir` - The crashes directory
+///
+/// # Returns
+///
+/// Returns the notice for the previous session, if it ended unexpectedly.
+///
+/// # Errors
+///
+/// Returns `AppError::Io` if the marker or report cannot be written.
+pub fn start_session_in_dir(dir: &Path) -> Result<Option<CrashNotice>, AppError> {
+    fs::create_dir_all(dir)
+        .map_err(|e| AppError::io("Failed to create crashes directory", &e, Some(dir)))?;
+    let marker = dir.join(SESSION_MARKER_FILE);
+
+    let notice = match fs::read_to_string(&marker) {
+        Ok(state) => {
+            let report = CrashReport {
+                kind: CrashKind::UnexpectedExit,
+                crashed_at: now_millis(),
+                message: None,
+                location: None,
+                thread: None,
+                backtrace: None,
+                pipeline_state: Some(state.trim().to_string()).filter(|state| !state.is_empty()),
+            };
+            Some(save_crash_report_in_dir(dir, &report)?)
+        }
+        Err(_) => None,
+    };
+
+    fs::write(&marker, IDLE_STATE)
+        .map_err(|e| AppError::io("Failed to write session marker", &e, Some(&marker)))?;
+    Ok(notice)
+}
+
+/// Loads the notice from a specific directory.
+///
+/// # Returns
+///
+/// Returns the notice, or `None` if there is none or it has been dismissed.
+///
+/// # Errors
+///
+/// Returns `AppError::Io` if the file cannot be read, or
+/// `AppError::Settings` if it cannot be parsed.
+pub fn load_crash_notice_from_dir(dir: &Path) -> Result<Option<CrashNotice>, AppError> {
+    let path = dir.join(CRASH_NOTICE_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(&path)
+        .map_err(|e| AppError::io("Failed to read crash notice", &e, Some(&path)))?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| AppError::Settings(format!("Failed to parse crash notice: {e}")))
+}
+
+/// Deletes the notice in `dir`; the report is kept.
+///
+/// # Errors
+///
+/// Returns `AppError::Io` if the notice exists but cannot be removed.
+pub fn dismiss_crash_notice_in_dir(dir: &Path) -> Result<(), AppError> {
+    let path = dir.join(CRASH_NOTICE_FILE);
+    match fs::remove_file(&path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(AppError::io(
+            "Failed to dismiss crash notice",
+            &e,
+            Some(&path),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Returns the directory crash reports are written to.
+///
+/// # Errors
+///
+/// Returns `AppError` if the settings directory cannot be found.
+pub fn crashes_dir() -> Result<PathBuf, AppError> {
+    match CRASHES_PATH.get() {
+        Some(dir) => Ok(dir.clone()),
+        None => Ok(get_settings_dir()?.join(CRASHES_DIR)),
+    }
+}
+
+/// Installs the panic hook and starts the session marker
+///
+/// Called once at startup, right after logging is set up. If the crashes
+/// directory cannot be used, Speakr runs without crash reports.
+pub fn install_crash_handler() {
+    let dir = match crashes_dir() {
+        Ok(dir) => dir,
+        Err(e) => {
+            warn!("Crash reports disabled: {}", e);
+            return;
+        }
+    };
+    match start_session_in_dir(&dir) {
+        Ok(Some(notice)) => warn!("The last run ended unexpectedly: {}", notice.report_path),
+        Ok(None) => {}
+        Err(e) => warn!("Failed to start crash session marker: {}", e),
+    }
+    let _ = CRASHES_PATH.set(dir.clone());
+
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let report = CrashReport::from_panic(info);
+        match save_crash_report_in_dir(&dir, &report) {
+            Ok(notice) => {
+                PANIC_REPORTED.store(true, Ordering::SeqCst);
+                let _ = fs::remove_file(dir.join(SESSION_MARKER_FILE));
+                error!(
+                    "💥 {}; crash report saved to {}",
+                    notice.summary, notice.report_path
+                );
+            }
+            Err(e) => error!(
+                "💥 {}; failed to save crash report: {}",
+                report.summary(),
+                e
+            ),
+        }
+        previous(info);
+    }));
+}
+
+/// Records the stage of the dictation in flight for crash reports
+///
+/// Only the stage name is kept, never the text of partial transcripts.
+pub fn record_pipeline_state(event: &PipelineEvent) {
+    let state = event.display_name().to_string();
+    {
+        let mut current = PIPELINE_STATE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if current.as_deref() == Some(state.as_str()) {
+            return;
+        }
+        *current = Some(state.clone());
+    }
+
+    // Keep the marker up to date so an unexpected exit reports the stage too
+    if PANIC_REPORTED.load(Ordering::SeqCst) {
+        return;
+    }
+    if let Some(dir) = CRASHES_PATH.get() {
+        if let Err(e) = fs::write(dir.join(SESSION_MARKER_FILE), &state) {
+            warn!("Failed to update crash session marker: {}", e);
+        }
+    }
+}
+
+/// Returns the stage of the most recent dictation
+fn pipeline_state() -> Option<String> {
+    PIPELINE_STATE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+        .or_else(|| Some(IDLE_STATE.to_string()))
+}
+
+/// Removes the session marker on a clean exit
+pub fn end_session() {
+    if let Some(dir) = CRASHES_PATH.get() {
+        match fs::remove_file(dir.join(SESSION_MARKER_FILE)) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                warn!("Failed to remove crash session marker: {}", e);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Tells the UI at startup if the previous run crashed
+pub fn spawn_crash_notice(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        match get_crash_notice_internal() {
+            Ok(Some(notice)) => {
+                if let Err(e) = app_handle.emit(CRASH_REPORTED_EVENT, &notice) {
+                    warn!("Failed to emit crash notice: {}", e);
+                }
+            }
+            Ok(None) => {}
+            Err(e) => warn!("Failed to check for a crash notice: {}", e),
+        }
+    });
+}
+
+/// Returns the notice that has not been dismissed yet, if any.
+///
+/// # Errors
+///
+/// See [`load_crash_notice_from_dir`].
+pub fn get_crash_notice_internal() -> Result<Option<CrashNotice>, AppError> {
+    load_crash_notice_from_dir(&crashes_dir()?)
+}
+
+/// Dismisses the notice so it is not shown again.
+///
+/// # Errors
+///
+/// See [`dismiss_crash_notice_in_dir`].
+pub fn dismiss_crash_notice_internal() -> Result<(), AppError> {
+    dismiss_crash_notice_in_dir(&crashes_dir()?)?;
+    info!("Crash notice dismissed");
+    Ok(())
+}
+
+/// Opens the crash report in the default editor.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if there is no notice or the report cannot
+/// be opened.
+pub fn open_crash_report_internal(app_handle: &AppHandle) -> Result<(), AppError> {
+    let notice = get_crash_notice_internal()?
+        .ok_or_else(|| AppError::Settings("No crash report".to_string()))?;
+    app_handle
+        .opener()
+        .open_path(&notice.report_path, None::<&str>)
+        .map_err(|e| AppError::Settings(format!("Failed to open {}: {e}", notice.report_path)))
+}
+
+/// Current time in Unix milliseconds
+fn now_millis() -> u64 {
+    chrono::Utc::now().timestamp_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn panic_report() -> CrashReport {
+        CrashReport {
+            kind: CrashKind::Panic,
+            crashed_at: 1_700_000_000_000,
+            message: Some(redact_message("bad input \"call mum\"")),
+            location: Some("src/workflow.rs:42".to_string()),
+            thread: Some("main".to_string()),
+            backtrace: None,
+            pipeline_state: Some("Transcribing".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_redact_message_removes_quoted_text() {
+        assert_eq!(
+            redact_message(r#"called `Option::unwrap()` on "secret words""#),
+            r#"called `Option::unwrap()` on "<redacted>""#
+        );
+        assert_eq!(
+            redact_message("unterminated \"call mum"),
+            "unterminated \"<redacted>"
+        );
+        assert_eq!(redact_message("index out of bounds"), "index out of bounds");
+    }
+
+    #[test]
+    fn test_report_render_has_no_dictated_text() {
+        let text = panic_report().render();
+        assert!(text.contains("Panic at src/workflow.rs:42"));
+        assert!(text.contains("Last pipeline state: Transcribing"));
+        assert!(text.contains(env!("CARGO_PKG_VERSION")));
+        assert!(!text.contains("call mum"));
+    }
+
+    #[test]
+    fn test_saved_report_is_noticed_until_dismissed() {
+        let dir = TempDir::new().unwrap();
+        let notice = save_crash_report_in_dir(dir.path(), &panic_report()).unwrap();
+
+        assert!(Path::new(&notice.report_path).exists());
+        assert_eq!(
+            load_crash_notice_from_dir(dir.path()).unwrap(),
+            Some(notice.clone())
+        );
+
+        dismiss_crash_notice_in_dir(dir.path()).unwrap();
+        assert_eq!(load_crash_notice_from_dir(dir.path()).unwrap(), None);
+        assert!(Path::new(&notice.report_path).exists());
+        dismiss_crash_notice_in_dir(dir.path()).unwrap();
+    }
+
+    #[test]
+    fn test_leftover_session_marker_is_reported() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(start_session_in_dir(dir.path()).unwrap(), None);
+
+        // The first session never removed its marker
+        fs::write(dir.path().join(SESSION_MARKER_FILE), "Recording").unwrap();
+        let notice = start_session_in_dir(dir.path())
+            .unwrap()
+            .expect("unexpected exit should be reported");
+
+        assert_eq!(notice.summary, "Speakr quit unexpectedly");
+        let report = fs::read_to_string(&notice.report_path).unwrap();
+        assert!(report.contains("Last pipeline state: Recording"));
+        assert!(dir.path().join(SESSION_MARKER_FILE).exists());
+    }
+}
@@ -5,14 +5,30 @@
 //! This module contains the internal implementations of debug Tauri commands
 //! for audio recording tests, log management, and debug panel functionality.
 
+use crate::audio::files::encode_wav_in_memory;
+use crate::commands::storage::recordings_dir;
+use crate::commands::transcription::transcribe_samples;
 use crate::debug::{
-    storage::{DEBUG_LOG_MESSAGES, DEBUG_RECORDING_STATE},
-    types::{DebugLogLevel, DebugLogMessage},
+    fixtures::{save_fixture_pair, AudioFixture},
+    session::{save_session, start_session_recording, stop_session_recording},
+    storage::{
+        last_recording, retain_last_recording, DEBUG_LOG_MESSAGES, DEBUG_RECORDING_STATE,
+        MAX_DEBUG_LOG_CAPACITY, MIN_DEBUG_LOG_CAPACITY,
+    },
+    types::{DebugLogLevel, DebugLogPage, DebugLogQuery, DenoiseComparison},
 };
+use crate::services::privacy::loggable_text;
 use crate::settings::commands::load_settings_internal;
-use speakr_core::audio::{AudioRecorder, RecordingConfig};
-use speakr_types::AppError;
+use crate::workflow::{execute_replay_workflow, Delivery, InjectionMode};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use speakr_core::{
+    audio::{denoise::Denoiser, AudioRecorder, RecordingConfig},
+    pipeline::transcription_pipeline,
+    transcription::models::ModelManager,
+};
+use speakr_types::{level_to_dbfs, AppError, AudioDuration, TranscriptionConfig};
 use std::{fs, path::PathBuf, time::Duration};
+use tauri::AppHandle;
 use tracing::{info, warn};
 
 /// Internal implementation for debug audio recording test
@@ -54,10 +70,7 @@ pub async fn debug_test_audio_recording_internal() -> Result<String, AppError> {
 ///
 /// Returns `AppError` if the directory cannot be created.
 pub fn get_debug_recordings_directory() -> Result<PathBuf, AppError> {
-    let documents_dir = dirs::document_dir()
-        .ok_or_else(|| AppError::Settings("Could not find Documents directory".to_string()))?;
-
-    let debug_dir = documents_dir.join("Speakr").join("debug_recordings");
+    let debug_dir = recordings_dir()?;
 
     // Create directory if it doesn't exist
     if !debug_dir.exists() {
@@ -103,15 +116,12 @@ pub async fn debug_start_recording_internal() -> Result<String, AppError> {
         e
     });
 
-    let duration_secs = match settings {
-        Ok(settings) => {
-            // For debug recording, use settings duration but cap at 30 seconds for safety
-            std::cmp::min(settings.audio_duration_secs, 30)
-        }
-        Err(_) => 30, // Fallback to 30 seconds if settings loading fails
+    let duration = match settings {
+        Ok(settings) => settings.audio_duration,
+        Err(_) => AudioDuration::MAX, // Fallback to 30 seconds if settings loading fails
     };
 
-    let config = RecordingConfig::new(duration_secs);
+    let config = RecordingConfig::new(duration);
     let recorder = AudioRecorder::new(config)
         .await
         .map_err(|e| AppError::Settings(format!("Failed to create audio recorder: {e}")))?;
@@ -175,6 +185,7 @@ pub async fn debug_stop_recording_internal() -> Result<String, AppError> {
 
     let samples = result.samples();
     let duration = start_time.map(|t| t.elapsed()).unwrap_or_default();
+    retain_last_recording(&samples);
 
     crate::debug::storage::add_debug_log(
         DebugLogLevel::Info,
@@ -200,24 +211,278 @@ pub async fn debug_stop_recording_internal() -> Result<String, AppError> {
         output_path.display()
     );
 
-    crate::debug::storage::add_debug_log(DebugLogLevel::Info, "speakr-debug", &success_message);
-
     info!("{}", success_message);
     Ok(success_message)
 }
 
+/// Internal implementation for the debug input level meter
+///
+/// # Returns
+///
+/// Returns the input level of the debug recording in dBFS, or `None` if no
+/// debug recording is active.
+///
+/// # Errors
+///
+/// This function currently never returns an error.
+pub async fn debug_input_level_internal() -> Result<Option<f32>, AppError> {
+    let state = DEBUG_RECORDING_STATE.lock().unwrap();
+    Ok(state
+        .recorder
+        .as_ref()
+        .and_then(AudioRecorder::input_level)
+        .map(level_to_dbfs))
+}
+
+/// Internal implementation for the debug playback command
+///
+/// Encodes the last captured audio (see
+/// [`retain_last_recording`](crate::debug::storage::retain_last_recording))
+/// as WAV in memory, so developers can listen to what the microphone picked
+/// up without saving it to disk.
+///
+/// # Returns
+///
+/// Returns a `data:audio/wav;base64,…` URL for an `<audio>` element.
+///
+/// # Errors
+///
+/// Returns `AppError::AudioCapture` if nothing has been recorded since
+/// launch, or `AppError::FileSystem` if the audio cannot be encoded.
+pub async fn debug_play_last_recording_internal() -> Result<String, AppError> {
+    let samples = last_recording()
+        .ok_or_else(|| AppError::AudioCapture("No audio has been recorded yet".to_string()))?;
+    let wav = encode_wav_in_memory(&samples)?;
+
+    crate::debug::storage::add_debug_log(
+        DebugLogLevel::Info,
+        "speakr-debug",
+        &format!("Playing back last recording ({} samples)", samples.len()),
+    );
+
+    Ok(format!("data:audio/wav;base64,{}", STANDARD.encode(wav)))
+}
+
+/// Internal implementation for the debug WAV replay command
+///
+/// Loads a previously saved recording and pushes it through the same
+/// transcription and injection steps as a live dictation.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle for event emission
+/// * `path` - Path to a 16 kHz mono 16-bit WAV file
+/// * `sandboxed` - When `true`, the text injection step is skipped
+///
+/// # Returns
+///
+/// Returns the transcribed text.
+///
+/// # Errors
+///
+/// Returns `AppError` if the file cannot be loaded or any workflow step fails.
+pub async fn debug_replay_wav_internal(
+    app_handle: AppHandle,
+    path: PathBuf,
+    sandboxed: bool,
+) -> Result<String, AppError> {
+    info!("🔁 Debug: Replaying WAV file {}", path.display());
+
+    let samples = crate::audio::files::load_audio_samples_from_wav_file(&path).await?;
+
+    crate::debug::storage::add_debug_log(
+        DebugLogLevel::Info,
+        "speakr-debug",
+        &format!(
+            "Replaying {} samples from {} ({})",
+            samples.len(),
+            path.display(),
+            if sandboxed {
+                "sandboxed"
+            } else {
+                "live injection"
+            }
+        ),
+    );
+
+    let mode = if sandboxed {
+        InjectionMode::Sandboxed
+    } else {
+        InjectionMode::Live(Delivery::default())
+    };
+
+    match execute_replay_workflow(app_handle, samples, mode).await {
+        Ok(text) => {
+            crate::debug::storage::add_debug_log(
+                DebugLogLevel::Info,
+                "speakr-debug",
+                &replay_log_message(&text),
+            );
+            Ok(text)
+        }
+        Err(e) => {
+            crate::debug::storage::add_debug_log(
+                DebugLogLevel::Error,
+                "speakr-debug",
+                &format!("Replay failed: {e}"),
+            );
+            Err(e)
+        }
+    }
+}
+
+/// Internal implementation for the debug noise suppression A/B comparison
+///
+/// Transcribes a saved recording twice, as captured and after RNNoise, so
+/// the effect of the denoiser can be judged on real audio.
+///
+/// # Arguments
+///
+/// * `path` - Path to a 16 kHz mono 16-bit WAV file
+///
+/// # Returns
+///
+/// Returns both transcripts.
+///
+/// # Errors
+///
+/// Returns `AppError` if the file cannot be loaded, the RNNoise weights are
+/// not downloaded, or transcription fails.
+pub async fn debug_compare_denoise_internal(path: PathBuf) -> Result<DenoiseComparison, AppError> {
+    info!(
+        "🔁 Debug: Comparing noise suppression on {}",
+        path.display()
+    );
+
+    let samples = crate::audio::files::load_audio_samples_from_wav_file(&path).await?;
+    let denoiser =
+        Denoiser::load(&ModelManager::new()).map_err(|e| AppError::AudioCapture(e.to_string()))?;
+    let denoised = denoiser.denoise(&samples);
+
+    let comparison = DenoiseComparison {
+        original: transcribe_samples(samples).await?.text,
+        denoised: transcribe_samples(denoised).await?.text,
+    };
+
+    crate::debug::storage::add_debug_log(
+        DebugLogLevel::Info,
+        "speakr-debug",
+        &format!(
+            "Denoise A/B for {}: original '{}', denoised '{}'",
+            path.display(),
+            comparison.original,
+            comparison.denoised
+        ),
+    );
+    Ok(comparison)
+}
+
+/// Internal implementation for the debug fixture recorder
+///
+/// Captures a real dictation, transcribes it with the user's model and
+/// language settings, and saves the audio plus the engine output as an
+/// anonymised fixture pair for regression tests.
+///
+/// # Arguments
+///
+/// * `output_dir` - Folder to write the fixture into (created if missing)
+/// * `duration` - Recording length; defaults to the settings duration
+///
+/// # Returns
+///
+/// Returns the metadata written to the fixture's JSON file.
+///
+/// # Errors
+///
+/// Returns `AppError` if recording, transcription, or saving fails.
+pub async fn debug_record_fixture_internal(
+    output_dir: PathBuf,
+    duration: Option<AudioDuration>,
+) -> Result<AudioFixture, AppError> {
+    let settings = load_settings_internal().await.unwrap_or_default();
+    let duration = duration.unwrap_or(settings.audio_duration);
+
+    info!(
+        "🧪 Debug: Recording {} fixture into {}",
+        duration,
+        output_dir.display()
+    );
+    crate::debug::storage::add_debug_log(
+        DebugLogLevel::Info,
+        "speakr-debug",
+        &format!("Recording {duration} fixture"),
+    );
+
+    let config = RecordingConfig::new(duration);
+    let recorder = AudioRecorder::new(config.clone())
+        .await
+        .map_err(|e| AppError::AudioCapture(format!("Failed to create audio recorder: {e}")))?;
+    recorder
+        .start_recording()
+        .await
+        .map_err(|e| AppError::AudioCapture(format!("Failed to start recording: {e}")))?;
+
+    tokio::time::sleep(config.max_duration().as_duration()).await;
+
+    let samples = recorder
+        .stop_recording()
+        .await
+        .map_err(|e| AppError::AudioCapture(format!("Failed to stop recording: {e}")))?
+        .samples();
+
+    let result = transcription_pipeline(
+        samples.clone(),
+        TranscriptionConfig::from_settings(&settings),
+    )
+    .await
+    .map_err(|e| AppError::Transcription(e.to_string()))?;
+
+    let fixture = save_fixture_pair(&samples, &result, &output_dir).await?;
+
+    crate::debug::storage::add_debug_log(
+        DebugLogLevel::Info,
+        "speakr-debug",
+        &fixture_log_message(&fixture),
+    );
+
+    Ok(fixture)
+}
+
+/// Debug log line for a replayed dictation; the transcript is hidden while
+/// privacy mode is on
+fn replay_log_message(text: &str) -> String {
+    format!("Replay transcription: '{}'", loggable_text(text))
+}
+
+/// Debug log line for a saved fixture; the transcript is hidden while
+/// privacy mode is on
+fn fixture_log_message(fixture: &AudioFixture) -> String {
+    format!(
+        "Saved fixture {} ({} samples): '{}'",
+        fixture.name,
+        fixture.sample_count,
+        loggable_text(&fixture.transcript)
+    )
+}
+
 /// Internal implementation for getting log messages
 ///
+/// # Arguments
+///
+/// * `query` - Minimum level and page of messages to return
+///
 /// # Returns
 ///
-/// Returns a vector of log messages.
+/// Returns the requested page of log messages, newest first.
 ///
 /// # Errors
 ///
 /// Returns `AppError` if the operation fails.
-pub async fn debug_get_log_messages_internal() -> Result<Vec<DebugLogMessage>, AppError> {
+pub async fn debug_get_log_messages_internal(
+    query: DebugLogQuery,
+) -> Result<DebugLogPage, AppError> {
     if let Ok(logs) = DEBUG_LOG_MESSAGES.lock() {
-        Ok(logs.iter().cloned().collect())
+        Ok(logs.page(&query))
     } else {
         Err(AppError::Settings(
             "Failed to access log messages".to_string(),
@@ -249,3 +514,118 @@ pub async fn debug_clear_log_messages_internal() -> Result<(), AppError> {
         ))
     }
 }
+
+/// Internal implementation for changing how many log messages are kept
+///
+/// # Arguments
+///
+/// * `capacity` - Number of messages to keep, between
+///   [`MIN_DEBUG_LOG_CAPACITY`] and [`MAX_DEBUG_LOG_CAPACITY`]
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success.
+///
+/// # Errors
+///
+/// Returns `AppError` if the capacity is out of range or the log cannot be
+/// accessed.
+pub async fn debug_set_log_capacity_internal(capacity: usize) -> Result<(), AppError> {
+    if !(MIN_DEBUG_LOG_CAPACITY..=MAX_DEBUG_LOG_CAPACITY).contains(&capacity) {
+        return Err(AppError::Settings(format!(
+            "Log capacity must be between {MIN_DEBUG_LOG_CAPACITY} and {MAX_DEBUG_LOG_CAPACITY} messages"
+        )));
+    }
+    let Ok(mut logs) = DEBUG_LOG_MESSAGES.lock() else {
+        return Err(AppError::Settings(
+            "Failed to access log messages".to_string(),
+        ));
+    };
+    logs.set_capacity(capacity);
+    drop(logs);
+
+    crate::debug::storage::add_debug_log(
+        DebugLogLevel::Info,
+        "speakr-debug",
+        &format!("Keeping the last {capacity} log messages"),
+    );
+    Ok(())
+}
+
+/// Internal implementation for starting a session recording
+///
+/// Pipeline events are recorded, together with the current settings, until
+/// [`debug_stop_session_recording_internal`] is called.
+///
+/// # Errors
+///
+/// Returns `AppError` if settings cannot be loaded or a session is already
+/// being recorded.
+pub async fn debug_start_session_recording_internal() -> Result<(), AppError> {
+    let settings = load_settings_internal().await?;
+    start_session_recording(settings)?;
+
+    info!("🎬 Debug: Session recording started");
+    Ok(())
+}
+
+/// Internal implementation for stopping a session recording
+///
+/// # Arguments
+///
+/// * `output_dir` - The folder to write the session file into
+///
+/// # Returns
+///
+/// Returns the path of the saved session file.
+///
+/// # Errors
+///
+/// Returns `AppError` if no session is being recorded or the file cannot be
+/// written.
+pub async fn debug_stop_session_recording_internal(
+    output_dir: PathBuf,
+) -> Result<PathBuf, AppError> {
+    let session = stop_session_recording()
+        .ok_or_else(|| AppError::Command("No session is being recorded".to_string()))?;
+    let path = save_session(&session, &output_dir)?;
+
+    info!(
+        "🎬 Debug: Saved session with {} events → {}",
+        session.events.len(),
+        path.display()
+    );
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::privacy::with_privacy_mode;
+    use speakr_types::ModelId;
+
+    #[test]
+    fn test_debug_log_hides_transcripts_in_privacy_mode() {
+        let fixture = AudioFixture {
+            name: "fixture_001".to_string(),
+            audio_file: "fixture_001.wav".to_string(),
+            sample_rate_hz: 16_000,
+            sample_count: 16_000,
+            duration_ms: 1_000,
+            model: ModelId::default(),
+            language: None,
+            transcript: "my secret".to_string(),
+        };
+
+        with_privacy_mode(|| {
+            assert_eq!(
+                replay_log_message("my secret"),
+                "Replay transcription: '<9 characters hidden>'"
+            );
+            assert_eq!(
+                fixture_log_message(&fixture),
+                "Saved fixture fixture_001 (16000 samples): '<9 characters hidden>'"
+            );
+        });
+    }
+}
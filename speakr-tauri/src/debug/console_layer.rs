@@ -0,0 +1,110 @@
+// ============================================================================
+//! Tracing → Debug Console Bridge
+// ============================================================================
+//!
+//! A `tracing_subscriber` layer that copies every `tracing` event into the
+//! debug log storage, so the debug panel's console shows the real backend
+//! logs rather than only messages added with `add_debug_log`. It sits behind
+//! the global filter installed by `init_tracing`, so `RUST_LOG` and the
+//! per-subsystem verbosity settings decide what reaches the console.
+
+use crate::debug::{storage::add_debug_log, types::DebugLogLevel};
+use std::fmt::{self, Write as _};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+
+/// Layer that forwards `tracing` events to the debug console
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DebugConsoleLayer;
+
+impl<S: Subscriber> Layer<S> for DebugConsoleLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        add_debug_log(
+            console_level(metadata.level()),
+            metadata.target(),
+            &visitor.finish(),
+        );
+    }
+}
+
+/// Maps a `tracing` level onto the debug console's levels
+fn console_level(level: &Level) -> DebugLogLevel {
+    match *level {
+        Level::TRACE => DebugLogLevel::Trace,
+        Level::DEBUG => DebugLogLevel::Debug,
+        Level::INFO => DebugLogLevel::Info,
+        Level::WARN => DebugLogLevel::Warn,
+        Level::ERROR => DebugLogLevel::Error,
+    }
+}
+
+/// Renders an event as its message followed by its other fields as
+/// `key=value` pairs
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl MessageVisitor {
+    fn finish(self) -> String {
+        match (self.message.is_empty(), self.fields.is_empty()) {
+            (_, true) => self.message,
+            (true, false) => self.fields,
+            (false, false) => format!("{} {}", self.message, self.fields),
+        }
+    }
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.record_debug(field, &value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+            return;
+        }
+        if !self.fields.is_empty() {
+            self.fields.push(' ');
+        }
+        let _ = write!(self.fields, "{}={:?}", field.name(), value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::debug::storage::DEBUG_LOG_MESSAGES;
+    use crate::debug::types::DebugLogQuery;
+    use tracing_subscriber::prelude::*;
+
+    #[test]
+    fn test_events_reach_the_debug_console() {
+        let subscriber = tracing_subscriber::registry().with(DebugConsoleLayer);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!(target: "console_layer_test", shortcut = "F5", "Hotkey taken");
+        });
+
+        let page = DEBUG_LOG_MESSAGES
+            .lock()
+            .unwrap()
+            .page(&DebugLogQuery::default());
+        let entry = page
+            .messages
+            .iter()
+            .find(|m| m.target == "console_layer_test")
+            .expect("event was not forwarded");
+        assert_eq!(entry.level, DebugLogLevel::Warn);
+        assert_eq!(entry.message, "Hotkey taken shortcut=\"F5\"");
+    }
+}
@@ -0,0 +1,159 @@
+// ============================================================================
+//! Audio Fixture Recording
+// ============================================================================
+//!
+//! Helpers for turning a real dictation into a regression-test fixture: a
+//! `fixture_NNN.wav` audio file plus a `fixture_NNN.json` file holding the
+//! engine output that was produced for it.
+//!
+//! Fixtures are anonymised: the JSON only contains what a test needs to
+//! reproduce the run (audio shape, model, language, transcript). Timestamps,
+//! machine-specific metrics, device names, and absolute paths are never
+//! written.
+
+use crate::audio::files::save_audio_samples_to_wav_file;
+use serde::{Deserialize, Serialize};
+use speakr_core::audio::SAMPLE_RATE_HZ;
+use speakr_types::{AppError, ModelId, TranscriptionResult};
+use std::path::Path;
+
+/// Metadata stored alongside a fixture's audio file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AudioFixture {
+    /// Fixture name shared by the `.wav` and `.json` files
+    pub name: String,
+    /// Audio filename, relative to the fixture's folder
+    pub audio_file: String,
+    /// Sample rate of the audio in Hz
+    pub sample_rate_hz: u32,
+    /// Number of samples in the audio file
+    pub sample_count: usize,
+    /// Audio duration in milliseconds
+    pub duration_ms: u64,
+    /// Model that produced the transcript
+    #[serde(alias = "model_size")]
+    pub model: ModelId,
+    /// Detected or configured language, if known
+    pub language: Option<String>,
+    /// Transcript produced by the engine at capture time
+    pub transcript: String,
+}
+
+/// Finds the first unused `fixture_NNN` name in a directory
+///
+/// # Arguments
+///
+/// * `dir` - The folder fixtures are written to
+///
+/// # Returns
+///
+/// Returns a name for which neither a `.wav` nor a `.json` file exists yet.
+pub fn next_fixture_name(dir: &Path) -> String {
+    let mut index = 1;
+    loop {
+        let name = format!("fixture_{index:03}");
+        if !dir.join(format!("{name}.wav")).exists() && !dir.join(format!("{name}.json")).exists() {
+            return name;
+        }
+        index += 1;
+    }
+}
+
+/// Writes an audio/transcript fixture pair to a directory
+///
+/// # Arguments
+///
+/// * `samples` - The captured 16 kHz mono samples
+/// * `result` - The engine output produced for those samples
+/// * `output_dir` - The folder to write the fixture into (created if missing)
+///
+/// # Returns
+///
+/// Returns the metadata that was written to the fixture's JSON file.
+///
+/// # Errors
+///
+/// Returns `AppError::FileSystem` if the folder or either file cannot be written.
+pub async fn save_fixture_pair(
+    samples: &[i16],
+    result: &TranscriptionResult,
+    output_dir: &Path,
+) -> Result<AudioFixture, AppError> {
+    std::fs::create_dir_all(output_dir)
+        .map_err(|e| AppError::FileSystem(format!("Failed to create fixture dir: {e}")))?;
+
+    let name = next_fixture_name(output_dir);
+    let audio_file = format!("{name}.wav");
+
+    save_audio_samples_to_wav_file(samples, &output_dir.join(&audio_file)).await?;
+
+    let fixture = AudioFixture {
+        name: name.clone(),
+        audio_file,
+        sample_rate_hz: SAMPLE_RATE_HZ,
+        sample_count: samples.len(),
+        duration_ms: samples.len() as u64 * 1000 / u64::from(SAMPLE_RATE_HZ),
+        model: result.model_used.clone(),
+        language: result.language.clone(),
+        transcript: result.text.clone(),
+    };
+
+    let json = serde_json::to_string_pretty(&fixture)
+        .map_err(|e| AppError::FileSystem(format!("Failed to serialise fixture: {e}")))?;
+    std::fs::write(output_dir.join(format!("{name}.json")), json)
+        .map_err(|e| AppError::FileSystem(format!("Failed to write fixture metadata: {e}")))?;
+
+    Ok(fixture)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn sample_result() -> TranscriptionResult {
+        TranscriptionResult {
+            text: "hello world".to_string(),
+            language: Some("en".to_string()),
+            confidence: 0.9,
+            avg_logprob: None,
+            processing_time: Duration::from_millis(250),
+            memory_delta_bytes: 1024,
+            model_used: ModelId::new("small"),
+            segments: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_save_fixture_pair_writes_wav_and_json() {
+        let temp_dir = TempDir::new().unwrap();
+        let samples = vec![0i16; 8000];
+
+        let fixture = save_fixture_pair(&samples, &sample_result(), temp_dir.path())
+            .await
+            .unwrap();
+
+        assert_eq!(fixture.name, "fixture_001");
+        assert_eq!(fixture.duration_ms, 500);
+        assert!(temp_dir.path().join("fixture_001.wav").exists());
+
+        let json = std::fs::read_to_string(temp_dir.path().join("fixture_001.json")).unwrap();
+        let loaded: AudioFixture = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, fixture);
+        // Machine-specific metrics are not part of the fixture
+        assert!(!json.contains("processing_time"));
+        assert!(!json.contains(&temp_dir.path().display().to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_next_fixture_name_skips_existing() {
+        let temp_dir = TempDir::new().unwrap();
+        save_fixture_pair(&[0i16; 16], &sample_result(), temp_dir.path())
+            .await
+            .unwrap();
+
+        assert_eq!(next_fixture_name(temp_dir.path()), "fixture_002");
+    }
+}
@@ -3,27 +3,42 @@
 // ============================================================================
 //!
 //! This module provides debugging utilities for the Speakr application,
-//! including log message storage, audio recording test commands, and
-//! debug console functionality. All debug code is conditionally compiled
+//! including log message storage fed by `tracing`, audio recording test commands, test
+//! fixture recording, session recording, developer shortcuts, and debug console functionality. All debug code is conditionally compiled
 //! and only available in debug builds.
 
 #[cfg(debug_assertions)]
 pub mod commands;
 #[cfg(debug_assertions)]
+pub mod console_layer;
+#[cfg(debug_assertions)]
+pub mod fixtures;
+#[cfg(debug_assertions)]
+pub mod session;
+#[cfg(debug_assertions)]
+pub mod shortcuts;
+#[cfg(debug_assertions)]
 pub mod storage;
 #[cfg(debug_assertions)]
 pub mod types;
 
 // Re-export types for lib.rs to use
 #[cfg(debug_assertions)]
-pub use types::{DebugLogLevel, DebugLogMessage};
+pub use console_layer::DebugConsoleLayer;
+#[cfg(debug_assertions)]
+pub use fixtures::AudioFixture;
+#[cfg(debug_assertions)]
+pub use types::{DebugLogLevel, DebugLogMessage, DebugLogPage, DebugLogQuery, DenoiseComparison};
 
 // Re-export functions that lib.rs needs to access
 #[cfg(debug_assertions)]
 pub use commands::{
-    debug_clear_log_messages_internal, debug_get_log_messages_internal,
-    debug_start_recording_internal, debug_stop_recording_internal,
-    debug_test_audio_recording_internal,
+    debug_clear_log_messages_internal, debug_compare_denoise_internal,
+    debug_get_log_messages_internal, debug_input_level_internal,
+    debug_play_last_recording_internal, debug_record_fixture_internal, debug_replay_wav_internal,
+    debug_set_log_capacity_internal, debug_start_recording_internal,
+    debug_start_session_recording_internal, debug_stop_recording_internal,
+    debug_stop_session_recording_internal, debug_test_audio_recording_internal,
 };
 #[cfg(debug_assertions)]
-pub use storage::add_debug_log;
+pub use storage::{add_debug_log, discard_debug_audio, retain_last_recording};
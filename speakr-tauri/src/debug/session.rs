@@ -0,0 +1,375 @@
+// ============================================================================
+//! Session Recording & Replay
+// ============================================================================
+//!
+//! Records what happened during a stretch of use so a user-reported workflow
+//! bug can be reproduced in a test. While a recording is running, every
+//! pipeline event is stored with its offset from the start of the recording;
+//! stopping writes the events plus a snapshot of the settings to a
+//! `session_NNN.json` file.
+//!
+//! Audio is never recorded. Replaying a session stands in for the microphone
+//! and the transcription engine instead: each recorded dictation is
+//! "transcribed" by a caller-supplied function (usually
+//! [`recorded_transcription`], which returns what the engine produced at the
+//! time) and post-processed with the snapshot settings. In privacy mode the
+//! transcribed text is left out of the recording.
+
+use crate::services::privacy::is_privacy_mode_active;
+use crate::settings::SettingsLoader;
+use crate::workflow::create_post_processor_with_loader;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use speakr_types::{AppError, AppSettings, PipelineEvent};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Instant;
+
+/// Version of the session file format
+pub const SESSION_FORMAT_VERSION: u32 = 1;
+
+/// A pipeline event and when it happened
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RecordedEvent {
+    /// Milliseconds since the recording started
+    pub offset_ms: u64,
+    pub event: PipelineEvent,
+}
+
+/// Everything captured by a session recording
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RecordedSession {
+    /// Format version, see [`SESSION_FORMAT_VERSION`]
+    pub version: u32,
+    /// Version of Speakr that made the recording
+    pub app_version: String,
+    /// Settings in effect when the recording started
+    pub settings: AppSettings,
+    /// Pipeline events in the order they were emitted
+    pub events: Vec<RecordedEvent>,
+}
+
+/// The events of one dictation within a session
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedDictation {
+    pub events: Vec<RecordedEvent>,
+}
+
+impl RecordedDictation {
+    /// Text the engine produced, before post-processing
+    pub fn transcript(&self) -> Option<&str> {
+        self.events
+            .iter()
+            .rev()
+            .find_map(|recorded| match &recorded.event {
+                PipelineEvent::PartialText(text) => Some(text.as_str()),
+                _ => None,
+            })
+    }
+
+    /// Error the dictation stopped with, if it failed
+    pub fn failure(&self) -> Option<&AppError> {
+        self.events
+            .iter()
+            .find_map(|recorded| match &recorded.event {
+                PipelineEvent::Failed(error) => Some(error),
+                _ => None,
+            })
+    }
+}
+
+impl RecordedSession {
+    /// Splits the events into dictations
+    ///
+    /// A dictation ends with its first finishing event; events after the
+    /// last one form a final, unfinished dictation.
+    pub fn dictations(&self) -> Vec<RecordedDictation> {
+        let mut dictations = Vec::new();
+        let mut current = Vec::new();
+        for recorded in &self.events {
+            current.push(recorded.clone());
+            if recorded.event.is_finished() {
+                dictations.push(RecordedDictation {
+                    events: std::mem::take(&mut current),
+                });
+            }
+        }
+        if !current.is_empty() {
+            dictations.push(RecordedDictation { events: current });
+        }
+        dictations
+    }
+
+    /// Settings loader that returns the snapshot settings
+    ///
+    /// Lets tests build workflow components exactly as the user had them.
+    pub fn settings_loader(&self) -> Arc<dyn SettingsLoader> {
+        Arc::new(SnapshotSettingsLoader(self.settings.clone()))
+    }
+}
+
+/// Settings loader backed by a session's settings snapshot
+struct SnapshotSettingsLoader(AppSettings);
+
+#[async_trait]
+impl SettingsLoader for SnapshotSettingsLoader {
+    async fn load_settings(&self) -> Result<AppSettings, AppError> {
+        Ok(self.0.clone())
+    }
+}
+
+/// A session recording in progress
+struct ActiveSession {
+    started: Instant,
+    session: RecordedSession,
+}
+
+/// The session being recorded, if any
+static ACTIVE_SESSION: Mutex<Option<ActiveSession>> = Mutex::new(None);
+
+/// Starts recording a session
+///
+/// # Arguments
+///
+/// * `settings` - The settings in effect, stored with the session
+///
+/// # Errors
+///
+/// Returns `AppError::Command` if a session is already being recorded.
+pub fn start_session_recording(settings: AppSettings) -> Result<(), AppError> {
+    let mut active = ACTIVE_SESSION
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    if active.is_some() {
+        return Err(AppError::Command(
+            "A session is already being recorded".to_string(),
+        ));
+    }
+    *active = Some(ActiveSession {
+        started: Instant::now(),
+        session: RecordedSession {
+            version: SESSION_FORMAT_VERSION,
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            settings,
+            events: Vec::new(),
+        },
+    });
+    Ok(())
+}
+
+/// Whether a session is being recorded
+pub fn is_session_recording() -> bool {
+    ACTIVE_SESSION
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .is_some()
+}
+
+/// Adds a pipeline event to the session being recorded, if any
+pub fn record_session_event(event: &PipelineEvent) {
+    let mut active = ACTIVE_SESSION
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let Some(active) = active.as_mut() else {
+        return;
+    };
+    let event = match event {
+        PipelineEvent::PartialText(_) if is_privacy_mode_active() => {
+            PipelineEvent::PartialText(String::new())
+        }
+        event => event.clone(),
+    };
+    active.session.events.push(RecordedEvent {
+        offset_ms: active.started.elapsed().as_millis() as u64,
+        event,
+    });
+}
+
+/// Stops recording and returns the session, if one was being recorded
+pub fn stop_session_recording() -> Option<RecordedSession> {
+    ACTIVE_SESSION
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take()
+        .map(|active| active.session)
+}
+
+/// Finds the first unused `session_NNN.json` path in a directory
+fn next_session_path(dir: &Path) -> PathBuf {
+    (1..)
+        .map(|index| dir.join(format!("session_{index:03}.json")))
+        .find(|path| !path.exists())
+        .expect("an unused session name")
+}
+
+/// Writes a session to a directory
+///
+/// # Arguments
+///
+/// * `session` - The recorded session
+/// * `output_dir` - The folder to write the session into (created if missing)
+///
+/// # Returns
+///
+/// Returns the path of the new `session_NNN.json` file.
+///
+/// # Errors
+///
+/// Returns `AppError::FileSystem` if the folder or file cannot be written.
+pub fn save_session(session: &RecordedSession, output_dir: &Path) -> Result<PathBuf, AppError> {
+    std::fs::create_dir_all(output_dir)
+        .map_err(|e| AppError::FileSystem(format!("Failed to create session dir: {e}")))?;
+
+    let path = next_session_path(output_dir);
+    let json = serde_json::to_string_pretty(session)
+        .map_err(|e| AppError::FileSystem(format!("Failed to serialise session: {e}")))?;
+    std::fs::write(&path, json)
+        .map_err(|e| AppError::FileSystem(format!("Failed to write session: {e}")))?;
+
+    Ok(path)
+}
+
+/// Reads a session written by [`save_session`]
+///
+/// # Errors
+///
+/// Returns `AppError::FileSystem` if the file cannot be read or is not a
+/// session of a supported version.
+pub fn load_session(path: &Path) -> Result<RecordedSession, AppError> {
+    let json = std::fs::read_to_string(path)
+        .map_err(|e| AppError::FileSystem(format!("Failed to read session: {e}")))?;
+    let session: RecordedSession = serde_json::from_str(&json)
+        .map_err(|e| AppError::FileSystem(format!("Invalid session file: {e}")))?;
+    if session.version != SESSION_FORMAT_VERSION {
+        return Err(AppError::FileSystem(format!(
+            "Unsupported session version {}",
+            session.version
+        )));
+    }
+    Ok(session)
+}
+
+/// Mock transcription that returns what the engine produced at the time
+///
+/// # Errors
+///
+/// Returns the error the dictation failed with, or
+/// `AppError::Transcription` if nothing was transcribed.
+pub fn recorded_transcription(dictation: &RecordedDictation) -> Result<String, AppError> {
+    if let Some(error) = dictation.failure() {
+        return Err(error.clone());
+    }
+    dictation
+        .transcript()
+        .map(str::to_string)
+        .ok_or_else(|| AppError::Transcription("No transcript was recorded".to_string()))
+}
+
+/// Replays a session's dictations
+///
+/// # Arguments
+///
+/// * `session` - The recorded session
+/// * `transcribe` - Stands in for the transcription engine, called once per
+///   dictation
+///
+/// # Returns
+///
+/// Returns, per dictation, the post-processed text that would have been
+/// delivered with the snapshot settings, or the error it failed with.
+pub async fn replay_session<F>(
+    session: &RecordedSession,
+    mut transcribe: F,
+) -> Vec<Result<String, AppError>>
+where
+    F: FnMut(&RecordedDictation) -> Result<String, AppError>,
+{
+    let post_processor = create_post_processor_with_loader(session.settings_loader()).await;
+    session
+        .dictations()
+        .iter()
+        .map(|dictation| transcribe(dictation).map(|text| post_processor.process(&text)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use speakr_types::TextTransform;
+    use tempfile::TempDir;
+
+    fn event(offset_ms: u64, event: PipelineEvent) -> RecordedEvent {
+        RecordedEvent { offset_ms, event }
+    }
+
+    fn sample_session() -> RecordedSession {
+        RecordedSession {
+            version: SESSION_FORMAT_VERSION,
+            app_version: "0.1.0".to_string(),
+            settings: AppSettings {
+                post_processing: vec![
+                    TextTransform::TrimWhitespace,
+                    TextTransform::CapitaliseSentences,
+                ],
+                ..AppSettings::default()
+            },
+            events: vec![
+                event(0, PipelineEvent::RecordingStarted),
+                event(900, PipelineEvent::TranscriptionStarted),
+                event(
+                    1200,
+                    PipelineEvent::PartialText(" hello there ".to_string()),
+                ),
+                event(1300, PipelineEvent::Injected),
+                event(5000, PipelineEvent::RecordingStarted),
+                event(
+                    5100,
+                    PipelineEvent::Failed(AppError::AudioCapture("no input".to_string())),
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_recorder_keeps_events_in_order_until_stopped() {
+        start_session_recording(AppSettings::default()).unwrap();
+        assert!(start_session_recording(AppSettings::default()).is_err());
+        record_session_event(&PipelineEvent::RecordingStarted);
+        record_session_event(&PipelineEvent::Injected);
+
+        let session = stop_session_recording().unwrap();
+        record_session_event(&PipelineEvent::RecordingStarted);
+
+        let events: Vec<_> = session.events.into_iter().map(|e| e.event).collect();
+        assert_eq!(
+            events,
+            [PipelineEvent::RecordingStarted, PipelineEvent::Injected]
+        );
+        assert!(stop_session_recording().is_none());
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let session = sample_session();
+
+        let path = save_session(&session, temp_dir.path()).unwrap();
+
+        assert!(path.ends_with("session_001.json"));
+        assert_eq!(load_session(&path).unwrap(), session);
+        assert!(save_session(&session, temp_dir.path())
+            .unwrap()
+            .ends_with("session_002.json"));
+    }
+
+    #[tokio::test]
+    async fn test_replay_uses_snapshot_settings_and_recorded_outcomes() {
+        let results = replay_session(&sample_session(), recorded_transcription).await;
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap(), "Hello there");
+        assert!(matches!(results[1], Err(AppError::AudioCapture(_))));
+    }
+}
@@ -0,0 +1,59 @@
+// ============================================================================
+//! Developer Shortcuts
+// ============================================================================
+//!
+//! Runs the [`DevAction`] bound to a developer shortcut. The shortcuts
+//! themselves come from [`AppSettings::dev_shortcuts`] and are registered by
+//! the hot-key service in debug builds only, so a release build never takes
+//! a key combination away from users.
+//!
+//! [`AppSettings::dev_shortcuts`]: speakr_types::AppSettings::dev_shortcuts
+
+use crate::debug::commands::{
+    debug_clear_log_messages_internal, debug_start_session_recording_internal,
+    debug_stop_session_recording_internal,
+};
+use crate::debug::session::is_session_recording;
+use crate::settings::get_settings_dir;
+use crate::workflow::execute_simulated_workflow;
+use speakr_types::{AppError, DevAction};
+use tauri::AppHandle;
+use tracing::{info, warn};
+
+/// Folder, inside the settings folder, that shortcut-saved sessions go to.
+pub const SESSIONS_DIR_NAME: &str = "sessions";
+
+/// Runs a developer action
+///
+/// Failures are logged rather than returned: there is no caller to report
+/// them to when a shortcut is pressed.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+/// * `action` - The action bound to the pressed shortcut
+pub async fn run_dev_action(app_handle: AppHandle, action: DevAction) {
+    info!("🛠️ Developer shortcut: {}", action.display_name());
+    if let Err(e) = try_run_dev_action(app_handle, action).await {
+        warn!(
+            "Developer shortcut '{}' failed: {}",
+            action.display_name(),
+            e
+        );
+    }
+}
+
+async fn try_run_dev_action(app_handle: AppHandle, action: DevAction) -> Result<(), AppError> {
+    match action {
+        DevAction::SimulateDictation => {
+            execute_simulated_workflow(app_handle).await;
+        }
+        DevAction::ToggleSessionRecording if is_session_recording() => {
+            let output_dir = get_settings_dir()?.join(SESSIONS_DIR_NAME);
+            debug_stop_session_recording_internal(output_dir).await?;
+        }
+        DevAction::ToggleSessionRecording => debug_start_session_recording_internal().await?,
+        DevAction::ClearDebugLog => debug_clear_log_messages_internal().await?,
+    }
+    Ok(())
+}
@@ -2,13 +2,94 @@
 //! Debug Message Storage & Recording State
 // ============================================================================
 
-use crate::debug::types::{DebugLogLevel, DebugLogMessage, DebugRecordingState};
+use crate::debug::types::{
+    DebugLogLevel, DebugLogMessage, DebugLogPage, DebugLogQuery, DebugRecordingState,
+};
 use std::collections::VecDeque;
-use std::sync::{Arc, LazyLock, Mutex};
+use std::sync::{Arc, LazyLock, Mutex, PoisonError};
+use zeroize::Zeroize;
 
-/// Global storage for debug log messages with capacity limit
-pub(crate) static DEBUG_LOG_MESSAGES: LazyLock<Arc<Mutex<VecDeque<DebugLogMessage>>>> =
-    LazyLock::new(|| Arc::new(Mutex::new(VecDeque::with_capacity(1000))));
+/// Number of debug log messages kept until the capacity is changed
+pub const DEFAULT_DEBUG_LOG_CAPACITY: usize = 1000;
+
+/// Smallest debug log capacity that can be configured
+pub const MIN_DEBUG_LOG_CAPACITY: usize = 100;
+
+/// Largest debug log capacity that can be configured
+pub const MAX_DEBUG_LOG_CAPACITY: usize = 50_000;
+
+/// Bounded buffer of debug log messages
+///
+/// Once full, each new message evicts the oldest one, so a long session
+/// holds at most `capacity` messages.
+#[derive(Debug)]
+pub(crate) struct DebugLogBuffer {
+    messages: VecDeque<DebugLogMessage>,
+    capacity: usize,
+    dropped: u64,
+}
+
+impl DebugLogBuffer {
+    /// Creates an empty buffer holding up to `capacity` messages
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            messages: VecDeque::with_capacity(capacity),
+            capacity,
+            dropped: 0,
+        }
+    }
+
+    /// Appends a message, evicting the oldest if the buffer is full
+    pub fn push(&mut self, message: DebugLogMessage) {
+        self.messages.push_back(message);
+        self.evict_overflow();
+    }
+
+    /// Changes the capacity, evicting the oldest messages that no longer fit
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.evict_overflow();
+        self.messages.shrink_to(capacity);
+    }
+
+    /// Removes every message and resets the dropped count
+    pub fn clear(&mut self) {
+        self.messages.clear();
+        self.dropped = 0;
+    }
+
+    /// Returns one page of messages, newest first
+    pub fn page(&self, query: &DebugLogQuery) -> DebugLogPage {
+        let matching = self
+            .messages
+            .iter()
+            .rev()
+            .filter(|message| query.min_level.is_none_or(|min| message.level >= min));
+        let total = matching.clone().count();
+        let messages = matching
+            .skip(query.offset)
+            .take(query.limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect();
+
+        DebugLogPage {
+            messages,
+            total,
+            dropped: self.dropped,
+        }
+    }
+
+    fn evict_overflow(&mut self) {
+        while self.messages.len() > self.capacity {
+            self.messages.pop_front();
+            self.dropped += 1;
+        }
+    }
+}
+
+/// Global storage for debug log messages
+pub(crate) static DEBUG_LOG_MESSAGES: LazyLock<Arc<Mutex<DebugLogBuffer>>> =
+    LazyLock::new(|| Arc::new(Mutex::new(DebugLogBuffer::new(DEFAULT_DEBUG_LOG_CAPACITY))));
 
 /// Global state for debug recording sessions
 pub(crate) static DEBUG_RECORDING_STATE: LazyLock<Arc<Mutex<DebugRecordingState>>> =
@@ -19,6 +100,49 @@ pub(crate) static DEBUG_RECORDING_STATE: LazyLock<Arc<Mutex<DebugRecordingState>
         }))
     });
 
+/// The most recently captured audio, kept for playback in the debug panel
+static LAST_RECORDING: Mutex<Option<Vec<i16>>> = Mutex::new(None);
+
+/// Keeps a copy of captured audio so it can be played back
+///
+/// Called with the raw samples of every dictation and push-to-talk test,
+/// before noise suppression or gain control, replacing (and wiping) the
+/// previous copy.
+pub fn retain_last_recording(samples: &[i16]) {
+    if let Ok(mut last) = LAST_RECORDING.lock() {
+        if let Some(mut previous) = last.replace(samples.to_vec()) {
+            previous.zeroize();
+        }
+    }
+}
+
+/// Discards the audio held for debugging
+///
+/// Cancels a push-to-talk test recording in progress and zeroes the copy of
+/// the last recording before dropping it. Called when Speakr shuts down.
+pub fn discard_debug_audio() {
+    if let Some(recorder) = DEBUG_RECORDING_STATE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .recorder
+        .take()
+    {
+        recorder.cancel_recording();
+    }
+    if let Some(mut samples) = LAST_RECORDING
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take()
+    {
+        samples.zeroize();
+    }
+}
+
+/// Returns the most recently captured audio, if any
+pub fn last_recording() -> Option<Vec<i16>> {
+    LAST_RECORDING.lock().ok().and_then(|last| last.clone())
+}
+
 /// Adds a debug log message to the global storage
 ///
 /// # Arguments
@@ -29,15 +153,72 @@ pub(crate) static DEBUG_RECORDING_STATE: LazyLock<Arc<Mutex<DebugRecordingState>
 ///
 /// # Note
 ///
-/// Messages are stored in a circular buffer with a maximum of 1000 entries.
-/// Oldest messages are automatically removed when capacity is exceeded.
+/// Messages are stored in a ring buffer of [`DEFAULT_DEBUG_LOG_CAPACITY`]
+/// entries unless the capacity has been changed. Oldest messages are
+/// automatically removed when capacity is exceeded.
 pub fn add_debug_log(level: DebugLogLevel, target: &str, message: &str) {
     if let Ok(mut logs) = DEBUG_LOG_MESSAGES.lock() {
-        logs.push_back(DebugLogMessage::new(level, target, message));
+        logs.push(DebugLogMessage::new(level, target, message));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        // Keep only the last 1000 messages
-        while logs.len() > 1000 {
-            logs.pop_front();
+    fn buffer_with(capacity: usize, levels: &[DebugLogLevel]) -> DebugLogBuffer {
+        let mut buffer = DebugLogBuffer::new(capacity);
+        for (i, level) in levels.iter().enumerate() {
+            buffer.push(DebugLogMessage::new(*level, "test", &i.to_string()));
         }
+        buffer
+    }
+
+    fn texts(page: &DebugLogPage) -> Vec<&str> {
+        page.messages.iter().map(|m| m.message.as_str()).collect()
+    }
+
+    #[test]
+    fn test_buffer_evicts_oldest_when_full() {
+        let buffer = buffer_with(3, &[DebugLogLevel::Info; 5]);
+        let page = buffer.page(&DebugLogQuery::default());
+
+        assert_eq!(texts(&page), ["4", "3", "2"]);
+        assert_eq!(page.total, 3);
+        assert_eq!(page.dropped, 2);
+    }
+
+    #[test]
+    fn test_page_filters_by_min_level_and_paginates() {
+        let buffer = buffer_with(
+            10,
+            &[
+                DebugLogLevel::Debug,
+                DebugLogLevel::Warn,
+                DebugLogLevel::Info,
+                DebugLogLevel::Error,
+                DebugLogLevel::Trace,
+                DebugLogLevel::Info,
+            ],
+        );
+        let query = DebugLogQuery {
+            min_level: Some(DebugLogLevel::Info),
+            offset: 1,
+            limit: Some(2),
+        };
+        let page = buffer.page(&query);
+
+        assert_eq!(texts(&page), ["3", "2"]);
+        assert_eq!(page.total, 4);
+    }
+
+    #[test]
+    fn test_shrinking_capacity_keeps_newest() {
+        let mut buffer = buffer_with(5, &[DebugLogLevel::Info; 5]);
+        buffer.set_capacity(2);
+
+        buffer.push(DebugLogMessage::new(DebugLogLevel::Info, "test", "5"));
+
+        assert_eq!(texts(&buffer.page(&DebugLogQuery::default())), ["5", "4"]);
     }
 }
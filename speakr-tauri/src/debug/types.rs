@@ -6,7 +6,9 @@ use serde::{Deserialize, Serialize};
 use speakr_core::audio::AudioRecorder;
 
 /// Debug log levels for categorizing log messages
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Levels are ordered by severity, `Trace` lowest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub enum DebugLogLevel {
     Trace,
@@ -40,6 +42,40 @@ impl DebugLogMessage {
     }
 }
 
+/// Which debug log messages to fetch
+///
+/// Messages are paged newest first: `offset` skips that many of the newest
+/// matching messages and `limit` caps how many are returned.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DebugLogQuery {
+    /// Least severe level to include; `None` includes every level
+    pub min_level: Option<DebugLogLevel>,
+    pub offset: usize,
+    /// Most messages to return; `None` returns all remaining
+    pub limit: Option<usize>,
+}
+
+/// One page of debug log messages
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DebugLogPage {
+    /// Matching messages, newest first
+    pub messages: Vec<DebugLogMessage>,
+    /// Number of matching messages in the buffer, across all pages
+    pub total: usize,
+    /// Messages dropped from the buffer since it was last cleared
+    pub dropped: u64,
+}
+
+/// Transcripts of one recording with and without noise suppression
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DenoiseComparison {
+    pub original: String,
+    pub denoised: String,
+}
+
 /// Shared state for debug recording session
 #[derive(Debug)]
 pub(crate) struct DebugRecordingState {
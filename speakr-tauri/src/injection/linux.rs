@@ -0,0 +1,391 @@
+// ============================================================================
+//! Linux Injection Backend
+// ============================================================================
+//!
+//! Linux has no single API for typing into other applications, so the
+//! backend drives the tools the desktop provides, detected once at launch:
+//! - **X11** - `xdotool` types and presses keys; `xclip` or `xsel` fills the
+//!   clipboard
+//! - **Wayland** - `wtype`, or `ydotool` (which needs its daemon running),
+//!   types and presses keys; `wl-copy` from wl-clipboard fills the clipboard
+//!
+//! Text is handed to the tools on standard input, so dictations never show
+//! up in the process list.
+
+use super::TextInjector;
+use speakr_types::{AppError, PlatformLimitation};
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+use tracing::{debug, info};
+
+/// Time the clipboard tool is given to take ownership before pasting.
+const CLIPBOARD_SETTLE: Duration = Duration::from_millis(50);
+
+/// Display server of the desktop session
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayServer {
+    /// An X11 session
+    X11,
+    /// A Wayland session
+    Wayland,
+    /// No graphical session, e.g. over SSH
+    Headless,
+}
+
+impl DisplayServer {
+    /// Detects the display server from the session's environment
+    pub fn detect() -> Self {
+        Self::from_env(|name| std::env::var(name).ok())
+    }
+
+    /// Detects the display server from the given environment variables
+    ///
+    /// `WAYLAND_DISPLAY` or `XDG_SESSION_TYPE=wayland` mean Wayland, even
+    /// when XWayland also sets `DISPLAY`.
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Self {
+        let is_set = |name: &str| var(name).is_some_and(|value| !value.is_empty());
+        if is_set("WAYLAND_DISPLAY") || var("XDG_SESSION_TYPE").as_deref() == Some("wayland") {
+            DisplayServer::Wayland
+        } else if is_set("DISPLAY") {
+            DisplayServer::X11
+        } else {
+            DisplayServer::Headless
+        }
+    }
+}
+
+/// Tool that synthesises keystrokes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeystrokeTool {
+    /// `xdotool`, for X11
+    Xdotool,
+    /// `wtype`, for Wayland compositors with the virtual keyboard protocol
+    Wtype,
+    /// `ydotool`, for any Wayland compositor through `/dev/uinput`
+    Ydotool,
+}
+
+impl KeystrokeTool {
+    /// Name of the program.
+    pub fn program(self) -> &'static str {
+        match self {
+            KeystrokeTool::Xdotool => "xdotool",
+            KeystrokeTool::Wtype => "wtype",
+            KeystrokeTool::Ydotool => "ydotool",
+        }
+    }
+
+    /// Arguments that type the text read from standard input.
+    fn type_args(self, delay: Duration) -> Vec<String> {
+        let delay = delay.as_millis().to_string();
+        match self {
+            KeystrokeTool::Xdotool => args(["type", "--delay", &delay, "--file", "-"]),
+            KeystrokeTool::Wtype => args(["-d", &delay, "-"]),
+            KeystrokeTool::Ydotool => args(["type", "--key-delay", &delay, "--file", "-"]),
+        }
+    }
+
+    /// Arguments that press Backspace `count` times.
+    fn erase_args(self, count: usize, delay: Duration) -> Vec<String> {
+        let delay = delay.as_millis().to_string();
+        match self {
+            KeystrokeTool::Xdotool => args([
+                "key",
+                "--repeat",
+                &count.to_string(),
+                "--repeat-delay",
+                &delay,
+                "BackSpace",
+            ]),
+            KeystrokeTool::Wtype => {
+                let mut erase = args(["-d", &delay]);
+                for _ in 0..count {
+                    erase.extend(args(["-k", "BackSpace"]));
+                }
+                erase
+            }
+            KeystrokeTool::Ydotool => {
+                // Linux input event codes: KEY_BACKSPACE is 14
+                let mut erase = args(["key", "--key-delay", &delay]);
+                for _ in 0..count {
+                    erase.extend(args(["14:1", "14:0"]));
+                }
+                erase
+            }
+        }
+    }
+
+    /// Arguments that press Ctrl+V.
+    fn paste_args(self) -> Vec<String> {
+        match self {
+            KeystrokeTool::Xdotool => args(["key", "--clearmodifiers", "ctrl+v"]),
+            KeystrokeTool::Wtype => args(["-M", "ctrl", "-k", "v", "-m", "ctrl"]),
+            // KEY_LEFTCTRL is 29 and KEY_V is 47
+            KeystrokeTool::Ydotool => args(["key", "29:1", "47:1", "47:0", "29:0"]),
+        }
+    }
+}
+
+/// Tool that puts text on the clipboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipboardTool {
+    /// `xclip`, for X11
+    Xclip,
+    /// `xsel`, for X11
+    Xsel,
+    /// `wl-copy` from wl-clipboard, for Wayland
+    WlCopy,
+}
+
+impl ClipboardTool {
+    /// Name of the program.
+    pub fn program(self) -> &'static str {
+        match self {
+            ClipboardTool::Xclip => "xclip",
+            ClipboardTool::Xsel => "xsel",
+            ClipboardTool::WlCopy => "wl-copy",
+        }
+    }
+
+    /// Arguments that copy standard input to the clipboard.
+    fn copy_args(self) -> Vec<String> {
+        match self {
+            ClipboardTool::Xclip => args(["-selection", "clipboard", "-in"]),
+            ClipboardTool::Xsel => args(["--clipboard", "--input"]),
+            ClipboardTool::WlCopy => Vec::new(),
+        }
+    }
+}
+
+fn args<const N: usize>(args: [&str; N]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+/// Picks the first installed tools that work with the display server
+///
+/// # Arguments
+///
+/// * `display` - The session's display server
+/// * `installed` - Whether a program is installed
+pub fn choose_tools(
+    display: DisplayServer,
+    installed: impl Fn(&str) -> bool,
+) -> (Option<KeystrokeTool>, Option<ClipboardTool>) {
+    let (keystrokes, clipboard): (&[KeystrokeTool], &[ClipboardTool]) = match display {
+        DisplayServer::X11 => (
+            &[KeystrokeTool::Xdotool],
+            &[ClipboardTool::Xclip, ClipboardTool::Xsel],
+        ),
+        DisplayServer::Wayland => (
+            &[KeystrokeTool::Wtype, KeystrokeTool::Ydotool],
+            &[ClipboardTool::WlCopy],
+        ),
+        DisplayServer::Headless => (&[], &[]),
+    };
+    (
+        keystrokes
+            .iter()
+            .copied()
+            .find(|tool| installed(tool.program())),
+        clipboard
+            .iter()
+            .copied()
+            .find(|tool| installed(tool.program())),
+    )
+}
+
+/// Whether a program is on the `PATH`
+fn is_installed(program: &str) -> bool {
+    std::env::var_os("PATH")
+        .is_some_and(|paths| std::env::split_paths(&paths).any(|dir| dir.join(program).is_file()))
+}
+
+/// Runs a tool, writing `input` to its standard input.
+fn run(program: &str, args: &[String], input: &str) -> Result<(), AppError> {
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| AppError::TextInjection(format!("Failed to run {program}: {e}")))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(input.as_bytes())
+            .map_err(|e| AppError::TextInjection(format!("Failed to write to {program}: {e}")))?;
+    }
+    let output = child
+        .wait_with_output()
+        .map_err(|e| AppError::TextInjection(format!("Failed to run {program}: {e}")))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(AppError::TextInjection(format!(
+            "{program} failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
+
+/// Injects text through the tools installed on this desktop
+#[derive(Debug, Clone, Copy)]
+pub struct LinuxInjector {
+    keystrokes: Option<KeystrokeTool>,
+    clipboard: Option<ClipboardTool>,
+}
+
+impl LinuxInjector {
+    /// Detects the display server and the tools installed for it
+    pub fn detect() -> Self {
+        let display_server = DisplayServer::detect();
+        let (keystrokes, clipboard) = choose_tools(display_server, is_installed);
+        info!(
+            "Text injection on {:?}: keystrokes via {}, clipboard via {}",
+            display_server,
+            keystrokes.map_or("nothing", KeystrokeTool::program),
+            clipboard.map_or("nothing", ClipboardTool::program)
+        );
+        Self {
+            keystrokes,
+            clipboard,
+        }
+    }
+
+    fn keystrokes(&self) -> Result<KeystrokeTool, AppError> {
+        self.keystrokes.ok_or_else(|| {
+            AppError::TextInjection(
+                PlatformLimitation::NoKeystrokeTool
+                    .description()
+                    .to_string(),
+            )
+        })
+    }
+}
+
+impl TextInjector for LinuxInjector {
+    fn type_text(&self, text: &str, delay: Duration) -> Result<(), AppError> {
+        let tool = self.keystrokes()?;
+        debug!(
+            "Typing {} characters with {}",
+            text.chars().count(),
+            tool.program()
+        );
+        run(tool.program(), &tool.type_args(delay), text)
+    }
+
+    fn erase(&self, count: usize, delay: Duration) -> Result<(), AppError> {
+        if count == 0 {
+            return Ok(());
+        }
+        let tool = self.keystrokes()?;
+        run(tool.program(), &tool.erase_args(count, delay), "")
+    }
+
+    fn paste(&self, text: &str) -> Result<(), AppError> {
+        let keystrokes = self.keystrokes()?;
+        let clipboard = self.clipboard.ok_or_else(|| {
+            AppError::TextInjection(
+                PlatformLimitation::NoClipboardTool
+                    .description()
+                    .to_string(),
+            )
+        })?;
+        run(clipboard.program(), &clipboard.copy_args(), text)?;
+        std::thread::sleep(CLIPBOARD_SETTLE);
+        run(keystrokes.program(), &keystrokes.paste_args(), "")
+    }
+
+    fn limitations(&self) -> Vec<PlatformLimitation> {
+        let mut limitations = Vec::new();
+        if self.keystrokes.is_none() {
+            limitations.push(PlatformLimitation::NoKeystrokeTool);
+        }
+        if self.clipboard.is_none() {
+            limitations.push(PlatformLimitation::NoClipboardTool);
+        }
+        limitations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| {
+            vars.iter()
+                .find(|(var, _)| *var == name)
+                .map(|(_, value)| value.to_string())
+        }
+    }
+
+    #[test]
+    fn test_detects_display_server() {
+        assert_eq!(
+            DisplayServer::from_env(env(&[("DISPLAY", ":0")])),
+            DisplayServer::X11
+        );
+        assert_eq!(
+            DisplayServer::from_env(env(&[("DISPLAY", ":0"), ("WAYLAND_DISPLAY", "wayland-0")])),
+            DisplayServer::Wayland
+        );
+        assert_eq!(
+            DisplayServer::from_env(env(&[("XDG_SESSION_TYPE", "wayland")])),
+            DisplayServer::Wayland
+        );
+        assert_eq!(
+            DisplayServer::from_env(env(&[("DISPLAY", "")])),
+            DisplayServer::Headless
+        );
+    }
+
+    #[test]
+    fn test_chooses_tools_for_display_server() {
+        let installed = |programs: &'static [&'static str]| move |p: &str| programs.contains(&p);
+
+        assert_eq!(
+            choose_tools(DisplayServer::X11, installed(&["xdotool", "xsel", "wtype"])),
+            (Some(KeystrokeTool::Xdotool), Some(ClipboardTool::Xsel))
+        );
+        assert_eq!(
+            choose_tools(
+                DisplayServer::Wayland,
+                installed(&["xdotool", "ydotool", "wl-copy"])
+            ),
+            (Some(KeystrokeTool::Ydotool), Some(ClipboardTool::WlCopy))
+        );
+        assert_eq!(
+            choose_tools(DisplayServer::Headless, installed(&["xdotool", "xclip"])),
+            (None, None)
+        );
+    }
+
+    #[test]
+    fn test_reports_missing_tools() {
+        let injector = LinuxInjector {
+            keystrokes: Some(KeystrokeTool::Wtype),
+            clipboard: None,
+        };
+        assert_eq!(
+            injector.limitations(),
+            [PlatformLimitation::NoClipboardTool]
+        );
+        assert!(matches!(
+            injector.paste("text"),
+            Err(AppError::TextInjection(_))
+        ));
+    }
+
+    #[test]
+    fn test_tool_arguments() {
+        assert_eq!(
+            KeystrokeTool::Wtype.erase_args(2, Duration::from_millis(5)),
+            ["-d", "5", "-k", "BackSpace", "-k", "BackSpace"]
+        );
+        assert_eq!(
+            KeystrokeTool::Xdotool.type_args(Duration::from_millis(12)),
+            ["type", "--delay", "12", "--file", "-"]
+        );
+    }
+}
@@ -0,0 +1,21 @@
+// ============================================================================
+//! macOS Secure Input Detection
+// ============================================================================
+//!
+//! While a password field (or a terminal with "Secure Keyboard Entry") has
+//! focus, macOS turns on secure event input: synthetic keystrokes from other
+//! processes are dropped, and typing a dictation there would at best do
+//! nothing and at worst leak it into the wrong place once focus moves.
+//! [`secure_input_active`] lets the workflow suppress injection instead.
+
+#[link(name = "Carbon", kind = "framework")]
+extern "C" {
+    /// `Boolean IsSecureEventInputEnabled(void)` from HIToolbox
+    fn IsSecureEventInputEnabled() -> u8;
+}
+
+/// Whether any application has secure event input turned on
+pub fn secure_input_active() -> bool {
+    // SAFETY: takes no arguments and only reads the window server's state
+    unsafe { IsSecureEventInputEnabled() != 0 }
+}
@@ -0,0 +1,153 @@
+// ============================================================================
+//! Text Injection Backends
+// ============================================================================
+//!
+//! Types dictated text into the focused application, or pastes it, through
+//! the backend for the platform Speakr runs on (see [`platform_injector`]):
+//! - **Linux** - `xdotool` under X11, `wtype` or `ydotool` under Wayland,
+//!   pasting through `xclip`/`xsel` or `wl-copy` (see [`linux`])
+//! - **Windows** - `SendInput` Unicode keystrokes, pasting while an IME is
+//!   open (see [`windows`])
+//! - **Other platforms** - simulated until their backends land: the time
+//!   the keystrokes would take is waited out and nothing is typed
+//!
+//! Backends block while they type, so the workflow runs them on a blocking
+//! thread through [`run_injector`]. Before typing, the workflow asks
+//! [`injection_suppression`] whether it should: on macOS, secure input (a
+//! focused password field) suppresses injection. What a backend cannot do on this desktop
+//! is reported as [`PlatformLimitation`]s in the backend status (see
+//! [`report_injection_limitations`]).
+
+#[cfg(target_os = "linux")]
+pub mod linux;
+#[cfg(target_os = "macos")]
+pub mod macos;
+#[cfg(target_os = "windows")]
+pub mod windows;
+
+use crate::services::update_platform_limitation;
+use speakr_types::{AppError, PlatformLimitation};
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
+use tracing::warn;
+
+/// Types and pastes text into the focused application
+pub trait TextInjector: Send + Sync {
+    /// Types `text`, pausing `delay` between characters
+    ///
+    /// # Errors
+    ///
+    /// Returns `AppError::TextInjection` if the text cannot be typed.
+    fn type_text(&self, text: &str, delay: Duration) -> Result<(), AppError>;
+
+    /// Erases `count` characters before the cursor, pausing `delay` between
+    /// key presses
+    ///
+    /// # Errors
+    ///
+    /// Returns `AppError::TextInjection` if the keys cannot be pressed.
+    fn erase(&self, count: usize, delay: Duration) -> Result<(), AppError>;
+
+    /// Puts `text` on the clipboard and presses the paste shortcut
+    ///
+    /// # Errors
+    ///
+    /// Returns `AppError::TextInjection` if the text cannot be pasted.
+    fn paste(&self, text: &str) -> Result<(), AppError>;
+
+    /// What this backend cannot do on this desktop
+    fn limitations(&self) -> Vec<PlatformLimitation> {
+        Vec::new()
+    }
+}
+
+/// Backend for the platform, chosen on first use.
+static INJECTOR: LazyLock<Arc<dyn TextInjector>> = LazyLock::new(|| {
+    #[cfg(target_os = "linux")]
+    {
+        Arc::new(linux::LinuxInjector::detect())
+    }
+    #[cfg(target_os = "windows")]
+    {
+        Arc::new(windows::WindowsInjector)
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "windows")))]
+    {
+        Arc::new(SimulatedInjector)
+    }
+});
+
+/// Returns the injection backend for the platform Speakr runs on
+pub fn platform_injector() -> Arc<dyn TextInjector> {
+    Arc::clone(&INJECTOR)
+}
+
+/// Runs `inject` with the platform backend on a blocking thread
+///
+/// # Errors
+///
+/// Returns the error from `inject`, or `AppError::TextInjection` if the
+/// thread fails.
+pub async fn run_injector<F>(inject: F) -> Result<(), AppError>
+where
+    F: FnOnce(&dyn TextInjector) -> Result<(), AppError> + Send + 'static,
+{
+    let injector = platform_injector();
+    tokio::task::spawn_blocking(move || inject(injector.as_ref()))
+        .await
+        .map_err(|e| AppError::TextInjection(format!("Injection task failed: {e}")))?
+}
+
+/// Explains why text must not be injected right now
+///
+/// # Returns
+///
+/// The reason, shown to the user, or `None` if injecting is fine.
+pub fn injection_suppression() -> Option<String> {
+    #[cfg(target_os = "macos")]
+    if macos::secure_input_active() {
+        return Some(
+            "Secure input is on (a password field may have focus), so Speakr did not type the dictation"
+                .to_string(),
+        );
+    }
+    None
+}
+
+/// Records in the backend status what the injection backend cannot do here
+pub async fn report_injection_limitations() {
+    let limitations = platform_injector().limitations();
+    for limitation in [
+        PlatformLimitation::NoKeystrokeTool,
+        PlatformLimitation::NoClipboardTool,
+    ] {
+        let applies = limitations.contains(&limitation);
+        if applies {
+            warn!("{}", limitation.description());
+        }
+        update_platform_limitation(limitation, applies).await;
+    }
+}
+
+/// Stands in for the platforms without a backend yet
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+struct SimulatedInjector;
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+impl TextInjector for SimulatedInjector {
+    fn type_text(&self, text: &str, delay: Duration) -> Result<(), AppError> {
+        std::thread::sleep(delay * text.chars().count() as u32);
+        Ok(())
+    }
+
+    fn erase(&self, count: usize, delay: Duration) -> Result<(), AppError> {
+        std::thread::sleep(delay * count as u32);
+        Ok(())
+    }
+
+    fn paste(&self, _text: &str) -> Result<(), AppError> {
+        // One paste shortcut
+        std::thread::sleep(Duration::from_millis(50));
+        Ok(())
+    }
+}
@@ -0,0 +1,300 @@
+// ============================================================================
+//! Windows Injection Backend
+// ============================================================================
+//!
+//! Types through `SendInput` with `KEYEVENTF_UNICODE`, so any character can
+//! be typed whatever the keyboard layout. Characters outside the Basic
+//! Multilingual Plane, such as emoji, are sent as their UTF-16 surrogate
+//! pair in a single `SendInput` call so the target receives both halves
+//! together; the pause between characters falls after the whole pair.
+//!
+//! An open IME (Chinese, Japanese, Korean input) would capture the typed
+//! characters as a composition instead of inserting them, so while the
+//! focused window has one open the text is pasted instead.
+
+use super::TextInjector;
+use speakr_types::AppError;
+use std::mem::size_of;
+use std::time::Duration;
+use tracing::debug;
+use windows_sys::Win32::Foundation::{GlobalFree, HWND};
+use windows_sys::Win32::System::DataExchange::{
+    CloseClipboard, EmptyClipboard, OpenClipboard, SetClipboardData,
+};
+use windows_sys::Win32::System::Memory::{GlobalAlloc, GlobalLock, GlobalUnlock, GMEM_MOVEABLE};
+use windows_sys::Win32::System::Ole::CF_UNICODETEXT;
+use windows_sys::Win32::UI::Input::Ime::ImmGetDefaultIMEWnd;
+use windows_sys::Win32::UI::Input::KeyboardAndMouse::{
+    SendInput, INPUT, INPUT_0, INPUT_KEYBOARD, KEYBDINPUT, KEYEVENTF_KEYUP, KEYEVENTF_UNICODE,
+    VIRTUAL_KEY, VK_BACK, VK_CONTROL, VK_RETURN, VK_TAB, VK_V,
+};
+use windows_sys::Win32::UI::WindowsAndMessaging::{
+    GetForegroundWindow, SendMessageTimeoutW, SMTO_ABORTIFHUNG, WM_IME_CONTROL,
+};
+
+/// `WM_IME_CONTROL` request for whether the IME is open (not exported by
+/// windows-sys).
+const IMC_GETOPENSTATUS: usize = 0x0005;
+
+/// How long the focused window's IME gets to answer whether it is open.
+const IME_QUERY_TIMEOUT_MS: u32 = 100;
+
+/// Attempts at opening the clipboard while another application holds it.
+const CLIPBOARD_ATTEMPTS: u32 = 5;
+
+/// Time the target is given to read the clipboard after the paste shortcut.
+const CLIPBOARD_SETTLE: Duration = Duration::from_millis(50);
+
+/// Injects text through `SendInput` and the clipboard
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WindowsInjector;
+
+/// A key press or release of a virtual key.
+fn key_input(key: VIRTUAL_KEY, up: bool) -> INPUT {
+    keyboard_input(KEYBDINPUT {
+        wVk: key,
+        wScan: 0,
+        dwFlags: if up { KEYEVENTF_KEYUP } else { 0 },
+        time: 0,
+        dwExtraInfo: 0,
+    })
+}
+
+/// A press or release of one UTF-16 code unit.
+fn unicode_input(unit: u16, up: bool) -> INPUT {
+    keyboard_input(KEYBDINPUT {
+        wVk: 0,
+        wScan: unit,
+        dwFlags: KEYEVENTF_UNICODE | if up { KEYEVENTF_KEYUP } else { 0 },
+        time: 0,
+        dwExtraInfo: 0,
+    })
+}
+
+fn keyboard_input(ki: KEYBDINPUT) -> INPUT {
+    INPUT {
+        r#type: INPUT_KEYBOARD,
+        Anonymous: INPUT_0 { ki },
+    }
+}
+
+/// Inputs that type one character
+///
+/// Line breaks and tabs are sent as the Enter and Tab keys, which
+/// applications handle more reliably than the Unicode control characters;
+/// a `\r\n` pair becomes a single Enter by skipping the `\r`.
+fn character_inputs(ch: char) -> Vec<INPUT> {
+    match ch {
+        '\r' => Vec::new(),
+        '\n' => vec![key_input(VK_RETURN, false), key_input(VK_RETURN, true)],
+        '\t' => vec![key_input(VK_TAB, false), key_input(VK_TAB, true)],
+        _ => {
+            let mut units = [0u16; 2];
+            let units = ch.encode_utf16(&mut units);
+            // Both halves of a surrogate pair go down before either comes up
+            let mut inputs: Vec<INPUT> = units.iter().map(|&u| unicode_input(u, false)).collect();
+            inputs.extend(units.iter().map(|&u| unicode_input(u, true)));
+            inputs
+        }
+    }
+}
+
+/// Sends `inputs` in one call, so nothing the user types interleaves.
+fn send(inputs: &[INPUT]) -> Result<(), AppError> {
+    if inputs.is_empty() {
+        return Ok(());
+    }
+    // SAFETY: `inputs` is a valid slice of initialised INPUT structures and
+    // the size passed matches the element type.
+    let sent = unsafe {
+        SendInput(
+            inputs.len() as u32,
+            inputs.as_ptr(),
+            size_of::<INPUT>() as i32,
+        )
+    };
+    if sent as usize == inputs.len() {
+        Ok(())
+    } else {
+        // Windows blocks input into elevated windows from a normal process
+        Err(AppError::TextInjection(format!(
+            "SendInput delivered {sent} of {} key events: {}",
+            inputs.len(),
+            std::io::Error::last_os_error()
+        )))
+    }
+}
+
+/// Whether the focused window has an IME open
+fn ime_is_open() -> bool {
+    // SAFETY: these calls take no pointers other than the out-parameter,
+    // which points at a live local.
+    unsafe {
+        let window: HWND = GetForegroundWindow();
+        if window.is_null() {
+            return false;
+        }
+        let ime_window = ImmGetDefaultIMEWnd(window);
+        if ime_window.is_null() {
+            return false;
+        }
+        let mut open = 0usize;
+        let answered = SendMessageTimeoutW(
+            ime_window,
+            WM_IME_CONTROL,
+            IMC_GETOPENSTATUS,
+            0,
+            SMTO_ABORTIFHUNG,
+            IME_QUERY_TIMEOUT_MS,
+            &mut open,
+        );
+        answered != 0 && open != 0
+    }
+}
+
+/// Replaces the clipboard contents with `text`.
+fn set_clipboard_text(text: &str) -> Result<(), AppError> {
+    let mut units: Vec<u16> = text.encode_utf16().collect();
+    units.push(0);
+
+    // SAFETY: the clipboard is opened before it is written and closed after,
+    // whatever the outcome.
+    unsafe {
+        let mut opened = false;
+        for _ in 0..CLIPBOARD_ATTEMPTS {
+            if OpenClipboard(std::ptr::null_mut()) != 0 {
+                opened = true;
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        if !opened {
+            return Err(AppError::TextInjection(
+                "The clipboard is in use by another application".to_string(),
+            ));
+        }
+        let result = write_clipboard(&units);
+        CloseClipboard();
+        result
+    }
+}
+
+/// Writes nul-terminated UTF-16 text to the open clipboard
+///
+/// # Safety
+///
+/// The clipboard must be open on this thread.
+unsafe fn write_clipboard(units: &[u16]) -> Result<(), AppError> {
+    if EmptyClipboard() == 0 {
+        return Err(clipboard_error("clear"));
+    }
+    // The clipboard owns the memory once it accepts it; until then it is
+    // freed on failure
+    let memory = GlobalAlloc(GMEM_MOVEABLE, std::mem::size_of_val(units));
+    if memory.is_null() {
+        return Err(clipboard_error("allocate"));
+    }
+    let target = GlobalLock(memory) as *mut u16;
+    if target.is_null() {
+        GlobalFree(memory);
+        return Err(clipboard_error("lock"));
+    }
+    std::ptr::copy_nonoverlapping(units.as_ptr(), target, units.len());
+    GlobalUnlock(memory);
+    if SetClipboardData(u32::from(CF_UNICODETEXT), memory).is_null() {
+        GlobalFree(memory);
+        return Err(clipboard_error("set"));
+    }
+    Ok(())
+}
+
+fn clipboard_error(action: &str) -> AppError {
+    AppError::TextInjection(format!(
+        "Failed to {action} the clipboard: {}",
+        std::io::Error::last_os_error()
+    ))
+}
+
+impl TextInjector for WindowsInjector {
+    fn type_text(&self, text: &str, delay: Duration) -> Result<(), AppError> {
+        if ime_is_open() {
+            debug!("IME open in the focused window, pasting instead of typing");
+            return self.paste(text);
+        }
+        for ch in text.chars() {
+            let inputs = character_inputs(ch);
+            if inputs.is_empty() {
+                continue;
+            }
+            send(&inputs)?;
+            std::thread::sleep(delay);
+        }
+        Ok(())
+    }
+
+    fn erase(&self, count: usize, delay: Duration) -> Result<(), AppError> {
+        for _ in 0..count {
+            send(&[key_input(VK_BACK, false), key_input(VK_BACK, true)])?;
+            std::thread::sleep(delay);
+        }
+        Ok(())
+    }
+
+    fn paste(&self, text: &str) -> Result<(), AppError> {
+        set_clipboard_text(text)?;
+        send(&[
+            key_input(VK_CONTROL, false),
+            key_input(VK_V, false),
+            key_input(VK_V, true),
+            key_input(VK_CONTROL, true),
+        ])?;
+        std::thread::sleep(CLIPBOARD_SETTLE);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan_codes(inputs: &[INPUT]) -> Vec<(u16, bool)> {
+        inputs
+            .iter()
+            // SAFETY: every input built here is a keyboard input
+            .map(|input| unsafe {
+                (
+                    input.Anonymous.ki.wScan,
+                    input.Anonymous.ki.dwFlags & KEYEVENTF_KEYUP != 0,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_surrogate_pairs_are_sent_together() {
+        // U+1F600 is the surrogate pair D83D DE00
+        assert_eq!(
+            scan_codes(&character_inputs('😀')),
+            [
+                (0xD83D, false),
+                (0xDE00, false),
+                (0xD83D, true),
+                (0xDE00, true)
+            ]
+        );
+        assert_eq!(
+            scan_codes(&character_inputs('é')),
+            [(0x00E9, false), (0x00E9, true)]
+        );
+    }
+
+    #[test]
+    fn test_line_breaks_press_enter() {
+        let inputs = character_inputs('\n');
+        // SAFETY: keyboard inputs
+        assert!(inputs
+            .iter()
+            .all(|input| unsafe { input.Anonymous.ki.wVk } == VK_RETURN));
+        assert!(character_inputs('\r').is_empty());
+    }
+}
@@ -4,7 +4,9 @@
 //! This module provides the Tauri commands and backend functionality for the Speakr
 //! dictation application, including:
 //! - Settings management and persistence
-//! - Global hot-key registration using tauri-plugin-global-shortcut
+//! - Global hot-key registration using tauri-plugin-global-shortcut, or the
+//!   shortcuts portal under Wayland
+//! - Text injection into the focused application
 //! - Model file validation
 //! - System integration
 // ============================================================================
@@ -14,8 +16,12 @@
 // =========================
 pub mod audio;
 pub mod commands;
+pub mod crash;
 #[cfg(debug_assertions)]
 pub mod debug;
+pub mod injection;
+pub mod lifecycle;
+pub mod logging;
 pub mod services;
 pub mod settings;
 pub mod workflow;
@@ -23,181 +29,1261 @@ pub mod workflow;
 // =========================
 // External Imports
 // =========================
+use audio::pre_buffer::apply_pre_buffer_setting;
 use commands::{
+    hotkey_conflicts::DEFAULT_SUGGESTION_COUNT,
     legacy::register_hot_key_internal,
-    system::{check_model_availability_internal, set_auto_launch_internal},
+    permissions::{
+        check_accessibility_permission_internal, check_microphone_permission_internal,
+        open_permission_settings_internal, spawn_permission_watcher,
+    },
+    plugins::{install_plugin_internal, list_plugins_internal, uninstall_plugin_internal},
+    storage::{clean_up_storage_internal, get_storage_usage_internal},
+    system::{
+        check_denoise_model_internal, check_disk_space_for_model_internal,
+        check_model_availability_internal, delete_model_internal, download_model_internal,
+        get_auto_launch_status_internal, get_models_disk_usage_internal,
+        get_supported_languages_internal, list_input_devices_internal,
+        list_installed_models_internal, list_model_catalogue_internal, set_auto_launch_internal,
+        test_microphone_internal,
+    },
+    transcription::{
+        export_transcription_internal, transcribe_file_internal, transcribe_folder_internal,
+    },
     validation::validate_hot_key_internal,
 };
+use crash::{
+    dismiss_crash_notice_internal, get_crash_notice_internal, install_crash_handler,
+    open_crash_report_internal, spawn_crash_notice,
+};
 #[cfg(debug_assertions)]
 use debug::{
-    add_debug_log, debug_clear_log_messages_internal, debug_get_log_messages_internal,
-    debug_start_recording_internal, debug_stop_recording_internal,
-    debug_test_audio_recording_internal, DebugLogLevel, DebugLogMessage,
+    debug_clear_log_messages_internal, debug_compare_denoise_internal,
+    debug_get_log_messages_internal, debug_input_level_internal,
+    debug_play_last_recording_internal, debug_record_fixture_internal, debug_replay_wav_internal,
+    debug_set_log_capacity_internal, debug_start_recording_internal,
+    debug_start_session_recording_internal, debug_stop_recording_internal,
+    debug_stop_session_recording_internal, debug_test_audio_recording_internal, AudioFixture,
+    DebugLogPage, DebugLogQuery, DenoiseComparison,
+};
+use injection::report_injection_limitations;
+use lifecycle::{shutdown, spawn_signal_handler};
+use logging::{
+    get_log_file_path_internal, get_log_levels_internal, init_tracing, open_logs_folder_internal,
+    set_log_level_internal,
+};
+use services::connectivity::spawn_connectivity_events;
+use services::event_throttle::acknowledge_event_internal;
+use services::history::{
+    apply_history_encryption, correct_history_internal, delete_history_internal,
+    get_vocabulary_suggestions_internal, list_history_internal,
+};
+#[cfg(debug_assertions)]
+use services::hotkey::register_dev_shortcuts;
+use services::input_preview::{
+    input_preview_level_internal, start_input_preview_internal, stop_input_preview_internal,
 };
+use services::last_transcription::reinject_last_transcription_internal;
+use services::local_api::serve_local_api;
+use services::model_manager::{
+    apply_model_settings, attach_model_events, spawn_idle_unloader, warm_load_model,
+};
+use services::pause::{is_dictation_enabled, set_dictation_enabled_internal};
+use services::pending::{
+    discard_pending_internal, inject_pending_internal, list_pending_internal,
+    restore_pending_badge, update_pending_internal,
+};
+use services::performance::get_performance_metrics_internal;
+use services::privacy::{privacy_mode, set_privacy_mode_internal};
+use services::profiles::switch_profile_and_apply;
+use services::secrets::{get_secret_internal, set_secret_internal};
+use services::tray::refresh_tray_menu;
+#[cfg(desktop)]
+use services::tray::setup_tray;
+use services::watchdog::spawn_service_watchdog;
+#[cfg(desktop)]
+use services::window_state::{fit_windows_to_monitors, window_state_plugin};
 use services::{
-    get_backend_status_internal,
+    attach_status_events, get_backend_status_internal,
     hotkey::{
-        register_global_hotkey_internal, unregister_global_hotkey_internal,
-        update_global_hotkey_internal,
+        bind_hotkeys_with_portal, get_hotkey_status_internal, register_global_hotkey_internal,
+        register_hotkeys_from_settings, register_language_hotkeys, register_privacy_hotkey,
+        register_reinject_hotkey, suggest_available_hotkeys_internal,
+        unregister_global_hotkey_internal, update_global_hotkey_internal,
     },
     update_service_status_internal, ServiceComponent,
 };
-use settings::{load_settings_internal, save_settings_internal};
-use speakr_types::{AppError, AppSettings, HotkeyConfig, ServiceStatus, StatusUpdate};
-use tauri::{App, AppHandle, Listener, Manager};
-use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt, Modifiers, Shortcut, ShortcutState};
-use tracing::{error, info, warn};
-use tracing_subscriber::fmt::fmt;
-use tracing_subscriber::EnvFilter;
-use workflow::execute_dictation_workflow;
+use settings::profiles::{
+    delete_profile_internal, load_profiles_internal, save_current_as_profile_internal,
+};
+use settings::recovery::{
+    dismiss_settings_recovery_internal, get_settings_recovery_internal,
+    open_quarantined_settings_internal, spawn_settings_recovery_notice,
+};
+use settings::{
+    commit_migration_preview_internal, load_settings_internal,
+    rollback_interrupted_migration_internal, save_settings_internal, MIGRATION_TRIAL_PERIOD,
+};
+use speakr_types::events::{HOTKEY_TRIGGERED_EVENT, SETTINGS_CHANGED_EVENT};
+use speakr_types::{
+    AppError, AppSettings, BatchJob, CatalogueModel, CommandError, CrashNotice, DictationOverrides,
+    DiskSpaceCheck, HistoryEntry, HotkeyConfig, HotkeyStatus, InstalledModel, LanguageOption,
+    LogSubsystem, LogVerbosity, MicrophoneTest, ModelId, ModelsDiskUsage, PendingDictation,
+    PerformanceMetrics, PermissionKind, PluginInfo, PrivacyMode, ServiceStatus, SettingsProfiles,
+    SettingsRecovery, StatusUpdate, StorageCategory, StorageUsage, SubsystemLogLevel,
+    SubtitleFormat, TranscriptionResult, VocabularySuggestion, DEFAULT_BATCH_PARALLELISM,
+};
+use tauri::{App, AppHandle, Emitter, Listener, Manager, RunEvent};
+use tracing::{info, warn};
+use workflow::{
+    cancel_active_dictation, execute_simulated_workflow, start_dictation_with_overrides,
+};
+
+// ============================================================================
+// Tauri Command Definitions
+// ============================================================================
+
+// --------------------------------------------------------------------------
+/// Saves application settings to disk atomically.
+///
+/// Emits `settings-changed` with the saved settings so open views (e.g. hints
+/// that show the hot-key) stay in sync. The history is encrypted or
+/// decrypted first when `encrypt_history` changes.
+///
+/// # Arguments
+/// * `settings` - The settings to save
+///
+/// # Returns
+/// Returns `Ok(())` on success.
+///
+/// # Errors
+/// Returns `AppError` if the settings cannot be saved, or if the history
+/// cannot be encrypted or decrypted to match them.
+///
+/// # Example
+/// ```no_run
+/// // In frontend: invoke('save_settings', { settings })
+/// ```
+#[tauri::command]
+async fn save_settings(app_handle: AppHandle, settings: AppSettings) -> Result<(), CommandError> {
+    apply_history_encryption(settings.encrypt_history)?;
+    save_settings_internal(settings.clone()).await?;
+    if let Err(e) = apply_pre_buffer_setting(&settings).await {
+        warn!("Failed to apply pre-recording setting: {}", e);
+    }
+    // Paused dictation registers the hot-keys again when it resumes
+    if is_dictation_enabled() && !bind_hotkeys_with_portal(&app_handle, &settings) {
+        if let Err(e) = register_language_hotkeys(&app_handle, &settings.language_hotkeys) {
+            warn!("Failed to apply language hotkeys: {}", e);
+        }
+        if let Err(e) = register_privacy_hotkey(&app_handle, settings.privacy_hotkey.as_deref()) {
+            warn!("Failed to apply privacy hotkey: {}", e);
+        }
+        if let Err(e) = register_reinject_hotkey(&app_handle, settings.reinject_hotkey.as_deref()) {
+            warn!("Failed to apply re-type hotkey: {}", e);
+        }
+        #[cfg(debug_assertions)]
+        if let Err(e) = register_dev_shortcuts(&app_handle, &settings.dev_shortcuts) {
+            warn!("Failed to apply developer shortcuts: {}", e);
+        }
+    }
+    let model_settings = settings.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = apply_model_settings(&model_settings).await {
+            warn!("Failed to load the selected model: {}", e);
+        }
+    });
+    if let Err(e) = app_handle.emit(SETTINGS_CHANGED_EVENT, &settings) {
+        warn!("Failed to emit settings change: {}", e);
+    }
+    Ok(())
+}
+
+// --------------------------------------------------------------------------
+/// Runs a simulated dictation so users can try Speakr before downloading a model.
+///
+/// # Returns
+/// Returns the sample transcript after the user's clean-up rules are applied.
+///
+/// # Example
+/// ```no_run
+/// // In frontend: invoke('simulate_dictation')
+/// ```
+#[tauri::command]
+async fn simulate_dictation(app_handle: AppHandle) -> Result<String, CommandError> {
+    Ok(execute_simulated_workflow(app_handle).await)
+}
+
+// --------------------------------------------------------------------------
+/// Cancels the dictation in flight, discarding its audio and transcript.
+///
+/// Emits `dictation-cancelled` when a dictation was running.
+///
+/// # Returns
+/// Returns `true` if a dictation was cancelled, `false` if none was running.
+///
+/// # Example
+/// ```no_run
+/// // In frontend: invoke('cancel_dictation')
+/// ```
+#[tauri::command]
+async fn cancel_dictation(app_handle: AppHandle) -> Result<bool, CommandError> {
+    Ok(cancel_active_dictation(&app_handle))
+}
+
+// --------------------------------------------------------------------------
+/// Acknowledges that the frontend has handled the latest payload of a
+/// throttled event, allowing the backend to send the next one.
+///
+/// # Arguments
+/// * `event` - Name of the acknowledged event (e.g. `"transcription-progress"`)
+///
+/// # Returns
+/// Returns `true` if the event is currently throttled by acknowledgement.
+///
+/// # Example
+/// ```no_run
+/// // In frontend: invoke('ack_event', { event: 'transcription-progress' })
+/// ```
+#[tauri::command]
+async fn ack_event(event: String) -> Result<bool, CommandError> {
+    Ok(acknowledge_event_internal(&event))
+}
+
+// --------------------------------------------------------------------------
+/// Loads application settings from disk with corruption recovery.
+///
+/// # Returns
+/// Returns the loaded settings or default settings if the file doesn't exist.
+/// If the file is corrupt, attempts to recover from backup, then falls back to defaults.
+///
+/// # Errors
+/// Returns `AppError` if all recovery attempts fail.
+///
+/// # Example
+/// ```no_run
+/// // In frontend: invoke('load_settings')
+/// ```
+#[tauri::command]
+async fn load_settings() -> Result<AppSettings, CommandError> {
+    load_settings_internal().await.map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Validates a hot-key string for correctness and conflicts.
+///
+/// # Arguments
+/// * `hot_key` - The hot-key combination as a string
+///
+/// # Returns
+/// Returns `Ok(())` if the hot-key is valid and available.
+///
+/// # Errors
+/// Returns `AppError` if the hot-key is invalid or conflicts with system/global shortcuts.
+#[tauri::command]
+async fn validate_hot_key(hot_key: String) -> Result<(), CommandError> {
+    validate_hot_key_internal(hot_key)
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Checks if a catalogue model has been downloaded.
+///
+/// # Arguments
+/// * `model` - The model identifier (e.g., "small.en", "large-v3-turbo")
+///
+/// # Returns
+/// Returns `Ok(true)` if the model is available, `Ok(false)` otherwise.
+///
+/// # Errors
+/// Returns `AppError` if the model is not in the catalogue.
+#[tauri::command]
+async fn check_model_availability(model: ModelId) -> Result<bool, CommandError> {
+    check_model_availability_internal(model)
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Lists every catalogue model with its size, checksum and install state.
+///
+/// # Returns
+/// Returns the catalogue for the settings model picker.
+#[tauri::command]
+async fn list_model_catalogue() -> Result<Vec<CatalogueModel>, CommandError> {
+    list_model_catalogue_internal()
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Lists the connected microphones.
+///
+/// # Returns
+/// Returns the device names, with the system default first.
+///
+/// # Errors
+/// Returns `AppError` if no microphone is connected.
+#[tauri::command]
+async fn list_input_devices() -> Result<Vec<String>, CommandError> {
+    list_input_devices_internal()
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Records a couple of seconds from the microphone and measures its levels.
+///
+/// # Returns
+/// Returns the peak and RMS levels and whether the input clipped.
+///
+/// # Errors
+/// Returns `AppError` if a dictation is in progress or recording fails.
+#[tauri::command]
+async fn test_microphone() -> Result<MicrophoneTest, CommandError> {
+    test_microphone_internal().await.map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Starts a live level preview of a microphone at an input gain.
+///
+/// # Arguments
+/// * `device` - Name of the input device
+/// * `gain` - Input gain to preview
+///
+/// # Errors
+/// Returns `AppError` if the gain is out of range, a dictation is in
+/// progress or recording fails.
+#[tauri::command]
+async fn start_input_preview(device: String, gain: f32) -> Result<(), CommandError> {
+    start_input_preview_internal(device, gain)
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Reads the level of the input gain preview.
+///
+/// # Returns
+/// Returns the level in dBFS, or `None` once the preview has stopped.
+#[tauri::command]
+async fn input_preview_level() -> Result<Option<f32>, CommandError> {
+    Ok(input_preview_level_internal())
+}
+
+/// Stops the input gain preview.
+#[tauri::command]
+async fn stop_input_preview() -> Result<(), CommandError> {
+    stop_input_preview_internal();
+    Ok(())
+}
+
+// --------------------------------------------------------------------------
+/// Checks whether the noise suppression weights have been downloaded.
+///
+/// # Returns
+/// Returns `Ok(true)` if the RNNoise weights are installed.
+#[tauri::command]
+async fn check_denoise_model() -> Result<bool, CommandError> {
+    check_denoise_model_internal()
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Lists the model files installed in the configured model directory.
+///
+/// # Arguments
+/// * `verify_checksums` - Whether to hash each file against its catalogue checksum
+///
+/// # Returns
+/// Returns the installed models with their sizes and checksum status.
+///
+/// # Errors
+/// Returns `AppError` if the model directory cannot be read.
+#[tauri::command]
+async fn list_installed_models(
+    verify_checksums: bool,
+) -> Result<Vec<InstalledModel>, CommandError> {
+    list_installed_models_internal(verify_checksums)
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Deletes an installed model file.
+///
+/// # Arguments
+/// * `filename` - Bare file name of the model to delete
+///
+/// # Returns
+/// Returns `Ok(())` once the file has been removed.
+///
+/// # Errors
+/// Returns `AppError` if the name is invalid or the file cannot be removed.
+#[tauri::command]
+async fn delete_model(filename: String) -> Result<(), CommandError> {
+    delete_model_internal(filename)
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Reports the disk space used by installed models.
+///
+/// # Returns
+/// Returns the model directory, total size in bytes and model count.
+///
+/// # Errors
+/// Returns `AppError` if the model directory cannot be read.
+#[tauri::command]
+async fn get_models_disk_usage() -> Result<ModelsDiskUsage, CommandError> {
+    get_models_disk_usage_internal()
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Transcribes an existing recording with the current model and language.
+///
+/// # Arguments
+/// * `path` - A WAV, MP3 or M4A file
+///
+/// # Returns
+/// Returns the transcription, including segments and word timings.
+///
+/// # Errors
+/// Returns `AppError` if the file cannot be decoded or transcription fails.
+#[tauri::command]
+async fn transcribe_file(path: String) -> Result<TranscriptionResult, CommandError> {
+    transcribe_file_internal(std::path::PathBuf::from(path))
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Saves a transcription as SubRip or WebVTT captions.
+///
+/// # Arguments
+/// * `result` - The transcription to export
+/// * `format` - The caption format
+/// * `path` - The file to write
+///
+/// # Returns
+/// Returns `Ok(())` once the file is written.
+///
+/// # Errors
+/// Returns `AppError` if the file cannot be written.
+#[tauri::command]
+async fn export_transcription(
+    result: TranscriptionResult,
+    format: SubtitleFormat,
+    path: String,
+) -> Result<(), CommandError> {
+    export_transcription_internal(&result, format, std::path::PathBuf::from(path))
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Queues the audio files in a folder for batch transcription.
+///
+/// # Arguments
+/// * `app_handle` - The Tauri application handle
+/// * `path` - The folder to transcribe
+/// * `parallelism` - Files to transcribe at once (defaults to one)
+///
+/// # Returns
+/// Returns the queued job; progress follows as `batch-progress` events.
+///
+/// # Errors
+/// Returns `AppError` if the folder cannot be read or has no audio files.
+#[tauri::command]
+async fn transcribe_folder(
+    app_handle: AppHandle,
+    path: String,
+    parallelism: Option<usize>,
+) -> Result<BatchJob, CommandError> {
+    transcribe_folder_internal(
+        app_handle,
+        std::path::PathBuf::from(path),
+        parallelism.unwrap_or(DEFAULT_BATCH_PARALLELISM),
+    )
+    .await
+    .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Checks whether there is enough disk space to download a model.
+///
+/// # Arguments
+/// * `filename` - Catalogue file name of the model to download
+///
+/// # Returns
+/// Returns the space the download needs and the space available.
+///
+/// # Errors
+/// Returns `AppError` if the model is unknown.
+#[tauri::command]
+async fn check_disk_space_for_model(filename: String) -> Result<DiskSpaceCheck, CommandError> {
+    check_disk_space_for_model_internal(&filename).map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Downloads a model from the catalogue, emitting progress events.
+///
+/// # Arguments
+/// * `app_handle` - The Tauri application handle
+/// * `filename` - Catalogue file name of the model to download
+///
+/// # Returns
+/// Returns `Ok(())` once the model is downloaded and verified.
+///
+/// # Errors
+/// Returns `AppError` if the model is unknown or the download fails.
+#[tauri::command]
+async fn download_model(app_handle: AppHandle, filename: String) -> Result<(), CommandError> {
+    download_model_internal(app_handle, filename)
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Checks whether Speakr may record from the microphone.
+///
+/// # Returns
+/// Returns `Ready` if access was granted, or `Unavailable` otherwise.
+#[tauri::command]
+async fn check_microphone_permission() -> Result<ServiceStatus, CommandError> {
+    Ok(check_microphone_permission_internal().await)
+}
+
+// --------------------------------------------------------------------------
+/// Checks whether Speakr may type into other applications.
+///
+/// # Returns
+/// Returns `Ready` if accessibility access was granted, or `Unavailable`
+/// otherwise.
+#[tauri::command]
+async fn check_accessibility_permission() -> Result<ServiceStatus, CommandError> {
+    Ok(check_accessibility_permission_internal().await)
+}
+
+// --------------------------------------------------------------------------
+/// Opens the System Settings pane where a permission is granted.
+///
+/// # Arguments
+/// * `app_handle` - The Tauri application handle
+/// * `kind` - The permission whose pane to open
+///
+/// # Errors
+/// Returns `AppError` if System Settings cannot be opened.
+#[tauri::command]
+async fn open_permission_settings(
+    app_handle: AppHandle,
+    kind: PermissionKind,
+) -> Result<(), CommandError> {
+    open_permission_settings_internal(&app_handle, kind)
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Lists the installed post-processing plugins.
+///
+/// # Returns
+/// Returns each installed plugin, with an error if it cannot be loaded.
+///
+/// # Errors
+/// Returns `AppError` if the plugin directory cannot be read.
+#[tauri::command]
+async fn list_plugins() -> Result<Vec<PluginInfo>, CommandError> {
+    list_plugins_internal().await.map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Installs a WebAssembly post-processing plugin.
+///
+/// # Arguments
+/// * `path` - Path to the plugin's `.wasm` module
+///
+/// # Returns
+/// Returns the installed plugin.
+///
+/// # Errors
+/// Returns `AppError` if the module is invalid or cannot be copied.
+#[tauri::command]
+async fn install_plugin(path: String) -> Result<PluginInfo, CommandError> {
+    install_plugin_internal(path)
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Removes an installed post-processing plugin.
+///
+/// # Arguments
+/// * `name` - Name of the plugin to remove
+///
+/// # Returns
+/// Returns `Ok(())` once the plugin has been removed.
+///
+/// # Errors
+/// Returns `AppError` if the name is invalid or the module cannot be removed.
+#[tauri::command]
+async fn uninstall_plugin(name: String) -> Result<(), CommandError> {
+    uninstall_plugin_internal(name)
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Lists the transcription languages supported by Whisper.
+///
+/// # Returns
+/// Returns the supported languages as `{ code, name }` pairs (without "auto").
+///
+/// # Errors
+/// Returns `AppError` if the list cannot be produced.
+#[tauri::command]
+async fn get_supported_languages() -> Result<Vec<LanguageOption>, CommandError> {
+    get_supported_languages_internal()
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Gets the log verbosity override for each subsystem.
+///
+/// # Returns
+/// Returns one entry per subsystem; a `null` level follows `RUST_LOG`.
+#[tauri::command]
+async fn get_log_levels() -> Result<Vec<SubsystemLogLevel>, CommandError> {
+    Ok(get_log_levels_internal())
+}
+
+// --------------------------------------------------------------------------
+/// Sets the log verbosity for a single subsystem.
+///
+/// # Arguments
+/// * `subsystem` - The subsystem to configure
+/// * `level` - The new level, or `None` to follow `RUST_LOG` again
+///
+/// # Returns
+/// Returns the updated levels for all subsystems.
+///
+/// # Errors
+/// Returns `AppError` if the tracing filter cannot be reloaded.
+#[tauri::command]
+async fn set_log_level(
+    subsystem: LogSubsystem,
+    level: Option<LogVerbosity>,
+) -> Result<Vec<SubsystemLogLevel>, CommandError> {
+    set_log_level_internal(subsystem, level).map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Gets the path of the log file.
+///
+/// # Returns
+/// Returns the path of the JSON log file being written.
+///
+/// # Errors
+/// Returns `AppError` if logs are not being written to a file.
+#[tauri::command]
+async fn get_log_file_path() -> Result<String, CommandError> {
+    get_log_file_path_internal()
+        .map(|path| path.to_string_lossy().into_owned())
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Opens the folder holding the log files in the file manager.
+///
+/// # Errors
+/// Returns `AppError` if the folder cannot be found or opened.
+#[tauri::command]
+async fn open_logs_folder(app_handle: AppHandle) -> Result<(), CommandError> {
+    open_logs_folder_internal(&app_handle).map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Registers a global hot-key with the system (simple interface).
+///
+/// # Arguments
+/// * `hot_key` - The hot-key combination to register
+///
+/// # Returns
+/// Returns `Ok(())` if registration succeeds.
+///
+/// # Errors
+/// Returns `AppError::HotKey` if registration fails.
+#[tauri::command]
+async fn register_hot_key(hot_key: String) -> Result<(), CommandError> {
+    register_hot_key_internal(hot_key)
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Registers a global hotkey using the GlobalHotkeyService.
+///
+/// # Arguments
+/// * `app_handle` - The Tauri application handle
+/// * `config` - The hotkey configuration
+///
+/// # Returns
+/// Returns `Ok(())` if registration succeeds, or an `E_HOT_KEY` [`CommandError`] otherwise.
+#[tauri::command]
+async fn register_global_hotkey(
+    app_handle: AppHandle,
+    config: HotkeyConfig,
+) -> Result<(), CommandError> {
+    register_global_hotkey_internal(app_handle, config)
+        .await
+        .map_err(|e| CommandError::from(AppError::HotKey(e)))
+}
+
+// --------------------------------------------------------------------------
+/// Unregisters the current global hotkey.
+///
+/// # Arguments
+/// * `app_handle` - The Tauri application handle
+///
+/// # Returns
+/// Returns `Ok(())` if unregistration succeeds, or an `E_HOT_KEY` [`CommandError`] otherwise.
+#[tauri::command]
+async fn unregister_global_hotkey(app_handle: AppHandle) -> Result<(), CommandError> {
+    unregister_global_hotkey_internal(app_handle)
+        .await
+        .map_err(|e| CommandError::from(AppError::HotKey(e)))
+}
+
+// --------------------------------------------------------------------------
+/// Updates the global hotkey by re-registering with a new configuration.
+///
+/// # Arguments
+/// * `app_handle` - The Tauri application handle
+/// * `config` - The new hotkey configuration
+///
+/// # Returns
+/// Returns `Ok(())` if update succeeds, or an `E_HOT_KEY` [`CommandError`] otherwise.
+#[tauri::command]
+async fn update_global_hotkey(
+    app_handle: AppHandle,
+    config: HotkeyConfig,
+) -> Result<(), CommandError> {
+    update_global_hotkey_internal(app_handle, config)
+        .await
+        .map_err(|e| CommandError::from(AppError::HotKey(e)))
+}
+
+// --------------------------------------------------------------------------
+/// Reports which global hot-keys are live.
+///
+/// # Returns
+/// Returns the registered shortcuts with when they were registered and last
+/// pressed, and any fallback in effect.
+#[tauri::command]
+async fn get_hotkey_status() -> Result<HotkeyStatus, CommandError> {
+    Ok(get_hotkey_status_internal())
+}
+
+// --------------------------------------------------------------------------
+/// Suggests hot-keys that are free to use.
+///
+/// Each candidate is checked against the system shortcuts and Speakr's own
+/// hot-keys, then registered on trial and released again.
+///
+/// # Arguments
+/// * `count` - Most hot-keys to return (defaults to 3)
+///
+/// # Returns
+/// Returns the hot-keys that registered cleanly, best first.
+#[tauri::command]
+async fn suggest_available_hotkeys(
+    app_handle: AppHandle,
+    count: Option<usize>,
+) -> Result<Vec<String>, CommandError> {
+    Ok(suggest_available_hotkeys_internal(
+        &app_handle,
+        count.unwrap_or(DEFAULT_SUGGESTION_COUNT),
+    ))
+}
+
+// --------------------------------------------------------------------------
+/// Pauses or resumes dictation without quitting.
+///
+/// While paused the hot-keys are unregistered and every service reports
+/// `Unavailable`.
+///
+/// # Arguments
+/// * `enabled` - `false` to pause, `true` to resume
+///
+/// # Returns
+/// Returns `Ok(())` once the hot-keys and statuses are updated.
+///
+/// # Errors
+/// Returns `AppError` if the hot-keys cannot be released or the settings
+/// cannot be loaded to register them again.
+#[tauri::command]
+async fn set_dictation_enabled(app_handle: AppHandle, enabled: bool) -> Result<(), CommandError> {
+    set_dictation_enabled_internal(&app_handle, enabled)
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Returns whether privacy mode is on, and until when.
+///
+/// # Returns
+/// Returns the current privacy mode.
+#[tauri::command]
+async fn get_privacy_mode() -> Result<PrivacyMode, CommandError> {
+    Ok(privacy_mode())
+}
+
+// --------------------------------------------------------------------------
+/// Switches privacy mode on or off.
+///
+/// While it is on, transcripts are kept out of the history and the logs and
+/// no recordings are saved. It switches itself off after the
+/// `privacy_mode_mins` setting.
+///
+/// # Arguments
+/// * `active` - `true` to switch privacy mode on
+///
+/// # Returns
+/// Returns the new privacy mode; it is also emitted on `privacy-mode-changed`.
+///
+/// # Errors
+/// Returns `AppError` if the settings cannot be loaded when switching it on.
+#[tauri::command]
+async fn set_privacy_mode(
+    app_handle: AppHandle,
+    active: bool,
+) -> Result<PrivacyMode, CommandError> {
+    set_privacy_mode_internal(&app_handle, active)
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Lists the saved settings profiles.
+///
+/// # Returns
+/// Returns the profiles and the name of the active one.
+///
+/// # Errors
+/// Returns `AppError` if the profiles file cannot be read.
+#[tauri::command]
+async fn list_profiles() -> Result<SettingsProfiles, CommandError> {
+    load_profiles_internal().map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Saves the current hot-key, model and vocabulary as a named profile.
+///
+/// # Arguments
+/// * `name` - The profile's name; a profile with the same name is replaced
+///
+/// # Returns
+/// Returns the updated profiles, with the saved one active.
+///
+/// # Errors
+/// Returns `AppError` if the name is blank or the profiles cannot be saved.
+#[tauri::command]
+async fn save_profile(
+    app_handle: AppHandle,
+    name: String,
+) -> Result<SettingsProfiles, CommandError> {
+    let profiles = save_current_as_profile_internal(&name).await?;
+    refresh_tray_menu(&app_handle);
+    Ok(profiles)
+}
+
+// --------------------------------------------------------------------------
+/// Deletes a saved profile, leaving the active settings as they are.
+///
+/// # Arguments
+/// * `name` - The profile to delete
+///
+/// # Returns
+/// Returns the updated profiles.
+///
+/// # Errors
+/// Returns `AppError` if no profile has that name or the profiles cannot be
+/// saved.
+#[tauri::command]
+async fn delete_profile(
+    app_handle: AppHandle,
+    name: String,
+) -> Result<SettingsProfiles, CommandError> {
+    let profiles = delete_profile_internal(&name)?;
+    refresh_tray_menu(&app_handle);
+    Ok(profiles)
+}
+
+// --------------------------------------------------------------------------
+/// Switches the whole configuration to a saved profile.
+///
+/// # Arguments
+/// * `name` - The profile to switch to
+///
+/// # Returns
+/// Returns the settings now in effect.
+///
+/// # Errors
+/// Returns `AppError` if no profile has that name or the settings cannot be
+/// saved.
+#[tauri::command]
+async fn switch_profile(app_handle: AppHandle, name: String) -> Result<AppSettings, CommandError> {
+    switch_profile_and_apply(&app_handle, &name)
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Gets the notice shown when unreadable settings were reset to defaults.
+///
+/// # Returns
+/// Returns the notice, or `None` if the settings loaded normally or the
+/// notice has been dismissed.
+///
+/// # Errors
+/// Returns `AppError` if the notice cannot be read.
+#[tauri::command]
+async fn get_settings_recovery() -> Result<Option<SettingsRecovery>, CommandError> {
+    get_settings_recovery_internal().map_err(CommandError::from)
+}
 
-// ============================================================================
-// Tauri Command Definitions
-// ============================================================================
+// --------------------------------------------------------------------------
+/// Dismisses the settings recovery notice; the quarantined file is kept.
+///
+/// # Errors
+/// Returns `AppError` if the notice cannot be removed.
+#[tauri::command]
+async fn dismiss_settings_recovery() -> Result<(), CommandError> {
+    dismiss_settings_recovery_internal().map_err(CommandError::from)
+}
 
 // --------------------------------------------------------------------------
-/// Saves application settings to disk atomically.
+/// Opens the quarantined settings file in the default editor.
+///
+/// # Errors
+/// Returns `AppError` if there is no quarantined file or it cannot be opened.
+#[tauri::command]
+async fn open_quarantined_settings(app_handle: AppHandle) -> Result<(), CommandError> {
+    open_quarantined_settings_internal(&app_handle).map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Gets the notice shown when the previous run crashed.
+///
+/// # Returns
+/// Returns the notice, or `None` if the previous run exited cleanly or the
+/// notice has been dismissed.
+///
+/// # Errors
+/// Returns `AppError` if the notice cannot be read.
+#[tauri::command]
+async fn get_crash_notice() -> Result<Option<CrashNotice>, CommandError> {
+    get_crash_notice_internal().map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Dismisses the crash notice; the crash report is kept.
+///
+/// # Errors
+/// Returns `AppError` if the notice cannot be removed.
+#[tauri::command]
+async fn dismiss_crash_notice() -> Result<(), CommandError> {
+    dismiss_crash_notice_internal().map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Opens the latest crash report in the default editor.
+///
+/// # Errors
+/// Returns `AppError` if there is no crash report or it cannot be opened.
+#[tauri::command]
+async fn open_crash_report(app_handle: AppHandle) -> Result<(), CommandError> {
+    open_crash_report_internal(&app_handle).map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Sets the auto-launch preference for the application.
 ///
 /// # Arguments
-/// * `settings` - The settings to save
+/// * `enable` - Whether to enable auto-launch
 ///
 /// # Returns
 /// Returns `Ok(())` on success.
 ///
 /// # Errors
-/// Returns `AppError` if the settings cannot be saved.
+/// Returns `AppError` if the operation fails.
+#[tauri::command]
+async fn set_auto_launch(enable: bool) -> Result<(), CommandError> {
+    set_auto_launch_internal(enable)
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Reports whether Speakr is registered to start at login.
 ///
-/// # Example
-/// ```no_run
-/// // In frontend: invoke('save_settings', { settings })
-/// ```
+/// # Returns
+/// Returns `true` if the system will start this copy of Speakr at login.
+///
+/// # Errors
+/// Returns `AppError` if the login item cannot be read.
 #[tauri::command]
-async fn save_settings(settings: AppSettings) -> Result<(), AppError> {
-    save_settings_internal(settings).await
+async fn get_auto_launch_status() -> Result<bool, CommandError> {
+    get_auto_launch_status_internal()
+        .await
+        .map_err(CommandError::from)
 }
 
 // --------------------------------------------------------------------------
-/// Loads application settings from disk with corruption recovery.
+/// Lists recent dictations.
 ///
 /// # Returns
-/// Returns the loaded settings or default settings if the file doesn't exist.
-/// If the file is corrupt, attempts to recover from backup, then falls back to defaults.
+/// Returns the history, newest first.
 ///
 /// # Errors
-/// Returns `AppError` if all recovery attempts fail.
+/// Returns `AppError` if the history cannot be read.
+#[tauri::command]
+async fn list_history() -> Result<Vec<HistoryEntry>, CommandError> {
+    list_history_internal().map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Stores the user's correction of a dictation in the history.
 ///
-/// # Example
-/// ```no_run
-/// // In frontend: invoke('load_settings')
-/// ```
+/// # Arguments
+/// * `id` - The history entry
+/// * `text` - The corrected text
+///
+/// # Returns
+/// Returns the updated history.
+///
+/// # Errors
+/// Returns `AppError` if the entry does not exist or the history cannot be
+/// saved.
 #[tauri::command]
-async fn load_settings() -> Result<AppSettings, AppError> {
-    load_settings_internal().await
+async fn correct_history(
+    app_handle: AppHandle,
+    id: u64,
+    text: String,
+) -> Result<Vec<HistoryEntry>, CommandError> {
+    correct_history_internal(&app_handle, id, &text).map_err(CommandError::from)
 }
 
 // --------------------------------------------------------------------------
-/// Validates a hot-key string for correctness and conflicts.
+/// Deletes a dictation from the history.
 ///
 /// # Arguments
-/// * `hot_key` - The hot-key combination as a string
+/// * `id` - The history entry
 ///
 /// # Returns
-/// Returns `Ok(())` if the hot-key is valid and available.
+/// Returns the updated history.
 ///
 /// # Errors
-/// Returns `AppError` if the hot-key is invalid or conflicts with system/global shortcuts.
+/// Returns `AppError` if the entry does not exist or the history cannot be
+/// saved.
+#[tauri::command]
+async fn delete_history(app_handle: AppHandle, id: u64) -> Result<Vec<HistoryEntry>, CommandError> {
+    delete_history_internal(&app_handle, id).map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Suggests vocabulary terms from words the user often corrects.
+///
+/// # Returns
+/// Returns the suggestions, most often corrected first; none if suggestions
+/// are turned off.
+///
+/// # Errors
+/// Returns `AppError` if the settings or the history cannot be loaded.
 #[tauri::command]
-async fn validate_hot_key(hot_key: String) -> Result<(), AppError> {
-    validate_hot_key_internal(hot_key).await
+async fn get_vocabulary_suggestions() -> Result<Vec<VocabularySuggestion>, CommandError> {
+    get_vocabulary_suggestions_internal()
+        .await
+        .map_err(CommandError::from)
 }
 
 // --------------------------------------------------------------------------
-/// Checks if a model file of the given size is available and valid.
+/// Reads a secret from the system keychain.
 ///
 /// # Arguments
-/// * `model_size` - The model size identifier (e.g., "small", "medium")
+/// * `name` - The secret; only the names the backend allows can be read
 ///
 /// # Returns
-/// Returns `Ok(true)` if the model is available, `Ok(false)` otherwise.
+/// Returns the secret, or `None` if it has never been stored.
 ///
 /// # Errors
-/// Returns `AppError` if the check fails due to IO or validation errors.
+/// Returns `AppError` if the secret may not be read from the frontend or the
+/// keychain is not available.
 #[tauri::command]
-async fn check_model_availability(model_size: String) -> Result<bool, AppError> {
-    check_model_availability_internal(model_size).await
+async fn get_secret(name: String) -> Result<Option<String>, CommandError> {
+    get_secret_internal(&name).map_err(CommandError::from)
 }
 
 // --------------------------------------------------------------------------
-/// Registers a global hot-key with the system (simple interface).
+/// Stores a secret in the system keychain.
 ///
 /// # Arguments
-/// * `hot_key` - The hot-key combination to register
+/// * `name` - The secret; only the names the backend allows can be written
+/// * `value` - The new value; an empty value removes the secret
+///
+/// # Errors
+/// Returns `AppError` if the secret may not be written from the frontend or
+/// the keychain is not available.
+#[tauri::command]
+async fn set_secret(name: String, value: String) -> Result<(), CommandError> {
+    set_secret_internal(&name, &value).map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Reports the latency of recent dictations for the debug panel.
 ///
 /// # Returns
-/// Returns `Ok(())` if registration succeeds.
+/// Returns hot-key → recording, recording → first text and end-to-end
+/// statistics over the last dictations.
+#[tauri::command]
+async fn get_performance_metrics() -> Result<PerformanceMetrics, CommandError> {
+    Ok(get_performance_metrics_internal())
+}
+
+// --------------------------------------------------------------------------
+/// Lists the dictations waiting for review.
+///
+/// # Returns
+/// Returns the pending dictations, oldest first.
 ///
 /// # Errors
-/// Returns `AppError::HotKey` if registration fails.
+/// Returns `AppError` if the queue cannot be read.
 #[tauri::command]
-async fn register_hot_key(hot_key: String) -> Result<(), AppError> {
-    register_hot_key_internal(hot_key).await
+async fn list_pending() -> Result<Vec<PendingDictation>, CommandError> {
+    list_pending_internal().map_err(CommandError::from)
 }
 
 // --------------------------------------------------------------------------
-/// Registers a global hotkey using the GlobalHotkeyService.
+/// Saves the edited text of a pending dictation.
 ///
 /// # Arguments
-/// * `app_handle` - The Tauri application handle
-/// * `config` - The hotkey configuration
+/// * `id` - The pending dictation
+/// * `text` - Its new text
 ///
 /// # Returns
-/// Returns `Ok(())` if registration succeeds, or an error string otherwise.
+/// Returns the updated queue.
+///
+/// # Errors
+/// Returns `AppError` if the dictation does not exist or the queue cannot be
+/// saved.
 #[tauri::command]
-async fn register_global_hotkey(app_handle: AppHandle, config: HotkeyConfig) -> Result<(), String> {
-    register_global_hotkey_internal(app_handle, config).await
+async fn update_pending(
+    app_handle: AppHandle,
+    id: u64,
+    text: String,
+) -> Result<Vec<PendingDictation>, CommandError> {
+    update_pending_internal(&app_handle, id, &text).map_err(CommandError::from)
 }
 
 // --------------------------------------------------------------------------
-/// Unregisters the current global hotkey.
+/// Types a pending dictation into the app the user was working in and
+/// removes it from the queue.
 ///
 /// # Arguments
-/// * `app_handle` - The Tauri application handle
+/// * `id` - The pending dictation
 ///
 /// # Returns
-/// Returns `Ok(())` if unregistration succeeds, or an error string otherwise.
+/// Returns the updated queue.
+///
+/// # Errors
+/// Returns `AppError` if the dictation does not exist or cannot be injected.
 #[tauri::command]
-async fn unregister_global_hotkey(app_handle: AppHandle) -> Result<(), String> {
-    unregister_global_hotkey_internal(app_handle).await
+async fn inject_pending(
+    app_handle: AppHandle,
+    id: u64,
+) -> Result<Vec<PendingDictation>, CommandError> {
+    inject_pending_internal(&app_handle, id)
+        .await
+        .map_err(CommandError::from)
 }
 
 // --------------------------------------------------------------------------
-/// Updates the global hotkey by re-registering with a new configuration.
+/// Types the most recent dictation again into the app the user is working
+/// in, e.g. after it went to the wrong window.
+///
+/// # Returns
+/// Returns `Ok(())` once the text has been typed.
+///
+/// # Errors
+/// Returns `AppError` if there is no dictation to type or it cannot be injected.
+#[tauri::command]
+async fn reinject_last_transcription(app_handle: AppHandle) -> Result<(), CommandError> {
+    reinject_last_transcription_internal(&app_handle)
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Discards a pending dictation.
 ///
 /// # Arguments
-/// * `app_handle` - The Tauri application handle
-/// * `config` - The new hotkey configuration
+/// * `id` - The pending dictation
 ///
 /// # Returns
-/// Returns `Ok(())` if update succeeds, or an error string otherwise.
+/// Returns the updated queue.
+///
+/// # Errors
+/// Returns `AppError` if the dictation does not exist or the queue cannot be
+/// saved.
 #[tauri::command]
-async fn update_global_hotkey(app_handle: AppHandle, config: HotkeyConfig) -> Result<(), String> {
-    update_global_hotkey_internal(app_handle, config).await
+async fn discard_pending(
+    app_handle: AppHandle,
+    id: u64,
+) -> Result<Vec<PendingDictation>, CommandError> {
+    discard_pending_internal(&app_handle, id).map_err(CommandError::from)
 }
 
 // --------------------------------------------------------------------------
-/// Sets the auto-launch preference for the application.
+/// Reports the disk space used by Speakr's models, recordings, logs and
+/// settings.
+///
+/// # Returns
+/// Returns the usage of each category.
+///
+/// # Errors
+/// Returns `AppError` if a data directory cannot be found or read.
+#[tauri::command]
+async fn get_storage_usage(app_handle: AppHandle) -> Result<StorageUsage, CommandError> {
+    get_storage_usage_internal(&app_handle)
+        .await
+        .map_err(CommandError::from)
+}
+
+// --------------------------------------------------------------------------
+/// Frees the disk space used by one category of data.
 ///
 /// # Arguments
-/// * `enable` - Whether to enable auto-launch
+/// * `category` - The kind of data to clean up
 ///
 /// # Returns
-/// Returns `Ok(())` on success.
+/// Returns the storage usage afterwards.
 ///
 /// # Errors
-/// Returns `AppError` if the operation fails.
+/// Returns `AppError` if the category cannot be cleaned up or files cannot
+/// be removed.
 #[tauri::command]
-async fn set_auto_launch(enable: bool) -> Result<(), AppError> {
-    set_auto_launch_internal(enable).await
+async fn clean_up_storage(
+    app_handle: AppHandle,
+    category: StorageCategory,
+) -> Result<StorageUsage, CommandError> {
+    clean_up_storage_internal(&app_handle, category)
+        .await
+        .map_err(CommandError::from)
 }
 
 // =========================
@@ -206,36 +1292,179 @@ async fn set_auto_launch(enable: bool) -> Result<(), AppError> {
 #[cfg(debug_assertions)]
 /// Debug: Test audio recording functionality (stub for debug builds).
 #[tauri::command]
-async fn debug_test_audio_recording() -> Result<String, AppError> {
-    debug_test_audio_recording_internal().await
+async fn debug_test_audio_recording() -> Result<String, CommandError> {
+    debug_test_audio_recording_internal()
+        .await
+        .map_err(CommandError::from)
 }
 
 #[cfg(debug_assertions)]
 /// Debug: Start push-to-talk recording with real audio backend.
 #[tauri::command]
-async fn debug_start_recording() -> Result<String, AppError> {
-    debug_start_recording_internal().await
+async fn debug_start_recording() -> Result<String, CommandError> {
+    debug_start_recording_internal()
+        .await
+        .map_err(CommandError::from)
 }
 
 #[cfg(debug_assertions)]
 /// Debug: Stop push-to-talk recording.
 #[tauri::command]
-async fn debug_stop_recording() -> Result<String, AppError> {
-    debug_stop_recording_internal().await
+async fn debug_stop_recording() -> Result<String, CommandError> {
+    debug_stop_recording_internal()
+        .await
+        .map_err(CommandError::from)
+}
+
+#[cfg(debug_assertions)]
+/// Debug: Read the input level of the push-to-talk recording.
+///
+/// # Returns
+/// Returns the level in dBFS, or `None` if no debug recording is active.
+///
+/// # Errors
+/// This function currently never returns an error.
+#[tauri::command]
+async fn debug_input_level() -> Result<Option<f32>, CommandError> {
+    debug_input_level_internal()
+        .await
+        .map_err(CommandError::from)
+}
+
+#[cfg(debug_assertions)]
+/// Debug: Play back the last captured audio.
+///
+/// # Returns
+/// Returns a WAV data URL for the frontend's audio player.
+///
+/// # Errors
+/// Returns `AppError` if nothing has been recorded yet or encoding fails.
+#[tauri::command]
+async fn debug_play_last_recording() -> Result<String, CommandError> {
+    debug_play_last_recording_internal()
+        .await
+        .map_err(CommandError::from)
+}
+
+#[cfg(debug_assertions)]
+/// Debug: Replay a saved WAV file through the full dictation workflow.
+///
+/// # Arguments
+/// * `path` - Path to a 16 kHz mono 16-bit WAV file
+/// * `sandboxed` - Skip the text injection step (defaults to `true`)
+///
+/// # Returns
+/// Returns the transcribed text.
+///
+/// # Errors
+/// Returns `AppError` if the file cannot be loaded or any workflow step fails.
+#[tauri::command]
+async fn debug_replay_wav(
+    app_handle: AppHandle,
+    path: String,
+    sandboxed: Option<bool>,
+) -> Result<String, CommandError> {
+    debug_replay_wav_internal(
+        app_handle,
+        std::path::PathBuf::from(path),
+        sandboxed.unwrap_or(true),
+    )
+    .await
+    .map_err(CommandError::from)
+}
+
+#[cfg(debug_assertions)]
+/// Debug: Transcribe a saved WAV file with and without noise suppression.
+///
+/// # Arguments
+/// * `path` - Path to a 16 kHz mono 16-bit WAV file
+///
+/// # Returns
+/// Returns the original and denoised transcripts.
+///
+/// # Errors
+/// Returns `AppError` if the file cannot be loaded, the RNNoise weights are
+/// missing, or transcription fails.
+#[tauri::command]
+async fn debug_compare_denoise(path: String) -> Result<DenoiseComparison, CommandError> {
+    debug_compare_denoise_internal(std::path::PathBuf::from(path))
+        .await
+        .map_err(CommandError::from)
+}
+
+#[cfg(debug_assertions)]
+/// Debug: Record a real dictation and save it as an audio/transcript fixture pair.
+///
+/// # Arguments
+/// * `output_dir` - Folder to write the fixture into
+/// * `duration` - Recording length (defaults to the settings duration)
+///
+/// # Returns
+/// Returns the fixture metadata that was written.
+///
+/// # Errors
+/// Returns `AppError` if recording, transcription, or saving fails.
+#[tauri::command]
+async fn debug_record_fixture(
+    output_dir: String,
+    duration: Option<speakr_types::AudioDuration>,
+) -> Result<AudioFixture, CommandError> {
+    debug_record_fixture_internal(std::path::PathBuf::from(output_dir), duration)
+        .await
+        .map_err(CommandError::from)
+}
+
+#[cfg(debug_assertions)]
+/// Debug: Get a page of log messages for display in the frontend.
+///
+/// Without a query every buffered message is returned, newest first.
+#[tauri::command]
+async fn debug_get_log_messages(
+    query: Option<DebugLogQuery>,
+) -> Result<DebugLogPage, CommandError> {
+    debug_get_log_messages_internal(query.unwrap_or_default())
+        .await
+        .map_err(CommandError::from)
+}
+
+#[cfg(debug_assertions)]
+/// Debug: Start recording pipeline events for bug reproduction.
+#[tauri::command]
+async fn debug_start_session_recording() -> Result<(), CommandError> {
+    debug_start_session_recording_internal()
+        .await
+        .map_err(CommandError::from)
+}
+
+#[cfg(debug_assertions)]
+/// Debug: Stop the session recording and save it as a JSON file.
+///
+/// # Returns
+/// Returns the path of the saved session file.
+#[tauri::command]
+async fn debug_stop_session_recording(output_dir: String) -> Result<String, CommandError> {
+    debug_stop_session_recording_internal(std::path::PathBuf::from(output_dir))
+        .await
+        .map(|path| path.display().to_string())
+        .map_err(CommandError::from)
 }
 
 #[cfg(debug_assertions)]
-/// Debug: Get all log messages for display in the frontend.
+/// Debug: Change how many log messages are kept in memory.
 #[tauri::command]
-async fn debug_get_log_messages() -> Result<Vec<DebugLogMessage>, AppError> {
-    debug_get_log_messages_internal().await
+async fn debug_set_log_capacity(capacity: usize) -> Result<(), CommandError> {
+    debug_set_log_capacity_internal(capacity)
+        .await
+        .map_err(CommandError::from)
 }
 
 #[cfg(debug_assertions)]
 /// Debug: Clear all accumulated debug log messages.
 #[tauri::command]
-async fn debug_clear_log_messages() -> Result<(), AppError> {
-    debug_clear_log_messages_internal().await
+async fn debug_clear_log_messages() -> Result<(), CommandError> {
+    debug_clear_log_messages_internal()
+        .await
+        .map_err(CommandError::from)
 }
 
 // --------------------------------------------------------------------------
@@ -247,8 +1476,10 @@ async fn debug_clear_log_messages() -> Result<(), AppError> {
 /// # Errors
 /// Returns `AppError` if the status cannot be retrieved.
 #[tauri::command]
-async fn get_backend_status() -> Result<StatusUpdate, AppError> {
-    get_backend_status_internal().await
+async fn get_backend_status() -> Result<StatusUpdate, CommandError> {
+    get_backend_status_internal()
+        .await
+        .map_err(CommandError::from)
 }
 
 // --------------------------------------------------------------------------
@@ -267,8 +1498,10 @@ async fn get_backend_status() -> Result<StatusUpdate, AppError> {
 async fn update_service_status(
     component: ServiceComponent,
     status: ServiceStatus,
-) -> Result<(), AppError> {
-    update_service_status_internal(component, status).await
+) -> Result<(), CommandError> {
+    update_service_status_internal(component, status)
+        .await
+        .map_err(CommandError::from)
 }
 
 // ============================================================================
@@ -279,136 +1512,187 @@ async fn update_service_status(
 fn setup_app(app: &mut App) -> Result<(), Box<dyn std::error::Error>> {
     info!("Speakr backend starting up...");
 
+    // Restore the original settings if the last run crashed after a migration
+    if let Err(e) = rollback_interrupted_migration_internal() {
+        warn!(
+            "Failed to check for an interrupted settings migration: {}",
+            e
+        );
+    }
+
     #[cfg(desktop)]
     {
-        let ctrl_n_shortcut = Shortcut::new(Some(Modifiers::CONTROL), Code::KeyN);
-        app.handle().plugin(
-            tauri_plugin_global_shortcut::Builder::new()
-                .with_handler(move |_app, shortcut, event| {
-                    println!("{shortcut:?}");
-                    if shortcut == &ctrl_n_shortcut {
-                        match event.state() {
-                            ShortcutState::Pressed => {
-                                println!("Ctrl-N Pressed!");
-                            }
-                            ShortcutState::Released => {
-                                println!("Ctrl-N Released!");
-                            }
-                        }
-                    }
-                })
-                .build(),
-        )?;
-
-        app.global_shortcut().register(ctrl_n_shortcut)?;
+        // Shortcuts are registered from settings by the hotkey service
+        app.handle()
+            .plugin(tauri_plugin_global_shortcut::Builder::new().build())?;
+
+        setup_tray(app)?;
+        restore_pending_badge(app.handle());
+        fit_windows_to_monitors(app.handle());
     }
 
+    // Push service status changes to the frontend
+    attach_status_events(app.app_handle().clone());
+
+    // Tell the user if unreadable settings were reset to defaults
+    spawn_settings_recovery_notice(app.app_handle().clone());
+
+    // Offer to open the crash report if the last run crashed
+    spawn_crash_notice(app.app_handle().clone());
+
     // Set up the hotkey-triggered listener
     setup_hotkey_trigger_listener(app);
 
-    // Spawn task to register the default global hotkey
-    spawn_register_default_hotkey(app.app_handle().clone());
+    // Tell the UI what text injection cannot do on this desktop
+    spawn_report_injection_limitations();
+
+    // Register the dictation and language hot-keys from settings
+    spawn_register_hotkeys(app.app_handle().clone());
+
+    // Start pre-recording if the user has enabled it
+    spawn_apply_pre_buffer_setting();
+
+    // Encrypt or decrypt the history to match the settings
+    spawn_apply_history_encryption();
+
+    // Keep migrated settings once the app has run with them for a while
+    spawn_commit_settings_migration();
+
+    // Serve the local API if the user has enabled it
+    spawn_local_api(app.app_handle().clone());
+
+    // Tell the UI when downloads go offline or come back
+    spawn_connectivity_events(app.app_handle().clone());
+
+    // Load the selected model now so the first dictation is fast, and tell
+    // the UI if its file turns out to be corrupt
+    attach_model_events(app.app_handle().clone());
+    spawn_warm_load_model();
+    spawn_idle_unloader();
+
+    // Restart services that fail, and tell the UI if they cannot recover
+    spawn_service_watchdog(app.app_handle().clone());
+
+    // Pick up permissions granted in System Settings without a restart
+    spawn_permission_watcher(app.app_handle().clone());
+
+    // Shut down cleanly when the system asks Speakr to terminate
+    spawn_signal_handler(app.app_handle().clone());
 
     Ok(())
 }
 
-// Sets up the event listener for the "hotkey-triggered" event
+// Sets up the event listener for the hot-key event
 fn setup_hotkey_trigger_listener(app: &App) {
     let app_handle_for_listener = app.app_handle().clone();
-    app.listen("hotkey-triggered", move |_event| {
+    app.listen(HOTKEY_TRIGGERED_EVENT, move |event| {
         let app_handle = app_handle_for_listener.clone();
-        tauri::async_runtime::spawn(async move {
-            info!("🔥 Hotkey triggered, starting dictation workflow");
 
-            #[cfg(debug_assertions)]
-            add_debug_log(
-                DebugLogLevel::Info,
-                "workflow",
-                "Hotkey triggered, starting dictation workflow",
-            );
-
-            if let Err(e) = execute_dictation_workflow(app_handle.clone()).await {
-                error!("Dictation workflow failed: {}", e);
-
-                #[cfg(debug_assertions)]
-                add_debug_log(
-                    DebugLogLevel::Error,
-                    "workflow",
-                    &format!("Dictation workflow failed: {e}"),
-                );
-            }
-        });
+        // Pressing the hotkey again cancels the dictation in flight
+        if cancel_active_dictation(&app_handle) {
+            return;
+        }
+
+        // Language hot-keys send the settings to use; the main hot-key sends none
+        let overrides = serde_json::from_str::<Option<DictationOverrides>>(event.payload())
+            .unwrap_or_else(|e| {
+                warn!("Ignoring invalid hotkey payload: {}", e);
+                None
+            })
+            .unwrap_or_default();
+        start_dictation_with_overrides(&app_handle, overrides);
     });
 }
 
-// Spawns the async task to register the default global hotkey
-fn spawn_register_default_hotkey(app_handle: AppHandle) {
+// Spawns the async task that records the injection backend's limitations
+fn spawn_report_injection_limitations() {
+    tauri::async_runtime::spawn(report_injection_limitations());
+}
+
+// Commits a pending settings migration after the trial period
+fn spawn_commit_settings_migration() {
     tauri::async_runtime::spawn(async move {
-        register_default_hotkey(app_handle).await;
+        tokio::time::sleep(MIGRATION_TRIAL_PERIOD).await;
+        if let Err(e) = commit_migration_preview_internal().await {
+            warn!("Failed to commit settings migration: {}", e);
+        }
     });
 }
 
-// Performs default and fallback hotkey registration
-async fn register_default_hotkey(app_handle: AppHandle) {
-    // Load hotkey from persisted settings, falling back to default if loading fails
-    let hotkey_config = match load_settings_internal().await {
-        Ok(settings) => {
-            info!("Loaded hotkey from settings: {}", settings.hot_key);
-            HotkeyConfig {
-                shortcut: settings.hot_key,
-                enabled: true,
+// Spawns the async task that starts pre-recording if the user enabled it
+fn spawn_apply_pre_buffer_setting() {
+    tauri::async_runtime::spawn(async move {
+        let settings = match load_settings_internal().await {
+            Ok(settings) => settings,
+            Err(e) => {
+                warn!("Failed to load settings for pre-recording: {}", e);
+                return;
             }
+        };
+        if let Err(e) = apply_pre_buffer_setting(&settings).await {
+            warn!("Failed to start pre-recording: {}", e);
         }
-        Err(e) => {
-            warn!("Failed to load settings, using default hotkey: {}", e);
-            HotkeyConfig {
-                shortcut: "CmdOrCtrl+Alt+Space".to_string(),
-                enabled: true,
+    });
+}
+
+// Spawns the async task that moves the history into the form the settings ask for
+fn spawn_apply_history_encryption() {
+    tauri::async_runtime::spawn(async move {
+        let enabled = match load_settings_internal().await {
+            Ok(settings) => settings.encrypt_history,
+            Err(e) => {
+                warn!("Failed to load settings for history encryption: {}", e);
+                return;
             }
+        };
+        if let Err(e) = apply_history_encryption(enabled) {
+            warn!("Failed to apply history encryption: {}", e);
         }
-    };
-
-    info!("Registering hotkey: {}", hotkey_config.shortcut);
-    // #[cfg(debug_assertions)]
-    // add_debug_log(
-    //     DebugLogLevel::Info,
-    //     "speakr-tauri",
-    //     &format!("Registering default hotkey: {}", default_config.shortcut)
-    // );
-
-    if let Err(e) = register_global_hotkey_internal(app_handle.clone(), hotkey_config.clone()).await
-    {
-        error!(
-            "⚠️  Failed to register hotkey '{}': {}",
-            hotkey_config.shortcut, e
-        );
-        warn!("💡 You can change the hotkey in Settings to avoid conflicts");
+    });
+}
 
-        // Fallback hotkey
-        let fallback_config = HotkeyConfig {
-            shortcut: "CmdOrCtrl+Alt+F2".to_string(),
-            enabled: true,
+// Loads the selected Whisper model in the background
+fn spawn_warm_load_model() {
+    tauri::async_runtime::spawn(async move {
+        let settings = match load_settings_internal().await {
+            Ok(settings) => settings,
+            Err(e) => {
+                warn!("Failed to load settings for model warm-up: {}", e);
+                return;
+            }
         };
+        if let Err(e) = warm_load_model(&settings).await {
+            warn!("Failed to warm-load model: {}", e);
+        }
+    });
+}
 
-        if let Err(e2) = register_global_hotkey_internal(app_handle, fallback_config.clone()).await
-        {
-            error!(
-                "⚠️  Fallback hotkey '{}' also failed: {}",
-                fallback_config.shortcut, e2
-            );
-            warn!("App will start without global hotkey - configure one in Settings");
-        } else {
-            info!("Using fallback hotkey: {}", fallback_config.shortcut);
+// Spawns the local API server if the user enabled it
+fn spawn_local_api(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        match load_settings_internal().await {
+            Ok(settings) if settings.local_api => {}
+            Ok(_) => return,
+            Err(e) => {
+                warn!("Failed to load settings for the local API: {}", e);
+                return;
+            }
         }
-    } else {
-        info!("Hotkey registered: {}", hotkey_config.shortcut);
-        #[cfg(debug_assertions)]
-        add_debug_log(
-            DebugLogLevel::Info,
-            "speakr-tauri",
-            &format!("Hotkey registered: {}", hotkey_config.shortcut),
-        );
-    }
+        if let Err(e) = serve_local_api(app_handle).await {
+            warn!("Local API stopped: {}", e);
+        }
+    });
+}
+
+// Spawns the async task that registers the hot-keys from settings
+fn spawn_register_hotkeys(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let settings = load_settings_internal().await.unwrap_or_else(|e| {
+            warn!("Failed to load settings, using default hotkeys: {}", e);
+            AppSettings::default()
+        });
+        register_hotkeys_from_settings(&app_handle, &settings).await;
+    });
 }
 
 /// Runs the Tauri application, registering all plugins and commands.
@@ -425,30 +1709,107 @@ pub fn run() {
     //     builder = builder.plugin(tauri_plugin_devtools::init());
     // }
 
-    // Initialise a logging subscriber that respects RUST_LOG
-    fmt().with_env_filter(EnvFilter::from_default_env()).init();
+    // Initialise a logging subscriber that respects RUST_LOG and supports
+    // per-subsystem level overrides at runtime
+    init_tracing();
+
+    // Write local crash reports for panics and unexpected exits
+    install_crash_handler();
+
+    // Restore window size and position before the windows are created
+    #[cfg(desktop)]
+    let builder = builder.plugin(window_state_plugin());
 
     builder
         .plugin(tauri_plugin_store::Builder::new().build())
         .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_clipboard_manager::init())
+        .plugin(tauri_plugin_notification::init())
         .invoke_handler({
             #[cfg(debug_assertions)]
             {
                 tauri::generate_handler![
                     save_settings,
                     load_settings,
+                    simulate_dictation,
+                    cancel_dictation,
+                    ack_event,
                     validate_hot_key,
                     check_model_availability,
+                    list_model_catalogue,
+                    check_denoise_model,
+                    list_input_devices,
+                    test_microphone,
+                    start_input_preview,
+                    input_preview_level,
+                    stop_input_preview,
+                    list_installed_models,
+                    delete_model,
+                    get_models_disk_usage,
+                    check_disk_space_for_model,
+                    download_model,
+                    check_microphone_permission,
+                    check_accessibility_permission,
+                    open_permission_settings,
+                    transcribe_file,
+                    transcribe_folder,
+                    export_transcription,
+                    list_plugins,
+                    install_plugin,
+                    uninstall_plugin,
+                    get_supported_languages,
+                    get_log_levels,
+                    set_log_level,
+                    get_log_file_path,
+                    open_logs_folder,
                     register_hot_key,
                     set_auto_launch,
+                    get_auto_launch_status,
+                    get_storage_usage,
+                    clean_up_storage,
+                    list_history,
+                    correct_history,
+                    delete_history,
+                    get_vocabulary_suggestions,
+                    get_secret,
+                    set_secret,
+                    get_performance_metrics,
+                    list_pending,
+                    update_pending,
+                    inject_pending,
+                    discard_pending,
+                    reinject_last_transcription,
                     register_global_hotkey,
                     unregister_global_hotkey,
                     update_global_hotkey,
+                    set_dictation_enabled,
+                    get_privacy_mode,
+                    set_privacy_mode,
+                    get_hotkey_status,
+                    suggest_available_hotkeys,
+                    list_profiles,
+                    save_profile,
+                    delete_profile,
+                    switch_profile,
+                    get_settings_recovery,
+                    dismiss_settings_recovery,
+                    open_quarantined_settings,
+                    get_crash_notice,
+                    dismiss_crash_notice,
+                    open_crash_report,
                     debug_test_audio_recording,
                     debug_start_recording,
                     debug_stop_recording,
+                    debug_input_level,
+                    debug_play_last_recording,
+                    debug_replay_wav,
+                    debug_compare_denoise,
+                    debug_record_fixture,
+                    debug_start_session_recording,
+                    debug_stop_session_recording,
                     debug_get_log_messages,
                     debug_clear_log_messages,
+                    debug_set_log_capacity,
                     get_backend_status,
                     update_service_status
                 ]
@@ -458,20 +1819,85 @@ pub fn run() {
                 tauri::generate_handler![
                     save_settings,
                     load_settings,
+                    simulate_dictation,
+                    cancel_dictation,
+                    ack_event,
                     validate_hot_key,
                     check_model_availability,
+                    list_model_catalogue,
+                    check_denoise_model,
+                    list_input_devices,
+                    test_microphone,
+                    start_input_preview,
+                    input_preview_level,
+                    stop_input_preview,
+                    list_installed_models,
+                    delete_model,
+                    get_models_disk_usage,
+                    check_disk_space_for_model,
+                    download_model,
+                    check_microphone_permission,
+                    check_accessibility_permission,
+                    open_permission_settings,
+                    transcribe_file,
+                    transcribe_folder,
+                    export_transcription,
+                    list_plugins,
+                    install_plugin,
+                    uninstall_plugin,
+                    get_supported_languages,
+                    get_log_levels,
+                    set_log_level,
+                    get_log_file_path,
+                    open_logs_folder,
                     register_hot_key,
                     set_auto_launch,
+                    get_auto_launch_status,
+                    get_storage_usage,
+                    clean_up_storage,
+                    list_history,
+                    correct_history,
+                    delete_history,
+                    get_vocabulary_suggestions,
+                    get_secret,
+                    set_secret,
+                    get_performance_metrics,
+                    list_pending,
+                    update_pending,
+                    inject_pending,
+                    discard_pending,
+                    reinject_last_transcription,
                     register_global_hotkey,
                     unregister_global_hotkey,
                     update_global_hotkey,
+                    set_dictation_enabled,
+                    get_privacy_mode,
+                    set_privacy_mode,
+                    get_hotkey_status,
+                    suggest_available_hotkeys,
+                    list_profiles,
+                    save_profile,
+                    delete_profile,
+                    switch_profile,
+                    get_settings_recovery,
+                    dismiss_settings_recovery,
+                    open_quarantined_settings,
+                    get_crash_notice,
+                    dismiss_crash_notice,
+                    open_crash_report,
                     get_backend_status,
                     update_service_status
                 ]
             }
         })
         .setup(move |app| setup_app(app))
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app_handle, event| {
+            // Window close, tray quit and SIGTERM all end here
+            if let RunEvent::ExitRequested { .. } | RunEvent::Exit = event {
+                shutdown(app_handle);
+            }
+        });
 }
 // ===========================================================================
@@ -0,0 +1,125 @@
+// ============================================================================
+//! Application Lifecycle
+// ============================================================================
+//!
+//! Coordinates shutdown, so Speakr leaves nothing behind however it is
+//! closed: closing the last window, choosing Quit from the tray menu, or a
+//! `SIGTERM` from the system. All three end in [`shutdown`], which
+//!
+//! 1. unregisters every global shortcut, so the key combinations are handed
+//!    back to other applications straight away;
+//! 2. stops the recording in progress, discarding its audio;
+//! 3. zeroes the audio buffers kept in memory (the pre-recording buffer and,
+//!    in debug builds, the copy of the last recording);
+//! 4. waits for settings, history and pending dictations being written to
+//!    reach the disk;
+//! 5. marks the session as ended cleanly and commits a settings migration on
+//!    trial.
+//!
+//! Shutdown runs once; a second exit request finds it done.
+
+use crate::audio::pre_buffer::stop_pre_buffer;
+use crate::crash::end_session;
+use crate::services::history::flush_history;
+use crate::services::hotkey::release_all_hotkeys;
+use crate::services::pending::flush_pending;
+use crate::settings::{commit_migration_preview_internal, flush_settings};
+use crate::workflow::cancel_active_dictation;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::AppHandle;
+use tracing::{info, warn};
+
+/// Whether shutdown has started.
+static SHUTTING_DOWN: AtomicBool = AtomicBool::new(false);
+
+/// Marks shutdown as started
+///
+/// # Returns
+///
+/// Returns `true` the first time, and `false` once shutdown has started.
+fn begin_shutdown() -> bool {
+    !SHUTTING_DOWN.swap(true, Ordering::SeqCst)
+}
+
+/// Whether Speakr is shutting down
+pub fn is_shutting_down() -> bool {
+    SHUTTING_DOWN.load(Ordering::SeqCst)
+}
+
+/// Releases everything Speakr holds before the process exits
+///
+/// Called from the run loop when an exit is requested; does nothing if
+/// shutdown has already run. Failures are logged rather than returned, so
+/// one step failing does not skip the others.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+pub fn shutdown(app_handle: &AppHandle) {
+    if !begin_shutdown() {
+        return;
+    }
+    info!("Speakr shutting down...");
+
+    if let Err(e) = release_all_hotkeys(app_handle) {
+        warn!("Failed to unregister hotkeys: {}", e);
+    }
+
+    cancel_active_dictation(app_handle);
+    stop_pre_buffer();
+    #[cfg(debug_assertions)]
+    crate::debug::discard_debug_audio();
+
+    flush_settings();
+    flush_history();
+    flush_pending();
+
+    // Quitting normally also counts as a successful migration trial
+    end_session();
+    if let Err(e) = tauri::async_runtime::block_on(commit_migration_preview_internal()) {
+        warn!("Failed to commit settings migration: {}", e);
+    }
+    info!("Speakr shut down cleanly");
+}
+
+/// Quits Speakr when the system asks it to terminate
+///
+/// `SIGTERM` exits through the run loop like any other quit, so
+/// [`shutdown`] runs before the process ends. Other platforms have no
+/// equivalent signal and rely on the run loop alone.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+pub fn spawn_signal_handler(app_handle: AppHandle) {
+    #[cfg(unix)]
+    tauri::async_runtime::spawn(async move {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let mut terminate = match signal(SignalKind::terminate()) {
+            Ok(terminate) => terminate,
+            Err(e) => {
+                warn!("Failed to listen for SIGTERM: {}", e);
+                return;
+            }
+        };
+        if terminate.recv().await.is_some() {
+            info!("Received SIGTERM");
+            app_handle.exit(0);
+        }
+    });
+    #[cfg(not(unix))]
+    let _ = app_handle;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shutdown_only_begins_once() {
+        assert!(begin_shutdown());
+        assert!(is_shutting_down());
+        assert!(!begin_shutdown());
+    }
+}
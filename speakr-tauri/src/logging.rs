@@ -0,0 +1,439 @@
+// ============================================================================
+//! Tracing Setup & Per-Subsystem Log Verbosity
+// ============================================================================
+//!
+//! Installs the global `tracing` subscriber with a reloadable `EnvFilter`.
+//! The base filter comes from `RUST_LOG`; on top of that, each
+//! [`LogSubsystem`] can be given its own verbosity at runtime. Changing a
+//! level rebuilds the filter directives and swaps them in without
+//! restarting the app, so detailed logs can be captured for just the
+//! misbehaving subsystem.
+//!
+//! Besides the console, events are written as JSON lines to [`LOG_FILE_NAME`]
+//! in the log directory (see [`log_dir`]). Once the file reaches
+//! [`MAX_LOG_FILE_BYTES`] it is rotated to `speakr.log.1`, `speakr.log.2`
+//! and so on, keeping at most [`ROTATED_LOG_FILES`] old files. Debug builds
+//! also forward events to the debug panel's log console.
+
+use speakr_types::{AppError, LogSubsystem, LogVerbosity, SubsystemLogLevel};
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex, OnceLock};
+use tauri::AppHandle;
+use tauri_plugin_opener::OpenerExt;
+use tracing::{info, warn};
+use tracing_subscriber::fmt;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::reload::{self, Handle};
+use tracing_subscriber::{EnvFilter, Registry};
+
+/// Target used for text injection events.
+///
+/// Injection has no module of its own yet, so its log lines opt in to this
+/// target explicitly.
+pub const INJECTION_TARGET: &str = "speakr_lib::injection";
+
+/// Bundle identifier from `tauri.conf.json`, which names the log directory.
+const APP_IDENTIFIER: &str = "com.jessup.speakr";
+
+/// Name of the current log file.
+pub const LOG_FILE_NAME: &str = "speakr.log";
+
+/// Size at which the log file is rotated.
+pub const MAX_LOG_FILE_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Number of rotated log files kept next to the current one.
+pub const ROTATED_LOG_FILES: usize = 3;
+
+/// Path of the log file being written, once logging has started.
+static LOG_FILE_PATH: OnceLock<PathBuf> = OnceLock::new();
+
+/// Handle used to swap the active filter at runtime.
+static FILTER_HANDLE: OnceLock<Handle<EnvFilter, Registry>> = OnceLock::new();
+
+/// Per-subsystem overrides applied on top of the `RUST_LOG` filter.
+static SUBSYSTEM_LEVELS: LazyLock<Mutex<BTreeMap<LogSubsystem, LogVerbosity>>> =
+    LazyLock::new(|| Mutex::new(BTreeMap::new()));
+
+/// Returns the `tracing` targets that make up a subsystem.
+pub fn subsystem_targets(subsystem: LogSubsystem) -> &'static [&'static str] {
+    match subsystem {
+        LogSubsystem::Audio => &["speakr_core::audio", "speakr_lib::audio"],
+        LogSubsystem::Transcription => &[
+            "speakr_core::transcription",
+            "speakr_core::pipeline",
+            "speakr_core::post_processing",
+        ],
+        LogSubsystem::Hotkey => &["speakr_lib::services::hotkey"],
+        LogSubsystem::Injection => &[INJECTION_TARGET],
+    }
+}
+
+/// Builds the filter directive string for a base filter plus overrides.
+///
+/// # Arguments
+///
+/// * `base` - The global directives (usually the value of `RUST_LOG`)
+/// * `overrides` - Per-subsystem levels to append
+///
+/// # Returns
+///
+/// A comma-separated directive string. Target directives are more specific
+/// than a bare level, so overrides win over the base filter.
+pub fn build_filter_directives(
+    base: &str,
+    overrides: &BTreeMap<LogSubsystem, LogVerbosity>,
+) -> String {
+    let mut directives: Vec<String> = base
+        .split(',')
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    for (subsystem, level) in overrides {
+        for target in subsystem_targets(*subsystem) {
+            directives.push(format!("{target}={}", level.as_directive()));
+        }
+    }
+
+    directives.join(",")
+}
+
+/// Returns the directory log files are written to.
+///
+/// This is the directory Tauri reports as the app log directory, so the
+/// storage usage panel counts and cleans up the log files:
+/// `~/Library/Logs/com.jessup.speakr` on macOS and
+/// `<local data dir>/com.jessup.speakr/logs` elsewhere.
+///
+/// # Errors
+///
+/// Returns `AppError::FileSystem` if the home or data directory cannot be
+/// found.
+pub fn log_dir() -> Result<PathBuf, AppError> {
+    #[cfg(target_os = "macos")]
+    let dir = dirs::home_dir().map(|home| home.join("Library/Logs").join(APP_IDENTIFIER));
+    #[cfg(not(target_os = "macos"))]
+    let dir = dirs::data_local_dir().map(|data| data.join(APP_IDENTIFIER).join("logs"));
+
+    dir.ok_or_else(|| AppError::FileSystem("Could not find log directory".to_string()))
+}
+
+/// Returns the path of a rotated log file, e.g. `speakr.log.2` for index 2.
+pub fn rotated_log_path(path: &Path, index: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{index}"));
+    PathBuf::from(name)
+}
+
+/// Log file that rotates itself once it grows past a size limit.
+pub struct RotatingFile {
+    path: PathBuf,
+    max_bytes: u64,
+    keep: usize,
+    file: File,
+    written: u64,
+}
+
+impl RotatingFile {
+    /// Opens a log file for appending, creating it and its directory if
+    /// needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The current log file
+    /// * `max_bytes` - Size at which the file is rotated
+    /// * `keep` - Number of rotated files to keep
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the directory or file cannot be created.
+    pub fn open(path: &Path, max_bytes: u64, keep: usize) -> io::Result<Self> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let written = file.metadata()?.len();
+        Ok(Self {
+            path: path.to_path_buf(),
+            max_bytes,
+            keep,
+            file,
+            written,
+        })
+    }
+
+    /// Shifts the rotated files up by one, dropping the oldest, and starts
+    /// a new, empty current file.
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        if self.keep == 0 {
+            self.file = File::create(&self.path)?;
+        } else {
+            for index in (1..self.keep).rev() {
+                let from = rotated_log_path(&self.path, index);
+                if from.exists() {
+                    fs::rename(&from, rotated_log_path(&self.path, index + 1))?;
+                }
+            }
+            fs::rename(&self.path, rotated_log_path(&self.path, 1))?;
+            self.file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)?;
+        }
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // A line longer than the limit still goes into a file of its own
+        if self.written > 0 && self.written + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        let written = self.file.write(buf)?;
+        self.written += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// Installs the global tracing subscriber.
+///
+/// Respects `RUST_LOG` exactly as before; the filter is wrapped in a reload
+/// layer so per-subsystem levels can be changed later. Events go to the
+/// console and, as JSON, to the rotating log file. If the log file cannot
+/// be opened, logging continues on the console only.
+pub fn init_tracing() {
+    let (filter, handle) = reload::Layer::new(current_filter());
+
+    let log_file = log_dir().and_then(|dir| {
+        let path = dir.join(LOG_FILE_NAME);
+        RotatingFile::open(&path, MAX_LOG_FILE_BYTES, ROTATED_LOG_FILES)
+            .map(|file| (path, file))
+            .map_err(|e| AppError::FileSystem(format!("Failed to open log file: {e}")))
+    });
+    let (file_layer, file_error) = match log_file {
+        Ok((path, file)) => {
+            let _ = LOG_FILE_PATH.set(path);
+            let layer = fmt::layer()
+                .json()
+                .with_ansi(false)
+                .with_writer(Mutex::new(file));
+            (Some(layer), None)
+        }
+        Err(e) => (None, Some(e)),
+    };
+
+    // Debug builds also mirror every event into the debug panel's console
+    #[cfg(debug_assertions)]
+    let console_layer = crate::debug::DebugConsoleLayer;
+    #[cfg(not(debug_assertions))]
+    let console_layer = tracing_subscriber::layer::Identity::new();
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(fmt::layer())
+        .with(file_layer)
+        .with(console_layer)
+        .init();
+
+    let _ = FILTER_HANDLE.set(handle);
+    if let Some(e) = file_error {
+        warn!("Logging to the console only: {}", e);
+    }
+}
+
+/// Internal implementation for finding the log file.
+///
+/// # Returns
+///
+/// Returns the path of the log file being written.
+///
+/// # Errors
+///
+/// Returns `AppError::FileSystem` if logs are not being written to a file.
+pub fn get_log_file_path_internal() -> Result<PathBuf, AppError> {
+    LOG_FILE_PATH
+        .get()
+        .cloned()
+        .ok_or_else(|| AppError::FileSystem("Logs are not being written to a file".to_string()))
+}
+
+/// Internal implementation for showing the log files.
+///
+/// Opens the log directory in the file manager, creating it if needed.
+///
+/// # Errors
+///
+/// Returns `AppError::FileSystem` if the directory cannot be found, created
+/// or opened.
+pub fn open_logs_folder_internal(app_handle: &AppHandle) -> Result<(), AppError> {
+    let dir = log_dir()?;
+    fs::create_dir_all(&dir)
+        .map_err(|e| AppError::FileSystem(format!("Failed to create log directory: {e}")))?;
+    app_handle
+        .opener()
+        .open_path(dir.to_string_lossy(), None::<&str>)
+        .map_err(|e| AppError::FileSystem(format!("Failed to open {}: {e}", dir.display())))
+}
+
+/// Internal implementation for reading the per-subsystem log levels.
+///
+/// # Returns
+///
+/// One entry per [`LogSubsystem`], with `None` for subsystems that follow
+/// the global filter.
+pub fn get_log_levels_internal() -> Vec<SubsystemLogLevel> {
+    let overrides = SUBSYSTEM_LEVELS
+        .lock()
+        .map(|levels| levels.clone())
+        .unwrap_or_default();
+
+    LogSubsystem::all()
+        .into_iter()
+        .map(|subsystem| SubsystemLogLevel {
+            subsystem,
+            level: overrides.get(&subsystem).copied(),
+        })
+        .collect()
+}
+
+/// Internal implementation for changing a subsystem's log level.
+///
+/// # Arguments
+///
+/// * `subsystem` - The subsystem to configure
+/// * `level` - The new level, or `None` to follow the global filter again
+///
+/// # Returns
+///
+/// Returns the updated levels for all subsystems.
+///
+/// # Errors
+///
+/// Returns `AppError::Command` if the new filter cannot be applied.
+pub fn set_log_level_internal(
+    subsystem: LogSubsystem,
+    level: Option<LogVerbosity>,
+) -> Result<Vec<SubsystemLogLevel>, AppError> {
+    {
+        let mut levels = SUBSYSTEM_LEVELS
+            .lock()
+            .map_err(|e| AppError::Command(format!("Failed to lock log levels: {e}")))?;
+        match level {
+            Some(level) => levels.insert(subsystem, level),
+            None => levels.remove(&subsystem),
+        };
+    }
+
+    if let Some(handle) = FILTER_HANDLE.get() {
+        handle
+            .reload(current_filter())
+            .map_err(|e| AppError::Command(format!("Failed to apply log filter: {e}")))?;
+    }
+
+    info!(
+        "Log level for {} set to {}",
+        subsystem.display_name(),
+        level.map_or("default", |l| l.as_directive())
+    );
+
+    Ok(get_log_levels_internal())
+}
+
+/// Builds the filter for `RUST_LOG` plus the current overrides.
+fn current_filter() -> EnvFilter {
+    let base = std::env::var(EnvFilter::DEFAULT_ENV).unwrap_or_default();
+    let overrides = SUBSYSTEM_LEVELS
+        .lock()
+        .map(|levels| levels.clone())
+        .unwrap_or_default();
+
+    // Invalid directives in RUST_LOG are skipped rather than failing startup
+    EnvFilter::builder().parse_lossy(build_filter_directives(&base, &overrides))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_filter_directives_appends_overrides() {
+        let mut overrides = BTreeMap::new();
+        overrides.insert(LogSubsystem::Hotkey, LogVerbosity::Trace);
+        overrides.insert(LogSubsystem::Audio, LogVerbosity::Off);
+
+        let directives = build_filter_directives("info, hyper=warn", &overrides);
+
+        assert_eq!(
+            directives,
+            "info,hyper=warn,speakr_core::audio=off,speakr_lib::audio=off,\
+             speakr_lib::services::hotkey=trace"
+        );
+    }
+
+    #[test]
+    fn test_build_filter_directives_without_overrides() {
+        assert_eq!(build_filter_directives("", &BTreeMap::new()), "");
+    }
+
+    #[test]
+    fn test_rotated_log_path_appends_index() {
+        assert_eq!(
+            rotated_log_path(Path::new("/logs/speakr.log"), 2),
+            PathBuf::from("/logs/speakr.log.2")
+        );
+    }
+
+    #[test]
+    fn test_rotating_file_keeps_a_limited_number_of_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join(LOG_FILE_NAME);
+        let mut file = RotatingFile::open(&path, 10, 2).unwrap();
+
+        for line in ["first\n", "second\n", "third\n", "fourth\n"] {
+            file.write_all(line.as_bytes()).unwrap();
+        }
+        file.flush().unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fourth\n");
+        assert_eq!(
+            fs::read_to_string(rotated_log_path(&path, 1)).unwrap(),
+            "third\n"
+        );
+        assert_eq!(
+            fs::read_to_string(rotated_log_path(&path, 2)).unwrap(),
+            "second\n"
+        );
+        assert!(!rotated_log_path(&path, 3).exists());
+    }
+
+    #[test]
+    fn test_rotating_file_appends_to_an_existing_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join(LOG_FILE_NAME);
+        fs::write(&path, "old\n").unwrap();
+
+        let mut file = RotatingFile::open(&path, 1024, 1).unwrap();
+        file.write_all(b"new\n").unwrap();
+        file.flush().unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "old\nnew\n");
+    }
+
+    #[test]
+    fn test_every_subsystem_has_targets() {
+        for subsystem in LogSubsystem::all() {
+            assert!(!subsystem_targets(subsystem).is_empty());
+        }
+    }
+}
@@ -0,0 +1,286 @@
+// ============================================================================
+//! Auto-launch at Login
+// ============================================================================
+//!
+//! Registers Speakr with the operating system so it starts when the user
+//! logs in:
+//!
+//! - **macOS**: a LaunchAgent in `~/Library/LaunchAgents` that runs the app
+//!   when launchd loads it at login
+//! - **Windows**: a value under
+//!   `HKEY_CURRENT_USER\Software\Microsoft\Windows\CurrentVersion\Run`
+//! - **Linux**: an XDG autostart entry in `~/.config/autostart`
+//!
+//! The login item always points at the running executable. It only counts as
+//! enabled if it still does, so moving the app shows auto-launch as off
+//! until it is enabled again.
+
+use speakr_types::AppError;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+/// Identifier of the login item; the app's bundle identifier.
+pub const LOGIN_ITEM_ID: &str = "com.jessup.speakr";
+
+/// Registers or removes Speakr as a login item
+///
+/// # Arguments
+///
+/// * `enable` - `true` to start Speakr at login, `false` to stop doing so
+///
+/// # Errors
+///
+/// Returns an `AppError` if the executable cannot be located or the login
+/// item cannot be written or removed.
+pub fn set_auto_launch(enable: bool) -> Result<(), AppError> {
+    let exe = current_exe()?;
+    platform::set_auto_launch(&exe, enable)?;
+    info!(
+        "Auto-launch {} for {}",
+        if enable { "enabled" } else { "disabled" },
+        exe.display()
+    );
+    Ok(())
+}
+
+/// Returns whether Speakr is registered to start at login
+///
+/// # Errors
+///
+/// Returns an `AppError` if the executable cannot be located or the login
+/// item cannot be read.
+pub fn is_auto_launch_enabled() -> Result<bool, AppError> {
+    platform::is_auto_launch_enabled(&current_exe()?)
+}
+
+fn current_exe() -> Result<PathBuf, AppError> {
+    std::env::current_exe().map_err(|e| AppError::io("Failed to locate Speakr", &e, None))
+}
+
+// ============================================================================
+// File-based Login Items (macOS and Linux)
+// ============================================================================
+
+/// The LaunchAgent property list that starts `exe` at login.
+pub fn launch_agent_plist(exe: &Path) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>Label</key>
+    <string>{LOGIN_ITEM_ID}</string>
+    <key>ProgramArguments</key>
+    <array>
+        <string>{}</string>
+    </array>
+    <key>RunAtLoad</key>
+    <true/>
+</dict>
+</plist>
+"#,
+        escape_xml(&exe.display().to_string())
+    )
+}
+
+/// The XDG autostart desktop entry that starts `exe` at login.
+pub fn autostart_desktop_entry(exe: &Path) -> String {
+    format!(
+        "[Desktop Entry]\nType=Application\nName=Speakr\nExec={}\nX-GNOME-Autostart-enabled=true\n",
+        quote_exec_arg(&exe.display().to_string())
+    )
+}
+
+/// Writes the login item at `path`, or removes it if `contents` is `None`
+///
+/// # Errors
+///
+/// Returns `AppError::Io` if the file or its directory cannot be written or
+/// removed.
+pub fn write_login_item(path: &Path, contents: Option<&str>) -> Result<(), AppError> {
+    match contents {
+        Some(contents) => {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).map_err(|e| {
+                    AppError::io("Failed to create login item directory", &e, Some(dir))
+                })?;
+            }
+            fs::write(path, contents)
+                .map_err(|e| AppError::io("Failed to write login item", &e, Some(path)))
+        }
+        None if path.exists() => fs::remove_file(path)
+            .map_err(|e| AppError::io("Failed to remove login item", &e, Some(path))),
+        None => Ok(()),
+    }
+}
+
+/// Returns whether the login item at `path` starts `exe`
+///
+/// # Errors
+///
+/// Returns `AppError::Io` if the file exists but cannot be read.
+pub fn login_item_launches(path: &Path, exe: &Path) -> Result<bool, AppError> {
+    if !path.exists() {
+        return Ok(false);
+    }
+    let contents = fs::read_to_string(path)
+        .map_err(|e| AppError::io("Failed to read login item", &e, Some(path)))?;
+    let exe = exe.display().to_string();
+    Ok(contents.contains(&escape_xml(&exe)) || contents.contains(&quote_exec_arg(&exe)))
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn quote_exec_arg(arg: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in arg.chars() {
+        if matches!(c, '"' | '`' | '$' | '\\') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+// ============================================================================
+// Platform Integration
+// ============================================================================
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use super::{launch_agent_plist, login_item_launches, write_login_item, LOGIN_ITEM_ID};
+    use speakr_types::AppError;
+    use std::path::{Path, PathBuf};
+
+    fn launch_agent_path() -> Result<PathBuf, AppError> {
+        let home = dirs::home_dir()
+            .ok_or_else(|| AppError::FileSystem("Could not find home directory".to_string()))?;
+        Ok(home
+            .join("Library/LaunchAgents")
+            .join(format!("{LOGIN_ITEM_ID}.plist")))
+    }
+
+    pub fn set_auto_launch(exe: &Path, enable: bool) -> Result<(), AppError> {
+        let plist = enable.then(|| launch_agent_plist(exe));
+        write_login_item(&launch_agent_path()?, plist.as_deref())
+    }
+
+    pub fn is_auto_launch_enabled(exe: &Path) -> Result<bool, AppError> {
+        login_item_launches(&launch_agent_path()?, exe)
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use speakr_types::AppError;
+    use std::os::windows::process::CommandExt;
+    use std::path::Path;
+    use std::process::{Command, Output};
+
+    const RUN_KEY: &str = r"HKCU\Software\Microsoft\Windows\CurrentVersion\Run";
+    const VALUE_NAME: &str = "Speakr";
+    /// Keeps `reg.exe` from flashing a console window.
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    fn reg(args: &[&str]) -> Result<Output, AppError> {
+        Command::new("reg")
+            .args(args)
+            .creation_flags(CREATE_NO_WINDOW)
+            .output()
+            .map_err(|e| AppError::Command(format!("Failed to run reg.exe: {e}")))
+    }
+
+    pub fn set_auto_launch(exe: &Path, enable: bool) -> Result<(), AppError> {
+        let output = if enable {
+            let command = format!("\"{}\"", exe.display());
+            reg(&[
+                "add", RUN_KEY, "/v", VALUE_NAME, "/t", "REG_SZ", "/d", &command, "/f",
+            ])?
+        } else if is_registered()? {
+            reg(&["delete", RUN_KEY, "/v", VALUE_NAME, "/f"])?
+        } else {
+            return Ok(());
+        };
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(AppError::Command(format!(
+                "Failed to update the startup registry entry: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )))
+        }
+    }
+
+    fn is_registered() -> Result<bool, AppError> {
+        Ok(reg(&["query", RUN_KEY, "/v", VALUE_NAME])?.status.success())
+    }
+
+    pub fn is_auto_launch_enabled(exe: &Path) -> Result<bool, AppError> {
+        let output = reg(&["query", RUN_KEY, "/v", VALUE_NAME])?;
+        let exe = exe.display().to_string().to_lowercase();
+        Ok(output.status.success()
+            && String::from_utf8_lossy(&output.stdout)
+                .to_lowercase()
+                .contains(&exe))
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+mod platform {
+    use super::{autostart_desktop_entry, login_item_launches, write_login_item};
+    use speakr_types::AppError;
+    use std::path::{Path, PathBuf};
+
+    fn autostart_path() -> Result<PathBuf, AppError> {
+        let config = dirs::config_dir()
+            .ok_or_else(|| AppError::FileSystem("Could not find config directory".to_string()))?;
+        Ok(config.join("autostart/speakr.desktop"))
+    }
+
+    pub fn set_auto_launch(exe: &Path, enable: bool) -> Result<(), AppError> {
+        let entry = enable.then(|| autostart_desktop_entry(exe));
+        write_login_item(&autostart_path()?, entry.as_deref())
+    }
+
+    pub fn is_auto_launch_enabled(exe: &Path) -> Result<bool, AppError> {
+        login_item_launches(&autostart_path()?, exe)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_login_item_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("LaunchAgents").join("speakr.plist");
+        let exe = Path::new("/Applications/Speakr & Co.app/Contents/MacOS/speakr");
+
+        assert!(!login_item_launches(&path, exe).unwrap());
+
+        write_login_item(&path, Some(&launch_agent_plist(exe))).unwrap();
+        assert!(login_item_launches(&path, exe).unwrap());
+        assert!(!login_item_launches(&path, Path::new("/elsewhere/speakr")).unwrap());
+
+        write_login_item(&path, None).unwrap();
+        assert!(!path.exists());
+        write_login_item(&path, None).unwrap();
+    }
+
+    #[test]
+    fn test_login_item_contents_quote_the_executable() {
+        let exe = Path::new("/opt/my apps/<speakr>");
+        assert!(launch_agent_plist(exe).contains("<string>/opt/my apps/&lt;speakr&gt;</string>"));
+        assert!(autostart_desktop_entry(Path::new("/opt/$HOME/speakr"))
+            .contains("Exec=\"/opt/\\$HOME/speakr\"\n"));
+    }
+}
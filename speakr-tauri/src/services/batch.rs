@@ -0,0 +1,246 @@
+// ============================================================================
+//! Batch Transcription Queue
+// ============================================================================
+//!
+//! Transcribes every audio file in a folder as one [`BatchJob`]. Files are
+//! processed in order, sequentially by default or a few at a time (bounded
+//! by [`MAX_BATCH_PARALLELISM`]), and a [`BatchProgress`] update is reported
+//! whenever a file starts or finishes. A file that fails is reported and
+//! skipped; the rest of the batch carries on.
+//!
+//! Each result is written next to its audio file as a plain-text `.txt` and
+//! a SubRip `.srt` sidecar, replacing any from an earlier run.
+
+use futures::stream::{self, StreamExt};
+use speakr_core::subtitles::to_srt;
+use speakr_types::{
+    AppError, BatchFileStatus, BatchJob, BatchProgress, TranscriptionResult, MAX_BATCH_PARALLELISM,
+};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::Duration;
+use tracing::warn;
+
+/// Identifier of the next batch job.
+static NEXT_JOB_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Creates a job for the given files with a fresh identifier.
+pub fn new_job(folder: &Path, files: &[PathBuf]) -> BatchJob {
+    BatchJob {
+        id: NEXT_JOB_ID.fetch_add(1, Ordering::Relaxed),
+        folder: folder.display().to_string(),
+        files: files
+            .iter()
+            .map(|file| file.display().to_string())
+            .collect(),
+    }
+}
+
+/// Runs a batch to completion.
+///
+/// # Arguments
+///
+/// * `job` - The files to process
+/// * `parallelism` - Files to process at once, clamped to
+///   `1..=MAX_BATCH_PARALLELISM`
+/// * `transcribe` - Transcribes one file and writes its sidecars
+/// * `on_progress` - Called when a file starts and when it finishes
+///
+/// # Returns
+///
+/// The number of files that failed.
+pub async fn run_batch<F, Fut>(
+    job: &BatchJob,
+    parallelism: usize,
+    transcribe: F,
+    on_progress: impl Fn(&BatchProgress),
+) -> usize
+where
+    F: Fn(PathBuf) -> Fut,
+    Fut: Future<Output = Result<(), AppError>>,
+{
+    let total = job.files.len();
+    let completed = AtomicUsize::new(0);
+    let report = |file: &str, status: BatchFileStatus, completed: usize| {
+        on_progress(&BatchProgress {
+            job_id: job.id,
+            file: file.to_string(),
+            status,
+            completed,
+            total,
+        });
+    };
+
+    stream::iter(job.files.iter().cloned())
+        .map(|file| {
+            let (completed, report, transcribe) = (&completed, &report, &transcribe);
+            async move {
+                report(
+                    &file,
+                    BatchFileStatus::Transcribing,
+                    completed.load(Ordering::SeqCst),
+                );
+                let status = match transcribe(PathBuf::from(&file)).await {
+                    Ok(()) => BatchFileStatus::Done,
+                    Err(e) => {
+                        warn!("Batch {}: failed to transcribe {}: {}", job.id, file, e);
+                        BatchFileStatus::Failed(e.to_string())
+                    }
+                };
+                let failed = matches!(status, BatchFileStatus::Failed(_));
+                report(&file, status, completed.fetch_add(1, Ordering::SeqCst) + 1);
+                failed
+            }
+        })
+        .buffer_unordered(parallelism.clamp(1, MAX_BATCH_PARALLELISM))
+        .filter(|failed| std::future::ready(*failed))
+        .count()
+        .await
+}
+
+/// Writes a transcription next to its audio file as `.txt` and `.srt`.
+///
+/// # Arguments
+///
+/// * `audio_file` - The transcribed file; sidecars share its name
+/// * `result` - The transcription
+/// * `duration` - Length of the recording, for subtitles without segments
+///
+/// # Errors
+///
+/// Returns `AppError::Io` if a sidecar cannot be written.
+pub async fn write_sidecars(
+    audio_file: &Path,
+    result: &TranscriptionResult,
+    duration: Duration,
+) -> Result<(), AppError> {
+    let sidecars = [
+        (
+            audio_file.with_extension("txt"),
+            format!("{}\n", result.text.trim()),
+        ),
+        (audio_file.with_extension("srt"), to_srt(result, duration)),
+    ];
+    for (path, contents) in sidecars {
+        tokio::fs::write(&path, contents)
+            .await
+            .map_err(|e| AppError::io("Failed to write sidecar", &e, Some(&path)))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use speakr_types::ModelId;
+    use std::sync::Mutex;
+    use tempfile::TempDir;
+
+    fn job(files: &[&str]) -> BatchJob {
+        new_job(
+            Path::new("/recordings"),
+            &files.iter().map(PathBuf::from).collect::<Vec<_>>(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_run_batch_reports_every_file_and_continues_after_failures() {
+        let job = job(&[
+            "/recordings/a.wav",
+            "/recordings/b.mp3",
+            "/recordings/c.m4a",
+        ]);
+        let updates = Mutex::new(Vec::new());
+
+        let failures = run_batch(
+            &job,
+            1,
+            |file| async move {
+                if file.ends_with("b.mp3") {
+                    Err(AppError::AudioCapture("corrupt".to_string()))
+                } else {
+                    Ok(())
+                }
+            },
+            |progress| updates.lock().unwrap().push(progress.clone()),
+        )
+        .await;
+
+        assert_eq!(failures, 1);
+        let updates = updates.into_inner().unwrap();
+        let summary: Vec<_> = updates
+            .iter()
+            .map(|update| (update.file.as_str(), &update.status, update.completed))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("/recordings/a.wav", &BatchFileStatus::Transcribing, 0),
+                ("/recordings/a.wav", &BatchFileStatus::Done, 1),
+                ("/recordings/b.mp3", &BatchFileStatus::Transcribing, 1),
+                (
+                    "/recordings/b.mp3",
+                    &BatchFileStatus::Failed("Audio capture error: corrupt".to_string()),
+                    2
+                ),
+                ("/recordings/c.m4a", &BatchFileStatus::Transcribing, 2),
+                ("/recordings/c.m4a", &BatchFileStatus::Done, 3),
+            ]
+        );
+        assert!(updates.iter().all(|update| update.job_id == job.id));
+        assert!(updates.last().unwrap().is_finished());
+    }
+
+    #[tokio::test]
+    async fn test_run_batch_bounds_parallelism() {
+        let job = job(&["/r/1.wav", "/r/2.wav", "/r/3.wav", "/r/4.wav", "/r/5.wav"]);
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+
+        run_batch(
+            &job,
+            2,
+            |_| async {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                Ok(())
+            },
+            |_| {},
+        )
+        .await;
+
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_write_sidecars_next_to_the_audio_file() {
+        let dir = TempDir::new().unwrap();
+        let audio_file = dir.path().join("memo.m4a");
+        let result = TranscriptionResult {
+            text: " Hello world ".to_string(),
+            language: None,
+            confidence: 0.9,
+            avg_logprob: None,
+            processing_time: Duration::ZERO,
+            memory_delta_bytes: 0,
+            model_used: ModelId::new("small"),
+            segments: vec![],
+        };
+
+        write_sidecars(&audio_file, &result, Duration::from_secs(2))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("memo.txt")).unwrap(),
+            "Hello world\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("memo.srt")).unwrap(),
+            "1\n00:00:00,000 --> 00:00:02,000\nHello world\n"
+        );
+    }
+}
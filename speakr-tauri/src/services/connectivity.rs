@@ -0,0 +1,40 @@
+// ============================================================================
+//! Connectivity Watcher
+// ============================================================================
+//!
+//! Shares one [`ConnectivityWatcher`] between the features that need the
+//! network (model downloads) and forwards every change to the frontend as
+//! [`CONNECTIVITY_CHANGED_EVENT`], so the UI can show an offline state instead
+//! of an error and clear it when the network returns.
+
+use speakr_core::connectivity::ConnectivityWatcher;
+use speakr_types::CONNECTIVITY_CHANGED_EVENT;
+use std::sync::LazyLock;
+use tauri::{AppHandle, Emitter};
+use tracing::{info, warn};
+
+/// Connectivity state shared by every network feature.
+static CONNECTIVITY: LazyLock<ConnectivityWatcher> = LazyLock::new(ConnectivityWatcher::new);
+
+/// Returns the shared connectivity watcher
+pub fn connectivity() -> &'static ConnectivityWatcher {
+    &CONNECTIVITY
+}
+
+/// Forwards connectivity changes to the frontend
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle used to emit events
+pub fn spawn_connectivity_events(app_handle: AppHandle) {
+    let mut changes = connectivity().subscribe();
+    tauri::async_runtime::spawn(async move {
+        while changes.changed().await.is_ok() {
+            let online = *changes.borrow_and_update();
+            info!(online, "Connectivity changed");
+            if let Err(e) = app_handle.emit(CONNECTIVITY_CHANGED_EVENT, online) {
+                warn!("Failed to emit connectivity change: {}", e);
+            }
+        }
+    });
+}
@@ -0,0 +1,145 @@
+// ============================================================================
+//! Dictation Sessions
+// ============================================================================
+//!
+//! With the `session_window_secs` setting, dictations that follow each other
+//! closely are one continuous block of text: a dictation started within
+//! the window after the previous one, in the same application, is joined
+//! onto it with the right spacing and capitalisation (see
+//! [`join_dictation`]) instead of being typed as a new sentence.
+//!
+//! A session ends when the window passes without a dictation, the target
+//! application changes, or the user chooses "End Dictation Session" from
+//! the tray menu, which finalises the block so the next dictation starts a
+//! new one. Only the last character delivered is kept, to decide how the
+//! next dictation joins on.
+
+use crate::services::tray::refresh_tray_menu;
+use speakr_core::formatting::join_dictation;
+use std::borrow::Cow;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+use tracing::{debug, info};
+
+/// An open session.
+#[derive(Debug, Clone, PartialEq)]
+struct DictationSession {
+    /// Application the session's text is going into.
+    target_app: Option<String>,
+    /// Last character delivered, once the first dictation has been.
+    last_char: Option<char>,
+    /// When the last dictation was delivered.
+    delivered_at: Instant,
+}
+
+/// The open session, if any.
+static SESSION: Mutex<Option<DictationSession>> = Mutex::new(None);
+
+/// Returns `true` while a session has text that the next dictation joins
+pub fn is_session_active() -> bool {
+    SESSION
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_ref()
+        .is_some_and(|session| session.last_char.is_some())
+}
+
+/// Decides whether a starting dictation continues the open session
+///
+/// Ends the session if sessions are off, the window has passed since its
+/// last dictation, or `target_app` differs, and opens a new one if
+/// sessions are on. Called when a dictation starts, so the time spent
+/// speaking does not count against the window.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle, to update the tray menu
+/// * `window` - The session window from the settings, `None` if sessions are off
+/// * `target_app` - Identifier of the application being dictated into
+pub fn begin_session_dictation(
+    app_handle: &AppHandle,
+    window: Option<Duration>,
+    target_app: Option<&str>,
+) {
+    let mut session = SESSION.lock().unwrap_or_else(PoisonError::into_inner);
+    let continues = match (session.as_ref(), window) {
+        (Some(open), Some(window)) => {
+            open.delivered_at.elapsed() <= window && open.target_app.as_deref() == target_app
+        }
+        _ => false,
+    };
+    if continues {
+        debug!("Dictation continues the open session");
+        return;
+    }
+    let ended = session
+        .take()
+        .is_some_and(|session| session.last_char.is_some());
+    *session = window.map(|_| DictationSession {
+        target_app: target_app.map(str::to_string),
+        last_char: None,
+        delivered_at: Instant::now(),
+    });
+    drop(session);
+    if ended {
+        info!("Dictation session ended");
+        refresh_tray_menu(app_handle);
+    }
+}
+
+/// Joins a dictation's text onto the open session
+///
+/// # Returns
+///
+/// The text to deliver: unchanged for the first dictation of a session,
+/// otherwise adjusted to read on from the text before it.
+pub fn join_session(text: &str) -> Cow<'_, str> {
+    let last_char = SESSION
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_ref()
+        .and_then(|session| session.last_char);
+    match last_char {
+        Some(previous) => Cow::Owned(join_dictation(previous, text)),
+        None => Cow::Borrowed(text),
+    }
+}
+
+/// Records that a dictation of the open session was delivered
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle, to update the tray menu
+/// * `last_char` - The last character typed or copied, including any
+///   trailing text
+pub fn record_session_delivery(app_handle: &AppHandle, last_char: char) {
+    let mut session = SESSION.lock().unwrap_or_else(PoisonError::into_inner);
+    let Some(open) = session.as_mut() else {
+        return;
+    };
+    open.delivered_at = Instant::now();
+    let started = open.last_char.replace(last_char).is_none();
+    drop(session);
+    if started {
+        info!("Dictation session started");
+        refresh_tray_menu(app_handle);
+    }
+}
+
+/// Ends the open session, so the next dictation starts a new block
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle, to update the tray menu
+pub fn end_session(app_handle: &AppHandle) {
+    let ended = SESSION
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take()
+        .is_some();
+    if ended {
+        info!("Dictation session ended by the user");
+    }
+    refresh_tray_menu(app_handle);
+}
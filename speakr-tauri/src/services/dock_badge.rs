@@ -0,0 +1,107 @@
+// ============================================================================
+//! Dock Badge Indicator
+// ============================================================================
+//!
+//! Optionally badges the app's Dock icon while a dictation is recording or
+//! transcribing, as a second visual indicator next to the in-app status.
+//! The badge is controlled by [`AppSettings::dock_badge`] and is cleared
+//! automatically when the [`DockBadge`] handle is dropped, so an early
+//! return on error never leaves a stale badge behind.
+//!
+//! On macOS the badge is a short text label; on Linux a count badge is used
+//! instead. Other platforms have no equivalent and ignore it.
+//!
+//! [`AppSettings::dock_badge`]: speakr_types::AppSettings::dock_badge
+
+use tauri::{AppHandle, Manager};
+use tracing::debug;
+
+/// Label of the window whose Dock icon is badged.
+const MAIN_WINDOW_LABEL: &str = "main";
+
+/// Dictation phase shown on the Dock icon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BadgeState {
+    /// No dictation in progress; the badge is removed
+    Idle,
+    /// Audio is being captured
+    Recording,
+    /// Captured audio is being transcribed
+    Transcribing,
+}
+
+impl BadgeState {
+    /// Returns the badge label for this state, or `None` to clear the badge
+    pub fn label(self) -> Option<&'static str> {
+        match self {
+            BadgeState::Idle => None,
+            BadgeState::Recording => Some("●"),
+            BadgeState::Transcribing => Some("…"),
+        }
+    }
+}
+
+/// Handle that reflects the workflow's progress on the Dock icon
+pub struct DockBadge {
+    app_handle: Option<AppHandle>,
+}
+
+impl DockBadge {
+    /// Creates a badge handle
+    ///
+    /// # Arguments
+    ///
+    /// * `app_handle` - The Tauri application handle
+    /// * `enabled` - Whether the user has turned the Dock badge on; a disabled
+    ///   handle does nothing
+    pub fn new(app_handle: &AppHandle, enabled: bool) -> Self {
+        Self {
+            app_handle: enabled.then(|| app_handle.clone()),
+        }
+    }
+
+    /// Shows the given state on the Dock icon
+    ///
+    /// Failures are logged and otherwise ignored: the badge is cosmetic and
+    /// must never interrupt a dictation.
+    pub fn set(&self, state: BadgeState) {
+        let Some(app_handle) = &self.app_handle else {
+            return;
+        };
+        let Some(window) = app_handle.get_webview_window(MAIN_WINDOW_LABEL) else {
+            debug!("No main window, skipping Dock badge update");
+            return;
+        };
+
+        #[cfg(target_os = "macos")]
+        let result = window.set_badge_label(state.label().map(str::to_string));
+        #[cfg(not(target_os = "macos"))]
+        let result = window.set_badge_count(state.label().map(|_| 1));
+
+        if let Err(e) = result {
+            debug!("Failed to update Dock badge to {:?}: {}", state, e);
+        }
+    }
+}
+
+impl Drop for DockBadge {
+    fn drop(&mut self) {
+        self.set(BadgeState::Idle);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_active_states_have_a_label() {
+        assert_eq!(BadgeState::Idle.label(), None);
+        assert!(BadgeState::Recording.label().is_some());
+        assert!(BadgeState::Transcribing.label().is_some());
+        assert_ne!(
+            BadgeState::Recording.label(),
+            BadgeState::Transcribing.label()
+        );
+    }
+}
@@ -0,0 +1,250 @@
+// ============================================================================
+//! Event Throttling
+// ============================================================================
+//!
+//! High-frequency events (transcription progress today; audio levels and
+//! partial transcripts as they arrive) can flood the WebView's message queue
+//! on slow machines. A [`ThrottledEmitter`] coalesces them instead: only the
+//! latest payload is kept, and it is emitted at most once per interval.
+//!
+//! In acknowledged mode the emitter additionally waits for the frontend to
+//! confirm it has handled the previous payload (via the `ack_event` command)
+//! before sending the next one, so a busy WebView is never sent more than it
+//! can process. A missing acknowledgement only delays the next payload by
+//! [`ACK_TIMEOUT`], so a subscriber that never acknowledges cannot stall
+//! the stream.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex, PoisonError, Weak};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+use tracing::debug;
+
+/// Default minimum time between two emissions of the same event (10 Hz).
+pub const DEFAULT_MIN_EVENT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How long an acknowledged emitter waits for an acknowledgement before
+/// sending the next payload anyway.
+pub const ACK_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Rate-limiting state for a single event stream.
+#[derive(Debug)]
+pub struct Throttle<T> {
+    min_interval: Duration,
+    requires_ack: bool,
+    last_sent: Option<Instant>,
+    awaiting_ack: bool,
+    pending: Option<T>,
+}
+
+impl<T> Throttle<T> {
+    /// Creates a throttle allowing one payload per `min_interval`.
+    pub fn new(min_interval: Duration, requires_ack: bool) -> Self {
+        Self {
+            min_interval,
+            requires_ack,
+            last_sent: None,
+            awaiting_ack: false,
+            pending: None,
+        }
+    }
+
+    /// Offers a payload, replacing any payload not yet sent.
+    ///
+    /// # Returns
+    ///
+    /// The payload to emit now, or `None` if it must wait.
+    pub fn offer(&mut self, payload: T, now: Instant) -> Option<T> {
+        self.pending = Some(payload);
+        if !self.is_ready(now) {
+            return None;
+        }
+
+        self.last_sent = Some(now);
+        self.awaiting_ack = self.requires_ack;
+        self.pending.take()
+    }
+
+    /// Records that the subscriber has handled the last payload.
+    pub fn acknowledge(&mut self) {
+        self.awaiting_ack = false;
+    }
+
+    /// Takes the coalesced payload that has not been sent yet, if any.
+    pub fn take_pending(&mut self) -> Option<T> {
+        self.pending.take()
+    }
+
+    fn is_ready(&self, now: Instant) -> bool {
+        let Some(last_sent) = self.last_sent else {
+            return true;
+        };
+        let elapsed = now.saturating_duration_since(last_sent);
+
+        elapsed >= self.min_interval && (!self.awaiting_ack || elapsed >= ACK_TIMEOUT)
+    }
+}
+
+/// Type-erased handle used to deliver acknowledgements.
+trait Acknowledge: Send + Sync {
+    fn acknowledge(&self);
+}
+
+impl<T: Send> Acknowledge for Mutex<Throttle<T>> {
+    fn acknowledge(&self) {
+        self.lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .acknowledge();
+    }
+}
+
+/// Acknowledged emitters, keyed by event name.
+static ACK_SUBSCRIPTIONS: LazyLock<Mutex<HashMap<&'static str, Weak<dyn Acknowledge>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Delivers a frontend acknowledgement for `event`
+///
+/// # Returns
+///
+/// Returns `true` if an acknowledged emitter for `event` is active.
+pub fn acknowledge_event_internal(event: &str) -> bool {
+    let subscription = ACK_SUBSCRIPTIONS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(event)
+        .and_then(Weak::upgrade);
+
+    match subscription {
+        Some(throttle) => {
+            throttle.acknowledge();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Emits an event to the frontend at a bounded rate, coalescing payloads
+pub struct ThrottledEmitter<T> {
+    app_handle: AppHandle,
+    event: &'static str,
+    throttle: Arc<Mutex<Throttle<T>>>,
+}
+
+impl<T: Serialize + Clone + Send + 'static> ThrottledEmitter<T> {
+    /// Creates an emitter limited to one payload per `min_interval`
+    ///
+    /// # Arguments
+    ///
+    /// * `app_handle` - The Tauri application handle used to emit
+    /// * `event` - Name of the event to emit
+    /// * `min_interval` - Minimum time between emissions
+    pub fn new(app_handle: &AppHandle, event: &'static str, min_interval: Duration) -> Self {
+        Self {
+            app_handle: app_handle.clone(),
+            event,
+            throttle: Arc::new(Mutex::new(Throttle::new(min_interval, false))),
+        }
+    }
+
+    /// Creates an emitter that also waits for the frontend to acknowledge
+    /// each payload (see [`acknowledge_event_internal`])
+    ///
+    /// Only one acknowledged emitter per event name is active at a time; a
+    /// newer one replaces the older.
+    pub fn acknowledged(
+        app_handle: &AppHandle,
+        event: &'static str,
+        min_interval: Duration,
+    ) -> Self {
+        let throttle = Arc::new(Mutex::new(Throttle::new(min_interval, true)));
+        let subscription: Arc<dyn Acknowledge> = throttle.clone();
+        ACK_SUBSCRIPTIONS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(event, Arc::downgrade(&subscription));
+
+        Self {
+            app_handle: app_handle.clone(),
+            event,
+            throttle,
+        }
+    }
+
+    /// Emits `payload` now if the rate allows, otherwise keeps it as the
+    /// latest pending payload
+    pub fn emit(&self, payload: T) {
+        let ready = self
+            .throttle
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .offer(payload, Instant::now());
+        if let Some(payload) = ready {
+            self.send(payload);
+        }
+    }
+
+    /// Emits the pending payload, if any, regardless of the rate limit
+    pub fn flush(&self) {
+        let pending = self
+            .throttle
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take_pending();
+        if let Some(payload) = pending {
+            self.send(payload);
+        }
+    }
+
+    fn send(&self, payload: T) {
+        if let Err(e) = self.app_handle.emit(self.event, payload) {
+            debug!("Failed to emit {}: {}", self.event, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_throttle_coalesces_to_latest_payload() {
+        let start = Instant::now();
+        let mut throttle = Throttle::new(Duration::from_millis(100), false);
+
+        assert_eq!(throttle.offer(1, start), Some(1));
+        assert_eq!(throttle.offer(2, start + Duration::from_millis(10)), None);
+        assert_eq!(throttle.offer(3, start + Duration::from_millis(20)), None);
+        assert_eq!(
+            throttle.offer(4, start + Duration::from_millis(100)),
+            Some(4)
+        );
+        assert_eq!(throttle.take_pending(), None);
+    }
+
+    #[test]
+    fn test_throttle_waits_for_acknowledgement() {
+        let start = Instant::now();
+        let mut throttle = Throttle::new(Duration::from_millis(100), true);
+
+        assert_eq!(throttle.offer(1, start), Some(1));
+        assert_eq!(throttle.offer(2, start + Duration::from_millis(200)), None);
+        throttle.acknowledge();
+        assert_eq!(
+            throttle.offer(3, start + Duration::from_millis(300)),
+            Some(3)
+        );
+
+        // A missing acknowledgement only delays the stream
+        assert_eq!(throttle.offer(4, start + ACK_TIMEOUT), None);
+        assert_eq!(
+            throttle.offer(5, start + Duration::from_millis(300) + ACK_TIMEOUT),
+            Some(5)
+        );
+    }
+
+    #[test]
+    fn test_acknowledging_unknown_event_is_ignored() {
+        assert!(!acknowledge_event_internal("no-such-event"));
+    }
+}
@@ -0,0 +1,611 @@
+// ============================================================================
+//! Dictation History
+// ============================================================================
+//!
+//! Keeps the most recent dictations in `history.json` next to
+//! `settings.json`, newest first, so users can look back at what they said
+//! and correct transcripts that came out wrong. Each entry keeps the
+//! original transcript alongside the correction.
+//!
+//! With the `encrypt_history` setting on, the history is kept in
+//! `history.enc` instead, encrypted with AES-256-GCM under a key stored in
+//! the keychain (see [`crate::services::secrets`]). Switching the setting
+//! moves the existing history across (see [`apply_history_encryption`]).
+//!
+//! Words that users correct again and again are suggested as vocabulary
+//! terms (see [`vocabulary_suggestions`]), unless they turned the
+//! `vocabulary_suggestions` setting off. Every change to the history is
+//! emitted on [`HISTORY_CHANGED_EVENT`].
+
+use crate::services::secrets::{
+    delete_secret, get_secret, is_keychain_available, set_secret, HISTORY_KEY_SECRET,
+};
+use crate::settings::{get_settings_dir, load_settings_internal};
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use speakr_types::{AppError, HistoryEntry, VocabularySuggestion, HISTORY_CHANGED_EVENT};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, PoisonError};
+use tauri::{AppHandle, Emitter};
+use tracing::{debug, info, warn};
+use zeroize::Zeroizing;
+
+/// Name of the file holding the history.
+const HISTORY_FILE: &str = "history.json";
+
+/// Name of the file holding the encrypted history.
+const ENCRYPTED_HISTORY_FILE: &str = "history.enc";
+
+/// Length of the nonce written ahead of the encrypted history.
+const NONCE_LEN: usize = 12;
+
+/// Number of dictations kept; older ones are dropped.
+pub const MAX_HISTORY_ENTRIES: usize = 500;
+
+/// Corrections of the same word needed before it is suggested.
+pub const MIN_SUGGESTION_CORRECTIONS: u32 = 2;
+
+/// Shortest word suggested as a vocabulary term.
+const MIN_SUGGESTION_CHARS: usize = 3;
+
+/// Serialises read-modify-write cycles of the history file.
+static HISTORY_LOCK: Mutex<()> = Mutex::new(());
+
+/// Whether new writes of the history are encrypted.
+static ENCRYPT_HISTORY: AtomicBool = AtomicBool::new(false);
+
+/// Key the history is encrypted with; wiped when dropped.
+pub struct HistoryKey(Zeroizing<[u8; 32]>);
+
+impl HistoryKey {
+    /// Generates a new random key.
+    pub fn generate() -> Self {
+        let mut key = Zeroizing::new([0u8; 32]);
+        key.copy_from_slice(&Aes256Gcm::generate_key(OsRng));
+        Self(key)
+    }
+
+    /// Decodes a key stored with [`HistoryKey::to_secret`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `AppError::Settings` if the secret is not a valid key.
+    pub fn from_secret(secret: &str) -> Result<Self, AppError> {
+        let bytes = Zeroizing::new(STANDARD.decode(secret).unwrap_or_default());
+        let mut key = Zeroizing::new([0u8; 32]);
+        if bytes.len() != key.len() {
+            return Err(AppError::Settings(
+                "The stored history key is invalid".to_string(),
+            ));
+        }
+        key.copy_from_slice(&bytes);
+        Ok(Self(key))
+    }
+
+    /// Encodes the key for the keychain.
+    pub fn to_secret(&self) -> Zeroizing<String> {
+        Zeroizing::new(STANDARD.encode(*self.0))
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(self.0.as_slice()))
+    }
+}
+
+/// Encrypts `plaintext`, returning the nonce followed by the ciphertext.
+fn encrypt(key: &HistoryKey, plaintext: &[u8]) -> Result<Vec<u8>, AppError> {
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = key
+        .cipher()
+        .encrypt(&nonce, plaintext)
+        .map_err(|e| AppError::Settings(format!("Failed to encrypt history: {e}")))?;
+    let mut data = nonce.to_vec();
+    data.extend(ciphertext);
+    Ok(data)
+}
+
+/// Decrypts data written by [`encrypt`].
+fn decrypt(key: &HistoryKey, data: &[u8]) -> Result<Zeroizing<Vec<u8>>, AppError> {
+    let invalid = || {
+        AppError::Settings(
+            "Failed to decrypt history; it was encrypted with a different key".to_string(),
+        )
+    };
+    if data.len() < NONCE_LEN {
+        return Err(invalid());
+    }
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    key.cipher()
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map(Zeroizing::new)
+        .map_err(|_| invalid())
+}
+
+/// Loads the history from a specific directory.
+///
+/// An encrypted history is read with `key`; a plain one is read as it is,
+/// whatever the key, so it can be encrypted on the next save.
+///
+/// # Returns
+///
+/// Returns the entries, newest first; none if the file does not exist.
+///
+/// # Errors
+///
+/// Returns `AppError::Io` if the file cannot be read, or
+/// `AppError::Settings` if it cannot be decrypted or parsed.
+pub fn load_history_from_dir(
+    dir: &Path,
+    key: Option<&HistoryKey>,
+) -> Result<Vec<HistoryEntry>, AppError> {
+    let encrypted_path = dir.join(ENCRYPTED_HISTORY_FILE);
+    let path = dir.join(HISTORY_FILE);
+    let content = if encrypted_path.exists() {
+        let key = key.ok_or_else(|| {
+            AppError::Settings(
+                "The history is encrypted; turn history encryption on to read it".to_string(),
+            )
+        })?;
+        let data = fs::read(&encrypted_path)
+            .map_err(|e| AppError::io("Failed to read history", &e, Some(&encrypted_path)))?;
+        decrypt(key, &data)?
+    } else if path.exists() {
+        Zeroizing::new(
+            fs::read(&path).map_err(|e| AppError::io("Failed to read history", &e, Some(&path)))?,
+        )
+    } else {
+        return Ok(Vec::new());
+    };
+    serde_json::from_slice(&content)
+        .map_err(|e| AppError::Settings(format!("Failed to parse history: {e}")))
+}
+
+/// Saves the history to a specific directory.
+///
+/// With a `key` the history is encrypted and the plain file removed;
+/// without one it is written in plain and the encrypted file removed.
+///
+/// # Errors
+///
+/// Returns `AppError::Io` if the file cannot be written.
+pub fn save_history_to_dir(
+    history: &[HistoryEntry],
+    dir: &Path,
+    key: Option<&HistoryKey>,
+) -> Result<(), AppError> {
+    let json = Zeroizing::new(
+        serde_json::to_vec_pretty(history)
+            .map_err(|e| AppError::Settings(format!("Failed to serialize history: {e}")))?,
+    );
+    let (path, stale_path, content) = match key {
+        Some(key) => (
+            dir.join(ENCRYPTED_HISTORY_FILE),
+            dir.join(HISTORY_FILE),
+            encrypt(key, &json)?,
+        ),
+        None => (
+            dir.join(HISTORY_FILE),
+            dir.join(ENCRYPTED_HISTORY_FILE),
+            json.to_vec(),
+        ),
+    };
+    fs::write(&path, content)
+        .map_err(|e| AppError::io("Failed to write history", &e, Some(&path)))?;
+    if stale_path.exists() {
+        fs::remove_file(&stale_path)
+            .map_err(|e| AppError::io("Failed to remove history", &e, Some(&stale_path)))?;
+    }
+    Ok(())
+}
+
+/// Rewrites the history in `dir` encrypted with `key`, or in plain.
+///
+/// # Errors
+///
+/// Returns an `AppError` if the history cannot be read or written.
+pub fn migrate_history_in_dir(dir: &Path, key: &HistoryKey, encrypt: bool) -> Result<(), AppError> {
+    let _guard = HISTORY_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    let history = load_history_from_dir(dir, Some(key))?;
+    save_history_to_dir(&history, dir, encrypt.then_some(key))
+}
+
+/// Waits for a history write in progress to finish
+///
+/// Every change is written as it happens, so once this returns the history
+/// on disk is complete. Called when Speakr shuts down.
+pub fn flush_history() {
+    drop(HISTORY_LOCK.lock().unwrap_or_else(PoisonError::into_inner));
+}
+
+/// Loads the history from `dir`, changes it and saves it back.
+fn modify_history_in_dir(
+    dir: &Path,
+    key: Option<&HistoryKey>,
+    change: impl FnOnce(&mut Vec<HistoryEntry>) -> Result<(), AppError>,
+) -> Result<Vec<HistoryEntry>, AppError> {
+    let _guard = HISTORY_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    let mut history = load_history_from_dir(dir, key)?;
+    change(&mut history)?;
+    save_history_to_dir(&history, dir, key)?;
+    Ok(history)
+}
+
+/// Adds a dictation to the front of the history in `dir`, dropping the
+/// oldest entries beyond [`MAX_HISTORY_ENTRIES`].
+///
+/// # Returns
+///
+/// Returns the updated history.
+///
+/// # Errors
+///
+/// Returns an `AppError` if the history cannot be read or written.
+pub fn add_history_in_dir(
+    dir: &Path,
+    key: Option<&HistoryKey>,
+    text: &str,
+) -> Result<Vec<HistoryEntry>, AppError> {
+    modify_history_in_dir(dir, key, |history| {
+        let id = history.iter().map(|entry| entry.id).max().unwrap_or(0) + 1;
+        history.insert(
+            0,
+            HistoryEntry {
+                id,
+                created_at: chrono::Utc::now().timestamp_millis() as u64,
+                text: text.to_string(),
+                corrected_text: None,
+            },
+        );
+        history.truncate(MAX_HISTORY_ENTRIES);
+        Ok(())
+    })
+}
+
+/// Stores the user's correction of an entry in `dir`.
+///
+/// Correcting the text back to the original clears the correction.
+///
+/// # Returns
+///
+/// Returns the updated history.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if no entry has that id, or an `AppError` if
+/// the history cannot be read or written.
+pub fn correct_history_in_dir(
+    dir: &Path,
+    key: Option<&HistoryKey>,
+    id: u64,
+    text: &str,
+) -> Result<Vec<HistoryEntry>, AppError> {
+    modify_history_in_dir(dir, key, |history| {
+        let entry = history
+            .iter_mut()
+            .find(|entry| entry.id == id)
+            .ok_or_else(|| not_found(id))?;
+        entry.corrected_text = (text != entry.text).then(|| text.to_string());
+        Ok(())
+    })
+}
+
+/// Removes an entry from the history in `dir`.
+///
+/// # Returns
+///
+/// Returns the updated history.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if no entry has that id, or an `AppError` if
+/// the history cannot be read or written.
+pub fn delete_history_in_dir(
+    dir: &Path,
+    key: Option<&HistoryKey>,
+    id: u64,
+) -> Result<Vec<HistoryEntry>, AppError> {
+    modify_history_in_dir(dir, key, |history| {
+        let index = history
+            .iter()
+            .position(|entry| entry.id == id)
+            .ok_or_else(|| not_found(id))?;
+        history.remove(index);
+        Ok(())
+    })
+}
+
+fn not_found(id: u64) -> AppError {
+    AppError::Settings(format!("No history entry with id {id}"))
+}
+
+/// Splits text into words, without surrounding punctuation.
+fn words(text: &str) -> impl Iterator<Item = &str> {
+    text.split_whitespace()
+        .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric()))
+        .filter(|word| !word.is_empty())
+}
+
+/// Finds words the user keeps correcting
+///
+/// A word counts as corrected in an entry when it appears in the correction
+/// but not, spelled exactly the same, in the original transcript. Words
+/// corrected in at least [`MIN_SUGGESTION_CORRECTIONS`] entries are
+/// suggested unless they are already in `vocabulary` (ignoring case).
+///
+/// # Returns
+///
+/// Returns the suggestions, most often corrected first.
+pub fn vocabulary_suggestions(
+    history: &[HistoryEntry],
+    vocabulary: &[String],
+) -> Vec<VocabularySuggestion> {
+    let known: BTreeSet<String> = vocabulary.iter().map(|term| term.to_lowercase()).collect();
+    let mut counts: BTreeMap<&str, u32> = BTreeMap::new();
+
+    for entry in history {
+        let Some(corrected) = &entry.corrected_text else {
+            continue;
+        };
+        let original: BTreeSet<&str> = words(&entry.text).collect();
+        let changed: BTreeSet<&str> = words(corrected)
+            .filter(|word| !original.contains(word))
+            .filter(|word| word.chars().count() >= MIN_SUGGESTION_CHARS)
+            .filter(|word| !known.contains(&word.to_lowercase()))
+            .collect();
+        for word in changed {
+            *counts.entry(word).or_default() += 1;
+        }
+    }
+
+    let mut suggestions: Vec<VocabularySuggestion> = counts
+        .into_iter()
+        .filter(|(_, corrections)| *corrections >= MIN_SUGGESTION_CORRECTIONS)
+        .map(|(term, corrections)| VocabularySuggestion {
+            term: term.to_string(),
+            corrections,
+        })
+        .collect();
+    suggestions.sort_by_key(|suggestion| std::cmp::Reverse(suggestion.corrections));
+    suggestions
+}
+
+/// Reads the history key from the keychain, storing a new one if there is
+/// none yet.
+fn stored_history_key() -> Result<HistoryKey, AppError> {
+    if let Some(secret) = get_secret(HISTORY_KEY_SECRET)? {
+        return HistoryKey::from_secret(&secret);
+    }
+    let key = HistoryKey::generate();
+    set_secret(HISTORY_KEY_SECRET, &key.to_secret())?;
+    Ok(key)
+}
+
+/// Key the history is read and written with; none while encryption is off.
+fn current_history_key() -> Result<Option<HistoryKey>, AppError> {
+    if !ENCRYPT_HISTORY.load(Ordering::SeqCst) {
+        return Ok(None);
+    }
+    stored_history_key().map(Some)
+}
+
+/// Switches encryption of the history on or off
+///
+/// Moves an existing history into the requested form: a plain history is
+/// encrypted when encryption is switched on, and an encrypted one decrypted
+/// (and its key removed from the keychain) when it is switched off. Applied
+/// at launch and whenever settings are saved; does nothing when the history
+/// is already in the requested form.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if encryption is switched on where the
+/// keychain is not available, or an `AppError` if the history cannot be
+/// moved across. The setting is left as it was in either case.
+pub fn apply_history_encryption(enabled: bool) -> Result<(), AppError> {
+    if enabled && !is_keychain_available() {
+        return Err(AppError::Settings(
+            "History encryption needs a system keychain, which is not available on this platform"
+                .to_string(),
+        ));
+    }
+    let dir = get_settings_dir()?;
+    let stale_file = if enabled {
+        HISTORY_FILE
+    } else {
+        ENCRYPTED_HISTORY_FILE
+    };
+    if dir.join(stale_file).exists() {
+        migrate_history_in_dir(&dir, &stored_history_key()?, enabled)?;
+        if !enabled {
+            delete_secret(HISTORY_KEY_SECRET)?;
+        }
+        info!(
+            "History {}",
+            if enabled { "encrypted" } else { "decrypted" }
+        );
+    }
+    ENCRYPT_HISTORY.store(enabled, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Tells the UI about a changed history.
+fn publish(app_handle: &AppHandle, history: &[HistoryEntry]) {
+    if let Err(e) = app_handle.emit(HISTORY_CHANGED_EVENT, history) {
+        warn!("Failed to emit history: {}", e);
+    }
+}
+
+/// Adds a completed dictation to the history
+///
+/// Failures are logged; the dictation itself has already finished.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+/// * `text` - The delivered transcript
+pub fn record_history(app_handle: &AppHandle, text: &str) {
+    if text.trim().is_empty() {
+        return;
+    }
+    let added = get_settings_dir()
+        .and_then(|dir| add_history_in_dir(&dir, current_history_key()?.as_ref(), text));
+    match added {
+        Ok(history) => {
+            debug!("Dictation added to history ({} entries)", history.len());
+            publish(app_handle, &history);
+        }
+        Err(e) => warn!("Failed to add dictation to history: {}", e),
+    }
+}
+
+/// Lists the history, newest first.
+///
+/// # Errors
+///
+/// See [`load_history_from_dir`].
+pub fn list_history_internal() -> Result<Vec<HistoryEntry>, AppError> {
+    load_history_from_dir(&get_settings_dir()?, current_history_key()?.as_ref())
+}
+
+/// Stores the user's correction of a history entry.
+///
+/// # Errors
+///
+/// See [`correct_history_in_dir`].
+pub fn correct_history_internal(
+    app_handle: &AppHandle,
+    id: u64,
+    text: &str,
+) -> Result<Vec<HistoryEntry>, AppError> {
+    let history = correct_history_in_dir(
+        &get_settings_dir()?,
+        current_history_key()?.as_ref(),
+        id,
+        text,
+    )?;
+    publish(app_handle, &history);
+    Ok(history)
+}
+
+/// Deletes a history entry.
+///
+/// # Errors
+///
+/// See [`delete_history_in_dir`].
+pub fn delete_history_internal(
+    app_handle: &AppHandle,
+    id: u64,
+) -> Result<Vec<HistoryEntry>, AppError> {
+    let history = delete_history_in_dir(&get_settings_dir()?, current_history_key()?.as_ref(), id)?;
+    publish(app_handle, &history);
+    Ok(history)
+}
+
+/// Suggests vocabulary terms from the corrections in the history.
+///
+/// # Returns
+///
+/// Returns no suggestions if the `vocabulary_suggestions` setting is off.
+///
+/// # Errors
+///
+/// Returns an `AppError` if the settings or the history cannot be loaded.
+pub async fn get_vocabulary_suggestions_internal() -> Result<Vec<VocabularySuggestion>, AppError> {
+    let settings = load_settings_internal().await?;
+    if !settings.vocabulary_suggestions {
+        return Ok(Vec::new());
+    }
+    Ok(vocabulary_suggestions(
+        &list_history_internal()?,
+        &settings.vocabulary,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn corrected(id: u64, text: &str, correction: &str) -> HistoryEntry {
+        HistoryEntry {
+            id,
+            created_at: 0,
+            text: text.to_string(),
+            corrected_text: Some(correction.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_history_keeps_corrections_newest_first() {
+        let dir = TempDir::new().unwrap();
+        add_history_in_dir(dir.path(), None, "first").unwrap();
+        let history = add_history_in_dir(dir.path(), None, "secnd").unwrap();
+        assert_eq!(history[0].text, "secnd");
+
+        let history = correct_history_in_dir(dir.path(), None, history[0].id, "second").unwrap();
+        assert_eq!(history[0].text, "secnd");
+        assert_eq!(history[0].current_text(), "second");
+
+        let history = correct_history_in_dir(dir.path(), None, history[0].id, "secnd").unwrap();
+        assert_eq!(history[0].corrected_text, None);
+
+        let history = delete_history_in_dir(dir.path(), None, history[1].id).unwrap();
+        assert_eq!(load_history_from_dir(dir.path(), None).unwrap(), history);
+        assert!(matches!(
+            delete_history_in_dir(dir.path(), None, 99),
+            Err(AppError::Settings(_))
+        ));
+    }
+
+    #[test]
+    fn test_history_encryption_round_trip_and_migration() {
+        let dir = TempDir::new().unwrap();
+        let key = HistoryKey::generate();
+        add_history_in_dir(dir.path(), None, "plain secret").unwrap();
+
+        migrate_history_in_dir(dir.path(), &key, true).unwrap();
+        assert!(!dir.path().join(HISTORY_FILE).exists());
+        let on_disk = fs::read(dir.path().join(ENCRYPTED_HISTORY_FILE)).unwrap();
+        assert!(!String::from_utf8_lossy(&on_disk).contains("plain secret"));
+
+        let history = add_history_in_dir(dir.path(), Some(&key), "second").unwrap();
+        assert_eq!(history.len(), 2);
+        assert!(matches!(
+            load_history_from_dir(dir.path(), None),
+            Err(AppError::Settings(_))
+        ));
+        assert!(matches!(
+            load_history_from_dir(dir.path(), Some(&HistoryKey::generate())),
+            Err(AppError::Settings(_))
+        ));
+
+        let restored = HistoryKey::from_secret(&key.to_secret()).unwrap();
+        migrate_history_in_dir(dir.path(), &restored, false).unwrap();
+        assert!(!dir.path().join(ENCRYPTED_HISTORY_FILE).exists());
+        assert_eq!(load_history_from_dir(dir.path(), None).unwrap(), history);
+    }
+
+    #[test]
+    fn test_suggests_words_corrected_repeatedly() {
+        let history = [
+            corrected(1, "deploy to cubernetes", "deploy to Kubernetes."),
+            corrected(2, "the cube ernetes cluster", "the Kubernetes cluster"),
+            corrected(3, "ask jon", "ask John"),
+            corrected(4, "use g rpc", "use gRPC"),
+            corrected(5, "g rpc again", "gRPC again"),
+        ];
+
+        let suggestions = vocabulary_suggestions(&history, &[]);
+        let terms: Vec<_> = suggestions.iter().map(|s| s.term.as_str()).collect();
+        assert_eq!(terms, ["Kubernetes", "gRPC"]);
+        assert_eq!(suggestions[0].corrections, 2);
+
+        let known = vocabulary_suggestions(&history, &["kubernetes".to_string()]);
+        assert_eq!(known.len(), 1);
+        assert_eq!(known[0].term, "gRPC");
+    }
+}
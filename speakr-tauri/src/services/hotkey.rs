@@ -1,12 +1,59 @@
 // ============================================================================
 //! Global Hotkey Service
 // ============================================================================
+//!
+//! Every global hot-key comes from the settings: the main dictation hot-key,
+//! the language hot-keys, the privacy hot-key and the re-type hot-key are
+//! registered together by [`register_hotkeys_from_settings`]. Each binding
+//! has an identifier (see [`DICTATION_BINDING`], [`language_binding_id`],
+//! [`PRIVACY_BINDING`] and [`REINJECT_BINDING`]) that is logged when it is
+//! triggered.
+//!
+//! Debug builds also register the developer shortcuts from the settings (see
+//! [`DEV_BINDING`]); release builds never do.
+//!
+//! Under Wayland the hot-keys are bound through the desktop's shortcuts
+//! portal instead (see [`bind_hotkeys_with_portal`]).
 
-use speakr_types::{HotkeyConfig, HotkeyError};
-use std::sync::{Arc, Mutex};
+use crate::commands::hotkey_conflicts::{suggest_hotkeys, ShortcutPlatform};
+use crate::services::last_transcription::spawn_reinject_last_transcription;
+use crate::services::pause::is_dictation_enabled;
+use crate::services::privacy::toggle_privacy_mode;
+use speakr_types::events::HOTKEY_TRIGGERED_EVENT;
+#[cfg(debug_assertions)]
+use speakr_types::DevShortcut;
+use speakr_types::{
+    AppError, AppSettings, DictationOverrides, HotkeyConfig, HotkeyError, HotkeyStatus,
+    LanguageHotkey, RegisteredHotkey,
+};
+use std::sync::{Arc, LazyLock, Mutex, PoisonError};
 use tauri::{AppHandle, Emitter};
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
-use tracing::{debug, info};
+use tracing::{debug, error, info, warn};
+
+/// Identifies the main dictation hot-key in logs.
+pub const DICTATION_BINDING: &str = "dictation";
+
+/// Identifies the cancel shortcut in logs.
+pub const CANCEL_BINDING: &str = "cancel";
+
+/// Identifies the privacy mode hot-key in logs.
+pub const PRIVACY_BINDING: &str = "privacy";
+
+/// Identifies the hot-key that types the last dictation again in logs.
+pub const REINJECT_BINDING: &str = "reinject";
+
+/// Identifies a developer shortcut in logs.
+#[cfg(debug_assertions)]
+pub const DEV_BINDING: &str = "dev";
+
+/// Shortcut registered instead when the configured hot-key is unavailable.
+pub const FALLBACK_HOTKEY: &str = "CmdOrCtrl+Alt+F2";
+
+/// Identifies a language hot-key in logs, e.g. `"dictation:de"`.
+pub fn language_binding_id(language: &str) -> String {
+    format!("{DICTATION_BINDING}:{language}")
+}
 
 /// Service responsible for managing global hot-keys
 pub struct GlobalHotkeyService {
@@ -78,6 +125,7 @@ impl GlobalHotkeyService {
 
         // Register the new shortcut with the system
         let app_handle_clone = self.app_handle.clone();
+        let shortcut_text = config.shortcut.clone();
 
         self.app_handle
             .global_shortcut()
@@ -85,11 +133,9 @@ impl GlobalHotkeyService {
                 // Only react to the key *press* event; ignore the release to
                 // prevent duplicate workflow invocations.
                 if Self::should_handle_hotkey_event(event.state()) {
-                    // Emit an event when the hotkey is triggered
-                    let _ = app_handle_clone.emit("hotkey-triggered", ());
-
-                    // TODO: Wire this to speakr-core pipeline in next step
-                    debug!("Global hotkey triggered");
+                    debug!(binding = DICTATION_BINDING, shortcut = %shortcut_text, "Hotkey triggered");
+                    record_hotkey_triggered(&shortcut_text);
+                    let _ = app_handle_clone.emit(HOTKEY_TRIGGERED_EVENT, None::<DictationOverrides>);
                 }
             })
             .map_err(|e| {
@@ -115,6 +161,8 @@ impl GlobalHotkeyService {
             *current_instance = Some(shortcut);
         }
 
+        forget_hotkeys(|hotkey| hotkey.action == DICTATION_ACTION);
+        record_hotkey(&config.shortcut, DICTATION_ACTION);
         info!("Successfully registered global hotkey: {}", config.shortcut);
         Ok(())
     }
@@ -172,7 +220,9 @@ pub async fn register_global_hotkey_internal(
     service
         .register_hotkey(&config)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    set_hotkey_fallback(None);
+    Ok(())
 }
 
 /// Unregister the current global hotkey using the GlobalHotkeyService
@@ -196,6 +246,583 @@ pub async fn update_global_hotkey_internal(
         .map_err(|e| e.to_string())
 }
 
+/// Checks that a shortcut can be registered by registering it and
+/// releasing it again straight away
+///
+/// Shortcuts that are already registered, by Speakr or through the plugin,
+/// count as taken.
+fn registers_cleanly(app_handle: &AppHandle, shortcut_text: &str) -> bool {
+    let Ok(shortcut) = shortcut_text.parse::<Shortcut>() else {
+        return false;
+    };
+    let global_shortcut = app_handle.global_shortcut();
+    if global_shortcut.is_registered(shortcut) {
+        return false;
+    }
+    match global_shortcut.register(shortcut) {
+        Ok(()) => {
+            if let Err(e) = global_shortcut.unregister(shortcut) {
+                warn!("Failed to release trial shortcut {}: {}", shortcut_text, e);
+            }
+            true
+        }
+        Err(e) => {
+            debug!("Shortcut {} is not available: {}", shortcut_text, e);
+            false
+        }
+    }
+}
+
+/// Suggests hot-keys that are free to use
+///
+/// Candidates from
+/// [`SUGGESTED_HOTKEYS`](crate::commands::hotkey_conflicts::SUGGESTED_HOTKEYS)
+/// that are neither system nor Speakr shortcuts are registered on trial,
+/// and the first `count` that register cleanly are returned, best first.
+pub fn suggest_available_hotkeys_internal(app_handle: &AppHandle, count: usize) -> Vec<String> {
+    suggest_hotkeys(
+        ShortcutPlatform::current(),
+        &other_registered_hotkeys(),
+        count,
+        |candidate| registers_cleanly(app_handle, candidate),
+    )
+}
+
+/// Language hot-keys currently registered with the system.
+static LANGUAGE_SHORTCUTS: LazyLock<Mutex<Vec<Shortcut>>> =
+    LazyLock::new(|| Mutex::new(Vec::new()));
+
+/// Registers the language hot-keys, replacing any registered before
+///
+/// Each binding emits `hotkey-triggered` with its [`DictationOverrides`]
+/// as the payload, so the dictation it starts uses the binding's language.
+/// A binding that cannot be registered does not prevent the others.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle for registering shortcuts
+/// * `bindings` - The language hot-keys from settings
+///
+/// # Errors
+///
+/// Returns the first `HotkeyError` if any binding could not be registered
+///
+/// [`DictationOverrides`]: speakr_types::DictationOverrides
+pub fn register_language_hotkeys(
+    app_handle: &AppHandle,
+    bindings: &[LanguageHotkey],
+) -> Result<(), HotkeyError> {
+    let mut registered = LANGUAGE_SHORTCUTS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    for shortcut in registered.drain(..) {
+        let _ = app_handle.global_shortcut().unregister(shortcut);
+    }
+    forget_hotkeys(|hotkey| hotkey.action.starts_with(LANGUAGE_ACTION_PREFIX));
+
+    let mut first_error = None;
+    for binding in bindings {
+        match register_language_hotkey(app_handle, binding) {
+            Ok(shortcut) => {
+                info!(
+                    "Registered language hotkey {} ({})",
+                    binding.shortcut, binding.language
+                );
+                record_hotkey(
+                    &binding.shortcut,
+                    &format!("{LANGUAGE_ACTION_PREFIX}{})", binding.language),
+                );
+                registered.push(shortcut);
+            }
+            Err(e) => {
+                warn!(
+                    "Failed to register language hotkey '{}': {}",
+                    binding.shortcut, e
+                );
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    first_error.map_or(Ok(()), Err)
+}
+
+/// Registers one language hot-key
+fn register_language_hotkey(
+    app_handle: &AppHandle,
+    binding: &LanguageHotkey,
+) -> Result<Shortcut, HotkeyError> {
+    let shortcut = binding
+        .shortcut
+        .parse::<Shortcut>()
+        .map_err(|e| HotkeyError::RegistrationFailed(format!("Invalid shortcut format: {e}")))?;
+    let overrides = binding.overrides();
+    let binding_id = language_binding_id(&binding.language);
+    let shortcut_text = binding.shortcut.clone();
+    app_handle
+        .global_shortcut()
+        .on_shortcut(shortcut, move |app, _shortcut, event| {
+            if GlobalHotkeyService::should_handle_hotkey_event(event.state()) {
+                debug!(binding = %binding_id, shortcut = %shortcut_text, "Hotkey triggered");
+                record_hotkey_triggered(&shortcut_text);
+                let _ = app.emit(HOTKEY_TRIGGERED_EVENT, Some(&overrides));
+            }
+        })
+        .map_err(|e| HotkeyError::ConflictDetected(format!("Failed to register shortcut: {e}")))?;
+    Ok(shortcut)
+}
+
+/// Privacy hot-key currently registered with the system.
+static PRIVACY_SHORTCUT: Mutex<Option<Shortcut>> = Mutex::new(None);
+
+/// Registers the hot-key that toggles privacy mode, replacing any registered
+/// before
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle for registering shortcuts
+/// * `shortcut` - The privacy hot-key from settings; `None` releases it
+///
+/// # Errors
+///
+/// Returns `HotkeyError` if the shortcut could not be registered
+pub fn register_privacy_hotkey(
+    app_handle: &AppHandle,
+    shortcut: Option<&str>,
+) -> Result<(), HotkeyError> {
+    let mut registered = PRIVACY_SHORTCUT
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    if let Some(previous) = registered.take() {
+        let _ = app_handle.global_shortcut().unregister(previous);
+    }
+    forget_hotkeys(|hotkey| hotkey.action == PRIVACY_ACTION);
+
+    let Some(shortcut_text) = shortcut.map(str::to_string) else {
+        return Ok(());
+    };
+    let shortcut = shortcut_text
+        .parse::<Shortcut>()
+        .map_err(|e| HotkeyError::RegistrationFailed(format!("Invalid shortcut format: {e}")))?;
+    let triggered = shortcut_text.clone();
+    app_handle
+        .global_shortcut()
+        .on_shortcut(shortcut, move |app, _shortcut, event| {
+            if GlobalHotkeyService::should_handle_hotkey_event(event.state()) {
+                debug!(binding = PRIVACY_BINDING, shortcut = %triggered, "Hotkey triggered");
+                record_hotkey_triggered(&triggered);
+                toggle_privacy_mode(app);
+            }
+        })
+        .map_err(|e| HotkeyError::ConflictDetected(format!("Failed to register shortcut: {e}")))?;
+    info!("Registered privacy hotkey {}", shortcut_text);
+    record_hotkey(&shortcut_text, PRIVACY_ACTION);
+    *registered = Some(shortcut);
+    Ok(())
+}
+
+/// Re-type hot-key currently registered with the system.
+static REINJECT_SHORTCUT: Mutex<Option<Shortcut>> = Mutex::new(None);
+
+/// Registers the hot-key that types the last dictation again, replacing any
+/// registered before
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle for registering shortcuts
+/// * `shortcut` - The re-type hot-key from settings; `None` releases it
+///
+/// # Errors
+///
+/// Returns `HotkeyError` if the shortcut could not be registered
+pub fn register_reinject_hotkey(
+    app_handle: &AppHandle,
+    shortcut: Option<&str>,
+) -> Result<(), HotkeyError> {
+    let mut registered = REINJECT_SHORTCUT
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    if let Some(previous) = registered.take() {
+        let _ = app_handle.global_shortcut().unregister(previous);
+    }
+    forget_hotkeys(|hotkey| hotkey.action == REINJECT_ACTION);
+
+    let Some(shortcut_text) = shortcut.map(str::to_string) else {
+        return Ok(());
+    };
+    let shortcut = shortcut_text
+        .parse::<Shortcut>()
+        .map_err(|e| HotkeyError::RegistrationFailed(format!("Invalid shortcut format: {e}")))?;
+    let triggered = shortcut_text.clone();
+    app_handle
+        .global_shortcut()
+        .on_shortcut(shortcut, move |app, _shortcut, event| {
+            if GlobalHotkeyService::should_handle_hotkey_event(event.state()) {
+                debug!(binding = REINJECT_BINDING, shortcut = %triggered, "Hotkey triggered");
+                record_hotkey_triggered(&triggered);
+                spawn_reinject_last_transcription(app);
+            }
+        })
+        .map_err(|e| HotkeyError::ConflictDetected(format!("Failed to register shortcut: {e}")))?;
+    info!("Registered re-type hotkey {}", shortcut_text);
+    record_hotkey(&shortcut_text, REINJECT_ACTION);
+    *registered = Some(shortcut);
+    Ok(())
+}
+
+/// Developer shortcuts currently registered with the system.
+#[cfg(debug_assertions)]
+static DEV_SHORTCUTS: Mutex<Vec<Shortcut>> = Mutex::new(Vec::new());
+
+/// Prefix of the action recorded for a developer shortcut.
+#[cfg(debug_assertions)]
+const DEV_ACTION_PREFIX: &str = "Developer: ";
+
+/// Registers the developer shortcuts, replacing any registered before
+///
+/// Only compiled into debug builds. Each shortcut runs its [`DevAction`]
+/// through [`run_dev_action`]. A shortcut that cannot be registered does not
+/// prevent the others.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle for registering shortcuts
+/// * `bindings` - The developer shortcuts from settings
+///
+/// # Errors
+///
+/// Returns the first `HotkeyError` if any shortcut could not be registered
+///
+/// [`DevAction`]: speakr_types::DevAction
+/// [`run_dev_action`]: crate::debug::shortcuts::run_dev_action
+#[cfg(debug_assertions)]
+pub fn register_dev_shortcuts(
+    app_handle: &AppHandle,
+    bindings: &[DevShortcut],
+) -> Result<(), HotkeyError> {
+    let mut registered = DEV_SHORTCUTS.lock().unwrap_or_else(PoisonError::into_inner);
+    for shortcut in registered.drain(..) {
+        let _ = app_handle.global_shortcut().unregister(shortcut);
+    }
+    forget_hotkeys(|hotkey| hotkey.action.starts_with(DEV_ACTION_PREFIX));
+
+    let mut first_error = None;
+    for binding in bindings {
+        match register_dev_shortcut(app_handle, binding) {
+            Ok(shortcut) => {
+                info!(
+                    "Registered developer shortcut {} ({})",
+                    binding.shortcut,
+                    binding.action.display_name()
+                );
+                record_hotkey(
+                    &binding.shortcut,
+                    &format!("{DEV_ACTION_PREFIX}{}", binding.action.display_name()),
+                );
+                registered.push(shortcut);
+            }
+            Err(e) => {
+                warn!(
+                    "Failed to register developer shortcut '{}': {}",
+                    binding.shortcut, e
+                );
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    first_error.map_or(Ok(()), Err)
+}
+
+/// Registers one developer shortcut
+#[cfg(debug_assertions)]
+fn register_dev_shortcut(
+    app_handle: &AppHandle,
+    binding: &DevShortcut,
+) -> Result<Shortcut, HotkeyError> {
+    let shortcut = binding
+        .shortcut
+        .parse::<Shortcut>()
+        .map_err(|e| HotkeyError::RegistrationFailed(format!("Invalid shortcut format: {e}")))?;
+    let action = binding.action;
+    let shortcut_text = binding.shortcut.clone();
+    app_handle
+        .global_shortcut()
+        .on_shortcut(shortcut, move |app, _shortcut, event| {
+            if GlobalHotkeyService::should_handle_hotkey_event(event.state()) {
+                debug!(binding = DEV_BINDING, shortcut = %shortcut_text, "Hotkey triggered");
+                record_hotkey_triggered(&shortcut_text);
+                tauri::async_runtime::spawn(crate::debug::shortcuts::run_dev_action(
+                    app.clone(),
+                    action,
+                ));
+            }
+        })
+        .map_err(|e| HotkeyError::ConflictDetected(format!("Failed to register shortcut: {e}")))?;
+    Ok(shortcut)
+}
+
+/// Binds the hot-keys in the settings through the Wayland shortcuts portal
+/// when Speakr runs under Wayland
+///
+/// # Returns
+///
+/// Returns `true` if the portal handles the hot-keys, in which case the
+/// global shortcut plugin must not register them as well.
+pub fn bind_hotkeys_with_portal(app_handle: &AppHandle, settings: &AppSettings) -> bool {
+    #[cfg(target_os = "linux")]
+    if crate::services::shortcut_portal::uses_shortcuts_portal() {
+        crate::services::shortcut_portal::bind_portal_shortcuts(app_handle, settings);
+        return true;
+    }
+    let _ = (app_handle, settings);
+    false
+}
+
+/// Registers every hot-key defined by the settings
+///
+/// The main dictation hot-key falls back to [`FALLBACK_HOTKEY`] if it cannot
+/// be registered, which is reported by `get_hotkey_status`. Failures are
+/// logged rather than returned, so one unavailable shortcut does not keep
+/// the others from working.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+/// * `settings` - The settings holding the hot-keys
+pub async fn register_hotkeys_from_settings(app_handle: &AppHandle, settings: &AppSettings) {
+    if bind_hotkeys_with_portal(app_handle, settings) {
+        info!("Hotkeys bound through the shortcuts portal");
+        return;
+    }
+
+    let config = HotkeyConfig {
+        shortcut: settings.hot_key.clone(),
+        enabled: true,
+    };
+    info!(binding = DICTATION_BINDING, shortcut = %config.shortcut, "Registering hotkey");
+
+    if let Err(e) = register_global_hotkey_internal(app_handle.clone(), config.clone()).await {
+        error!("⚠️  Failed to register hotkey '{}': {}", config.shortcut, e);
+        warn!("💡 You can change the hotkey in Settings to avoid conflicts");
+
+        let fallback = HotkeyConfig {
+            shortcut: FALLBACK_HOTKEY.to_string(),
+            enabled: true,
+        };
+        match register_global_hotkey_internal(app_handle.clone(), fallback).await {
+            Ok(()) => {
+                info!("Using fallback hotkey: {}", FALLBACK_HOTKEY);
+                set_hotkey_fallback(Some(format!(
+                    "{FALLBACK_HOTKEY} fallback active; {} could not be registered",
+                    config.shortcut
+                )));
+            }
+            Err(e2) => {
+                error!(
+                    "⚠️  Fallback hotkey '{}' also failed: {}",
+                    FALLBACK_HOTKEY, e2
+                );
+                warn!("App will start without global hotkey - configure one in Settings");
+            }
+        }
+    }
+
+    if let Err(e) = register_language_hotkeys(app_handle, &settings.language_hotkeys) {
+        warn!("Failed to register language hotkeys: {}", e);
+    }
+    if let Err(e) = register_privacy_hotkey(app_handle, settings.privacy_hotkey.as_deref()) {
+        warn!("Failed to register privacy hotkey: {}", e);
+    }
+    if let Err(e) = register_reinject_hotkey(app_handle, settings.reinject_hotkey.as_deref()) {
+        warn!("Failed to register re-type hotkey: {}", e);
+    }
+    #[cfg(debug_assertions)]
+    if let Err(e) = register_dev_shortcuts(app_handle, &settings.dev_shortcuts) {
+        warn!("Failed to register developer shortcuts: {}", e);
+    }
+}
+
+/// Shortcut that cancels the dictation in flight.
+///
+/// Only registered while a dictation is running, so Esc keeps working
+/// normally in other applications the rest of the time.
+pub const CANCEL_SHORTCUT: &str = "Escape";
+
+/// Registers [`CANCEL_SHORTCUT`] to call `on_cancel` when pressed
+///
+/// # Errors
+///
+/// Returns `HotkeyError::ConflictDetected` if the shortcut cannot be registered
+pub(crate) fn register_cancel_shortcut<F>(
+    app_handle: &AppHandle,
+    on_cancel: F,
+) -> Result<(), HotkeyError>
+where
+    F: Fn(&AppHandle) + Send + Sync + 'static,
+{
+    app_handle
+        .global_shortcut()
+        .on_shortcut(CANCEL_SHORTCUT, move |app, _shortcut, event| {
+            if GlobalHotkeyService::should_handle_hotkey_event(event.state()) {
+                debug!(
+                    binding = CANCEL_BINDING,
+                    shortcut = CANCEL_SHORTCUT,
+                    "Hotkey triggered"
+                );
+                record_hotkey_triggered(CANCEL_SHORTCUT);
+                on_cancel(app);
+            }
+        })
+        .map_err(|e| {
+            HotkeyError::ConflictDetected(format!("Failed to register cancel shortcut: {e}"))
+        })?;
+    record_hotkey(CANCEL_SHORTCUT, "Cancel dictation");
+    Ok(())
+}
+
+/// Unregisters [`CANCEL_SHORTCUT`]; does nothing if it is not registered
+pub(crate) fn unregister_cancel_shortcut(app_handle: &AppHandle) {
+    forget_hotkey(CANCEL_SHORTCUT);
+    if let Err(e) = app_handle.global_shortcut().unregister(CANCEL_SHORTCUT) {
+        debug!("Cancel shortcut was not registered: {}", e);
+    }
+}
+
+// ============================================================================
+// Registration Status
+// ============================================================================
+
+/// Action of the main dictation hot-key.
+const DICTATION_ACTION: &str = "Dictation";
+
+/// Start of the action of each language hot-key, followed by its language.
+const LANGUAGE_ACTION_PREFIX: &str = "Dictation (";
+
+/// Action of the privacy hot-key.
+const PRIVACY_ACTION: &str = "Toggle privacy mode";
+
+/// Action of the re-type hot-key.
+const REINJECT_ACTION: &str = "Type last dictation again";
+
+/// Shortcuts registered with the system, reported by `get_hotkey_status`.
+static REGISTERED_HOTKEYS: LazyLock<Mutex<Vec<RegisteredHotkey>>> =
+    LazyLock::new(|| Mutex::new(Vec::new()));
+
+/// Explanation of the fallback hot-key in effect, if any.
+static HOTKEY_FALLBACK: Mutex<Option<String>> = Mutex::new(None);
+
+/// Returns the registered shortcuts and any fallback in effect
+pub fn get_hotkey_status_internal() -> HotkeyStatus {
+    HotkeyStatus {
+        hotkeys: REGISTERED_HOTKEYS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone(),
+        fallback: HOTKEY_FALLBACK
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone(),
+        paused: !is_dictation_enabled(),
+    }
+}
+
+/// Returns the registered shortcuts other than the main dictation hot-key
+///
+/// These are the hot-keys a new main hot-key must not collide with; the
+/// current main hot-key is about to be replaced, so it does not count.
+pub fn other_registered_hotkeys() -> Vec<RegisteredHotkey> {
+    REGISTERED_HOTKEYS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .filter(|hotkey| hotkey.action != DICTATION_ACTION)
+        .cloned()
+        .collect()
+}
+
+/// Records the fallback in effect, or clears it with `None`
+pub(crate) fn set_hotkey_fallback(fallback: Option<String>) {
+    *HOTKEY_FALLBACK
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = fallback;
+}
+
+/// Forgets a shortcut that was unregistered
+pub(crate) fn forget_hotkey(shortcut: &str) {
+    forget_hotkeys(|hotkey| hotkey.shortcut == shortcut);
+}
+
+/// Unregisters every global shortcut Speakr holds
+///
+/// Used when dictation is paused and when Speakr shuts down.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+///
+/// # Errors
+///
+/// Returns `AppError::HotKey` if the shortcuts could not be unregistered
+pub fn release_all_hotkeys(app_handle: &AppHandle) -> Result<(), AppError> {
+    #[cfg(target_os = "linux")]
+    crate::services::shortcut_portal::release_portal_shortcuts();
+    if let Err(e) = register_language_hotkeys(app_handle, &[]) {
+        warn!("Failed to release language hotkeys: {}", e);
+    }
+    if let Err(e) = register_privacy_hotkey(app_handle, None) {
+        warn!("Failed to release privacy hotkey: {}", e);
+    }
+    if let Err(e) = register_reinject_hotkey(app_handle, None) {
+        warn!("Failed to release re-type hotkey: {}", e);
+    }
+    #[cfg(debug_assertions)]
+    if let Err(e) = register_dev_shortcuts(app_handle, &[]) {
+        warn!("Failed to release developer shortcuts: {}", e);
+    }
+    app_handle
+        .global_shortcut()
+        .unregister_all()
+        .map_err(|e| AppError::HotKey(format!("Failed to unregister hotkeys: {e}")))?;
+    forget_all_hotkeys();
+    Ok(())
+}
+
+/// Forgets every shortcut, e.g. after unregistering them all
+fn forget_all_hotkeys() {
+    forget_hotkeys(|_| true);
+}
+
+fn forget_hotkeys(unregistered: impl Fn(&RegisteredHotkey) -> bool) {
+    REGISTERED_HOTKEYS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .retain(|hotkey| !unregistered(hotkey));
+}
+
+fn record_hotkey(shortcut: &str, action: &str) {
+    let mut hotkeys = REGISTERED_HOTKEYS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    hotkeys.retain(|hotkey| hotkey.shortcut != shortcut);
+    hotkeys.push(RegisteredHotkey {
+        shortcut: shortcut.to_string(),
+        action: action.to_string(),
+        registered_at: chrono::Utc::now().timestamp_millis() as u64,
+        last_triggered_at: None,
+    });
+}
+
+fn record_hotkey_triggered(shortcut: &str) {
+    let mut hotkeys = REGISTERED_HOTKEYS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    if let Some(hotkey) = hotkeys
+        .iter_mut()
+        .find(|hotkey| hotkey.shortcut == shortcut)
+    {
+        hotkey.last_triggered_at = Some(chrono::Utc::now().timestamp_millis() as u64);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -230,4 +857,33 @@ mod tests {
             "Exactly one workflow invocation expected on key press"
         );
     }
+
+    #[test]
+    fn test_language_binding_ids() {
+        assert_eq!(language_binding_id("de"), "dictation:de");
+        assert_ne!(language_binding_id("auto"), DICTATION_BINDING);
+    }
+
+    #[test]
+    fn test_registration_status_tracks_shortcuts() {
+        let registered = |shortcut: &str| {
+            get_hotkey_status_internal()
+                .hotkeys
+                .into_iter()
+                .find(|hotkey| hotkey.shortcut == shortcut)
+        };
+
+        record_hotkey("CmdOrCtrl+Alt+F9", "First");
+        record_hotkey("CmdOrCtrl+Alt+F9", "Second");
+        let hotkey = registered("CmdOrCtrl+Alt+F9").unwrap();
+        assert_eq!(hotkey.action, "Second");
+        assert!(hotkey.last_triggered_at.is_none());
+
+        record_hotkey_triggered("CmdOrCtrl+Alt+F9");
+        let hotkey = registered("CmdOrCtrl+Alt+F9").unwrap();
+        assert!(hotkey.last_triggered_at.unwrap() >= hotkey.registered_at);
+
+        forget_hotkey("CmdOrCtrl+Alt+F9");
+        assert!(registered("CmdOrCtrl+Alt+F9").is_none());
+    }
 }
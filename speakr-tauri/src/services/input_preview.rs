@@ -0,0 +1,104 @@
+// ============================================================================
+//! Input Gain Preview
+// ============================================================================
+//!
+//! While the user adjusts a microphone's input gain in Settings, its level
+//! is shown live: the preview records from that device at the gain being
+//! tried, so the meter reads what a dictation would capture. Nothing is
+//! kept; the audio is discarded when the preview stops, and a preview left
+//! running ends by itself after [`INPUT_PREVIEW_SECS`].
+//!
+//! The gain is fixed when the stream opens, so changing it means starting
+//! the preview again.
+
+use crate::workflow::is_dictation_active;
+use speakr_core::audio::{AudioRecorder, RecordingConfig};
+use speakr_types::{
+    level_to_dbfs, AppError, AudioDuration, InputGain, MAX_INPUT_GAIN, MIN_INPUT_GAIN,
+};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+use tracing::info;
+
+/// Longest a preview runs before it stops by itself.
+pub const INPUT_PREVIEW_SECS: u64 = 60;
+
+/// The running preview, if any.
+static INPUT_PREVIEW: Mutex<Option<AudioRecorder>> = Mutex::new(None);
+
+/// Starts previewing a microphone at an input gain, replacing any preview
+/// already running
+///
+/// # Arguments
+///
+/// * `device` - Name of the input device, as listed by the system
+/// * `gain` - Input gain to preview
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if the gain is outside [`MIN_INPUT_GAIN`] to
+/// [`MAX_INPUT_GAIN`], or `AppError::AudioCapture` if a dictation is in
+/// progress or the device cannot be recorded from.
+pub async fn start_input_preview_internal(device: String, gain: f32) -> Result<(), AppError> {
+    if !(MIN_INPUT_GAIN..=MAX_INPUT_GAIN).contains(&gain) {
+        return Err(AppError::Settings(format!(
+            "Invalid input gain for '{device}': {gain}. Must be between {MIN_INPUT_GAIN} and {MAX_INPUT_GAIN}."
+        )));
+    }
+    if is_dictation_active() {
+        return Err(AppError::AudioCapture(
+            "A dictation is in progress; try again when it has finished".to_string(),
+        ));
+    }
+    stop_input_preview_internal();
+
+    let config = RecordingConfig::new(AudioDuration::MAX)
+        .with_time_limit(Duration::from_secs(INPUT_PREVIEW_SECS))
+        .with_input_gains(vec![InputGain {
+            device: device.clone(),
+            gain,
+        }])
+        .with_preferred_devices(vec![device.clone()]);
+    let recorder = AudioRecorder::new(config)
+        .await
+        .map_err(|e| AppError::AudioCapture(e.to_string()))?;
+    recorder
+        .start_recording()
+        .await
+        .map_err(|e| AppError::AudioCapture(e.to_string()))?;
+    info!("Previewing input gain {} on {}", gain, device);
+
+    let previous = INPUT_PREVIEW
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .replace(recorder);
+    if let Some(previous) = previous {
+        previous.cancel_recording();
+    }
+    Ok(())
+}
+
+/// Returns the level of the running preview in dBFS
+///
+/// # Returns
+///
+/// `None` once the preview has stopped.
+pub fn input_preview_level_internal() -> Option<f32> {
+    INPUT_PREVIEW
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_ref()
+        .and_then(AudioRecorder::input_level)
+        .map(level_to_dbfs)
+}
+
+/// Stops the running preview, discarding its audio
+pub fn stop_input_preview_internal() {
+    let recorder = INPUT_PREVIEW
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take();
+    if recorder.is_some_and(|recorder| recorder.cancel_recording()) {
+        info!("Input gain preview stopped");
+    }
+}
@@ -0,0 +1,83 @@
+// ============================================================================
+//! Last Transcription
+// ============================================================================
+//!
+//! Keeps the text of the most recent completed dictation, as it was
+//! delivered, so it can be typed again when it went to the wrong window:
+//! from the `reinject_last_transcription` command or the optional re-type
+//! hot-key. The local API also reads it.
+//!
+//! Only the latest text is held, in memory; it is wiped when replaced.
+
+use crate::services::pending::inject_into_working_app;
+use crate::workflow::is_dictation_active;
+use speakr_types::AppError;
+use std::sync::{Mutex, PoisonError};
+use tauri::AppHandle;
+use tracing::{info, warn};
+use zeroize::Zeroizing;
+
+/// Text of the most recent completed dictation.
+static LAST_TRANSCRIPTION: Mutex<Option<Zeroizing<String>>> = Mutex::new(None);
+
+/// Keeps the text of a completed dictation, replacing the previous one
+pub fn retain_last_transcription(text: &str) {
+    *LAST_TRANSCRIPTION
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = Some(Zeroizing::new(text.to_string()));
+}
+
+/// Returns the text of the most recent completed dictation
+pub fn last_transcription() -> Option<String> {
+    LAST_TRANSCRIPTION
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_ref()
+        .map(|text| text.to_string())
+}
+
+/// Types the most recent dictation again into the app the user is working in
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+///
+/// # Errors
+///
+/// Returns `AppError::TextInjection` if there is no dictation to type, one
+/// is in progress, or the text cannot be typed.
+pub async fn reinject_last_transcription_internal(app_handle: &AppHandle) -> Result<(), AppError> {
+    if is_dictation_active() {
+        return Err(AppError::TextInjection(
+            "A dictation is in progress; try again when it has finished".to_string(),
+        ));
+    }
+    let text = last_transcription()
+        .map(Zeroizing::new)
+        .ok_or_else(|| AppError::TextInjection("No dictation to type again yet".to_string()))?;
+    info!("Typing the last dictation again");
+    inject_into_working_app(app_handle, &text).await
+}
+
+/// Types the most recent dictation again in the background, e.g. when its
+/// hot-key is pressed; failures are only logged
+pub fn spawn_reinject_last_transcription(app_handle: &AppHandle) {
+    let app_handle = app_handle.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = reinject_last_transcription_internal(&app_handle).await {
+            warn!("Failed to type the last dictation again: {}", e);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retains_only_the_latest_transcription() {
+        retain_last_transcription("first");
+        retain_last_transcription("second");
+        assert_eq!(last_transcription().as_deref(), Some("second"));
+    }
+}
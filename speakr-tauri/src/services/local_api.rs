@@ -0,0 +1,221 @@
+// ============================================================================
+//! Local API
+// ============================================================================
+//!
+//! Lets other tools on the same machine drive Speakr, e.g. an editor plugin
+//! that starts a dictation or pulls the latest transcript. The protocol is
+//! deliberately small: one JSON-encoded [`ApiRequest`] per line over a TCP
+//! connection to `127.0.0.1:`[`DEFAULT_API_PORT`], answered by one
+//! [`ApiResponse`] per line. The `speakr-client` crate wraps it for Rust
+//! callers.
+//!
+//! For menu-bar widgets and status bar scripts that only speak HTTP, the
+//! same port answers a read-only `GET /status` with the [`ApiStatus`] JSON
+//! that [`ApiRequest::Status`] returns, e.g.
+//! `curl http://127.0.0.1:47615/status`.
+//!
+//! The API is opt-in (`AppSettings::local_api`) and only ever binds to the
+//! loopback interface.
+
+use crate::services::last_transcription::last_transcription;
+use crate::services::pause::is_dictation_enabled;
+use crate::services::status::get_backend_status_internal;
+use crate::settings::load_settings_internal;
+use crate::settings::profiles::load_profiles_internal;
+use crate::workflow::{
+    cancel_active_dictation, is_dictation_active, last_dictation, start_dictation,
+};
+use speakr_types::{ApiRequest, ApiResponse, ApiStatus, AppError, DEFAULT_API_PORT};
+use std::net::{Ipv4Addr, SocketAddr};
+use tauri::AppHandle;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{debug, info, warn};
+
+/// Serves the local API until the listener fails
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle used to run dictations
+///
+/// # Errors
+///
+/// Returns an error if the port cannot be bound, e.g. because another
+/// Speakr instance is already serving it.
+pub async fn serve_local_api(app_handle: AppHandle) -> std::io::Result<()> {
+    let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, DEFAULT_API_PORT));
+    let listener = TcpListener::bind(addr).await?;
+    info!("Local API listening on {}", addr);
+
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        debug!("Local API connection from {}", peer);
+        let app_handle = app_handle.clone();
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = handle_connection(stream, &app_handle).await {
+                warn!("Local API connection from {} failed: {}", peer, e);
+            }
+        });
+    }
+}
+
+/// Answers each request line on a connection until the client disconnects
+///
+/// A connection that opens with an HTTP `GET` is answered once over HTTP
+/// and closed.
+async fn handle_connection(stream: TcpStream, app_handle: &AppHandle) -> std::io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        if let Some(path) = http_get_path(&line) {
+            let path = path.to_string();
+            // Skip the headers; nothing in them changes the answer
+            while let Some(header) = lines.next_line().await? {
+                if header.trim().is_empty() {
+                    break;
+                }
+            }
+            let response = handle_http_get(&path).await;
+            writer.write_all(response.as_bytes()).await?;
+            return writer.shutdown().await;
+        }
+
+        let response = match parse_request(&line) {
+            Ok(request) => handle_request(request, app_handle).await,
+            Err(response) => response,
+        };
+        let mut json = serde_json::to_string(&response).map_err(std::io::Error::other)?;
+        json.push('\n');
+        writer.write_all(json.as_bytes()).await?;
+    }
+
+    Ok(())
+}
+
+/// Parses one request line, producing an error response for bad input
+fn parse_request(line: &str) -> Result<ApiRequest, ApiResponse> {
+    serde_json::from_str(line.trim())
+        .map_err(|e| ApiResponse::Error(AppError::Command(format!("Invalid API request: {e}"))))
+}
+
+/// Returns the path of an HTTP `GET` request line, without any query string
+fn http_get_path(line: &str) -> Option<&str> {
+    let mut parts = line.split_whitespace();
+    let (Some("GET"), Some(target), Some(version)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return None;
+    };
+    version
+        .starts_with("HTTP/")
+        .then(|| target.split('?').next().unwrap_or(target))
+}
+
+/// Answers a read-only HTTP request; only `/status` exists
+async fn handle_http_get(path: &str) -> String {
+    if path.trim_end_matches('/') != "/status" {
+        return http_response("404 Not Found", r#"{"error":"Not found"}"#);
+    }
+    match current_status().await.and_then(|status| {
+        serde_json::to_string(&status).map_err(|e| AppError::Command(e.to_string()))
+    }) {
+        Ok(json) => http_response("200 OK", &json),
+        Err(e) => {
+            let body = serde_json::json!({ "error": e.to_string() }).to_string();
+            http_response("500 Internal Server Error", &body)
+        }
+    }
+}
+
+/// Formats an HTTP response carrying a JSON body
+fn http_response(status: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+         Connection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+/// Collects the read-only status snapshot
+///
+/// # Errors
+///
+/// Returns `AppError` if the settings cannot be loaded.
+async fn current_status() -> Result<ApiStatus, AppError> {
+    let settings = load_settings_internal().await?;
+    let active_profile = load_profiles_internal()
+        .map(|profiles| profiles.active)
+        .unwrap_or_else(|e| {
+            warn!("Failed to load profiles for the API status: {}", e);
+            None
+        });
+    Ok(ApiStatus {
+        backend: get_backend_status_internal().await?,
+        dictating: is_dictation_active(),
+        paused: !is_dictation_enabled(),
+        active_profile,
+        model: settings.model,
+        last_dictation: last_dictation(),
+    })
+}
+
+/// Performs a request against the running application
+async fn handle_request(request: ApiRequest, app_handle: &AppHandle) -> ApiResponse {
+    debug!(?request, "Handling local API request");
+    match request {
+        ApiRequest::StartDictation => {
+            if start_dictation(app_handle) {
+                ApiResponse::DictationStarted
+            } else {
+                ApiResponse::Error(AppError::Command(
+                    "A dictation is already in progress".to_string(),
+                ))
+            }
+        }
+        ApiRequest::CancelDictation => {
+            ApiResponse::DictationCancelled(cancel_active_dictation(app_handle))
+        }
+        ApiRequest::LastTranscript => ApiResponse::Transcript(last_transcription()),
+        ApiRequest::Status => match current_status().await {
+            Ok(status) => ApiResponse::Status(Box::new(status)),
+            Err(e) => ApiResponse::Error(e),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_request_accepts_json_lines() {
+        assert_eq!(
+            parse_request("\"LastTranscript\"\r\n"),
+            Ok(ApiRequest::LastTranscript)
+        );
+    }
+
+    #[test]
+    fn test_http_get_path() {
+        assert_eq!(http_get_path("GET /status HTTP/1.1"), Some("/status"));
+        assert_eq!(http_get_path("GET /status?t=1 HTTP/1.0\r"), Some("/status"));
+        assert_eq!(http_get_path("POST /status HTTP/1.1"), None);
+        assert_eq!(http_get_path("\"Status\""), None);
+    }
+
+    #[test]
+    fn test_http_response_sets_the_content_length() {
+        let response = http_response("200 OK", "{}");
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Content-Length: 2\r\n"));
+        assert!(response.ends_with("\r\n\r\n{}"));
+    }
+
+    #[test]
+    fn test_parse_request_rejects_unknown_requests() {
+        assert!(matches!(
+            parse_request("\"Shutdown\""),
+            Err(ApiResponse::Error(AppError::Command(_)))
+        ));
+    }
+}
@@ -3,8 +3,33 @@
 // ============================================================================
 //!
 //! This module contains service implementations for:
+//! - **Auto-launch** - Registers Speakr to start at login on each platform
+//! - **Batch transcription** - Job queue that transcribes a folder of recordings
+//! - **History** - Recent dictations with user corrections and vocabulary suggestions,
+//!   optionally encrypted at rest
 //! - **Global hotkey management** - Handles system-wide keyboard shortcuts
+//! - **Input preview** - Live level of a microphone while its input gain is adjusted
 //! - **Backend status tracking** - Monitors service component health and readiness
+//! - **Connectivity** - Offline detection shared by network features
+//! - **Dictation sessions** - Append mode joining consecutive dictations into one block
+//! - **Dock badge** - Optional Dock icon indicator while recording/transcribing
+//! - **Event throttling** - Rate-limits high-frequency events sent to the UI
+//! - **Last transcription** - The latest dictation, kept so it can be typed again
+//! - **Local API** - Opt-in loopback API for driving dictation from other tools
+//! - **Model manager** - Keeps the selected Whisper model loaded between dictations
+//! - **Notifications** - System notifications when a dictation finishes in the background or fails
+//! - **Pause** - Temporarily disables dictation and its hot-keys without quitting
+//! - **Pending** - Queue of undelivered dictations waiting for review
+//! - **Privacy** - Temporary mode that keeps transcripts out of history and logs
+//! - **Performance** - Rolling latency metrics of recent dictations
+//! - **Profiles** - Applies a switched settings profile to the running app
+//! - **Secrets** - Sensitive values such as encryption keys, kept in the keychain
+//! - **Shortcuts portal** - Global hot-keys under Wayland, bound through the desktop portal
+//! - **Target application** - Detects the app receiving dictated text
+//! - **Tray icon** - Menu-bar icon with pause and privacy toggles, a profile switcher and an
+//!   action ending the dictation session; its tooltip reports progress
+//! - **Watchdog** - Restarts services that report an error, with backoff
+//! - **Window state** - Reopens windows where the user left them
 //! - **Service component types** - Shared enums and types across services
 //!
 //! # Service Architecture
@@ -21,17 +46,43 @@
 //! multiple contexts (frontend events, background tasks, tests) without
 //! data races or corruption.
 
+pub mod auto_launch;
+pub mod batch;
+pub mod connectivity;
+pub mod dictation_session;
+pub mod dock_badge;
+pub mod event_throttle;
+pub mod history;
 pub mod hotkey;
+pub mod input_preview;
+pub mod last_transcription;
+pub mod local_api;
+pub mod model_manager;
+pub mod notifications;
+pub mod pause;
+pub mod pending;
+pub mod performance;
+pub mod privacy;
+pub mod profiles;
+pub mod secrets;
+#[cfg(target_os = "linux")]
+pub mod shortcut_portal;
 pub mod status;
+pub mod target_app;
+pub mod tray;
 pub mod types;
+pub mod watchdog;
+#[cfg(desktop)]
+pub mod window_state;
 
 // Re-export types that need to be public across modules
 pub use types::ServiceComponent;
 
 // Re-export status functions needed by lib.rs and tests
 pub use status::{
-    get_backend_status_internal, get_global_backend_service, update_global_service_status,
-    update_service_status_internal, BackendStatusService,
+    attach_status_events, get_backend_status_internal, get_global_backend_service,
+    update_global_service_status, update_platform_limitation, update_service_status_internal,
+    BackendStatusService,
 };
 
 // Re-export reset function for tests
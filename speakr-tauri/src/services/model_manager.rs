@@ -0,0 +1,306 @@
+// ============================================================================
+//! Resident Whisper Model
+// ============================================================================
+//!
+//! Loading a large Whisper model takes seconds, so the selected model is
+//! loaded at startup and kept resident between dictations. It is reloaded
+//! when the `model` setting changes and, unless the user opted out,
+//! unloaded after a period without dictations to give the memory back. The
+//! next dictation after an unload loads the model again.
+//!
+//! Every load verifies the model file's checksum (hashing it only when the
+//! file has changed since it last passed). A corrupt file is reported on
+//! [`CORRUPT_MODEL_EVENT`] so the UI can offer to download it again.
+
+use speakr_core::transcription::engine::TranscriptionEngine;
+use speakr_types::{
+    AppError, AppSettings, ModelId, TranscriptionConfig, TranscriptionError, CORRUPT_MODEL_EVENT,
+};
+use std::sync::{LazyLock, Mutex, MutexGuard, OnceLock, PoisonError};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+use tracing::{debug, info, warn};
+
+/// How often the idle timer checks whether the model should be unloaded.
+const IDLE_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// A loaded engine and when a dictation last used it.
+struct LoadedModel {
+    /// Model that was requested (the engine may have fallen back to a
+    /// smaller one to fit in memory).
+    model: ModelId,
+    engine: TranscriptionEngine,
+    last_used: Instant,
+}
+
+/// Keeps one Whisper model loaded between dictations
+#[derive(Default)]
+pub struct ModelManager {
+    loaded: Option<LoadedModel>,
+    idle_unload: Option<Duration>,
+}
+
+impl ModelManager {
+    /// Creates a manager with nothing loaded
+    ///
+    /// # Arguments
+    ///
+    /// * `idle_unload` - Idle time before the model is unloaded, or `None`
+    ///   to keep it loaded
+    pub fn new(idle_unload: Option<Duration>) -> Self {
+        Self {
+            loaded: None,
+            idle_unload,
+        }
+    }
+
+    /// Returns the requested model of the loaded engine, if any
+    pub fn loaded_model(&self) -> Option<&ModelId> {
+        self.loaded.as_ref().map(|loaded| &loaded.model)
+    }
+
+    /// Updates the idle time before the model is unloaded
+    pub fn set_idle_unload(&mut self, idle_unload: Option<Duration>) {
+        self.idle_unload = idle_unload;
+    }
+
+    /// Keeps `engine` resident as the engine for `model`, replacing any other
+    pub fn insert(&mut self, model: ModelId, engine: TranscriptionEngine, now: Instant) {
+        self.loaded = Some(LoadedModel {
+            model,
+            engine,
+            last_used: now,
+        });
+    }
+
+    /// Returns the loaded engine set up for one dictation
+    ///
+    /// # Returns
+    ///
+    /// A copy of the engine with the per-dictation options of `config`
+    /// applied, or `None` if no engine for `config.model` is loaded.
+    pub fn engine_for(
+        &mut self,
+        config: &TranscriptionConfig,
+        now: Instant,
+    ) -> Option<TranscriptionEngine> {
+        let loaded = self
+            .loaded
+            .as_mut()
+            .filter(|loaded| loaded.model == config.model)?;
+        loaded.last_used = now;
+
+        let mut engine = loaded.engine.clone();
+        engine.apply_options(config);
+        Some(engine)
+    }
+
+    /// Unloads the model if it has been idle for longer than allowed
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if a model was unloaded.
+    pub fn unload_if_idle(&mut self, now: Instant) -> bool {
+        let Some(idle_unload) = self.idle_unload else {
+            return false;
+        };
+        let is_idle = self
+            .loaded
+            .as_ref()
+            .is_some_and(|loaded| now.saturating_duration_since(loaded.last_used) >= idle_unload);
+        if is_idle {
+            self.loaded = None;
+        }
+        is_idle
+    }
+}
+
+/// Model kept resident for dictations.
+static GLOBAL_MODEL_MANAGER: LazyLock<Mutex<ModelManager>> =
+    LazyLock::new(|| Mutex::new(ModelManager::default()));
+
+fn global_model_manager() -> MutexGuard<'static, ModelManager> {
+    GLOBAL_MODEL_MANAGER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+// Application handle corrupt models are reported through
+static MODEL_EVENTS: OnceLock<AppHandle> = OnceLock::new();
+
+/// Emits [`CORRUPT_MODEL_EVENT`] whenever a later load finds a corrupt model.
+///
+/// Call once during application setup; later calls are ignored.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle to emit through
+pub fn attach_model_events(app_handle: AppHandle) {
+    let _ = MODEL_EVENTS.set(app_handle);
+}
+
+/// Loads the model for `config` off the async runtime and keeps it resident.
+async fn load_resident_engine(
+    config: TranscriptionConfig,
+) -> Result<TranscriptionEngine, TranscriptionError> {
+    let model = config.model.clone();
+    let started = Instant::now();
+    let engine = tokio::task::spawn_blocking(move || TranscriptionEngine::with_config(config))
+        .await
+        .map_err(|e| TranscriptionError::ProcessingFailed(e.to_string()))?
+        .inspect_err(|e| {
+            if let (TranscriptionError::CorruptModel { model }, Some(app_handle)) =
+                (e, MODEL_EVENTS.get())
+            {
+                if let Err(e) = app_handle.emit(CORRUPT_MODEL_EVENT, model) {
+                    warn!("Failed to report corrupt model: {}", e);
+                }
+            }
+        })?;
+
+    info!(model = %model, elapsed = ?started.elapsed(), "Whisper model loaded");
+    global_model_manager().insert(model, engine.clone(), Instant::now());
+    Ok(engine)
+}
+
+/// Loads the selected model so the first dictation does not wait for it
+///
+/// # Arguments
+///
+/// * `settings` - The user's settings (model and idle-unload time)
+///
+/// # Errors
+///
+/// Returns `AppError::Transcription` if the model cannot be loaded.
+pub async fn warm_load_model(settings: &AppSettings) -> Result<(), AppError> {
+    global_model_manager().set_idle_unload(settings.model_idle_unload());
+    load_resident_engine(TranscriptionConfig::from_settings(settings))
+        .await
+        .map(|_| ())
+        .map_err(|e| AppError::Transcription(e.to_string()))
+}
+
+/// Applies saved settings to the resident model
+///
+/// Updates the idle-unload time and, if a different model is loaded,
+/// replaces it with the newly selected one. Nothing is loaded if the model
+/// was already unloaded for being idle.
+///
+/// # Errors
+///
+/// Returns `AppError::Transcription` if the new model cannot be loaded.
+pub async fn apply_model_settings(settings: &AppSettings) -> Result<(), AppError> {
+    let config = TranscriptionConfig::from_settings(settings);
+    let needs_reload = {
+        let mut manager = global_model_manager();
+        manager.set_idle_unload(settings.model_idle_unload());
+        manager
+            .loaded_model()
+            .is_some_and(|model| *model != config.model)
+    };
+    if !needs_reload {
+        return Ok(());
+    }
+
+    info!(model = %config.model, "Model changed, reloading");
+    load_resident_engine(config)
+        .await
+        .map(|_| ())
+        .map_err(|e| AppError::Transcription(e.to_string()))
+}
+
+/// Returns an engine for a dictation, loading the model if it is not resident
+///
+/// # Errors
+///
+/// Returns `TranscriptionError` if the model has to be loaded and loading
+/// fails.
+pub async fn resident_engine(
+    config: &TranscriptionConfig,
+) -> Result<TranscriptionEngine, TranscriptionError> {
+    if let Some(engine) = global_model_manager().engine_for(config, Instant::now()) {
+        debug!("Using resident Whisper model");
+        return Ok(engine);
+    }
+    load_resident_engine(config.clone()).await
+}
+
+/// Spawns the timer that unloads the model after the configured idle time
+pub fn spawn_idle_unloader() {
+    tauri::async_runtime::spawn(async {
+        let mut interval = tokio::time::interval(IDLE_CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            if global_model_manager().unload_if_idle(Instant::now()) {
+                info!("Unloaded idle Whisper model");
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use speakr_core::transcription::models::ModelManager as ModelFiles;
+    use tempfile::TempDir;
+
+    fn small_engine(dir: &TempDir) -> TranscriptionEngine {
+        std::fs::write(dir.path().join("ggml-small.bin"), []).unwrap();
+        let config = TranscriptionConfig {
+            model: ModelId::new("small"),
+            ..Default::default()
+        };
+        TranscriptionEngine::with_config_and_manager(
+            config,
+            ModelFiles::with_cache_dir(dir.path().to_path_buf()),
+        )
+        .expect("engine init")
+    }
+
+    #[test]
+    fn test_engine_for_requires_matching_model() {
+        let dir = TempDir::new().unwrap();
+        let start = Instant::now();
+        let mut manager = ModelManager::new(None);
+        manager.insert(ModelId::new("small"), small_engine(&dir), start);
+
+        let dictation = TranscriptionConfig {
+            model: ModelId::new("small"),
+            language: Some("de".to_string()),
+            auto_detect_language: false,
+            ..Default::default()
+        };
+        let engine = manager.engine_for(&dictation, start).expect("model loaded");
+        assert_eq!(engine.config().language.as_deref(), Some("de"));
+
+        let other_size = TranscriptionConfig {
+            model: ModelId::new("medium"),
+            ..Default::default()
+        };
+        assert!(manager.engine_for(&other_size, start).is_none());
+    }
+
+    #[test]
+    fn test_unloads_only_after_idle_time() {
+        let dir = TempDir::new().unwrap();
+        let start = Instant::now();
+        let idle = Duration::from_secs(600);
+        let mut manager = ModelManager::new(Some(idle));
+        manager.insert(ModelId::new("small"), small_engine(&dir), start);
+
+        // Using the model restarts the idle timer
+        let dictation = TranscriptionConfig {
+            model: ModelId::new("small"),
+            ..Default::default()
+        };
+        let used = start + Duration::from_secs(300);
+        assert!(manager.engine_for(&dictation, used).is_some());
+        assert!(!manager.unload_if_idle(start + idle));
+        assert!(manager.unload_if_idle(used + idle));
+        assert_eq!(manager.loaded_model(), None);
+
+        let mut resident = ModelManager::new(None);
+        resident.insert(ModelId::new("small"), small_engine(&dir), start);
+        assert!(!resident.unload_if_idle(start + idle * 10));
+    }
+}
@@ -0,0 +1,163 @@
+// ============================================================================
+//! Dictation Notifications
+// ============================================================================
+//!
+//! Posts a system notification when a dictation finishes while Speakr is in
+//! the background, and whenever a dictation fails, so the user learns the
+//! outcome without switching to the app. Both are controlled by
+//! [`AppSettings::notifications`]; the dictated text is only included when
+//! [`AppSettings::notification_text`] is on, and never in privacy mode.
+//!
+//! A dictation counts as in the background unless the main window is both
+//! visible and focused, which is the usual case: the user is dictating into
+//! another application.
+//!
+//! [`AppSettings::notifications`]: speakr_types::AppSettings::notifications
+//! [`AppSettings::notification_text`]: speakr_types::AppSettings::notification_text
+
+use crate::services::privacy::is_privacy_mode_active;
+use speakr_types::AppError;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_notification::NotificationExt;
+use tracing::{debug, warn};
+
+/// Label of the window whose visibility decides whether Speakr is in view.
+const MAIN_WINDOW_LABEL: &str = "main";
+
+/// Longest excerpt of dictated text shown in a notification.
+pub const NOTIFICATION_PREVIEW_CHARS: usize = 120;
+
+/// Shortens text for a notification body
+///
+/// Text longer than [`NOTIFICATION_PREVIEW_CHARS`] is cut off with an
+/// ellipsis.
+pub fn notification_preview(text: &str) -> String {
+    let preview: String = text.chars().take(NOTIFICATION_PREVIEW_CHARS).collect();
+    if preview.len() < text.len() {
+        format!("{preview}…")
+    } else {
+        preview
+    }
+}
+
+/// Body of the notification for a finished dictation
+///
+/// # Arguments
+///
+/// * `text` - The dictated text
+/// * `include_text` - Whether the text may be shown; otherwise only the
+///   number of words is
+pub fn finished_body(text: &str, include_text: bool) -> String {
+    if include_text {
+        return notification_preview(text);
+    }
+    match text.split_whitespace().count() {
+        1 => "1 word dictated".to_string(),
+        words => format!("{words} words dictated"),
+    }
+}
+
+/// Handle that reports the outcome of a dictation as a system notification
+pub struct DictationNotifier {
+    app_handle: Option<AppHandle>,
+    include_text: bool,
+}
+
+impl DictationNotifier {
+    /// Creates a notifier
+    ///
+    /// # Arguments
+    ///
+    /// * `app_handle` - The Tauri application handle
+    /// * `enabled` - Whether the user has turned notifications on; a disabled
+    ///   notifier does nothing
+    /// * `include_text` - Whether the "finished" notification shows the text
+    pub fn new(app_handle: &AppHandle, enabled: bool, include_text: bool) -> Self {
+        Self {
+            app_handle: enabled.then(|| app_handle.clone()),
+            include_text,
+        }
+    }
+
+    /// Reports a finished dictation, unless Speakr is in view
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The dictated text
+    pub fn finished(&self, text: &str) {
+        let Some(app_handle) = &self.app_handle else {
+            return;
+        };
+        if is_in_view(app_handle) {
+            debug!("Speakr is in view, skipping dictation notification");
+            return;
+        }
+        let include_text = self.include_text && !is_privacy_mode_active();
+        show(
+            app_handle,
+            "Dictation finished",
+            &finished_body(text, include_text),
+        );
+    }
+
+    /// Reports a dictation that failed
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - Why the dictation failed
+    pub fn failed(&self, error: &AppError) {
+        if let Some(app_handle) = &self.app_handle {
+            show(app_handle, "Dictation failed", &error.to_string());
+        }
+    }
+}
+
+/// Whether the main window is visible and focused
+fn is_in_view(app_handle: &AppHandle) -> bool {
+    app_handle
+        .get_webview_window(MAIN_WINDOW_LABEL)
+        .is_some_and(|window| {
+            window.is_visible().unwrap_or(false) && window.is_focused().unwrap_or(false)
+        })
+}
+
+/// Shows a notification
+///
+/// Failures are logged and otherwise ignored: a notification must never
+/// interrupt a dictation.
+fn show(app_handle: &AppHandle, title: &str, body: &str) {
+    if let Err(e) = app_handle
+        .notification()
+        .builder()
+        .title(title)
+        .body(body)
+        .show()
+    {
+        warn!("Failed to show notification '{}': {}", title, e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preview_keeps_short_text() {
+        assert_eq!(notification_preview("Hello world."), "Hello world.");
+    }
+
+    #[test]
+    fn test_preview_cuts_long_text() {
+        let text = "é".repeat(NOTIFICATION_PREVIEW_CHARS + 5);
+        let preview = notification_preview(&text);
+        assert!(preview.ends_with('…'));
+        assert_eq!(preview.chars().count(), NOTIFICATION_PREVIEW_CHARS + 1);
+    }
+
+    #[test]
+    fn test_finished_body_hides_text_unless_asked() {
+        assert_eq!(finished_body("Call me back", false), "3 words dictated");
+        assert_eq!(finished_body("Hi", false), "1 word dictated");
+        assert_eq!(finished_body("Call me back", true), "Call me back");
+    }
+}
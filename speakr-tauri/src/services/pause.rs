@@ -0,0 +1,127 @@
+// ============================================================================
+//! Dictation Pause
+// ============================================================================
+//!
+//! Lets users switch Speakr off temporarily (e.g. while screen sharing or
+//! gaming) without quitting. While paused, every global hot-key is
+//! unregistered so the key combinations reach other applications, no
+//! dictation can start, and all services report
+//! [`ServiceStatus::Unavailable`].
+//!
+//! Resuming registers the hot-keys from settings again and restores the
+//! statuses reported before the pause. The paused state is not persisted;
+//! Speakr always starts enabled.
+
+use crate::services::hotkey::{register_hotkeys_from_settings, release_all_hotkeys};
+use crate::services::tray::set_tray_dictation_enabled;
+use crate::services::{get_global_backend_service, update_global_service_status, ServiceComponent};
+use crate::settings::load_settings_internal;
+use crate::workflow::cancel_active_dictation;
+use speakr_types::{AppError, BackendStatus, ServiceStatus};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{LazyLock, Mutex, PoisonError};
+use tauri::AppHandle;
+use tracing::info;
+
+/// Whether dictation is currently enabled.
+static DICTATION_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Service statuses reported when dictation was paused, restored on resume.
+static STATUS_BEFORE_PAUSE: LazyLock<Mutex<Option<BackendStatus>>> =
+    LazyLock::new(|| Mutex::new(None));
+
+/// Service components reported as unavailable while paused.
+const COMPONENTS: [ServiceComponent; 3] = [
+    ServiceComponent::AudioCapture,
+    ServiceComponent::Transcription,
+    ServiceComponent::TextInjection,
+];
+
+/// Returns `false` while dictation is paused
+pub fn is_dictation_enabled() -> bool {
+    DICTATION_ENABLED.load(Ordering::SeqCst)
+}
+
+/// Pauses or resumes dictation
+///
+/// Pausing cancels the dictation in flight, unregisters all global hot-keys
+/// and marks every service unavailable. Resuming re-registers the hot-keys
+/// from settings and restores the previous service statuses. Either way the
+/// tray menu and tooltip are updated.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+/// * `enabled` - `false` to pause, `true` to resume
+///
+/// # Errors
+///
+/// Returns `AppError::HotKey` if the hot-keys cannot be unregistered when
+/// pausing, or an `AppError` if the settings cannot be loaded when resuming.
+/// Dictation stays in its previous state on error.
+pub async fn set_dictation_enabled_internal(
+    app_handle: &AppHandle,
+    enabled: bool,
+) -> Result<(), AppError> {
+    if DICTATION_ENABLED.swap(enabled, Ordering::SeqCst) == enabled {
+        return Ok(());
+    }
+
+    let result = if enabled {
+        resume(app_handle).await
+    } else {
+        pause(app_handle).await
+    };
+    if let Err(e) = result {
+        DICTATION_ENABLED.store(!enabled, Ordering::SeqCst);
+        return Err(e);
+    }
+
+    set_tray_dictation_enabled(app_handle);
+    Ok(())
+}
+
+/// Releases the hot-keys and reports every service as unavailable
+async fn pause(app_handle: &AppHandle) -> Result<(), AppError> {
+    cancel_active_dictation(app_handle);
+    release_all_hotkeys(app_handle)?;
+
+    let service = get_global_backend_service().await;
+    let status = service
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get_current_status();
+    *STATUS_BEFORE_PAUSE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = Some(status);
+    for component in COMPONENTS {
+        update_global_service_status(component, ServiceStatus::Unavailable).await;
+    }
+
+    info!("⏸️ Dictation paused");
+    Ok(())
+}
+
+/// Registers the hot-keys from settings and restores the service statuses
+async fn resume(app_handle: &AppHandle) -> Result<(), AppError> {
+    let settings = load_settings_internal().await?;
+    register_hotkeys_from_settings(app_handle, &settings).await;
+
+    let previous = STATUS_BEFORE_PAUSE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take();
+    if let Some(previous) = previous {
+        let statuses = [
+            previous.audio_capture,
+            previous.transcription,
+            previous.text_injection,
+        ];
+        for (component, status) in COMPONENTS.into_iter().zip(statuses) {
+            update_global_service_status(component, status).await;
+        }
+    }
+
+    info!("▶️ Dictation resumed");
+    Ok(())
+}
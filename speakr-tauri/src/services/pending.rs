@@ -0,0 +1,470 @@
+// ============================================================================
+//! Pending Dictations
+// ============================================================================
+//!
+//! Dictations that were not delivered wait here for review: those taken in
+//! dry-run mode and those whose text injection failed. The queue is stored
+//! in `pending.json` next to `settings.json`, so it survives restarts, and
+//! its length is shown as a badge on the tray icon.
+//!
+//! Dictations taken while privacy mode is on, and those held back because
+//! a password field had focus, are never written to disk: they are held in
+//! memory and are gone when Speakr quits.
+//!
+//! Users edit, inject or discard each dictation from the Pending view. Every
+//! change is emitted on [`PENDING_CHANGED_EVENT`] with the whole queue.
+
+use crate::injection::injection_suppression;
+use crate::services::privacy::is_privacy_mode_active;
+use crate::services::target_app::frontmost_app_id;
+use crate::services::tray::set_tray_pending_count;
+use crate::settings::{get_settings_dir, load_settings_internal};
+use crate::workflow::inject_text;
+use speakr_types::{AppError, PendingDictation, PendingReason, PENDING_CHANGED_EVENT};
+use std::fs;
+use std::path::Path;
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+use tracing::{info, warn};
+
+/// Name of the file holding the queue.
+const PENDING_FILE: &str = "pending.json";
+
+/// Label of the window hidden before injecting, so the text reaches the app
+/// the user was working in.
+const MAIN_WINDOW_LABEL: &str = "main";
+
+/// Time for focus to return to that app after the window is hidden.
+const FOCUS_RETURN_DELAY: Duration = Duration::from_millis(300);
+
+/// Dictations held in memory only; holding the lock also serialises
+/// read-modify-write cycles of the queue file.
+static PENDING_LOCK: Mutex<Vec<PendingDictation>> = Mutex::new(Vec::new());
+
+/// Loads the queue from a specific directory.
+///
+/// # Returns
+///
+/// Returns the pending dictations, oldest first; none if the file does not
+/// exist.
+///
+/// # Errors
+///
+/// Returns `AppError::Io` if the file cannot be read, or
+/// `AppError::Settings` if it cannot be parsed.
+pub fn load_pending_from_dir(dir: &Path) -> Result<Vec<PendingDictation>, AppError> {
+    let path = dir.join(PENDING_FILE);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path)
+        .map_err(|e| AppError::io("Failed to read pending dictations", &e, Some(&path)))?;
+    serde_json::from_str(&content)
+        .map_err(|e| AppError::Settings(format!("Failed to parse pending dictations: {e}")))
+}
+
+/// Saves the queue to a specific directory.
+///
+/// # Errors
+///
+/// Returns `AppError::Io` if the file cannot be written.
+pub fn save_pending_to_dir(pending: &[PendingDictation], dir: &Path) -> Result<(), AppError> {
+    let json = serde_json::to_string_pretty(pending)
+        .map_err(|e| AppError::Settings(format!("Failed to serialize pending dictations: {e}")))?;
+    let path = dir.join(PENDING_FILE);
+    fs::write(&path, json)
+        .map_err(|e| AppError::io("Failed to write pending dictations", &e, Some(&path)))
+}
+
+/// Waits for a write of the queue in progress to finish
+///
+/// Called when Speakr shuts down, so a dictation being queued is not lost.
+pub fn flush_pending() {
+    drop(PENDING_LOCK.lock().unwrap_or_else(PoisonError::into_inner));
+}
+
+/// Lists the saved dictations together with those held in memory, oldest
+/// first.
+fn merge_pending(
+    saved: &[PendingDictation],
+    in_memory: &[PendingDictation],
+) -> Vec<PendingDictation> {
+    let mut pending = [saved, in_memory].concat();
+    pending.sort_by_key(|item| item.created_at);
+    pending
+}
+
+/// Lists the dictations pending in `dir` and in `in_memory`, oldest first.
+///
+/// # Errors
+///
+/// See [`load_pending_from_dir`].
+pub fn pending_in_dir(
+    dir: &Path,
+    in_memory: &[PendingDictation],
+) -> Result<Vec<PendingDictation>, AppError> {
+    Ok(merge_pending(&load_pending_from_dir(dir)?, in_memory))
+}
+
+/// Loads the queue from `dir`, changes it and the dictations held in
+/// memory, and saves the file back if it changed.
+fn modify_pending_in_dir<T>(
+    dir: &Path,
+    in_memory: &mut Vec<PendingDictation>,
+    change: impl FnOnce(&mut Vec<PendingDictation>, &mut Vec<PendingDictation>) -> Result<T, AppError>,
+) -> Result<(T, Vec<PendingDictation>), AppError> {
+    let loaded = load_pending_from_dir(dir)?;
+    let mut saved = loaded.clone();
+    let result = change(&mut saved, in_memory)?;
+    if saved != loaded {
+        save_pending_to_dir(&saved, dir)?;
+    }
+    Ok((result, merge_pending(&saved, in_memory)))
+}
+
+/// Adds a dictation to the end of the queue in `dir`, or to `in_memory`
+/// unless `keep_on_disk` is set.
+///
+/// # Returns
+///
+/// Returns the updated queue.
+///
+/// # Errors
+///
+/// Returns an `AppError` if the queue cannot be read or written.
+pub fn add_pending_in_dir(
+    dir: &Path,
+    in_memory: &mut Vec<PendingDictation>,
+    text: &str,
+    reason: PendingReason,
+    keep_on_disk: bool,
+) -> Result<Vec<PendingDictation>, AppError> {
+    modify_pending_in_dir(dir, in_memory, |saved, in_memory| {
+        let id = saved
+            .iter()
+            .chain(in_memory.iter())
+            .map(|item| item.id)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let item = PendingDictation {
+            id,
+            text: text.to_string(),
+            created_at: chrono::Utc::now().timestamp_millis() as u64,
+            reason,
+        };
+        if keep_on_disk {
+            saved.push(item);
+        } else {
+            in_memory.push(item);
+        }
+        Ok(())
+    })
+    .map(|((), pending)| pending)
+}
+
+/// Replaces the text of a pending dictation in `dir` or `in_memory`.
+///
+/// # Returns
+///
+/// Returns the updated queue.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if no dictation has that id, or an
+/// `AppError` if the queue cannot be read or written.
+pub fn update_pending_in_dir(
+    dir: &Path,
+    in_memory: &mut Vec<PendingDictation>,
+    id: u64,
+    text: &str,
+) -> Result<Vec<PendingDictation>, AppError> {
+    modify_pending_in_dir(dir, in_memory, |saved, in_memory| {
+        let item = in_memory
+            .iter_mut()
+            .chain(saved.iter_mut())
+            .find(|item| item.id == id)
+            .ok_or_else(|| not_found(id))?;
+        item.text = text.to_string();
+        Ok(())
+    })
+    .map(|((), pending)| pending)
+}
+
+/// Removes a dictation from the queue in `dir` or from `in_memory`.
+///
+/// # Returns
+///
+/// Returns the removed dictation and the updated queue.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if no dictation has that id, or an
+/// `AppError` if the queue cannot be read or written.
+pub fn remove_pending_in_dir(
+    dir: &Path,
+    in_memory: &mut Vec<PendingDictation>,
+    id: u64,
+) -> Result<(PendingDictation, Vec<PendingDictation>), AppError> {
+    modify_pending_in_dir(dir, in_memory, |saved, in_memory| {
+        for queue in [in_memory, saved] {
+            if let Some(index) = queue.iter().position(|item| item.id == id) {
+                return Ok(queue.remove(index));
+            }
+        }
+        Err(not_found(id))
+    })
+}
+
+fn not_found(id: u64) -> AppError {
+    AppError::Settings(format!("No pending dictation with id {id}"))
+}
+
+/// Tells the UI and the tray icon about a changed queue.
+fn publish(app_handle: &AppHandle, pending: &[PendingDictation]) {
+    set_tray_pending_count(app_handle, pending.len());
+    if let Err(e) = app_handle.emit(PENDING_CHANGED_EVENT, pending) {
+        warn!("Failed to emit pending dictations: {}", e);
+    }
+}
+
+/// Holds an undelivered dictation for review
+///
+/// While privacy mode is on, or when secure input suppressed typing, the
+/// dictation is held in memory only. Failures are logged; the dictation
+/// itself has already finished.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+/// * `text` - The transcript
+/// * `reason` - Why it was not delivered
+pub fn queue_pending(app_handle: &AppHandle, text: &str, reason: PendingReason) {
+    let keep_on_disk =
+        !is_privacy_mode_active() && !matches!(reason, PendingReason::InjectionSuppressed(_));
+    let queued = get_settings_dir().and_then(|dir| {
+        let mut in_memory = PENDING_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        add_pending_in_dir(&dir, &mut in_memory, text, reason, keep_on_disk)
+    });
+    match queued {
+        Ok(pending) => {
+            let place = if keep_on_disk { "" } else { " in memory" };
+            info!(
+                "📥 Dictation held{} for review ({} pending)",
+                place,
+                pending.len()
+            );
+            publish(app_handle, &pending);
+        }
+        Err(e) => warn!("Failed to hold dictation for review: {}", e),
+    }
+}
+
+/// Shows the number of pending dictations on the tray icon at startup
+pub fn restore_pending_badge(app_handle: &AppHandle) {
+    match list_pending_internal() {
+        Ok(pending) => set_tray_pending_count(app_handle, pending.len()),
+        Err(e) => warn!("Failed to load pending dictations: {}", e),
+    }
+}
+
+/// Lists the pending dictations, oldest first.
+///
+/// # Errors
+///
+/// See [`load_pending_from_dir`].
+pub fn list_pending_internal() -> Result<Vec<PendingDictation>, AppError> {
+    let dir = get_settings_dir()?;
+    let in_memory = PENDING_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    pending_in_dir(&dir, &in_memory)
+}
+
+/// Saves an edited pending dictation.
+///
+/// # Errors
+///
+/// See [`update_pending_in_dir`].
+pub fn update_pending_internal(
+    app_handle: &AppHandle,
+    id: u64,
+    text: &str,
+) -> Result<Vec<PendingDictation>, AppError> {
+    let dir = get_settings_dir()?;
+    let pending = {
+        let mut in_memory = PENDING_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        update_pending_in_dir(&dir, &mut in_memory, id, text)?
+    };
+    publish(app_handle, &pending);
+    Ok(pending)
+}
+
+/// Discards a pending dictation.
+///
+/// # Errors
+///
+/// See [`remove_pending_in_dir`].
+pub fn discard_pending_internal(
+    app_handle: &AppHandle,
+    id: u64,
+) -> Result<Vec<PendingDictation>, AppError> {
+    let dir = get_settings_dir()?;
+    let (_, pending) = {
+        let mut in_memory = PENDING_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        remove_pending_in_dir(&dir, &mut in_memory, id)?
+    };
+    publish(app_handle, &pending);
+    Ok(pending)
+}
+
+/// Types a pending dictation into the app the user was working in.
+///
+/// The dictation leaves the queue once it has been injected; if injection
+/// fails it stays queued.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if no dictation has that id, or
+/// `AppError::TextInjection` if the text cannot be typed (see
+/// [`inject_into_working_app`]).
+pub async fn inject_pending_internal(
+    app_handle: &AppHandle,
+    id: u64,
+) -> Result<Vec<PendingDictation>, AppError> {
+    let dir = get_settings_dir()?;
+    let item = list_pending_internal()?
+        .into_iter()
+        .find(|item| item.id == id)
+        .ok_or_else(|| not_found(id))?;
+
+    inject_into_working_app(app_handle, &item.text).await?;
+
+    let (_, pending) = {
+        let mut in_memory = PENDING_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        remove_pending_in_dir(&dir, &mut in_memory, id)?
+    };
+    publish(app_handle, &pending);
+    Ok(pending)
+}
+
+/// Types text into the app the user was working in, outside a dictation.
+///
+/// If the Speakr window has focus it is hidden first, so focus returns to
+/// that app. The app's profile decides how the text is injected. Also used
+/// to type the last dictation again.
+///
+/// # Errors
+///
+/// Returns `AppError::TextInjection` if the text cannot be typed, the app is
+/// on the injection blocklist or secure input is on.
+pub(crate) async fn inject_into_working_app(
+    app_handle: &AppHandle,
+    text: &str,
+) -> Result<(), AppError> {
+    if let Some(window) = app_handle.get_webview_window(MAIN_WINDOW_LABEL) {
+        if window.is_focused().unwrap_or(false) {
+            if let Err(e) = window.hide() {
+                warn!("Failed to hide window before injecting: {}", e);
+            }
+            tokio::time::sleep(FOCUS_RETURN_DELAY).await;
+        }
+    }
+
+    if let Some(reason) = injection_suppression() {
+        return Err(AppError::TextInjection(reason));
+    }
+    let target_app = frontmost_app_id();
+    let settings = load_settings_internal().await.unwrap_or_default();
+    if settings.blocks_injection(target_app.as_deref()) {
+        return Err(AppError::TextInjection(
+            "Speakr does not type into this app; copy the dictation instead".to_string(),
+        ));
+    }
+    let options = settings.injection_for(target_app.as_deref());
+    inject_text(options.finish(text), options, app_handle).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_pending_queue_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let mut in_memory = Vec::new();
+        assert!(load_pending_from_dir(dir.path()).unwrap().is_empty());
+
+        add_pending_in_dir(
+            dir.path(),
+            &mut in_memory,
+            "first",
+            PendingReason::DryRun,
+            true,
+        )
+        .unwrap();
+        let pending = add_pending_in_dir(
+            dir.path(),
+            &mut in_memory,
+            "second",
+            PendingReason::InjectionFailed("no focus".to_string()),
+            true,
+        )
+        .unwrap();
+        assert_eq!(pending.len(), 2);
+        assert_ne!(pending[0].id, pending[1].id);
+
+        let pending =
+            update_pending_in_dir(dir.path(), &mut in_memory, pending[1].id, "edited").unwrap();
+        assert_eq!(pending[1].text, "edited");
+
+        let (removed, pending) =
+            remove_pending_in_dir(dir.path(), &mut in_memory, pending[0].id).unwrap();
+        assert_eq!(removed.text, "first");
+        assert_eq!(load_pending_from_dir(dir.path()).unwrap(), pending);
+
+        assert!(matches!(
+            remove_pending_in_dir(dir.path(), &mut in_memory, removed.id),
+            Err(AppError::Settings(_))
+        ));
+    }
+
+    #[test]
+    fn test_pending_held_in_memory_is_never_saved() {
+        let dir = TempDir::new().unwrap();
+        let mut in_memory = Vec::new();
+        add_pending_in_dir(
+            dir.path(),
+            &mut in_memory,
+            "saved",
+            PendingReason::DryRun,
+            true,
+        )
+        .unwrap();
+
+        let pending = add_pending_in_dir(
+            dir.path(),
+            &mut in_memory,
+            "private",
+            PendingReason::DryRun,
+            false,
+        )
+        .unwrap();
+        assert_eq!(pending.len(), 2);
+        assert_ne!(pending[0].id, pending[1].id);
+        let content = fs::read_to_string(dir.path().join(PENDING_FILE)).unwrap();
+        assert!(!content.contains("private"));
+
+        let pending =
+            update_pending_in_dir(dir.path(), &mut in_memory, pending[1].id, "edited").unwrap();
+        assert_eq!(in_memory[0].text, "edited");
+        let content = fs::read_to_string(dir.path().join(PENDING_FILE)).unwrap();
+        assert!(!content.contains("edited"));
+
+        let (removed, pending) =
+            remove_pending_in_dir(dir.path(), &mut in_memory, pending[1].id).unwrap();
+        assert_eq!(removed.text, "edited");
+        assert!(in_memory.is_empty());
+        assert_eq!(pending_in_dir(dir.path(), &in_memory).unwrap(), pending);
+    }
+}
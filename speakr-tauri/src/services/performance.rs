@@ -0,0 +1,36 @@
+// ============================================================================
+//! Dictation Performance Metrics
+// ============================================================================
+//!
+//! Collects the latency of every completed dictation in a rolling
+//! [`LatencyTracker`] so the debug panel can show how quickly recording
+//! starts, how long the first text takes after recording stops, the
+//! end-to-end time and the last dictation's post-processing stages. Metrics
+//! live in memory only and start empty.
+
+use speakr_core::transcription::performance::{DictationTimings, LatencyTracker};
+use speakr_types::{PerformanceMetrics, ProcessingStage};
+use std::sync::{LazyLock, Mutex, PoisonError};
+use tracing::debug;
+
+/// Latencies of the recent dictations.
+static LATENCY_TRACKER: LazyLock<Mutex<LatencyTracker>> =
+    LazyLock::new(|| Mutex::new(LatencyTracker::default()));
+
+/// Adds a completed dictation and its post-processing stages to the metrics
+pub fn record_dictation_timings(timings: DictationTimings, processing_stages: &[ProcessingStage]) {
+    debug!(?timings, "Recording dictation latency");
+    let mut tracker = LATENCY_TRACKER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    tracker.record(timings);
+    tracker.record_processing_stages(processing_stages.to_vec());
+}
+
+/// Summarises the latency of the recent dictations
+pub fn get_performance_metrics_internal() -> PerformanceMetrics {
+    LATENCY_TRACKER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .metrics()
+}
@@ -0,0 +1,184 @@
+// ============================================================================
+//! Privacy Mode
+// ============================================================================
+//!
+//! A temporary mode for dictating something sensitive. While it is on,
+//! dictations are still delivered, but:
+//!
+//! - their text is not added to the history,
+//! - transcripts are left out of the logs (see [`loggable_text`]), and
+//! - no recordings are saved, whatever the `save_recordings` setting says.
+//!
+//! It is switched on and off from the tray menu, the privacy hot-key or the
+//! UI, and switches itself off after the `privacy_mode_mins` setting. Every
+//! change is emitted on [`PRIVACY_MODE_CHANGED_EVENT`] and shown in the
+//! tray. Like pausing, privacy mode is not persisted; Speakr always starts
+//! with it off.
+
+use crate::services::tray::{refresh_tray_menu, set_tray_status};
+use crate::settings::load_settings_internal;
+use speakr_types::{AppError, PrivacyMode, PRIVACY_MODE_CHANGED_EVENT};
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use tracing::{info, warn};
+
+/// The current privacy mode.
+static PRIVACY_MODE: Mutex<PrivacyMode> = Mutex::new(PrivacyMode {
+    active: false,
+    expires_at: None,
+});
+
+/// Incremented on every switch, so an expiry timer from an earlier switch
+/// does nothing.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Serialises tests that switch privacy mode, which is shared by the process.
+#[cfg(test)]
+static TEST_LOCK: Mutex<()> = Mutex::new(());
+
+/// Returns the current privacy mode
+pub fn privacy_mode() -> PrivacyMode {
+    *PRIVACY_MODE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Returns `true` while privacy mode is on
+pub fn is_privacy_mode_active() -> bool {
+    privacy_mode().active
+}
+
+/// Text of a transcript as it may appear in the logs
+///
+/// While privacy mode is on only its length is logged.
+pub fn loggable_text(text: &str) -> Cow<'_, str> {
+    if is_privacy_mode_active() {
+        Cow::Owned(format!("<{} characters hidden>", text.chars().count()))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// Records a switch of privacy mode
+///
+/// # Returns
+///
+/// Returns the new mode and the generation identifying this switch.
+fn switch(mode: PrivacyMode) -> (PrivacyMode, u64) {
+    let mut current = PRIVACY_MODE.lock().unwrap_or_else(PoisonError::into_inner);
+    *current = mode;
+    (mode, GENERATION.fetch_add(1, Ordering::SeqCst) + 1)
+}
+
+/// Switches privacy mode off if it has not been switched since `generation`
+///
+/// # Returns
+///
+/// Returns `true` if privacy mode was switched off.
+fn expire(generation: u64) -> bool {
+    let mut current = PRIVACY_MODE.lock().unwrap_or_else(PoisonError::into_inner);
+    if GENERATION.load(Ordering::SeqCst) != generation || !current.active {
+        return false;
+    }
+    *current = PrivacyMode::default();
+    true
+}
+
+/// Switches privacy mode on or off
+///
+/// Switching it on (again) starts the expiry timer from the
+/// `privacy_mode_mins` setting.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+/// * `active` - `true` to switch privacy mode on
+///
+/// # Returns
+///
+/// Returns the new privacy mode.
+///
+/// # Errors
+///
+/// Returns an `AppError` if the settings cannot be loaded when switching it
+/// on; privacy mode is then left as it was.
+pub async fn set_privacy_mode_internal(
+    app_handle: &AppHandle,
+    active: bool,
+) -> Result<PrivacyMode, AppError> {
+    if !active {
+        let (mode, _) = switch(PrivacyMode::default());
+        info!("🔓 Privacy mode off");
+        publish(app_handle, mode);
+        return Ok(mode);
+    }
+
+    let minutes = load_settings_internal().await?.privacy_mode_mins.max(1);
+    let lasts = Duration::from_secs(u64::from(minutes) * 60);
+    let expires_at = chrono::Utc::now().timestamp_millis() as u64 + lasts.as_millis() as u64;
+    let (mode, generation) = switch(PrivacyMode::until(expires_at));
+    info!("🔒 Privacy mode on for {} minutes", minutes);
+    publish(app_handle, mode);
+
+    let app_handle = app_handle.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(lasts).await;
+        if expire(generation) {
+            info!("🔓 Privacy mode expired");
+            publish(&app_handle, PrivacyMode::default());
+        }
+    });
+    Ok(mode)
+}
+
+/// Toggles privacy mode, e.g. from the tray menu or the privacy hot-key
+pub fn toggle_privacy_mode(app_handle: &AppHandle) {
+    let app_handle = app_handle.clone();
+    tauri::async_runtime::spawn(async move {
+        let active = !is_privacy_mode_active();
+        if let Err(e) = set_privacy_mode_internal(&app_handle, active).await {
+            warn!("Failed to toggle privacy mode: {}", e);
+        }
+    });
+}
+
+/// Runs `f` with privacy mode on, for tests
+#[cfg(test)]
+pub(crate) fn with_privacy_mode<T>(f: impl FnOnce() -> T) -> T {
+    let _guard = TEST_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    switch(PrivacyMode::until(u64::MAX));
+    let result = f();
+    switch(PrivacyMode::default());
+    result
+}
+
+/// Updates the tray and tells the UI about a change
+fn publish(app_handle: &AppHandle, mode: PrivacyMode) {
+    refresh_tray_menu(app_handle);
+    set_tray_status(app_handle, None);
+    if let Err(e) = app_handle.emit(PRIVACY_MODE_CHANGED_EVENT, mode) {
+        warn!("Failed to emit privacy mode change: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_privacy_mode_hides_transcripts_and_expires_once() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        let (mode, generation) = switch(PrivacyMode::until(1));
+        assert!(mode.active);
+        assert_eq!(loggable_text("secret"), "<6 characters hidden>");
+
+        // A timer from an earlier switch leaves the mode alone
+        assert!(!expire(generation - 1));
+        assert!(is_privacy_mode_active());
+
+        assert!(expire(generation));
+        assert!(!expire(generation));
+        assert_eq!(loggable_text("secret"), "secret");
+    }
+}
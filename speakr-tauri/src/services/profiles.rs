@@ -0,0 +1,78 @@
+// ============================================================================
+//! Profile Switching
+// ============================================================================
+//!
+//! Applies a settings profile (see [`crate::settings::profiles`]) to the
+//! running app, from the settings window or the tray menu: the new hot-key
+//! replaces the old one, the profile's model is loaded, and open views and
+//! the tray menu are updated.
+
+use crate::services::hotkey::{
+    bind_hotkeys_with_portal, forget_hotkey, register_global_hotkey_internal,
+};
+use crate::services::model_manager::apply_model_settings;
+use crate::services::pause::is_dictation_enabled;
+use crate::services::tray::refresh_tray_menu;
+use crate::settings::load_settings_internal;
+use crate::settings::profiles::switch_profile_internal;
+use speakr_types::events::SETTINGS_CHANGED_EVENT;
+use speakr_types::{AppError, AppSettings, HotkeyConfig};
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_global_shortcut::GlobalShortcutExt;
+use tracing::warn;
+
+/// Switches to a saved profile and applies it
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+/// * `name` - The profile to switch to
+///
+/// # Returns
+///
+/// Returns the settings now in effect.
+///
+/// # Errors
+///
+/// Returns `AppError` if the profile does not exist or the settings cannot
+/// be saved. Failing to register the new hot-key is only logged.
+pub async fn switch_profile_and_apply(
+    app_handle: &AppHandle,
+    name: &str,
+) -> Result<AppSettings, AppError> {
+    let previous_hot_key = load_settings_internal().await?.hot_key;
+    let settings = switch_profile_internal(name).await?;
+
+    // Paused dictation registers the hot-key from settings when it resumes
+    if is_dictation_enabled()
+        && !bind_hotkeys_with_portal(app_handle, &settings)
+        && settings.hot_key != previous_hot_key
+    {
+        if let Err(e) = app_handle
+            .global_shortcut()
+            .unregister(previous_hot_key.as_str())
+        {
+            warn!("Failed to unregister hotkey '{}': {}", previous_hot_key, e);
+        }
+        forget_hotkey(&previous_hot_key);
+        let config = HotkeyConfig {
+            shortcut: settings.hot_key.clone(),
+            enabled: true,
+        };
+        if let Err(e) = register_global_hotkey_internal(app_handle.clone(), config).await {
+            warn!("Failed to register hotkey '{}': {}", settings.hot_key, e);
+        }
+    }
+
+    let model_settings = settings.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = apply_model_settings(&model_settings).await {
+            warn!("Failed to load the profile's model: {}", e);
+        }
+    });
+    if let Err(e) = app_handle.emit(SETTINGS_CHANGED_EVENT, &settings) {
+        warn!("Failed to emit settings change: {}", e);
+    }
+    refresh_tray_menu(app_handle);
+    Ok(settings)
+}
@@ -0,0 +1,188 @@
+// ============================================================================
+//! Secrets
+// ============================================================================
+//!
+//! Keeps sensitive values, such as the key that encrypts the history, in the
+//! macOS Keychain or the Windows Credential Manager instead of
+//! `settings.json`. Each secret is stored as a generic password under
+//! Speakr's bundle identifier, named after the secret.
+//!
+//! Other platforms have no keychain support yet: every call reports that
+//! secrets are unavailable, and features that need one cannot be switched
+//! on there (see [`is_keychain_available`]).
+//!
+//! The frontend reads and writes secrets through the `get_secret` and
+//! `set_secret` commands, which only accept the names in
+//! [`COMMAND_SECRETS`]; secrets the backend keeps to itself, such as the
+//! history key, are never handed out.
+
+use speakr_types::AppError;
+
+/// Keychain service the secrets are stored under.
+pub const KEYCHAIN_SERVICE: &str = "com.jessup.speakr";
+
+/// Name of the secret holding the history encryption key.
+pub const HISTORY_KEY_SECRET: &str = "history-encryption-key";
+
+/// Secrets the frontend may read and write; add a name here when a setting
+/// needs one, e.g. an API key.
+pub const COMMAND_SECRETS: &[&str] = &[];
+
+/// Whether secrets can be stored on this platform
+pub fn is_keychain_available() -> bool {
+    platform::AVAILABLE
+}
+
+/// Reads a secret
+///
+/// # Arguments
+///
+/// * `name` - The secret's name, e.g. [`HISTORY_KEY_SECRET`]
+///
+/// # Returns
+///
+/// Returns the secret, or `None` if it has never been stored.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if the keychain cannot be read or is not
+/// available on this platform.
+pub fn get_secret(name: &str) -> Result<Option<String>, AppError> {
+    platform::get(name)
+}
+
+/// Stores a secret, replacing any stored before
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if the keychain cannot be written or is not
+/// available on this platform.
+pub fn set_secret(name: &str, value: &str) -> Result<(), AppError> {
+    platform::set(name, value)
+}
+
+/// Removes a secret; removing one that was never stored is not an error
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if the keychain cannot be written or is not
+/// available on this platform.
+pub fn delete_secret(name: &str) -> Result<(), AppError> {
+    platform::delete(name)
+}
+
+/// Rejects secrets the frontend may not touch.
+fn check_command_secret(name: &str) -> Result<(), AppError> {
+    if COMMAND_SECRETS.contains(&name) {
+        Ok(())
+    } else {
+        Err(AppError::Settings(format!(
+            "Secret '{name}' cannot be accessed from the frontend"
+        )))
+    }
+}
+
+/// Reads a secret for the frontend.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if the secret is not in [`COMMAND_SECRETS`],
+/// or see [`get_secret`].
+pub fn get_secret_internal(name: &str) -> Result<Option<String>, AppError> {
+    check_command_secret(name)?;
+    get_secret(name)
+}
+
+/// Stores a secret for the frontend; an empty value removes it.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if the secret is not in [`COMMAND_SECRETS`],
+/// or see [`set_secret`] and [`delete_secret`].
+pub fn set_secret_internal(name: &str, value: &str) -> Result<(), AppError> {
+    check_command_secret(name)?;
+    if value.is_empty() {
+        delete_secret(name)
+    } else {
+        set_secret(name, value)
+    }
+}
+
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+mod platform {
+    use super::KEYCHAIN_SERVICE;
+    use keyring::{Entry, Error};
+    use speakr_types::AppError;
+
+    pub const AVAILABLE: bool = true;
+
+    fn keychain_error(e: Error) -> AppError {
+        AppError::Settings(format!("Keychain error: {e}"))
+    }
+
+    fn entry(name: &str) -> Result<Entry, AppError> {
+        Entry::new(KEYCHAIN_SERVICE, name).map_err(keychain_error)
+    }
+
+    pub fn get(name: &str) -> Result<Option<String>, AppError> {
+        match entry(name)?.get_password() {
+            Ok(value) => Ok(Some(value)),
+            Err(Error::NoEntry) => Ok(None),
+            Err(e) => Err(keychain_error(e)),
+        }
+    }
+
+    pub fn set(name: &str, value: &str) -> Result<(), AppError> {
+        entry(name)?.set_password(value).map_err(keychain_error)
+    }
+
+    pub fn delete(name: &str) -> Result<(), AppError> {
+        match entry(name)?.delete_credential() {
+            Ok(()) | Err(Error::NoEntry) => Ok(()),
+            Err(e) => Err(keychain_error(e)),
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+mod platform {
+    use speakr_types::AppError;
+
+    pub const AVAILABLE: bool = false;
+
+    fn unavailable() -> AppError {
+        AppError::Settings("Secure storage is not available on this platform".to_string())
+    }
+
+    pub fn get(_name: &str) -> Result<Option<String>, AppError> {
+        Err(unavailable())
+    }
+
+    pub fn set(_name: &str, _value: &str) -> Result<(), AppError> {
+        Err(unavailable())
+    }
+
+    pub fn delete(_name: &str) -> Result<(), AppError> {
+        Err(unavailable())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commands_cannot_reach_backend_secrets() {
+        for name in [HISTORY_KEY_SECRET, "unknown"] {
+            assert!(matches!(
+                get_secret_internal(name),
+                Err(AppError::Settings(_))
+            ));
+            assert!(matches!(
+                set_secret_internal(name, "value"),
+                Err(AppError::Settings(_))
+            ));
+        }
+        assert!(!COMMAND_SECRETS.contains(&HISTORY_KEY_SECRET));
+    }
+}
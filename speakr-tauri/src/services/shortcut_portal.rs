@@ -0,0 +1,244 @@
+// ============================================================================
+//! Wayland Shortcuts Portal
+// ============================================================================
+//!
+//! Wayland does not let applications grab keys, so the global shortcut
+//! plugin never sees hot-keys pressed in other applications there. Under
+//! Wayland, Speakr binds its dictation, language, privacy and re-type hot-keys
+//! through the desktop's global shortcuts portal
+//! (`org.freedesktop.portal.GlobalShortcuts`) instead, offering the
+//! configured shortcuts as preferred triggers; the desktop may ask the user
+//! to confirm them or to choose others. Developer shortcuts are not bound.
+//!
+//! Desktops without the portal cannot offer global hot-keys at all; this is
+//! reported as [`PlatformLimitation::NoGlobalShortcuts`].
+
+use crate::injection::linux::DisplayServer;
+use crate::services::last_transcription::spawn_reinject_last_transcription;
+use crate::services::privacy::toggle_privacy_mode;
+use crate::services::update_platform_limitation;
+use ashpd::desktop::global_shortcuts::{GlobalShortcuts, NewShortcut};
+use futures::StreamExt;
+use speakr_types::events::HOTKEY_TRIGGERED_EVENT;
+use speakr_types::{AppError, AppSettings, DictationOverrides, PlatformLimitation};
+use std::sync::{Mutex, PoisonError};
+use tauri::async_runtime::JoinHandle;
+use tauri::{AppHandle, Emitter};
+use tracing::{debug, info, warn};
+
+/// Identifies the main dictation hot-key with the portal.
+const DICTATION_ID: &str = "dictation";
+
+/// Identifies the privacy hot-key with the portal.
+const PRIVACY_ID: &str = "privacy";
+
+/// Identifies the re-type hot-key with the portal.
+const REINJECT_ID: &str = "reinject";
+
+/// What a portal shortcut does when activated
+#[derive(Debug, Clone, PartialEq)]
+enum PortalAction {
+    /// Starts a dictation, with a language hot-key's overrides if any
+    Dictate(Option<DictationOverrides>),
+    /// Switches privacy mode on or off
+    TogglePrivacy,
+    /// Types the last dictation again
+    Reinject,
+}
+
+/// A hot-key to bind through the portal
+#[derive(Debug, Clone, PartialEq)]
+struct PortalShortcut {
+    id: String,
+    description: String,
+    trigger: String,
+    action: PortalAction,
+}
+
+/// The bound shortcuts and the task listening for them.
+static PORTAL_BINDING: Mutex<Option<(Vec<PortalShortcut>, JoinHandle<()>)>> = Mutex::new(None);
+
+/// Whether hot-keys have to go through the portal, i.e. in a Wayland session
+pub fn uses_shortcuts_portal() -> bool {
+    DisplayServer::detect() == DisplayServer::Wayland
+}
+
+/// Converts an accelerator such as `CmdOrCtrl+Alt+Space` into the portal's
+/// trigger format, `CTRL+ALT+space`
+pub fn portal_trigger(shortcut: &str) -> String {
+    shortcut
+        .split('+')
+        .map(|part| match part.to_lowercase().as_str() {
+            "cmdorctrl" | "commandorcontrol" | "cmd" | "command" | "ctrl" | "control" => {
+                "CTRL".to_string()
+            }
+            "alt" | "option" => "ALT".to_string(),
+            "shift" => "SHIFT".to_string(),
+            "super" | "meta" => "LOGO".to_string(),
+            key => key.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("+")
+}
+
+/// Lists the hot-keys in the settings as portal shortcuts.
+fn portal_shortcuts(settings: &AppSettings) -> Vec<PortalShortcut> {
+    let mut shortcuts = vec![PortalShortcut {
+        id: DICTATION_ID.to_string(),
+        description: "Start or stop dictation".to_string(),
+        trigger: portal_trigger(&settings.hot_key),
+        action: PortalAction::Dictate(None),
+    }];
+    for binding in &settings.language_hotkeys {
+        shortcuts.push(PortalShortcut {
+            id: format!("{DICTATION_ID}:{}", binding.language),
+            description: format!("Dictate in {}", binding.language),
+            trigger: portal_trigger(&binding.shortcut),
+            action: PortalAction::Dictate(Some(binding.overrides())),
+        });
+    }
+    if let Some(shortcut) = &settings.privacy_hotkey {
+        shortcuts.push(PortalShortcut {
+            id: PRIVACY_ID.to_string(),
+            description: "Toggle privacy mode".to_string(),
+            trigger: portal_trigger(shortcut),
+            action: PortalAction::TogglePrivacy,
+        });
+    }
+    if let Some(shortcut) = &settings.reinject_hotkey {
+        shortcuts.push(PortalShortcut {
+            id: REINJECT_ID.to_string(),
+            description: "Type the last dictation again".to_string(),
+            trigger: portal_trigger(shortcut),
+            action: PortalAction::Reinject,
+        });
+    }
+    shortcuts
+}
+
+/// Binds the hot-keys in the settings through the portal
+///
+/// Replaces the shortcuts bound before; does nothing if they have not
+/// changed, so the desktop does not ask the user to confirm them again.
+/// Binding happens in the background: a desktop without the portal is
+/// reported as [`PlatformLimitation::NoGlobalShortcuts`].
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle used to start dictations
+/// * `settings` - The settings holding the hot-keys
+pub fn bind_portal_shortcuts(app_handle: &AppHandle, settings: &AppSettings) {
+    let shortcuts = portal_shortcuts(settings);
+    let mut binding = PORTAL_BINDING
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    if let Some((bound, task)) = binding.take() {
+        if bound == shortcuts {
+            *binding = Some((bound, task));
+            return;
+        }
+        task.abort();
+    }
+
+    let app_handle = app_handle.clone();
+    let listened = shortcuts.clone();
+    let task = tauri::async_runtime::spawn(async move {
+        if let Err(e) = listen_for_shortcuts(&app_handle, &listened).await {
+            warn!("Global shortcuts portal unavailable: {}", e);
+            update_platform_limitation(PlatformLimitation::NoGlobalShortcuts, true).await;
+        }
+    });
+    *binding = Some((shortcuts, task));
+}
+
+/// Releases the shortcuts bound through the portal
+pub fn release_portal_shortcuts() {
+    let binding = PORTAL_BINDING
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take();
+    if let Some((_, task)) = binding {
+        task.abort();
+    }
+}
+
+fn portal_error(e: ashpd::Error) -> AppError {
+    AppError::HotKey(format!("Global shortcuts portal: {e}"))
+}
+
+/// Binds `shortcuts` and runs their actions until the task is aborted.
+async fn listen_for_shortcuts(
+    app_handle: &AppHandle,
+    shortcuts: &[PortalShortcut],
+) -> Result<(), AppError> {
+    let portal = GlobalShortcuts::new().await.map_err(portal_error)?;
+    let session = portal.create_session().await.map_err(portal_error)?;
+    let requested: Vec<NewShortcut> = shortcuts
+        .iter()
+        .map(|shortcut| {
+            NewShortcut::new(&shortcut.id, &shortcut.description)
+                .preferred_trigger(shortcut.trigger.as_str())
+        })
+        .collect();
+    let bound = portal
+        .bind_shortcuts(&session, &requested, None)
+        .await
+        .map_err(portal_error)?
+        .response()
+        .map_err(portal_error)?;
+    for shortcut in bound.shortcuts() {
+        info!(
+            "Bound {} to {} through the shortcuts portal",
+            shortcut.id(),
+            shortcut.trigger_description()
+        );
+    }
+    update_platform_limitation(PlatformLimitation::NoGlobalShortcuts, false).await;
+
+    let mut activations = portal.receive_activated().await.map_err(portal_error)?;
+    while let Some(activated) = activations.next().await {
+        let Some(shortcut) = shortcuts
+            .iter()
+            .find(|shortcut| shortcut.id == activated.shortcut_id())
+        else {
+            continue;
+        };
+        debug!(binding = %shortcut.id, "Portal shortcut triggered");
+        match &shortcut.action {
+            PortalAction::Dictate(overrides) => {
+                let _ = app_handle.emit(HOTKEY_TRIGGERED_EVENT, overrides);
+            }
+            PortalAction::TogglePrivacy => toggle_privacy_mode(app_handle),
+            PortalAction::Reinject => spawn_reinject_last_transcription(app_handle),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use speakr_types::LanguageHotkey;
+
+    #[test]
+    fn test_portal_trigger_format() {
+        assert_eq!(portal_trigger("CmdOrCtrl+Alt+Space"), "CTRL+ALT+space");
+        assert_eq!(portal_trigger("Super+Shift+F9"), "LOGO+SHIFT+f9");
+    }
+
+    #[test]
+    fn test_portal_shortcuts_from_settings() {
+        let settings = AppSettings {
+            language_hotkeys: vec![LanguageHotkey {
+                shortcut: "Ctrl+Alt+G".to_string(),
+                language: "de".to_string(),
+            }],
+            privacy_hotkey: Some("Ctrl+Alt+P".to_string()),
+            ..AppSettings::default()
+        };
+        let shortcuts = portal_shortcuts(&settings);
+        let ids: Vec<_> = shortcuts.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, [DICTATION_ID, "dictation:de", PRIVACY_ID]);
+        assert_eq!(shortcuts[2].action, PortalAction::TogglePrivacy);
+    }
+}
@@ -1,11 +1,24 @@
 // ============================================================================
 //! Backend Status Service
 // ============================================================================
+//!
+//! Tracks the status of each backend component. Once
+//! [`attach_status_events`] has been called, every change made through
+//! [`update_global_service_status`] is pushed to the frontend on
+//! [`STATUS_CHANGED_EVENT`], so the UI does not need to poll
+//! `get_backend_status`.
+//!
+//! The status also lists the [`PlatformLimitation`]s detected on this
+//! desktop, recorded through [`update_platform_limitation`].
 
 use crate::services::types::ServiceComponent;
-use speakr_types::{AppError, BackendStatus, ServiceStatus, StatusUpdate};
-use std::sync::{Arc, LazyLock, Mutex};
+use speakr_types::events::{HEARTBEAT_EVENT, SPEAKR_STATUS_CHANGED_EVENT};
+use speakr_types::{
+    AppError, BackendStatus, PlatformLimitation, ServiceStatus, StatusUpdate, STATUS_CHANGED_EVENT,
+};
+use std::sync::{Arc, LazyLock, Mutex, OnceLock};
 use tauri::{AppHandle, Emitter};
+use tracing::warn;
 
 /// Service responsible for tracking backend component status
 pub struct BackendStatusService {
@@ -53,11 +66,26 @@ impl BackendStatusService {
         }
     }
 
+    /// Records a platform limitation, or clears it once it no longer applies
+    pub fn update_limitation(&mut self, limitation: PlatformLimitation, applies: bool) {
+        let mut current_status = match self.status.lock() {
+            Ok(status) => status,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        current_status.timestamp = chrono::Utc::now().timestamp_millis() as u64;
+        current_status
+            .limitations
+            .retain(|other| *other != limitation);
+        if applies {
+            current_status.limitations.push(limitation);
+        }
+    }
+
     /// Emits status change event to frontend
     pub fn emit_status_change(&self, app_handle: &AppHandle) -> Result<(), String> {
         let status = self.get_current_status();
         app_handle
-            .emit("speakr-status-changed", &status)
+            .emit(SPEAKR_STATUS_CHANGED_EVENT, &status)
             .map_err(|e| format!("Failed to emit status change: {e}"))
     }
 
@@ -65,7 +93,7 @@ impl BackendStatusService {
     pub fn emit_heartbeat(&self, app_handle: &AppHandle) -> Result<(), String> {
         let status = self.get_current_status();
         app_handle
-            .emit("speakr-heartbeat", &status)
+            .emit(HEARTBEAT_EVENT, &status)
             .map_err(|e| format!("Failed to emit heartbeat: {e}"))
     }
 }
@@ -80,6 +108,20 @@ impl Default for BackendStatusService {
 static GLOBAL_BACKEND_SERVICE: LazyLock<Arc<Mutex<BackendStatusService>>> =
     LazyLock::new(|| Arc::new(Mutex::new(BackendStatusService::new())));
 
+// Application handle status changes are emitted through
+static STATUS_EVENTS: OnceLock<AppHandle> = OnceLock::new();
+
+/// Emits [`STATUS_CHANGED_EVENT`] on every later status update.
+///
+/// Call once during application setup; later calls are ignored.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle to emit through
+pub fn attach_status_events(app_handle: AppHandle) {
+    let _ = STATUS_EVENTS.set(app_handle);
+}
+
 /// Gets a reference to the global backend service instance.
 ///
 /// # Internal API
@@ -100,6 +142,9 @@ pub async fn get_backend_status_internal() -> Result<StatusUpdate, AppError> {
 
 /// Updates the status of a specific service component in the global service.
 ///
+/// The new status is emitted on [`STATUS_CHANGED_EVENT`] once
+/// [`attach_status_events`] has been called.
+///
 /// # Arguments
 ///
 /// * `component` - The service component to update
@@ -124,6 +169,36 @@ pub async fn update_global_service_status(component: ServiceComponent, status: S
         Err(poisoned) => poisoned.into_inner(),
     };
     service_guard.update_service_status(component, status);
+    publish_status(&service_guard);
+}
+
+/// Records a limitation of the platform in the global service, or clears it
+/// with `applies` set to `false`.
+///
+/// Emitted on [`STATUS_CHANGED_EVENT`] like any other status update.
+///
+/// # Arguments
+///
+/// * `limitation` - The limitation detected
+/// * `applies` - Whether it applies on this desktop
+pub async fn update_platform_limitation(limitation: PlatformLimitation, applies: bool) {
+    let service = Arc::clone(&GLOBAL_BACKEND_SERVICE);
+    let mut service_guard = match service.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    service_guard.update_limitation(limitation, applies);
+    publish_status(&service_guard);
+}
+
+/// Emits the current status once [`attach_status_events`] has been called.
+fn publish_status(service: &BackendStatusService) {
+    if let Some(app_handle) = STATUS_EVENTS.get() {
+        let status = service.get_current_status();
+        if let Err(e) = app_handle.emit(STATUS_CHANGED_EVENT, &status) {
+            warn!("Failed to emit status change: {}", e);
+        }
+    }
 }
 
 /// Internal implementation for updating service status
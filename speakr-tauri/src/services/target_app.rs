@@ -0,0 +1,46 @@
+// ============================================================================
+//! Target Application Detection
+// ============================================================================
+//!
+//! Identifies the application that will receive dictated text, so the
+//! matching [`AppProfile`] can adjust post-processing for it. The target is
+//! read when the dictation starts, while the user's application still has
+//! focus.
+//!
+//! Only macOS is supported for now (via `NSWorkspace`); elsewhere the target
+//! is unknown and the global settings apply unchanged.
+//!
+//! [`AppProfile`]: speakr_types::AppProfile
+
+use tracing::debug;
+
+/// Returns the identifier of the frontmost application
+///
+/// # Returns
+///
+/// The bundle identifier on macOS (e.g. `"com.apple.Terminal"`), or `None`
+/// if it cannot be determined on this platform.
+pub fn frontmost_app_id() -> Option<String> {
+    let app_id = platform::frontmost_app_id();
+    debug!("Dictation target application: {:?}", app_id);
+    app_id
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use objc2_app_kit::NSWorkspace;
+
+    pub fn frontmost_app_id() -> Option<String> {
+        let application = NSWorkspace::sharedWorkspace().frontmostApplication()?;
+        application
+            .bundleIdentifier()
+            .map(|identifier| identifier.to_string())
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+mod platform {
+    pub fn frontmost_app_id() -> Option<String> {
+        None
+    }
+}
@@ -0,0 +1,251 @@
+// ============================================================================
+//! Menu-bar (tray) icon
+// ============================================================================
+//!
+//! Speakr lives in the menu bar. The icon's tooltip doubles as a lightweight
+//! status line, e.g. showing download progress while a large model is being
+//! fetched and the settings window is closed. Its menu pauses and resumes
+//! dictation (see [`crate::services::pause`]), toggles privacy mode (see
+//! [`crate::services::privacy`]) and switches between settings profiles (see
+//! [`crate::services::profiles`]). While a dictation session is open, it
+//! can be ended from the menu (see [`crate::services::dictation_session`]).
+//! A badge next to the icon
+//! counts the dictations waiting for review (see
+//! [`crate::services::pending`]). Quitting from the menu shuts Speakr down
+//! cleanly (see [`crate::lifecycle`]).
+
+use crate::services::dictation_session::{end_session, is_session_active};
+use crate::services::pause::{is_dictation_enabled, set_dictation_enabled_internal};
+use crate::services::privacy::{is_privacy_mode_active, toggle_privacy_mode};
+use crate::services::profiles::switch_profile_and_apply;
+use crate::settings::profiles::load_profiles_internal;
+use tauri::menu::{
+    CheckMenuItem, IsMenuItem, Menu, MenuEvent, MenuItem, PredefinedMenuItem, Submenu,
+};
+use tauri::tray::TrayIconBuilder;
+use tauri::{App, AppHandle, Manager, Wry};
+use tracing::{debug, warn};
+
+/// Identifier of Speakr's tray icon.
+pub const TRAY_ID: &str = "speakr";
+
+/// Tooltip shown when nothing is in progress.
+pub const DEFAULT_TRAY_TOOLTIP: &str = "Speakr";
+
+/// Identifier of the menu item that pauses and resumes dictation.
+pub const TOGGLE_DICTATION_MENU_ID: &str = "toggle-dictation";
+
+/// Identifier of the menu item that switches privacy mode on and off.
+pub const TOGGLE_PRIVACY_MENU_ID: &str = "toggle-privacy";
+
+/// Identifier of the menu item that ends the dictation session.
+pub const END_SESSION_MENU_ID: &str = "end-session";
+
+/// Identifier of the menu item that quits Speakr.
+pub const QUIT_MENU_ID: &str = "quit";
+
+/// Prefix of the menu items that switch to a settings profile.
+const PROFILE_MENU_PREFIX: &str = "profile:";
+
+/// Tray status while dictation is paused.
+const PAUSED_TRAY_STATUS: &str = "Paused";
+
+/// Tray status while privacy mode is on.
+const PRIVATE_TRAY_STATUS: &str = "Private";
+
+/// Creates the tray icon and its menu
+///
+/// # Errors
+///
+/// Returns an error if the platform refuses to create the icon.
+pub fn setup_tray(app: &App) -> tauri::Result<()> {
+    let mut builder = TrayIconBuilder::with_id(TRAY_ID)
+        .tooltip(DEFAULT_TRAY_TOOLTIP)
+        .menu(&tray_menu(app, true)?)
+        .on_menu_event(handle_menu_event);
+    if let Some(icon) = app.default_window_icon() {
+        builder = builder.icon(icon.clone());
+    }
+    builder.build(app)?;
+    Ok(())
+}
+
+/// Updates the tray tooltip
+///
+/// When `tooltip` is `None` the tooltip shows whether dictation is paused
+/// or privacy mode is on, or just the app name.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+/// * `tooltip` - Status text to show after the app name
+pub fn set_tray_status(app_handle: &AppHandle, tooltip: Option<&str>) {
+    let Some(tray) = app_handle.tray_by_id(TRAY_ID) else {
+        return;
+    };
+    let idle = if !is_dictation_enabled() {
+        Some(PAUSED_TRAY_STATUS)
+    } else if is_privacy_mode_active() {
+        Some(PRIVATE_TRAY_STATUS)
+    } else {
+        None
+    };
+    let text = match tooltip.or(idle) {
+        Some(status) => format!("{DEFAULT_TRAY_TOOLTIP} — {status}"),
+        None => DEFAULT_TRAY_TOOLTIP.to_string(),
+    };
+    if let Err(e) = tray.set_tooltip(Some(text)) {
+        debug!("Failed to update tray tooltip: {}", e);
+    }
+}
+
+/// Shows the number of pending dictations next to the tray icon
+///
+/// The badge is hidden when nothing is pending. Platforms that cannot show
+/// text next to the icon ignore it.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+/// * `count` - Number of dictations waiting for review
+pub fn set_tray_pending_count(app_handle: &AppHandle, count: usize) {
+    let Some(tray) = app_handle.tray_by_id(TRAY_ID) else {
+        return;
+    };
+    let badge = (count > 0).then(|| count.to_string());
+    if let Err(e) = tray.set_title(badge) {
+        debug!("Failed to update tray badge: {}", e);
+    }
+}
+
+/// Updates the tray menu and tooltip after dictation is paused or resumed
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+pub fn set_tray_dictation_enabled(app_handle: &AppHandle) {
+    refresh_tray_menu(app_handle);
+    set_tray_status(app_handle, None);
+}
+
+/// Rebuilds the tray menu, e.g. after profiles are added or switched
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+pub fn refresh_tray_menu(app_handle: &AppHandle) {
+    let Some(tray) = app_handle.tray_by_id(TRAY_ID) else {
+        return;
+    };
+    match tray_menu(app_handle, is_dictation_enabled()) {
+        Ok(menu) => {
+            if let Err(e) = tray.set_menu(Some(menu)) {
+                debug!("Failed to update tray menu: {}", e);
+            }
+        }
+        Err(e) => debug!("Failed to build tray menu: {}", e),
+    }
+}
+
+/// Builds the tray menu for the current dictation state and profiles
+fn tray_menu<M: Manager<Wry>>(manager: &M, enabled: bool) -> tauri::Result<Menu<Wry>> {
+    let label = if enabled {
+        "Pause Dictation"
+    } else {
+        "Resume Dictation"
+    };
+    let toggle = MenuItem::with_id(manager, TOGGLE_DICTATION_MENU_ID, label, true, None::<&str>)?;
+    let privacy = CheckMenuItem::with_id(
+        manager,
+        TOGGLE_PRIVACY_MENU_ID,
+        "Privacy Mode",
+        true,
+        is_privacy_mode_active(),
+        None::<&str>,
+    )?;
+
+    let profiles = load_profiles_internal().unwrap_or_else(|e| {
+        debug!("Failed to load profiles for the tray menu: {}", e);
+        Default::default()
+    });
+    let end_session = MenuItem::with_id(
+        manager,
+        END_SESSION_MENU_ID,
+        "End Dictation Session",
+        is_session_active(),
+        None::<&str>,
+    )?;
+    let separator = PredefinedMenuItem::separator(manager)?;
+    let quit = MenuItem::with_id(manager, QUIT_MENU_ID, "Quit Speakr", true, None::<&str>)?;
+    if profiles.profiles.is_empty() {
+        return Menu::with_items(
+            manager,
+            &[&toggle, &privacy, &end_session, &separator, &quit],
+        );
+    }
+
+    let items = profiles
+        .profiles
+        .iter()
+        .map(|profile| {
+            let active = profiles
+                .active
+                .as_deref()
+                .is_some_and(|active| active.eq_ignore_ascii_case(&profile.name));
+            CheckMenuItem::with_id(
+                manager,
+                format!("{PROFILE_MENU_PREFIX}{}", profile.name),
+                &profile.name,
+                true,
+                active,
+                None::<&str>,
+            )
+        })
+        .collect::<tauri::Result<Vec<_>>>()?;
+    let items: Vec<&dyn IsMenuItem<Wry>> = items
+        .iter()
+        .map(|item| item as &dyn IsMenuItem<Wry>)
+        .collect();
+    let switcher = Submenu::with_items(manager, "Profile", true, &items)?;
+    Menu::with_items(
+        manager,
+        &[
+            &toggle,
+            &privacy,
+            &end_session,
+            &switcher,
+            &separator,
+            &quit,
+        ],
+    )
+}
+
+/// Toggles dictation, ends the session, switches profile or quits when a
+/// menu item is chosen
+fn handle_menu_event(app_handle: &AppHandle, event: MenuEvent) {
+    let app_handle = app_handle.clone();
+    if event.id() == TOGGLE_DICTATION_MENU_ID {
+        tauri::async_runtime::spawn(async move {
+            let enabled = !is_dictation_enabled();
+            if let Err(e) = set_dictation_enabled_internal(&app_handle, enabled).await {
+                warn!("Failed to toggle dictation: {}", e);
+            }
+        });
+    } else if event.id() == TOGGLE_PRIVACY_MENU_ID {
+        toggle_privacy_mode(&app_handle);
+    } else if event.id() == END_SESSION_MENU_ID {
+        end_session(&app_handle);
+    } else if event.id() == QUIT_MENU_ID {
+        // Exits through the run loop, which shuts Speakr down cleanly
+        app_handle.exit(0);
+    } else if let Some(name) = event.id().as_ref().strip_prefix(PROFILE_MENU_PREFIX) {
+        let name = name.to_string();
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = switch_profile_and_apply(&app_handle, &name).await {
+                warn!("Failed to switch to profile '{}': {}", name, e);
+                // Restore the check mark on the active profile
+                refresh_tray_menu(&app_handle);
+            }
+        });
+    }
+}
@@ -2,6 +2,8 @@
 //! Service Types & Enums
 // ============================================================================
 
+use speakr_types::{BackendStatus, ServiceStatus};
+
 /// Enum to identify different service components
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[serde(deny_unknown_fields)]
@@ -10,3 +12,23 @@ pub enum ServiceComponent {
     Transcription,
     TextInjection,
 }
+
+impl ServiceComponent {
+    /// Name of the component as shown to users
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ServiceComponent::AudioCapture => "Microphone",
+            ServiceComponent::Transcription => "Transcription",
+            ServiceComponent::TextInjection => "Text insertion",
+        }
+    }
+
+    /// The component's status within a backend status snapshot
+    pub fn status_in<'a>(&self, status: &'a BackendStatus) -> &'a ServiceStatus {
+        match self {
+            ServiceComponent::AudioCapture => &status.audio_capture,
+            ServiceComponent::Transcription => &status.transcription,
+            ServiceComponent::TextInjection => &status.text_injection,
+        }
+    }
+}
@@ -0,0 +1,249 @@
+// ============================================================================
+//! Service Watchdog
+// ============================================================================
+//!
+//! Watches the backend status for components in [`ServiceStatus::Error`] and
+//! restarts them with exponential backoff (see [`RestartPolicy`]):
+//!
+//! - **Audio capture**: looks for a microphone again
+//! - **Transcription**: reloads the selected Whisper model
+//! - **Text injection**: checks the accessibility permission again
+//!
+//! A successful restart marks the component ready and emits
+//! [`SERVICE_RECOVERED_EVENT`]. Once every attempt has failed the watchdog
+//! emits [`SERVICE_GAVE_UP_EVENT`] so the UI can report the persistent
+//! failure, and leaves the component alone until its status changes.
+
+use crate::commands::permissions::check_accessibility_permission_internal;
+use crate::commands::system::list_input_devices_internal;
+use crate::services::model_manager::warm_load_model;
+use crate::services::{
+    get_backend_status_internal, update_global_service_status, ServiceComponent,
+};
+use crate::settings::load_settings_internal;
+use speakr_types::{
+    AppError, ServiceRecovery, ServiceStatus, SERVICE_GAVE_UP_EVENT, SERVICE_RECOVERED_EVENT,
+};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use tracing::{error, info, warn};
+
+/// How often the backend status is checked for failed components.
+const CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Components the watchdog restarts, indexing [`SUPERVISION`].
+const COMPONENTS: [ServiceComponent; 3] = [
+    ServiceComponent::AudioCapture,
+    ServiceComponent::Transcription,
+    ServiceComponent::TextInjection,
+];
+
+/// What the watchdog is doing about a component.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Supervision {
+    /// Waiting for the component to fail.
+    Idle,
+    /// Restart attempts are in progress.
+    Restarting,
+    /// Every attempt failed; waiting for the status to change.
+    GaveUp,
+}
+
+static SUPERVISION: Mutex<[Supervision; 3]> = Mutex::new([Supervision::Idle; 3]);
+
+/// Bounded exponential backoff between restart attempts
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RestartPolicy {
+    /// Delay before the first attempt, doubled for each later one.
+    pub initial_delay: Duration,
+    /// Upper bound on the delay between attempts.
+    pub max_delay: Duration,
+    /// Attempts made before giving up.
+    pub max_attempts: u32,
+}
+
+impl RestartPolicy {
+    /// Five attempts over roughly half a minute.
+    pub const DEFAULT: Self = Self {
+        initial_delay: Duration::from_secs(1),
+        max_delay: Duration::from_secs(16),
+        max_attempts: 5,
+    };
+
+    /// Returns the delay before restart `attempt` (counting from 1)
+    ///
+    /// # Returns
+    ///
+    /// `None` once `attempt` exceeds [`RestartPolicy::max_attempts`].
+    pub fn delay_before(&self, attempt: u32) -> Option<Duration> {
+        if attempt == 0 || attempt > self.max_attempts {
+            return None;
+        }
+        let factor = 1u32.checked_shl(attempt - 1).unwrap_or(u32::MAX);
+        Some(
+            self.initial_delay
+                .saturating_mul(factor)
+                .min(self.max_delay),
+        )
+    }
+}
+
+fn set_supervision(index: usize, supervision: Supervision) {
+    SUPERVISION.lock().unwrap_or_else(PoisonError::into_inner)[index] = supervision;
+}
+
+/// Starts watching the backend status for failed components
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle used to emit events
+pub fn spawn_service_watchdog(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            let Ok(status) = get_backend_status_internal().await else {
+                continue;
+            };
+
+            let mut supervision = SUPERVISION.lock().unwrap_or_else(PoisonError::into_inner);
+            for (index, component) in COMPONENTS.iter().enumerate() {
+                let failed = matches!(component.status_in(&status), ServiceStatus::Error(_));
+                match (supervision[index], failed) {
+                    (Supervision::Idle, true) => {
+                        supervision[index] = Supervision::Restarting;
+                        tauri::async_runtime::spawn(supervise(
+                            app_handle.clone(),
+                            index,
+                            RestartPolicy::DEFAULT,
+                        ));
+                    }
+                    (Supervision::GaveUp, false) => supervision[index] = Supervision::Idle,
+                    _ => {}
+                }
+            }
+        }
+    });
+}
+
+/// Restarts a failed component until it recovers or the policy runs out.
+async fn supervise(app_handle: AppHandle, index: usize, policy: RestartPolicy) {
+    let component = &COMPONENTS[index];
+    let mut attempts = 0;
+    let mut last_error = None;
+
+    while let Some(delay) = policy.delay_before(attempts + 1) {
+        tokio::time::sleep(delay).await;
+        if !is_failed(component).await {
+            // Recovered by a later dictation, or paused in the meantime
+            set_supervision(index, Supervision::Idle);
+            return;
+        }
+
+        attempts += 1;
+        warn!(component = ?component, attempts, "Restarting failed service");
+        match restart(component).await {
+            Ok(()) => {
+                update_global_service_status(component.clone(), ServiceStatus::Ready).await;
+                info!(component = ?component, attempts, "Service recovered");
+                set_supervision(index, Supervision::Idle);
+                emit(
+                    &app_handle,
+                    SERVICE_RECOVERED_EVENT,
+                    component,
+                    attempts,
+                    None,
+                );
+                return;
+            }
+            Err(e) => last_error = Some(e.to_string()),
+        }
+    }
+
+    error!(component = ?component, attempts, "Giving up restarting service");
+    set_supervision(index, Supervision::GaveUp);
+    emit(
+        &app_handle,
+        SERVICE_GAVE_UP_EVENT,
+        component,
+        attempts,
+        last_error,
+    );
+}
+
+async fn is_failed(component: &ServiceComponent) -> bool {
+    get_backend_status_internal()
+        .await
+        .is_ok_and(|status| matches!(component.status_in(&status), ServiceStatus::Error(_)))
+}
+
+/// Brings a component back into a usable state.
+async fn restart(component: &ServiceComponent) -> Result<(), AppError> {
+    match component {
+        ServiceComponent::AudioCapture => {
+            if list_input_devices_internal().await?.is_empty() {
+                return Err(AppError::AudioCapture("No microphone found".to_string()));
+            }
+            Ok(())
+        }
+        ServiceComponent::Transcription => warm_load_model(&load_settings_internal().await?).await,
+        ServiceComponent::TextInjection => match check_accessibility_permission_internal().await {
+            ServiceStatus::Ready => Ok(()),
+            _ => Err(AppError::TextInjection(
+                "Accessibility permission has not been granted".to_string(),
+            )),
+        },
+    }
+}
+
+fn emit(
+    app_handle: &AppHandle,
+    event: &str,
+    component: &ServiceComponent,
+    attempts: u32,
+    error: Option<String>,
+) {
+    let recovery = ServiceRecovery {
+        service: component.display_name().to_string(),
+        attempts,
+        error,
+    };
+    if let Err(e) = app_handle.emit(event, &recovery) {
+        warn!("Failed to emit {}: {}", event, e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restart_delays_double_up_to_the_cap() {
+        let policy = RestartPolicy::DEFAULT;
+        let delays: Vec<_> = (1..=6)
+            .map(|attempt| policy.delay_before(attempt))
+            .collect();
+        assert_eq!(
+            delays,
+            [
+                Some(Duration::from_secs(1)),
+                Some(Duration::from_secs(2)),
+                Some(Duration::from_secs(4)),
+                Some(Duration::from_secs(8)),
+                Some(Duration::from_secs(16)),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_restart_delay_is_bounded_for_many_attempts() {
+        let policy = RestartPolicy {
+            max_attempts: 100,
+            ..RestartPolicy::DEFAULT
+        };
+        assert_eq!(policy.delay_before(64), Some(policy.max_delay));
+        assert_eq!(policy.delay_before(0), None);
+    }
+}
@@ -0,0 +1,90 @@
+// ============================================================================
+//! Window State Persistence
+// ============================================================================
+//!
+//! Every window reopens with the size, position and maximised state it was
+//! closed with. Saving and restoring is handled by
+//! `tauri-plugin-window-state`, which only restores a saved position if it
+//! is still on one of the attached monitors.
+//!
+//! A window restored from a larger display can still be bigger than the
+//! monitor it now opens on (e.g. after unplugging an external display), so
+//! [`fit_windows_to_monitors`] shrinks such windows and centres them.
+
+use tauri::{plugin::TauriPlugin, AppHandle, Manager, PhysicalSize, Runtime};
+use tauri_plugin_window_state::StateFlags;
+use tracing::{debug, info};
+
+/// Window properties that are saved and restored.
+const PERSISTED_STATE: StateFlags = StateFlags::SIZE
+    .union(StateFlags::POSITION)
+    .union(StateFlags::MAXIMIZED);
+
+/// Builds the plugin that saves and restores window state.
+///
+/// Must be registered on the builder, before windows are created, so their
+/// state is restored as they open.
+pub fn window_state_plugin<R: Runtime>() -> TauriPlugin<R> {
+    tauri_plugin_window_state::Builder::new()
+        .with_state_flags(PERSISTED_STATE)
+        .build()
+}
+
+/// Returns the size a window must shrink to in order to fit on a monitor.
+///
+/// # Returns
+///
+/// `None` if the window already fits.
+pub fn size_to_fit(
+    window: PhysicalSize<u32>,
+    monitor: PhysicalSize<u32>,
+) -> Option<PhysicalSize<u32>> {
+    if window.width <= monitor.width && window.height <= monitor.height {
+        return None;
+    }
+    Some(PhysicalSize::new(
+        window.width.min(monitor.width),
+        window.height.min(monitor.height),
+    ))
+}
+
+/// Shrinks and centres restored windows that are larger than their monitor.
+pub fn fit_windows_to_monitors<R: Runtime>(app_handle: &AppHandle<R>) {
+    for (label, window) in app_handle.webview_windows() {
+        let (Ok(size), Ok(Some(monitor))) = (window.outer_size(), window.current_monitor()) else {
+            debug!("Could not read the size or monitor of window '{}'", label);
+            continue;
+        };
+        let Some(fitted) = size_to_fit(size, *monitor.size()) else {
+            continue;
+        };
+
+        info!(
+            "Window '{}' ({}x{}) is larger than its monitor, resizing to {}x{}",
+            label, size.width, size.height, fitted.width, fitted.height
+        );
+        let _ = window.set_size(fitted);
+        let _ = window.center();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_to_fit_only_shrinks_oversized_windows() {
+        let laptop = PhysicalSize::new(1440, 900);
+
+        assert_eq!(size_to_fit(PhysicalSize::new(800, 600), laptop), None);
+        assert_eq!(size_to_fit(laptop, laptop), None);
+        assert_eq!(
+            size_to_fit(PhysicalSize::new(2560, 700), laptop),
+            Some(PhysicalSize::new(1440, 700))
+        );
+        assert_eq!(
+            size_to_fit(PhysicalSize::new(3000, 2000), laptop),
+            Some(laptop)
+        );
+    }
+}
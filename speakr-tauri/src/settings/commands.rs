@@ -5,6 +5,7 @@
 use crate::settings::persistence::{
     get_settings_path, load_settings_from_dir, save_settings_to_dir,
 };
+use speakr_core::post_processing::TextPostProcessor;
 use speakr_types::{AppError, AppSettings};
 
 /// Internal implementation for saving settings.
@@ -19,13 +20,16 @@ use speakr_types::{AppError, AppSettings};
 ///
 /// # Errors
 ///
-/// Returns `AppError` if the settings cannot be saved.
+/// Returns `AppError` if the settings cannot be saved, or if a
+/// post-processing rule contains an invalid regex pattern.
 ///
 /// # Internal API
 /// This function is only intended for internal use and testing.
 pub async fn save_settings_internal(settings: AppSettings) -> Result<(), AppError> {
     // Validate settings before saving
     settings.validate().map_err(AppError::Settings)?;
+    TextPostProcessor::new(&settings.post_processing)
+        .map_err(|e| AppError::Settings(e.to_string()))?;
 
     // Use the global settings directory for production
     let settings_path = get_settings_path()?;
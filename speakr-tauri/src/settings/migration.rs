@@ -1,55 +1,183 @@
 // ============================================================================
 //! Settings Migration Logic
 // ============================================================================
+//!
+//! Settings files are migrated one schema version at a time by the ordered
+//! steps in [`MIGRATIONS`]. Each step is a [`Migration`] that rewrites the raw
+//! JSON from its version to the next, before the file is deserialized into
+//! [`AppSettings`], so steps can rename or restructure fields that the
+//! current settings type would reject.
+//!
+//! To change the schema, bump `DEFAULT_SCHEMA_VERSION` in `speakr-types`,
+//! add a step from the previous version to the end of [`MIGRATIONS`] and
+//! give it its own unit test.
 
-use speakr_types::{AppSettings, DEFAULT_AUDIO_DURATION_SECS, DEFAULT_SCHEMA_VERSION};
+use serde_json::Value;
+use speakr_types::{AppSettings, ModelId, DEFAULT_AUDIO_DURATION_SECS, DEFAULT_SCHEMA_VERSION};
 use tracing::{info, warn};
 
-/// Migrates settings from older versions to the current version.
+/// A single schema migration step.
+pub trait Migration: Send + Sync {
+    /// The schema version this step migrates from; it produces the next one.
+    fn source_version(&self) -> u32;
+
+    /// A short description of the change, for the logs.
+    fn description(&self) -> &'static str;
+
+    /// Rewrites `settings` from [`Migration::source_version`] to the next
+    /// version. The `version` field is updated by the caller.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if the settings cannot be migrated.
+    fn migrate(&self, settings: &mut Value) -> Result<(), String>;
+}
+
+/// Version 0 → 1: no structural change.
+pub struct InitialVersion;
+
+impl Migration for InitialVersion {
+    fn source_version(&self) -> u32 {
+        0
+    }
+
+    fn description(&self) -> &'static str {
+        "mark unversioned settings as version 1"
+    }
+
+    fn migrate(&self, _settings: &mut Value) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Version 1 → 2: adds `audio_duration_secs`, set to the default.
+pub struct AddAudioDuration;
+
+impl Migration for AddAudioDuration {
+    fn source_version(&self) -> u32 {
+        1
+    }
+
+    fn description(&self) -> &'static str {
+        "add the maximum recording length"
+    }
+
+    fn migrate(&self, settings: &mut Value) -> Result<(), String> {
+        settings_object(settings)?.insert(
+            "audio_duration_secs".to_string(),
+            DEFAULT_AUDIO_DURATION_SECS.into(),
+        );
+        Ok(())
+    }
+}
+
+/// Version 2 → 3: replaces the `model_size` size with a catalogue `model`.
+///
+/// The value is kept as is; [`ModelId`] reads the old sizes as the models
+/// they selected.
+pub struct ModelSizeToModel;
+
+impl Migration for ModelSizeToModel {
+    fn source_version(&self) -> u32 {
+        2
+    }
+
+    fn description(&self) -> &'static str {
+        "select a catalogue model instead of a model size"
+    }
+
+    fn migrate(&self, settings: &mut Value) -> Result<(), String> {
+        let settings = settings_object(settings)?;
+        if let Some(size) = settings.remove("model_size") {
+            let model = ModelId::new(size.as_str().unwrap_or_default());
+            settings.insert("model".to_string(), model.as_str().into());
+        }
+        Ok(())
+    }
+}
+
+/// Every migration step, ordered by the version it migrates from.
+pub static MIGRATIONS: &[&dyn Migration] = &[&InitialVersion, &AddAudioDuration, &ModelSizeToModel];
+
+/// Migrates raw settings JSON to the current schema version.
+///
+/// Settings without a `version` field are treated as version 0. Settings
+/// from a newer version than this build supports are returned unchanged.
 ///
 /// # Arguments
 ///
-/// * `settings` - The settings loaded from disk
+/// * `settings` - The settings file's JSON
 ///
 /// # Returns
 ///
-/// Returns the migrated settings with updated version number.
+/// Returns the migrated JSON, with `version` set to the version reached.
 ///
-/// # Migration Strategy
+/// # Errors
 ///
-/// - Version 0 or 1 → 2: Adds `audio_duration_secs` field, otherwise no structural change
-/// - Future versions will be handled incrementally
-/// - Invalid versions are reset to defaults with warning
+/// Returns an error message if the JSON is not an object, a step fails, or
+/// no step exists for a version along the way.
 ///
 /// # Internal API
 /// This function is only intended for internal use and testing.
-pub fn migrate_settings(mut settings: AppSettings) -> AppSettings {
-    match settings.version {
-        v if v < DEFAULT_SCHEMA_VERSION => {
-            // Migrate from any version prior to 2 – add audio_duration_secs
-            info!("Migrating settings from version {v} to 2");
-            settings.audio_duration_secs = DEFAULT_AUDIO_DURATION_SECS;
-            settings.version = 2;
-        }
-        DEFAULT_SCHEMA_VERSION => {
-            // Current version - no migration needed
-        }
-        v if v > DEFAULT_SCHEMA_VERSION => {
-            // Future version - log warning but don't modify
-            warn!(
-                "Warning: Settings file has newer version {v} than supported ({DEFAULT_SCHEMA_VERSION}). Using as-is."
-            );
-        }
-        _ => {
-            // Invalid version - reset to defaults
-            warn!(
-                "Warning: Invalid settings version {}. Resetting to defaults.",
-                settings.version
-            );
-            settings = AppSettings::default();
+pub fn migrate_settings_value(mut settings: Value) -> Result<Value, String> {
+    let mut version = schema_version(&settings);
+    if version > DEFAULT_SCHEMA_VERSION {
+        warn!(
+            "Warning: Settings file has newer version {version} than supported ({DEFAULT_SCHEMA_VERSION}). Using as-is."
+        );
+        return Ok(settings);
+    }
+
+    while version < DEFAULT_SCHEMA_VERSION {
+        let step = MIGRATIONS
+            .iter()
+            .find(|step| step.source_version() == version)
+            .ok_or_else(|| format!("No settings migration from version {version}"))?;
+        info!(
+            "Migrating settings from version {} to {}: {}",
+            version,
+            version + 1,
+            step.description()
+        );
+        step.migrate(&mut settings)?;
+        version += 1;
+        settings_object(&mut settings)?.insert("version".to_string(), version.into());
+    }
+
+    Ok(settings)
+}
+
+/// Migrates settings from older versions to the current version.
+///
+/// # Arguments
+///
+/// * `settings` - The settings loaded from disk
+///
+/// # Returns
+///
+/// Returns the migrated settings with updated version number. Settings that
+/// cannot be migrated or are invalid afterwards are reset to defaults with a
+/// warning.
+///
+/// # Internal API
+/// This function is only intended for internal use and testing.
+pub fn migrate_settings(settings: AppSettings) -> AppSettings {
+    let migrated = serde_json::to_value(&settings)
+        .map_err(|e| e.to_string())
+        .and_then(migrate_settings_value)
+        .and_then(|value| serde_json::from_value::<AppSettings>(value).map_err(|e| e.to_string()));
+
+    match migrated {
+        Ok(migrated) => validated_or_default(migrated),
+        Err(e) => {
+            warn!("Failed to migrate settings: {}. Resetting to defaults.", e);
+            AppSettings::default()
         }
     }
+}
 
+/// Returns `settings` if they are valid, otherwise the defaults.
+pub(crate) fn validated_or_default(settings: AppSettings) -> AppSettings {
     match settings.validate() {
         Ok(_) => settings,
         Err(e) => {
@@ -58,3 +186,73 @@ pub fn migrate_settings(mut settings: AppSettings) -> AppSettings {
         }
     }
 }
+
+/// The schema version of raw settings JSON, 0 if it has none.
+pub fn schema_version(settings: &Value) -> u32 {
+    settings
+        .get("version")
+        .and_then(Value::as_u64)
+        .and_then(|version| u32::try_from(version).ok())
+        .unwrap_or(0)
+}
+
+fn settings_object(settings: &mut Value) -> Result<&mut serde_json::Map<String, Value>, String> {
+    settings
+        .as_object_mut()
+        .ok_or_else(|| "Settings must be a JSON object".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_migrations_cover_every_version() {
+        for (index, step) in MIGRATIONS.iter().enumerate() {
+            assert_eq!(step.source_version(), index as u32);
+        }
+        assert_eq!(MIGRATIONS.len() as u32, DEFAULT_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_add_audio_duration_step() {
+        let mut settings = json!({ "version": 1, "hot_key": "CmdOrCtrl+Alt+D" });
+        AddAudioDuration.migrate(&mut settings).unwrap();
+        assert_eq!(
+            settings["audio_duration_secs"],
+            json!(DEFAULT_AUDIO_DURATION_SECS)
+        );
+        assert_eq!(settings["hot_key"], json!("CmdOrCtrl+Alt+D"));
+
+        assert!(AddAudioDuration.migrate(&mut json!([])).is_err());
+    }
+
+    #[test]
+    fn test_model_size_to_model_step() {
+        let mut settings = json!({ "version": 2, "model_size": "large" });
+        ModelSizeToModel.migrate(&mut settings).unwrap();
+        assert_eq!(settings["model"], json!("large-v3-turbo"));
+        assert!(settings.get("model_size").is_none());
+
+        let mut settings = json!({ "version": 2, "model": "tiny.en" });
+        ModelSizeToModel.migrate(&mut settings).unwrap();
+        assert_eq!(settings["model"], json!("tiny.en"));
+    }
+
+    #[test]
+    fn test_unversioned_settings_migrate_to_current() {
+        let migrated = migrate_settings_value(json!({ "hot_key": "F1" })).unwrap();
+        assert_eq!(schema_version(&migrated), DEFAULT_SCHEMA_VERSION);
+        assert_eq!(
+            migrated["audio_duration_secs"],
+            json!(DEFAULT_AUDIO_DURATION_SECS)
+        );
+    }
+
+    #[test]
+    fn test_newer_settings_are_left_alone() {
+        let newer = json!({ "version": DEFAULT_SCHEMA_VERSION + 1, "future": true });
+        assert_eq!(migrate_settings_value(newer.clone()).unwrap(), newer);
+    }
+}
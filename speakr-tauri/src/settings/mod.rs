@@ -4,22 +4,35 @@
 //!
 //! This module handles all settings-related functionality including:
 //! - File I/O operations and persistence
-//! - Version migrations
+//! - Per-version schema migrations, previewed until the app has loaded with them
+//! - Named profiles with their own hot-key, model and vocabulary
+//! - Quarantining unreadable settings and telling the user about it
 //! - Directory validation
 //! - Tauri command implementations
 
 pub mod commands;
 pub mod migration;
 pub mod persistence;
+pub mod preview;
+pub mod profiles;
+pub mod recovery;
 pub mod traits;
 pub mod validation;
 
 // Re-export functions needed by lib.rs and tests
 pub use commands::{load_settings_internal, save_settings_internal};
-pub use migration::migrate_settings;
+pub use migration::{migrate_settings, migrate_settings_value, Migration, MIGRATIONS};
 pub use persistence::{
-    get_settings_backup_path, get_settings_path, load_settings_from_dir, save_settings_to_dir,
-    try_load_settings_file,
+    flush_settings, get_settings_backup_path, get_settings_dir, get_settings_path,
+    load_settings_from_dir, read_settings_json, save_settings_to_dir, try_load_settings_file,
+};
+pub use preview::{
+    commit_migration_preview, commit_migration_preview_internal, rollback_interrupted_migration,
+    rollback_interrupted_migration_internal, write_migration_preview, MIGRATION_TRIAL_PERIOD,
+};
+pub use profiles::{
+    delete_profile_in_dir, load_profiles_from_dir, save_current_as_profile_in_dir,
+    switch_profile_in_dir,
 };
 pub use traits::{GlobalSettingsLoader, IsolatedSettingsLoader, SettingsLoader};
 pub use validation::validate_settings_directory_permissions;
@@ -3,13 +3,32 @@
 // ============================================================================
 
 use crate::settings::{
-    migration::migrate_settings, validation::validate_settings_directory_permissions,
+    migration::{migrate_settings_value, schema_version, validated_or_default},
+    preview::write_migration_preview,
+    recovery::quarantine_settings_file,
+    validation::validate_settings_directory_permissions,
 };
-use speakr_types::{AppError, AppSettings, DEFAULT_AUDIO_DURATION_SECS, MAX_SETTINGS_FILE_SIZE};
+use speakr_types::{AppError, AppSettings, MAX_SETTINGS_FILE_SIZE};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
 use tracing::{error, info, warn};
 
+/// Serialises writes of the settings file.
+static SETTINGS_WRITE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Waits for a settings write in progress to finish
+///
+/// Settings are written as soon as they are saved, so once this returns the
+/// file on disk is complete. Called when Speakr shuts down.
+pub fn flush_settings() {
+    drop(
+        SETTINGS_WRITE_LOCK
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner),
+    );
+}
+
 /// Gets the settings file path in the app data directory.
 ///
 /// # Internal API
@@ -40,6 +59,19 @@ pub fn get_settings_backup_path() -> Result<PathBuf, AppError> {
     Ok(settings_path.with_extension("json.backup"))
 }
 
+/// Gets the directory holding `settings.json` and the files kept next to it.
+///
+/// # Errors
+///
+/// Returns `AppError` if the config directory cannot be found or created.
+pub fn get_settings_dir() -> Result<PathBuf, AppError> {
+    let settings_path = get_settings_path()?;
+    settings_path
+        .parent()
+        .map(Path::to_path_buf)
+        .ok_or_else(|| AppError::Settings("Invalid settings path".to_string()))
+}
+
 /// Attempts to load settings from a specific file path.
 ///
 /// # Arguments
@@ -57,6 +89,46 @@ pub fn get_settings_backup_path() -> Result<PathBuf, AppError> {
 /// # Internal API
 /// This function is only intended for internal use and testing.
 pub fn try_load_settings_file(path: &PathBuf) -> Result<AppSettings, String> {
+    let json = read_settings_json(path)?;
+
+    // Out-of-range recording lengths are clamped by `AudioDuration` itself
+    serde_path_to_error::deserialize(json)
+        .map_err(|e| format!("Failed to parse settings JSON: {e}"))
+}
+
+/// Loads settings from a file, migrating them to the current schema first.
+///
+/// # Arguments
+///
+/// * `path` - The file path to load from
+///
+/// # Returns
+///
+/// Returns the migrated settings and the schema version stored in the file.
+///
+/// # Errors
+///
+/// Returns error string if the file cannot be read, migrated or parsed.
+fn load_and_migrate_settings_file(path: &PathBuf) -> Result<(AppSettings, u32), String> {
+    let json = read_settings_json(path)?;
+    let stored_version = schema_version(&json);
+    let migrated = migrate_settings_value(json)?;
+    let settings = serde_path_to_error::deserialize(migrated)
+        .map_err(|e| format!("Failed to parse settings JSON: {e}"))?;
+    Ok((validated_or_default(settings), stored_version))
+}
+
+/// Reads a settings file as raw JSON, rejecting oversized files.
+///
+/// # Arguments
+///
+/// * `path` - The file path to read
+///
+/// # Errors
+///
+/// Returns error string if the file is too large, cannot be read, or is not
+/// valid JSON.
+pub fn read_settings_json(path: &PathBuf) -> Result<serde_json::Value, String> {
     // Check file size before reading to prevent DoS attacks
     let metadata =
         fs::metadata(path).map_err(|e| format!("Failed to read settings file metadata: {e}"))?;
@@ -72,19 +144,7 @@ pub fn try_load_settings_file(path: &PathBuf) -> Result<AppSettings, String> {
     let content =
         fs::read_to_string(path).map_err(|e| format!("Failed to read settings file: {e}"))?;
 
-    let mut settings: AppSettings =
-        serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_str(&content))
-            .map_err(|e| format!("Failed to parse settings JSON: {e}"))?;
-
-    if !AppSettings::validate_audio_duration(settings.audio_duration_secs) {
-        warn!(
-            "Invalid audio duration: {}. Using default ({DEFAULT_AUDIO_DURATION_SECS}).",
-            settings.audio_duration_secs
-        );
-        settings.audio_duration_secs = DEFAULT_AUDIO_DURATION_SECS;
-    }
-
-    Ok(settings)
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse settings JSON: {e}"))
 }
 
 /// Saves application settings to a specific directory (for testing and isolation).
@@ -114,7 +174,7 @@ pub async fn save_settings_to_dir(
     // Ensure directory exists
     if !settings_dir.exists() {
         fs::create_dir_all(settings_dir)
-            .map_err(|e| AppError::FileSystem(format!("Failed to create settings dir: {e}")))?;
+            .map_err(|e| AppError::io("Failed to create settings dir", &e, Some(settings_dir)))?;
     }
 
     let settings_path = settings_dir.join("settings.json");
@@ -127,21 +187,30 @@ pub async fn save_settings_to_dir(
         .map_err(|e| AppError::Settings(format!("Failed to serialize settings: {e}")))?;
 
     // Atomic write: write to temporary file first, then rename
+    let _guard = SETTINGS_WRITE_LOCK
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
     let temp_path = settings_path.with_extension("json.tmp");
 
     // Write to temporary file
     fs::write(&temp_path, &json)
-        .map_err(|e| AppError::FileSystem(format!("Failed to write temp settings file: {e}")))?;
+        .map_err(|e| AppError::io("Failed to write temp settings file", &e, Some(&temp_path)))?;
 
     // Create backup of existing file if it exists
     if settings_path.exists() {
-        fs::copy(&settings_path, &backup_path)
-            .map_err(|e| AppError::FileSystem(format!("Failed to create settings backup: {e}")))?;
+        fs::copy(&settings_path, &backup_path).map_err(|e| {
+            AppError::io("Failed to create settings backup", &e, Some(&backup_path))
+        })?;
     }
 
     // Atomically move temp file to final location
-    fs::rename(&temp_path, &settings_path)
-        .map_err(|e| AppError::FileSystem(format!("Failed to move temp settings file: {e}")))?;
+    fs::rename(&temp_path, &settings_path).map_err(|e| {
+        AppError::io(
+            "Failed to move temp settings file",
+            &e,
+            Some(&settings_path),
+        )
+    })?;
 
     Ok(())
 }
@@ -155,7 +224,11 @@ pub async fn save_settings_to_dir(
 /// # Returns
 ///
 /// Returns the loaded settings or default settings if the file doesn't exist.
-/// If the file is corrupt, attempts to recover from backup, then falls back to defaults.
+/// If the file is corrupt, attempts to recover from backup, then falls back to
+/// defaults, quarantining the corrupt file and recording a notice for the user;
+/// see [`crate::settings::recovery`].
+/// Settings from an older schema are migrated in memory and written to a
+/// preview file; see [`crate::settings::preview`].
 ///
 /// # Errors
 ///
@@ -172,14 +245,14 @@ pub async fn load_settings_from_dir(settings_dir: &PathBuf) -> Result<AppSetting
     }
 
     // Try to load from main settings file
-    match try_load_settings_file(&settings_path) {
-        Ok(settings) => {
-            let original_version = settings.version;
-            let migrated_settings = migrate_settings(settings);
-            // If migration changed the version, save the updated settings
+    match load_and_migrate_settings_file(&settings_path) {
+        Ok((migrated_settings, original_version)) => {
+            // If migration changed the version, preview the migrated settings
+            // and keep the original until the app has loaded with them
             if migrated_settings.version != original_version {
-                // Save the migrated settings (fire and forget)
-                let _ = save_settings_to_dir(&migrated_settings, settings_dir).await;
+                if let Err(e) = write_migration_preview(&migrated_settings, settings_dir) {
+                    warn!("Failed to write migrated settings preview: {}", e);
+                }
             }
             Ok(migrated_settings)
         }
@@ -188,10 +261,9 @@ pub async fn load_settings_from_dir(settings_dir: &PathBuf) -> Result<AppSetting
 
             // Try to recover from backup
             if backup_path.exists() {
-                match try_load_settings_file(&backup_path) {
-                    Ok(backup_settings) => {
+                match load_and_migrate_settings_file(&backup_path) {
+                    Ok((migrated_settings, _)) => {
                         info!("Successfully recovered settings from backup");
-                        let migrated_settings = migrate_settings(backup_settings);
 
                         // Save the recovered settings to main file
                         if let Err(save_error) =
@@ -205,10 +277,11 @@ pub async fn load_settings_from_dir(settings_dir: &PathBuf) -> Result<AppSetting
                     Err(backup_error) => {
                         error!("Warning: Backup settings file also corrupt: {backup_error}");
 
-                        // Move corrupt files aside for debugging
-                        let _ = fs::rename(
+                        // Keep the corrupt files for recovery and tell the user
+                        quarantine_settings_file(
+                            settings_dir,
                             &settings_path,
-                            settings_path.with_extension("json.corrupt"),
+                            &format!("{main_error} (backup: {backup_error})"),
                         );
                         let _ =
                             fs::rename(&backup_path, backup_path.with_extension("json.corrupt"));
@@ -226,8 +299,8 @@ pub async fn load_settings_from_dir(settings_dir: &PathBuf) -> Result<AppSetting
             } else {
                 info!("No backup file available. Using defaults.");
 
-                // Move corrupt file aside and save defaults
-                let _ = fs::rename(&settings_path, settings_path.with_extension("json.corrupt"));
+                // Keep the corrupt file for recovery, tell the user and save defaults
+                quarantine_settings_file(settings_dir, &settings_path, &main_error);
                 let defaults = AppSettings::default();
                 if let Err(save_error) = save_settings_to_dir(&defaults, settings_dir).await {
                     error!("Warning: Failed to save default settings: {save_error}");
@@ -0,0 +1,181 @@
+// ============================================================================
+//! Settings Migration Preview
+// ============================================================================
+//!
+//! Schema migrations are not applied to `settings.json` straight away. The
+//! migrated settings are written to a preview file and used in memory, while
+//! the original file stays untouched until the app has run successfully once
+//! with them:
+//!
+//! 1. Loading settings that need migrating writes `settings.json.preview`
+//!    and a trial marker next to it.
+//! 2. Once the app has been running for [`MIGRATION_TRIAL_PERIOD`], or
+//!    quits normally, [`commit_migration_preview`] promotes the preview to `settings.json`
+//!    (the original becomes the backup) and removes the marker.
+//! 3. If the app crashes before that, the marker is still present on the
+//!    next launch and [`rollback_interrupted_migration`] sets the preview
+//!    aside as `settings.json.preview.failed`. Later launches migrate in
+//!    memory only, leaving the original file as it is until the user saves.
+
+use crate::settings::migration::schema_version;
+use crate::settings::persistence::{
+    get_settings_dir, read_settings_json, save_settings_to_dir, try_load_settings_file,
+};
+use speakr_types::{AppError, AppSettings, DEFAULT_SCHEMA_VERSION};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+/// How long the app must run with migrated settings before they are kept.
+pub const MIGRATION_TRIAL_PERIOD: Duration = Duration::from_secs(30);
+
+const PREVIEW_FILE: &str = "settings.json.preview";
+const TRIAL_MARKER_FILE: &str = "settings.json.preview.trial";
+const FAILED_PREVIEW_FILE: &str = "settings.json.preview.failed";
+
+/// Returns `true` if settings at `version` need migrating.
+fn needs_migration(version: u32) -> bool {
+    version < DEFAULT_SCHEMA_VERSION
+}
+
+/// Writes migrated settings to the preview file and starts a trial.
+///
+/// Nothing is written if an earlier trial with these settings crashed; the
+/// migration then only applies in memory.
+///
+/// # Arguments
+///
+/// * `settings` - The migrated settings
+/// * `settings_dir` - The directory holding `settings.json`
+///
+/// # Errors
+///
+/// Returns `AppError::Io` if the preview or marker cannot be written.
+pub fn write_migration_preview(
+    settings: &AppSettings,
+    settings_dir: &Path,
+) -> Result<(), AppError> {
+    if settings_dir.join(FAILED_PREVIEW_FILE).exists() {
+        warn!("An earlier settings migration failed; migrating in memory only");
+        return Ok(());
+    }
+
+    let json = serde_json::to_string_pretty(settings)
+        .map_err(|e| AppError::Settings(format!("Failed to serialize settings: {e}")))?;
+    let preview_path = settings_dir.join(PREVIEW_FILE);
+    fs::write(&preview_path, json)
+        .map_err(|e| AppError::io("Failed to write settings preview", &e, Some(&preview_path)))?;
+    let marker_path = settings_dir.join(TRIAL_MARKER_FILE);
+    fs::write(&marker_path, "")
+        .map_err(|e| AppError::io("Failed to mark migration trial", &e, Some(&marker_path)))?;
+    info!("Wrote migrated settings preview; original kept until the app has loaded");
+    Ok(())
+}
+
+/// Keeps the migrated settings after a successful trial.
+///
+/// The preview replaces `settings.json`, whose previous contents become the
+/// backup. If the user saved settings during the trial, the preview is stale
+/// and is discarded instead.
+///
+/// # Arguments
+///
+/// * `settings_dir` - The directory holding `settings.json`
+///
+/// # Returns
+///
+/// Returns `true` if a migration was committed.
+///
+/// # Errors
+///
+/// Returns `AppError` if the preview cannot be read or saved.
+pub async fn commit_migration_preview(settings_dir: &PathBuf) -> Result<bool, AppError> {
+    let preview_path = settings_dir.join(PREVIEW_FILE);
+    if !preview_path.exists() {
+        return Ok(false);
+    }
+
+    let current = read_settings_json(&settings_dir.join("settings.json"));
+    let committed = match current {
+        Ok(current) if !needs_migration(schema_version(&current)) => {
+            info!("Settings were saved during the migration trial; discarding preview");
+            false
+        }
+        _ => {
+            let preview = try_load_settings_file(&preview_path).map_err(AppError::Settings)?;
+            save_settings_to_dir(&preview, settings_dir).await?;
+            info!(
+                "Committed settings migration to version {}",
+                preview.version
+            );
+            true
+        }
+    };
+
+    remove_if_exists(&preview_path);
+    remove_if_exists(&settings_dir.join(TRIAL_MARKER_FILE));
+    Ok(committed)
+}
+
+/// Rolls back a migration whose trial did not finish.
+///
+/// Call this at startup, before settings are loaded. A trial marker left by
+/// the previous run means it ended before the migration was committed, so
+/// the preview is set aside and the original settings file is kept.
+///
+/// # Arguments
+///
+/// * `settings_dir` - The directory holding `settings.json`
+///
+/// # Returns
+///
+/// Returns `true` if a migration was rolled back.
+pub fn rollback_interrupted_migration(settings_dir: &Path) -> bool {
+    let marker = settings_dir.join(TRIAL_MARKER_FILE);
+    if !marker.exists() {
+        return false;
+    }
+
+    error!("The app did not finish loading after a settings migration; rolling back");
+    let preview = settings_dir.join(PREVIEW_FILE);
+    if preview.exists() {
+        if let Err(e) = fs::rename(&preview, settings_dir.join(FAILED_PREVIEW_FILE)) {
+            warn!("Failed to set aside settings preview: {}", e);
+            remove_if_exists(&preview);
+        }
+    }
+    remove_if_exists(&marker);
+    true
+}
+
+/// Rolls back an interrupted migration in the global settings directory.
+///
+/// See [`rollback_interrupted_migration`].
+///
+/// # Errors
+///
+/// Returns `AppError` if the settings directory cannot be found.
+pub fn rollback_interrupted_migration_internal() -> Result<bool, AppError> {
+    Ok(rollback_interrupted_migration(&get_settings_dir()?))
+}
+
+/// Commits a pending migration in the global settings directory.
+///
+/// See [`commit_migration_preview`].
+///
+/// # Errors
+///
+/// Returns `AppError` if the settings directory cannot be found or the
+/// preview cannot be committed.
+pub async fn commit_migration_preview_internal() -> Result<bool, AppError> {
+    commit_migration_preview(&get_settings_dir()?).await
+}
+
+fn remove_if_exists(path: &Path) {
+    if path.exists() {
+        if let Err(e) = fs::remove_file(path) {
+            warn!("Failed to remove {}: {}", path.display(), e);
+        }
+    }
+}
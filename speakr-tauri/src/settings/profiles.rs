@@ -0,0 +1,198 @@
+// ============================================================================
+//! Settings Profiles
+// ============================================================================
+//!
+//! Named profiles (e.g. "Work", "Personal", "Meetings") hold their own
+//! hot-key, model and vocabulary. They are stored in `profiles.json` next to
+//! `settings.json`; switching to one copies its values into the active
+//! settings, after saving the values in use back to the profile being left,
+//! so edits made while a profile is active are kept with it.
+
+use crate::settings::persistence::{
+    get_settings_dir, load_settings_from_dir, save_settings_to_dir,
+};
+use speakr_types::{AppError, AppSettings, SettingsProfile, SettingsProfiles};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+/// Name of the file holding the saved profiles.
+const PROFILES_FILE: &str = "profiles.json";
+
+/// Loads the saved profiles from a specific directory.
+///
+/// # Arguments
+///
+/// * `settings_dir` - The directory holding `settings.json`
+///
+/// # Returns
+///
+/// Returns the saved profiles, or none if the file does not exist.
+///
+/// # Errors
+///
+/// Returns `AppError::Io` if the file cannot be read, or
+/// `AppError::Settings` if it cannot be parsed.
+pub fn load_profiles_from_dir(settings_dir: &Path) -> Result<SettingsProfiles, AppError> {
+    let path = settings_dir.join(PROFILES_FILE);
+    if !path.exists() {
+        return Ok(SettingsProfiles::default());
+    }
+    let content = fs::read_to_string(&path)
+        .map_err(|e| AppError::io("Failed to read profiles", &e, Some(&path)))?;
+    serde_json::from_str(&content)
+        .map_err(|e| AppError::Settings(format!("Failed to parse profiles: {e}")))
+}
+
+/// Saves profiles to a specific directory.
+///
+/// # Errors
+///
+/// Returns `AppError::Io` if the file cannot be written.
+pub fn save_profiles_to_dir(
+    profiles: &SettingsProfiles,
+    settings_dir: &Path,
+) -> Result<(), AppError> {
+    let json = serde_json::to_string_pretty(profiles)
+        .map_err(|e| AppError::Settings(format!("Failed to serialize profiles: {e}")))?;
+    let path = settings_dir.join(PROFILES_FILE);
+    fs::write(&path, json).map_err(|e| AppError::io("Failed to write profiles", &e, Some(&path)))
+}
+
+/// Saves the current hot-key, model and vocabulary as a profile and makes
+/// it the active one.
+///
+/// # Arguments
+///
+/// * `settings_dir` - The directory holding `settings.json`
+/// * `name` - The profile's name; a profile with the same name is replaced
+///
+/// # Returns
+///
+/// Returns the updated profiles.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if the name is blank, or an `AppError` if
+/// the settings or profiles cannot be read or written.
+pub async fn save_current_as_profile_in_dir(
+    settings_dir: &PathBuf,
+    name: &str,
+) -> Result<SettingsProfiles, AppError> {
+    let settings = load_settings_from_dir(settings_dir).await?;
+    let mut profiles = load_profiles_from_dir(settings_dir)?;
+    profiles
+        .upsert(SettingsProfile::from_settings(name.trim(), &settings))
+        .map_err(AppError::Settings)?;
+    profiles.active = Some(name.trim().to_string());
+    save_profiles_to_dir(&profiles, settings_dir)?;
+    info!("Saved settings profile '{}'", name.trim());
+    Ok(profiles)
+}
+
+/// Deletes a saved profile.
+///
+/// The active settings are left as they are.
+///
+/// # Returns
+///
+/// Returns the updated profiles.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if no profile has that name, or
+/// `AppError::Io` if the profiles cannot be read or written.
+pub fn delete_profile_in_dir(
+    settings_dir: &Path,
+    name: &str,
+) -> Result<SettingsProfiles, AppError> {
+    let mut profiles = load_profiles_from_dir(settings_dir)?;
+    if !profiles.remove(name) {
+        return Err(AppError::Settings(format!("No profile named '{name}'")));
+    }
+    save_profiles_to_dir(&profiles, settings_dir)?;
+    info!("Deleted settings profile '{}'", name);
+    Ok(profiles)
+}
+
+/// Switches the active settings to a saved profile.
+///
+/// The hot-key, model and vocabulary in use are first saved to the profile
+/// being left, if there is one.
+///
+/// # Arguments
+///
+/// * `settings_dir` - The directory holding `settings.json`
+/// * `name` - The profile to switch to
+///
+/// # Returns
+///
+/// Returns the settings now in effect.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if no profile has that name or the result
+/// is invalid, or an `AppError` if the files cannot be read or written.
+pub async fn switch_profile_in_dir(
+    settings_dir: &PathBuf,
+    name: &str,
+) -> Result<AppSettings, AppError> {
+    let mut profiles = load_profiles_from_dir(settings_dir)?;
+    let target = profiles
+        .get(name)
+        .cloned()
+        .ok_or_else(|| AppError::Settings(format!("No profile named '{name}'")))?;
+
+    let mut settings = load_settings_from_dir(settings_dir).await?;
+    if let Some(active) = profiles.active.clone() {
+        if profiles.get(&active).is_some() {
+            profiles
+                .upsert(SettingsProfile::from_settings(active, &settings))
+                .map_err(AppError::Settings)?;
+        }
+    }
+
+    target.apply_to(&mut settings);
+    save_settings_to_dir(&settings, settings_dir).await?;
+    profiles.active = Some(target.name.clone());
+    save_profiles_to_dir(&profiles, settings_dir)?;
+
+    info!("Switched to settings profile '{}'", target.name);
+    Ok(settings)
+}
+
+/// Loads the saved profiles from the global settings directory.
+///
+/// # Errors
+///
+/// See [`load_profiles_from_dir`].
+pub fn load_profiles_internal() -> Result<SettingsProfiles, AppError> {
+    load_profiles_from_dir(&get_settings_dir()?)
+}
+
+/// Saves the current settings as a profile in the global settings directory.
+///
+/// # Errors
+///
+/// See [`save_current_as_profile_in_dir`].
+pub async fn save_current_as_profile_internal(name: &str) -> Result<SettingsProfiles, AppError> {
+    save_current_as_profile_in_dir(&get_settings_dir()?, name).await
+}
+
+/// Deletes a profile from the global settings directory.
+///
+/// # Errors
+///
+/// See [`delete_profile_in_dir`].
+pub fn delete_profile_internal(name: &str) -> Result<SettingsProfiles, AppError> {
+    delete_profile_in_dir(&get_settings_dir()?, name)
+}
+
+/// Switches to a profile in the global settings directory.
+///
+/// # Errors
+///
+/// See [`switch_profile_in_dir`].
+pub async fn switch_profile_internal(name: &str) -> Result<AppSettings, AppError> {
+    switch_profile_in_dir(&get_settings_dir()?, name).await
+}
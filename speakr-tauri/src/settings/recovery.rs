@@ -0,0 +1,194 @@
+// ============================================================================
+//! Settings Recovery Notice
+// ============================================================================
+//!
+//! When `settings.json` cannot be read and no readable backup exists, Speakr
+//! starts with default settings. Rather than doing so silently:
+//!
+//! 1. The unreadable file is quarantined as
+//!    `settings.corrupt-<timestamp>.json`, so earlier copies are never
+//!    overwritten and the user can recover what they had.
+//! 2. A [`SettingsRecovery`] notice is written to `settings.json.recovered`.
+//! 3. At startup the notice is emitted on [`SETTINGS_RECOVERED_EVENT`]; the
+//!    UI also asks for it when it loads, in case it missed the event. It is
+//!    shown until the user dismisses it, which deletes the notice.
+
+use crate::settings::load_settings_internal;
+use crate::settings::persistence::get_settings_dir;
+use speakr_types::{AppError, SettingsRecovery, SETTINGS_RECOVERED_EVENT};
+use std::fs;
+use std::path::Path;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_opener::OpenerExt;
+use tracing::{error, info, warn};
+
+/// Name of the file holding the notice until it is dismissed.
+const RECOVERY_NOTICE_FILE: &str = "settings.json.recovered";
+
+/// Moves an unreadable settings file aside and records a notice for the user.
+///
+/// # Arguments
+///
+/// * `settings_dir` - The directory holding `settings.json`
+/// * `settings_path` - The unreadable settings file
+/// * `reason` - Why the settings and backup could not be loaded
+///
+/// # Returns
+///
+/// Returns the notice, or `None` if the file could not be moved; it is then
+/// left in place and overwritten by the defaults.
+pub fn quarantine_settings_file(
+    settings_dir: &Path,
+    settings_path: &Path,
+    reason: &str,
+) -> Option<SettingsRecovery> {
+    let recovered_at = chrono::Utc::now().timestamp_millis() as u64;
+    let quarantined = settings_dir.join(format!("settings.corrupt-{recovered_at}.json"));
+    if let Err(e) = fs::rename(settings_path, &quarantined) {
+        error!("Failed to quarantine corrupt settings file: {e}");
+        return None;
+    }
+    warn!(
+        "Corrupt settings quarantined at {}; using defaults",
+        quarantined.display()
+    );
+
+    let recovery = SettingsRecovery {
+        quarantined_path: quarantined.display().to_string(),
+        error: reason.to_string(),
+        recovered_at,
+    };
+    let written = serde_json::to_string_pretty(&recovery)
+        .map_err(|e| e.to_string())
+        .and_then(|json| {
+            fs::write(settings_dir.join(RECOVERY_NOTICE_FILE), json).map_err(|e| e.to_string())
+        });
+    if let Err(e) = written {
+        error!("Failed to record settings recovery notice: {e}");
+    }
+    Some(recovery)
+}
+
+/// Loads the notice from a specific directory.
+///
+/// # Returns
+///
+/// Returns the notice, or `None` if there is none or it has been dismissed.
+///
+/// # Errors
+///
+/// Returns `AppError::Io` if the file cannot be read, or
+/// `AppError::Settings` if it cannot be parsed.
+pub fn load_settings_recovery_from_dir(dir: &Path) -> Result<Option<SettingsRecovery>, AppError> {
+    let path = dir.join(RECOVERY_NOTICE_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(&path)
+        .map_err(|e| AppError::io("Failed to read settings recovery notice", &e, Some(&path)))?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| AppError::Settings(format!("Failed to parse settings recovery notice: {e}")))
+}
+
+/// Deletes the notice in `dir`; the quarantined file is kept.
+///
+/// # Errors
+///
+/// Returns `AppError::Io` if the notice exists but cannot be removed.
+pub fn dismiss_settings_recovery_in_dir(dir: &Path) -> Result<(), AppError> {
+    let path = dir.join(RECOVERY_NOTICE_FILE);
+    match fs::remove_file(&path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(AppError::io(
+            "Failed to dismiss settings recovery notice",
+            &e,
+            Some(&path),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Tells the UI at startup if the settings had to be reset
+///
+/// Settings are loaded first, so a corrupt file found now is reported too.
+pub fn spawn_settings_recovery_notice(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = load_settings_internal().await {
+            warn!("Failed to load settings: {}", e);
+        }
+        match get_settings_recovery_internal() {
+            Ok(Some(recovery)) => {
+                if let Err(e) = app_handle.emit(SETTINGS_RECOVERED_EVENT, &recovery) {
+                    warn!("Failed to emit settings recovery notice: {}", e);
+                }
+            }
+            Ok(None) => {}
+            Err(e) => warn!("Failed to check for a settings recovery notice: {}", e),
+        }
+    });
+}
+
+/// Returns the notice that has not been dismissed yet, if any.
+///
+/// # Errors
+///
+/// See [`load_settings_recovery_from_dir`].
+pub fn get_settings_recovery_internal() -> Result<Option<SettingsRecovery>, AppError> {
+    load_settings_recovery_from_dir(&get_settings_dir()?)
+}
+
+/// Dismisses the notice so it is not shown again.
+///
+/// # Errors
+///
+/// See [`dismiss_settings_recovery_in_dir`].
+pub fn dismiss_settings_recovery_internal() -> Result<(), AppError> {
+    dismiss_settings_recovery_in_dir(&get_settings_dir()?)?;
+    info!("Settings recovery notice dismissed");
+    Ok(())
+}
+
+/// Opens the quarantined settings file in the default editor.
+///
+/// # Errors
+///
+/// Returns `AppError::Settings` if there is no notice or the file cannot be
+/// opened.
+pub fn open_quarantined_settings_internal(app_handle: &AppHandle) -> Result<(), AppError> {
+    let recovery = get_settings_recovery_internal()?
+        .ok_or_else(|| AppError::Settings("No quarantined settings file".to_string()))?;
+    app_handle
+        .opener()
+        .open_path(&recovery.quarantined_path, None::<&str>)
+        .map_err(|e| {
+            AppError::Settings(format!("Failed to open {}: {e}", recovery.quarantined_path))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_quarantine_keeps_the_file_and_records_a_notice() {
+        let dir = TempDir::new().unwrap();
+        let settings_path = dir.path().join("settings.json");
+        fs::write(&settings_path, "{ not json").unwrap();
+
+        let recovery = quarantine_settings_file(dir.path(), &settings_path, "bad json").unwrap();
+        assert!(!settings_path.exists());
+        assert_eq!(
+            fs::read_to_string(&recovery.quarantined_path).unwrap(),
+            "{ not json"
+        );
+        assert_eq!(
+            load_settings_recovery_from_dir(dir.path()).unwrap(),
+            Some(recovery)
+        );
+
+        dismiss_settings_recovery_in_dir(dir.path()).unwrap();
+        assert_eq!(load_settings_recovery_from_dir(dir.path()).unwrap(), None);
+        dismiss_settings_recovery_in_dir(dir.path()).unwrap();
+    }
+}
@@ -4,22 +4,91 @@
 //! This module orchestrates the complete dictation pipeline:
 //! 1. Audio capture using speakr-core
 //! 2. Transcription (placeholder for future implementation)
-//! 3. Text injection (placeholder for future implementation)
+//! 3. Post-processing of the transcript using the user's text transforms
+//! 4. Text injection through the platform backend (see [`crate::injection`])
 //!
 //! The workflow is triggered by global hotkey events and provides
-//! comprehensive error handling and user feedback.
+//! comprehensive error handling and user feedback. A dictation in flight can
+//! be cancelled (hotkey pressed again, Esc, or the `cancel_dictation`
+//! command), which stops the recorder, drops any pending transcription and
+//! emits `dictation-cancelled`.
+//!
+//! Alongside the individual step events, progress is reported as structured
+//! [`PipelineEvent`]s on the [`PIPELINE_EVENT`] channel.
+//!
+//! With long-form dictation on, recordings run for minutes instead of
+//! seconds and are transcribed in overlapping chunks while they are recorded
+//! (see [`speakr_core::long_form`]), leaving only the last chunk to
+//! transcribe once the user stops. Noise suppression and gain control are
+//! applied to each chunk on its own.
 // ============================================================================
 
 // =========================
 // External Imports
 // =========================
+use crate::audio::files::{save_tagged_recording, RecordingMetadata};
+use crate::audio::pre_buffer::pre_buffering_recorder;
+use crate::commands::storage::recordings_dir;
+use crate::crash::record_pipeline_state;
+#[cfg(debug_assertions)]
+use crate::debug::retain_last_recording;
+use crate::injection::{injection_suppression, run_injector};
+use crate::lifecycle::is_shutting_down;
+use crate::logging::INJECTION_TARGET;
+use crate::services::dictation_session::{
+    begin_session_dictation, join_session, record_session_delivery,
+};
+use crate::services::dock_badge::{BadgeState, DockBadge};
+use crate::services::event_throttle::{ThrottledEmitter, DEFAULT_MIN_EVENT_INTERVAL};
+use crate::services::history::record_history;
+use crate::services::hotkey::{register_cancel_shortcut, unregister_cancel_shortcut};
+use crate::services::input_preview::stop_input_preview_internal;
+use crate::services::last_transcription::retain_last_transcription;
+use crate::services::model_manager::resident_engine;
+use crate::services::notifications::{notification_preview, DictationNotifier};
+use crate::services::pause::is_dictation_enabled;
+use crate::services::pending::queue_pending;
+use crate::services::performance::record_dictation_timings;
+use crate::services::privacy::{is_privacy_mode_active, loggable_text};
+use crate::services::target_app::frontmost_app_id;
 use crate::settings::{GlobalSettingsLoader, SettingsLoader};
-use speakr_core::audio::{AudioRecorder, RecordingConfig};
-use speakr_types::{AppError, AppSettings};
-use std::sync::Arc;
-use std::time::Duration;
+use speakr_core::audio::{denoise::Denoiser, gain};
+use speakr_core::audio::{AudioRecorder, RecordingConfig, LEVEL_WINDOW_MS};
+use speakr_core::formatting::format_paragraphs;
+use speakr_core::long_form::{ChunkedTranscript, LONG_FORM_POLL_INTERVAL};
+use speakr_core::pipeline::transcription_pipeline_with_handle;
+use speakr_core::plugins::PluginManager;
+use speakr_core::post_processing::{run_stage, TextPostProcessor};
+use speakr_core::streaming::{LiveTranscript, TextEdit, STREAMING_POLL_INTERVAL};
+use speakr_core::transcription::engine::TranscriptionHandle;
+use speakr_core::transcription::language;
+use speakr_core::transcription::models::ModelManager;
+use speakr_core::transcription::performance::DictationTimings;
+use speakr_core::voice_command::{listen_for_stop_phrase, StopPhraseDetector};
+use speakr_types::events::{
+    AudioCaptureCompleted, WorkflowError, AUDIO_CAPTURE_COMPLETED_EVENT,
+    AUDIO_CAPTURE_STARTED_EVENT, DICTATION_CANCELLED_EVENT, TEXT_COPIED_EVENT,
+    TEXT_INJECTION_COMPLETED_EVENT, TEXT_INJECTION_REVISED_EVENT, TEXT_INJECTION_SANDBOXED_EVENT,
+    TEXT_INJECTION_STARTED_EVENT, TRANSCRIPTION_COMPLETED_EVENT, TRANSCRIPTION_ERROR_EVENT,
+    TRANSCRIPTION_LANGUAGE_EVENT, TRANSCRIPTION_PROGRESS_EVENT, TRANSCRIPTION_STARTED_EVENT,
+    WORKFLOW_COMPLETED_EVENT, WORKFLOW_ERROR_EVENT, WORKFLOW_STARTED_EVENT,
+};
+use speakr_types::{
+    AppError, AudioDuration, AutoGain, DictationLatency, DictationOverrides, DictationSummary,
+    InjectionMethod, InjectionOptions, NewlineOptions, OutputMode, PendingReason, PipelineEvent,
+    TranscriptionConfig, TranscriptionResult, DICTATION_LATENCY_EVENT, PIPELINE_EVENT,
+    TRANSCRIPTION_RESULT_EVENT,
+};
+use std::borrow::Cow;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, PoisonError};
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use tauri_plugin_notification::NotificationExt;
 use tracing::{debug, error, info, instrument, warn};
+use zeroize::{Zeroize, Zeroizing};
 
 // ============================================================================
 // Workflow Orchestration
@@ -30,6 +99,7 @@ use tracing::{debug, error, info, instrument, warn};
 /// # Arguments
 ///
 /// * `app_handle` - The Tauri application handle for event emission
+/// * `overrides` - Settings replaced for this dictation only
 ///
 /// # Returns
 ///
@@ -39,9 +109,12 @@ use tracing::{debug, error, info, instrument, warn};
 ///
 /// Returns `AppError` if audio capture, transcription, or text injection fails.
 #[instrument(level = "info", skip(app_handle))]
-pub async fn execute_dictation_workflow(app_handle: AppHandle) -> Result<(), AppError> {
+pub async fn execute_dictation_workflow(
+    app_handle: AppHandle,
+    overrides: DictationOverrides,
+) -> Result<(), AppError> {
     let loader = GlobalSettingsLoader;
-    execute_dictation_workflow_with_loader(app_handle, Arc::new(loader)).await
+    execute_dictation_workflow_with_loader(app_handle, Arc::new(loader), overrides).await
 }
 
 /// Executes the complete dictation workflow with custom settings loader (for testing)
@@ -50,6 +123,7 @@ pub async fn execute_dictation_workflow(app_handle: AppHandle) -> Result<(), App
 ///
 /// * `app_handle` - The Tauri application handle for event emission
 /// * `loader` - The settings loader to use
+/// * `overrides` - Settings replaced for this dictation only
 ///
 /// # Returns
 ///
@@ -62,55 +136,801 @@ pub async fn execute_dictation_workflow(app_handle: AppHandle) -> Result<(), App
 pub async fn execute_dictation_workflow_with_loader(
     app_handle: AppHandle,
     loader: Arc<dyn SettingsLoader>,
+    overrides: DictationOverrides,
 ) -> Result<(), AppError> {
     info!("🎙️ Starting dictation workflow");
+    // The workflow is spawned as soon as the hot-key fires
+    let triggered_at = Instant::now();
+    let recorded_at = chrono::Utc::now();
+
+    // The target still has focus when the hotkey is pressed
+    let target_app = frontmost_app_id();
+    // A gain preview left open in Settings would hold the microphone
+    stop_input_preview_internal();
 
     // Emit workflow start event for UI feedback
-    let _ = app_handle.emit("workflow-started", ());
+    let _ = app_handle.emit(WORKFLOW_STARTED_EVENT, ());
+
+    let mut transcription_config = create_transcription_config_with_loader(loader.clone()).await;
+    overrides.apply(&mut transcription_config);
+    let post_processor =
+        create_post_processor_for_app_with_loader(loader.clone(), target_app.as_deref()).await;
+    let dock_badge = create_dock_badge_with_loader(&app_handle, loader.clone()).await;
+    let notifier = create_notifier_with_loader(&app_handle, loader.clone()).await;
+    let stop_phrase = create_stop_phrase_detector_with_loader(loader.clone()).await;
+    let denoiser = create_denoiser_with_loader(loader.clone()).await;
+    let auto_gain = create_auto_gain_with_loader(loader.clone()).await;
+    let delivery = create_delivery_for_app_with_loader(loader.clone(), target_app.as_deref()).await;
+    let recording_template = create_recording_template_with_loader(loader.clone()).await;
+    begin_session_dictation(&app_handle, delivery.session_window, target_app.as_deref());
+    let mut streamer = delivery.stream_words.then(|| WordStreamer {
+        config: transcription_config.clone(),
+        post_processor: post_processor.clone(),
+        options: delivery.injection,
+        live: LiveTranscript::default(),
+    });
+    let mut chunker =
+        create_long_form_with_loader(loader.clone())
+            .await
+            .then(|| ChunkTranscriber {
+                config: transcription_config.clone(),
+                denoiser: denoiser.clone(),
+                auto_gain,
+                transcript: ChunkedTranscript::default(),
+            });
 
     // Step 1: Audio Capture
-    let audio_samples = match capture_audio_with_loader(&app_handle, loader).await {
-        Ok(samples) => {
-            info!("✅ Audio capture completed with {} samples", samples.len());
-            samples
+    dock_badge.set(BadgeState::Recording);
+    let capture_started = Instant::now();
+    let (mut audio_samples, input_device, recording_started, recording_stopped) =
+        match capture_audio_with_loader(
+            &app_handle,
+            loader,
+            stop_phrase.as_ref(),
+            &transcription_config,
+            streamer.as_mut(),
+            chunker.as_mut(),
+        )
+        .await
+        {
+            Ok((samples, input_device, recording_started)) => {
+                let recording_stopped = Instant::now();
+                info!("✅ Audio capture completed with {} samples", samples.len());
+                #[cfg(debug_assertions)]
+                retain_last_recording(&samples);
+                (samples, input_device, recording_started, recording_stopped)
+            }
+            Err(e) => {
+                error!("❌ Audio capture failed: {}", e);
+                let _ = app_handle.emit(
+                    WORKFLOW_ERROR_EVENT,
+                    WorkflowError::new(format!("Audio capture failed: {e}")),
+                );
+                emit_pipeline_event(&app_handle, PipelineEvent::Failed(e.clone()));
+                notifier.failed(&e);
+                return Err(e);
+            }
+        };
+
+    // Steps 2 & 3: Transcription and text injection
+    // The clipboard notification already tells the user the dictation is done
+    let copy_notified = delivery.copies()
+        && (delivery.notify_on_copy || delivery.injection_blocked)
+        && !delivery.dry_run;
+    let mode = match streamer {
+        Some(streamer) => InjectionMode::Streamed(delivery, streamer.live),
+        None => InjectionMode::Live(delivery),
+    };
+    let capture_time = capture_started.elapsed();
+    // The copy kept for saving is wiped when dropped, however the dictation ends
+    let (audio_samples, saved_samples) = match chunker.as_ref() {
+        Some(chunker) => {
+            // The saved copy is conditioned as a whole, like any other
+            let saved = recording_template.as_ref().map(|_| {
+                Zeroizing::new(condition_samples(
+                    denoiser.as_ref(),
+                    auto_gain,
+                    &mut audio_samples.clone(),
+                ))
+            });
+            (chunker.condition_remaining(audio_samples), saved)
         }
-        Err(e) => {
-            error!("❌ Audio capture failed: {}", e);
-            let _ = app_handle.emit("workflow-error", format!("Audio capture failed: {e}"));
-            return Err(e);
+        None => {
+            let samples = condition_samples(denoiser.as_ref(), auto_gain, &mut audio_samples);
+            let saved = recording_template
+                .as_ref()
+                .map(|_| Zeroizing::new(samples.clone()));
+            (samples, saved)
         }
     };
+    let completed = complete_workflow_from_samples(
+        audio_samples,
+        chunker.map(|chunker| chunker.transcript),
+        transcription_config,
+        post_processor,
+        &app_handle,
+        &dock_badge,
+        stop_phrase.as_ref(),
+        mode,
+    )
+    .await
+    .inspect_err(|e| notifier.failed(e))?;
+    if !copy_notified {
+        notifier.finished(&completed.text);
+    }
+
+    record_dictation_timings(
+        DictationTimings {
+            hotkey_to_record_start: recording_started.saturating_duration_since(triggered_at),
+            record_stop_to_first_token: completed
+                .transcribed_at
+                .saturating_duration_since(recording_stopped),
+            end_to_end: triggered_at.elapsed(),
+        },
+        &completed.latency.processing_stages,
+    );
+    if is_privacy_mode_active() {
+        debug!("Privacy mode on; recording not saved");
+    } else if let (Some(template), Some(samples)) = (recording_template, saved_samples) {
+        let metadata = RecordingMetadata {
+            recorded_at,
+            duration: Duration::from_secs_f64(samples.len() as f64 / 16_000.0),
+            model: completed.latency.model.clone(),
+            target_app,
+        };
+        save_dictation_recording(&samples, &template, &metadata).await;
+    }
+    let latency = DictationLatency {
+        capture_ms: millis(capture_time),
+        input_device,
+        ..completed.latency
+    };
+    report_latency(&app_handle, &latency);
+    *LAST_DICTATION
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = Some(DictationSummary {
+        completed_at: chrono::Utc::now().timestamp_millis() as u64,
+        word_count: completed.text.split_whitespace().count(),
+        latency,
+    });
+    Ok(())
+}
+
+// ============================================================================
+// Cancellation
+// ============================================================================
+
+/// The dictation currently in flight.
+struct ActiveDictation {
+    id: u64,
+    task: tauri::async_runtime::JoinHandle<()>,
+    /// Aborts the dictation's inference, which runs on a blocking thread
+    /// that aborting `task` does not stop.
+    transcription: TranscriptionHandle,
+}
+
+/// At most one dictation runs at a time.
+static ACTIVE_DICTATION: LazyLock<Mutex<Option<ActiveDictation>>> =
+    LazyLock::new(|| Mutex::new(None));
+
+static NEXT_DICTATION_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Summary of the most recent completed dictation.
+static LAST_DICTATION: LazyLock<Mutex<Option<DictationSummary>>> =
+    LazyLock::new(|| Mutex::new(None));
+
+/// Starts a dictation unless one is already in flight
+///
+/// This is what the hot-key and the local API trigger.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+///
+/// # Returns
+///
+/// Returns `false` if a dictation was already running and nothing was started.
+pub fn start_dictation(app_handle: &AppHandle) -> bool {
+    start_dictation_with_overrides(app_handle, DictationOverrides::default())
+}
+
+/// Starts a dictation with some settings replaced, unless one is already in
+/// flight or dictation is paused
+///
+/// Language hot-keys use this to dictate in their language.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+/// * `overrides` - Settings replaced for this dictation only
+///
+/// # Returns
+///
+/// Returns `false` if a dictation was already running, dictation is paused
+/// or Speakr is shutting down, and nothing was started.
+pub fn start_dictation_with_overrides(
+    app_handle: &AppHandle,
+    overrides: DictationOverrides,
+) -> bool {
+    if !is_dictation_enabled() {
+        info!("Dictation is paused; ignoring request");
+        return false;
+    }
+    if is_shutting_down() {
+        info!("Speakr is shutting down; ignoring dictation request");
+        return false;
+    }
+
+    let workflow_handle = app_handle.clone();
+    spawn_dictation(app_handle, async move {
+        info!("🔥 Starting dictation workflow");
+
+        if let Err(e) = execute_dictation_workflow(workflow_handle, overrides).await {
+            error!("Dictation workflow failed: {}", e);
+        }
+    })
+}
+
+/// Returns `true` while a dictation is in flight
+pub fn is_dictation_active() -> bool {
+    ACTIVE_DICTATION
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .is_some()
+}
+
+/// Returns the abort handle for transcriptions of the dictation in flight
+///
+/// Outside a dictation this is a fresh handle that is never aborted.
+fn active_transcription_handle() -> TranscriptionHandle {
+    ACTIVE_DICTATION
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_ref()
+        .map(|dictation| dictation.transcription.clone())
+        .unwrap_or_default()
+}
+
+/// Returns a summary of the most recent completed dictation
+pub fn last_dictation() -> Option<DictationSummary> {
+    LAST_DICTATION
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+/// Runs a dictation workflow as a cancellable background task, unless one
+/// is already in flight
+///
+/// While the task runs, Esc cancels it via [`cancel_active_dictation`].
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+/// * `workflow` - The dictation to run, usually wrapping
+///   [`execute_dictation_workflow`]
+///
+/// # Returns
+///
+/// Returns `false` if a dictation was already running and `workflow` was not
+/// started.
+pub fn spawn_dictation<F>(app_handle: &AppHandle, workflow: F) -> bool
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    // Checking and tracking under one lock means two hot-key presses cannot
+    // both start a dictation, and the task cannot finish before it is tracked
+    let mut active = ACTIVE_DICTATION
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    if active.is_some() {
+        return false;
+    }
+    let id = NEXT_DICTATION_ID.fetch_add(1, Ordering::Relaxed);
+
+    if let Err(e) = register_cancel_shortcut(app_handle, |app| {
+        cancel_active_dictation(app);
+    }) {
+        warn!("Esc will not cancel this dictation: {}", e);
+    }
+
+    let finished_handle = app_handle.clone();
+    let task = tauri::async_runtime::spawn(async move {
+        workflow.await;
+        finish_dictation(&finished_handle, id);
+    });
+    *active = Some(ActiveDictation {
+        id,
+        task,
+        transcription: TranscriptionHandle::new(),
+    });
+    true
+}
+
+/// Cancels the dictation in flight, if any
+///
+/// Aborting the task drops the workflow at its current step: an active
+/// recording is discarded and any inference in progress is interrupted, so
+/// the CPU is freed immediately.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle for event emission
+///
+/// # Returns
+///
+/// Returns `true` if a dictation was cancelled.
+pub fn cancel_active_dictation(app_handle: &AppHandle) -> bool {
+    let active = ACTIVE_DICTATION
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take();
+    let Some(active) = active else {
+        return false;
+    };
+
+    active.transcription.abort();
+    active.task.abort();
+    unregister_cancel_shortcut(app_handle);
+    info!("🛑 Dictation cancelled");
+    let _ = app_handle.emit(DICTATION_CANCELLED_EVENT, ());
+    true
+}
+
+/// Clears the active dictation once its task has finished on its own
+fn finish_dictation(app_handle: &AppHandle, id: u64) {
+    let mut active = ACTIVE_DICTATION
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    if active.as_ref().is_some_and(|dictation| dictation.id == id) {
+        *active = None;
+        unregister_cancel_shortcut(app_handle);
+    }
+}
+
+/// Discards the recording if the capture step is dropped part-way through,
+/// e.g. when the dictation is cancelled.
+struct CancelRecordingOnDrop<'a>(&'a AudioRecorder);
+
+impl Drop for CancelRecordingOnDrop<'_> {
+    fn drop(&mut self) {
+        self.0.cancel_recording();
+    }
+}
+
+/// Emits a [`PipelineEvent`] on the [`PIPELINE_EVENT`] channel
+fn emit_pipeline_event(app_handle: &AppHandle, event: PipelineEvent) {
+    record_pipeline_state(&event);
+    #[cfg(debug_assertions)]
+    crate::debug::session::record_session_event(&event);
+    if let Err(e) = app_handle.emit(PIPELINE_EVENT, &event) {
+        debug!(
+            "Failed to emit pipeline event {}: {}",
+            event.display_name(),
+            e
+        );
+    }
+}
+
+/// Reports the recorder's input level until the recording ends
+///
+/// Levels are sampled every [`LEVEL_WINDOW_MS`] and throttled, so a slow
+/// WebView only receives as many updates as it can handle.
+async fn report_input_levels(app_handle: &AppHandle, recorder: &AudioRecorder) {
+    let levels =
+        ThrottledEmitter::acknowledged(app_handle, PIPELINE_EVENT, DEFAULT_MIN_EVENT_INTERVAL);
+    let interval = Duration::from_millis(LEVEL_WINDOW_MS as u64);
+
+    while let Some(level) = recorder.input_level() {
+        levels.emit(PipelineEvent::RecordingLevel(level));
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// Where and how the final text of a live dictation is delivered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Delivery {
+    /// Whether the text is injected, copied to the clipboard, or both.
+    pub output_mode: OutputMode,
+    /// Injection options for the target application.
+    pub injection: InjectionOptions,
+    /// Whether copying shows a notification with the copied text.
+    pub notify_on_copy: bool,
+    /// How paragraph breaks and the "new paragraph" command are output.
+    pub newlines: NewlineOptions,
+    /// Whether words are typed while recording. Only set when the output
+    /// mode injects text.
+    pub stream_words: bool,
+    /// Whether the text is held in the pending queue for review instead of
+    /// being delivered.
+    pub dry_run: bool,
+    /// Whether the target application is on the injection blocklist, in
+    /// which case the text is copied with a notification instead of typed.
+    pub injection_blocked: bool,
+    /// How long after a dictation the next one continues its session, if
+    /// append-mode sessions are on.
+    pub session_window: Option<Duration>,
+}
+
+impl Delivery {
+    /// Whether the text is copied to the clipboard
+    pub fn copies(&self) -> bool {
+        self.output_mode.copies() || self.injection_blocked
+    }
+
+    /// Whether the text is typed into the target application
+    pub fn injects(&self) -> bool {
+        self.output_mode.injects() && !self.injection_blocked
+    }
+}
+
+/// Selects how the final text injection step of the workflow is performed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InjectionMode {
+    /// Deliver the transcribed text to the user as configured.
+    Live(Delivery),
+    /// Deliver the text as configured, correcting the words that were
+    /// already typed while recording into the final transcript.
+    Streamed(Delivery, LiveTranscript),
+    /// Run every step except the actual injection; the text is only reported
+    /// via events and logs. Used when replaying recordings during development.
+    Sandboxed,
+}
 
-    // Step 2: Transcription (placeholder)
-    let transcribed_text = match transcribe_audio_with_status(audio_samples, &app_handle).await {
-        Ok(text) => {
-            info!("✅ Transcription completed: '{}'", text);
-            text
+/// Replays pre-recorded audio through the dictation workflow.
+///
+/// The samples skip the capture step but otherwise follow exactly the same
+/// path (and emit the same events) as a live recording, which makes bugs in
+/// transcription or injection reproducible from a saved sample.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle for event emission
+/// * `audio_samples` - The 16 kHz mono samples to replay
+/// * `mode` - Whether the transcribed text is injected or only reported
+///
+/// # Returns
+///
+/// Returns the transcribed text on success.
+///
+/// # Errors
+///
+/// Returns `AppError` if transcription or text injection fails.
+#[instrument(level = "info", skip(app_handle, audio_samples))]
+pub async fn execute_replay_workflow(
+    app_handle: AppHandle,
+    mut audio_samples: Vec<i16>,
+    mode: InjectionMode,
+) -> Result<String, AppError> {
+    info!(
+        "🔁 Replaying {} samples through dictation workflow",
+        audio_samples.len()
+    );
+
+    let loader: Arc<dyn SettingsLoader> = Arc::new(GlobalSettingsLoader);
+    let transcription_config = create_transcription_config_with_loader(loader.clone()).await;
+    let post_processor = create_post_processor_with_loader(loader.clone()).await;
+    let denoiser = create_denoiser_with_loader(loader.clone()).await;
+    let auto_gain = create_auto_gain_with_loader(loader.clone()).await;
+    let dock_badge = create_dock_badge_with_loader(&app_handle, loader).await;
+
+    let _ = app_handle.emit(WORKFLOW_STARTED_EVENT, ());
+    complete_workflow_from_samples(
+        condition_samples(denoiser.as_ref(), auto_gain, &mut audio_samples),
+        None,
+        transcription_config,
+        post_processor,
+        &app_handle,
+        &dock_badge,
+        None,
+        mode,
+    )
+    .await
+    .map(|completed| completed.text)
+}
+
+/// Transcript produced by [`execute_simulated_workflow`].
+///
+/// Starts with a filler word so the user's clean-up rules visibly apply.
+pub const SIMULATED_TRANSCRIPT: &str = "um, this is a preview of how your dictation will appear.";
+
+/// Runs a simulated dictation that needs neither a microphone nor a model.
+///
+/// Lets new users try the flow before any model has been downloaded: the
+/// same events as a real dictation are emitted, with [`SIMULATED_TRANSCRIPT`]
+/// standing in for the transcription and the injection step sandboxed.
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle for event emission
+///
+/// # Returns
+///
+/// Returns the post-processed sample transcript.
+#[instrument(level = "info", skip(app_handle))]
+pub async fn execute_simulated_workflow(app_handle: AppHandle) -> String {
+    info!("🎭 Running simulated dictation");
+
+    let loader: Arc<dyn SettingsLoader> = Arc::new(GlobalSettingsLoader);
+    let post_processor = create_post_processor_with_loader(loader).await;
+
+    let _ = app_handle.emit(WORKFLOW_STARTED_EVENT, ());
+    let _ = app_handle.emit(AUDIO_CAPTURE_STARTED_EVENT, ());
+    emit_pipeline_event(&app_handle, PipelineEvent::RecordingStarted);
+    let _ = app_handle.emit(
+        AUDIO_CAPTURE_COMPLETED_EVENT,
+        AudioCaptureCompleted { sample_count: 0 },
+    );
+    let _ = app_handle.emit(TRANSCRIPTION_STARTED_EVENT, ());
+    emit_pipeline_event(&app_handle, PipelineEvent::TranscriptionStarted);
+    let _ = app_handle.emit(TRANSCRIPTION_COMPLETED_EVENT, SIMULATED_TRANSCRIPT);
+    emit_pipeline_event(
+        &app_handle,
+        PipelineEvent::PartialText(SIMULATED_TRANSCRIPT.to_string()),
+    );
+
+    let text = post_processor.process(SIMULATED_TRANSCRIPT);
+    let _ = app_handle.emit(TEXT_INJECTION_SANDBOXED_EVENT, text.clone());
+    let _ = app_handle.emit(WORKFLOW_COMPLETED_EVENT, text.clone());
+
+    text
+}
+
+/// Outcome of the steps that follow audio capture.
+struct CompletedDictation {
+    /// The post-processed text that was delivered.
+    text: String,
+    /// Stage timings; the capture time is left at zero for the caller.
+    latency: DictationLatency,
+    /// When the transcript became available.
+    transcribed_at: Instant,
+}
+
+/// Runs the steps that follow audio capture: transcribe → post-process → inject.
+///
+/// # Arguments
+///
+/// * `audio_samples` - The captured (or replayed) audio samples
+/// * `chunked` - Chunks of a long-form recording transcribed while it was
+///   recorded, if any
+/// * `transcription_config` - Model and language settings for transcription
+/// * `post_processor` - The user's text transforms, applied before injection
+/// * `app_handle` - The Tauri application handle for event emission
+/// * `dock_badge` - Dock icon indicator, switched to "transcribing" here
+/// * `stop_phrase` - Spoken stop phrase to remove from the end of the transcript
+/// * `mode` - Whether the transcribed text is injected or only reported
+///
+/// # Returns
+///
+/// Returns the post-processed text and the time each step took on success.
+///
+/// # Errors
+///
+/// Returns `AppError` if transcription or text injection fails.
+#[allow(clippy::too_many_arguments)]
+async fn complete_workflow_from_samples(
+    audio_samples: Vec<i16>,
+    chunked: Option<ChunkedTranscript>,
+    transcription_config: TranscriptionConfig,
+    post_processor: TextPostProcessor,
+    app_handle: &AppHandle,
+    dock_badge: &DockBadge,
+    stop_phrase: Option<&StopPhraseDetector>,
+    mode: InjectionMode,
+) -> Result<CompletedDictation, AppError> {
+    // Step 2: Transcription
+    dock_badge.set(BadgeState::Transcribing);
+    let transcribe_started = Instant::now();
+    let transcription = match transcribe_audio_with_status(
+        audio_samples,
+        chunked.as_ref(),
+        transcription_config,
+        app_handle,
+    )
+    .await
+    {
+        Ok(result) => {
+            info!(
+                "✅ Transcription completed: '{}'",
+                loggable_text(&result.text)
+            );
+            result
         }
         Err(e) => {
             error!("❌ Transcription failed: {}", e);
-            let _ = app_handle.emit("workflow-error", format!("Transcription failed: {e}"));
+            let _ = app_handle.emit(
+                WORKFLOW_ERROR_EVENT,
+                WorkflowError::new(format!("Transcription failed: {e}")),
+            );
+            emit_pipeline_event(app_handle, PipelineEvent::Failed(e.clone()));
             return Err(e);
         }
     };
+    let transcribed_at = Instant::now();
+    let transcribe_time = transcribed_at - transcribe_started;
+
+    // Step 3: Post-processing, after dropping the spoken stop phrase
+    let process_started = Instant::now();
+    let mut processing_stages = Vec::new();
+    let transcribed_text = match stop_phrase {
+        Some(detector) => run_stage(
+            &mut processing_stages,
+            "Stop phrase",
+            transcription.text,
+            |text| detector.strip(text),
+        ),
+        None => transcription.text,
+    };
+    let (transcribed_text, stages) = post_processor.process_timed(&transcribed_text);
+    processing_stages.extend(stages);
+    debug!(
+        "Post-processed text: '{}'",
+        loggable_text(&transcribed_text)
+    );
+
+    // Final formatting of paragraph breaks for the output
+    let transcribed_text = match &mode {
+        InjectionMode::Live(delivery) | InjectionMode::Streamed(delivery, _) => run_stage(
+            &mut processing_stages,
+            "Paragraphs",
+            transcribed_text,
+            |text| format_paragraphs(text, &delivery.newlines),
+        ),
+        InjectionMode::Sandboxed => transcribed_text,
+    };
+    let process_time = process_started.elapsed();
 
-    // Step 3: Text Injection (placeholder)
-    match inject_text(transcribed_text.clone(), &app_handle).await {
-        Ok(()) => {
-            info!("✅ Text injection completed");
+    // Step 4: Clipboard output and text injection
+    let inject_started = Instant::now();
+    match &mode {
+        InjectionMode::Live(delivery) if delivery.dry_run => {
+            info!("🧪 Dry run, holding dictation for review");
+            queue_pending(app_handle, &transcribed_text, PendingReason::DryRun);
         }
-        Err(e) => {
-            error!("❌ Text injection failed: {}", e);
-            let _ = app_handle.emit("workflow-error", format!("Text injection failed: {e}"));
-            return Err(e);
+        InjectionMode::Live(delivery) | InjectionMode::Streamed(delivery, _) => {
+            if delivery.injection_blocked {
+                info!("🚫 Not typing into a blocklisted application; copying instead");
+                notify_injection_blocked(app_handle);
+            }
+            // A dictation continuing a session reads on from the text before it
+            let session_text = match delivery.session_window {
+                Some(_) => join_session(&transcribed_text),
+                None => Cow::Borrowed(transcribed_text.as_str()),
+            };
+            if delivery.copies() {
+                let notify = delivery.notify_on_copy && !delivery.injection_blocked;
+                match copy_to_clipboard(&session_text, notify, app_handle) {
+                    Ok(()) => {
+                        info!("📋 Transcription copied to clipboard");
+                        if delivery.session_window.is_some() && !delivery.injects() {
+                            if let Some(last) = session_text.chars().next_back() {
+                                record_session_delivery(app_handle, last);
+                            }
+                        }
+                    }
+                    // Injection still delivers the text, so only a copy-only
+                    // dictation fails here
+                    Err(e) if delivery.injects() => {
+                        warn!("Failed to copy transcription to clipboard: {}", e);
+                    }
+                    Err(e) => {
+                        error!("❌ Clipboard copy failed: {}", e);
+                        let _ = app_handle.emit(
+                            WORKFLOW_ERROR_EVENT,
+                            WorkflowError::new(format!("Clipboard copy failed: {e}")),
+                        );
+                        emit_pipeline_event(app_handle, PipelineEvent::Failed(e.clone()));
+                        return Err(e);
+                    }
+                }
+            }
+
+            // Typing into a password field would lose the text, or leak it
+            // once focus moves on; the text is held in memory, never saved
+            let suppression = delivery.injects().then(injection_suppression).flatten();
+            if let Some(reason) = suppression {
+                warn!("🔒 Text injection suppressed: {}", reason);
+                queue_pending(
+                    app_handle,
+                    &transcribed_text,
+                    PendingReason::InjectionSuppressed(reason.clone()),
+                );
+                emit_pipeline_event(app_handle, PipelineEvent::InjectionSuppressed(reason));
+            } else if delivery.injects() {
+                let options = delivery.injection;
+                let text = options.finish(&session_text);
+                let last_typed = text
+                    .chars()
+                    .next_back()
+                    .filter(|_| delivery.session_window.is_some());
+                let injected = match &mode {
+                    InjectionMode::Streamed(_, live) => {
+                        apply_text_edit(live.correction(&text), options, app_handle).await
+                    }
+                    _ => inject_text(text, options, app_handle).await,
+                };
+                match injected {
+                    Ok(()) => {
+                        info!("✅ Text injection completed");
+                        if let Some(last) = last_typed {
+                            record_session_delivery(app_handle, last);
+                        }
+                        emit_pipeline_event(app_handle, PipelineEvent::Injected);
+                    }
+                    Err(e) => {
+                        error!("❌ Text injection failed: {}", e);
+                        queue_pending(
+                            app_handle,
+                            &transcribed_text,
+                            PendingReason::InjectionFailed(e.to_string()),
+                        );
+                        let _ = app_handle.emit(
+                            WORKFLOW_ERROR_EVENT,
+                            WorkflowError::new(format!(
+                                "Text injection failed: {e}. The text is waiting in Pending."
+                            )),
+                        );
+                        emit_pipeline_event(app_handle, PipelineEvent::Failed(e.clone()));
+                        return Err(e);
+                    }
+                }
+            }
+        }
+        InjectionMode::Sandboxed => {
+            info!(
+                "🧪 Sandboxed run, skipping text injection: '{}'",
+                loggable_text(&transcribed_text)
+            );
+            let _ = app_handle.emit(TEXT_INJECTION_SANDBOXED_EVENT, transcribed_text.clone());
         }
     }
 
+    if is_privacy_mode_active() {
+        debug!("Privacy mode on; dictation not added to history");
+    } else if !matches!(mode, InjectionMode::Sandboxed) {
+        record_history(app_handle, &transcribed_text);
+    }
+
     // Emit workflow completion event
-    let _ = app_handle.emit("workflow-completed", transcribed_text);
+    retain_last_transcription(&transcribed_text);
+    let _ = app_handle.emit(WORKFLOW_COMPLETED_EVENT, transcribed_text.clone());
     info!("🎉 Dictation workflow completed successfully");
 
-    Ok(())
+    Ok(CompletedDictation {
+        text: transcribed_text,
+        latency: DictationLatency {
+            capture_ms: 0,
+            transcribe_ms: millis(transcribe_time),
+            process_ms: millis(process_time),
+            inject_ms: millis(inject_started.elapsed()),
+            model: transcription.model_used,
+            input_device: None,
+            processing_stages,
+        },
+        transcribed_at,
+    })
+}
+
+/// Logs the stage timings of a completed dictation and emits them on
+/// [`DICTATION_LATENCY_EVENT`]
+fn report_latency(app_handle: &AppHandle, latency: &DictationLatency) {
+    info!(
+        "⏱️ Dictation took {} ms (capture {} ms, transcribe {} ms, process {} ms, inject {} ms) with the {:?} model",
+        latency.total_ms(),
+        latency.capture_ms,
+        latency.transcribe_ms,
+        latency.process_ms,
+        latency.inject_ms,
+        latency.model
+    );
+    if let Err(e) = app_handle.emit(DICTATION_LATENCY_EVENT, latency) {
+        debug!("Failed to emit dictation latency: {}", e);
+    }
+}
+
+/// Whole milliseconds in `duration`, saturating at `u64::MAX`
+fn millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
 }
 
 // ============================================================================
@@ -153,40 +973,414 @@ pub async fn create_recording_config_with_loader(
         e
     });
 
-    let duration_secs = match settings {
+    let (duration, time_limit, input_devices, input_gains) = match settings {
+        Ok(settings) => (
+            settings.audio_duration,
+            settings.recording_limit(),
+            settings.input_devices,
+            settings.input_gains,
+        ),
+        // Fallback to default if settings loading fails
+        Err(_) => (
+            AudioDuration::DEFAULT,
+            AudioDuration::DEFAULT.as_duration(),
+            Vec::new(),
+            Vec::new(),
+        ),
+    };
+
+    debug!(
+        "Using audio duration: {}, recording limit: {:?}",
+        duration, time_limit
+    );
+    RecordingConfig::new(duration)
+        .with_time_limit(time_limit)
+        .with_preferred_devices(input_devices)
+        .with_input_gains(input_gains)
+}
+
+/// Creates a TranscriptionConfig from user settings (model size and language)
+///
+/// # Arguments
+///
+/// * `loader` - The settings loader to use
+///
+/// # Returns
+///
+/// Returns a TranscriptionConfig derived from settings. Falls back to
+/// auto-detection if the configured language is not supported by Whisper, and
+/// to the default config if settings cannot be loaded
+pub async fn create_transcription_config_with_loader(
+    loader: Arc<dyn SettingsLoader>,
+) -> TranscriptionConfig {
+    match loader.load_settings().await {
+        Ok(settings)
+            if settings
+                .transcription_language()
+                .is_none_or(|code| language::is_supported(&code)) =>
+        {
+            TranscriptionConfig::from_settings(&settings)
+        }
         Ok(settings) => {
-            if AppSettings::validate_audio_duration(settings.audio_duration_secs) {
-                settings.audio_duration_secs
-            } else {
-                warn!("Invalid settings, using default duration");
-                speakr_types::DEFAULT_AUDIO_DURATION_SECS
+            warn!(
+                "Invalid language '{}' in settings, using auto-detection",
+                settings.language
+            );
+            TranscriptionConfig {
+                language: None,
+                auto_detect_language: true,
+                ..TranscriptionConfig::from_settings(&settings)
             }
         }
-        Err(_) => speakr_types::DEFAULT_AUDIO_DURATION_SECS, // Fallback to default if settings loading fails
+        Err(e) => {
+            warn!(
+                "Failed to load settings, using default transcription config: {}",
+                e
+            );
+            TranscriptionConfig::default()
+        }
+    }
+}
+
+/// Creates a TextPostProcessor from the user's post-processing rules
+///
+/// # Arguments
+///
+/// * `loader` - The settings loader to use
+///
+/// # Returns
+///
+/// Returns a processor for the configured transform chain. Falls back to the
+/// default chain if settings cannot be loaded or a rule fails to compile, so
+/// a bad rule never blocks dictation
+pub async fn create_post_processor_with_loader(
+    loader: Arc<dyn SettingsLoader>,
+) -> TextPostProcessor {
+    create_post_processor_for_app_with_loader(loader, None).await
+}
+
+/// Creates a TextPostProcessor for dictating into a specific application
+///
+/// # Arguments
+///
+/// * `loader` - The settings loader to use
+/// * `app_id` - Identifier of the target application, if known
+///
+/// # Returns
+///
+/// Returns a processor for the configured voice commands and transform
+/// chain, adjusted by the target's app profile and followed by the enabled
+/// plugins. Falls back like
+/// [`create_post_processor_with_loader`]; plugins that fail to load are skipped
+pub async fn create_post_processor_for_app_with_loader(
+    loader: Arc<dyn SettingsLoader>,
+    app_id: Option<&str>,
+) -> TextPostProcessor {
+    let settings = match loader.load_settings().await {
+        Ok(settings) => settings,
+        Err(e) => {
+            warn!(
+                "Failed to load settings, using default post-processing: {}",
+                e
+            );
+            return TextPostProcessor::default();
+        }
     };
 
-    debug!("Using audio duration: {} seconds", duration_secs);
-    RecordingConfig::new(duration_secs)
+    let transforms = settings.post_processing_for(app_id);
+    let processor = TextPostProcessor::new(&transforms)
+        .unwrap_or_else(|e| {
+            warn!("Invalid post-processing rules, using defaults: {}", e);
+            TextPostProcessor::default()
+        })
+        .with_voice_commands(&settings.voice_commands);
+
+    if settings.plugins.is_empty() {
+        return processor;
+    }
+    let plugins = PluginManager::new()
+        .load_enabled(&settings.plugins, settings.plugin_limits)
+        .await;
+    processor.with_plugins(plugins, app_id, &settings.language)
 }
 
-/// Captures audio using speakr-core AudioRecorder
+/// Creates the delivery options for dictating into a specific application
 ///
 /// # Arguments
 ///
-/// * `app_handle` - The Tauri application handle for event emission
+/// * `loader` - The settings loader to use
+/// * `app_id` - Identifier of the target application, if known
 ///
 /// # Returns
 ///
-/// Returns the captured audio samples as Vec<i16>
+/// Returns the configured output mode and newline handling with the target's
+/// app profile injection options, or the defaults if settings cannot be loaded
+pub async fn create_delivery_for_app_with_loader(
+    loader: Arc<dyn SettingsLoader>,
+    app_id: Option<&str>,
+) -> Delivery {
+    match loader.load_settings().await {
+        Ok(settings) => {
+            let injection_blocked = settings.blocks_injection(app_id);
+            if injection_blocked {
+                info!(
+                    "Target application {:?} is on the injection blocklist",
+                    app_id
+                );
+            }
+            Delivery {
+                output_mode: settings.output_mode,
+                injection: settings.injection_for(app_id),
+                notify_on_copy: settings.clipboard_notification,
+                newlines: settings.newlines,
+                // Words typed while speaking could not be joined onto the
+                // session's text, and re-transcribing minutes of long-form
+                // audio every second would not keep up
+                stream_words: settings.streaming_injection
+                    && settings.output_mode.injects()
+                    && !settings.dry_run
+                    && !injection_blocked
+                    && settings.session_window_secs.is_none()
+                    && settings.long_form_mins.is_none(),
+                dry_run: settings.dry_run,
+                injection_blocked,
+                session_window: settings.session_window(),
+            }
+        }
+        Err(e) => {
+            warn!("Failed to load settings, using default output: {}", e);
+            Delivery::default()
+        }
+    }
+}
+
+/// Creates a detector for the user's spoken stop phrase, if one is configured
 ///
-/// # Errors
+/// # Arguments
 ///
-/// Returns `AppError` if audio capture initialization or recording fails.
-#[instrument(level = "debug", skip(app_handle))]
-#[allow(dead_code)]
-async fn capture_audio(app_handle: &AppHandle) -> Result<Vec<i16>, AppError> {
-    let loader = GlobalSettingsLoader;
-    capture_audio_with_loader(app_handle, Arc::new(loader)).await
+/// * `loader` - The settings loader to use
+///
+/// # Returns
+///
+/// Returns `None` if no stop phrase is set or settings cannot be loaded, in
+/// which case recordings run for the configured duration
+pub async fn create_stop_phrase_detector_with_loader(
+    loader: Arc<dyn SettingsLoader>,
+) -> Option<StopPhraseDetector> {
+    match loader.load_settings().await {
+        Ok(settings) => settings
+            .stop_phrase
+            .as_ref()
+            .and_then(StopPhraseDetector::new),
+        Err(e) => {
+            warn!("Failed to load settings, stop phrase disabled: {}", e);
+            None
+        }
+    }
+}
+
+/// Creates the background noise suppressor if the user has enabled it
+///
+/// # Arguments
+///
+/// * `loader` - The settings loader to use
+///
+/// # Returns
+///
+/// Returns `None` if denoising is off, settings cannot be loaded, or the
+/// RNNoise weights are missing, in which case audio is transcribed as
+/// captured
+pub async fn create_denoiser_with_loader(loader: Arc<dyn SettingsLoader>) -> Option<Denoiser> {
+    match loader.load_settings().await {
+        Ok(settings) if settings.denoise => match Denoiser::load(&ModelManager::new()) {
+            Ok(denoiser) => Some(denoiser),
+            Err(e) => {
+                warn!("Noise suppression disabled: {}", e);
+                None
+            }
+        },
+        Ok(_) => None,
+        Err(e) => {
+            warn!("Failed to load settings, noise suppression disabled: {}", e);
+            None
+        }
+    }
+}
+
+/// Loads the automatic gain control settings
+///
+/// # Arguments
+///
+/// * `loader` - The settings loader to use
+///
+/// # Returns
+///
+/// Returns `None` if gain control is off or settings cannot be loaded
+pub async fn create_auto_gain_with_loader(loader: Arc<dyn SettingsLoader>) -> Option<AutoGain> {
+    match loader.load_settings().await {
+        Ok(settings) => settings.auto_gain,
+        Err(e) => {
+            warn!("Failed to load settings, gain control disabled: {}", e);
+            None
+        }
+    }
+}
+
+/// Loads whether long-form dictation is on
+///
+/// # Arguments
+///
+/// * `loader` - The settings loader to use
+///
+/// # Returns
+///
+/// Returns `false` if long-form dictation is off or settings cannot be loaded,
+/// in which case recordings stop at the audio duration
+pub async fn create_long_form_with_loader(loader: Arc<dyn SettingsLoader>) -> bool {
+    match loader.load_settings().await {
+        Ok(settings) => settings.long_form_mins.is_some(),
+        Err(e) => {
+            warn!(
+                "Failed to load settings, long-form dictation disabled: {}",
+                e
+            );
+            false
+        }
+    }
+}
+
+/// Loads the file name template for saved recordings
+///
+/// # Arguments
+///
+/// * `loader` - The settings loader to use
+///
+/// # Returns
+///
+/// Returns `None` if saving recordings is off or settings cannot be loaded
+pub async fn create_recording_template_with_loader(
+    loader: Arc<dyn SettingsLoader>,
+) -> Option<String> {
+    match loader.load_settings().await {
+        Ok(settings) if settings.save_recordings => Some(settings.recording_filename_template),
+        Ok(_) => None,
+        Err(e) => {
+            warn!("Failed to load settings, recordings not saved: {}", e);
+            None
+        }
+    }
+}
+
+/// Saves a dictation's audio with its metadata to the recordings directory
+///
+/// Failures are logged; the dictation itself has already finished.
+async fn save_dictation_recording(samples: &[i16], template: &str, metadata: &RecordingMetadata) {
+    let saved = match recordings_dir() {
+        Ok(dir) => save_tagged_recording(samples, &dir, template, metadata).await,
+        Err(e) => Err(e),
+    };
+    match saved {
+        Ok(path) => info!("💾 Recording saved to {}", path.display()),
+        Err(e) => warn!("Failed to save recording: {}", e),
+    }
+}
+
+/// Zeroes audio samples the workflow no longer needs
+///
+/// The whole allocation is wiped, not just the samples in use, and the
+/// buffer is left empty. Audio is wiped as soon as it has been transcribed
+/// or replaced by a processed copy, so a dictation leaves no audio behind.
+///
+/// # Arguments
+///
+/// * `samples` - The buffer to wipe
+pub fn wipe_samples(samples: &mut Vec<i16>) {
+    samples.zeroize();
+}
+
+/// Runs captured samples through the noise suppressor and gain control,
+/// whichever are enabled
+///
+/// The caller's samples are zeroed, and so is every intermediate copy, so
+/// only the returned audio is left.
+///
+/// # Arguments
+///
+/// * `denoiser` - The noise suppressor, if enabled
+/// * `auto_gain` - The gain control settings, if enabled
+/// * `samples` - The captured audio, wiped once it has been processed
+pub fn condition_samples(
+    denoiser: Option<&Denoiser>,
+    auto_gain: Option<AutoGain>,
+    samples: &mut [i16],
+) -> Vec<i16> {
+    let mut conditioned = match denoiser {
+        Some(denoiser) => {
+            debug!("Suppressing background noise in {} samples", samples.len());
+            denoiser.denoise(samples)
+        }
+        None => samples.to_vec(),
+    };
+    samples.zeroize();
+    if let Some(auto_gain) = auto_gain {
+        let normalized = gain::normalize(&conditioned, auto_gain.target_dbfs);
+        wipe_samples(&mut conditioned);
+        conditioned = normalized;
+    }
+    conditioned
+}
+
+/// Creates the Dock badge indicator if the user has enabled it in settings
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+/// * `loader` - The settings loader to use
+///
+/// # Returns
+///
+/// Returns a badge handle; it is inert if the badge is disabled or settings
+/// cannot be loaded
+pub async fn create_dock_badge_with_loader(
+    app_handle: &AppHandle,
+    loader: Arc<dyn SettingsLoader>,
+) -> DockBadge {
+    let enabled = match loader.load_settings().await {
+        Ok(settings) => settings.dock_badge,
+        Err(e) => {
+            warn!("Failed to load settings, Dock badge disabled: {}", e);
+            false
+        }
+    };
+    DockBadge::new(app_handle, enabled)
+}
+
+/// Creates the dictation notifier from the user's notification settings
+///
+/// # Arguments
+///
+/// * `app_handle` - The Tauri application handle
+/// * `loader` - The settings loader to use
+///
+/// # Returns
+///
+/// Returns a notifier; it is inert if notifications are off or settings
+/// cannot be loaded
+pub async fn create_notifier_with_loader(
+    app_handle: &AppHandle,
+    loader: Arc<dyn SettingsLoader>,
+) -> DictationNotifier {
+    match loader.load_settings().await {
+        Ok(settings) => DictationNotifier::new(
+            app_handle,
+            settings.notifications,
+            settings.notification_text,
+        ),
+        Err(e) => {
+            warn!("Failed to load settings, notifications disabled: {}", e);
+            DictationNotifier::new(app_handle, false, false)
+        }
+    }
 }
 
 /// Captures audio using speakr-core AudioRecorder with custom settings loader
@@ -195,35 +1389,63 @@ async fn capture_audio(app_handle: &AppHandle) -> Result<Vec<i16>, AppError> {
 ///
 /// * `app_handle` - The Tauri application handle for event emission
 /// * `loader` - The settings loader to use
+/// * `stop_phrase` - Spoken phrase that ends the recording early, if any
+/// * `transcription_config` - Settings used to listen for the stop phrase
+/// * `streamer` - Types words while recording, if streaming is enabled
 ///
 /// # Returns
 ///
-/// Returns the captured audio samples as Vec<i16>
+/// Returns the captured audio samples as Vec<i16>, with the name of the
+/// input device they were recorded from if known and when recording started
 ///
 /// # Errors
 ///
 /// Returns `AppError` if audio capture initialization or recording fails.
-#[instrument(level = "debug", skip(app_handle, loader))]
+#[instrument(level = "debug", skip_all)]
 async fn capture_audio_with_loader(
     app_handle: &AppHandle,
     loader: Arc<dyn SettingsLoader>,
-) -> Result<Vec<i16>, AppError> {
+    stop_phrase: Option<&StopPhraseDetector>,
+    transcription_config: &TranscriptionConfig,
+    streamer: Option<&mut WordStreamer>,
+    chunker: Option<&mut ChunkTranscriber>,
+) -> Result<(Vec<i16>, Option<String>, Instant), AppError> {
     debug!("Initializing audio recorder");
 
     // Emit audio capture start event
-    let _ = app_handle.emit("audio-capture-started", ());
+    let _ = app_handle.emit(AUDIO_CAPTURE_STARTED_EVENT, ());
 
     // Create recording config using settings-based duration
     let config = create_recording_config_with_loader(loader).await;
-    let recorder = AudioRecorder::new(config.clone())
-        .await
-        .map_err(|e| AppError::AudioCapture(format!("Failed to initialize recorder: {e}")))?;
+    // Reuse the pre-recording recorder so the buffered audio is included
+    let recorder =
+        match pre_buffering_recorder() {
+            Some(recorder) => {
+                recorder.set_preferred_devices(config.preferred_devices().to_vec());
+                recorder.set_input_gains(config.input_gains().to_vec());
+                recorder.set_time_limit(config.time_limit());
+                recorder
+            }
+            None => Arc::new(AudioRecorder::new(config.clone()).await.map_err(|e| {
+                AppError::AudioCapture(format!("Failed to initialize recorder: {e}"))
+            })?),
+        };
 
     // Start recording
     recorder
         .start_recording()
         .await
         .map_err(|e| AppError::AudioCapture(format!("Failed to start recording: {e}")))?;
+    let recording_started = Instant::now();
+    let _cancel_guard = CancelRecordingOnDrop(&recorder);
+    let input_device = recorder.input_device();
+    info!(
+        "🎙️ Recording from {}",
+        input_device
+            .as_deref()
+            .unwrap_or("the default input device")
+    );
+    emit_pipeline_event(app_handle, PipelineEvent::RecordingStarted);
 
     debug!("Recording started, waiting for completion");
 
@@ -232,10 +1454,41 @@ async fn capture_audio_with_loader(
     // 2. Show visual feedback that recording is active
     // 3. Handle user cancellation
 
-    // Wait for the recording duration specified in config
-    // TODO: In a real implementation, we would also listen for early stop signals
-    let recording_duration = Duration::from_secs(config.max_duration_secs() as u64);
-    tokio::time::sleep(recording_duration).await;
+    // Wait for the recording duration specified in config, or until the
+    // spoken stop phrase is heard
+    let recording_duration = config.time_limit();
+    let wait_for_end = async {
+        match stop_phrase {
+            Some(detector) => {
+                let listener =
+                    listen_for_stop_phrase(&recorder, detector, transcription_config.clone());
+                let _ = tokio::time::timeout(recording_duration, listener).await;
+            }
+            None => tokio::time::sleep(recording_duration).await,
+        }
+    };
+    // Streaming never ends the recording, even if it gives up early
+    let stream_words = async {
+        if let Some(streamer) = streamer {
+            streamer.run(app_handle, &recorder).await;
+        }
+        std::future::pending::<()>().await
+    };
+    // Nor does transcribing chunks; a chunk cut short is transcribed with
+    // the rest of the audio once recording stops
+    let transcribe_chunks = async {
+        if let Some(chunker) = chunker {
+            chunker.run(&recorder).await;
+        }
+        std::future::pending::<()>().await
+    };
+    // Level reporting only ends early if the recorder stops on its own
+    tokio::select! {
+        _ = wait_for_end => {}
+        _ = report_input_levels(app_handle, &recorder) => {}
+        _ = stream_words => {}
+        _ = transcribe_chunks => {}
+    }
 
     // Stop recording and get samples
     let result = recorder
@@ -246,63 +1499,257 @@ async fn capture_audio_with_loader(
     let samples = result.samples();
 
     // Emit audio capture completion event
-    let _ = app_handle.emit("audio-capture-completed", samples.len());
+    let _ = app_handle.emit(
+        AUDIO_CAPTURE_COMPLETED_EVENT,
+        AudioCaptureCompleted {
+            sample_count: samples.len(),
+        },
+    );
 
     debug!("Audio capture completed with {} samples", samples.len());
-    Ok(samples)
+    Ok((samples, input_device, recording_started))
+}
+
+/// Types finalised words into the target application while recording
+struct WordStreamer {
+    config: TranscriptionConfig,
+    post_processor: TextPostProcessor,
+    options: InjectionOptions,
+    live: LiveTranscript,
+}
+
+impl WordStreamer {
+    /// Re-transcribes the recording every [`STREAMING_POLL_INTERVAL`] and
+    /// types newly finalised words, correcting revised ones, until the
+    /// recording ends
+    ///
+    /// A failed check is logged and skipped. Gives up for the rest of the
+    /// dictation if the model cannot be loaded; the final transcript is
+    /// still delivered.
+    async fn run(&mut self, app_handle: &AppHandle, recorder: &AudioRecorder) {
+        let engine = match resident_engine(&self.config).await {
+            Ok(engine) => engine,
+            Err(e) => {
+                warn!("Not streaming words for this dictation: {}", e);
+                return;
+            }
+        };
+
+        loop {
+            tokio::time::sleep(STREAMING_POLL_INTERVAL).await;
+
+            let Some(samples) = recorder.peek_samples() else {
+                return;
+            };
+            if samples.is_empty() {
+                continue;
+            }
+
+            let hypothesis = match transcription_pipeline_with_handle(
+                samples,
+                &engine,
+                active_transcription_handle(),
+            )
+            .await
+            {
+                Ok(result) => self.post_processor.process(&result.text),
+                Err(e) => {
+                    debug!("Streaming transcription failed: {}", e);
+                    continue;
+                }
+            };
+            // Words held back now are typed once secure input ends
+            if injection_suppression().is_some() {
+                continue;
+            }
+            let edit = self.live.update(&hypothesis);
+            if edit.is_empty() {
+                continue;
+            }
+            if let Err(e) = apply_text_edit(edit, self.options, app_handle).await {
+                warn!("Failed to type streamed words: {}", e);
+            }
+        }
+    }
+}
+
+/// Transcribes a long-form recording in chunks while it is recorded
+///
+/// Each chunk is denoised and gain-controlled on its own before it is
+/// transcribed, and so is the audio left after the last one, so every part
+/// of the transcript is heard through the same processing.
+struct ChunkTranscriber {
+    config: TranscriptionConfig,
+    denoiser: Option<Denoiser>,
+    auto_gain: Option<AutoGain>,
+    transcript: ChunkedTranscript,
+}
+
+impl ChunkTranscriber {
+    /// Conditions the audio after the chunks already transcribed, the way
+    /// each chunk was
+    ///
+    /// The chunks' audio is left as recorded; only the rest is transcribed
+    /// once recording stops.
+    fn condition_remaining(&self, mut samples: Vec<i16>) -> Vec<i16> {
+        let remaining = self.transcript.remaining(samples.len());
+        let conditioned = condition_samples(
+            self.denoiser.as_ref(),
+            self.auto_gain,
+            &mut samples[remaining.clone()],
+        );
+        samples.truncate(remaining.start);
+        samples.extend(conditioned);
+        samples
+    }
+
+    /// Transcribes each chunk of the recording as soon as it has been
+    /// captured, until the recording ends
+    ///
+    /// Stops at the first failure, or if the model cannot be loaded; the
+    /// audio after the last transcribed chunk is then transcribed once
+    /// recording stops.
+    async fn run(&mut self, recorder: &AudioRecorder) {
+        let engine = match resident_engine(&self.config).await {
+            Ok(engine) => engine,
+            Err(e) => {
+                warn!("Not transcribing long-form chunks while recording: {}", e);
+                return;
+            }
+        };
+
+        loop {
+            tokio::time::sleep(LONG_FORM_POLL_INTERVAL).await;
+
+            // Only the audio from the next chunk on is copied
+            let offset = self.transcript.next_start();
+            let Some(mut samples) = recorder.peek_samples_from(offset) else {
+                return;
+            };
+            while let Some(chunk) = self.transcript.next_chunk(offset + samples.len()) {
+                let chunk_samples = condition_samples(
+                    self.denoiser.as_ref(),
+                    self.auto_gain,
+                    &mut samples[chunk.start - offset..chunk.end - offset],
+                );
+                let result = transcription_pipeline_with_handle(
+                    chunk_samples,
+                    &engine,
+                    active_transcription_handle(),
+                )
+                .await;
+                match result {
+                    Ok(result) => {
+                        self.transcript.push(&result.text);
+                        debug!(
+                            "Long-form transcript so far: '{}'",
+                            loggable_text(self.transcript.text())
+                        );
+                    }
+                    Err(e) => {
+                        warn!("Long-form chunk transcription failed: {}", e);
+                        wipe_samples(&mut samples);
+                        return;
+                    }
+                }
+            }
+            wipe_samples(&mut samples);
+        }
+    }
 }
 
 /// Transcription Step – with status updates & progress (FR-3 task 6.2)
 /// --------------------------------------------------------------------------
 /// Emits status events and progress updates while delegating the heavy work to
-/// the speakr-core pipeline.
-#[allow(dead_code)]
-#[instrument(level = "debug", skip(audio_samples, app_handle))]
+/// the speakr-core pipeline. For a long-form recording only the audio after
+/// the chunks transcribed while recording is transcribed here, and the
+/// result's text is the whole stitched transcript.
+#[instrument(level = "debug", skip(audio_samples, chunked, app_handle))]
 async fn transcribe_audio_with_status(
-    audio_samples: Vec<i16>,
+    mut audio_samples: Vec<i16>,
+    chunked: Option<&ChunkedTranscript>,
+    cfg: TranscriptionConfig,
     app_handle: &AppHandle,
-) -> Result<String, AppError> {
+) -> Result<TranscriptionResult, AppError> {
     use crate::services::{update_global_service_status, ServiceComponent};
     use speakr_core::pipeline;
-    use speakr_types::{ServiceStatus, TranscriptionConfig};
+    use speakr_types::ServiceStatus;
     use tokio::time::{sleep, Duration};
 
+    if let Some(chunked) = chunked {
+        let remaining = audio_samples[chunked.remaining(audio_samples.len())].to_vec();
+        debug!(
+            "Transcribing the last {} of {} long-form samples",
+            remaining.len(),
+            audio_samples.len()
+        );
+        wipe_samples(&mut audio_samples);
+        audio_samples = remaining;
+    }
     debug!("Starting transcription of {} samples", audio_samples.len());
 
     // Update backend status to "Starting" / processing
     update_global_service_status(ServiceComponent::Transcription, ServiceStatus::Starting).await;
 
     // Emit start event for UI
-    let _ = app_handle.emit("transcription-started", ());
+    let _ = app_handle.emit(TRANSCRIPTION_STARTED_EVENT, ());
+    emit_pipeline_event(app_handle, PipelineEvent::TranscriptionStarted);
 
-    // Spawn periodic pseudo-progress reporter
+    // Spawn periodic pseudo-progress reporter, throttled so a slow WebView
+    // only receives the updates it can keep up with
     let progress_handle = {
-        let app_handle = app_handle.clone();
+        let progress_events = ThrottledEmitter::acknowledged(
+            app_handle,
+            TRANSCRIPTION_PROGRESS_EVENT,
+            DEFAULT_MIN_EVENT_INTERVAL,
+        );
         tokio::spawn(async move {
             let mut progress: u8 = 0;
             while progress < 95 {
                 sleep(Duration::from_millis(500)).await;
                 progress = progress.saturating_add(5);
-                let _ = app_handle.emit("transcription-progress", progress);
+                progress_events.emit(progress);
             }
         })
     };
 
-    // Run core transcription pipeline (non-blocking)
-    let cfg = TranscriptionConfig::default();
-    let result = pipeline::transcription_pipeline(audio_samples, cfg).await;
+    // Run core transcription pipeline on the resident model (non-blocking)
+    let result = match resident_engine(&cfg).await {
+        Ok(engine) => {
+            pipeline::transcription_pipeline_with_handle(
+                audio_samples,
+                &engine,
+                active_transcription_handle(),
+            )
+            .await
+        }
+        Err(e) => Err(e),
+    };
 
     // Stop progress task gracefully
     progress_handle.abort();
 
+    let result = result.map(|mut res| {
+        if let Some(chunked) = chunked {
+            res.text = chunked.finish(&res.text);
+        }
+        res
+    });
+
     match result {
         Ok(res) => {
             // Ensure UI reaches 100% and completion event
-            let _ = app_handle.emit("transcription-progress", 100u8);
-            let _ = app_handle.emit("transcription-completed", res.text.clone());
+            let _ = app_handle.emit(TRANSCRIPTION_PROGRESS_EVENT, 100u8);
+            let _ = app_handle.emit(TRANSCRIPTION_COMPLETED_EVENT, res.text.clone());
+            let _ = app_handle.emit(TRANSCRIPTION_RESULT_EVENT, &res);
+            emit_pipeline_event(app_handle, PipelineEvent::PartialText(res.text.clone()));
             update_global_service_status(ServiceComponent::Transcription, ServiceStatus::Ready)
                 .await;
-            Ok(res.text)
+            if let Some(language) = &res.language {
+                debug!("Transcription language: {}", language);
+                let _ = app_handle.emit(TRANSCRIPTION_LANGUAGE_EVENT, language.clone());
+            }
+            Ok(res)
         }
         Err(err) => {
             error!("Transcription failed: {}", err);
@@ -311,7 +1758,7 @@ async fn transcribe_audio_with_status(
                 ServiceStatus::Error(err.to_string()),
             )
             .await;
-            let _ = app_handle.emit("transcription-error", err.to_string());
+            let _ = app_handle.emit(TRANSCRIPTION_ERROR_EVENT, err.to_string());
             Err(AppError::Transcription(err.to_string()))
         }
     }
@@ -349,7 +1796,7 @@ async fn transcribe_audio(
     debug!("Starting transcription of {} samples", audio_samples.len());
 
     // Emit transcription start event
-    let _ = app_handle.emit("transcription-started", ());
+    let _ = app_handle.emit(TRANSCRIPTION_STARTED_EVENT, ());
 
     // TODO: Replace with actual Whisper transcription
     // This placeholder simulates transcription processing time
@@ -371,21 +1818,83 @@ async fn transcribe_audio(
     let transcribed_text = mock_text.to_string();
 
     // Emit transcription completion event
-    let _ = app_handle.emit("transcription-completed", transcribed_text.clone());
+    let _ = app_handle.emit(TRANSCRIPTION_COMPLETED_EVENT, transcribed_text.clone());
 
-    debug!("Transcription completed: '{}'", transcribed_text);
+    debug!(
+        "Transcription completed: '{}'",
+        loggable_text(&transcribed_text)
+    );
     Ok(transcribed_text)
 }
 
 // ============================================================================
-// Text Injection Step (Placeholder)
+// Clipboard Output Step
+// ============================================================================
+
+/// Copies the transcribed text to the clipboard
+///
+/// # Arguments
+///
+/// * `text` - The text to copy
+/// * `notify` - Whether to show a notification with the copied text
+/// * `app_handle` - The Tauri application handle for clipboard access
+///
+/// # Errors
+///
+/// Returns `AppError::TextInjection` if the clipboard cannot be written.
+/// A notification that fails to show is only logged.
+fn copy_to_clipboard(text: &str, notify: bool, app_handle: &AppHandle) -> Result<(), AppError> {
+    app_handle
+        .clipboard()
+        .write_text(text)
+        .map_err(|e| AppError::TextInjection(format!("Failed to write clipboard: {e}")))?;
+    let _ = app_handle.emit(TEXT_COPIED_EVENT, text);
+
+    if notify {
+        if let Err(e) = app_handle
+            .notification()
+            .builder()
+            .title("Copied to clipboard")
+            .body(notification_preview(text))
+            .show()
+        {
+            warn!("Failed to show clipboard notification: {}", e);
+        }
+    }
+    Ok(())
+}
+
+/// Tells the user a dictation was copied because its target application is
+/// on the injection blocklist
+///
+/// Shown regardless of the notification settings: without it, the user
+/// would be left wondering why nothing was typed.
+fn notify_injection_blocked(app_handle: &AppHandle) {
+    if let Err(e) = app_handle
+        .notification()
+        .builder()
+        .title("Copied instead of typed")
+        .body("Speakr does not type into this app. Paste the dictation where you need it.")
+        .show()
+    {
+        warn!("Failed to show injection blocklist notification: {}", e);
+    }
+}
+
+// ============================================================================
+// Text Injection Step
 // ============================================================================
 
-/// Injects transcribed text into the currently focused application (placeholder)
+/// Injects transcribed text into the currently focused application
+///
+/// Types or pastes the text, depending on `options.method`, through the
+/// platform's injection backend (see [`crate::injection`]). Also used to
+/// inject pending dictations after review.
 ///
 /// # Arguments
 ///
 /// * `text` - The text to inject
+/// * `options` - Injection method and typing delay for the target application
 /// * `app_handle` - The Tauri application handle for event emission
 ///
 /// # Returns
@@ -394,37 +1903,83 @@ async fn transcribe_audio(
 ///
 /// # Errors
 ///
-/// Returns `AppError` if text injection fails.
-///
-/// # Note
-///
-/// This is a placeholder implementation that simulates text injection.
-/// The actual implementation will use the enigo crate for synthetic keystrokes.
-#[instrument(level = "debug", skip(app_handle))]
-async fn inject_text(text: String, app_handle: &AppHandle) -> Result<(), AppError> {
-    debug!("Starting text injection: '{}'", text);
-
-    // Emit text injection start event
-    let _ = app_handle.emit("text-injection-started", text.clone());
-
-    // TODO: Replace with actual text injection using enigo
-    // This placeholder simulates injection processing time
-    let injection_time = Duration::from_millis((text.len() as u64) * 3); // ~3ms per character
-    tokio::time::sleep(injection_time).await;
+/// Returns `AppError::TextInjection` if the text is empty or cannot be
+/// injected.
+#[instrument(level = "debug", skip(app_handle, text))]
+pub(crate) async fn inject_text(
+    text: String,
+    options: InjectionOptions,
+    app_handle: &AppHandle,
+) -> Result<(), AppError> {
+    debug!(target: INJECTION_TARGET, "Starting text injection: '{}'", loggable_text(&text));
 
-    // Simulate potential injection failures for testing
     if text.is_empty() {
         return Err(AppError::TextInjection(
             "Cannot inject empty text".to_string(),
         ));
     }
 
-    // Mock successful injection
-    info!("Mock text injection completed: '{}'", text);
+    // Emit text injection start event
+    let _ = app_handle.emit(TEXT_INJECTION_STARTED_EVENT, text.clone());
+
+    debug!(target: INJECTION_TARGET, "Injecting via {:?}", options.method);
+    let typed = Zeroizing::new(text.clone());
+    let delay = Duration::from_millis(u64::from(options.typing_delay_ms));
+    run_injector(move |injector| match options.method {
+        InjectionMethod::Keystrokes => injector.type_text(&typed, delay),
+        InjectionMethod::Clipboard => injector.paste(&typed),
+    })
+    .await?;
+
+    info!(target: INJECTION_TARGET, "Text injection completed: '{}'", loggable_text(&text));
 
     // Emit text injection completion event
-    let _ = app_handle.emit("text-injection-completed", text);
+    let _ = app_handle.emit(TEXT_INJECTION_COMPLETED_EVENT, text);
+
+    Ok(())
+}
+
+/// Corrects text already typed into the target application
+///
+/// Erases `edit.backspaces` characters and types `edit.text`.
+///
+/// # Arguments
+///
+/// * `edit` - The correction to apply
+/// * `options` - Injection options for the target application
+/// * `app_handle` - The Tauri application handle for event emission
+///
+/// # Returns
+///
+/// Returns `Ok(())` if the correction was applied.
+///
+/// # Errors
+///
+/// Returns `AppError::TextInjection` if the correction cannot be applied.
+#[instrument(level = "debug", skip(app_handle, edit))]
+async fn apply_text_edit(
+    edit: TextEdit,
+    options: InjectionOptions,
+    app_handle: &AppHandle,
+) -> Result<(), AppError> {
+    debug!(
+        target: INJECTION_TARGET,
+        "Erasing {} characters, then typing '{}'", edit.backspaces, loggable_text(&edit.text)
+    );
+
+    let delay = Duration::from_millis(u64::from(options.typing_delay_ms));
+    let backspaces = edit.backspaces;
+    let typed = Zeroizing::new(edit.text.clone());
+    run_injector(move |injector| {
+        injector.erase(backspaces, delay)?;
+        if typed.is_empty() {
+            return Ok(());
+        }
+        injector.type_text(&typed, delay)
+    })
+    .await?;
 
+    let _ = app_handle.emit(TEXT_INJECTION_REVISED_EVENT, edit.text);
     Ok(())
 }
 
@@ -449,7 +2004,8 @@ pub async fn handle_workflow_error(error: AppError, app_handle: &AppHandle) {
         _ => format!("Workflow error: {error}"),
     };
 
-    let _ = app_handle.emit("workflow-error", error_message);
+    let _ = app_handle.emit(WORKFLOW_ERROR_EVENT, WorkflowError::new(error_message));
+    emit_pipeline_event(app_handle, PipelineEvent::Failed(error.clone()));
 
     // TODO: Implement cleanup logic:
     // - Stop any active recording
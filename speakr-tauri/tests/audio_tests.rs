@@ -4,9 +4,12 @@ use tempfile::TempDir;
 
 // Import audio functions from their new module locations
 use speakr_lib::audio::files::{
-    generate_audio_filename_with_timestamp, save_audio_samples_to_wav_file,
+    encode_wav_in_memory, generate_audio_filename_with_timestamp, load_audio_samples_from_wav_file,
+    read_wav_info_tags, render_recording_filename, save_audio_samples_to_wav_file,
+    save_tagged_recording, RecordingMetadata,
 };
 use speakr_lib::audio::recording::{debug_record_audio_to_file, debug_record_real_audio_to_file};
+use speakr_types::{AudioDuration, ModelId};
 
 #[tokio::test]
 async fn test_debug_record_audio_to_file_saves_with_timestamp() {
@@ -118,6 +121,128 @@ async fn test_save_audio_samples_to_wav_file() {
     assert_eq!(&file_content[8..12], b"WAVE", "Should have WAVE format");
 }
 
+#[tokio::test]
+async fn test_load_audio_samples_from_wav_file_round_trips() {
+    // Samples written by the recorder must load back unchanged for replay
+    let temp_dir = TempDir::new().expect("Should create temp dir");
+    let path = temp_dir.path().join("replay.wav");
+    let samples: Vec<i16> = (0..1600).map(|i| (i * 7 % 2000) as i16 - 1000).collect();
+
+    save_audio_samples_to_wav_file(&samples, &path)
+        .await
+        .expect("Should save audio samples to WAV file");
+
+    let loaded = load_audio_samples_from_wav_file(&path)
+        .await
+        .expect("Should load audio samples from WAV file");
+
+    assert_eq!(loaded, samples);
+}
+
+#[test]
+fn test_encode_wav_in_memory_matches_recording_spec() {
+    let samples: Vec<i16> = (0..800).map(|i| (i * 13 % 4000) as i16 - 2000).collect();
+    let wav = encode_wav_in_memory(&samples).expect("Should encode WAV data");
+
+    let mut reader = hound::WavReader::new(std::io::Cursor::new(wav)).expect("Should parse WAV");
+    let spec = reader.spec();
+    assert_eq!(
+        (spec.channels, spec.sample_rate, spec.bits_per_sample),
+        (1, 16_000, 16)
+    );
+    let decoded: Vec<i16> = reader.samples::<i16>().map(Result::unwrap).collect();
+    assert_eq!(decoded, samples);
+}
+
+#[tokio::test]
+async fn test_load_audio_samples_from_wav_file_rejects_wrong_format() {
+    // Replay only accepts the 16 kHz mono 16-bit recording spec
+    let temp_dir = TempDir::new().expect("Should create temp dir");
+    let path = temp_dir.path().join("stereo.wav");
+    let spec = hound::WavSpec {
+        channels: 2,
+        sample_rate: 44_100,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut writer = hound::WavWriter::create(&path, spec).expect("Should create WAV file");
+    writer.write_sample(0i16).expect("Should write sample");
+    writer.write_sample(0i16).expect("Should write sample");
+    writer.finalize().expect("Should finalize WAV file");
+
+    let result = load_audio_samples_from_wav_file(&path).await;
+    assert!(matches!(
+        result,
+        Err(speakr_types::AppError::AudioCapture(_))
+    ));
+
+    let missing = load_audio_samples_from_wav_file(&temp_dir.path().join("missing.wav")).await;
+    assert!(matches!(
+        missing,
+        Err(speakr_types::AppError::FileSystem(_))
+    ));
+}
+
+fn sample_metadata(target_app: Option<&str>) -> RecordingMetadata {
+    use chrono::TimeZone;
+    RecordingMetadata {
+        recorded_at: chrono::Utc.with_ymd_and_hms(2025, 3, 4, 9, 15, 30).unwrap(),
+        duration: std::time::Duration::from_millis(2500),
+        model: ModelId::new("small"),
+        target_app: target_app.map(str::to_string),
+    }
+}
+
+#[test]
+fn test_render_recording_filename_fills_placeholders() {
+    let metadata = sample_metadata(Some("com.apple.Notes"));
+    assert_eq!(
+        render_recording_filename("{date}_{time}_{app}_{model}_{duration}s", &metadata),
+        "2025-03-04_09-15-30_com.apple.Notes_small_2s.wav"
+    );
+    assert_eq!(
+        render_recording_filename("", &sample_metadata(None)),
+        "recording_2025-03-04_09-15-30.wav"
+    );
+    assert_eq!(
+        render_recording_filename("../{app}:x", &sample_metadata(None)),
+        "_unknown_x.wav"
+    );
+}
+
+#[tokio::test]
+async fn test_save_tagged_recording_embeds_metadata() {
+    let temp_dir = TempDir::new().expect("Should create temp dir");
+    let dir = temp_dir.path().join("recordings");
+    let samples: Vec<i16> = (0..1601).map(|i| (i % 500) as i16).collect();
+    let metadata = sample_metadata(Some("com.apple.Notes"));
+
+    let first = save_tagged_recording(&samples, &dir, "{app}", &metadata)
+        .await
+        .expect("Should save tagged recording");
+    let second = save_tagged_recording(&samples, &dir, "{app}", &metadata)
+        .await
+        .expect("Should save second recording");
+    assert_eq!(first.file_name().unwrap(), "com.apple.Notes.wav");
+    assert_eq!(second.file_name().unwrap(), "com.apple.Notes-2.wav");
+
+    // The tags must not get in the way of reading the audio back
+    let loaded = load_audio_samples_from_wav_file(&first)
+        .await
+        .expect("Should load tagged recording");
+    assert_eq!(loaded, samples);
+
+    let tags = read_wav_info_tags(&first).await.expect("Should read tags");
+    assert_eq!(tags, metadata.info_tags());
+    assert!(tags.contains(&(*b"ISRC", "com.apple.Notes".to_string())));
+    assert!(tags.contains(&(*b"ICMT", "Duration: 2.5 s; Model: small".to_string())));
+
+    let size = std::fs::metadata(&first).unwrap().len();
+    let bytes = std::fs::read(&first).unwrap();
+    let riff_size = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+    assert_eq!(u64::from(riff_size), size - 8);
+}
+
 #[tokio::test]
 async fn test_generate_audio_filename_with_timestamp() {
     // Validates timestamp components and uniqueness over time
@@ -165,7 +290,7 @@ async fn test_debug_real_audio_recording_integration() {
     // This would use the actual AudioRecorder from speakr-core
 
     // Act - This should do a real recording for 1 second
-    let file_path = debug_record_real_audio_to_file(output_dir, 1)
+    let file_path = debug_record_real_audio_to_file(output_dir, AudioDuration::MIN)
         .await
         .expect("Should record real audio to file");
 
@@ -5,7 +5,7 @@
 
 // Import functions from the speakr_lib crate (now pub)
 use speakr_lib::commands::validation::validate_hot_key_internal;
-use speakr_types::{AppSettings, HotkeyConfig};
+use speakr_types::{AppSettings, AudioDuration, HotkeyConfig, ModelId};
 // Additional imports for merged tests
 
 #[allow(unused_imports)]
@@ -18,7 +18,7 @@ async fn test_validate_hot_key_success() {
         "CmdOrCtrl+Alt+Space".to_string(),
         "Ctrl+Shift+F".to_string(),
         "Alt+`".to_string(),
-        "CMD+SPACE".to_string(), // Legacy format support
+        "CMD+SHIFT+SPACE".to_string(), // Legacy format support
     ];
 
     // Act & Assert
@@ -310,9 +310,10 @@ fn test_app_settings_structure_supports_hotkey_configuration() {
     let settings = AppSettings {
         version: 1,
         hot_key: "CmdOrCtrl+Alt+TestKey".to_string(),
-        model_size: "medium".to_string(),
+        model: ModelId::new("medium"),
         auto_launch: false,
-        audio_duration_secs: 10,
+        audio_duration: AudioDuration::DEFAULT,
+        ..Default::default()
     };
 
     // Assert
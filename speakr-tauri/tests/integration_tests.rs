@@ -72,7 +72,7 @@ mod future_integration_tests {
 // Tests for the integration between settings persistence and hotkey registration.
 // ============================================================================
 
-use speakr_types::{AppSettings, HotkeyConfig};
+use speakr_types::{AppSettings, AudioDuration, HotkeyConfig, ModelId};
 use std::path::PathBuf;
 use tempfile::TempDir;
 
@@ -88,9 +88,10 @@ async fn create_test_settings_with_hotkey(hotkey: &str) -> (TempDir, PathBuf) {
     let settings = AppSettings {
         version: 1,
         hot_key: hotkey.to_string(),
-        model_size: "medium".to_string(),
+        model: ModelId::new("medium"),
         auto_launch: false,
-        audio_duration_secs: 10,
+        audio_duration: AudioDuration::DEFAULT,
+        ..Default::default()
     };
 
     let settings_json =
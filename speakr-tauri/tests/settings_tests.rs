@@ -3,61 +3,88 @@
 // ============================================================================
 #![allow(clippy::field_reassign_with_default)]
 
-use speakr_types::{AppSettings, DEFAULT_MODEL_SIZE, DEFAULT_SCHEMA_VERSION};
+use speakr_types::{AppSettings, AudioDuration, ModelId, DEFAULT_MODEL, DEFAULT_SCHEMA_VERSION};
 use tempfile::TempDir;
 use tracing::debug;
 
 // Import functions from the speakr_lib crate (now pub(crate))
 use speakr_lib::settings::{
-    load_settings_from_dir, migrate_settings, save_settings_to_dir, try_load_settings_file,
-    validate_settings_directory_permissions,
+    commit_migration_preview, delete_profile_in_dir, load_profiles_from_dir,
+    load_settings_from_dir, migrate_settings, rollback_interrupted_migration,
+    save_current_as_profile_in_dir, save_settings_to_dir, switch_profile_in_dir,
+    try_load_settings_file, validate_settings_directory_permissions,
 };
 
 #[tokio::test]
 async fn test_app_settings_default() {
     let settings = AppSettings::default();
-    assert_eq!(settings.version, 2); // Updated to version 2
+    assert_eq!(settings.version, DEFAULT_SCHEMA_VERSION);
     assert_eq!(settings.hot_key, "CmdOrCtrl+Alt+F1"); // Use the actual default from speakr_types
-    assert_eq!(settings.model_size, "medium");
+    assert_eq!(settings.model.as_str(), "medium");
     assert!(!settings.auto_launch);
 }
 
 #[tokio::test]
-async fn test_save_settings_rejects_invalid_audio_duration() {
-    // Test that save_settings_to_dir rejects invalid audio duration
+async fn test_load_settings_clamps_audio_duration_below_min() {
+    // A hand-edited file with a zero duration loads with the minimum instead
     let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let settings_dir = temp_dir.path().to_path_buf();
+    write_settings_with_audio_duration(&settings_dir, 0);
 
-    let mut invalid_settings = AppSettings::default();
-    invalid_settings.audio_duration_secs = 0; // Invalid: below minimum
-
-    let result = save_settings_to_dir(&invalid_settings, &temp_dir.path().to_path_buf()).await;
-    assert!(result.is_err());
-
-    if let Err(speakr_types::AppError::Settings(msg)) = result {
-        assert!(msg.contains("Invalid audio duration"));
-        assert!(msg.contains("0 seconds"));
-    } else {
-        panic!("Expected Settings error with audio duration message");
-    }
+    let loaded_settings = load_settings_from_dir(&settings_dir).await.unwrap();
+    assert_eq!(loaded_settings.audio_duration, AudioDuration::MIN);
 }
 
 #[tokio::test]
-async fn test_save_settings_rejects_audio_duration_above_max() {
-    // Test that save_settings_to_dir rejects audio duration above maximum
+async fn test_load_settings_clamps_audio_duration_above_max() {
+    // A hand-edited file with a too-long duration loads with the maximum instead
     let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let settings_dir = temp_dir.path().to_path_buf();
+    write_settings_with_audio_duration(&settings_dir, 31);
 
-    let mut invalid_settings = AppSettings::default();
-    invalid_settings.audio_duration_secs = 31; // Invalid: above maximum
+    let loaded_settings = load_settings_from_dir(&settings_dir).await.unwrap();
+    assert_eq!(loaded_settings.audio_duration, AudioDuration::MAX);
+}
 
-    let result = save_settings_to_dir(&invalid_settings, &temp_dir.path().to_path_buf()).await;
-    assert!(result.is_err());
+/// Writes default settings with `audio_duration_secs` replaced by `secs`.
+fn write_settings_with_audio_duration(settings_dir: &std::path::Path, secs: u32) {
+    let mut json = serde_json::to_value(AppSettings::default()).unwrap();
+    json["audio_duration_secs"] = secs.into();
+    std::fs::write(
+        settings_dir.join("settings.json"),
+        serde_json::to_string_pretty(&json).unwrap(),
+    )
+    .unwrap();
+}
 
-    if let Err(speakr_types::AppError::Settings(msg)) = result {
-        assert!(msg.contains("Invalid audio duration"));
-        assert!(msg.contains("31 seconds"));
-    } else {
-        panic!("Expected Settings error with audio duration message");
-    }
+#[test]
+fn test_audio_duration_rejects_out_of_range_values() {
+    let error = AudioDuration::new(0).unwrap_err();
+    assert!(error.contains("Invalid audio duration"));
+    assert!(error.contains("0 seconds"));
+
+    let error = AudioDuration::new(31).unwrap_err();
+    assert!(error.contains("Invalid audio duration"));
+    assert!(error.contains("31 seconds"));
+}
+
+#[tokio::test]
+async fn test_save_settings_io_error_keeps_path() {
+    // A settings "directory" that is actually a file cannot be written into
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let not_a_dir = temp_dir.path().join("settings-dir");
+    std::fs::write(&not_a_dir, "").unwrap();
+
+    let error = save_settings_to_dir(&AppSettings::default(), &not_a_dir)
+        .await
+        .unwrap_err();
+
+    assert!(error.code().starts_with("E_IO"), "{error:?}");
+    let details = error.details().expect("I/O errors carry details");
+    assert!(details
+        .path
+        .as_deref()
+        .is_some_and(|path| path.starts_with(&*not_a_dir.to_string_lossy())));
 }
 
 #[tokio::test]
@@ -66,7 +93,7 @@ async fn test_save_settings_accepts_valid_audio_duration() {
     let temp_dir = TempDir::new().expect("Failed to create temp directory");
 
     let mut valid_settings = AppSettings::default();
-    valid_settings.audio_duration_secs = 15; // Valid: within range
+    valid_settings.audio_duration = AudioDuration::new(15).unwrap(); // Valid: within range
 
     let result = save_settings_to_dir(&valid_settings, &temp_dir.path().to_path_buf()).await;
     assert!(result.is_ok());
@@ -75,7 +102,7 @@ async fn test_save_settings_accepts_valid_audio_duration() {
     let loaded_settings = load_settings_from_dir(&temp_dir.path().to_path_buf())
         .await
         .unwrap();
-    assert_eq!(loaded_settings.audio_duration_secs, 15);
+    assert_eq!(loaded_settings.audio_duration.as_secs(), 15);
 }
 
 #[tokio::test]
@@ -85,17 +112,14 @@ async fn test_save_settings_internal_validates_settings() {
 
     // Arrange - Create invalid settings
     let mut invalid_settings = AppSettings::default();
-    invalid_settings.audio_duration_secs = 0; // Invalid duration
+    invalid_settings.language = "english".to_string(); // Invalid language code
 
     // Act - Try to save invalid settings
     let result = save_settings_internal(invalid_settings).await;
 
     // Assert - Should fail with validation error
     assert!(result.is_err());
-    assert!(result
-        .unwrap_err()
-        .to_string()
-        .contains("Invalid audio duration"));
+    assert!(result.unwrap_err().to_string().contains("Invalid language"));
 }
 
 #[tokio::test]
@@ -121,17 +145,14 @@ async fn test_save_settings_to_dir_validates_settings() {
 
     // Arrange - Create invalid settings
     let mut invalid_settings = AppSettings::default();
-    invalid_settings.audio_duration_secs = 31; // Invalid duration
+    invalid_settings.language = "english".to_string(); // Invalid language code
 
     // Act - Try to save invalid settings directly to dir
     let result = save_settings_to_dir(&invalid_settings, &temp_dir_path).await;
 
     // Assert - Should fail with validation error
     assert!(result.is_err());
-    assert!(result
-        .unwrap_err()
-        .to_string()
-        .contains("Invalid audio duration"));
+    assert!(result.unwrap_err().to_string().contains("Invalid language"));
 }
 
 #[tokio::test]
@@ -142,7 +163,7 @@ async fn test_save_settings_to_dir_preserves_version() {
 
     // Arrange - Create settings with current version
     let settings = AppSettings::default();
-    assert_eq!(settings.version, 2); // Should be version 2 now
+    assert_eq!(settings.version, DEFAULT_SCHEMA_VERSION);
 
     // Act - Save settings
     let result = save_settings_to_dir(&settings, &temp_dir_path).await;
@@ -150,7 +171,7 @@ async fn test_save_settings_to_dir_preserves_version() {
 
     // Assert - Load and verify version is preserved
     let loaded_settings = load_settings_from_dir(&temp_dir_path).await.unwrap();
-    assert_eq!(loaded_settings.version, 2);
+    assert_eq!(loaded_settings.version, DEFAULT_SCHEMA_VERSION);
 }
 
 #[tokio::test]
@@ -158,9 +179,10 @@ async fn test_settings_serialization() {
     let settings = AppSettings {
         version: 1,
         hot_key: "CmdOrCtrl+Alt+D".to_string(),
-        model_size: "large".to_string(),
+        model: ModelId::new("large"),
         auto_launch: true,
-        audio_duration_secs: 10,
+        audio_duration: AudioDuration::DEFAULT,
+        ..Default::default()
     };
 
     let json = serde_json::to_string(&settings).expect("Settings should serialize to JSON");
@@ -184,9 +206,10 @@ async fn debug_save_button_functionality() {
     let test_settings = AppSettings {
         version: 1,
         hot_key: "CmdOrCtrl+Alt+T".to_string(),
-        model_size: "medium".to_string(),
+        model: ModelId::new("medium"),
         auto_launch: true,
-        audio_duration_secs: 10,
+        audio_duration: AudioDuration::DEFAULT,
+        ..Default::default()
     };
 
     debug!("⚙️  Test settings: {:?}", test_settings);
@@ -229,11 +252,12 @@ async fn test_save_and_load_settings() {
 
     // Arrange
     let test_settings = AppSettings {
-        version: 2,
+        version: DEFAULT_SCHEMA_VERSION,
         hot_key: "CmdOrCtrl+Alt+S".to_string(),
-        model_size: "large".to_string(),
+        model: ModelId::new("large"),
         auto_launch: true,
-        audio_duration_secs: 10,
+        audio_duration: AudioDuration::DEFAULT,
+        ..Default::default()
     };
 
     // Test the helper function directly since we can't override the global path
@@ -248,7 +272,7 @@ async fn test_save_and_load_settings() {
 
     // Test migration works
     let migrated = migrate_settings(loaded_settings);
-    assert_eq!(migrated, test_settings); // Should be unchanged since it's already version 1
+    assert_eq!(migrated, test_settings); // Should be unchanged since it's already current
 }
 
 #[tokio::test]
@@ -268,6 +292,66 @@ async fn test_settings_migration() {
     assert_eq!(unchanged, current_settings);
 }
 
+/// Writes a version 1 settings file, which needs migrating.
+fn write_version_1_settings(settings_dir: &std::path::Path) -> String {
+    let mut old_settings = AppSettings::default();
+    old_settings.version = 1;
+    old_settings.hot_key = "CmdOrCtrl+Shift+D".to_string();
+    let json = serde_json::to_string_pretty(&old_settings).unwrap();
+    std::fs::write(settings_dir.join("settings.json"), &json).unwrap();
+    json
+}
+
+#[tokio::test]
+async fn test_migration_is_previewed_until_committed() {
+    let temp_dir = TempDir::new().unwrap();
+    let settings_dir = temp_dir.path().to_path_buf();
+    let original = write_version_1_settings(&settings_dir);
+
+    let loaded = load_settings_from_dir(&settings_dir).await.unwrap();
+    assert_eq!(loaded.version, DEFAULT_SCHEMA_VERSION);
+    assert_eq!(loaded.hot_key, "CmdOrCtrl+Shift+D");
+
+    // The original is untouched while the migration is on trial
+    let settings_path = settings_dir.join("settings.json");
+    assert_eq!(std::fs::read_to_string(&settings_path).unwrap(), original);
+    assert!(settings_dir.join("settings.json.preview").exists());
+
+    assert!(commit_migration_preview(&settings_dir).await.unwrap());
+    let committed = try_load_settings_file(&settings_path).unwrap();
+    assert_eq!(committed, loaded);
+    assert_eq!(
+        std::fs::read_to_string(settings_dir.join("settings.json.backup")).unwrap(),
+        original
+    );
+    assert!(!settings_dir.join("settings.json.preview").exists());
+    assert!(!rollback_interrupted_migration(&settings_dir));
+}
+
+#[tokio::test]
+async fn test_interrupted_migration_is_rolled_back() {
+    let temp_dir = TempDir::new().unwrap();
+    let settings_dir = temp_dir.path().to_path_buf();
+    let original = write_version_1_settings(&settings_dir);
+
+    // The first run loads the migrated settings, then crashes before committing
+    load_settings_from_dir(&settings_dir).await.unwrap();
+
+    assert!(rollback_interrupted_migration(&settings_dir));
+    assert!(settings_dir.join("settings.json.preview.failed").exists());
+    assert!(!commit_migration_preview(&settings_dir).await.unwrap());
+
+    // Later runs migrate in memory only and leave the original alone
+    let loaded = load_settings_from_dir(&settings_dir).await.unwrap();
+    assert_eq!(loaded.version, DEFAULT_SCHEMA_VERSION);
+    assert!(!settings_dir.join("settings.json.preview").exists());
+    assert!(!rollback_interrupted_migration(&settings_dir));
+    assert_eq!(
+        std::fs::read_to_string(settings_dir.join("settings.json")).unwrap(),
+        original
+    );
+}
+
 #[tokio::test]
 async fn test_corruption_recovery_from_backup() {
     // Use isolated temporary directory instead of real settings path
@@ -348,6 +432,32 @@ async fn test_corruption_recovery_fallback_to_defaults() {
     // The load_settings command handles this logic
 }
 
+#[tokio::test]
+async fn test_corrupt_settings_are_quarantined_with_a_notice() {
+    use speakr_lib::settings::recovery::load_settings_recovery_from_dir;
+
+    let temp_dir = TempDir::new().expect("Should create temp dir");
+    let settings_dir = temp_dir.path().to_path_buf();
+    std::fs::write(settings_dir.join("settings.json"), "invalid json")
+        .expect("Should write corrupt main file");
+    std::fs::write(settings_dir.join("settings.json.backup"), "also invalid")
+        .expect("Should write corrupt backup file");
+
+    let loaded = load_settings_from_dir(&settings_dir)
+        .await
+        .expect("Should fall back to defaults");
+    assert_eq!(loaded, AppSettings::default());
+
+    let recovery = load_settings_recovery_from_dir(&settings_dir)
+        .expect("Should read the notice")
+        .expect("Should record a notice");
+    assert!(recovery.error.contains("backup"));
+    assert_eq!(
+        std::fs::read_to_string(&recovery.quarantined_path).expect("Should keep the corrupt file"),
+        "invalid json"
+    );
+}
+
 #[tokio::test]
 async fn test_settings_performance() {
     use std::time::Instant;
@@ -406,9 +516,10 @@ async fn test_isolated_settings_save_and_load() {
     let test_settings = AppSettings {
         version: DEFAULT_SCHEMA_VERSION,
         hot_key: "CmdOrCtrl+Alt+T".to_string(),
-        model_size: "large".to_string(),
+        model: ModelId::new("large"),
         auto_launch: true,
-        audio_duration_secs: 10,
+        audio_duration: AudioDuration::DEFAULT,
+        ..Default::default()
     };
 
     // These functions should accept directory paths to enable test isolation
@@ -477,7 +588,7 @@ async fn test_load_settings_from_dir_with_custom_hotkey() {
     // Assert
     assert_eq!(loaded_settings.hot_key, custom_hotkey);
     assert_eq!(loaded_settings.version, DEFAULT_SCHEMA_VERSION);
-    assert_eq!(loaded_settings.model_size, DEFAULT_MODEL_SIZE);
+    assert_eq!(loaded_settings.model.as_str(), DEFAULT_MODEL);
     assert!(!loaded_settings.auto_launch);
 }
 
@@ -498,7 +609,7 @@ async fn test_load_settings_from_dir_returns_defaults_when_file_missing() {
     let default_settings = AppSettings::default();
     assert_eq!(loaded_settings.hot_key, default_settings.hot_key);
     assert_eq!(loaded_settings.version, default_settings.version);
-    assert_eq!(loaded_settings.model_size, default_settings.model_size);
+    assert_eq!(loaded_settings.model, default_settings.model);
     assert_eq!(loaded_settings.auto_launch, default_settings.auto_launch);
 }
 
@@ -535,9 +646,10 @@ async fn test_save_and_load_settings_roundtrip_with_custom_hotkey() {
     let original_settings = AppSettings {
         version: DEFAULT_SCHEMA_VERSION,
         hot_key: custom_hotkey.to_string(),
-        model_size: "large".to_string(),
+        model: ModelId::new("large"),
         auto_launch: true,
-        audio_duration_secs: 10,
+        audio_duration: AudioDuration::DEFAULT,
+        ..Default::default()
     };
 
     // Act
@@ -552,7 +664,7 @@ async fn test_save_and_load_settings_roundtrip_with_custom_hotkey() {
     // Assert
     assert_eq!(loaded_settings.hot_key, original_settings.hot_key);
     assert_eq!(loaded_settings.version, original_settings.version);
-    assert_eq!(loaded_settings.model_size, original_settings.model_size);
+    assert_eq!(loaded_settings.model, original_settings.model);
     assert_eq!(loaded_settings.auto_launch, original_settings.auto_launch);
 }
 
@@ -580,9 +692,10 @@ async fn test_load_settings_preserves_various_hotkey_formats() {
         let settings = AppSettings {
             version: 1,
             hot_key: hotkey.to_string(),
-            model_size: "medium".to_string(),
+            model: ModelId::new("medium"),
             auto_launch: false,
-            audio_duration_secs: 10,
+            audio_duration: AudioDuration::DEFAULT,
+            ..Default::default()
         };
 
         // Act
@@ -611,9 +724,10 @@ async fn test_load_settings_handles_empty_hotkey() {
     let settings = AppSettings {
         version: 1,
         hot_key: "".to_string(), // Empty hotkey
-        model_size: "medium".to_string(),
+        model: ModelId::new("medium"),
         auto_launch: false,
-        audio_duration_secs: 10,
+        audio_duration: AudioDuration::DEFAULT,
+        ..Default::default()
     };
 
     // Act
@@ -639,9 +753,10 @@ async fn test_load_settings_handles_special_characters_in_hotkey() {
     let settings = AppSettings {
         version: 1,
         hot_key: special_hotkey.to_string(),
-        model_size: "medium".to_string(),
+        model: ModelId::new("medium"),
         auto_launch: false,
-        audio_duration_secs: 10,
+        audio_duration: AudioDuration::DEFAULT,
+        ..Default::default()
     };
 
     // Act
@@ -672,9 +787,10 @@ async fn test_settings_roundtrip_preserves_custom_hotkey() {
     let settings = AppSettings {
         version: 1,
         hot_key: custom_hotkey.to_string(),
-        model_size: "medium".to_string(),
+        model: ModelId::new("medium"),
         auto_launch: false,
-        audio_duration_secs: 10,
+        audio_duration: AudioDuration::DEFAULT,
+        ..Default::default()
     };
 
     save_settings_to_dir(&settings, &temp_dir.path().to_path_buf())
@@ -767,3 +883,58 @@ async fn test_load_settings_performance() {
         "Settings loading took too long: {duration:?}"
     );
 }
+
+#[tokio::test]
+async fn test_switching_profiles_keeps_each_profiles_values() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let settings_dir = temp_dir.path().to_path_buf();
+
+    let mut work = AppSettings::default();
+    work.hot_key = "CmdOrCtrl+Alt+W".to_string();
+    work.vocabulary = vec!["Kubernetes".to_string()];
+    save_settings_to_dir(&work, &settings_dir).await.unwrap();
+    save_current_as_profile_in_dir(&settings_dir, "Work")
+        .await
+        .unwrap();
+
+    let mut personal = work.clone();
+    personal.hot_key = "CmdOrCtrl+Alt+P".to_string();
+    personal.model = ModelId::new("small");
+    personal.vocabulary = vec![];
+    save_settings_to_dir(&personal, &settings_dir)
+        .await
+        .unwrap();
+    let profiles = save_current_as_profile_in_dir(&settings_dir, "Personal")
+        .await
+        .unwrap();
+    assert_eq!(profiles.profiles.len(), 2);
+    assert_eq!(profiles.active.as_deref(), Some("Personal"));
+
+    // Edits made while a profile is active are kept with it
+    personal.vocabulary = vec!["Speakr".to_string()];
+    save_settings_to_dir(&personal, &settings_dir)
+        .await
+        .unwrap();
+
+    let switched = switch_profile_in_dir(&settings_dir, "work").await.unwrap();
+    assert_eq!(switched.hot_key, "CmdOrCtrl+Alt+W");
+    assert_eq!(switched.vocabulary, vec!["Kubernetes".to_string()]);
+    assert_eq!(
+        load_settings_from_dir(&settings_dir).await.unwrap(),
+        switched
+    );
+
+    let profiles = load_profiles_from_dir(&settings_dir).unwrap();
+    assert_eq!(profiles.active.as_deref(), Some("Work"));
+    assert_eq!(
+        profiles.get("Personal").unwrap().vocabulary,
+        vec!["Speakr".to_string()]
+    );
+
+    assert!(switch_profile_in_dir(&settings_dir, "Meetings")
+        .await
+        .is_err());
+    let profiles = delete_profile_in_dir(&settings_dir, "Work").unwrap();
+    assert_eq!(profiles.active, None);
+    assert_eq!(profiles.profiles.len(), 1);
+}
@@ -7,7 +7,7 @@ use speakr_lib::services::{
     },
     ServiceComponent,
 };
-use speakr_types::ServiceStatus;
+use speakr_types::{PlatformLimitation, ServiceStatus};
 
 #[tokio::test]
 async fn test_backend_status_service_creation() {
@@ -48,6 +48,29 @@ async fn test_backend_status_service_all_services_ready() {
     assert!(status.is_ready()); // All ready
 }
 
+#[tokio::test]
+async fn test_backend_status_service_tracks_limitations() {
+    let mut service = BackendStatusService::new();
+    assert!(service.get_current_status().limitations.is_empty());
+
+    service.update_limitation(PlatformLimitation::NoClipboardTool, true);
+    service.update_limitation(PlatformLimitation::NoClipboardTool, true);
+    service.update_limitation(PlatformLimitation::NoGlobalShortcuts, true);
+    assert_eq!(
+        service.get_current_status().limitations,
+        [
+            PlatformLimitation::NoClipboardTool,
+            PlatformLimitation::NoGlobalShortcuts
+        ]
+    );
+
+    service.update_limitation(PlatformLimitation::NoClipboardTool, false);
+    assert_eq!(
+        service.get_current_status().limitations,
+        [PlatformLimitation::NoGlobalShortcuts]
+    );
+}
+
 #[tokio::test]
 async fn test_backend_status_service_error_handling() {
     let mut service = BackendStatusService::new();
@@ -7,7 +7,7 @@
 #![allow(clippy::field_reassign_with_default)]
 
 use speakr_lib::settings::{save_settings_to_dir, IsolatedSettingsLoader, SettingsLoader};
-use speakr_types::{AppSettings, DEFAULT_AUDIO_DURATION_SECS};
+use speakr_types::{AppSettings, AudioDuration};
 use std::sync::Arc;
 use tempfile::TempDir;
 
@@ -53,12 +53,12 @@ pub async fn create_isolated_settings_env() -> (TempDir, Arc<IsolatedSettingsLoa
 ///
 /// ```rust
 /// use test_utils::create_isolated_settings_env_with_settings;
-/// use speakr_types::AppSettings;
+/// use speakr_types::{AppSettings, AudioDuration};
 ///
 /// #[tokio::test]
 /// async fn test_with_custom_settings() {
 ///     let mut settings = AppSettings::default();
-///     settings.audio_duration_secs = 15;
+///     settings.audio_duration = AudioDuration::new(15).unwrap();
 ///
 ///     let (_temp_dir, loader) = create_isolated_settings_env_with_settings(settings).await;
 ///     // Use loader in your test...
@@ -189,19 +189,19 @@ mod tests {
 
         // Should be able to load default settings
         let settings = loader.load_settings().await.unwrap();
-        assert_eq!(settings.audio_duration_secs, DEFAULT_AUDIO_DURATION_SECS);
+        assert_eq!(settings.audio_duration, AudioDuration::DEFAULT);
     }
 
     #[tokio::test]
     async fn test_create_isolated_settings_env_with_custom_settings() {
         let mut custom_settings = AppSettings::default();
-        custom_settings.audio_duration_secs = 25;
+        custom_settings.audio_duration = AudioDuration::new(25).unwrap();
 
         let (_temp_dir, loader) = create_isolated_settings_env_with_settings(custom_settings).await;
 
         // Should load the custom settings
         let loaded_settings = loader.load_settings().await.unwrap();
-        assert_eq!(loaded_settings.audio_duration_secs, 25);
+        assert_eq!(loaded_settings.audio_duration.as_secs(), 25);
     }
 
     #[tokio::test]
@@ -216,8 +216,8 @@ mod tests {
         let settings1 = loader1.load_settings().await.unwrap();
         let settings2 = loader2.load_settings().await.unwrap();
 
-        assert_eq!(settings1.audio_duration_secs, DEFAULT_AUDIO_DURATION_SECS);
-        assert_eq!(settings2.audio_duration_secs, DEFAULT_AUDIO_DURATION_SECS);
+        assert_eq!(settings1.audio_duration, AudioDuration::DEFAULT);
+        assert_eq!(settings2.audio_duration, AudioDuration::DEFAULT);
 
         // They should be independent instances
         assert_eq!(settings1, settings2); // Same values but different instances
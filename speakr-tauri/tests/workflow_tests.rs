@@ -7,8 +7,16 @@
 #![allow(clippy::field_reassign_with_default)]
 
 use speakr_lib::settings::{load_settings_from_dir, save_settings_to_dir};
-use speakr_lib::workflow::create_recording_config_with_loader;
-use speakr_types::{AppError, AppSettings};
+use speakr_lib::workflow::{
+    condition_samples, create_auto_gain_with_loader, create_delivery_for_app_with_loader,
+    create_long_form_with_loader, create_post_processor_for_app_with_loader,
+    create_post_processor_with_loader, create_recording_config_with_loader, Delivery,
+};
+use speakr_types::{
+    AppError, AppProfile, AppSettings, AudioDuration, AutoGain, InjectionMethod, InjectionOptions,
+    InputGain, NewlineOptions, OutputMode, TextTransform, TrailingText, VoiceCommandSettings,
+};
+use std::time::Duration;
 use tempfile::TempDir;
 
 mod test_utils;
@@ -72,7 +80,7 @@ async fn test_audio_capture_uses_settings_duration() {
 
     // Create custom settings with specific audio duration
     let mut settings = AppSettings::default();
-    settings.audio_duration_secs = 15; // Different from default 10 seconds
+    settings.audio_duration = AudioDuration::new(15).unwrap(); // Different from default 10 seconds
 
     // Save settings to isolated directory
     save_settings_to_dir(&settings, &temp_dir_path)
@@ -83,10 +91,10 @@ async fn test_audio_capture_uses_settings_duration() {
     let loaded_settings = load_settings_from_dir(&temp_dir_path).await.unwrap();
 
     // Assert - Verify the audio duration is correctly loaded
-    assert_eq!(loaded_settings.audio_duration_secs, 15);
+    assert_eq!(loaded_settings.audio_duration.as_secs(), 15);
     assert_eq!(
-        loaded_settings.audio_duration_secs,
-        settings.audio_duration_secs
+        loaded_settings.audio_duration.as_secs(),
+        settings.audio_duration.as_secs()
     );
 }
 
@@ -96,19 +104,15 @@ async fn test_audio_capture_respects_duration_limits() {
     let mut settings = AppSettings::default();
 
     // Test valid range
-    settings.audio_duration_secs = 1;
-    assert!(AppSettings::validate_audio_duration(
-        settings.audio_duration_secs
-    ));
+    settings.audio_duration = AudioDuration::new(1).unwrap();
+    assert!(settings.validate().is_ok());
 
-    settings.audio_duration_secs = 30;
-    assert!(AppSettings::validate_audio_duration(
-        settings.audio_duration_secs
-    ));
+    settings.audio_duration = AudioDuration::new(30).unwrap();
+    assert!(settings.validate().is_ok());
 
     // Test invalid range
-    assert!(!AppSettings::validate_audio_duration(0));
-    assert!(!AppSettings::validate_audio_duration(31));
+    assert!(AudioDuration::new(0).is_err());
+    assert!(AudioDuration::new(31).is_err());
 }
 
 #[tokio::test]
@@ -119,7 +123,7 @@ async fn test_workflow_loads_audio_duration_from_settings() {
 
     // Set custom audio duration in settings
     let mut settings = AppSettings::default();
-    settings.audio_duration_secs = 25; // Different from default 10 seconds
+    settings.audio_duration = AudioDuration::new(25).unwrap(); // Different from default 10 seconds
     save_settings_to_dir(&settings, &temp_dir_path)
         .await
         .unwrap();
@@ -128,7 +132,7 @@ async fn test_workflow_loads_audio_duration_from_settings() {
     let loaded_settings = load_settings_from_dir(&temp_dir_path).await.unwrap();
 
     // Assert - The workflow should use this duration when creating RecordingConfig
-    assert_eq!(loaded_settings.audio_duration_secs, 25);
+    assert_eq!(loaded_settings.audio_duration.as_secs(), 25);
 
     // Verify that the workflow uses the settings duration
 }
@@ -139,7 +143,7 @@ async fn test_create_recording_config_from_settings_uses_settings_duration() {
 
     // Arrange - Use isolated settings environment
     let mut settings = AppSettings::default();
-    settings.audio_duration_secs = 20; // Different from default 10 seconds
+    settings.audio_duration = AudioDuration::new(20).unwrap(); // Different from default 10 seconds
 
     let (_temp_dir, loader) =
         test_utils::create_isolated_settings_env_with_settings(settings).await;
@@ -148,7 +152,7 @@ async fn test_create_recording_config_from_settings_uses_settings_duration() {
     let config = create_recording_config_with_loader(loader).await;
 
     // Assert - The config should use the settings duration
-    assert_eq!(config.max_duration_secs(), 20);
+    assert_eq!(config.max_duration().as_secs(), 20);
 }
 
 #[tokio::test]
@@ -169,7 +173,7 @@ async fn test_create_recording_config_from_settings_fallback_on_error() {
     let config = create_recording_config_with_loader(Arc::new(mock_loader)).await;
 
     // Assert - Should fall back to default duration (10 seconds)
-    assert_eq!(config.max_duration_secs(), 10);
+    assert_eq!(config.max_duration().as_secs(), 10);
 }
 
 #[tokio::test]
@@ -182,7 +186,7 @@ async fn test_settings_integration_end_to_end() {
     for duration in test_cases {
         // Set up isolated settings with specific duration
         let mut settings = AppSettings::default();
-        settings.audio_duration_secs = duration;
+        settings.audio_duration = AudioDuration::new(duration).unwrap();
 
         let (_temp_dir, loader) =
             test_utils::create_isolated_settings_env_with_settings(settings).await;
@@ -192,7 +196,7 @@ async fn test_settings_integration_end_to_end() {
 
         // Assert - Config should match settings
         assert_eq!(
-            config.max_duration_secs(),
+            config.max_duration().as_secs(),
             duration,
             "RecordingConfig duration should match settings for duration {duration}"
         );
@@ -218,10 +222,10 @@ async fn test_isolated_settings_environments_dont_interfere() {
 
     // Arrange - Create multiple isolated environments with different settings
     let mut settings1 = AppSettings::default();
-    settings1.audio_duration_secs = 5;
+    settings1.audio_duration = AudioDuration::new(5).unwrap();
 
     let mut settings2 = AppSettings::default();
-    settings2.audio_duration_secs = 25;
+    settings2.audio_duration = AudioDuration::new(25).unwrap();
 
     let (_temp_dir1, loader1) =
         test_utils::create_isolated_settings_env_with_settings(settings1).await;
@@ -236,20 +240,20 @@ async fn test_isolated_settings_environments_dont_interfere() {
 
     // Assert - Each config should use its own settings
     assert_eq!(
-        config1.max_duration_secs(),
+        config1.max_duration().as_secs(),
         5,
         "First environment should use 5 seconds"
     );
     assert_eq!(
-        config2.max_duration_secs(),
+        config2.max_duration().as_secs(),
         25,
         "Second environment should use 25 seconds"
     );
 
     // Verify they are truly independent
     assert_ne!(
-        config1.max_duration_secs(),
-        config2.max_duration_secs(),
+        config1.max_duration().as_secs(),
+        config2.max_duration().as_secs(),
         "Configs should have different durations proving isolation"
     );
 }
@@ -264,7 +268,7 @@ async fn test_mock_settings_loader_for_error_scenarios() {
     // Arrange - Create mock that returns specific settings
     let mut mock_loader = MockSettingsLoader::new();
     let mut expected_settings = AppSettings::default();
-    expected_settings.audio_duration_secs = 15;
+    expected_settings.audio_duration = AudioDuration::new(15).unwrap();
 
     mock_loader
         .expect_load_settings()
@@ -275,7 +279,7 @@ async fn test_mock_settings_loader_for_error_scenarios() {
     let config = create_recording_config_with_loader(Arc::new(mock_loader)).await;
 
     // Assert - Should use mocked settings
-    assert_eq!(config.max_duration_secs(), 15);
+    assert_eq!(config.max_duration().as_secs(), 15);
 }
 
 #[tokio::test]
@@ -291,7 +295,7 @@ async fn test_parallel_isolated_settings_loading() {
 
     for duration in &durations {
         let mut settings = AppSettings::default();
-        settings.audio_duration_secs = *duration;
+        settings.audio_duration = AudioDuration::new(*duration).unwrap();
 
         let (temp_dir, loader) =
             test_utils::create_isolated_settings_env_with_settings(settings).await;
@@ -315,7 +319,7 @@ async fn test_parallel_isolated_settings_loading() {
     // Assert - Each config should have the correct duration
     for (i, config) in configs.iter().enumerate() {
         assert_eq!(
-            config.max_duration_secs(),
+            config.max_duration().as_secs(),
             durations[i],
             "Config {} should have duration {}",
             i,
@@ -328,8 +332,8 @@ async fn test_parallel_isolated_settings_loading() {
         for j in i + 1..configs.len() {
             if durations[i] != durations[j] {
                 assert_ne!(
-                    configs[i].max_duration_secs(),
-                    configs[j].max_duration_secs(),
+                    configs[i].max_duration().as_secs(),
+                    configs[j].max_duration().as_secs(),
                     "Configs with different settings should have different durations"
                 );
             }
@@ -380,7 +384,7 @@ async fn test_workflow_uses_settings_duration_for_recording_time() {
 
     // Arrange - Create settings with a specific duration
     let mut settings = AppSettings::default();
-    settings.audio_duration_secs = 3; // 3 seconds for testing
+    settings.audio_duration = AudioDuration::new(3).unwrap(); // 3 seconds for testing
 
     let (_temp_dir, loader) =
         test_utils::create_isolated_settings_env_with_settings(settings).await;
@@ -389,9 +393,262 @@ async fn test_workflow_uses_settings_duration_for_recording_time() {
     let config = create_recording_config_with_loader(loader).await;
 
     // Assert - Config should use the settings duration
-    assert_eq!(config.max_duration_secs(), 3);
+    assert_eq!(config.max_duration().as_secs(), 3);
 
     // The actual sleep duration in capture_audio_with_loader should match this
     // This is verified by the fact that the config is used to determine sleep time
-    // in the line: Duration::from_secs(config.max_duration_secs() as u64)
+    // in the line: config.max_duration().as_duration()
+}
+
+#[tokio::test]
+async fn test_post_processor_uses_settings_rules() {
+    let settings = AppSettings {
+        post_processing: vec![
+            TextTransform::TrimWhitespace,
+            TextTransform::CapitaliseSentences,
+        ],
+        ..AppSettings::default()
+    };
+    let (_temp_dir, loader) =
+        test_utils::create_isolated_settings_env_with_settings(settings).await;
+
+    let processor = create_post_processor_with_loader(loader).await;
+
+    assert_eq!(processor.process("  hello there "), "Hello there");
+}
+
+#[tokio::test]
+async fn test_post_processor_falls_back_on_invalid_rule() {
+    let settings = AppSettings {
+        post_processing: vec![TextTransform::RegexReplace {
+            pattern: "(unclosed".to_string(),
+            replacement: String::new(),
+        }],
+        ..AppSettings::default()
+    };
+    let (_temp_dir, loader) =
+        test_utils::create_isolated_settings_env_with_settings(settings).await;
+
+    let processor = create_post_processor_with_loader(loader).await;
+
+    // Default chain only trims whitespace
+    assert_eq!(processor.process("  (unclosed "), "(unclosed");
+}
+
+#[tokio::test]
+async fn test_post_processor_interprets_enabled_voice_commands() {
+    let settings = AppSettings {
+        post_processing: vec![
+            TextTransform::TrimWhitespace,
+            TextTransform::CapitaliseSentences,
+        ],
+        voice_commands: VoiceCommandSettings {
+            enabled: true,
+            custom: vec![],
+        },
+        ..AppSettings::default()
+    };
+    let (_temp_dir, loader) =
+        test_utils::create_isolated_settings_env_with_settings(settings).await;
+
+    let processor = create_post_processor_with_loader(loader).await;
+
+    assert_eq!(
+        processor.process("hello comma world period how are you question mark"),
+        "Hello, world. How are you?"
+    );
+}
+
+#[tokio::test]
+async fn test_post_processor_applies_target_app_profile() {
+    let settings = AppSettings {
+        post_processing: vec![
+            TextTransform::TrimWhitespace,
+            TextTransform::CapitaliseSentences,
+        ],
+        app_profiles: vec![AppProfile {
+            punctuation: false,
+            capitalisation: false,
+            ..AppProfile::new("com.apple.Terminal")
+        }],
+        ..AppSettings::default()
+    };
+    let (_temp_dir, loader) =
+        test_utils::create_isolated_settings_env_with_settings(settings).await;
+
+    let terminal =
+        create_post_processor_for_app_with_loader(loader.clone(), Some("com.apple.Terminal")).await;
+    let other = create_post_processor_for_app_with_loader(loader, Some("com.apple.TextEdit")).await;
+
+    assert_eq!(terminal.process(" git status. "), "git status");
+    assert_eq!(other.process(" git status. "), "Git status.");
+}
+
+#[tokio::test]
+async fn test_delivery_follows_output_mode_and_app_profile() {
+    let chat = InjectionOptions {
+        method: InjectionMethod::Clipboard,
+        typing_delay_ms: 0,
+        trailing: TrailingText::Newline,
+    };
+    let settings = AppSettings {
+        app_profiles: vec![AppProfile {
+            injection: chat,
+            ..AppProfile::new("com.tinyspeck.slackmacgap")
+        }],
+        output_mode: OutputMode::Both,
+        clipboard_notification: true,
+        streaming_injection: true,
+        ..AppSettings::default()
+    };
+    let (_temp_dir, loader) =
+        test_utils::create_isolated_settings_env_with_settings(settings).await;
+
+    let slack =
+        create_delivery_for_app_with_loader(loader.clone(), Some("com.tinyspeck.slackmacgap"))
+            .await;
+    let unknown = create_delivery_for_app_with_loader(loader, None).await;
+
+    assert_eq!(
+        slack,
+        Delivery {
+            output_mode: OutputMode::Both,
+            injection: chat,
+            notify_on_copy: true,
+            newlines: NewlineOptions::default(),
+            stream_words: true,
+            dry_run: false,
+            injection_blocked: false,
+            session_window: None,
+        }
+    );
+    assert_eq!(unknown.injection, InjectionOptions::default());
+    assert_eq!(unknown.output_mode, OutputMode::Both);
+}
+
+#[tokio::test]
+async fn test_delivery_copies_for_blocklisted_apps() {
+    let settings = AppSettings {
+        injection_blocklist: vec!["com.bitwarden.desktop".to_string()],
+        streaming_injection: true,
+        ..AppSettings::default()
+    };
+    let (_temp_dir, loader) =
+        test_utils::create_isolated_settings_env_with_settings(settings).await;
+
+    let blocked =
+        create_delivery_for_app_with_loader(loader.clone(), Some("com.bitwarden.desktop")).await;
+    let allowed = create_delivery_for_app_with_loader(loader, Some("com.apple.TextEdit")).await;
+
+    assert!(blocked.injection_blocked);
+    assert!(blocked.copies() && !blocked.injects());
+    assert!(!blocked.stream_words);
+    assert!(!allowed.injection_blocked);
+    assert_eq!(allowed.injects(), allowed.output_mode.injects());
+}
+
+#[tokio::test]
+async fn test_sessions_turn_off_word_streaming() {
+    let settings = AppSettings {
+        session_window_secs: Some(20),
+        streaming_injection: true,
+        ..AppSettings::default()
+    };
+    let (_temp_dir, loader) =
+        test_utils::create_isolated_settings_env_with_settings(settings).await;
+
+    let delivery = create_delivery_for_app_with_loader(loader, None).await;
+
+    assert_eq!(delivery.session_window, Some(Duration::from_secs(20)));
+    assert!(!delivery.stream_words);
+}
+
+#[tokio::test]
+async fn test_long_form_extends_the_recording_limit() {
+    let settings = AppSettings {
+        audio_duration: AudioDuration::new(15).unwrap(),
+        long_form_mins: Some(5),
+        streaming_injection: true,
+        ..AppSettings::default()
+    };
+    let (_temp_dir, loader) =
+        test_utils::create_isolated_settings_env_with_settings(settings).await;
+
+    let config = create_recording_config_with_loader(loader.clone()).await;
+
+    assert_eq!(config.time_limit(), Duration::from_secs(5 * 60));
+    assert!(create_long_form_with_loader(loader.clone()).await);
+    // Chunks are transcribed instead of streaming words
+    assert!(
+        !create_delivery_for_app_with_loader(loader, None)
+            .await
+            .stream_words
+    );
+}
+
+#[tokio::test]
+async fn test_recording_config_carries_input_gains() {
+    let input_gains = vec![InputGain {
+        device: "USB Microphone".to_string(),
+        gain: 2.5,
+    }];
+    let settings = AppSettings {
+        input_gains: input_gains.clone(),
+        ..AppSettings::default()
+    };
+    let (_temp_dir, loader) =
+        test_utils::create_isolated_settings_env_with_settings(settings).await;
+
+    let config = create_recording_config_with_loader(loader).await;
+
+    assert_eq!(config.input_gains(), input_gains.as_slice());
+}
+
+#[tokio::test]
+async fn test_auto_gain_follows_settings() {
+    let settings = AppSettings {
+        auto_gain: Some(AutoGain { target_dbfs: -18.0 }),
+        ..AppSettings::default()
+    };
+    let (_temp_dir, loader) =
+        test_utils::create_isolated_settings_env_with_settings(settings).await;
+    let (_default_dir, default_loader) =
+        test_utils::create_isolated_settings_env_with_settings(AppSettings::default()).await;
+
+    assert_eq!(
+        create_auto_gain_with_loader(loader).await,
+        Some(AutoGain { target_dbfs: -18.0 })
+    );
+    assert_eq!(create_auto_gain_with_loader(default_loader).await, None);
+}
+
+#[tokio::test]
+async fn test_recording_config_carries_device_preferences() {
+    let settings = AppSettings {
+        input_devices: vec!["USB Headset".to_string(), "Desk Mic".to_string()],
+        ..AppSettings::default()
+    };
+    let (_temp_dir, loader) =
+        test_utils::create_isolated_settings_env_with_settings(settings).await;
+
+    let config = create_recording_config_with_loader(loader).await;
+
+    assert_eq!(config.preferred_devices(), ["USB Headset", "Desk Mic"]);
+}
+
+#[test]
+fn test_condition_samples_wipes_the_callers_recording() {
+    let speech: Vec<i16> = (0..16_000).map(|i| (i % 200 - 100) as i16 * 20).collect();
+
+    let mut recording = speech.clone();
+    let conditioned = condition_samples(None, None, &mut recording);
+    assert_eq!(conditioned, speech);
+    assert_eq!(recording.len(), speech.len());
+    assert!(recording.iter().all(|&sample| sample == 0));
+
+    let mut recording = speech.clone();
+    let conditioned = condition_samples(None, Some(AutoGain::default()), &mut recording);
+    assert_eq!(conditioned.len(), speech.len());
+    assert!(conditioned.iter().any(|&sample| sample != 0));
+    assert!(recording.iter().all(|&sample| sample == 0));
 }
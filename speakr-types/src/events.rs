@@ -0,0 +1,137 @@
+// ============================================================================
+//! Tauri event names and payloads shared by the backend and the UI.
+//!
+//! Every event the backend emits is named here, together with the type of
+//! its payload, so the emitter in `speakr-tauri` and the listener in
+//! `speakr-ui` cannot drift apart. Events whose payload is a larger shared
+//! type (e.g. [`PipelineEvent`](crate::PipelineEvent)) keep their name
+//! constant next to that type and are re-exported from this module.
+//!
+//! ```no_run
+//! use speakr_types::events::{WorkflowError, WORKFLOW_ERROR_EVENT};
+//!
+//! let payload = WorkflowError::new("Audio capture failed: no input");
+//! assert_eq!(WORKFLOW_ERROR_EVENT, "workflow-error");
+//! assert!(payload.message.starts_with("Audio"));
+//! ```
+// ============================================================================
+
+use serde::{Deserialize, Serialize};
+
+pub use crate::{
+    BATCH_PROGRESS_EVENT, CONNECTIVITY_CHANGED_EVENT, CORRUPT_MODEL_EVENT, CRASH_REPORTED_EVENT,
+    DICTATION_LATENCY_EVENT, HISTORY_CHANGED_EVENT, MODEL_DOWNLOAD_PROGRESS_EVENT,
+    PENDING_CHANGED_EVENT, PERMISSION_GRANTED_EVENT, PIPELINE_EVENT, PRIVACY_MODE_CHANGED_EVENT,
+    SERVICE_GAVE_UP_EVENT, SERVICE_RECOVERED_EVENT, SETTINGS_RECOVERED_EVENT, STATUS_CHANGED_EVENT,
+    TRANSCRIPTION_RESULT_EVENT,
+};
+
+// =========================
+// Hot-keys & Settings
+// =========================
+
+/// A dictation hot-key was pressed.
+///
+/// Payload: `Option<DictationOverrides>`; `None` for the main hot-key,
+/// the settings to use for language hot-keys.
+pub const HOTKEY_TRIGGERED_EVENT: &str = "hotkey-triggered";
+
+/// Settings were saved or a profile was switched.
+///
+/// Payload: the saved [`AppSettings`](crate::AppSettings).
+pub const SETTINGS_CHANGED_EVENT: &str = "settings-changed";
+
+// =========================
+// Dictation Workflow
+// =========================
+
+/// A dictation started. Payload: `()`.
+pub const WORKFLOW_STARTED_EVENT: &str = "workflow-started";
+
+/// A dictation finished. Payload: the delivered text as a `String`.
+pub const WORKFLOW_COMPLETED_EVENT: &str = "workflow-completed";
+
+/// A dictation failed. Payload: [`WorkflowError`].
+pub const WORKFLOW_ERROR_EVENT: &str = "workflow-error";
+
+/// The dictation in flight was cancelled. Payload: `()`.
+pub const DICTATION_CANCELLED_EVENT: &str = "dictation-cancelled";
+
+/// Recording started. Payload: `()`.
+pub const AUDIO_CAPTURE_STARTED_EVENT: &str = "audio-capture-started";
+
+/// Recording finished. Payload: [`AudioCaptureCompleted`].
+pub const AUDIO_CAPTURE_COMPLETED_EVENT: &str = "audio-capture-completed";
+
+/// Transcription started. Payload: `()`.
+pub const TRANSCRIPTION_STARTED_EVENT: &str = "transcription-started";
+
+/// Transcription progress as a percentage (`u8`). Acknowledged: the UI
+/// confirms each update with `ack_event` before the next one is sent.
+pub const TRANSCRIPTION_PROGRESS_EVENT: &str = "transcription-progress";
+
+/// Transcription finished. Payload: the raw transcript as a `String`.
+pub const TRANSCRIPTION_COMPLETED_EVENT: &str = "transcription-completed";
+
+/// The language was detected. Payload: the language code as a `String`.
+pub const TRANSCRIPTION_LANGUAGE_EVENT: &str = "transcription-language";
+
+/// Transcription failed. Payload: the error message as a `String`.
+pub const TRANSCRIPTION_ERROR_EVENT: &str = "transcription-error";
+
+/// The text was copied to the clipboard. Payload: the text as a `String`.
+pub const TEXT_COPIED_EVENT: &str = "text-copied";
+
+/// Text injection started. Payload: the text as a `String`.
+pub const TEXT_INJECTION_STARTED_EVENT: &str = "text-injection-started";
+
+/// Text injection finished. Payload: the text as a `String`.
+pub const TEXT_INJECTION_COMPLETED_EVENT: &str = "text-injection-completed";
+
+/// Injected text was revised in place. Payload: the new text as a `String`.
+pub const TEXT_INJECTION_REVISED_EVENT: &str = "text-injection-revised";
+
+/// Injection was skipped because the dictation was sandboxed. Payload: the
+/// text that would have been typed as a `String`.
+pub const TEXT_INJECTION_SANDBOXED_EVENT: &str = "text-injection-sandboxed";
+
+// =========================
+// Backend Status
+// =========================
+
+/// The backend status changed. Payload: [`BackendStatus`](crate::BackendStatus).
+pub const SPEAKR_STATUS_CHANGED_EVENT: &str = "speakr-status-changed";
+
+/// Periodic heartbeat. Payload: [`BackendStatus`](crate::BackendStatus).
+pub const HEARTBEAT_EVENT: &str = "speakr-heartbeat";
+
+// =========================
+// Payloads
+// =========================
+
+/// Payload of [`WORKFLOW_ERROR_EVENT`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct WorkflowError {
+    /// What failed, ready to show to the user.
+    pub message: String,
+}
+
+impl WorkflowError {
+    /// Creates the payload from a message.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+/// Payload of [`AUDIO_CAPTURE_COMPLETED_EVENT`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct AudioCaptureCompleted {
+    /// Number of 16 kHz samples captured.
+    pub sample_count: usize,
+}
@@ -18,12 +18,22 @@
 //! let settings = AppSettings::default();
 //! let status = BackendStatus::new_ready();
 //! ```
+//!
+//! # TypeScript Bindings
+//!
+//! With the `ts-bindings` feature every shared type also derives
+//! `ts_rs::TS`, and `cargo test --features ts-bindings` writes its
+//! TypeScript definition to `bindings/`. 64-bit integers are declared as
+//! `number`, matching how they arrive over IPC.
 // ============================================================================
 
+pub mod events;
+
 // =========================
 // External Imports
 // =========================
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
 use std::time::Duration;
 use thiserror::Error;
 
@@ -35,7 +45,8 @@ use thiserror::Error;
 ///
 /// Used to handle backwards compatibility when settings format changes.
 /// Version 2: Added audio_duration_secs field with validation
-pub const DEFAULT_SCHEMA_VERSION: u32 = 2;
+/// Version 3: Replaced model_size with a catalogue model
+pub const DEFAULT_SCHEMA_VERSION: u32 = 3;
 
 /// Default global hotkey combination for dictation activation.
 ///
@@ -43,16 +54,173 @@ pub const DEFAULT_SCHEMA_VERSION: u32 = 2;
 /// Backtick (`) conflicts with system shortcuts on macOS.
 pub const DEFAULT_HOTKEY: &str = "CmdOrCtrl+Alt+F1";
 
-/// Default Whisper model size for transcription.
+/// Default Whisper model for transcription, as a [`ModelId`].
 ///
 /// Medium provides balanced accuracy and performance for most use cases.
-pub const DEFAULT_MODEL_SIZE: &str = "medium";
+pub const DEFAULT_MODEL: &str = "medium";
 
 /// Default auto-launch setting for system startup.
 ///
 /// Disabled by default to respect user privacy preferences.
 pub const DEFAULT_AUTO_LAUNCH: bool = false;
 
+/// Default Dock badge setting.
+///
+/// Disabled by default; the badge is an optional second recording indicator.
+pub const DEFAULT_DOCK_BADGE: bool = false;
+
+/// Default pre-record setting.
+///
+/// Disabled by default, since it keeps the microphone open while idle.
+pub const DEFAULT_PRE_BUFFER: bool = false;
+
+/// Default local API setting.
+///
+/// Disabled by default, since any local process could then trigger dictation.
+pub const DEFAULT_LOCAL_API: bool = false;
+
+/// Default idle time, in minutes, after which the resident Whisper model is
+/// unloaded to free memory.
+pub const DEFAULT_MODEL_IDLE_UNLOAD_MINS: u32 = 30;
+
+/// Default time, in minutes, after which privacy mode switches itself off.
+pub const DEFAULT_PRIVACY_MODE_MINS: u32 = 60;
+
+/// Session window, in seconds, used when append-mode sessions are switched on.
+pub const DEFAULT_SESSION_WINDOW_SECS: u32 = 20;
+
+/// Longest gap, in seconds, after which a dictation can still continue the
+/// previous one's session.
+pub const MAX_SESSION_WINDOW_SECS: u32 = 600;
+
+/// Applications Speakr does not type into by default: password managers
+/// and the macOS keychain.
+pub const DEFAULT_INJECTION_BLOCKLIST: &[&str] = &[
+    "com.1password.1password",
+    "com.agilebits.onepassword7",
+    "com.bitwarden.desktop",
+    "org.keepassxc.keepassxc",
+    "com.apple.keychainaccess",
+];
+
+/// Default for showing a notification with text copied to the clipboard.
+///
+/// Disabled by default, since the notification puts dictated text on screen.
+pub const DEFAULT_CLIPBOARD_NOTIFICATION: bool = false;
+
+/// Default for system notifications when a dictation finishes while Speakr is
+/// in the background, or fails.
+pub const DEFAULT_NOTIFICATIONS: bool = true;
+
+/// Default for including the dictated text in the "finished" notification.
+///
+/// Disabled by default, since the notification puts dictated text on screen.
+pub const DEFAULT_NOTIFICATION_TEXT: bool = false;
+
+/// Default for typing words while the user is still speaking.
+///
+/// Disabled by default: words may be corrected with backspace as the
+/// transcript is revised, which not every application handles well.
+pub const DEFAULT_STREAMING_INJECTION: bool = false;
+
+/// Default for suggesting vocabulary terms from corrections in the history.
+pub const DEFAULT_VOCABULARY_SUGGESTIONS: bool = true;
+
+/// Default file name template for saved dictation recordings.
+///
+/// Templates may use these placeholders; the `.wav` extension is added:
+/// - `{date}`: Recording date, `YYYY-MM-DD`
+/// - `{time}`: Recording time (UTC), `HH-MM-SS`
+/// - `{model}`: Model that transcribed the audio, e.g. `small`
+/// - `{app}`: Application the text was dictated into, or `unknown`
+/// - `{duration}`: Length of the recording in whole seconds
+pub const DEFAULT_RECORDING_FILENAME_TEMPLATE: &str = "recording_{date}_{time}";
+
+/// File name of the RNNoise weights in the model directory.
+///
+/// Downloaded through `download_model` like the Whisper models when the
+/// `denoise` setting is first enabled.
+pub const DENOISE_MODEL_FILENAME: &str = "sh.rnn";
+
+/// Loopback port the local API listens on when enabled.
+pub const DEFAULT_API_PORT: u16 = 47_615;
+
+/// Version of the post-processing plugin interface implemented by the host.
+///
+/// Plugins export `speakr_abi_version` returning this value.
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+/// Default WebAssembly fuel (roughly, instructions) a plugin may use per call.
+pub const DEFAULT_PLUGIN_MAX_FUEL: u64 = 100_000_000;
+
+/// Default linear memory a plugin may allocate, in bytes.
+pub const DEFAULT_PLUGIN_MAX_MEMORY_BYTES: u64 = 16 * 1024 * 1024;
+
+/// Seconds of audio kept in memory while pre-recording is enabled.
+pub const PRE_BUFFER_SECS: u32 = 2;
+
+/// Default minimum transcription confidence for a spoken stop phrase.
+///
+/// Matches below this score are ignored so that similar-sounding speech does
+/// not end a dictation early.
+pub const DEFAULT_STOP_PHRASE_CONFIDENCE: f32 = 0.6;
+
+/// Default loudness, in dBFS, that automatic gain control brings speech to.
+pub const DEFAULT_AGC_TARGET_DBFS: f32 = -20.0;
+
+/// Quietest automatic gain control target, in dBFS.
+pub const MIN_AGC_TARGET_DBFS: f32 = -40.0;
+
+/// Loudest automatic gain control target, in dBFS.
+///
+/// Louder targets leave too little headroom and clip on plosives.
+pub const MAX_AGC_TARGET_DBFS: f32 = -6.0;
+
+/// Smallest software input gain, as a multiplier.
+pub const MIN_INPUT_GAIN: f32 = 0.25;
+
+/// Largest software input gain, as a multiplier (about +12 dB).
+pub const MAX_INPUT_GAIN: f32 = 4.0;
+
+/// Level, in dBFS, reported for silence.
+///
+/// Quieter input is clamped to this floor so meters have a finite range.
+pub const SILENCE_DBFS: f32 = -60.0;
+
+/// Length of the recording made by the microphone test, in seconds.
+pub const MIC_TEST_SECS: u32 = 2;
+
+/// Quietest RMS level, in dBFS, at which the microphone test hears speech.
+pub const MIC_TEST_MIN_RMS_DBFS: f32 = -45.0;
+
+/// Peak level, in dBFS, at or above which input counts as clipping.
+pub const CLIPPING_DBFS: f32 = -0.1;
+
+/// Default transcription language setting.
+///
+/// `"auto"` lets Whisper detect the spoken language for each recording.
+pub const DEFAULT_LANGUAGE: &str = "auto";
+
+/// Default filler words removed by [`TextTransform::StripFillerWords`].
+///
+/// Only unambiguous hesitation sounds are included so that real words are
+/// never stripped from dictated text.
+pub const DEFAULT_FILLER_WORDS: &[&str] = &["um", "umm", "uh", "uhh", "erm", "er", "hmm", "mm"];
+
+/// Maximum length, in characters, of the vocabulary prompt passed to Whisper.
+///
+/// Whisper only attends to the last 224 prompt tokens; roughly 800
+/// characters keeps typical English vocabulary within that window.
+pub const MAX_VOCABULARY_PROMPT_CHARS: usize = 800;
+
+/// Default delay between synthetic keystrokes when typing dictated text.
+pub const DEFAULT_TYPING_DELAY_MS: u32 = 3;
+
+/// Maximum delay between synthetic keystrokes.
+///
+/// Higher values make even short dictations take seconds to type.
+pub const MAX_TYPING_DELAY_MS: u32 = 100;
+
 /// Minimum allowed audio recording duration in seconds.
 ///
 /// Set to 1 second to ensure meaningful audio capture while preventing
@@ -62,9 +230,17 @@ pub const MIN_AUDIO_DURATION_SECS: u32 = 1;
 /// Maximum allowed audio recording duration in seconds.
 ///
 /// Set to 30 seconds to balance memory usage and practical dictation needs.
-/// Longer recordings may consume excessive memory and processing time.
+/// Longer recordings may consume excessive memory and processing time;
+/// long-form dictation (see [`MAX_LONG_FORM_MINS`]) goes beyond it by
+/// transcribing in chunks while recording.
 pub const MAX_AUDIO_DURATION_SECS: u32 = 30;
 
+/// Longest long-form dictation, in minutes.
+pub const MAX_LONG_FORM_MINS: u32 = 10;
+
+/// Long-form limit, in minutes, used when long-form dictation is switched on.
+pub const DEFAULT_LONG_FORM_MINS: u32 = 5;
+
 /// Default audio recording duration in seconds.
 ///
 /// Set to 10 seconds to match current behaviour while providing
@@ -91,9 +267,17 @@ pub const MAX_SETTINGS_FILE_SIZE: usize = 64 * 1024;
 ///
 /// - `Settings`: Configuration and persistence errors
 /// - `FileSystem`: File I/O and permission errors
+/// - `Io`: File I/O errors with the underlying [`ErrorSource`]
 /// - `HotKey`: Global hotkey registration and validation errors
 /// - `Command`: General Tauri command execution errors
 ///
+/// # Wire Format
+///
+/// Errors serialize externally tagged by variant name, which the frontend
+/// can branch on: `{"Settings":"..."}` for message-only variants and
+/// `{"Io":{"message":"...","details":{...}}}` for structured ones. Variant
+/// and field names are part of the IPC contract and must not be renamed.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -103,6 +287,7 @@ pub const MAX_SETTINGS_FILE_SIZE: usize = 64 * 1024;
 /// assert_eq!(error.to_string(), "Settings error: Invalid hotkey format");
 /// ```
 #[derive(Error, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
 pub enum AppError {
     /// Settings-related errors including validation and persistence failures.
     #[error("Settings error: {0}")]
@@ -112,6 +297,16 @@ pub enum AppError {
     #[error("File system error: {0}")]
     FileSystem(String),
 
+    /// A failed I/O operation, keeping the details of the underlying error.
+    #[error("File system error: {message}")]
+    Io {
+        /// What was being attempted, e.g. "Failed to write settings file"
+        message: String,
+        /// The underlying I/O error
+        #[source]
+        details: ErrorSource,
+    },
+
     /// Hot-key registration or validation failures.
     #[error("Hot-key error: {0}")]
     HotKey(String),
@@ -141,6 +336,173 @@ pub enum AppError {
     TextInjection(String),
 }
 
+impl AppError {
+    /// Returns a stable code identifying the kind of error.
+    ///
+    /// Unlike the message, the code never contains user data, so it can be
+    /// quoted in bug reports and searched for.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use speakr_types::AppError;
+    ///
+    /// let error = AppError::AudioCapture("No microphone".to_string());
+    /// assert_eq!(error.code(), "E_AUDIO_CAPTURE");
+    /// ```
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::Settings(_) => "E_SETTINGS",
+            AppError::FileSystem(_) => "E_FILE_SYSTEM",
+            AppError::Io { details, .. } if details.kind == "NotFound" => "E_IO_NOT_FOUND",
+            AppError::Io { details, .. } if details.kind == "PermissionDenied" => {
+                "E_IO_PERMISSION_DENIED"
+            }
+            AppError::Io { .. } => "E_IO",
+            AppError::HotKey(_) => "E_HOT_KEY",
+            AppError::HotKeyConflict(_) => "E_HOT_KEY_CONFLICT",
+            AppError::HotKeyNotFound(_) => "E_HOT_KEY_NOT_FOUND",
+            AppError::Command(_) => "E_COMMAND",
+            AppError::AudioCapture(_) => "E_AUDIO_CAPTURE",
+            AppError::Transcription(_) => "E_TRANSCRIPTION",
+            AppError::TextInjection(_) => "E_TEXT_INJECTION",
+        }
+    }
+
+    /// Wraps an I/O error, keeping its kind, OS error number and path.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - What was being attempted
+    /// * `error` - The underlying I/O error
+    /// * `path` - The file the operation was on, if any
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use speakr_types::AppError;
+    /// use std::path::Path;
+    ///
+    /// let path = Path::new("/missing/settings.json");
+    /// let error = std::fs::read(path)
+    ///     .map_err(|e| AppError::io("Failed to read settings", &e, Some(path)))
+    ///     .unwrap_err();
+    /// assert_eq!(error.code(), "E_IO_NOT_FOUND");
+    /// ```
+    pub fn io(
+        message: impl Into<String>,
+        error: &std::io::Error,
+        path: Option<&std::path::Path>,
+    ) -> Self {
+        AppError::Io {
+            message: message.into(),
+            details: ErrorSource {
+                kind: format!("{:?}", error.kind()),
+                os_error: error.raw_os_error(),
+                path: path.map(|path| path.display().to_string()),
+                description: error.to_string(),
+            },
+        }
+    }
+
+    /// Details of the underlying error, for structured variants.
+    pub fn details(&self) -> Option<&ErrorSource> {
+        match self {
+            AppError::Io { details, .. } => Some(details),
+            _ => None,
+        }
+    }
+
+    /// Whether the user can get past the error themselves.
+    ///
+    /// Recoverable errors go away by trying again, choosing another hot-key,
+    /// connecting a microphone or granting a permission. The rest point at a
+    /// bug or a damaged installation.
+    pub fn is_recoverable(&self) -> bool {
+        match self {
+            AppError::FileSystem(_) | AppError::Command(_) => false,
+            AppError::Io { details, .. } => {
+                details.kind == "NotFound" || details.kind == "PermissionDenied"
+            }
+            _ => true,
+        }
+    }
+}
+
+// --------------------------------------------------------------------------
+/// The error every Tauri command rejects with.
+///
+/// Commands convert their [`AppError`] into this envelope so the frontend
+/// sees one shape, whichever command failed. The UI switches on `code` (see
+/// [`AppError::code`]) to decide what to tell the user, shows `message` as
+/// the details and only offers to retry when `recoverable` is set.
+///
+/// Commands that cannot fail return it too, so every command has the same
+/// contract; their docs have no `# Errors` section.
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::{AppError, CommandError};
+///
+/// let error = CommandError::from(AppError::HotKeyConflict("Cmd+Space".to_string()));
+/// assert_eq!(error.code, "E_HOT_KEY_CONFLICT");
+/// assert!(error.recoverable);
+/// ```
+#[derive(Error, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[error("{message}")]
+pub struct CommandError {
+    /// Stable error code, e.g. `"E_SETTINGS"`.
+    pub code: String,
+    /// What went wrong, ready to show to the user.
+    pub message: String,
+    /// Whether trying again or changing the input may succeed.
+    pub recoverable: bool,
+}
+
+impl CommandError {
+    /// Creates an envelope for an error that did not come from an [`AppError`].
+    pub fn new(code: impl Into<String>, message: impl Into<String>, recoverable: bool) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+            recoverable,
+        }
+    }
+}
+
+impl From<AppError> for CommandError {
+    fn from(error: AppError) -> Self {
+        Self::new(error.code(), error.to_string(), error.is_recoverable())
+    }
+}
+
+// --------------------------------------------------------------------------
+/// The underlying cause of an [`AppError`], kept in serializable form.
+///
+/// # Fields
+///
+/// - `kind`: The `std::io::ErrorKind` name, e.g. `"NotFound"`
+/// - `os_error`: The operating system error number, if there was one
+/// - `path`: The file involved, if known
+/// - `description`: The underlying error's message
+#[derive(Error, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[error("{description}")]
+pub struct ErrorSource {
+    /// The `std::io::ErrorKind` name.
+    pub kind: String,
+    /// The operating system error number.
+    #[serde(default)]
+    pub os_error: Option<i32>,
+    /// The file involved.
+    #[serde(default)]
+    pub path: Option<String>,
+    /// The underlying error's message.
+    pub description: String,
+}
+
 // --------------------------------------------------------------------------
 /// Specific error type for global hotkey operations.
 ///
@@ -161,6 +523,7 @@ pub enum AppError {
 /// let error = HotkeyError::ConflictDetected("Cmd+Space already in use".to_string());
 /// ```
 #[derive(Error, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
 pub enum HotkeyError {
     /// Failed to register the hotkey with the system.
     #[error("Failed to register global hot-key: {0}")]
@@ -201,6 +564,7 @@ pub enum HotkeyError {
 /// };
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
 #[serde(deny_unknown_fields)]
 pub struct HotkeyConfig {
     /// The hotkey combination string in Tauri format.
@@ -218,6 +582,323 @@ impl Default for HotkeyConfig {
     }
 }
 
+// --------------------------------------------------------------------------
+/// A shortcut currently registered with the system.
+///
+/// # Fields
+///
+/// - `shortcut`: Tauri-format hotkey string
+/// - `action`: What pressing it does, e.g. "Dictation"
+/// - `registered_at`: Unix timestamp in milliseconds of the registration
+/// - `last_triggered_at`: Unix timestamp in milliseconds of the last press
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct RegisteredHotkey {
+    /// The hotkey combination string in Tauri format.
+    pub shortcut: String,
+    /// What pressing the shortcut does.
+    pub action: String,
+    /// When the shortcut was registered, in Unix milliseconds.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub registered_at: u64,
+    /// When the shortcut was last pressed, in Unix milliseconds.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number | null"))]
+    pub last_triggered_at: Option<u64>,
+}
+
+// --------------------------------------------------------------------------
+/// What the global hot-keys are doing right now.
+///
+/// Returned by `get_hotkey_status` so users can see exactly which shortcuts
+/// are live.
+///
+/// # Fields
+///
+/// - `hotkeys`: Shortcuts registered with the system
+/// - `fallback`: Explains the fallback in effect when the configured
+///   hot-key could not be registered
+/// - `paused`: Whether dictation is paused, which releases every hot-key
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::HotkeyStatus;
+///
+/// let status = HotkeyStatus::default();
+/// assert!(status.hotkeys.is_empty() && status.fallback.is_none());
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct HotkeyStatus {
+    /// Shortcuts registered with the system.
+    pub hotkeys: Vec<RegisteredHotkey>,
+    /// The fallback in effect, e.g. "CmdOrCtrl+Alt+F2 fallback active".
+    #[serde(default)]
+    pub fallback: Option<String>,
+    /// Whether dictation is paused.
+    #[serde(default)]
+    pub paused: bool,
+}
+
+// --------------------------------------------------------------------------
+/// Settings that replace the user's defaults for a single dictation.
+///
+/// Sent as the payload of the `hotkey-triggered` event by hot-keys that
+/// start a dictation differently from the main one. Fields left as `None`
+/// keep the configured setting.
+///
+/// # Fields
+///
+/// - `language`: Language code to transcribe in, or `"auto"` for detection
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::{DictationOverrides, TranscriptionConfig};
+///
+/// let overrides = DictationOverrides {
+///     language: Some("de".to_string()),
+/// };
+/// let mut config = TranscriptionConfig::default();
+/// overrides.apply(&mut config);
+/// assert_eq!(config.language.as_deref(), Some("de"));
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct DictationOverrides {
+    /// Language code for this dictation (`"auto"` to detect it).
+    #[serde(default)]
+    pub language: Option<String>,
+}
+
+impl DictationOverrides {
+    /// Applies the overrides to a transcription configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The configuration built from the user's settings
+    pub fn apply(&self, config: &mut TranscriptionConfig) {
+        if let Some(language) = &self.language {
+            config.language = (language != DEFAULT_LANGUAGE).then(|| language.clone());
+            config.auto_detect_language = config.language.is_none();
+        }
+    }
+}
+
+// --------------------------------------------------------------------------
+/// Maximum length of a recording.
+///
+/// Always between [`MIN_AUDIO_DURATION_SECS`] and [`MAX_AUDIO_DURATION_SECS`]
+/// seconds, so code holding one never needs to check the range again.
+/// Serialized as a plain number of seconds; out-of-range values are clamped
+/// when deserialized.
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::AudioDuration;
+/// use std::time::Duration;
+///
+/// let duration = AudioDuration::new(15).unwrap();
+/// assert_eq!(duration.as_duration(), Duration::from_secs(15));
+/// assert_eq!(duration.to_string(), "15s");
+///
+/// assert!(AudioDuration::new(0).is_err());
+/// assert_eq!(AudioDuration::clamped(60), AudioDuration::MAX);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(transparent)]
+pub struct AudioDuration(u32);
+
+impl AudioDuration {
+    /// The shortest allowed recording.
+    pub const MIN: Self = Self(MIN_AUDIO_DURATION_SECS);
+
+    /// The longest allowed recording.
+    pub const MAX: Self = Self(MAX_AUDIO_DURATION_SECS);
+
+    /// The recording length used unless the user changes it.
+    pub const DEFAULT: Self = Self(DEFAULT_AUDIO_DURATION_SECS);
+
+    /// Creates a duration of `secs` seconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message if `secs` is outside the allowed range.
+    pub fn new(secs: u32) -> Result<Self, String> {
+        if (MIN_AUDIO_DURATION_SECS..=MAX_AUDIO_DURATION_SECS).contains(&secs) {
+            Ok(Self(secs))
+        } else {
+            Err(format!(
+                "Invalid audio duration: {secs} seconds. Must be between {MIN_AUDIO_DURATION_SECS} and {MAX_AUDIO_DURATION_SECS} seconds."
+            ))
+        }
+    }
+
+    /// Creates a duration of `secs` seconds, clamped to the allowed range.
+    pub fn clamped(secs: u32) -> Self {
+        Self(secs.clamp(MIN_AUDIO_DURATION_SECS, MAX_AUDIO_DURATION_SECS))
+    }
+
+    /// Returns the length in whole seconds.
+    pub const fn as_secs(self) -> u32 {
+        self.0
+    }
+
+    /// Returns the length as a [`Duration`].
+    pub fn as_duration(self) -> Duration {
+        Duration::from_secs(u64::from(self.0))
+    }
+
+    /// Returns how many samples a recording this long holds at `sample_rate_hz`.
+    pub fn sample_count(self, sample_rate_hz: u32) -> usize {
+        self.0 as usize * sample_rate_hz as usize
+    }
+}
+
+impl Default for AudioDuration {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl fmt::Display for AudioDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}s", self.0)
+    }
+}
+
+impl TryFrom<u32> for AudioDuration {
+    type Error = String;
+
+    fn try_from(secs: u32) -> Result<Self, Self::Error> {
+        Self::new(secs)
+    }
+}
+
+impl From<AudioDuration> for Duration {
+    fn from(duration: AudioDuration) -> Self {
+        duration.as_duration()
+    }
+}
+
+impl<'de> Deserialize<'de> for AudioDuration {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u32::deserialize(deserializer).map(Self::clamped)
+    }
+}
+
+// --------------------------------------------------------------------------
+/// Secondary hot-key that starts a dictation in a fixed language.
+///
+/// Bilingual users can keep the main hot-key on their usual language (or
+/// auto-detection) and use one of these for the other.
+///
+/// # Fields
+///
+/// - `shortcut`: Tauri-format hotkey string (e.g., "CmdOrCtrl+Alt+G")
+/// - `language`: Language code to dictate in
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::LanguageHotkey;
+///
+/// let binding = LanguageHotkey {
+///     shortcut: "CmdOrCtrl+Alt+G".to_string(),
+///     language: "de".to_string(),
+/// };
+/// assert_eq!(binding.overrides().language.as_deref(), Some("de"));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct LanguageHotkey {
+    /// The hotkey combination string in Tauri format.
+    pub shortcut: String,
+    /// Language code used for dictations started with this hot-key.
+    pub language: String,
+}
+
+// --------------------------------------------------------------------------
+/// What a developer shortcut does.
+///
+/// # Variants
+///
+/// - `SimulateDictation`: Run the dictation workflow without a microphone
+/// - `ToggleSessionRecording`: Start a session recording, or stop and save
+///   the one running
+/// - `ClearDebugLog`: Empty the debug log console
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+pub enum DevAction {
+    /// Run the dictation workflow without a microphone.
+    SimulateDictation,
+    /// Start a session recording, or stop and save the one running.
+    ToggleSessionRecording,
+    /// Empty the debug log console.
+    ClearDebugLog,
+}
+
+impl DevAction {
+    /// Every action, in the order the debug panel lists them.
+    pub const ALL: [DevAction; 3] = [
+        DevAction::SimulateDictation,
+        DevAction::ToggleSessionRecording,
+        DevAction::ClearDebugLog,
+    ];
+
+    /// Name shown in the hot-key status and the debug panel.
+    pub fn display_name(self) -> &'static str {
+        match self {
+            DevAction::SimulateDictation => "Simulate dictation",
+            DevAction::ToggleSessionRecording => "Start/stop session recording",
+            DevAction::ClearDebugLog => "Clear debug log",
+        }
+    }
+}
+
+// --------------------------------------------------------------------------
+/// Developer shortcut, registered by debug builds only.
+///
+/// Release builds ignore these, so a shortcut kept in the settings while
+/// developing never takes a key combination away from users.
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::{DevAction, DevShortcut};
+///
+/// let shortcut = DevShortcut {
+///     shortcut: "CmdOrCtrl+Alt+Shift+S".to_string(),
+///     action: DevAction::SimulateDictation,
+/// };
+/// assert_eq!(shortcut.action.display_name(), "Simulate dictation");
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct DevShortcut {
+    /// The hotkey combination string in Tauri format.
+    pub shortcut: String,
+    /// What pressing the shortcut does.
+    pub action: DevAction,
+}
+
+impl LanguageHotkey {
+    /// Returns the settings passed to the dictation this hot-key starts.
+    pub fn overrides(&self) -> DictationOverrides {
+        DictationOverrides {
+            language: Some(self.language.clone()),
+        }
+    }
+}
+
 // --------------------------------------------------------------------------
 /// Unified application settings - the single source of truth.
 ///
@@ -233,24 +914,108 @@ impl Default for HotkeyConfig {
 ///
 /// - `version`: Schema version for migration support
 /// - `hot_key`: Global hotkey combination string
-/// - `model_size`: Selected Whisper model size identifier
+/// - `model`: Selected Whisper model from the catalogue
 /// - `auto_launch`: Whether to start with system
-/// - `audio_duration_secs`: Recording duration limit in seconds (1-30)
+/// - `audio_duration`: Recording duration limit (1-30 seconds)
+/// - `long_form_mins`: Minutes a long-form dictation may run, transcribed in
+///   chunks while recording (`None` keeps the `audio_duration` limit)
+/// - `language`: Transcription language code, or `"auto"` for detection
+/// - `ui_language`: Language of the user interface
+/// - `theme`: Light or dark appearance, or following the system
+/// - `reduce_motion`: Turn off animations, as well as when the system asks to
+/// - `post_processing`: Ordered text transforms applied before injection
+/// - `vocabulary`: Names, jargon and acronyms used as Whisper's initial prompt
+/// - `dock_badge`: Whether the Dock icon is badged while recording/transcribing
+/// - `app_profiles`: Per-application overrides for post-processing and injection
+/// - `pre_buffer`: Keep the last few seconds of audio in memory while idle
+/// - `stop_phrase`: Optional spoken phrase that ends the recording
+/// - `voice_commands`: Spoken punctuation and editing commands
+/// - `local_api`: Whether other local tools may drive dictation over the API
+/// - `plugins`: Names of enabled post-processing plugins, in the order they run
+/// - `plugin_limits`: Sandbox limits applied to every plugin call
+/// - `model_idle_unload_mins`: Minutes idle before the loaded model is freed
+///   (`None` keeps it resident)
+/// - `output_mode`: Whether dictated text is injected, copied, or both
+/// - `clipboard_notification`: Show a notification with the copied text
+/// - `notifications`: Notify when a dictation finishes in the background or fails
+/// - `notification_text`: Include the dictated text in the "finished" notification
+/// - `newlines`: How paragraph breaks and the "new paragraph" command are output
+/// - `streaming_injection`: Type words as they are recognised while recording
+/// - `onboarding_completed`: Whether the first-run setup has been finished
+/// - `show_latency_breakdown`: Show stage timings after each dictation
+/// - `language_hotkeys`: Extra hot-keys that dictate in a fixed language
+/// - `denoise`: Suppress background noise before transcription (RNNoise)
+/// - `auto_gain`: Optional normalisation of quiet input to a target loudness
+/// - `input_devices`: Preferred microphones by name, highest priority first
+/// - `input_gains`: Software gain applied to individual microphones
+/// - `dry_run`: Hold every dictation for review instead of delivering it
+/// - `vocabulary_suggestions`: Suggest vocabulary terms the user often corrects
+/// - `save_recordings`: Keep the audio of each dictation as a tagged WAV file
+/// - `recording_filename_template`: File name template for saved recordings
+/// - `privacy_hotkey`: Hot-key that switches privacy mode on and off
+/// - `privacy_mode_mins`: Minutes after which privacy mode switches itself off
+/// - `encrypt_history`: Encrypt the dictation history on disk
+/// - `injection_blocklist`: Applications never typed into; dictations are copied instead
+/// - `reinject_hotkey`: Hot-key that types the last dictation again
+/// - `session_window_secs`: Seconds within which a new dictation continues the
+///   previous one as a single block (`None` delivers each on its own)
+/// - `dev_shortcuts`: Developer shortcuts, registered by debug builds only
 ///
 /// # Examples
 ///
 /// ```no_run
-/// use speakr_types::AppSettings;
+/// use speakr_types::{AppSettings, AudioDuration, ModelId, OutputMode, Theme, UiLanguage};
 ///
 /// let settings = AppSettings {
 ///     version: 1,
 ///     hot_key: "CmdOrCtrl+Alt+F1".to_string(),
-///     model_size: "medium".to_string(),
+///     model: ModelId::new("medium"),
 ///     auto_launch: false,
-///     audio_duration_secs: 10,
+///     audio_duration: AudioDuration::DEFAULT,
+///     long_form_mins: None,
+///     language: "auto".to_string(),
+///     ui_language: UiLanguage::System,
+///     theme: Theme::System,
+///     reduce_motion: false,
+///     post_processing: vec![],
+///     vocabulary: vec!["Speakr".to_string(), "Tauri".to_string()],
+///     dock_badge: false,
+///     app_profiles: vec![],
+///     pre_buffer: false,
+///     stop_phrase: None,
+///     voice_commands: Default::default(),
+///     local_api: false,
+///     plugins: vec![],
+///     plugin_limits: Default::default(),
+///     model_idle_unload_mins: Some(30),
+///     output_mode: OutputMode::Inject,
+///     clipboard_notification: false,
+///     notifications: true,
+///     notification_text: false,
+///     newlines: Default::default(),
+///     streaming_injection: false,
+///     onboarding_completed: true,
+///     show_latency_breakdown: false,
+///     language_hotkeys: vec![],
+///     denoise: false,
+///     auto_gain: None,
+///     input_devices: vec!["USB Headset".to_string()],
+///     input_gains: vec![],
+///     dry_run: false,
+///     vocabulary_suggestions: true,
+///     save_recordings: false,
+///     recording_filename_template: "{date}_{app}".to_string(),
+///     privacy_hotkey: None,
+///     privacy_mode_mins: 60,
+///     encrypt_history: false,
+///     injection_blocklist: vec!["com.1password.1password".to_string()],
+///     reinject_hotkey: None,
+///     session_window_secs: Some(20),
+///     dev_shortcuts: vec![],
 /// };
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
 #[serde(deny_unknown_fields)]
 pub struct AppSettings {
     /// Schema version for migration support.
@@ -260,15 +1025,193 @@ pub struct AppSettings {
     /// Global hot-key combination in Tauri format (e.g., "CmdOrCtrl+Alt+F1").
     pub hot_key: String,
 
-    /// Selected model size identifier ("small", "medium", "large").
-    pub model_size: String,
+    /// Selected model from the catalogue. Settings saved before any
+    /// catalogue model could be chosen hold a size in `model_size`.
+    #[serde(alias = "model_size")]
+    pub model: ModelId,
 
     /// Whether to auto-launch the app on system startup.
     pub auto_launch: bool,
 
-    /// Audio recording duration limit in seconds (1-30 seconds).
-    #[serde(default = "default_audio_duration_secs")]
-    pub audio_duration_secs: u32,
+    /// Audio recording duration limit (1-30 seconds).
+    #[serde(default, rename = "audio_duration_secs")]
+    pub audio_duration: AudioDuration,
+
+    /// Minutes a long-form dictation may run, or `None` to stop at
+    /// `audio_duration`. Long-form dictations are transcribed in overlapping
+    /// chunks while they are recorded.
+    #[serde(default)]
+    pub long_form_mins: Option<u32>,
+
+    /// Transcription language code (e.g. "en", "de") or "auto" for detection.
+    #[serde(default = "default_language")]
+    pub language: String,
+
+    /// Language of the user interface; follows the system by default.
+    #[serde(default)]
+    pub ui_language: UiLanguage,
+
+    /// Light or dark appearance; follows the system by default.
+    #[serde(default)]
+    pub theme: Theme,
+
+    /// Whether animations are turned off. They are also turned off when the
+    /// system's reduced-motion preference is on.
+    #[serde(default)]
+    pub reduce_motion: bool,
+
+    /// Ordered chain of transforms applied to transcribed text before injection.
+    #[serde(default = "default_post_processing")]
+    pub post_processing: Vec<TextTransform>,
+
+    /// Domain terms fed to Whisper as the initial prompt to aid recognition.
+    #[serde(default)]
+    pub vocabulary: Vec<String>,
+
+    /// Whether to badge the Dock icon while recording or transcribing.
+    #[serde(default = "default_dock_badge")]
+    pub dock_badge: bool,
+
+    /// Per-application overrides, matched against the dictation target.
+    #[serde(default)]
+    pub app_profiles: Vec<AppProfile>,
+
+    /// Whether to keep a rolling in-memory buffer so the first words of a
+    /// dictation are not lost (see [`PRE_BUFFER_SECS`]).
+    #[serde(default = "default_pre_buffer")]
+    pub pre_buffer: bool,
+
+    /// Spoken phrase that ends the recording early (e.g. "stop dictation").
+    #[serde(default)]
+    pub stop_phrase: Option<StopPhrase>,
+
+    /// Spoken punctuation and editing commands such as "comma".
+    #[serde(default)]
+    pub voice_commands: VoiceCommandSettings,
+
+    /// Whether to serve the local API on [`DEFAULT_API_PORT`] (loopback only).
+    #[serde(default = "default_local_api")]
+    pub local_api: bool,
+
+    /// Enabled post-processing plugins, run in order after the built-in chain.
+    #[serde(default)]
+    pub plugins: Vec<String>,
+
+    /// Sandbox limits applied to every plugin call.
+    #[serde(default)]
+    pub plugin_limits: PluginLimits,
+
+    /// Minutes without a dictation before the loaded Whisper model is
+    /// unloaded, or `None` to keep it loaded while Speakr runs.
+    #[serde(default = "default_model_idle_unload_mins")]
+    pub model_idle_unload_mins: Option<u32>,
+
+    /// Whether dictated text is injected, copied to the clipboard, or both.
+    #[serde(default)]
+    pub output_mode: OutputMode,
+
+    /// Whether copying to the clipboard shows a notification with the text.
+    #[serde(default = "default_clipboard_notification")]
+    pub clipboard_notification: bool,
+
+    /// Whether a system notification is shown when a dictation finishes
+    /// while Speakr is in the background, or when it fails.
+    #[serde(default = "default_notifications")]
+    pub notifications: bool,
+
+    /// Whether the "finished" notification includes the dictated text.
+    #[serde(default = "default_notification_text")]
+    pub notification_text: bool,
+
+    /// How paragraph breaks and the "new paragraph" command are output.
+    #[serde(default)]
+    pub newlines: NewlineOptions,
+
+    /// Whether words are typed as they are recognised while recording.
+    #[serde(default = "default_streaming_injection")]
+    pub streaming_injection: bool,
+
+    /// Whether the first-run setup (permissions and model) has been finished.
+    #[serde(default)]
+    pub onboarding_completed: bool,
+
+    /// Whether a breakdown of stage timings is shown after each dictation.
+    #[serde(default)]
+    pub show_latency_breakdown: bool,
+
+    /// Extra hot-keys that start a dictation in a fixed language.
+    #[serde(default)]
+    pub language_hotkeys: Vec<LanguageHotkey>,
+
+    /// Whether background noise is suppressed before transcription.
+    #[serde(default)]
+    pub denoise: bool,
+
+    /// Automatic gain control applied to captured audio, if enabled.
+    #[serde(default)]
+    pub auto_gain: Option<AutoGain>,
+
+    /// Input device names in order of preference. The first one connected
+    /// when a recording starts is used; the system default if none are.
+    #[serde(default)]
+    pub input_devices: Vec<String>,
+
+    /// Software gain for individual input devices, applied as audio is
+    /// captured. Devices not listed are recorded at unity gain.
+    #[serde(default)]
+    pub input_gains: Vec<InputGain>,
+
+    /// Whether dictations are held in the pending queue for review instead
+    /// of being typed or copied.
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// Whether words the user often corrects in the history are suggested
+    /// as vocabulary terms.
+    #[serde(default = "default_vocabulary_suggestions")]
+    pub vocabulary_suggestions: bool,
+
+    /// Whether the audio of each dictation is saved, with its metadata, to
+    /// the recordings directory.
+    #[serde(default)]
+    pub save_recordings: bool,
+
+    /// File name template for saved recordings (see
+    /// [`DEFAULT_RECORDING_FILENAME_TEMPLATE`] for the placeholders).
+    #[serde(default = "default_recording_filename_template")]
+    pub recording_filename_template: String,
+
+    /// Hot-key that switches privacy mode on and off, if any.
+    #[serde(default)]
+    pub privacy_hotkey: Option<String>,
+
+    /// Minutes after which privacy mode switches itself off.
+    #[serde(default = "default_privacy_mode_mins")]
+    pub privacy_mode_mins: u32,
+
+    /// Whether the dictation history is encrypted on disk, with a key kept
+    /// in the system keychain.
+    #[serde(default)]
+    pub encrypt_history: bool,
+
+    /// Identifiers of applications Speakr never types into, such as password
+    /// managers. Dictations aimed at them are copied to the clipboard instead.
+    #[serde(default = "default_injection_blocklist")]
+    pub injection_blocklist: Vec<String>,
+
+    /// Hot-key that types the most recent dictation again, if any.
+    #[serde(default)]
+    pub reinject_hotkey: Option<String>,
+
+    /// Seconds after a dictation within which the next one continues it as
+    /// one block, joined with the right spacing and capitalisation, or
+    /// `None` to deliver every dictation on its own.
+    #[serde(default)]
+    pub session_window_secs: Option<u32>,
+
+    /// Developer shortcuts; ignored by release builds.
+    #[serde(default)]
+    pub dev_shortcuts: Vec<DevShortcut>,
 }
 
 /// Provides the default schema version for serde deserialization.
@@ -276,47 +1219,132 @@ fn default_schema_version() -> u32 {
     DEFAULT_SCHEMA_VERSION
 }
 
-/// Provides the default audio duration for serde deserialization.
-fn default_audio_duration_secs() -> u32 {
-    DEFAULT_AUDIO_DURATION_SECS
+/// Provides the default transcription language for serde deserialization.
+fn default_language() -> String {
+    DEFAULT_LANGUAGE.to_string()
 }
 
-impl Default for AppSettings {
-    fn default() -> Self {
+/// Provides the default pre-record setting for serde deserialization.
+fn default_pre_buffer() -> bool {
+    DEFAULT_PRE_BUFFER
+}
+
+/// Provides the default local API setting for serde deserialization.
+fn default_local_api() -> bool {
+    DEFAULT_LOCAL_API
+}
+
+/// Provides the default model idle-unload time for serde deserialization.
+fn default_model_idle_unload_mins() -> Option<u32> {
+    Some(DEFAULT_MODEL_IDLE_UNLOAD_MINS)
+}
+
+/// Provides the default clipboard notification setting for serde deserialization.
+fn default_clipboard_notification() -> bool {
+    DEFAULT_CLIPBOARD_NOTIFICATION
+}
+
+/// Provides the default notification setting for serde deserialization.
+fn default_notifications() -> bool {
+    DEFAULT_NOTIFICATIONS
+}
+
+/// Provides the default notification text setting for serde deserialization.
+fn default_notification_text() -> bool {
+    DEFAULT_NOTIFICATION_TEXT
+}
+
+/// Provides the default streaming injection setting for serde deserialization.
+fn default_streaming_injection() -> bool {
+    DEFAULT_STREAMING_INJECTION
+}
+
+/// Provides the default vocabulary suggestion setting for serde deserialization.
+fn default_vocabulary_suggestions() -> bool {
+    DEFAULT_VOCABULARY_SUGGESTIONS
+}
+
+/// Provides the default recording file name template for serde deserialization.
+fn default_recording_filename_template() -> String {
+    DEFAULT_RECORDING_FILENAME_TEMPLATE.to_string()
+}
+
+/// Provides the default privacy mode duration for serde deserialization.
+fn default_privacy_mode_mins() -> u32 {
+    DEFAULT_PRIVACY_MODE_MINS
+}
+
+/// Provides the default injection blocklist for serde deserialization.
+fn default_injection_blocklist() -> Vec<String> {
+    DEFAULT_INJECTION_BLOCKLIST
+        .iter()
+        .map(|app_id| app_id.to_string())
+        .collect()
+}
+
+/// Provides the default Dock badge setting for serde deserialization.
+fn default_dock_badge() -> bool {
+    DEFAULT_DOCK_BADGE
+}
+
+/// Provides the default post-processing chain for serde deserialization.
+fn default_post_processing() -> Vec<TextTransform> {
+    TextTransform::default_chain()
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
         Self {
             version: DEFAULT_SCHEMA_VERSION,
             hot_key: DEFAULT_HOTKEY.to_string(),
-            model_size: DEFAULT_MODEL_SIZE.to_string(),
+            model: ModelId::default(),
             auto_launch: DEFAULT_AUTO_LAUNCH,
-            audio_duration_secs: DEFAULT_AUDIO_DURATION_SECS,
+            audio_duration: AudioDuration::DEFAULT,
+            long_form_mins: None,
+            language: DEFAULT_LANGUAGE.to_string(),
+            ui_language: UiLanguage::default(),
+            theme: Theme::default(),
+            reduce_motion: false,
+            post_processing: TextTransform::default_chain(),
+            vocabulary: Vec::new(),
+            dock_badge: DEFAULT_DOCK_BADGE,
+            app_profiles: Vec::new(),
+            pre_buffer: DEFAULT_PRE_BUFFER,
+            stop_phrase: None,
+            voice_commands: VoiceCommandSettings::default(),
+            local_api: DEFAULT_LOCAL_API,
+            plugins: Vec::new(),
+            plugin_limits: PluginLimits::default(),
+            model_idle_unload_mins: Some(DEFAULT_MODEL_IDLE_UNLOAD_MINS),
+            output_mode: OutputMode::default(),
+            clipboard_notification: DEFAULT_CLIPBOARD_NOTIFICATION,
+            notifications: DEFAULT_NOTIFICATIONS,
+            notification_text: DEFAULT_NOTIFICATION_TEXT,
+            newlines: NewlineOptions::default(),
+            streaming_injection: DEFAULT_STREAMING_INJECTION,
+            onboarding_completed: false,
+            show_latency_breakdown: false,
+            language_hotkeys: Vec::new(),
+            denoise: false,
+            auto_gain: None,
+            input_devices: Vec::new(),
+            input_gains: Vec::new(),
+            dry_run: false,
+            vocabulary_suggestions: DEFAULT_VOCABULARY_SUGGESTIONS,
+            save_recordings: false,
+            recording_filename_template: DEFAULT_RECORDING_FILENAME_TEMPLATE.to_string(),
+            privacy_hotkey: None,
+            privacy_mode_mins: DEFAULT_PRIVACY_MODE_MINS,
+            encrypt_history: false,
+            injection_blocklist: default_injection_blocklist(),
+            reinject_hotkey: None,
+            session_window_secs: None,
+            dev_shortcuts: Vec::new(),
         }
     }
 }
 
 impl AppSettings {
-    /// Validates that the audio duration is within acceptable range.
-    ///
-    /// # Arguments
-    ///
-    /// * `duration_secs` - The duration in seconds to validate
-    ///
-    /// # Returns
-    ///
-    /// `true` if the duration is between 1 and 30 seconds (inclusive), `false` otherwise.
-    ///
-    /// # Examples
-    ///
-    /// ```no_run
-    /// use speakr_types::AppSettings;
-    ///
-    /// assert!(AppSettings::validate_audio_duration(10));
-    /// assert!(!AppSettings::validate_audio_duration(0));
-    /// assert!(!AppSettings::validate_audio_duration(31));
-    /// ```
-    pub fn validate_audio_duration(duration_secs: u32) -> bool {
-        (MIN_AUDIO_DURATION_SECS..=MAX_AUDIO_DURATION_SECS).contains(&duration_secs)
-    }
-
     /// Validates that a file path is safe and doesn't contain path traversal attempts.
     ///
     /// # Arguments
@@ -360,832 +1388,4701 @@ impl AppSettings {
         true
     }
 
-    /// Validates all fields in the AppSettings structure.
+    /// Validates the shape of a transcription language setting.
+    ///
+    /// Only the format is checked here (`"auto"` or a 2-3 letter lowercase
+    /// code); whether Whisper supports the code is checked by the
+    /// transcription engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - The language setting to validate
     ///
     /// # Returns
     ///
-    /// `Ok(())` if all settings are valid, `Err(String)` with error message if invalid.
+    /// `true` if the language setting is well-formed, `false` otherwise.
     ///
     /// # Examples
     ///
     /// ```no_run
     /// use speakr_types::AppSettings;
     ///
-    /// let mut settings = AppSettings::default();
-    /// assert!(settings.validate().is_ok());
-    ///
-    /// settings.audio_duration_secs = 0;
-    /// assert!(settings.validate().is_err());
+    /// assert!(AppSettings::validate_language("auto"));
+    /// assert!(AppSettings::validate_language("en"));
+    /// assert!(!AppSettings::validate_language("English"));
     /// ```
-    pub fn validate(&self) -> Result<(), String> {
-        if !Self::validate_audio_duration(self.audio_duration_secs) {
-            return Err(format!(
-                "Invalid audio duration: {} seconds. Must be between {} and {} seconds.",
-                self.audio_duration_secs, MIN_AUDIO_DURATION_SECS, MAX_AUDIO_DURATION_SECS
-            ));
-        }
-
-        // Add other validation checks here as needed
-        Ok(())
+    pub fn validate_language(language: &str) -> bool {
+        language == DEFAULT_LANGUAGE
+            || ((2..=3).contains(&language.len())
+                && language.chars().all(|c| c.is_ascii_lowercase()))
     }
-}
-
-// ============================================================================
-// Model Configuration and Metadata
-// ============================================================================
-
-// --------------------------------------------------------------------------
-/// Available Whisper model sizes for transcription quality and performance.
-///
-/// Each model size represents a different balance between accuracy, speed,
-/// and resource consumption. Larger models provide better accuracy but
-/// require more memory and processing time.
-///
-/// # Model Characteristics
-///
-/// - `Small`: Fast processing, good for quick notes (39MB)
-/// - `Medium`: Balanced accuracy and speed (769MB)
-/// - `Large`: Highest accuracy, best for professional use (1550MB)
-///
-/// # Examples
-///
-/// ```no_run
-/// use speakr_types::ModelSize;
-///
-/// let size = ModelSize::Medium;
-/// assert_eq!(size.display_name(), "Medium (769MB, balanced)");
-/// assert_eq!(size.to_string_value(), "medium");
-/// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
-#[serde(deny_unknown_fields)]
-pub enum ModelSize {
-    /// Small model: 39MB, optimised for speed.
-    Small,
 
-    /// Medium model: 769MB, balanced performance (default).
-    #[default]
-    Medium,
+    /// Returns the explicitly selected transcription language.
+    ///
+    /// # Returns
+    ///
+    /// `None` when the language is `"auto"` (detect per recording), otherwise
+    /// the selected language code.
+    pub fn transcription_language(&self) -> Option<String> {
+        if self.language == DEFAULT_LANGUAGE {
+            None
+        } else {
+            Some(self.language.clone())
+        }
+    }
 
-    /// Large model: 1550MB, maximum accuracy.
-    Large,
-}
+    /// Returns how long the loaded model may sit idle before it is unloaded.
+    ///
+    /// # Returns
+    ///
+    /// `None` when the model should stay loaded while Speakr runs.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use speakr_types::AppSettings;
+    /// use std::time::Duration;
+    ///
+    /// let settings = AppSettings {
+    ///     model_idle_unload_mins: Some(5),
+    ///     ..AppSettings::default()
+    /// };
+    /// assert_eq!(settings.model_idle_unload(), Some(Duration::from_secs(300)));
+    /// ```
+    pub fn model_idle_unload(&self) -> Option<Duration> {
+        self.model_idle_unload_mins
+            .map(|mins| Duration::from_secs(u64::from(mins) * 60))
+    }
 
-impl ModelSize {
-    /// Returns the user-friendly display name with size and characteristics.
+    /// Returns the software gain for an input device.
     ///
     /// # Returns
     ///
-    /// A static string describing the model's characteristics.
+    /// The multiplier set for `device`, or `1.0` if none is.
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// use speakr_types::ModelSize;
+    /// use speakr_types::{AppSettings, InputGain};
     ///
-    /// assert_eq!(ModelSize::Small.display_name(), "Small (39MB, fast)");
+    /// let settings = AppSettings {
+    ///     input_gains: vec![InputGain {
+    ///         device: "Desk Mic".to_string(),
+    ///         gain: 2.0,
+    ///     }],
+    ///     ..AppSettings::default()
+    /// };
+    /// assert_eq!(settings.input_gain("Desk Mic"), 2.0);
+    /// assert_eq!(settings.input_gain("USB Headset"), 1.0);
     /// ```
-    pub fn display_name(&self) -> &'static str {
-        match self {
-            ModelSize::Small => "Small (39MB, fast)",
-            ModelSize::Medium => "Medium (769MB, balanced)",
-            ModelSize::Large => "Large (1550MB, accurate)",
-        }
+    pub fn input_gain(&self, device: &str) -> f32 {
+        input_gain_for(&self.input_gains, device)
     }
 
-    /// Returns the string identifier used for settings storage.
+    /// Returns how long a recording may run before it stops by itself.
     ///
     /// # Returns
     ///
-    /// A static string suitable for serialisation and storage.
+    /// The long-form limit if long-form dictation is on, otherwise the
+    /// audio duration.
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// use speakr_types::ModelSize;
+    /// use speakr_types::AppSettings;
+    /// use std::time::Duration;
     ///
-    /// assert_eq!(ModelSize::Medium.to_string_value(), "medium");
+    /// let settings = AppSettings {
+    ///     long_form_mins: Some(5),
+    ///     ..AppSettings::default()
+    /// };
+    /// assert_eq!(settings.recording_limit(), Duration::from_secs(300));
     /// ```
-    pub fn to_string_value(&self) -> &'static str {
-        match self {
-            ModelSize::Small => "small",
-            ModelSize::Medium => "medium",
-            ModelSize::Large => "large",
+    pub fn recording_limit(&self) -> Duration {
+        match self.long_form_mins {
+            Some(mins) => Duration::from_secs(u64::from(mins) * 60),
+            None => self.audio_duration.as_duration(),
         }
     }
 
-    /// Creates a ModelSize from a string identifier.
+    /// Returns how long after a dictation the next one continues its session.
     ///
-    /// # Arguments
+    /// # Returns
+    ///
+    /// The session window, or `None` if append-mode sessions are off.
     ///
-    /// * `s` - The string identifier ("small", "medium", "large")
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use speakr_types::AppSettings;
+    /// use std::time::Duration;
+    ///
+    /// let settings = AppSettings {
+    ///     session_window_secs: Some(20),
+    ///     ..AppSettings::default()
+    /// };
+    /// assert_eq!(settings.session_window(), Some(Duration::from_secs(20)));
+    /// ```
+    pub fn session_window(&self) -> Option<Duration> {
+        self.session_window_secs
+            .map(|secs| Duration::from_secs(u64::from(secs)))
+    }
+
+    /// Builds the Whisper initial prompt from the vocabulary.
     ///
     /// # Returns
     ///
-    /// The corresponding ModelSize variant, defaulting to Medium for unknown values.
+    /// The non-empty vocabulary entries joined with `", "`, or `None` if
+    /// there are no entries.
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// use speakr_types::ModelSize;
+    /// use speakr_types::AppSettings;
     ///
-    /// assert_eq!(ModelSize::from_string("small"), ModelSize::Small);
-    /// assert_eq!(ModelSize::from_string("unknown"), ModelSize::Medium);
+    /// let settings = AppSettings {
+    ///     vocabulary: vec!["Kubernetes".to_string(), "gRPC".to_string()],
+    ///     ..AppSettings::default()
+    /// };
+    /// assert_eq!(settings.initial_prompt().as_deref(), Some("Kubernetes, gRPC"));
     /// ```
-    pub fn from_string(s: &str) -> Self {
-        match s {
-            "small" => ModelSize::Small,
-            "medium" => ModelSize::Medium,
-            "large" => ModelSize::Large,
-            _ => ModelSize::Medium, // Default fallback for unknown values
+    pub fn initial_prompt(&self) -> Option<String> {
+        let terms: Vec<&str> = self
+            .vocabulary
+            .iter()
+            .map(|term| term.trim())
+            .filter(|term| !term.is_empty())
+            .collect();
+
+        if terms.is_empty() {
+            None
+        } else {
+            Some(terms.join(", "))
+        }
+    }
+
+    /// Finds the profile for a target application.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_id` - Identifier of the application receiving the text
+    ///
+    /// # Returns
+    ///
+    /// The first profile whose `app_id` matches (ignoring ASCII case), if any.
+    pub fn profile_for(&self, app_id: &str) -> Option<&AppProfile> {
+        self.app_profiles
+            .iter()
+            .find(|profile| profile.app_id.eq_ignore_ascii_case(app_id.trim()))
+    }
+
+    /// Returns the post-processing chain to use for a target application.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_id` - Identifier of the target application, if known
+    ///
+    /// # Returns
+    ///
+    /// The configured chain, adjusted by the matching [`AppProfile`] when
+    /// there is one.
+    pub fn post_processing_for(&self, app_id: Option<&str>) -> Vec<TextTransform> {
+        match app_id.and_then(|id| self.profile_for(id)) {
+            Some(profile) => profile.apply(&self.post_processing),
+            None => self.post_processing.clone(),
         }
     }
 
-    /// Returns all available model sizes for UI enumeration.
+    /// Returns how to inject text into a target application.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_id` - Identifier of the target application, if known
+    ///
+    /// # Returns
+    ///
+    /// The matching [`AppProfile`]'s injection options, or the defaults.
+    pub fn injection_for(&self, app_id: Option<&str>) -> InjectionOptions {
+        app_id
+            .and_then(|id| self.profile_for(id))
+            .map(|profile| profile.injection)
+            .unwrap_or_default()
+    }
+
+    /// Returns whether Speakr must not type into a target application.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_id` - Identifier of the target application, if known
+    ///
+    /// # Returns
+    ///
+    /// `true` if the application is on the injection blocklist. Identifiers
+    /// are compared case-insensitively; an unknown target is never blocked.
+    pub fn blocks_injection(&self, app_id: Option<&str>) -> bool {
+        app_id.is_some_and(|id| {
+            self.injection_blocklist
+                .iter()
+                .any(|blocked| blocked.eq_ignore_ascii_case(id))
+        })
+    }
+
+    /// Validates all fields in the AppSettings structure.
     ///
     /// # Returns
     ///
-    /// A vector containing all ModelSize variants in order.
+    /// `Ok(())` if all settings are valid, `Err(String)` with error message if invalid.
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// use speakr_types::ModelSize;
+    /// use speakr_types::AppSettings;
+    ///
+    /// let mut settings = AppSettings::default();
+    /// assert!(settings.validate().is_ok());
     ///
-    /// let sizes = ModelSize::all();
-    /// assert_eq!(sizes.len(), 3);
+    /// settings.language = "english".to_string();
+    /// assert!(settings.validate().is_err());
     /// ```
-    pub fn all() -> Vec<ModelSize> {
-        vec![ModelSize::Small, ModelSize::Medium, ModelSize::Large]
+    pub fn validate(&self) -> Result<(), String> {
+        if !Self::validate_language(&self.language) {
+            return Err(format!(
+                "Invalid language: '{}'. Must be a language code or '{}'.",
+                self.language, DEFAULT_LANGUAGE
+            ));
+        }
+
+        if self.model.as_str().is_empty() {
+            return Err("Invalid model: no model is selected.".to_string());
+        }
+
+        for transform in &self.post_processing {
+            match transform {
+                TextTransform::RegexReplace { pattern, .. } if pattern.is_empty() => {
+                    return Err("Invalid replacement rule: pattern must not be empty.".to_string());
+                }
+                TextTransform::Replace { from, .. } if from.trim().is_empty() => {
+                    return Err(
+                        "Invalid replacement: the text to replace must not be empty.".to_string(),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(prompt) = self.initial_prompt() {
+            let length = prompt.chars().count();
+            if length > MAX_VOCABULARY_PROMPT_CHARS {
+                return Err(format!(
+                    "Vocabulary is too long: {length} characters. Must be at most {MAX_VOCABULARY_PROMPT_CHARS}."
+                ));
+            }
+        }
+
+        let mut seen_app_ids = std::collections::HashSet::new();
+        for profile in &self.app_profiles {
+            let app_id = profile.app_id.trim();
+            if app_id.is_empty() {
+                return Err("Invalid app profile: application id must not be empty.".to_string());
+            }
+            if !seen_app_ids.insert(app_id.to_ascii_lowercase()) {
+                return Err(format!("Duplicate app profile for '{app_id}'."));
+            }
+            if profile.injection.typing_delay_ms > MAX_TYPING_DELAY_MS {
+                return Err(format!(
+                    "Invalid typing delay for '{app_id}': {} ms. Must be at most {MAX_TYPING_DELAY_MS} ms.",
+                    profile.injection.typing_delay_ms
+                ));
+            }
+        }
+
+        if let Some(stop_phrase) = &self.stop_phrase {
+            if stop_phrase.phrase.trim().is_empty() {
+                return Err("Invalid stop phrase: phrase must not be empty.".to_string());
+            }
+            if !(0.0..=1.0).contains(&stop_phrase.min_confidence) {
+                return Err(format!(
+                    "Invalid stop phrase confidence: {}. Must be between 0.0 and 1.0.",
+                    stop_phrase.min_confidence
+                ));
+            }
+        }
+
+        for command in &self.voice_commands.custom {
+            if command.phrase.trim().is_empty() {
+                return Err("Invalid voice command: phrase must not be empty.".to_string());
+            }
+        }
+
+        let mut seen_plugins = std::collections::HashSet::new();
+        for plugin in &self.plugins {
+            if !is_valid_plugin_name(plugin) {
+                return Err(format!(
+                    "Invalid plugin name '{plugin}': use letters, digits, '-' and '_'."
+                ));
+            }
+            if !seen_plugins.insert(plugin.as_str()) {
+                return Err(format!("Plugin '{plugin}' is enabled twice."));
+            }
+        }
+
+        if self.plugin_limits.max_fuel == 0 || self.plugin_limits.max_memory_bytes == 0 {
+            return Err("Invalid plugin limits: limits must be greater than zero.".to_string());
+        }
+
+        if self.model_idle_unload_mins == Some(0) {
+            return Err(
+                "Invalid model idle time: must be at least one minute, or never unload."
+                    .to_string(),
+            );
+        }
+
+        if self
+            .long_form_mins
+            .is_some_and(|mins| !(1..=MAX_LONG_FORM_MINS).contains(&mins))
+        {
+            return Err(format!(
+                "Invalid long-form limit: must be between 1 and {MAX_LONG_FORM_MINS} minutes."
+            ));
+        }
+
+        if self
+            .session_window_secs
+            .is_some_and(|secs| !(1..=MAX_SESSION_WINDOW_SECS).contains(&secs))
+        {
+            return Err(format!(
+                "Invalid session window: must be between 1 and {MAX_SESSION_WINDOW_SECS} seconds."
+            ));
+        }
+
+        let mut seen_shortcuts = std::collections::HashSet::from([self.hot_key.as_str()]);
+        for binding in &self.language_hotkeys {
+            let shortcut = binding.shortcut.trim();
+            if shortcut.is_empty() {
+                return Err("Invalid language hot-key: shortcut must not be empty.".to_string());
+            }
+            if !seen_shortcuts.insert(shortcut) {
+                return Err(format!("Hot-key '{shortcut}' is used more than once."));
+            }
+            if !Self::validate_language(&binding.language) {
+                return Err(format!(
+                    "Invalid language for hot-key '{shortcut}': '{}'.",
+                    binding.language
+                ));
+            }
+        }
+        if let Some(shortcut) = &self.privacy_hotkey {
+            let shortcut = shortcut.trim();
+            if shortcut.is_empty() {
+                return Err("Invalid privacy hot-key: shortcut must not be empty.".to_string());
+            }
+            if !seen_shortcuts.insert(shortcut) {
+                return Err(format!("Hot-key '{shortcut}' is used more than once."));
+            }
+        }
+        if let Some(shortcut) = &self.reinject_hotkey {
+            let shortcut = shortcut.trim();
+            if shortcut.is_empty() {
+                return Err("Invalid re-type hot-key: shortcut must not be empty.".to_string());
+            }
+            if !seen_shortcuts.insert(shortcut) {
+                return Err(format!("Hot-key '{shortcut}' is used more than once."));
+            }
+        }
+
+        for binding in &self.dev_shortcuts {
+            let shortcut = binding.shortcut.trim();
+            if shortcut.is_empty() {
+                return Err("Invalid developer shortcut: shortcut must not be empty.".to_string());
+            }
+            if !seen_shortcuts.insert(shortcut) {
+                return Err(format!("Hot-key '{shortcut}' is used more than once."));
+            }
+        }
+
+        if self.privacy_mode_mins == 0 {
+            return Err("Invalid privacy mode duration: must be at least one minute.".to_string());
+        }
+
+        if let Some(auto_gain) = &self.auto_gain {
+            if !(MIN_AGC_TARGET_DBFS..=MAX_AGC_TARGET_DBFS).contains(&auto_gain.target_dbfs) {
+                return Err(format!(
+                    "Invalid gain target: {} dBFS. Must be between {MIN_AGC_TARGET_DBFS} and {MAX_AGC_TARGET_DBFS} dBFS.",
+                    auto_gain.target_dbfs
+                ));
+            }
+        }
+
+        let mut seen_devices = std::collections::HashSet::new();
+        for device in &self.input_devices {
+            if device.trim().is_empty() {
+                return Err("Invalid input device: name must not be empty.".to_string());
+            }
+            if !seen_devices.insert(device.as_str()) {
+                return Err(format!("Input device '{device}' is listed more than once."));
+            }
+        }
+
+        for input_gain in &self.input_gains {
+            if !(MIN_INPUT_GAIN..=MAX_INPUT_GAIN).contains(&input_gain.gain) {
+                return Err(format!(
+                    "Invalid input gain for '{}': {}. Must be between {MIN_INPUT_GAIN} and {MAX_INPUT_GAIN}.",
+                    input_gain.device, input_gain.gain
+                ));
+            }
+        }
+
+        // Add other validation checks here as needed
+        Ok(())
     }
 }
 
 // --------------------------------------------------------------------------
-/// Comprehensive information about a specific Whisper model file.
+/// A single step in the text post-processing chain.
 ///
-/// Contains all metadata needed for model download, storage, and
-/// user interface display including file characteristics and descriptions.
+/// Transcribed text passes through the configured transforms in order
+/// before it is injected. Regex patterns are compiled (and rejected if
+/// invalid) by the post-processor in `speakr-core`.
 ///
-/// # Fields
+/// # Variants
 ///
-/// - `size`: The ModelSize variant
-/// - `filename`: Expected filename for the model file
-/// - `display_name`: User-friendly name with characteristics
-/// - `file_size_mb`: Approximate file size in megabytes
-/// - `description`: Detailed usage recommendations
+/// - `TrimWhitespace`: Trim the ends and collapse repeated spaces
+/// - `CapitaliseSentences`: Upper-case the first letter of each sentence
+/// - `StripPunctuation`: Remove punctuation added at the end of words
+/// - `StripFillerWords`: Remove hesitation words such as "um" and "uh"
+/// - `RegexReplace`: Replace every match of a regex pattern
+/// - `Replace`: Replace a word or phrase with literal text, e.g. "new line"
+///   with a line break
 ///
 /// # Examples
 ///
 /// ```no_run
-/// use speakr_types::{ModelInfo, ModelSize};
+/// use speakr_types::TextTransform;
 ///
-/// let info = ModelInfo::for_size(ModelSize::Medium);
-/// assert_eq!(info.filename, "ggml-medium.bin");
-/// assert_eq!(info.file_size_mb, 769);
+/// let chain = vec![
+///     TextTransform::TrimWhitespace,
+///     TextTransform::RegexReplace {
+///         pattern: r"\bteh\b".to_string(),
+///         replacement: "the".to_string(),
+///     },
+///     TextTransform::Replace {
+///         from: "em dash".to_string(),
+///         to: "—".to_string(),
+///         case_sensitive: false,
+///     },
+/// ];
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct ModelInfo {
-    /// The model size variant.
-    pub size: ModelSize,
-    /// Expected filename for the model file.
-    pub filename: String,
-    /// User-friendly display name with characteristics.
-    pub display_name: String,
-    /// Approximate file size in megabytes.
-    pub file_size_mb: u32,
-    /// Detailed description of model characteristics and use cases.
-    pub description: &'static str,
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum TextTransform {
+    /// Trim leading/trailing whitespace and collapse repeated spaces.
+    TrimWhitespace,
+
+    /// Capitalise the first letter of the text and of every sentence.
+    CapitaliseSentences,
+
+    /// Remove `.,!?;:` at the end of words, keeping e.g. "3.14" and "a::b".
+    StripPunctuation,
+
+    /// Remove the listed filler words (matched case-insensitively).
+    StripFillerWords {
+        /// Words to remove; see [`DEFAULT_FILLER_WORDS`].
+        words: Vec<String>,
+    },
+
+    /// Replace every match of `pattern` with `replacement`.
+    RegexReplace {
+        /// Regular expression to search for.
+        pattern: String,
+        /// Replacement text; may reference capture groups (`$1`).
+        replacement: String,
+    },
+
+    /// Replace every occurrence of `from` with `to`, both taken literally.
+    ///
+    /// Matches respect word boundaries, so "speakr" does not match inside
+    /// "speakrs".
+    Replace {
+        /// Word or phrase to search for.
+        from: String,
+        /// Text inserted instead, such as `"\n"` for a line break.
+        to: String,
+        /// Match letter case exactly; otherwise case is ignored.
+        #[serde(default)]
+        case_sensitive: bool,
+    },
 }
 
-impl ModelInfo {
-    /// Creates ModelInfo for a specific model size.
-    ///
-    /// # Arguments
-    ///
-    /// * `size` - The ModelSize to create information for
+impl TextTransform {
+    /// Returns the post-processing chain used for new settings.
     ///
     /// # Returns
     ///
-    /// Complete ModelInfo with all metadata populated.
-    ///
-    /// # Examples
-    ///
-    /// ```no_run
-    /// use speakr_types::{ModelInfo, ModelSize};
-    ///
-    /// let info = ModelInfo::for_size(ModelSize::Small);
-    /// assert_eq!(info.description, "Fast processing, good for quick notes");
-    /// ```
-    pub fn for_size(size: ModelSize) -> Self {
-        match size {
-            ModelSize::Small => ModelInfo {
-                size: ModelSize::Small,
-                filename: "ggml-small.bin".to_string(),
-                display_name: "Small (39MB, fast)".to_string(),
-                file_size_mb: 39,
-                description: "Fast processing, good for quick notes",
-            },
-            ModelSize::Medium => ModelInfo {
-                size: ModelSize::Medium,
-                filename: "ggml-medium.bin".to_string(),
-                display_name: "Medium (769MB, balanced)".to_string(),
-                file_size_mb: 769,
-                description: "Balanced accuracy and speed",
-            },
-            ModelSize::Large => ModelInfo {
-                size: ModelSize::Large,
-                filename: "ggml-large.bin".to_string(),
-                display_name: "Large (1550MB, accurate)".to_string(),
-                file_size_mb: 1550,
-                description: "Highest accuracy, best for professional use",
-            },
+    /// A chain that only trims whitespace, so dictated text is otherwise
+    /// injected exactly as transcribed until the user opts in to more.
+    pub fn default_chain() -> Vec<TextTransform> {
+        vec![TextTransform::TrimWhitespace]
+    }
+
+    /// Creates a filler-word transform using [`DEFAULT_FILLER_WORDS`].
+    pub fn default_filler_words() -> TextTransform {
+        TextTransform::StripFillerWords {
+            words: DEFAULT_FILLER_WORDS.iter().map(|w| w.to_string()).collect(),
         }
     }
 }
 
-// ============================================================================
-// Transcription Types and Configuration
-// ============================================================================
-
 // --------------------------------------------------------------------------
-/// Performance modes for transcription processing.
+/// Per-application overrides for dictated text.
 ///
-/// Each mode represents a different balance between processing speed,
-/// accuracy, and resource consumption during transcription.
+/// Some targets, such as code editors and terminals, should not receive the
+/// punctuation and sentence casing that suit prose, and some drop fast
+/// synthetic keystrokes. A profile adjusts post-processing and injection
+/// while dictating into the matching application.
 ///
-/// # Mode Characteristics
+/// # Fields
 ///
-/// - `Speed`: Optimises for fastest processing with acceptable accuracy
-/// - `Balanced`: Balanced performance between speed and accuracy (default)
-/// - `Accuracy`: Prioritises highest accuracy, longer processing time
+/// - `app_id`: Application identifier (the bundle identifier on macOS)
+/// - `punctuation`: Keep the punctuation produced by transcription
+/// - `capitalisation`: Apply sentence capitalisation, if configured
+/// - `injection`: How the text is delivered to the application
 ///
 /// # Examples
 ///
 /// ```no_run
-/// use speakr_types::PerformanceMode;
+/// use speakr_types::{AppProfile, TextTransform};
 ///
-/// let mode = PerformanceMode::Balanced;
+/// let terminal = AppProfile {
+///     punctuation: false,
+///     capitalisation: false,
+///     ..AppProfile::new("com.apple.Terminal")
+/// };
+/// let chain = terminal.apply(&[
+///     TextTransform::TrimWhitespace,
+///     TextTransform::CapitaliseSentences,
+/// ]);
+/// assert_eq!(
+///     chain,
+///     vec![TextTransform::TrimWhitespace, TextTransform::StripPunctuation]
+/// );
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
 #[serde(deny_unknown_fields)]
-pub enum PerformanceMode {
-    /// Optimised for speed with acceptable accuracy.
-    Speed,
+pub struct AppProfile {
+    /// Application identifier (the bundle identifier on macOS).
+    pub app_id: String,
 
-    /// Balanced performance between speed and accuracy (default).
-    #[default]
-    Balanced,
+    /// Whether punctuation produced by transcription is kept.
+    pub punctuation: bool,
 
-    /// Prioritises highest accuracy over processing speed.
-    Accuracy,
+    /// Whether sentence capitalisation is applied.
+    pub capitalisation: bool,
+
+    /// How the text is delivered to the application.
+    #[serde(default)]
+    pub injection: InjectionOptions,
+}
+
+impl AppProfile {
+    /// Creates a profile that keeps punctuation and capitalisation enabled
+    /// and injects with the default options.
+    pub fn new(app_id: impl Into<String>) -> Self {
+        Self {
+            app_id: app_id.into(),
+            punctuation: true,
+            capitalisation: true,
+            injection: InjectionOptions::default(),
+        }
+    }
+
+    /// Adjusts a post-processing chain for this application.
+    ///
+    /// # Arguments
+    ///
+    /// * `transforms` - The user's configured chain
+    ///
+    /// # Returns
+    ///
+    /// The chain without `CapitaliseSentences` when capitalisation is off,
+    /// and with `StripPunctuation` appended when punctuation is off.
+    pub fn apply(&self, transforms: &[TextTransform]) -> Vec<TextTransform> {
+        let mut chain: Vec<TextTransform> = transforms
+            .iter()
+            .filter(|transform| {
+                self.capitalisation || !matches!(transform, TextTransform::CapitaliseSentences)
+            })
+            .cloned()
+            .collect();
+
+        if !self.punctuation && !chain.contains(&TextTransform::StripPunctuation) {
+            chain.push(TextTransform::StripPunctuation);
+        }
+
+        chain
+    }
 }
 
 // --------------------------------------------------------------------------
-/// Configuration for transcription processing behaviour.
+/// A named configuration users can switch to as a whole, e.g. "Work" or
+/// "Meetings".
 ///
-/// Encapsulates all settings that control how transcription is performed,
-/// including model selection, language preferences, and performance tuning.
+/// Switching to a profile replaces the hot-key, model and vocabulary in the
+/// active settings; everything else is shared between profiles.
 ///
 /// # Fields
 ///
-/// - `model_size`: Selected Whisper model size for processing
-/// - `language`: Optional language code for processing (ISO 639-1)
-/// - `auto_detect_language`: Whether to automatically detect audio language
-/// - `performance_mode`: Processing optimisation preference
+/// - `name`: Name shown in the profile switcher, unique ignoring case
+/// - `hot_key`: Global hot-key combination
+/// - `model`: Selected Whisper model
+/// - `vocabulary`: Domain terms fed to Whisper
 ///
 /// # Examples
 ///
 /// ```no_run
-/// use speakr_types::{TranscriptionConfig, ModelSize, PerformanceMode};
+/// use speakr_types::{AppSettings, SettingsProfile};
 ///
-/// let config = TranscriptionConfig {
-///     model_size: ModelSize::Medium,
-///     language: Some("en".to_string()),
-///     auto_detect_language: false,
-///     performance_mode: PerformanceMode::Balanced,
-/// };
+/// let mut settings = AppSettings::default();
+/// settings.vocabulary = vec!["Kubernetes".to_string()];
+/// let work = SettingsProfile::from_settings("Work", &settings);
+///
+/// let mut personal = AppSettings::default();
+/// work.apply_to(&mut personal);
+/// assert_eq!(personal.vocabulary, vec!["Kubernetes".to_string()]);
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
 #[serde(deny_unknown_fields)]
-pub struct TranscriptionConfig {
-    /// Selected Whisper model size for processing.
-    pub model_size: ModelSize,
-    /// Optional language code for processing (ISO 639-1 format).
-    pub language: Option<String>,
-    /// Whether to automatically detect the audio language.
-    pub auto_detect_language: bool,
-    /// Processing optimisation preference.
-    pub performance_mode: PerformanceMode,
+pub struct SettingsProfile {
+    /// Name shown in the profile switcher.
+    pub name: String,
+
+    /// Global hot-key combination in Tauri format.
+    pub hot_key: String,
+
+    /// Selected model from the catalogue.
+    #[serde(alias = "model_size")]
+    pub model: ModelId,
+
+    /// Domain terms fed to Whisper as the initial prompt.
+    #[serde(default)]
+    pub vocabulary: Vec<String>,
 }
 
-impl Default for TranscriptionConfig {
-    fn default() -> Self {
+impl SettingsProfile {
+    /// Captures the per-profile values of `settings` under `name`.
+    pub fn from_settings(name: impl Into<String>, settings: &AppSettings) -> Self {
         Self {
-            model_size: ModelSize::default(),
-            language: None,
-            auto_detect_language: true,
-            performance_mode: PerformanceMode::default(),
+            name: name.into(),
+            hot_key: settings.hot_key.clone(),
+            model: settings.model.clone(),
+            vocabulary: settings.vocabulary.clone(),
         }
     }
+
+    /// Replaces the per-profile values of `settings` with this profile's.
+    pub fn apply_to(&self, settings: &mut AppSettings) {
+        settings.hot_key = self.hot_key.clone();
+        settings.model = self.model.clone();
+        settings.vocabulary = self.vocabulary.clone();
+    }
 }
 
 // --------------------------------------------------------------------------
-/// Specific error types for transcription operations.
+/// The saved settings profiles and which one is active.
 ///
-/// Provides detailed error information for transcription failures,
-/// enabling appropriate user feedback and recovery strategies.
+/// Stored in `profiles.json` next to `settings.json`.
 ///
-/// # Error Categories
+/// # Fields
 ///
-/// - Model-related errors (not found, loading failed, insufficient memory)
-/// - Processing errors (transcription failed, invalid audio format)
-/// - Language-related errors (unsupported language)
-/// - Network errors (model download failed)
+/// - `active`: Name of the profile last switched to, if any
+/// - `profiles`: The saved profiles, in the order they were created
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct SettingsProfiles {
+    /// Name of the profile last switched to.
+    #[serde(default)]
+    pub active: Option<String>,
+
+    /// The saved profiles.
+    #[serde(default)]
+    pub profiles: Vec<SettingsProfile>,
+}
+
+impl SettingsProfiles {
+    /// Finds a profile by name, ignoring case.
+    pub fn get(&self, name: &str) -> Option<&SettingsProfile> {
+        self.profiles
+            .iter()
+            .find(|profile| profile.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Adds `profile`, replacing a saved profile with the same name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if the name is blank.
+    pub fn upsert(&mut self, profile: SettingsProfile) -> Result<(), String> {
+        if profile.name.trim().is_empty() {
+            return Err("Invalid profile: name must not be empty.".to_string());
+        }
+        match self
+            .profiles
+            .iter_mut()
+            .find(|saved| saved.name.eq_ignore_ascii_case(&profile.name))
+        {
+            Some(saved) => *saved = profile,
+            None => self.profiles.push(profile),
+        }
+        Ok(())
+    }
+
+    /// Removes the profile called `name`, clearing it as the active one.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a profile was removed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        let before = self.profiles.len();
+        self.profiles
+            .retain(|profile| !profile.name.eq_ignore_ascii_case(name));
+        if self
+            .active
+            .as_deref()
+            .is_some_and(|active| active.eq_ignore_ascii_case(name))
+        {
+            self.active = None;
+        }
+        self.profiles.len() != before
+    }
+}
+
+// --------------------------------------------------------------------------
+/// Language of the user interface.
+///
+/// Stored as a language code (`"en"`, `"de"`, …) or `"system"` to follow the
+/// system language, falling back to English when it is not translated.
 ///
 /// # Examples
 ///
 /// ```no_run
-/// use speakr_types::{TranscriptionError, ModelSize};
+/// use speakr_types::UiLanguage;
 ///
-/// let error = TranscriptionError::ModelNotFound {
-///     model_size: ModelSize::Large
-/// };
+/// assert_eq!(UiLanguage::resolve(UiLanguage::System, Some("de-AT")), UiLanguage::German);
+/// assert_eq!(UiLanguage::resolve(UiLanguage::System, Some("ja")), UiLanguage::English);
+/// assert_eq!(UiLanguage::resolve(UiLanguage::French, Some("de")), UiLanguage::French);
 /// ```
-#[derive(Error, Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub enum TranscriptionError {
-    /// Requested model is not available locally.
-    #[error("Model not found: {model_size:?}")]
-    ModelNotFound { model_size: ModelSize },
-
-    /// Model loading failed during initialisation.
-    #[error("Model loading failed: {0}")]
-    ModelLoadingFailed(String),
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(rename_all = "lowercase")]
+pub enum UiLanguage {
+    /// Follow the system language.
+    #[default]
+    System,
+    /// English.
+    #[serde(rename = "en")]
+    English,
+    /// German.
+    #[serde(rename = "de")]
+    German,
+    /// French.
+    #[serde(rename = "fr")]
+    French,
+    /// Spanish.
+    #[serde(rename = "es")]
+    Spanish,
+}
 
-    /// Transcription processing failed during execution.
-    #[error("Transcription processing failed: {0}")]
-    ProcessingFailed(String),
+impl UiLanguage {
+    /// Every translated language, in the order they are listed.
+    pub const TRANSLATED: [UiLanguage; 4] = [
+        UiLanguage::English,
+        UiLanguage::German,
+        UiLanguage::French,
+        UiLanguage::Spanish,
+    ];
+
+    /// Returns the language code, or `"system"`.
+    pub fn code(self) -> &'static str {
+        match self {
+            UiLanguage::System => "system",
+            UiLanguage::English => "en",
+            UiLanguage::German => "de",
+            UiLanguage::French => "fr",
+            UiLanguage::Spanish => "es",
+        }
+    }
 
-    /// Insufficient memory available for the requested model.
-    #[error("Insufficient memory for model: {model_size:?}")]
-    InsufficientMemory { model_size: ModelSize },
+    /// Returns the language's name in that language, e.g. "Deutsch".
+    pub fn native_name(self) -> &'static str {
+        match self {
+            UiLanguage::System => "System",
+            UiLanguage::English => "English",
+            UiLanguage::German => "Deutsch",
+            UiLanguage::French => "Français",
+            UiLanguage::Spanish => "Español",
+        }
+    }
 
-    /// Invalid audio format provided for transcription.
-    #[error("Invalid audio format: {0}")]
-    InvalidAudioFormat(String),
+    /// Parses a code returned by [`code`](Self::code).
+    pub fn from_code(code: &str) -> Option<Self> {
+        [UiLanguage::System]
+            .into_iter()
+            .chain(Self::TRANSLATED)
+            .find(|language| language.code() == code)
+    }
 
-    /// Language is not supported by the current model.
-    #[error("Language not supported: {language}")]
-    UnsupportedLanguage { language: String },
+    /// Returns the translated language to show.
+    ///
+    /// # Arguments
+    ///
+    /// * `setting` - The `ui_language` setting
+    /// * `system_locale` - The system's language tag, e.g. "de-AT", if known
+    pub fn resolve(setting: UiLanguage, system_locale: Option<&str>) -> UiLanguage {
+        if setting != UiLanguage::System {
+            return setting;
+        }
+        let primary = system_locale
+            .and_then(|locale| locale.split(['-', '_']).next())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        Self::TRANSLATED
+            .into_iter()
+            .find(|language| language.code() == primary)
+            .unwrap_or(UiLanguage::English)
+    }
+}
 
-    /// Model download from remote source failed.
-    #[error("Model download failed: {0}")]
-    DownloadFailed(String),
+// --------------------------------------------------------------------------
+/// Light or dark appearance of the user interface.
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::Theme;
+///
+/// assert!(Theme::System.is_dark(true));
+/// assert!(!Theme::Light.is_dark(true));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    /// Follow the system appearance, switching when it changes.
+    #[default]
+    System,
+    /// Always light.
+    Light,
+    /// Always dark.
+    Dark,
 }
 
-impl TranscriptionError {
-    /// Return a short, user-friendly error message suitable for UI display.
-    pub fn user_message(&self) -> String {
+impl Theme {
+    /// Every theme, in the order they are listed.
+    pub const ALL: [Theme; 3] = [Theme::System, Theme::Light, Theme::Dark];
+
+    /// Returns the name used in settings, e.g. `"dark"`.
+    pub fn code(self) -> &'static str {
         match self {
-            TranscriptionError::ModelNotFound { model_size } => {
-                format!("The requested {model_size:?} model is not available on this device.")
-            }
-            TranscriptionError::ModelLoadingFailed(_) => {
-                "Unable to load the selected speech model.".to_string()
-            }
-            TranscriptionError::ProcessingFailed(_) => "Transcription failed.".to_string(),
-            TranscriptionError::InsufficientMemory { model_size } => {
-                format!("Not enough memory to run the {model_size:?} model.")
-            }
-            TranscriptionError::InvalidAudioFormat(_) => "Unsupported audio format.".to_string(),
-            TranscriptionError::UnsupportedLanguage { language } => {
-                format!("The language '{language}' is not supported by the current model.")
-            }
-            TranscriptionError::DownloadFailed(_) => "Model download failed.".to_string(),
+            Theme::System => "system",
+            Theme::Light => "light",
+            Theme::Dark => "dark",
         }
     }
 
-    /// Provide actionable suggestions that might resolve the error.
-    pub fn suggestions(&self) -> Vec<&'static str> {
+    /// Parses a name returned by [`code`](Self::code).
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|theme| theme.code() == code)
+    }
+
+    /// Whether the interface should be dark.
+    ///
+    /// # Arguments
+    ///
+    /// * `system_dark` - Whether the system appearance is currently dark
+    pub fn is_dark(self, system_dark: bool) -> bool {
         match self {
-            TranscriptionError::ModelNotFound { .. } => vec![
-                "Download the model in Settings → Models",
-                "Ensure you are connected to the Internet",
-            ],
-            TranscriptionError::ModelLoadingFailed(_) => vec![
-                "Restart Speakr to retry initialisation",
-                "Try switching to a smaller model size",
-            ],
-            TranscriptionError::ProcessingFailed(_) => vec![
-                "Retry the transcription operation",
-                "Make sure the microphone is not muted",
-            ],
-            TranscriptionError::InsufficientMemory { .. } => vec![
-                "Switch to a smaller model size in Settings",
-                "Close other memory-intensive applications then retry",
-            ],
-            TranscriptionError::InvalidAudioFormat(_) => {
-                vec!["Provide 16-kHz 16-bit mono PCM audio samples"]
-            }
-            TranscriptionError::UnsupportedLanguage { .. } => vec![
-                "Enable automatic language detection",
-                "Switch to a multilingual model variant",
-            ],
-            TranscriptionError::DownloadFailed(_) => {
-                vec!["Check your network connection", "Retry the download later"]
-            }
+            Theme::System => system_dark,
+            Theme::Light => false,
+            Theme::Dark => true,
         }
     }
 }
 
 // --------------------------------------------------------------------------
-/// Individual transcription segment with timing information.
-///
-/// Represents a portion of the transcribed text with precise timing
-/// and confidence metrics for detailed analysis.
+/// Where dictated text ends up.
 ///
-/// # Fields
+/// # Variants
 ///
-/// - `text`: The transcribed text for this segment
-/// - `start_time`: Beginning timestamp of the segment
-/// - `end_time`: Ending timestamp of the segment
-/// - `confidence`: Confidence score (0.0-1.0) for this segment
+/// - `Inject`: Insert the text into the focused application
+/// - `Clipboard`: Only copy the text to the clipboard; nothing is typed
+/// - `Both`: Insert the text and also copy it to the clipboard
 ///
 /// # Examples
 ///
 /// ```no_run
-/// use speakr_types::TranscriptionSegment;
-/// use std::time::Duration;
+/// use speakr_types::OutputMode;
 ///
-/// let segment = TranscriptionSegment {
-///     text: "Hello world".to_string(),
-///     start_time: Duration::from_millis(0),
-///     end_time: Duration::from_millis(1000),
-///     confidence: 0.95,
-/// };
+/// assert!(OutputMode::Both.injects() && OutputMode::Both.copies());
+/// assert!(!OutputMode::Clipboard.injects());
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct TranscriptionSegment {
-    /// The transcribed text for this segment.
-    pub text: String,
-    /// Beginning timestamp of the segment.
-    pub start_time: Duration,
-    /// Ending timestamp of the segment.
-    pub end_time: Duration,
-    /// Confidence score (0.0-1.0) for this segment.
-    pub confidence: f32,
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+pub enum OutputMode {
+    /// Insert the text into the focused application.
+    #[default]
+    Inject,
+    /// Only copy the text to the clipboard.
+    Clipboard,
+    /// Insert the text and copy it to the clipboard.
+    Both,
+}
+
+impl OutputMode {
+    /// Whether the text is inserted into the focused application.
+    pub fn injects(self) -> bool {
+        matches!(self, OutputMode::Inject | OutputMode::Both)
+    }
+
+    /// Whether the text is copied to the clipboard.
+    pub fn copies(self) -> bool {
+        matches!(self, OutputMode::Clipboard | OutputMode::Both)
+    }
 }
 
 // --------------------------------------------------------------------------
-/// Complete transcription result with metadata and segments.
+/// How dictated text is delivered to the target application.
 ///
-/// Contains the full output of a transcription operation, including
-/// the transcribed text, confidence metrics, timing information,
-/// and detailed segment breakdown.
+/// # Variants
+///
+/// - `Keystrokes`: Type the text as synthetic key presses
+/// - `Clipboard`: Paste the text through the clipboard, for applications
+///   that drop or reorder fast keystrokes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+pub enum InjectionMethod {
+    /// Type the text as synthetic key presses.
+    #[default]
+    Keystrokes,
+    /// Paste the text through the clipboard.
+    Clipboard,
+}
+
+// --------------------------------------------------------------------------
+/// Text appended after each dictation.
+///
+/// # Variants
+///
+/// - `Nothing`: Inject the text as transcribed
+/// - `Space`: Append a space, so consecutive dictations don't run together
+/// - `Newline`: Append a newline, e.g. to submit a chat message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+pub enum TrailingText {
+    /// Inject the text as transcribed.
+    #[default]
+    Nothing,
+    /// Append a space.
+    Space,
+    /// Append a newline.
+    Newline,
+}
+
+impl TrailingText {
+    /// Returns the text to append.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TrailingText::Nothing => "",
+            TrailingText::Space => " ",
+            TrailingText::Newline => "\n",
+        }
+    }
+}
+
+// --------------------------------------------------------------------------
+/// Options controlling how text is injected into an application.
 ///
 /// # Fields
 ///
-/// - `text`: Complete transcribed text
-/// - `language`: Detected or specified language code
-/// - `confidence`: Overall confidence score (0.0-1.0)
-/// - `processing_time`: Total time taken for transcription
-/// - `memory_delta_bytes`: Memory delta in bytes consumed during transcription
-/// - `model_used`: Model size that performed the transcription
-/// - `segments`: Detailed breakdown of transcription segments
+/// - `method`: Keystrokes or clipboard paste
+/// - `typing_delay_ms`: Delay between keystrokes (0-[`MAX_TYPING_DELAY_MS`]),
+///   ignored when pasting
+/// - `trailing`: Text appended after the dictation
 ///
 /// # Examples
 ///
 /// ```no_run
-/// use speakr_types::{TranscriptionResult, ModelSize};
-/// use std::time::Duration;
+/// use speakr_types::{InjectionOptions, TrailingText};
 ///
-/// let result = TranscriptionResult {
-///     text: "Hello world".to_string(),
-///     language: Some("en".to_string()),
-///     confidence: 0.95,
-///     processing_time: Duration::from_millis(500),
-///     memory_delta_bytes: 0,
-///     model_used: ModelSize::Medium,
-///     segments: vec![],
+/// let chat = InjectionOptions {
+///     trailing: TrailingText::Newline,
+///     ..InjectionOptions::default()
 /// };
+/// assert_eq!(chat.finish("Sounds good"), "Sounds good\n");
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
 #[serde(deny_unknown_fields)]
-pub struct TranscriptionResult {
-    /// Complete transcribed text.
-    pub text: String,
-    /// Detected or specified language code (ISO 639-1).
-    pub language: Option<String>,
-    /// Overall confidence score (0.0-1.0) for the transcription.
-    pub confidence: f32,
-    /// Total time taken for transcription processing.
-    pub processing_time: Duration,
-    /// Memory delta in bytes consumed during transcription.
-    pub memory_delta_bytes: u64,
-    /// Model size that performed the transcription.
-    pub model_used: ModelSize,
-    /// Detailed breakdown of transcription segments with timing.
-    pub segments: Vec<TranscriptionSegment>,
+pub struct InjectionOptions {
+    /// Keystrokes or clipboard paste.
+    #[serde(default)]
+    pub method: InjectionMethod,
+
+    /// Delay between synthetic keystrokes in milliseconds.
+    #[serde(default = "default_typing_delay_ms")]
+    pub typing_delay_ms: u32,
+
+    /// Text appended after the dictation.
+    #[serde(default)]
+    pub trailing: TrailingText,
 }
 
-// ============================================================================
-// Status and Service Management
-// ============================================================================
+/// Provides the default typing delay for serde deserialization.
+fn default_typing_delay_ms() -> u32 {
+    DEFAULT_TYPING_DELAY_MS
+}
+
+impl Default for InjectionOptions {
+    fn default() -> Self {
+        Self {
+            method: InjectionMethod::default(),
+            typing_delay_ms: DEFAULT_TYPING_DELAY_MS,
+            trailing: TrailingText::default(),
+        }
+    }
+}
+
+impl InjectionOptions {
+    /// Appends the configured trailing text to dictated text.
+    pub fn finish(&self, text: &str) -> String {
+        format!("{text}{}", self.trailing.as_str())
+    }
+}
 
 // --------------------------------------------------------------------------
-/// Status of an individual service component in the backend.
+/// What a paragraph break in the transcript becomes in the output.
 ///
-/// Provides detailed status information for each service, enabling
-/// the frontend to display appropriate UI states and error messages.
+/// # Variants
 ///
-/// # Status Values
+/// - `SingleNewline`: One line break, for chat boxes and plain editors
+/// - `DoubleNewline`: A blank line between paragraphs, as in prose
+/// - `NoBreak`: Paragraphs are joined with a space into one line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+pub enum ParagraphBreak {
+    /// One line break.
+    SingleNewline,
+    /// A blank line between paragraphs.
+    #[default]
+    DoubleNewline,
+    /// Paragraphs are joined with a space.
+    NoBreak,
+}
+
+impl ParagraphBreak {
+    /// Returns the text placed between paragraphs.
+    pub fn separator(self) -> &'static str {
+        match self {
+            ParagraphBreak::SingleNewline => "\n",
+            ParagraphBreak::DoubleNewline => "\n\n",
+            ParagraphBreak::NoBreak => " ",
+        }
+    }
+}
+
+// --------------------------------------------------------------------------
+/// What saying "new paragraph" while dictating does.
 ///
-/// - `Ready`: Service is operational and available
-/// - `Starting`: Service is initialising
-/// - `Error(String)`: Service failed with specific error details
-/// - `Unavailable`: Service is not available (e.g., permissions)
+/// # Variants
+///
+/// - `ParagraphBreak`: Start a new paragraph, output per [`ParagraphBreak`]
+/// - `LineBreak`: Insert a single line break
+/// - `Literal`: Treat the words as dictated text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+pub enum NewParagraphCommand {
+    /// Start a new paragraph.
+    #[default]
+    ParagraphBreak,
+    /// Insert a single line break.
+    LineBreak,
+    /// Keep the words in the text.
+    Literal,
+}
+
+// --------------------------------------------------------------------------
+/// Newline handling applied when formatting text for output.
+///
+/// # Fields
+///
+/// - `paragraph_break`: What a paragraph break becomes
+/// - `new_paragraph_command`: What saying "new paragraph" does
 ///
 /// # Examples
 ///
 /// ```no_run
-/// use speakr_types::ServiceStatus;
+/// use speakr_types::{NewlineOptions, ParagraphBreak};
 ///
-/// let status = ServiceStatus::Ready;
-/// assert!(status.is_ready());
-/// assert_eq!(status.display_name(), "Ready");
+/// let chat = NewlineOptions {
+///     paragraph_break: ParagraphBreak::SingleNewline,
+///     ..NewlineOptions::default()
+/// };
+/// assert_eq!(chat.paragraph_break.separator(), "\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct NewlineOptions {
+    /// What a paragraph break becomes.
+    #[serde(default)]
+    pub paragraph_break: ParagraphBreak,
+
+    /// What saying "new paragraph" does.
+    #[serde(default)]
+    pub new_paragraph_command: NewParagraphCommand,
+}
+
+// --------------------------------------------------------------------------
+/// A spoken phrase that ends a dictation.
 ///
-/// let error_status = ServiceStatus::Error("Permission denied".to_string());
-/// assert!(!error_status.is_ready());
+/// Lets users stop recording without touching the keyboard. The phrase is
+/// only honoured when it ends the speech heard so far and was transcribed
+/// with at least `min_confidence`; it is removed from the dictated text.
+///
+/// # Fields
+///
+/// - `phrase`: Words to listen for, matched case- and punctuation-insensitively
+/// - `min_confidence`: Minimum transcription confidence (0.0-1.0) for a match
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::{StopPhrase, DEFAULT_STOP_PHRASE_CONFIDENCE};
+///
+/// let stop = StopPhrase::new("stop dictation");
+/// assert_eq!(stop.min_confidence, DEFAULT_STOP_PHRASE_CONFIDENCE);
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
 #[serde(deny_unknown_fields)]
-pub enum ServiceStatus {
-    /// Service is ready and operational.
-    Ready,
-    /// Service is currently starting up.
-    Starting,
-    /// Service encountered an error with details.
-    Error(String),
-    /// Service is unavailable (e.g., missing permissions).
-    Unavailable,
+pub struct StopPhrase {
+    /// Words to listen for.
+    pub phrase: String,
+
+    /// Minimum transcription confidence (0.0-1.0) for a match.
+    #[serde(default = "default_stop_phrase_confidence")]
+    pub min_confidence: f32,
 }
 
-impl Default for ServiceStatus {
-    fn default() -> Self {
-        Self::Starting
+impl StopPhrase {
+    /// Creates a stop phrase with the default confidence threshold.
+    pub fn new(phrase: impl Into<String>) -> Self {
+        Self {
+            phrase: phrase.into(),
+            min_confidence: DEFAULT_STOP_PHRASE_CONFIDENCE,
+        }
     }
 }
 
-impl ServiceStatus {
-    /// Returns the user-friendly display name for the status.
-    ///
-    /// # Returns
-    ///
-    /// A static string representing the status for UI display.
-    ///
-    /// # Examples
-    ///
-    /// ```no_run
-    /// use speakr_types::ServiceStatus;
-    ///
-    /// assert_eq!(ServiceStatus::Ready.display_name(), "Ready");
-    /// assert_eq!(ServiceStatus::Error("test".to_string()).display_name(), "Error");
-    /// ```
-    pub fn display_name(&self) -> &str {
-        match self {
-            ServiceStatus::Ready => "Ready",
-            ServiceStatus::Starting => "Starting",
-            ServiceStatus::Error(_) => "Error",
-            ServiceStatus::Unavailable => "Unavailable",
+/// Provides the default stop phrase confidence for serde deserialization.
+fn default_stop_phrase_confidence() -> f32 {
+    DEFAULT_STOP_PHRASE_CONFIDENCE
+}
+
+// --------------------------------------------------------------------------
+/// What a spoken voice command does to the dictated text.
+///
+/// # Variants
+///
+/// - `Punctuation`: Attach text to the previous word, e.g. "," for "comma"
+/// - `Insert`: Insert text as a separate word
+/// - `NewLine`: Start a new line
+/// - `NewParagraph`: Start a new paragraph
+/// - `DeleteLastPhrase`: Remove the text back to the previous punctuation
+///   mark or line break
+/// - `AllCapsNextWord`: Upper-case the word that follows
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::VoiceCommandAction;
+///
+/// let action = VoiceCommandAction::Punctuation { text: ";".to_string() };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum VoiceCommandAction {
+    /// Attach `text` to the previous word, replacing any punctuation the
+    /// transcription already put there.
+    Punctuation {
+        /// The punctuation to insert.
+        text: String,
+    },
+
+    /// Insert `text` as a separate word.
+    Insert {
+        /// The text to insert.
+        text: String,
+    },
+
+    /// Start a new line.
+    NewLine,
+
+    /// Start a new paragraph.
+    NewParagraph,
+
+    /// Remove the text back to the previous punctuation mark or line break.
+    DeleteLastPhrase,
+
+    /// Upper-case the word that follows.
+    AllCapsNextWord,
+}
+
+// --------------------------------------------------------------------------
+/// A spoken phrase and the action it triggers.
+///
+/// # Fields
+///
+/// - `phrase`: Words to listen for, matched case- and punctuation-insensitively
+/// - `action`: What the phrase does to the text
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::{VoiceCommand, VoiceCommandAction};
+///
+/// let command = VoiceCommand::new("smiley", VoiceCommandAction::Insert {
+///     text: ":-)".to_string(),
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct VoiceCommand {
+    /// Words to listen for.
+    pub phrase: String,
+
+    /// What the phrase does to the text.
+    pub action: VoiceCommandAction,
+}
+
+impl VoiceCommand {
+    /// Creates a command for `phrase`.
+    pub fn new(phrase: impl Into<String>, action: VoiceCommandAction) -> Self {
+        Self {
+            phrase: phrase.into(),
+            action,
         }
     }
 
-    /// Returns true if the service is ready for operation.
-    ///
-    /// # Returns
-    ///
-    /// Boolean indicating operational readiness.
-    ///
-    /// # Examples
-    ///
-    /// ```no_run
-    /// use speakr_types::ServiceStatus;
-    ///
-    /// assert!(ServiceStatus::Ready.is_ready());
-    /// assert!(!ServiceStatus::Starting.is_ready());
-    /// ```
-    pub fn is_ready(&self) -> bool {
-        matches!(self, ServiceStatus::Ready)
+    /// Returns the commands recognised without any custom mappings.
+    pub fn built_in() -> Vec<VoiceCommand> {
+        let punctuation = |phrase: &str, text: &str| {
+            VoiceCommand::new(
+                phrase,
+                VoiceCommandAction::Punctuation {
+                    text: text.to_string(),
+                },
+            )
+        };
+        vec![
+            punctuation("period", "."),
+            punctuation("full stop", "."),
+            punctuation("comma", ","),
+            punctuation("question mark", "?"),
+            punctuation("exclamation mark", "!"),
+            punctuation("exclamation point", "!"),
+            punctuation("colon", ":"),
+            punctuation("semicolon", ";"),
+            VoiceCommand::new("new line", VoiceCommandAction::NewLine),
+            VoiceCommand::new("new paragraph", VoiceCommandAction::NewParagraph),
+            VoiceCommand::new("delete that", VoiceCommandAction::DeleteLastPhrase),
+            VoiceCommand::new("scratch that", VoiceCommandAction::DeleteLastPhrase),
+            VoiceCommand::new("all caps", VoiceCommandAction::AllCapsNextWord),
+        ]
     }
 }
 
 // --------------------------------------------------------------------------
-/// Overall backend system status combining all service states.
+/// Spoken punctuation and editing commands.
 ///
-/// Provides a comprehensive view of system health, enabling the frontend
-/// to determine overall application readiness and display appropriate
-/// status indicators to users.
+/// When enabled, phrases such as "comma", "new paragraph" and "delete that"
+/// are interpreted before post-processing instead of being typed. Custom
+/// commands are added to the [built-in ones](VoiceCommand::built_in) and
+/// take precedence over a built-in command with the same phrase.
 ///
-/// # Service Components
+/// # Fields
 ///
-/// - `audio_capture`: Microphone access and recording capability
-/// - `transcription`: Whisper model loading and processing
-/// - `text_injection`: Keyboard simulation and text insertion
-/// - `timestamp`: Unix timestamp in milliseconds for status age
+/// - `enabled`: Whether spoken commands are interpreted
+/// - `custom`: User-defined commands
 ///
 /// # Examples
 ///
 /// ```no_run
-/// use speakr_types::{BackendStatus, ServiceStatus};
-///
-/// let status = BackendStatus::new_ready();
-/// assert!(status.is_ready());
+/// use speakr_types::{VoiceCommand, VoiceCommandAction, VoiceCommandSettings};
 ///
-/// let partial_status = BackendStatus {
-///     audio_capture: ServiceStatus::Ready,
-///     transcription: ServiceStatus::Starting,
-///     text_injection: ServiceStatus::Ready,
-///     timestamp: 12345,
+/// let commands = VoiceCommandSettings {
+///     enabled: true,
+///     custom: vec![VoiceCommand::new("dash", VoiceCommandAction::Insert {
+///         text: "—".to_string(),
+///     })],
 /// };
-/// assert!(!partial_status.is_ready());
 /// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct VoiceCommandSettings {
+    /// Whether spoken commands are interpreted.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// User-defined commands, checked before the built-in ones.
+    #[serde(default)]
+    pub custom: Vec<VoiceCommand>,
+}
+
+// --------------------------------------------------------------------------
+/// Software gain for one input device.
+///
+/// Captured audio is multiplied by `gain` before it is converted to 16-bit
+/// samples, and limited so loud input does not clip.
+///
+/// # Fields
+///
+/// - `device`: Name of the input device, as listed by the system
+/// - `gain`: Multiplier ([`MIN_INPUT_GAIN`] to [`MAX_INPUT_GAIN`])
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
 #[serde(deny_unknown_fields)]
-pub struct BackendStatus {
-    /// Status of audio capture service (microphone access).
-    pub audio_capture: ServiceStatus,
-    /// Status of transcription service (Whisper model).
-    pub transcription: ServiceStatus,
-    /// Status of text injection service (keyboard simulation).
-    pub text_injection: ServiceStatus,
-    /// Unix timestamp in milliseconds when status was created.
-    pub timestamp: u64,
+pub struct InputGain {
+    /// Name of the input device.
+    pub device: String,
+    /// Multiplier applied to the device's samples.
+    pub gain: f32,
 }
 
-impl BackendStatus {
-    /// Returns true if all services are ready for operation.
-    ///
-    /// # Returns
-    ///
-    /// Boolean indicating if the entire system is operational.
-    ///
-    /// # Examples
-    ///
-    /// ```no_run
-    /// use speakr_types::BackendStatus;
-    ///
-    /// let ready_status = BackendStatus::new_ready();
-    /// assert!(ready_status.is_ready());
-    /// ```
-    pub fn is_ready(&self) -> bool {
-        self.audio_capture.is_ready()
-            && self.transcription.is_ready()
-            && self.text_injection.is_ready()
-    }
+/// Returns the gain in `input_gains` for `device`, or `1.0` if none is set.
+pub fn input_gain_for(input_gains: &[InputGain], device: &str) -> f32 {
+    input_gains
+        .iter()
+        .find(|input_gain| input_gain.device == device)
+        .map_or(1.0, |input_gain| input_gain.gain)
+}
 
-    /// Creates a new status with all services in starting state.
-    ///
-    /// # Returns
-    ///
-    /// BackendStatus with all services marked as Starting.
-    ///
-    /// # Examples
-    ///
-    /// ```no_run
-    /// use speakr_types::BackendStatus;
-    ///
-    /// let status = BackendStatus::new_starting();
-    /// assert!(!status.is_ready());
-    /// ```
-    pub fn new_starting() -> Self {
+// --------------------------------------------------------------------------
+/// Automatic gain control for quiet microphones.
+///
+/// Captured speech is normalised so its RMS level reaches `target_dbfs`
+/// before transcription. Gain is limited so loud input is never clipped.
+///
+/// # Fields
+///
+/// - `target_dbfs`: Loudness to normalise speech to, in dBFS
+///   ([`MIN_AGC_TARGET_DBFS`] to [`MAX_AGC_TARGET_DBFS`])
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::{AutoGain, DEFAULT_AGC_TARGET_DBFS};
+///
+/// assert_eq!(AutoGain::default().target_dbfs, DEFAULT_AGC_TARGET_DBFS);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct AutoGain {
+    /// Loudness to normalise speech to, in dBFS.
+    #[serde(default = "default_agc_target_dbfs")]
+    pub target_dbfs: f32,
+}
+
+impl Default for AutoGain {
+    fn default() -> Self {
         Self {
-            audio_capture: ServiceStatus::Starting,
-            transcription: ServiceStatus::Starting,
-            text_injection: ServiceStatus::Starting,
-            timestamp: chrono::Utc::now().timestamp_millis() as u64,
+            target_dbfs: DEFAULT_AGC_TARGET_DBFS,
         }
     }
+}
+
+/// Provides the default gain target for serde deserialization.
+fn default_agc_target_dbfs() -> f32 {
+    DEFAULT_AGC_TARGET_DBFS
+}
+
+// --------------------------------------------------------------------------
+/// Converts an RMS level (`0.0..=1.0` of full scale) to dBFS.
+///
+/// # Returns
+///
+/// The level in dBFS, no quieter than [`SILENCE_DBFS`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::{level_to_dbfs, SILENCE_DBFS};
+///
+/// assert_eq!(level_to_dbfs(1.0), 0.0);
+/// assert_eq!(level_to_dbfs(0.0), SILENCE_DBFS);
+/// ```
+pub fn level_to_dbfs(level: f32) -> f32 {
+    if level <= 0.0 {
+        return SILENCE_DBFS;
+    }
+    (20.0 * level.log10()).max(SILENCE_DBFS)
+}
+
+/// Converts a dBFS level to an RMS level (`0.0..=1.0` of full scale).
+pub fn dbfs_to_level(dbfs: f32) -> f32 {
+    10f32.powf(dbfs / 20.0).min(1.0)
+}
+
+// --------------------------------------------------------------------------
+/// Levels measured by the microphone test, a short recording made from
+/// Settings so users can check their setup before relying on the hot-key.
+///
+/// # Fields
+///
+/// - `device`: Name of the input device that was recorded, if known
+/// - `peak_dbfs`: Loudest sample, in dBFS
+/// - `rms_dbfs`: Average (RMS) level, in dBFS
+/// - `clipping`: Whether the peak reached [`CLIPPING_DBFS`]
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::MicrophoneTest;
+///
+/// let test = MicrophoneTest {
+///     device: Some("USB Headset".to_string()),
+///     peak_dbfs: -6.0,
+///     rms_dbfs: -24.0,
+///     clipping: false,
+/// };
+/// assert!(test.passed());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct MicrophoneTest {
+    /// Name of the input device that was recorded, if known.
+    pub device: Option<String>,
+    /// Loudest sample, in dBFS.
+    pub peak_dbfs: f32,
+    /// Average (RMS) level, in dBFS.
+    pub rms_dbfs: f32,
+    /// Whether the peak reached [`CLIPPING_DBFS`].
+    pub clipping: bool,
+}
+
+impl MicrophoneTest {
+    /// Returns `true` if speech was heard without clipping.
+    pub fn passed(&self) -> bool {
+        !self.clipping && self.rms_dbfs >= MIC_TEST_MIN_RMS_DBFS
+    }
+
+    /// Explains the result, e.g. "Too quiet: speak up or move closer to the
+    /// microphone."
+    pub fn summary(&self) -> &'static str {
+        if self.clipping {
+            "Clipping: lower the input volume or move away from the microphone."
+        } else if self.rms_dbfs < MIC_TEST_MIN_RMS_DBFS {
+            "Too quiet: speak up or move closer to the microphone."
+        } else {
+            "Your microphone is working."
+        }
+    }
+}
+
+// ============================================================================
+// Post-processing Plugins
+// ============================================================================
+
+// --------------------------------------------------------------------------
+/// Returns true if `name` is usable as a plugin name.
+///
+/// Plugin names double as file names in the plugin directory, so they are
+/// restricted to ASCII letters, digits, `-` and `_`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::is_valid_plugin_name;
+///
+/// assert!(is_valid_plugin_name("title-case"));
+/// assert!(!is_valid_plugin_name("../evil"));
+/// ```
+pub fn is_valid_plugin_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+// --------------------------------------------------------------------------
+/// Sandbox limits applied to each post-processing plugin call.
+///
+/// A plugin that exceeds either limit is stopped and its output discarded;
+/// the transcript continues unchanged to the next step.
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::{PluginLimits, DEFAULT_PLUGIN_MAX_FUEL};
+///
+/// let limits = PluginLimits::default();
+/// assert_eq!(limits.max_fuel, DEFAULT_PLUGIN_MAX_FUEL);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct PluginLimits {
+    /// WebAssembly fuel (roughly, instructions) available per call.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub max_fuel: u64,
+    /// Maximum linear memory the plugin may allocate, in bytes.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub max_memory_bytes: u64,
+}
+
+impl Default for PluginLimits {
+    fn default() -> Self {
+        Self {
+            max_fuel: DEFAULT_PLUGIN_MAX_FUEL,
+            max_memory_bytes: DEFAULT_PLUGIN_MAX_MEMORY_BYTES,
+        }
+    }
+}
+
+// --------------------------------------------------------------------------
+/// What a post-processing plugin receives: the transcript plus context.
+///
+/// Serialised as JSON and written into the plugin's memory; see the
+/// `speakr_core::plugins` module for the full interface.
+///
+/// # Fields
+///
+/// - `text`: Transcript after the built-in transforms and earlier plugins
+/// - `app_id`: Identifier of the application receiving the text, if known
+/// - `language`: Configured transcription language code, or `"auto"`
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::PluginInput;
+///
+/// let input = PluginInput {
+///     text: "hello world".to_string(),
+///     app_id: Some("com.apple.TextEdit".to_string()),
+///     language: "en".to_string(),
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+pub struct PluginInput {
+    /// Transcript after the built-in transforms and any earlier plugins.
+    pub text: String,
+    /// Identifier of the application receiving the text, if known.
+    pub app_id: Option<String>,
+    /// Configured transcription language code, or `"auto"`.
+    pub language: String,
+}
+
+// --------------------------------------------------------------------------
+/// A plugin installed in the plugin directory.
+///
+/// # Fields
+///
+/// - `name`: Plugin name, enabled by adding it to `AppSettings::plugins`
+/// - `size_bytes`: Size of the WebAssembly module on disk
+/// - `error`: Why the module cannot be loaded, if it is not a valid plugin
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct PluginInfo {
+    /// Plugin name (the module's file stem).
+    pub name: String,
+    /// Size of the WebAssembly module on disk in bytes.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub size_bytes: u64,
+    /// Why the module cannot be loaded, or `None` if it is a valid plugin.
+    pub error: Option<String>,
+}
+
+// ============================================================================
+// Model Configuration and Metadata
+// ============================================================================
+
+// --------------------------------------------------------------------------
+/// Identifies a Whisper model in the model catalogue.
+///
+/// The identifier is the model's file name without the `ggml-` prefix and
+/// `.bin` extension, e.g. `base.en-q5_1` for `ggml-base.en-q5_1.bin`; it
+/// matches `speakr_core::model::Model::filename`. Identifiers are stored in
+/// lower case.
+///
+/// Settings written before any catalogue model could be chosen hold one of
+/// three sizes ("small", "medium", "large"), and older transcription results
+/// hold the size variants ("Small", "Medium", "Large"). Both are read as the
+/// model they selected, with "large" meaning `large-v3-turbo`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::ModelId;
+///
+/// let model = ModelId::new("base.en-q5_1");
+/// assert_eq!(model.filename(), "ggml-base.en-q5_1.bin");
+/// assert!(model.is_english_only());
+/// assert_eq!(ModelId::new("Large").as_str(), "large-v3-turbo");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(from = "String", into = "String")]
+pub struct ModelId(String);
+
+impl ModelId {
+    /// Creates an identifier, reading the legacy model sizes as the models
+    /// they selected.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - A catalogue identifier such as "small.en", or a legacy size
+    pub fn new(id: impl Into<String>) -> Self {
+        let id = id.into().trim().to_ascii_lowercase();
+        match id.as_str() {
+            "large" => ModelId("large-v3-turbo".to_string()),
+            _ => ModelId(id),
+        }
+    }
+
+    /// Returns the identifier, e.g. "medium.en".
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the model's file name in the model directory.
+    pub fn filename(&self) -> String {
+        format!("ggml-{}.bin", self.0)
+    }
+
+    /// Returns `true` for models that only transcribe English (`.en`).
+    pub fn is_english_only(&self) -> bool {
+        self.0.contains(".en")
+    }
+
+    /// Returns `true` for quantised models, which are smaller and faster at
+    /// some cost in accuracy.
+    pub fn is_quantised(&self) -> bool {
+        self.0.contains("-q")
+    }
+}
+
+impl Default for ModelId {
+    fn default() -> Self {
+        ModelId::new(DEFAULT_MODEL)
+    }
+}
+
+impl fmt::Display for ModelId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for ModelId {
+    fn from(id: String) -> Self {
+        ModelId::new(id)
+    }
+}
+
+impl From<&str> for ModelId {
+    fn from(id: &str) -> Self {
+        ModelId::new(id)
+    }
+}
+
+impl From<ModelId> for String {
+    fn from(id: ModelId) -> Self {
+        id.0
+    }
+}
+
+// --------------------------------------------------------------------------
+/// A model offered in the settings model picker.
+///
+/// Returned by the `list_model_catalogue` command, one entry per model in
+/// the catalogue, with the download size and checksum it publishes.
+///
+/// # Fields
+///
+/// - `id`: The model's identifier
+/// - `size_bytes`: Download size of the model file
+/// - `sha`: Checksum the catalogue publishes for the file
+/// - `memory_mb`: Approximate memory needed to run the model
+/// - `installed`: Whether the model file is in the model directory
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::{CatalogueModel, ModelId};
+///
+/// let model = CatalogueModel {
+///     id: ModelId::new("tiny.en"),
+///     size_bytes: 77_704_715,
+///     sha: "c78c86eb1a8faa21b369bcd33207cc90d64ae9df".to_string(),
+///     memory_mb: 273,
+///     installed: false,
+/// };
+/// assert_eq!(model.size_mb(), 74);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct CatalogueModel {
+    /// The model's identifier.
+    pub id: ModelId,
+    /// Download size of the model file in bytes.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub size_bytes: u64,
+    /// Checksum the catalogue publishes for the file.
+    pub sha: String,
+    /// Approximate memory needed to run the model, in megabytes.
+    pub memory_mb: u32,
+    /// Whether the model file is in the model directory.
+    pub installed: bool,
+}
+
+impl CatalogueModel {
+    /// Returns the download size in whole megabytes (MiB).
+    pub fn size_mb(&self) -> u64 {
+        self.size_bytes / (1024 * 1024)
+    }
+}
+
+// --------------------------------------------------------------------------
+/// The model catalogue a model file was downloaded from.
+///
+/// Recorded when a catalogue model is downloaded, so the Models view can
+/// show which upstream revision is installed.
+///
+/// # Fields
+///
+/// - `git_ref`: Commit of the model repository the file was downloaded at
+/// - `sha`: Checksum the catalogue published for the file at that commit
+/// - `downloaded_at`: Unix timestamp in milliseconds
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::ModelSource;
+///
+/// let source = ModelSource {
+///     git_ref: "f281eb45af861ab5e5297d23694b7d46e090c02c".to_string(),
+///     sha: "bd577a113a864445d4c299885e0cb97d4ba92b5f".to_string(),
+///     downloaded_at: 1_700_000_000_000,
+/// };
+/// assert_eq!(source.short_ref(), "f281eb4");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct ModelSource {
+    /// Commit of the model repository the file was downloaded at.
+    pub git_ref: String,
+    /// Checksum published for the file at that commit.
+    pub sha: String,
+    /// When the file was downloaded (Unix timestamp in milliseconds).
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub downloaded_at: u64,
+}
+
+impl ModelSource {
+    /// Returns the first seven characters of the commit, as git shows it.
+    pub fn short_ref(&self) -> &str {
+        self.git_ref
+            .char_indices()
+            .nth(7)
+            .map_or(&self.git_ref, |(end, _)| &self.git_ref[..end])
+    }
+}
+
+// --------------------------------------------------------------------------
+/// A Whisper model file found in the local model directory.
+///
+/// Returned by the `list_installed_models` command so the settings panel can
+/// show what is on disk, how much space each file takes, whether it still
+/// matches its published checksum and which catalogue it came from.
+///
+/// # Fields
+///
+/// - `filename`: File name within the model directory (e.g. `ggml-base.en.bin`)
+/// - `size_bytes`: Size of the file on disk
+/// - `checksum_valid`: Result of checksum verification, if it was performed
+/// - `source`: The catalogue the file was downloaded from, if recorded
+/// - `catalogue_sha`: Checksum the current catalogue publishes for the file
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::InstalledModel;
+///
+/// let model = InstalledModel {
+///     filename: "ggml-base.en.bin".to_string(),
+///     size_bytes: 147_964_211,
+///     checksum_valid: None,
+///     source: None,
+///     catalogue_sha: None,
+/// };
+/// assert_eq!(model.size_mb(), 141);
+/// assert!(!model.upstream_changed());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct InstalledModel {
+    /// File name within the model directory.
+    pub filename: String,
+    /// Size of the file on disk in bytes.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub size_bytes: u64,
+    /// `Some(true)` when the file matches its catalogue checksum, `Some(false)`
+    /// when it does not, and `None` when it was not verified or the file is
+    /// not a known catalogue model.
+    pub checksum_valid: Option<bool>,
+    /// The catalogue the file was downloaded from, if it was recorded.
+    #[serde(default)]
+    pub source: Option<ModelSource>,
+    /// Checksum the current catalogue publishes for this file, if it is a
+    /// catalogue model.
+    #[serde(default)]
+    pub catalogue_sha: Option<String>,
+}
+
+impl InstalledModel {
+    /// Returns the file size in whole megabytes (MiB).
+    pub fn size_mb(&self) -> u64 {
+        self.size_bytes / (1024 * 1024)
+    }
+
+    /// Returns `true` if the catalogue now publishes a different file than
+    /// the one that was downloaded, so the user may want to download it again.
+    pub fn upstream_changed(&self) -> bool {
+        match (&self.source, &self.catalogue_sha) {
+            (Some(source), Some(sha)) => !source.sha.eq_ignore_ascii_case(sha),
+            _ => false,
+        }
+    }
+}
+
+// --------------------------------------------------------------------------
+/// Disk space used by the local model directory.
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::ModelsDiskUsage;
+///
+/// let usage = ModelsDiskUsage {
+///     directory: "/tmp/models".to_string(),
+///     total_bytes: 0,
+///     model_count: 0,
+/// };
+/// assert_eq!(usage.model_count, 0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct ModelsDiskUsage {
+    /// Absolute path of the model directory that was scanned.
+    pub directory: String,
+    /// Combined size of all installed model files in bytes.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub total_bytes: u64,
+    /// Number of installed model files.
+    pub model_count: usize,
+}
+
+// --------------------------------------------------------------------------
+/// Whether the model directory has room for a download.
+///
+/// Returned by the `check_disk_space_for_model` command before a download
+/// starts, so a full disk is reported up front rather than mid-download.
+///
+/// # Fields
+///
+/// - `required_bytes`: Download size plus headroom for the rest of the system
+/// - `available_bytes`: Free space on the disk holding the model directory,
+///   or `None` if it could not be determined
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::DiskSpaceCheck;
+///
+/// let check = DiskSpaceCheck {
+///     required_bytes: 2048 * 1024 * 1024,
+///     available_bytes: Some(1024 * 1024 * 1024),
+/// };
+/// assert!(!check.is_sufficient());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct DiskSpaceCheck {
+    /// Bytes that must be free for the download to go ahead.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub required_bytes: u64,
+    /// Free bytes on the disk holding the model directory, if known.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number | null"))]
+    pub available_bytes: Option<u64>,
+}
+
+impl DiskSpaceCheck {
+    /// Returns `true` if there is enough free space, or it is unknown.
+    pub fn is_sufficient(&self) -> bool {
+        self.available_bytes
+            .is_none_or(|available| available >= self.required_bytes)
+    }
+
+    /// Explains a shortfall, e.g. "Not enough disk space to download
+    /// ggml-medium.bin: it needs 1.7 GB free, but only 900 MB is available."
+    pub fn shortfall_message(&self, filename: &str) -> String {
+        let available = self.available_bytes.unwrap_or_default();
+        format!(
+            "Not enough disk space to download {filename}: it needs {} free, but only {} is \
+             available.",
+            format_megabytes(self.required_bytes),
+            format_megabytes(available)
+        )
+    }
+}
+
+/// Formats a byte count as megabytes, or gigabytes from 1 GB upwards.
+fn format_megabytes(bytes: u64) -> String {
+    let mb = bytes as f64 / (1024.0 * 1024.0);
+    if mb >= 1024.0 {
+        format!("{:.1} GB", mb / 1024.0)
+    } else {
+        format!("{mb:.0} MB")
+    }
+}
+
+// --------------------------------------------------------------------------
+/// A kind of data Speakr keeps on disk, each in its own directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+pub enum StorageCategory {
+    /// Whisper models and the RNNoise weights.
+    Models,
+    /// Saved dictation recordings and those made from the debug panel.
+    Recordings,
+    /// Log files.
+    Logs,
+    /// Settings, their backups and saved profiles.
+    Settings,
+}
+
+impl StorageCategory {
+    /// Every category, in the order they are listed.
+    pub const ALL: [StorageCategory; 4] = [
+        StorageCategory::Models,
+        StorageCategory::Recordings,
+        StorageCategory::Logs,
+        StorageCategory::Settings,
+    ];
+
+    /// Returns the user-friendly name of the category.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            StorageCategory::Models => "Models",
+            StorageCategory::Recordings => "Recordings",
+            StorageCategory::Logs => "Logs",
+            StorageCategory::Settings => "Settings",
+        }
+    }
+
+    /// Describes what cleaning up the category removes.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the category cannot be cleaned up.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use speakr_types::StorageCategory;
+    ///
+    /// assert_eq!(StorageCategory::Settings.cleanup_label(), None);
+    /// assert!(StorageCategory::Logs.cleanup_label().is_some());
+    /// ```
+    pub fn cleanup_label(&self) -> Option<&'static str> {
+        match self {
+            StorageCategory::Models => Some("Remove unused models"),
+            StorageCategory::Recordings => Some("Delete recordings"),
+            StorageCategory::Logs => Some("Delete logs"),
+            StorageCategory::Settings => None,
+        }
+    }
+}
+
+// --------------------------------------------------------------------------
+/// Disk space used by one [`StorageCategory`].
+///
+/// # Fields
+///
+/// - `category`: The kind of data
+/// - `directory`: Absolute path of the directory that was scanned
+/// - `total_bytes`: Combined size of the files in it, including subdirectories
+/// - `file_count`: Number of files in it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct StorageCategoryUsage {
+    /// The kind of data.
+    pub category: StorageCategory,
+    /// Absolute path of the scanned directory.
+    pub directory: String,
+    /// Combined size of the files in bytes.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub total_bytes: u64,
+    /// Number of files.
+    pub file_count: usize,
+}
+
+// --------------------------------------------------------------------------
+/// Disk space used by Speakr, per [`StorageCategory`].
+///
+/// Returned by `get_storage_usage`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::{StorageCategory, StorageCategoryUsage, StorageUsage};
+///
+/// let usage = StorageUsage {
+///     categories: vec![StorageCategoryUsage {
+///         category: StorageCategory::Logs,
+///         directory: "/tmp/logs".to_string(),
+///         total_bytes: 2048,
+///         file_count: 2,
+///     }],
+/// };
+/// assert_eq!(usage.total_bytes(), 2048);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct StorageUsage {
+    /// Usage of each category, in [`StorageCategory::ALL`] order.
+    pub categories: Vec<StorageCategoryUsage>,
+}
+
+impl StorageUsage {
+    /// Combined size of every category in bytes.
+    pub fn total_bytes(&self) -> u64 {
+        self.categories
+            .iter()
+            .map(|category| category.total_bytes)
+            .sum()
+    }
+}
+
+// --------------------------------------------------------------------------
+/// Tauri event channel on which [`DownloadProgress`] updates are emitted.
+pub const MODEL_DOWNLOAD_PROGRESS_EVENT: &str = "model-download-progress";
+
+/// Event emitted with `true`/`false` when the model host becomes reachable
+/// or unreachable.
+pub const CONNECTIVITY_CHANGED_EVENT: &str = "connectivity-changed";
+
+// --------------------------------------------------------------------------
+/// Progress of a model download, with speed and ETA over a sliding window.
+///
+/// # Fields
+///
+/// - `filename`: Model file being downloaded
+/// - `downloaded_bytes`: Bytes received so far
+/// - `total_bytes`: Expected size, if the server reported one
+/// - `bytes_per_sec`: Recent transfer rate
+/// - `eta_secs`: Estimated seconds remaining, if the size and rate are known
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::DownloadProgress;
+///
+/// let progress = DownloadProgress {
+///     filename: "ggml-small.bin".to_string(),
+///     downloaded_bytes: 200 * 1024 * 1024,
+///     total_bytes: Some(400 * 1024 * 1024),
+///     bytes_per_sec: 10 * 1024 * 1024,
+///     eta_secs: Some(20),
+/// };
+/// assert_eq!(progress.percent(), Some(50));
+/// assert_eq!(progress.summary(), "50% · 10.0 MB/s · 20s left");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct DownloadProgress {
+    /// Model file being downloaded.
+    pub filename: String,
+    /// Bytes received so far.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub downloaded_bytes: u64,
+    /// Expected size in bytes, if the server reported one.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number | null"))]
+    pub total_bytes: Option<u64>,
+    /// Transfer rate over the recent sliding window, in bytes per second.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub bytes_per_sec: u64,
+    /// Estimated seconds remaining, if the size and rate are known.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number | null"))]
+    pub eta_secs: Option<u64>,
+}
+
+impl DownloadProgress {
+    /// Returns the completed percentage (0-100), if the total size is known.
+    pub fn percent(&self) -> Option<u8> {
+        let total = self.total_bytes.filter(|total| *total > 0)?;
+        Some((self.downloaded_bytes.min(total) * 100 / total) as u8)
+    }
+
+    /// Returns true once every expected byte has been received.
+    pub fn is_complete(&self) -> bool {
+        self.total_bytes
+            .is_some_and(|total| self.downloaded_bytes >= total)
+    }
+
+    /// Returns a short human-readable summary, e.g. "42% · 12.3 MB/s · 1m 05s left".
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::with_capacity(3);
+        match self.percent() {
+            Some(percent) => parts.push(format!("{percent}%")),
+            None => parts.push(format!(
+                "{:.1} MB",
+                self.downloaded_bytes as f64 / (1024.0 * 1024.0)
+            )),
+        }
+        parts.push(format!(
+            "{:.1} MB/s",
+            self.bytes_per_sec as f64 / (1024.0 * 1024.0)
+        ));
+        if let Some(eta) = self.eta_secs {
+            parts.push(match eta {
+                0..=59 => format!("{eta}s left"),
+                60..=3599 => format!("{}m {:02}s left", eta / 60, eta % 60),
+                _ => format!("{}h {:02}m left", eta / 3600, eta % 3600 / 60),
+            });
+        }
+        parts.join(" · ")
+    }
+}
+
+// ============================================================================
+// Transcription Types and Configuration
+// ============================================================================
+
+// --------------------------------------------------------------------------
+/// Performance modes for transcription processing.
+///
+/// Each mode represents a different balance between processing speed,
+/// accuracy, and resource consumption during transcription.
+///
+/// # Mode Characteristics
+///
+/// - `Speed`: Optimises for fastest processing with acceptable accuracy
+/// - `Balanced`: Balanced performance between speed and accuracy (default)
+/// - `Accuracy`: Prioritises highest accuracy, longer processing time
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::PerformanceMode;
+///
+/// let mode = PerformanceMode::Balanced;
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub enum PerformanceMode {
+    /// Optimised for speed with acceptable accuracy.
+    Speed,
+
+    /// Balanced performance between speed and accuracy (default).
+    #[default]
+    Balanced,
+
+    /// Prioritises highest accuracy over processing speed.
+    Accuracy,
+}
+
+// --------------------------------------------------------------------------
+/// Configuration for transcription processing behaviour.
+///
+/// Encapsulates all settings that control how transcription is performed,
+/// including model selection, language preferences, and performance tuning.
+///
+/// # Fields
+///
+/// - `model`: Selected Whisper model for processing
+/// - `language`: Optional language code for processing (ISO 639-1)
+/// - `auto_detect_language`: Whether to automatically detect audio language
+/// - `performance_mode`: Processing optimisation preference
+/// - `initial_prompt`: Optional context text that biases recognition
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::{ModelId, TranscriptionConfig, PerformanceMode};
+///
+/// let config = TranscriptionConfig {
+///     model: ModelId::new("medium"),
+///     language: Some("en".to_string()),
+///     auto_detect_language: false,
+///     performance_mode: PerformanceMode::Balanced,
+///     initial_prompt: Some("Speakr, Whisper, Tauri".to_string()),
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct TranscriptionConfig {
+    /// Selected Whisper model for processing.
+    pub model: ModelId,
+    /// Optional language code for processing (ISO 639-1 format).
+    pub language: Option<String>,
+    /// Whether to automatically detect the audio language.
+    pub auto_detect_language: bool,
+    /// Processing optimisation preference.
+    pub performance_mode: PerformanceMode,
+    /// Initial prompt passed to Whisper (e.g. the user's vocabulary).
+    #[serde(default)]
+    pub initial_prompt: Option<String>,
+}
+
+impl Default for TranscriptionConfig {
+    fn default() -> Self {
+        Self {
+            model: ModelId::default(),
+            language: None,
+            auto_detect_language: true,
+            performance_mode: PerformanceMode::default(),
+            initial_prompt: None,
+        }
+    }
+}
+
+impl TranscriptionConfig {
+    /// Builds a transcription configuration from the user's settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - The application settings to derive the config from
+    ///
+    /// # Returns
+    ///
+    /// A config using the selected model, language and vocabulary.
+    /// Language auto-detection is enabled only when the language is `"auto"`.
+    pub fn from_settings(settings: &AppSettings) -> Self {
+        let language = settings.transcription_language();
+        Self {
+            model: settings.model.clone(),
+            auto_detect_language: language.is_none(),
+            language,
+            performance_mode: PerformanceMode::default(),
+            initial_prompt: settings.initial_prompt(),
+        }
+    }
+}
+
+// --------------------------------------------------------------------------
+/// A transcription language supported by Whisper.
+///
+/// Used to populate language pickers in the frontend.
+///
+/// # Fields
+///
+/// - `code`: Language code as used by Whisper (mostly ISO 639-1)
+/// - `name`: English display name
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::LanguageOption;
+///
+/// let option = LanguageOption {
+///     code: "en".to_string(),
+///     name: "English".to_string(),
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct LanguageOption {
+    /// Language code as used by Whisper (mostly ISO 639-1).
+    pub code: String,
+    /// English display name.
+    pub name: String,
+}
+
+// --------------------------------------------------------------------------
+/// Specific error types for transcription operations.
+///
+/// Provides detailed error information for transcription failures,
+/// enabling appropriate user feedback and recovery strategies.
+///
+/// # Error Categories
+///
+/// - Model-related errors (not found, loading failed, corrupt, insufficient
+///   memory)
+/// - Processing errors (transcription failed, invalid audio format)
+/// - Language-related errors (unsupported language)
+/// - Network errors (model download failed)
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::{ModelId, TranscriptionError};
+///
+/// let error = TranscriptionError::ModelNotFound {
+///     model: ModelId::new("large-v3"),
+/// };
+/// ```
+#[derive(Error, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+pub enum TranscriptionError {
+    /// Requested model is not available locally.
+    #[error("Model not found: {model}")]
+    ModelNotFound { model: ModelId },
+
+    /// Model loading failed during initialisation.
+    #[error("Model loading failed: {0}")]
+    ModelLoadingFailed(String),
+
+    /// Model file does not match its published checksum.
+    #[error("Model file is corrupt: {model}")]
+    CorruptModel { model: ModelId },
+
+    /// Transcription processing failed during execution.
+    #[error("Transcription processing failed: {0}")]
+    ProcessingFailed(String),
+
+    /// Insufficient memory available for the requested model.
+    #[error("Insufficient memory for model: {model}")]
+    InsufficientMemory { model: ModelId },
+
+    /// Invalid audio format provided for transcription.
+    #[error("Invalid audio format: {0}")]
+    InvalidAudioFormat(String),
+
+    /// Language is not supported by the current model.
+    #[error("Language not supported: {language}")]
+    UnsupportedLanguage { language: String },
+
+    /// Model download from remote source failed.
+    #[error("Model download failed: {0}")]
+    DownloadFailed(String),
+
+    /// Transcription was aborted before it finished.
+    #[error("Transcription aborted")]
+    Aborted,
+}
+
+impl TranscriptionError {
+    /// Return a short, user-friendly error message suitable for UI display.
+    pub fn user_message(&self) -> String {
+        match self {
+            TranscriptionError::ModelNotFound { model } => {
+                format!("The requested {model} model is not available on this device.")
+            }
+            TranscriptionError::ModelLoadingFailed(_) => {
+                "Unable to load the selected speech model.".to_string()
+            }
+            TranscriptionError::CorruptModel { model } => {
+                format!("The {model} model file is damaged. Download it again to continue.")
+            }
+            TranscriptionError::ProcessingFailed(_) => "Transcription failed.".to_string(),
+            TranscriptionError::InsufficientMemory { model } => {
+                format!("Not enough memory to run the {model} model.")
+            }
+            TranscriptionError::InvalidAudioFormat(_) => "Unsupported audio format.".to_string(),
+            TranscriptionError::UnsupportedLanguage { language } => {
+                format!("The language '{language}' is not supported by the current model.")
+            }
+            TranscriptionError::DownloadFailed(_) => "Model download failed.".to_string(),
+            TranscriptionError::Aborted => "Transcription was cancelled.".to_string(),
+        }
+    }
+
+    /// Provide actionable suggestions that might resolve the error.
+    pub fn suggestions(&self) -> Vec<&'static str> {
+        match self {
+            TranscriptionError::ModelNotFound { .. } => vec![
+                "Download the model in Settings → Models",
+                "Ensure you are connected to the Internet",
+            ],
+            TranscriptionError::ModelLoadingFailed(_) => vec![
+                "Restart Speakr to retry initialisation",
+                "Try switching to a smaller model size",
+            ],
+            TranscriptionError::CorruptModel { .. } => vec![
+                "Download the model again",
+                "Check that the disk is not full or failing",
+            ],
+            TranscriptionError::ProcessingFailed(_) => vec![
+                "Retry the transcription operation",
+                "Make sure the microphone is not muted",
+            ],
+            TranscriptionError::InsufficientMemory { .. } => vec![
+                "Switch to a smaller model size in Settings",
+                "Close other memory-intensive applications then retry",
+            ],
+            TranscriptionError::InvalidAudioFormat(_) => {
+                vec!["Provide 16-kHz 16-bit mono PCM audio samples"]
+            }
+            TranscriptionError::UnsupportedLanguage { .. } => vec![
+                "Enable automatic language detection",
+                "Switch to a multilingual model variant",
+            ],
+            TranscriptionError::DownloadFailed(_) => {
+                vec!["Check your network connection", "Retry the download later"]
+            }
+            TranscriptionError::Aborted => vec![],
+        }
+    }
+}
+
+// --------------------------------------------------------------------------
+/// Tauri event channel on which the [`ModelId`] of a model is emitted when
+/// its file fails checksum verification on load.
+///
+/// The UI offers to download the model again.
+pub const CORRUPT_MODEL_EVENT: &str = "corrupt-model";
+
+// --------------------------------------------------------------------------
+/// Confidence below which a transcribed word is considered uncertain.
+///
+/// The UI highlights such words so the user can check them.
+pub const LOW_CONFIDENCE_THRESHOLD: f32 = 0.5;
+
+// --------------------------------------------------------------------------
+/// Single transcribed word with timing information.
+///
+/// # Fields
+///
+/// - `text`: The word, including any attached punctuation
+/// - `start_time`: Offset into the recording where the word begins
+/// - `end_time`: Offset into the recording where the word ends
+/// - `confidence`: Token probability (0.0-1.0) of the word
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::{TranscriptionWord, LOW_CONFIDENCE_THRESHOLD};
+/// use std::time::Duration;
+///
+/// let word = TranscriptionWord {
+///     text: "Hello".to_string(),
+///     start_time: Duration::from_millis(0),
+///     end_time: Duration::from_millis(400),
+///     confidence: 0.3,
+/// };
+/// assert!(word.is_low_confidence(LOW_CONFIDENCE_THRESHOLD));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct TranscriptionWord {
+    /// The transcribed word.
+    pub text: String,
+    /// Beginning timestamp of the word.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "{ secs: number, nanos: number }"))]
+    pub start_time: Duration,
+    /// Ending timestamp of the word.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "{ secs: number, nanos: number }"))]
+    pub end_time: Duration,
+    /// Confidence score (0.0-1.0) for this word.
+    pub confidence: f32,
+}
+
+impl TranscriptionWord {
+    /// Returns true if the word's confidence is below `threshold`.
+    pub fn is_low_confidence(&self, threshold: f32) -> bool {
+        self.confidence < threshold
+    }
+}
+
+// --------------------------------------------------------------------------
+/// Individual transcription segment with timing information.
+///
+/// Represents a portion of the transcribed text with precise timing
+/// and confidence metrics for detailed analysis.
+///
+/// # Fields
+///
+/// - `text`: The transcribed text for this segment
+/// - `start_time`: Beginning timestamp of the segment
+/// - `end_time`: Ending timestamp of the segment
+/// - `confidence`: Confidence score (0.0-1.0) for this segment
+/// - `words`: Per-word timestamps and confidence, if the engine provides them
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::TranscriptionSegment;
+/// use std::time::Duration;
+///
+/// let segment = TranscriptionSegment {
+///     text: "Hello world".to_string(),
+///     start_time: Duration::from_millis(0),
+///     end_time: Duration::from_millis(1000),
+///     confidence: 0.95,
+///     words: vec![],
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct TranscriptionSegment {
+    /// The transcribed text for this segment.
+    pub text: String,
+    /// Beginning timestamp of the segment.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "{ secs: number, nanos: number }"))]
+    pub start_time: Duration,
+    /// Ending timestamp of the segment.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "{ secs: number, nanos: number }"))]
+    pub end_time: Duration,
+    /// Confidence score (0.0-1.0) for this segment.
+    pub confidence: f32,
+    /// Words of the segment with their own timing and confidence.
+    #[serde(default)]
+    pub words: Vec<TranscriptionWord>,
+}
+
+// --------------------------------------------------------------------------
+/// Complete transcription result with metadata and segments.
+///
+/// Contains the full output of a transcription operation, including
+/// the transcribed text, confidence metrics, timing information,
+/// and detailed segment breakdown.
+///
+/// # Fields
+///
+/// - `text`: Complete transcribed text
+/// - `language`: Detected or specified language code
+/// - `confidence`: Overall confidence score (0.0-1.0)
+/// - `avg_logprob`: Average token log-probability, if the engine reports it
+/// - `processing_time`: Total time taken for transcription
+/// - `memory_delta_bytes`: Memory delta in bytes consumed during transcription
+/// - `model_used`: Model size that performed the transcription
+/// - `segments`: Detailed breakdown of transcription segments
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::{ModelId, TranscriptionResult};
+/// use std::time::Duration;
+///
+/// let result = TranscriptionResult {
+///     text: "Hello world".to_string(),
+///     language: Some("en".to_string()),
+///     confidence: 0.95,
+///     avg_logprob: Some(-0.05),
+///     processing_time: Duration::from_millis(500),
+///     memory_delta_bytes: 0,
+///     model_used: ModelId::new("medium"),
+///     segments: vec![],
+/// };
+/// assert!(result.low_confidence_words(0.5).next().is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct TranscriptionResult {
+    /// Complete transcribed text.
+    pub text: String,
+    /// Detected or specified language code (ISO 639-1).
+    pub language: Option<String>,
+    /// Overall confidence score (0.0-1.0) for the transcription.
+    pub confidence: f32,
+    /// Average log-probability of the decoded tokens (0.0 is certain).
+    #[serde(default)]
+    pub avg_logprob: Option<f32>,
+    /// Total time taken for transcription processing.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "{ secs: number, nanos: number }"))]
+    pub processing_time: Duration,
+    /// Memory delta in bytes consumed during transcription.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub memory_delta_bytes: u64,
+    /// Model that performed the transcription.
+    pub model_used: ModelId,
+    /// Detailed breakdown of transcription segments with timing.
+    pub segments: Vec<TranscriptionSegment>,
+}
+
+impl TranscriptionResult {
+    /// Returns all words of the transcription, in order.
+    pub fn words(&self) -> impl Iterator<Item = &TranscriptionWord> {
+        self.segments.iter().flat_map(|segment| &segment.words)
+    }
+
+    /// Returns the words whose confidence is below `threshold`.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - Usually [`LOW_CONFIDENCE_THRESHOLD`]
+    pub fn low_confidence_words(&self, threshold: f32) -> impl Iterator<Item = &TranscriptionWord> {
+        self.words()
+            .filter(move |word| word.is_low_confidence(threshold))
+    }
+
+    /// Returns where the last segment ends, or zero without segments.
+    pub fn end_time(&self) -> Duration {
+        self.segments
+            .iter()
+            .map(|segment| segment.end_time)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+// --------------------------------------------------------------------------
+/// Caption file format a transcription can be exported as.
+///
+/// # Variants
+///
+/// - `Srt`: SubRip subtitles (`.srt`)
+/// - `WebVtt`: Web Video Text Tracks (`.vtt`)
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::SubtitleFormat;
+///
+/// assert_eq!(SubtitleFormat::WebVtt.extension(), "vtt");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+pub enum SubtitleFormat {
+    /// SubRip subtitles.
+    Srt,
+    /// Web Video Text Tracks.
+    WebVtt,
+}
+
+impl SubtitleFormat {
+    /// File extension used for this format, without the dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            SubtitleFormat::Srt => "srt",
+            SubtitleFormat::WebVtt => "vtt",
+        }
+    }
+}
+
+// --------------------------------------------------------------------------
+/// Tauri event channel on which each [`TranscriptionResult`] is emitted,
+/// including segments and word timings.
+pub const TRANSCRIPTION_RESULT_EVENT: &str = "transcription-result";
+
+// --------------------------------------------------------------------------
+/// Tauri event channel on which [`BatchProgress`] updates are emitted.
+pub const BATCH_PROGRESS_EVENT: &str = "batch-progress";
+
+/// Number of files a batch transcribes at once unless told otherwise.
+pub const DEFAULT_BATCH_PARALLELISM: usize = 1;
+
+/// Most files a batch may transcribe at once.
+///
+/// Each file in flight holds its decoded audio in memory and a share of the
+/// CPU, so parallelism is kept small.
+pub const MAX_BATCH_PARALLELISM: usize = 4;
+
+// --------------------------------------------------------------------------
+/// A batch transcription of the audio files in a folder.
+///
+/// Results are written next to each file as `.txt` and `.srt` sidecars.
+///
+/// # Fields
+///
+/// - `id`: Identifies the job in [`BatchProgress`] events
+/// - `folder`: The folder that was queued
+/// - `files`: The audio files to transcribe, in processing order
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::BatchJob;
+///
+/// let job = BatchJob {
+///     id: 1,
+///     folder: "/Users/me/Recordings".to_string(),
+///     files: vec!["/Users/me/Recordings/memo.m4a".to_string()],
+/// };
+/// assert_eq!(job.files.len(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct BatchJob {
+    /// Identifier of the job.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub id: u64,
+    /// The folder whose audio files are transcribed.
+    pub folder: String,
+    /// Paths of the audio files, in processing order.
+    pub files: Vec<String>,
+}
+
+// --------------------------------------------------------------------------
+/// State of one file in a batch.
+///
+/// # Variants
+///
+/// - `Transcribing`: The file is being decoded and transcribed
+/// - `Done`: The sidecar files were written
+/// - `Failed(String)`: The file could not be transcribed, with the reason
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+pub enum BatchFileStatus {
+    /// The file is being decoded and transcribed.
+    Transcribing,
+    /// The sidecar files were written.
+    Done,
+    /// The file could not be transcribed.
+    Failed(String),
+}
+
+// --------------------------------------------------------------------------
+/// Progress of a batch, emitted on [`BATCH_PROGRESS_EVENT`] whenever a file
+/// starts or finishes.
+///
+/// # Fields
+///
+/// - `job_id`: The [`BatchJob`] this update belongs to
+/// - `file`: Path of the file whose state changed
+/// - `status`: The file's new state
+/// - `completed`: Files finished so far, successfully or not
+/// - `total`: Files in the job
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::{BatchFileStatus, BatchProgress};
+///
+/// let progress = BatchProgress {
+///     job_id: 1,
+///     file: "/Users/me/Recordings/memo.m4a".to_string(),
+///     status: BatchFileStatus::Done,
+///     completed: 3,
+///     total: 3,
+/// };
+/// assert!(progress.is_finished());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct BatchProgress {
+    /// Identifier of the job.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub job_id: u64,
+    /// Path of the file whose state changed.
+    pub file: String,
+    /// The file's new state.
+    pub status: BatchFileStatus,
+    /// Number of files finished so far.
+    pub completed: usize,
+    /// Number of files in the job.
+    pub total: usize,
+}
+
+impl BatchProgress {
+    /// Returns true once every file in the job has finished.
+    pub fn is_finished(&self) -> bool {
+        self.completed >= self.total
+    }
+}
+
+// ============================================================================
+// Status and Service Management
+// ============================================================================
+
+// --------------------------------------------------------------------------
+/// Status of an individual service component in the backend.
+///
+/// Provides detailed status information for each service, enabling
+/// the frontend to display appropriate UI states and error messages.
+///
+/// # Status Values
+///
+/// - `Ready`: Service is operational and available
+/// - `Starting`: Service is initialising
+/// - `Error(String)`: Service failed with specific error details
+/// - `Unavailable`: Service is not available (e.g., permissions)
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::ServiceStatus;
+///
+/// let status = ServiceStatus::Ready;
+/// assert!(status.is_ready());
+/// assert_eq!(status.display_name(), "Ready");
+///
+/// let error_status = ServiceStatus::Error("Permission denied".to_string());
+/// assert!(!error_status.is_ready());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub enum ServiceStatus {
+    /// Service is ready and operational.
+    Ready,
+    /// Service is currently starting up.
+    Starting,
+    /// Service encountered an error with details.
+    Error(String),
+    /// Service is unavailable (e.g., missing permissions).
+    Unavailable,
+}
+
+impl Default for ServiceStatus {
+    fn default() -> Self {
+        Self::Starting
+    }
+}
+
+impl ServiceStatus {
+    /// Returns the user-friendly display name for the status.
+    ///
+    /// # Returns
+    ///
+    /// A static string representing the status for UI display.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use speakr_types::ServiceStatus;
+    ///
+    /// assert_eq!(ServiceStatus::Ready.display_name(), "Ready");
+    /// assert_eq!(ServiceStatus::Error("test".to_string()).display_name(), "Error");
+    /// ```
+    pub fn display_name(&self) -> &str {
+        match self {
+            ServiceStatus::Ready => "Ready",
+            ServiceStatus::Starting => "Starting",
+            ServiceStatus::Error(_) => "Error",
+            ServiceStatus::Unavailable => "Unavailable",
+        }
+    }
+
+    /// Returns true if the service is ready for operation.
+    ///
+    /// # Returns
+    ///
+    /// Boolean indicating operational readiness.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use speakr_types::ServiceStatus;
+    ///
+    /// assert!(ServiceStatus::Ready.is_ready());
+    /// assert!(!ServiceStatus::Starting.is_ready());
+    /// ```
+    pub fn is_ready(&self) -> bool {
+        matches!(self, ServiceStatus::Ready)
+    }
+}
+
+// --------------------------------------------------------------------------
+/// Overall backend system status combining all service states.
+///
+/// Provides a comprehensive view of system health, enabling the frontend
+/// to determine overall application readiness and display appropriate
+/// status indicators to users.
+///
+/// # Service Components
+///
+/// - `audio_capture`: Microphone access and recording capability
+/// - `transcription`: Whisper model loading and processing
+/// - `text_injection`: Keyboard simulation and text insertion
+/// - `timestamp`: Unix timestamp in milliseconds for status age
+/// - `limitations`: What Speakr cannot do on this desktop, for the UI to explain
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::{BackendStatus, ServiceStatus};
+///
+/// let status = BackendStatus::new_ready();
+/// assert!(status.is_ready());
+///
+/// let partial_status = BackendStatus {
+///     audio_capture: ServiceStatus::Ready,
+///     transcription: ServiceStatus::Starting,
+///     text_injection: ServiceStatus::Ready,
+///     timestamp: 12345,
+///     limitations: vec![],
+/// };
+/// assert!(!partial_status.is_ready());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct BackendStatus {
+    /// Status of audio capture service (microphone access).
+    pub audio_capture: ServiceStatus,
+    /// Status of transcription service (Whisper model).
+    pub transcription: ServiceStatus,
+    /// Status of text injection service (keyboard simulation).
+    pub text_injection: ServiceStatus,
+    /// Unix timestamp in milliseconds when status was created.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub timestamp: u64,
+    /// Features the platform does not support, e.g. global hot-keys on a
+    /// Wayland desktop without the shortcuts portal.
+    #[serde(default)]
+    pub limitations: Vec<PlatformLimitation>,
+}
+
+impl BackendStatus {
+    /// Returns true if all services are ready for operation.
+    ///
+    /// # Returns
+    ///
+    /// Boolean indicating if the entire system is operational.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use speakr_types::BackendStatus;
+    ///
+    /// let ready_status = BackendStatus::new_ready();
+    /// assert!(ready_status.is_ready());
+    /// ```
+    pub fn is_ready(&self) -> bool {
+        self.audio_capture.is_ready()
+            && self.transcription.is_ready()
+            && self.text_injection.is_ready()
+    }
+
+    /// Creates a new status with all services in starting state.
+    ///
+    /// # Returns
+    ///
+    /// BackendStatus with all services marked as Starting.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use speakr_types::BackendStatus;
+    ///
+    /// let status = BackendStatus::new_starting();
+    /// assert!(!status.is_ready());
+    /// ```
+    pub fn new_starting() -> Self {
+        Self {
+            audio_capture: ServiceStatus::Starting,
+            transcription: ServiceStatus::Starting,
+            text_injection: ServiceStatus::Starting,
+            timestamp: chrono::Utc::now().timestamp_millis() as u64,
+            limitations: Vec::new(),
+        }
+    }
+
+    /// Creates a new status with all services ready.
+    ///
+    /// # Returns
+    ///
+    /// BackendStatus with all services marked as Ready.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use speakr_types::BackendStatus;
+    ///
+    /// let status = BackendStatus::new_ready();
+    /// assert!(status.is_ready());
+    /// ```
+    pub fn new_ready() -> Self {
+        Self {
+            audio_capture: ServiceStatus::Ready,
+            transcription: ServiceStatus::Ready,
+            text_injection: ServiceStatus::Ready,
+            timestamp: chrono::Utc::now().timestamp_millis() as u64,
+            limitations: Vec::new(),
+        }
+    }
+}
+
+// --------------------------------------------------------------------------
+/// Something Speakr cannot do on the desktop it runs on.
+///
+/// Detected at launch and reported in [`BackendStatus::limitations`] so the
+/// UI can explain why, and what to install.
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::PlatformLimitation;
+///
+/// assert!(PlatformLimitation::NoKeystrokeTool.description().contains("xdotool"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+pub enum PlatformLimitation {
+    /// Global hot-keys cannot be registered: the Wayland desktop offers no
+    /// global shortcuts portal.
+    NoGlobalShortcuts,
+    /// Text cannot be typed or pasted: no keystroke tool was found.
+    NoKeystrokeTool,
+    /// Text can be typed but not pasted: no clipboard tool was found.
+    NoClipboardTool,
+}
+
+impl PlatformLimitation {
+    /// Explains the limitation to the user, with what to install.
+    pub fn description(&self) -> &'static str {
+        match self {
+            PlatformLimitation::NoGlobalShortcuts => {
+                "Global hot-keys are unavailable: this Wayland desktop has no global shortcuts portal. Bind a desktop shortcut to Speakr instead."
+            }
+            PlatformLimitation::NoKeystrokeTool => {
+                "Text cannot be inserted: install xdotool (X11), or wtype or ydotool (Wayland)."
+            }
+            PlatformLimitation::NoClipboardTool => {
+                "Text cannot be pasted, only typed: install xclip or xsel (X11), or wl-clipboard (Wayland)."
+            }
+        }
+    }
+}
+
+// --------------------------------------------------------------------------
+/// Tauri event channel on which [`PipelineEvent`]s are emitted.
+pub const PIPELINE_EVENT: &str = "pipeline-event";
+
+// --------------------------------------------------------------------------
+/// Progress of a single dictation through the pipeline.
+///
+/// Where [`BackendStatus`] reports whether each service is available, these
+/// events describe what the current dictation is doing, so the frontend can
+/// show real-time progress. They are emitted on [`PIPELINE_EVENT`].
+///
+/// # Variants
+///
+/// - `RecordingStarted`: The microphone is capturing audio
+/// - `RecordingLevel(f32)`: Current input level (RMS, 0.0-1.0)
+/// - `TranscriptionStarted`: Captured audio is being transcribed
+/// - `PartialText(String)`: Text transcribed so far, before post-processing
+/// - `Injected`: The final text was inserted into the target application
+/// - `InjectionSuppressed(String)`: The text was deliberately not typed,
+///   for the given reason, and is waiting in the pending queue
+/// - `Failed(AppError)`: The dictation stopped with an error
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::{AppError, PipelineEvent};
+///
+/// let event = PipelineEvent::Failed(AppError::AudioCapture("No microphone".to_string()));
+/// assert_eq!(event.display_name(), "Failed");
+/// assert!(event.is_finished());
+/// assert!(!PipelineEvent::RecordingLevel(0.4).is_finished());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+pub enum PipelineEvent {
+    /// The microphone is capturing audio.
+    RecordingStarted,
+    /// Current input level as an RMS value between 0.0 and 1.0.
+    RecordingLevel(f32),
+    /// Captured audio is being transcribed.
+    TranscriptionStarted,
+    /// Text transcribed so far, before post-processing.
+    PartialText(String),
+    /// The final text was inserted into the target application.
+    Injected,
+    /// The text was not typed for this reason, e.g. because a password field
+    /// has focus, and is waiting in the pending queue.
+    InjectionSuppressed(String),
+    /// The dictation stopped with an error.
+    Failed(AppError),
+}
+
+impl PipelineEvent {
+    /// Returns the user-friendly name of the pipeline stage.
+    pub fn display_name(&self) -> &str {
+        match self {
+            PipelineEvent::RecordingStarted | PipelineEvent::RecordingLevel(_) => "Recording",
+            PipelineEvent::TranscriptionStarted | PipelineEvent::PartialText(_) => "Transcribing",
+            PipelineEvent::Injected => "Inserted",
+            PipelineEvent::InjectionSuppressed(_) => "Suppressed",
+            PipelineEvent::Failed(_) => "Failed",
+        }
+    }
+
+    /// Returns true if no further events follow for this dictation.
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            PipelineEvent::Injected
+                | PipelineEvent::InjectionSuppressed(_)
+                | PipelineEvent::Failed(_)
+        )
+    }
+}
+
+// --------------------------------------------------------------------------
+/// Tauri event channel on which a [`DictationLatency`] is emitted after each
+/// completed dictation.
+pub const DICTATION_LATENCY_EVENT: &str = "dictation-latency";
+
+// --------------------------------------------------------------------------
+/// How long each stage of a completed dictation took.
+///
+/// # Fields
+///
+/// - `capture_ms`: Recording, from the hot-key to the end of capture
+/// - `transcribe_ms`: Transcription, including loading the model if needed
+/// - `process_ms`: Post-processing and output formatting
+/// - `inject_ms`: Clipboard copy and text injection
+/// - `model`: The model that transcribed the audio
+/// - `input_device`: The microphone the audio was recorded from, if known
+/// - `processing_stages`: Each step of post-processing and formatting, in
+///   the order it ran
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::{DictationLatency, ModelId};
+///
+/// let latency = DictationLatency {
+///     capture_ms: 4_000,
+///     transcribe_ms: 850,
+///     process_ms: 3,
+///     inject_ms: 120,
+///     model: ModelId::new("small"),
+///     input_device: Some("USB Headset".to_string()),
+///     processing_stages: Vec::new(),
+/// };
+/// assert_eq!(latency.total_ms(), 4_973);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct DictationLatency {
+    /// Time spent recording.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub capture_ms: u64,
+    /// Time spent transcribing.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub transcribe_ms: u64,
+    /// Time spent post-processing and formatting the text.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub process_ms: u64,
+    /// Time spent delivering the text.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub inject_ms: u64,
+    /// The model that transcribed the audio.
+    pub model: ModelId,
+    /// The microphone the audio was recorded from, if known.
+    #[serde(default)]
+    pub input_device: Option<String>,
+    /// Each step of post-processing and formatting, in the order it ran.
+    #[serde(default)]
+    pub processing_stages: Vec<ProcessingStage>,
+}
+
+impl DictationLatency {
+    /// Returns the total time of all stages.
+    pub fn total_ms(&self) -> u64 {
+        self.capture_ms + self.transcribe_ms + self.process_ms + self.inject_ms
+    }
+}
+
+// --------------------------------------------------------------------------
+/// One step of post-processing a transcript, e.g. a replacement rule or a
+/// plugin, and how long it took.
+///
+/// Steps run one after another, each on the previous step's output. A step
+/// that only looks within a line may split long text into segments that
+/// are processed concurrently.
+///
+/// # Fields
+///
+/// - `name`: What the step does, e.g. "Strip filler words"
+/// - `duration_us`: Time the step took, in microseconds
+/// - `changed`: Whether the step changed the text
+/// - `segments`: How many segments were processed concurrently; 1 when the
+///   step ran on the whole text
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct ProcessingStage {
+    /// What the step does.
+    pub name: String,
+    /// Time the step took, in microseconds.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub duration_us: u64,
+    /// Whether the step changed the text.
+    pub changed: bool,
+    /// How many segments were processed concurrently.
+    #[serde(default = "default_segments")]
+    pub segments: usize,
+}
+
+/// Provides the segment count of stages recorded before segments were
+/// tracked.
+fn default_segments() -> usize {
+    1
+}
+
+// --------------------------------------------------------------------------
+/// Summary of one latency over the recent dictations, in milliseconds.
+///
+/// All values are 0 when no dictation has been measured.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct LatencyStats {
+    /// The most recent dictation.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub last_ms: u64,
+    /// The fastest dictation.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub min_ms: u64,
+    /// The average over the window.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub mean_ms: u64,
+    /// The 95th percentile over the window.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub p95_ms: u64,
+    /// The slowest dictation.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub max_ms: u64,
+}
+
+// --------------------------------------------------------------------------
+/// Latency of the dictation pipeline over a rolling window of dictations.
+///
+/// # Fields
+///
+/// - `window`: Number of dictations the statistics are kept for
+/// - `samples`: Number of dictations measured so far, up to `window`
+/// - `hotkey_to_record_start`: From the hot-key press to recording starting
+/// - `record_stop_to_first_token`: From recording stopping to the first
+///   transcribed text
+/// - `end_to_end`: From the hot-key press to the text being delivered
+/// - `processing_stages`: Each post-processing step of the last dictation,
+///   in the order it ran
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct PerformanceMetrics {
+    /// Number of dictations the statistics are kept for.
+    pub window: usize,
+    /// Number of dictations measured, up to `window`.
+    pub samples: usize,
+    /// From the hot-key press to recording starting.
+    pub hotkey_to_record_start: LatencyStats,
+    /// From recording stopping to the first transcribed text.
+    pub record_stop_to_first_token: LatencyStats,
+    /// From the hot-key press to the text being delivered.
+    pub end_to_end: LatencyStats,
+    /// Each post-processing step of the last dictation, in order.
+    #[serde(default)]
+    pub processing_stages: Vec<ProcessingStage>,
+}
+
+// --------------------------------------------------------------------------
+/// Tauri event channel on which a [`SettingsRecovery`] is emitted when the
+/// settings could not be read and were reset to defaults.
+pub const SETTINGS_RECOVERED_EVENT: &str = "settings-recovered";
+
+// --------------------------------------------------------------------------
+/// Notice that the settings file and its backup were unreadable, so Speakr
+/// started with default settings.
+///
+/// It is shown to the user once and kept until they dismiss it.
+///
+/// # Fields
+///
+/// - `quarantined_path`: Where the unreadable settings file was moved to
+/// - `error`: Why the settings and backup could not be loaded
+/// - `recovered_at`: When the defaults were restored, in Unix milliseconds
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::SettingsRecovery;
+///
+/// let recovery = SettingsRecovery {
+///     quarantined_path: "/tmp/settings.corrupt-1700000000000.json".to_string(),
+///     error: "expected value at line 1 column 1".to_string(),
+///     recovered_at: 1_700_000_000_000,
+/// };
+/// assert!(recovery.quarantined_path.ends_with(".json"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct SettingsRecovery {
+    /// Where the unreadable settings file was moved to.
+    pub quarantined_path: String,
+    /// Why the settings and backup could not be loaded.
+    pub error: String,
+    /// When the defaults were restored, in Unix milliseconds.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub recovered_at: u64,
+}
+
+// --------------------------------------------------------------------------
+/// Tauri event channel on which a [`CrashNotice`] is emitted at startup when
+/// the previous run crashed.
+pub const CRASH_REPORTED_EVENT: &str = "crash-reported";
+
+// --------------------------------------------------------------------------
+/// Notice that the previous run of Speakr crashed and a crash report was
+/// saved locally. Reports are never uploaded.
+///
+/// It is shown to the user on the next launch and kept until they dismiss it.
+///
+/// # Fields
+///
+/// - `report_path`: Where the crash report was saved
+/// - `summary`: One line describing the crash, without any dictated text
+/// - `crashed_at`: When the crash happened, in Unix milliseconds
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::CrashNotice;
+///
+/// let notice = CrashNotice {
+///     report_path: "/tmp/crashes/crash-1700000000000.txt".to_string(),
+///     summary: "Panic at src/workflow.rs:120".to_string(),
+///     crashed_at: 1_700_000_000_000,
+/// };
+/// assert!(notice.report_path.ends_with(".txt"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct CrashNotice {
+    /// Where the crash report was saved.
+    pub report_path: String,
+    /// One line describing the crash, without any dictated text.
+    pub summary: String,
+    /// When the crash happened, in Unix milliseconds.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub crashed_at: u64,
+}
+
+// --------------------------------------------------------------------------
+/// Tauri event channel on which the [`PrivacyMode`] is emitted whenever it
+/// is switched on or off, including when it expires.
+pub const PRIVACY_MODE_CHANGED_EVENT: &str = "privacy-mode-changed";
+
+// --------------------------------------------------------------------------
+/// Whether privacy mode is on, and until when.
+///
+/// While privacy mode is on, dictations are still typed but their text is
+/// never written to the history or the logs, and no recordings are saved. It
+/// switches itself off after the `privacy_mode_mins` setting.
+///
+/// # Fields
+///
+/// - `active`: Whether privacy mode is on
+/// - `expires_at`: When it switches itself off, in Unix milliseconds
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::PrivacyMode;
+///
+/// let mode = PrivacyMode::until(1_700_000_000_000);
+/// assert!(mode.active);
+/// assert!(!PrivacyMode::default().active);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct PrivacyMode {
+    /// Whether privacy mode is on.
+    pub active: bool,
+    /// When privacy mode switches itself off, in Unix milliseconds.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number | null"))]
+    pub expires_at: Option<u64>,
+}
+
+impl PrivacyMode {
+    /// Privacy mode switched on until `expires_at`.
+    pub fn until(expires_at: u64) -> Self {
+        Self {
+            active: true,
+            expires_at: Some(expires_at),
+        }
+    }
+}
+
+// --------------------------------------------------------------------------
+/// Tauri event channel on which the pending queue (a list of
+/// [`PendingDictation`]) is emitted whenever it changes.
+pub const PENDING_CHANGED_EVENT: &str = "pending-changed";
+
+// --------------------------------------------------------------------------
+/// Why a dictation was held for review instead of being delivered.
+///
+/// # Variants
+///
+/// - `DryRun`: Dry-run mode is on, so nothing is delivered automatically
+/// - `InjectionFailed`: Typing the text failed, with the error message
+/// - `InjectionSuppressed`: Typing was not attempted, for the given reason
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+pub enum PendingReason {
+    /// Dry-run mode held the dictation.
+    DryRun,
+    /// Text injection failed with this message.
+    InjectionFailed(String),
+    /// Text injection was not attempted, for this reason.
+    InjectionSuppressed(String),
+}
+
+impl PendingReason {
+    /// Returns a short user-friendly explanation.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use speakr_types::PendingReason;
+    ///
+    /// assert_eq!(PendingReason::DryRun.description(), "Dry run");
+    /// ```
+    pub fn description(&self) -> String {
+        match self {
+            PendingReason::DryRun => "Dry run".to_string(),
+            PendingReason::InjectionFailed(message) => format!("Typing failed: {message}"),
+            PendingReason::InjectionSuppressed(reason) => format!("Not typed: {reason}"),
+        }
+    }
+}
+
+// --------------------------------------------------------------------------
+/// A dictation waiting in the pending queue to be reviewed and injected.
+///
+/// # Fields
+///
+/// - `id`: Identifies the dictation within the queue
+/// - `text`: The transcript, as edited by the user
+/// - `created_at`: When it was dictated, in Unix milliseconds
+/// - `reason`: Why it was held
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::{PendingDictation, PendingReason};
+///
+/// let pending = PendingDictation {
+///     id: 1,
+///     text: "Meeting notes".to_string(),
+///     created_at: 1_700_000_000_000,
+///     reason: PendingReason::DryRun,
+/// };
+/// assert_eq!(pending.reason, PendingReason::DryRun);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct PendingDictation {
+    /// Identifies the dictation within the queue.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub id: u64,
+    /// The transcript.
+    pub text: String,
+    /// When it was dictated, in Unix milliseconds.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub created_at: u64,
+    /// Why it was held.
+    pub reason: PendingReason,
+}
+
+// --------------------------------------------------------------------------
+/// Tauri event channel on which the dictation history (a list of
+/// [`HistoryEntry`], newest first) is emitted whenever it changes.
+pub const HISTORY_CHANGED_EVENT: &str = "history-changed";
+
+// --------------------------------------------------------------------------
+/// A completed dictation kept in the history.
+///
+/// # Fields
+///
+/// * `id` - Identifies the entry within the history
+/// * `created_at` - When it was dictated, in Unix milliseconds
+/// * `text` - The transcript as it was delivered
+/// * `corrected_text` - The user's correction, if they edited it
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::HistoryEntry;
+///
+/// let entry = HistoryEntry {
+///     id: 1,
+///     created_at: 0,
+///     text: "deploy to cubernetes".to_string(),
+///     corrected_text: Some("deploy to Kubernetes".to_string()),
+/// };
+/// assert_eq!(entry.current_text(), "deploy to Kubernetes");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct HistoryEntry {
+    /// Identifies the entry within the history.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub id: u64,
+    /// When it was dictated, in Unix milliseconds.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub created_at: u64,
+    /// The transcript as it was delivered.
+    pub text: String,
+    /// The user's correction, if they edited the transcript.
+    #[serde(default)]
+    pub corrected_text: Option<String>,
+}
+
+impl HistoryEntry {
+    /// Returns the corrected text, or the original if it was not corrected.
+    pub fn current_text(&self) -> &str {
+        self.corrected_text.as_deref().unwrap_or(&self.text)
+    }
+}
+
+// --------------------------------------------------------------------------
+/// A word the user keeps correcting, suggested as a vocabulary term.
+///
+/// # Fields
+///
+/// * `term` - The word as the user corrected it
+/// * `corrections` - Number of history entries in which it was corrected
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct VocabularySuggestion {
+    /// The word as the user corrected it.
+    pub term: String,
+    /// Number of history entries in which it was corrected.
+    pub corrections: u32,
+}
+
+// --------------------------------------------------------------------------
+/// A macOS privacy permission Speakr needs to work.
+///
+/// # Variants
+///
+/// - `Microphone`: Recording audio for dictation
+/// - `Accessibility`: Typing the transcribed text into other applications
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::PermissionKind;
+///
+/// let url = PermissionKind::Microphone.settings_url();
+/// assert!(url.ends_with("Privacy_Microphone"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+pub enum PermissionKind {
+    /// Access to the microphone.
+    Microphone,
+    /// Control of the computer through accessibility features.
+    Accessibility,
+}
+
+impl PermissionKind {
+    /// Every permission, in the order onboarding asks for them.
+    pub const ALL: [PermissionKind; 2] =
+        [PermissionKind::Microphone, PermissionKind::Accessibility];
+
+    /// Returns the user-friendly name of the permission.
+    pub fn display_name(self) -> &'static str {
+        match self {
+            PermissionKind::Microphone => "Microphone",
+            PermissionKind::Accessibility => "Accessibility",
+        }
+    }
+
+    /// Returns the URL that opens this permission's pane in System Settings.
+    pub fn settings_url(self) -> &'static str {
+        match self {
+            PermissionKind::Microphone => {
+                "x-apple.systempreferences:com.apple.preference.security?Privacy_Microphone"
+            }
+            PermissionKind::Accessibility => {
+                "x-apple.systempreferences:com.apple.preference.security?Privacy_Accessibility"
+            }
+        }
+    }
+}
+
+/// Tauri event channel on which a [`PermissionKind`] is emitted when a
+/// missing permission is granted while Speakr is running.
+pub const PERMISSION_GRANTED_EVENT: &str = "permission-granted";
+
+// ============================================================================
+// Local API
+// ============================================================================
+
+// --------------------------------------------------------------------------
+/// A request sent to the local API by another tool on the same machine.
+///
+/// Requests and responses are exchanged as one JSON document per line over a
+/// loopback TCP connection on [`DEFAULT_API_PORT`]. The same port also
+/// answers a plain HTTP `GET /status` with an [`ApiStatus`], for tools that
+/// only speak HTTP.
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::ApiRequest;
+///
+/// let line = serde_json::to_string(&ApiRequest::StartDictation).unwrap();
+/// assert_eq!(line, r#""StartDictation""#);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+pub enum ApiRequest {
+    /// Start a dictation, as if the hot-key had been pressed.
+    StartDictation,
+    /// Cancel the dictation in flight, if any.
+    CancelDictation,
+    /// Fetch the text produced by the most recent completed dictation.
+    LastTranscript,
+    /// Fetch a read-only summary of what Speakr is doing.
+    Status,
+}
+
+// --------------------------------------------------------------------------
+/// Summary of the most recent completed dictation.
+///
+/// The transcript itself is left out; fetch it with
+/// [`ApiRequest::LastTranscript`].
+///
+/// # Fields
+///
+/// - `completed_at`: When the dictation completed, in Unix milliseconds
+/// - `word_count`: Number of words delivered
+/// - `latency`: Time each stage took and the model used
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct DictationSummary {
+    /// When the dictation completed, in Unix milliseconds.
+    #[cfg_attr(feature = "ts-bindings", ts(type = "number"))]
+    pub completed_at: u64,
+    /// Number of words delivered.
+    pub word_count: usize,
+    /// Time each stage took and the model used.
+    pub latency: DictationLatency,
+}
+
+// --------------------------------------------------------------------------
+/// Read-only snapshot of Speakr's state, for menu-bar widgets and status
+/// bar integrations.
+///
+/// # Fields
+///
+/// - `backend`: Status of each backend service
+/// - `dictating`: Whether a dictation is in flight
+/// - `paused`: Whether dictation is paused from the tray
+/// - `active_profile`: Name of the settings profile last switched to
+/// - `model`: The selected transcription model
+/// - `last_dictation`: Summary of the most recent dictation, if any
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct ApiStatus {
+    /// Status of each backend service.
+    pub backend: StatusUpdate,
+    /// Whether a dictation is in flight.
+    pub dictating: bool,
+    /// Whether dictation is paused from the tray.
+    pub paused: bool,
+    /// Name of the settings profile last switched to, if any.
+    pub active_profile: Option<String>,
+    /// The selected transcription model.
+    pub model: ModelId,
+    /// Summary of the most recent completed dictation, if any.
+    pub last_dictation: Option<DictationSummary>,
+}
+
+// --------------------------------------------------------------------------
+/// The local API's reply to an [`ApiRequest`].
+///
+/// # Variants
+///
+/// - `DictationStarted`: A new dictation is running
+/// - `DictationCancelled(bool)`: Whether a dictation was in flight and cancelled
+/// - `Transcript(Option<String>)`: The latest transcript, if there is one yet
+/// - `Status(ApiStatus)`: A snapshot of Speakr's state
+/// - `Error(AppError)`: The request could not be handled
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+pub enum ApiResponse {
+    /// A new dictation is running.
+    DictationStarted,
+    /// Whether a dictation was in flight and has been cancelled.
+    DictationCancelled(bool),
+    /// Text from the most recent completed dictation, if any.
+    Transcript(Option<String>),
+    /// A snapshot of Speakr's state.
+    Status(Box<ApiStatus>),
+    /// The request could not be handled.
+    Error(AppError),
+}
+
+// ============================================================================
+// Diagnostics and Logging
+// ============================================================================
+
+// --------------------------------------------------------------------------
+/// Application subsystem whose log verbosity can be tuned independently.
+///
+/// Each subsystem maps onto one or more `tracing` targets in the backend, so
+/// detailed logs can be captured for just the part that is misbehaving.
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::LogSubsystem;
+///
+/// for subsystem in LogSubsystem::all() {
+///     println!("{}", subsystem.display_name());
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+pub enum LogSubsystem {
+    /// Audio device access and capture.
+    Audio,
+    /// Model loading, transcription, and post-processing.
+    Transcription,
+    /// Global hot-key registration and handling.
+    Hotkey,
+    /// Text injection into the focused application.
+    Injection,
+}
+
+impl LogSubsystem {
+    /// Returns every subsystem in display order.
+    pub fn all() -> [LogSubsystem; 4] {
+        [
+            LogSubsystem::Audio,
+            LogSubsystem::Transcription,
+            LogSubsystem::Hotkey,
+            LogSubsystem::Injection,
+        ]
+    }
+
+    /// Returns the human-readable name for the subsystem.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            LogSubsystem::Audio => "Audio",
+            LogSubsystem::Transcription => "Transcription",
+            LogSubsystem::Hotkey => "Hot-key",
+            LogSubsystem::Injection => "Text Injection",
+        }
+    }
+}
+
+// --------------------------------------------------------------------------
+/// Log verbosity that can be applied to a [`LogSubsystem`].
+///
+/// Levels are ordered from least to most verbose and map directly onto
+/// `tracing` level filters.
+///
+/// # Examples
+///
+/// ```no_run
+/// use speakr_types::LogVerbosity;
+///
+/// assert_eq!(LogVerbosity::Debug.as_directive(), "debug");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+pub enum LogVerbosity {
+    /// Suppress all output.
+    Off,
+    /// Errors only.
+    Error,
+    /// Warnings and errors.
+    Warn,
+    /// Informational messages and above.
+    Info,
+    /// Debug messages and above.
+    Debug,
+    /// Everything, including trace spans.
+    Trace,
+}
+
+impl LogVerbosity {
+    /// Returns every verbosity from least to most verbose.
+    pub fn all() -> [LogVerbosity; 6] {
+        [
+            LogVerbosity::Off,
+            LogVerbosity::Error,
+            LogVerbosity::Warn,
+            LogVerbosity::Info,
+            LogVerbosity::Debug,
+            LogVerbosity::Trace,
+        ]
+    }
+
+    /// Returns the level as used in a `tracing` filter directive.
+    pub fn as_directive(&self) -> &'static str {
+        match self {
+            LogVerbosity::Off => "off",
+            LogVerbosity::Error => "error",
+            LogVerbosity::Warn => "warn",
+            LogVerbosity::Info => "info",
+            LogVerbosity::Debug => "debug",
+            LogVerbosity::Trace => "trace",
+        }
+    }
+
+    /// Parses a filter directive level (case-insensitive).
+    ///
+    /// # Returns
+    ///
+    /// `None` if the string is not a known level.
+    pub fn from_directive(value: &str) -> Option<LogVerbosity> {
+        LogVerbosity::all()
+            .into_iter()
+            .find(|level| level.as_directive().eq_ignore_ascii_case(value))
+    }
+}
+
+// --------------------------------------------------------------------------
+/// Current verbosity override for a single subsystem.
+///
+/// A `level` of `None` means the subsystem follows the global filter
+/// (typically taken from `RUST_LOG`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct SubsystemLogLevel {
+    /// The subsystem being configured.
+    pub subsystem: LogSubsystem,
+    /// The override level, or `None` to inherit the global filter.
+    pub level: Option<LogVerbosity>,
+}
+
+// --------------------------------------------------------------------------
+/// Outcome of the watchdog restarting a service that reported an error.
+///
+/// Emitted on [`SERVICE_RECOVERED_EVENT`] once a restart succeeds, or on
+/// [`SERVICE_GAVE_UP_EVENT`] when every attempt has failed.
+///
+/// # Fields
+///
+/// * `service` - Display name of the service, e.g. "Transcription"
+/// * `attempts` - Number of restarts attempted
+/// * `error` - Error from the last failed attempt; `None` once recovered
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-bindings", derive(ts_rs::TS), ts(export))]
+#[serde(deny_unknown_fields)]
+pub struct ServiceRecovery {
+    /// Display name of the service.
+    pub service: String,
+    /// Number of restarts attempted.
+    pub attempts: u32,
+    /// Error from the last failed attempt.
+    pub error: Option<String>,
+}
+
+// =========================
+// Type Aliases and Exports
+// =========================
+
+/// Type alias for status updates sent to the frontend.
+///
+/// Provides semantic clarity when used specifically for status broadcasting
+/// while maintaining the same underlying structure as BackendStatus.
+pub type StatusUpdate = BackendStatus;
+
+/// Tauri event channel on which a [`StatusUpdate`] is emitted whenever a
+/// service's status changes.
+pub const STATUS_CHANGED_EVENT: &str = "status-changed";
+
+/// Tauri event channel on which a [`ServiceRecovery`] is emitted when a
+/// failed service has been restarted.
+pub const SERVICE_RECOVERED_EVENT: &str = "service-recovered";
+
+/// Tauri event channel on which a [`ServiceRecovery`] is emitted when the
+/// watchdog stops restarting a service that keeps failing.
+pub const SERVICE_GAVE_UP_EVENT: &str = "service-gave-up";
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // =========================
+    // Settings and Configuration Tests
+    // =========================
+
+    #[test]
+    fn test_app_settings_default() {
+        let settings = AppSettings::default();
+        assert_eq!(settings.version, DEFAULT_SCHEMA_VERSION);
+        assert_eq!(settings.hot_key, DEFAULT_HOTKEY);
+        assert_eq!(settings.model.as_str(), DEFAULT_MODEL);
+        assert_eq!(settings.auto_launch, DEFAULT_AUTO_LAUNCH);
+        assert_eq!(settings.audio_duration, AudioDuration::DEFAULT);
+    }
+
+    #[test]
+    fn test_audio_duration_validation_valid_range() {
+        assert!(AudioDuration::new(MIN_AUDIO_DURATION_SECS).is_ok());
+        assert!(AudioDuration::new(DEFAULT_AUDIO_DURATION_SECS).is_ok());
+        assert!(AudioDuration::new(MAX_AUDIO_DURATION_SECS).is_ok());
+    }
+
+    #[test]
+    fn test_audio_duration_validation_invalid_range() {
+        assert!(AudioDuration::new(MIN_AUDIO_DURATION_SECS - 1).is_err());
+        assert!(AudioDuration::new(MAX_AUDIO_DURATION_SECS + 1).is_err());
+        assert!(AudioDuration::try_from(100).is_err());
+    }
+
+    #[test]
+    fn test_audio_duration_constants_are_consistent() {
+        assert!(MIN_AUDIO_DURATION_SECS <= DEFAULT_AUDIO_DURATION_SECS);
+        assert!(DEFAULT_AUDIO_DURATION_SECS <= MAX_AUDIO_DURATION_SECS);
+        assert_eq!(AudioDuration::MIN.as_secs(), MIN_AUDIO_DURATION_SECS);
+        assert_eq!(
+            AudioDuration::default().as_secs(),
+            DEFAULT_AUDIO_DURATION_SECS
+        );
+        assert_eq!(AudioDuration::MAX.as_secs(), MAX_AUDIO_DURATION_SECS);
+    }
+
+    #[test]
+    fn test_audio_duration_clamps_and_converts() {
+        assert_eq!(AudioDuration::clamped(0), AudioDuration::MIN);
+        assert_eq!(AudioDuration::clamped(31), AudioDuration::MAX);
+        assert_eq!(AudioDuration::clamped(15).as_secs(), 15);
+
+        let duration = AudioDuration::new(15).unwrap();
+        assert_eq!(Duration::from(duration), Duration::from_secs(15));
+        assert_eq!(duration.sample_count(16_000), 240_000);
+        assert_eq!(duration.to_string(), "15s");
+    }
+
+    #[test]
+    fn test_audio_duration_serializes_as_seconds() {
+        let settings = AppSettings {
+            audio_duration: AudioDuration::new(15).unwrap(),
+            ..AppSettings::default()
+        };
+        let json = serde_json::to_string(&settings).unwrap();
+        assert!(json.contains(r#""audio_duration_secs":15"#), "{json}");
+
+        // Out-of-range values in a settings file are clamped
+        let json = json.replace(r#""audio_duration_secs":15"#, r#""audio_duration_secs":0"#);
+        let loaded: AppSettings = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.audio_duration, AudioDuration::MIN);
+        assert!(loaded.validate().is_ok());
+    }
+
+    #[test]
+    fn test_language_setting_validation() {
+        assert!(AppSettings::validate_language(DEFAULT_LANGUAGE));
+        assert!(AppSettings::validate_language("en"));
+        assert!(AppSettings::validate_language("haw"));
+        assert!(!AppSettings::validate_language(""));
+        assert!(!AppSettings::validate_language("EN"));
+        assert!(!AppSettings::validate_language("english"));
+
+        let settings = AppSettings {
+            language: "not a language".to_string(),
+            ..AppSettings::default()
+        };
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn test_settings_without_language_default_to_auto() {
+        let json = r#"{"version":2,"hot_key":"CmdOrCtrl+Alt+F1","model_size":"medium","auto_launch":false,"audio_duration_secs":10}"#;
+        let settings: AppSettings = serde_json::from_str(json).expect("Legacy JSON should parse");
+        assert_eq!(settings.language, DEFAULT_LANGUAGE);
+        assert_eq!(settings.transcription_language(), None);
+    }
+
+    #[test]
+    fn test_text_transform_serialization() {
+        let chain = vec![
+            TextTransform::TrimWhitespace,
+            TextTransform::default_filler_words(),
+            TextTransform::RegexReplace {
+                pattern: "foo".to_string(),
+                replacement: "bar".to_string(),
+            },
+            TextTransform::Replace {
+                from: "new line".to_string(),
+                to: "\n".to_string(),
+                case_sensitive: true,
+            },
+        ];
+        let json = serde_json::to_string(&chain).expect("Chain should serialize to JSON");
+        assert!(json.contains(r#""type":"trim_whitespace""#));
+        let deserialized: Vec<TextTransform> =
+            serde_json::from_str(&json).expect("JSON should deserialize to chain");
+        assert_eq!(chain, deserialized);
+    }
+
+    #[test]
+    fn test_settings_reject_empty_replacement_pattern() {
+        let settings = AppSettings {
+            post_processing: vec![TextTransform::RegexReplace {
+                pattern: String::new(),
+                replacement: "x".to_string(),
+            }],
+            ..AppSettings::default()
+        };
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn test_replace_defaults_to_case_insensitive() {
+        let transform: TextTransform =
+            serde_json::from_str(r#"{"type":"replace","from":"speakr","to":"Speakr"}"#).unwrap();
+        assert_eq!(
+            transform,
+            TextTransform::Replace {
+                from: "speakr".to_string(),
+                to: "Speakr".to_string(),
+                case_sensitive: false,
+            }
+        );
+
+        let settings = AppSettings {
+            post_processing: vec![TextTransform::Replace {
+                from: "  ".to_string(),
+                to: "x".to_string(),
+                case_sensitive: false,
+            }],
+            ..AppSettings::default()
+        };
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn test_log_verbosity_directive_round_trip() {
+        for level in LogVerbosity::all() {
+            assert_eq!(
+                LogVerbosity::from_directive(level.as_directive()),
+                Some(level)
+            );
+        }
+        assert_eq!(
+            LogVerbosity::from_directive("WARN"),
+            Some(LogVerbosity::Warn)
+        );
+        assert_eq!(LogVerbosity::from_directive("loud"), None);
+        assert!(LogVerbosity::Trace > LogVerbosity::Info);
+    }
+
+    #[test]
+    fn test_dock_badge_defaults_off_for_existing_settings() {
+        let json = r#"{"hot_key":"CmdOrCtrl+Alt+Space","model_size":"medium","auto_launch":false}"#;
+        let settings: AppSettings = serde_json::from_str(json).expect("should deserialize");
+        assert_eq!(settings.dock_badge, DEFAULT_DOCK_BADGE);
+        assert!(!AppSettings::default().dock_badge);
+    }
+
+    #[test]
+    fn test_pre_buffer_is_opt_in() {
+        let json = r#"{"hot_key":"CmdOrCtrl+Alt+Space","model_size":"medium","auto_launch":false}"#;
+        let settings: AppSettings = serde_json::from_str(json).expect("should deserialize");
+        assert!(!settings.pre_buffer);
+        assert!(!AppSettings::default().pre_buffer);
+    }
+
+    #[test]
+    fn test_input_gains() {
+        let with = |gain| AppSettings {
+            input_gains: vec![InputGain {
+                device: "Desk Mic".to_string(),
+                gain,
+            }],
+            ..AppSettings::default()
+        };
+        assert_eq!(with(2.0).input_gain("Desk Mic"), 2.0);
+        assert_eq!(with(2.0).input_gain("Headset"), 1.0);
+        assert!(with(MAX_INPUT_GAIN).validate().is_ok());
+        assert!(with(MAX_INPUT_GAIN + 0.5).validate().is_err());
+        assert!(with(0.0).validate().is_err());
+        assert!(with(f32::NAN).validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_duplicate_input_devices() {
+        let duplicate = AppSettings {
+            input_devices: vec!["Desk Mic".to_string(), "Desk Mic".to_string()],
+            ..AppSettings::default()
+        };
+        assert!(duplicate.validate().is_err());
+        let blank = AppSettings {
+            input_devices: vec![" ".to_string()],
+            ..AppSettings::default()
+        };
+        assert!(blank.validate().is_err());
+        let ordered = AppSettings {
+            input_devices: vec!["Headset".to_string(), "Desk Mic".to_string()],
+            ..AppSettings::default()
+        };
+        assert!(ordered.validate().is_ok());
+    }
+
+    #[test]
+    fn test_auto_gain_target_is_validated_and_converts_to_dbfs() {
+        let quiet = AppSettings {
+            auto_gain: Some(AutoGain { target_dbfs: -50.0 }),
+            ..AppSettings::default()
+        };
+        assert!(quiet.validate().is_err());
+        let default = AppSettings {
+            auto_gain: Some(AutoGain::default()),
+            ..AppSettings::default()
+        };
+        assert!(default.validate().is_ok());
+
+        assert!((level_to_dbfs(0.1) + 20.0).abs() < 1e-4);
+        assert!((dbfs_to_level(-20.0) - 0.1).abs() < 1e-6);
+        assert_eq!(level_to_dbfs(1e-9), SILENCE_DBFS);
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_plugin_settings() {
+        let traversal = AppSettings {
+            plugins: vec!["../escape".to_string()],
+            ..AppSettings::default()
+        };
+        assert!(traversal.validate().is_err());
+
+        let duplicate = AppSettings {
+            plugins: vec!["title-case".to_string(), "title-case".to_string()],
+            ..AppSettings::default()
+        };
+        assert!(duplicate.validate().is_err());
+
+        let no_fuel = AppSettings {
+            plugin_limits: PluginLimits {
+                max_fuel: 0,
+                ..PluginLimits::default()
+            },
+            ..AppSettings::default()
+        };
+        assert!(no_fuel.validate().is_err());
+
+        let valid = AppSettings {
+            plugins: vec!["title-case".to_string(), "emoji_2".to_string()],
+            ..AppSettings::default()
+        };
+        assert!(valid.validate().is_ok());
+    }
+
+    #[test]
+    fn test_download_progress_summary() {
+        let progress = DownloadProgress {
+            filename: "ggml-medium.bin".to_string(),
+            downloaded_bytes: 300 * 1024 * 1024,
+            total_bytes: Some(1000 * 1024 * 1024),
+            bytes_per_sec: 5 * 1024 * 1024 / 2,
+            eta_secs: Some(280),
+        };
+        assert_eq!(progress.percent(), Some(30));
+        assert!(!progress.is_complete());
+        assert_eq!(progress.summary(), "30% · 2.5 MB/s · 4m 40s left");
+
+        let unknown_size = DownloadProgress {
+            total_bytes: None,
+            eta_secs: None,
+            ..progress
+        };
+        assert_eq!(unknown_size.percent(), None);
+        assert_eq!(unknown_size.summary(), "300.0 MB · 2.5 MB/s");
+    }
+
+    #[test]
+    fn test_model_idle_unload_setting() {
+        let json = r#"{"hot_key":"CmdOrCtrl+Alt+Space","model_size":"medium","auto_launch":false}"#;
+        let settings: AppSettings = serde_json::from_str(json).expect("should deserialize");
+        assert_eq!(
+            settings.model_idle_unload_mins,
+            Some(DEFAULT_MODEL_IDLE_UNLOAD_MINS)
+        );
+
+        let never = AppSettings {
+            model_idle_unload_mins: None,
+            ..AppSettings::default()
+        };
+        assert_eq!(never.model_idle_unload(), None);
+        assert!(never.validate().is_ok());
+
+        let zero = AppSettings {
+            model_idle_unload_mins: Some(0),
+            ..AppSettings::default()
+        };
+        assert!(zero.validate().is_err());
+    }
+
+    #[test]
+    fn test_notifications_default_on_without_text() {
+        let json = r#"{"hot_key":"CmdOrCtrl+Alt+Space","model_size":"medium","auto_launch":false}"#;
+        let settings: AppSettings = serde_json::from_str(json).expect("should deserialize");
+        assert!(settings.notifications);
+        assert!(!settings.notification_text);
+    }
+
+    #[test]
+    fn test_output_mode_defaults_to_inject() {
+        let json = r#"{"hot_key":"CmdOrCtrl+Alt+Space","model_size":"medium","auto_launch":false}"#;
+        let settings: AppSettings = serde_json::from_str(json).expect("should deserialize");
+        assert_eq!(settings.output_mode, OutputMode::Inject);
+        assert!(!settings.clipboard_notification);
+        assert!(!settings.streaming_injection);
+
+        let json = r#"{"hot_key":"CmdOrCtrl+Alt+Space","model_size":"medium","auto_launch":false,"output_mode":"Clipboard"}"#;
+        let settings: AppSettings = serde_json::from_str(json).expect("should deserialize");
+        assert!(settings.output_mode.copies());
+        assert!(!settings.output_mode.injects());
+    }
+
+    #[test]
+    fn test_newline_options_default_to_paragraphs() {
+        let json = r#"{"hot_key":"CmdOrCtrl+Alt+Space","model_size":"medium","auto_launch":false,"newlines":{"paragraph_break":"NoBreak"}}"#;
+        let settings: AppSettings = serde_json::from_str(json).expect("should deserialize");
+        assert_eq!(settings.newlines.paragraph_break, ParagraphBreak::NoBreak);
+        assert_eq!(
+            settings.newlines.new_paragraph_command,
+            NewParagraphCommand::ParagraphBreak
+        );
+        assert_eq!(ParagraphBreak::default().separator(), "\n\n");
+        assert_eq!(ParagraphBreak::NoBreak.separator(), " ");
+    }
+
+    #[test]
+    fn test_local_api_is_opt_in() {
+        let json = r#"{"hot_key":"CmdOrCtrl+Alt+Space","model_size":"medium","auto_launch":false}"#;
+        let settings: AppSettings = serde_json::from_str(json).expect("should deserialize");
+        assert!(!settings.local_api);
+        assert!(!AppSettings::default().local_api);
+    }
+
+    #[test]
+    fn test_theme_codes() {
+        assert_eq!(AppSettings::default().theme, Theme::System);
+        for theme in Theme::ALL {
+            let json = serde_json::to_string(&theme).unwrap();
+            assert_eq!(json, format!("\"{}\"", theme.code()));
+            assert_eq!(Theme::from_code(theme.code()), Some(theme));
+        }
+        assert!(!Theme::System.is_dark(false));
+        assert!(Theme::Dark.is_dark(false));
+    }
+
+    #[test]
+    fn test_ui_language_codes() {
+        assert_eq!(AppSettings::default().ui_language, UiLanguage::System);
+        for language in UiLanguage::TRANSLATED {
+            let json = serde_json::to_string(&language).unwrap();
+            assert_eq!(json, format!("\"{}\"", language.code()));
+            assert_eq!(UiLanguage::from_code(language.code()), Some(language));
+        }
+        assert_eq!(
+            serde_json::from_str::<UiLanguage>("\"system\"").unwrap(),
+            UiLanguage::System
+        );
+        assert_eq!(
+            UiLanguage::resolve(UiLanguage::System, Some("es_MX")),
+            UiLanguage::Spanish
+        );
+        assert_eq!(
+            UiLanguage::resolve(UiLanguage::System, None),
+            UiLanguage::English
+        );
+    }
+
+    #[test]
+    fn test_api_messages_round_trip_as_json() {
+        let request = serde_json::to_string(&ApiRequest::LastTranscript).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ApiRequest>(&request).unwrap(),
+            ApiRequest::LastTranscript
+        );
+
+        let response = ApiResponse::Transcript(Some("hello".to_string()));
+        let json = serde_json::to_string(&response).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ApiResponse>(&json).unwrap(),
+            response
+        );
+    }
+
+    #[test]
+    fn test_api_status_round_trips_as_json() {
+        let status = ApiStatus {
+            backend: BackendStatus::new_ready(),
+            dictating: false,
+            paused: false,
+            active_profile: Some("Work".to_string()),
+            model: ModelId::new("small.en"),
+            last_dictation: Some(DictationSummary {
+                completed_at: 1_700_000_000_000,
+                word_count: 12,
+                latency: DictationLatency {
+                    capture_ms: 4_000,
+                    transcribe_ms: 850,
+                    process_ms: 3,
+                    inject_ms: 120,
+                    model: ModelId::new("small.en"),
+                    input_device: None,
+                    processing_stages: Vec::new(),
+                },
+            }),
+        };
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(json["model"], "small.en");
+        assert_eq!(json["last_dictation"]["word_count"], 12);
+
+        let response = ApiResponse::Status(Box::new(status));
+        let line = serde_json::to_string(&response).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ApiResponse>(&line).unwrap(),
+            response
+        );
+    }
+
+    #[test]
+    fn test_app_profile_adjusts_post_processing() {
+        let settings = AppSettings {
+            post_processing: vec![
+                TextTransform::TrimWhitespace,
+                TextTransform::CapitaliseSentences,
+            ],
+            app_profiles: vec![AppProfile {
+                punctuation: false,
+                capitalisation: false,
+                ..AppProfile::new("com.microsoft.VSCode")
+            }],
+            ..AppSettings::default()
+        };
+
+        assert_eq!(
+            settings.post_processing_for(Some("com.microsoft.vscode")),
+            vec![
+                TextTransform::TrimWhitespace,
+                TextTransform::StripPunctuation
+            ]
+        );
+        assert_eq!(
+            settings.post_processing_for(Some("com.apple.TextEdit")),
+            settings.post_processing
+        );
+        assert_eq!(settings.post_processing_for(None), settings.post_processing);
+    }
 
-    /// Creates a new status with all services ready.
-    ///
-    /// # Returns
-    ///
-    /// BackendStatus with all services marked as Ready.
-    ///
-    /// # Examples
-    ///
-    /// ```no_run
-    /// use speakr_types::BackendStatus;
-    ///
-    /// let status = BackendStatus::new_ready();
-    /// assert!(status.is_ready());
-    /// ```
-    pub fn new_ready() -> Self {
-        Self {
-            audio_capture: ServiceStatus::Ready,
-            transcription: ServiceStatus::Ready,
-            text_injection: ServiceStatus::Ready,
-            timestamp: chrono::Utc::now().timestamp_millis() as u64,
-        }
+    #[test]
+    fn test_app_profile_sets_injection_options() {
+        let slack = AppProfile {
+            injection: InjectionOptions {
+                method: InjectionMethod::Clipboard,
+                typing_delay_ms: 0,
+                trailing: TrailingText::Newline,
+            },
+            ..AppProfile::new("com.tinyspeck.slackmacgap")
+        };
+        let settings = AppSettings {
+            app_profiles: vec![slack.clone()],
+            ..AppSettings::default()
+        };
+
+        assert_eq!(
+            settings.injection_for(Some("com.tinyspeck.slackmacgap")),
+            slack.injection
+        );
+        assert_eq!(
+            settings.injection_for(Some("com.apple.TextEdit")),
+            InjectionOptions::default()
+        );
+        assert_eq!(slack.injection.finish("Ship it"), "Ship it\n");
+
+        // Profiles saved before injection options existed keep the defaults
+        let json = r#"{"app_id":"com.apple.Terminal","punctuation":false,"capitalisation":false}"#;
+        let legacy: AppProfile = serde_json::from_str(json).expect("should deserialize");
+        assert_eq!(legacy.injection, InjectionOptions::default());
+
+        let too_slow = AppSettings {
+            app_profiles: vec![AppProfile {
+                injection: InjectionOptions {
+                    typing_delay_ms: MAX_TYPING_DELAY_MS + 1,
+                    ..InjectionOptions::default()
+                },
+                ..AppProfile::new("com.apple.Terminal")
+            }],
+            ..AppSettings::default()
+        };
+        assert!(too_slow.validate().is_err());
     }
-}
 
-// =========================
-// Type Aliases and Exports
-// =========================
+    #[test]
+    fn test_injection_blocklist() {
+        let settings = AppSettings::default();
+        assert!(settings.blocks_injection(Some("com.1password.1password")));
+        assert!(settings.blocks_injection(Some("com.Bitwarden.Desktop")));
+        assert!(!settings.blocks_injection(Some("com.apple.TextEdit")));
+        assert!(!settings.blocks_injection(None));
+
+        // Settings saved before the blocklist existed get the default one
+        let mut legacy = serde_json::to_value(AppSettings::default()).unwrap();
+        legacy
+            .as_object_mut()
+            .unwrap()
+            .remove("injection_blocklist");
+        let legacy: AppSettings = serde_json::from_value(legacy).unwrap();
+        assert_eq!(legacy.injection_blocklist, settings.injection_blocklist);
+
+        let cleared = AppSettings {
+            injection_blocklist: vec![],
+            ..AppSettings::default()
+        };
+        assert!(!cleared.blocks_injection(Some("com.1password.1password")));
+    }
 
-/// Type alias for status updates sent to the frontend.
-///
-/// Provides semantic clarity when used specifically for status broadcasting
-/// while maintaining the same underlying structure as BackendStatus.
-pub type StatusUpdate = BackendStatus;
+    #[test]
+    fn test_settings_reject_invalid_app_profiles() {
+        let empty = AppSettings {
+            app_profiles: vec![AppProfile::new(" ")],
+            ..AppSettings::default()
+        };
+        assert!(empty.validate().is_err());
 
-// ============================================================================
-// Unit Tests
-// ============================================================================
+        let duplicate = AppSettings {
+            app_profiles: vec![
+                AppProfile::new("com.apple.Terminal"),
+                AppProfile::new("com.apple.terminal"),
+            ],
+            ..AppSettings::default()
+        };
+        assert!(duplicate.validate().is_err());
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_voice_commands_default_to_off() {
+        let json = r#"{"hot_key":"CmdOrCtrl+Alt+Space","model_size":"medium","auto_launch":false}"#;
+        let settings: AppSettings = serde_json::from_str(json).unwrap();
+        assert_eq!(settings.voice_commands, VoiceCommandSettings::default());
+        assert!(!settings.voice_commands.enabled);
+
+        let command: VoiceCommand =
+            serde_json::from_str(r#"{"phrase":"smiley","action":{"type":"insert","text":":-)"}}"#)
+                .unwrap();
+        assert_eq!(
+            command.action,
+            VoiceCommandAction::Insert {
+                text: ":-)".to_string()
+            }
+        );
 
-    // =========================
-    // Settings and Configuration Tests
-    // =========================
+        let settings = AppSettings {
+            voice_commands: VoiceCommandSettings {
+                enabled: true,
+                custom: vec![VoiceCommand::new(" ", VoiceCommandAction::NewLine)],
+            },
+            ..AppSettings::default()
+        };
+        assert!(settings.validate().is_err());
+    }
 
     #[test]
-    fn test_app_settings_default() {
-        let settings = AppSettings::default();
-        assert_eq!(settings.version, DEFAULT_SCHEMA_VERSION);
-        assert_eq!(settings.hot_key, DEFAULT_HOTKEY);
-        assert_eq!(settings.model_size, DEFAULT_MODEL_SIZE);
-        assert_eq!(settings.auto_launch, DEFAULT_AUTO_LAUNCH);
-        assert_eq!(settings.audio_duration_secs, DEFAULT_AUDIO_DURATION_SECS);
+    fn test_installed_model_reports_upstream_changes() {
+        let json = r#"{"filename":"ggml-tiny.bin","size_bytes":1,"checksum_valid":null}"#;
+        let mut model: InstalledModel = serde_json::from_str(json).unwrap();
+        assert_eq!(model.source, None);
+        assert!(!model.upstream_changed());
+
+        model.source = Some(ModelSource {
+            git_ref: "f281eb45af861ab5e5297d23694b7d46e090c02c".to_string(),
+            sha: "ABC".to_string(),
+            downloaded_at: 1,
+        });
+        model.catalogue_sha = Some("abc".to_string());
+        assert!(!model.upstream_changed());
+        model.catalogue_sha = Some("def".to_string());
+        assert!(model.upstream_changed());
+        assert_eq!(model.source.unwrap().short_ref(), "f281eb4");
     }
 
     #[test]
-    fn test_audio_duration_validation_valid_range() {
-        assert!(AppSettings::validate_audio_duration(
-            MIN_AUDIO_DURATION_SECS
-        ));
-        assert!(AppSettings::validate_audio_duration(
-            DEFAULT_AUDIO_DURATION_SECS
-        ));
-        assert!(AppSettings::validate_audio_duration(
-            MAX_AUDIO_DURATION_SECS
-        ));
+    fn test_settings_validate_stop_phrase() {
+        let json = r#"{"hot_key":"CmdOrCtrl+Alt+Space","model_size":"medium","auto_launch":false,"stop_phrase":{"phrase":"stop dictation"}}"#;
+        let settings: AppSettings = serde_json::from_str(json).expect("should deserialize");
+        assert_eq!(
+            settings.stop_phrase,
+            Some(StopPhrase::new("stop dictation"))
+        );
+        assert!(settings.validate().is_ok());
+
+        let empty = AppSettings {
+            stop_phrase: Some(StopPhrase::new("  ")),
+            ..AppSettings::default()
+        };
+        assert!(empty.validate().is_err());
+
+        let out_of_range = AppSettings {
+            stop_phrase: Some(StopPhrase {
+                min_confidence: 1.5,
+                ..StopPhrase::new("stop dictation")
+            }),
+            ..AppSettings::default()
+        };
+        assert!(out_of_range.validate().is_err());
     }
 
     #[test]
-    fn test_audio_duration_validation_invalid_range() {
-        assert!(!AppSettings::validate_audio_duration(
-            MIN_AUDIO_DURATION_SECS - 1
-        ));
-        assert!(!AppSettings::validate_audio_duration(
-            MAX_AUDIO_DURATION_SECS + 1
-        ));
-        assert!(!AppSettings::validate_audio_duration(100));
+    fn test_app_error_code_excludes_message() {
+        let error = AppError::TextInjection("typed 'secret text'".to_string());
+        assert_eq!(error.code(), "E_TEXT_INJECTION");
+        assert_eq!(
+            AppError::HotKeyConflict("Cmd+Space".to_string()).code(),
+            "E_HOT_KEY_CONFLICT"
+        );
     }
 
     #[test]
-    fn test_audio_duration_constants_are_consistent() {
-        assert!(MIN_AUDIO_DURATION_SECS <= DEFAULT_AUDIO_DURATION_SECS);
-        assert!(DEFAULT_AUDIO_DURATION_SECS <= MAX_AUDIO_DURATION_SECS);
-        assert!(AppSettings::validate_audio_duration(
-            DEFAULT_AUDIO_DURATION_SECS
-        ));
+    fn test_io_error_keeps_source_details() {
+        let io_error = std::io::Error::from_raw_os_error(13);
+        let error = AppError::io(
+            "Failed to write settings",
+            &io_error,
+            Some(std::path::Path::new("/tmp/settings.json")),
+        );
+
+        assert_eq!(error.code(), "E_IO_PERMISSION_DENIED");
+        let details = error.details().expect("io errors carry details");
+        assert_eq!(details.os_error, Some(13));
+        assert_eq!(details.path.as_deref(), Some("/tmp/settings.json"));
+        assert_eq!(
+            std::error::Error::source(&error).map(|source| source.to_string()),
+            Some(io_error.to_string())
+        );
+        assert!(error
+            .to_string()
+            .starts_with("File system error: Failed to write settings"));
+        assert_eq!(AppError::Settings("x".to_string()).details(), None);
     }
 
     #[test]
-    fn test_audio_duration_validation_uses_constants() {
-        // Test that validation uses the defined constants
-        assert!(AppSettings::validate_audio_duration(
-            MIN_AUDIO_DURATION_SECS
-        ));
-        assert!(AppSettings::validate_audio_duration(
-            MAX_AUDIO_DURATION_SECS
-        ));
-        assert!(!AppSettings::validate_audio_duration(
-            MIN_AUDIO_DURATION_SECS - 1
-        ));
-        assert!(!AppSettings::validate_audio_duration(
-            MAX_AUDIO_DURATION_SECS + 1
-        ));
+    fn test_app_error_wire_format() {
+        assert_eq!(
+            serde_json::to_string(&AppError::Settings("bad".to_string())).unwrap(),
+            r#"{"Settings":"bad"}"#
+        );
+
+        let error = AppError::Io {
+            message: "Failed to read".to_string(),
+            details: ErrorSource {
+                kind: "NotFound".to_string(),
+                os_error: Some(2),
+                path: Some("/a".to_string()),
+                description: "missing".to_string(),
+            },
+        };
+        let json = serde_json::to_string(&error).unwrap();
+        assert_eq!(
+            json,
+            r#"{"Io":{"message":"Failed to read","details":{"kind":"NotFound","os_error":2,"path":"/a","description":"missing"}}}"#
+        );
+        assert_eq!(serde_json::from_str::<AppError>(&json).unwrap(), error);
     }
 
     #[test]
-    fn test_app_settings_validate_method() {
-        // Test that AppSettings has a validate method that checks audio duration
-        let mut settings = AppSettings::default();
-        assert!(settings.validate().is_ok());
+    fn test_command_error_envelope() {
+        let error = CommandError::from(AppError::HotKeyConflict("Cmd+Space".to_string()));
+        assert_eq!(
+            serde_json::to_string(&error).unwrap(),
+            r#"{"code":"E_HOT_KEY_CONFLICT","message":"Hot-key conflict: Cmd+Space","recoverable":true}"#
+        );
 
-        settings.audio_duration_secs = 0;
-        assert!(settings.validate().is_err());
+        let missing = AppError::io(
+            "Failed to read settings",
+            &std::io::Error::from(std::io::ErrorKind::NotFound),
+            None,
+        );
+        assert!(CommandError::from(missing).recoverable);
+        let broken = AppError::io(
+            "Failed to read settings",
+            &std::io::Error::other("disk on fire"),
+            None,
+        );
+        assert!(!CommandError::from(broken).recoverable);
+        assert!(!CommandError::from(AppError::Command("x".to_string())).recoverable);
+    }
 
-        settings.audio_duration_secs = 31;
-        assert!(settings.validate().is_err());
+    #[test]
+    fn test_settings_profiles_upsert_and_remove() {
+        let mut profiles = SettingsProfiles::default();
+        let settings = AppSettings::default();
+        profiles
+            .upsert(SettingsProfile::from_settings("Work", &settings))
+            .unwrap();
+
+        let mut meetings = SettingsProfile::from_settings("work", &settings);
+        meetings.model = ModelId::new("large-v3");
+        profiles.upsert(meetings).unwrap();
+        assert_eq!(profiles.profiles.len(), 1);
+        assert_eq!(profiles.get("WORK").unwrap().model.as_str(), "large-v3");
+
+        assert!(profiles
+            .upsert(SettingsProfile::from_settings("  ", &settings))
+            .is_err());
+
+        profiles.active = Some("Work".to_string());
+        assert!(profiles.remove("work"));
+        assert!(!profiles.remove("work"));
+        assert_eq!(profiles.active, None);
+    }
 
-        settings.audio_duration_secs = 15;
-        assert!(settings.validate().is_ok());
+    #[test]
+    fn test_pipeline_event_serialization() {
+        let events = vec![
+            PipelineEvent::RecordingStarted,
+            PipelineEvent::RecordingLevel(0.25),
+            PipelineEvent::PartialText("hello".to_string()),
+            PipelineEvent::InjectionSuppressed("Secure input is on".to_string()),
+            PipelineEvent::Failed(AppError::Transcription("model missing".to_string())),
+        ];
+
+        let json = serde_json::to_string(&events).expect("should serialize");
+        let restored: Vec<PipelineEvent> = serde_json::from_str(&json).expect("should deserialize");
+        assert_eq!(restored, events);
+        assert_eq!(
+            serde_json::to_string(&PipelineEvent::Injected).unwrap(),
+            r#""Injected""#
+        );
     }
 
     #[test]
@@ -1209,37 +6106,30 @@ mod tests {
     // =========================
 
     #[test]
-    fn test_model_size_conversions() {
-        let medium = ModelSize::Medium;
-        assert_eq!(medium.to_string_value(), "medium");
-        assert_eq!(medium.display_name(), "Medium (769MB, balanced)");
-
-        let from_str = ModelSize::from_string("small");
-        assert_eq!(from_str, ModelSize::Small);
-    }
-
-    #[test]
-    fn test_model_size_from_string_fallback() {
-        let unknown = ModelSize::from_string("unknown_size");
-        assert_eq!(unknown, ModelSize::Medium);
-    }
-
-    #[test]
-    fn test_model_size_all() {
-        let all_sizes = ModelSize::all();
-        assert_eq!(all_sizes.len(), 3);
-        assert!(all_sizes.contains(&ModelSize::Small));
-        assert!(all_sizes.contains(&ModelSize::Medium));
-        assert!(all_sizes.contains(&ModelSize::Large));
+    fn test_model_id_reads_legacy_sizes() {
+        assert_eq!(ModelId::new("small"), ModelId::new("Small"));
+        assert_eq!(ModelId::new("Large").as_str(), "large-v3-turbo");
+        assert_eq!(ModelId::new(" Medium.EN ").as_str(), "medium.en");
+        assert_eq!(ModelId::default().filename(), "ggml-medium.bin");
+
+        let json = r#"{"hot_key":"CmdOrCtrl+Alt+Space","model_size":"large","auto_launch":false}"#;
+        let settings: AppSettings = serde_json::from_str(json).unwrap();
+        assert_eq!(settings.model.as_str(), "large-v3-turbo");
+        let saved = serde_json::to_value(&settings).unwrap();
+        assert_eq!(saved["model"], "large-v3-turbo");
+        assert!(saved.get("model_size").is_none());
+
+        let latency: ModelId = serde_json::from_str(r#""Small""#).unwrap();
+        assert_eq!(latency.as_str(), "small");
     }
 
     #[test]
-    fn test_model_info() {
-        let info = ModelInfo::for_size(ModelSize::Small);
-        assert_eq!(info.filename, "ggml-small.bin");
-        assert_eq!(info.file_size_mb, 39);
-        assert_eq!(info.size, ModelSize::Small);
-        assert_eq!(info.description, "Fast processing, good for quick notes");
+    fn test_model_id_kinds() {
+        let model = ModelId::new("base.en-q5_1");
+        assert!(model.is_english_only());
+        assert!(model.is_quantised());
+        assert!(!ModelId::new("large-v3-turbo").is_quantised());
+        assert!(!ModelId::new("large-v3-turbo").is_english_only());
     }
 
     // =========================
@@ -1271,6 +6161,175 @@ mod tests {
         assert!(!ServiceStatus::Unavailable.is_ready());
     }
 
+    #[test]
+    fn test_dictation_latency_round_trip() {
+        let latency = DictationLatency {
+            capture_ms: 2_500,
+            transcribe_ms: 640,
+            process_ms: 1,
+            inject_ms: 40,
+            model: ModelId::new("medium"),
+            input_device: Some("Desk Mic".to_string()),
+            processing_stages: vec![ProcessingStage {
+                name: "Strip filler words".to_string(),
+                duration_us: 120,
+                changed: true,
+                segments: 1,
+            }],
+        };
+
+        let json = serde_json::to_string(&latency).unwrap();
+        let parsed: DictationLatency = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, latency);
+        assert_eq!(parsed.total_ms(), 3_181);
+        assert!(!AppSettings::default().show_latency_breakdown);
+    }
+
+    #[test]
+    fn test_dictation_overrides_replace_language() {
+        let mut config = TranscriptionConfig::from_settings(&AppSettings::default());
+        DictationOverrides::default().apply(&mut config);
+        assert_eq!(config.language, None);
+        assert!(config.auto_detect_language);
+
+        let binding = LanguageHotkey {
+            shortcut: "CmdOrCtrl+Alt+G".to_string(),
+            language: "de".to_string(),
+        };
+        binding.overrides().apply(&mut config);
+        assert_eq!(config.language.as_deref(), Some("de"));
+        assert!(!config.auto_detect_language);
+
+        DictationOverrides {
+            language: Some(DEFAULT_LANGUAGE.to_string()),
+        }
+        .apply(&mut config);
+        assert_eq!(config.language, None);
+        assert!(config.auto_detect_language);
+
+        // The main hot-key sends no payload
+        let parsed: Option<DictationOverrides> = serde_json::from_str("null").unwrap();
+        assert_eq!(parsed, None);
+    }
+
+    #[test]
+    fn test_validate_language_hotkeys() {
+        let binding = |shortcut: &str, language: &str| LanguageHotkey {
+            shortcut: shortcut.to_string(),
+            language: language.to_string(),
+        };
+        let with = |bindings: Vec<LanguageHotkey>| AppSettings {
+            language_hotkeys: bindings,
+            ..AppSettings::default()
+        };
+
+        assert!(with(vec![binding("CmdOrCtrl+Alt+G", "de")])
+            .validate()
+            .is_ok());
+        assert!(with(vec![binding(" ", "de")]).validate().is_err());
+        assert!(with(vec![binding("CmdOrCtrl+Alt+G", "German")])
+            .validate()
+            .is_err());
+        assert!(with(vec![binding(DEFAULT_HOTKEY, "de")])
+            .validate()
+            .is_err());
+        assert!(with(vec![
+            binding("CmdOrCtrl+Alt+G", "de"),
+            binding("CmdOrCtrl+Alt+G", "fr"),
+        ])
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_dev_shortcuts() {
+        let with = |shortcut: &str| AppSettings {
+            dev_shortcuts: vec![DevShortcut {
+                shortcut: shortcut.to_string(),
+                action: DevAction::ClearDebugLog,
+            }],
+            ..AppSettings::default()
+        };
+
+        assert!(with("CmdOrCtrl+Alt+Shift+L").validate().is_ok());
+        assert!(with("").validate().is_err());
+        assert!(with(DEFAULT_HOTKEY).validate().is_err());
+
+        let mut legacy = serde_json::to_value(AppSettings::default()).unwrap();
+        legacy.as_object_mut().unwrap().remove("dev_shortcuts");
+        let legacy: AppSettings = serde_json::from_value(legacy).unwrap();
+        assert!(legacy.dev_shortcuts.is_empty());
+    }
+
+    #[test]
+    fn test_validate_privacy_mode() {
+        let with = |privacy_hotkey: Option<&str>, privacy_mode_mins: u32| AppSettings {
+            privacy_hotkey: privacy_hotkey.map(str::to_string),
+            privacy_mode_mins,
+            ..AppSettings::default()
+        };
+
+        assert!(with(Some("CmdOrCtrl+Alt+P"), 15).validate().is_ok());
+        assert!(with(None, 0).validate().is_err());
+        assert!(with(Some(""), 15).validate().is_err());
+        assert!(with(Some(DEFAULT_HOTKEY), 15).validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_reinject_hotkey() {
+        let with = |reinject_hotkey: &str| AppSettings {
+            reinject_hotkey: Some(reinject_hotkey.to_string()),
+            privacy_hotkey: Some("CmdOrCtrl+Alt+P".to_string()),
+            ..AppSettings::default()
+        };
+
+        assert!(with("CmdOrCtrl+Alt+R").validate().is_ok());
+        assert!(with(" ").validate().is_err());
+        assert!(with("CmdOrCtrl+Alt+P").validate().is_err());
+    }
+
+    #[test]
+    fn test_long_form_recording_limit() {
+        let with = |long_form_mins| AppSettings {
+            audio_duration: AudioDuration::new(20).unwrap(),
+            long_form_mins,
+            ..AppSettings::default()
+        };
+
+        assert_eq!(with(None).recording_limit(), Duration::from_secs(20));
+        assert_eq!(with(Some(3)).recording_limit(), Duration::from_secs(180));
+        assert!(with(Some(MAX_LONG_FORM_MINS)).validate().is_ok());
+        assert!(with(Some(0)).validate().is_err());
+        assert!(with(Some(MAX_LONG_FORM_MINS + 1)).validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_session_window() {
+        let with = |session_window_secs| AppSettings {
+            session_window_secs,
+            ..AppSettings::default()
+        };
+
+        assert!(with(None).validate().is_ok());
+        assert!(with(Some(20)).validate().is_ok());
+        assert!(with(Some(0)).validate().is_err());
+        assert!(with(Some(MAX_SESSION_WINDOW_SECS + 1)).validate().is_err());
+    }
+
+    #[test]
+    fn test_permission_kind_settings_urls() {
+        for kind in [PermissionKind::Microphone, PermissionKind::Accessibility] {
+            assert!(kind
+                .settings_url()
+                .starts_with("x-apple.systempreferences:com.apple.preference.security?Privacy_"));
+            assert!(kind.settings_url().ends_with(kind.display_name()));
+        }
+        assert_eq!(
+            serde_json::to_string(&PermissionKind::Accessibility).unwrap(),
+            "\"Accessibility\""
+        );
+    }
+
     // =========================
     // Backend Status Tests
     // =========================
@@ -1282,6 +6341,7 @@ mod tests {
             transcription: ServiceStatus::Ready,
             text_injection: ServiceStatus::Ready,
             timestamp: 12345,
+            limitations: vec![],
         };
         assert!(status.is_ready());
     }
@@ -1293,6 +6353,7 @@ mod tests {
             transcription: ServiceStatus::Ready,
             text_injection: ServiceStatus::Ready,
             timestamp: 12345,
+            limitations: vec![],
         };
         assert!(!status.is_ready());
     }
@@ -1304,6 +6365,7 @@ mod tests {
             transcription: ServiceStatus::Error("Failed to load model".to_string()),
             text_injection: ServiceStatus::Ready,
             timestamp: 12345,
+            limitations: vec![],
         };
         assert!(!status.is_ready());
     }
@@ -1315,6 +6377,7 @@ mod tests {
             transcription: ServiceStatus::Starting,
             text_injection: ServiceStatus::Error("Permission denied".to_string()),
             timestamp: 67890,
+            limitations: vec![],
         };
 
         let json = serde_json::to_string(&status).expect("Status should serialize to JSON");
@@ -1349,19 +6412,68 @@ mod tests {
     #[test]
     fn test_transcription_config_default() {
         let config = TranscriptionConfig::default();
-        assert_eq!(config.model_size, ModelSize::Medium);
+        assert_eq!(config.model, ModelId::new("medium"));
         assert_eq!(config.language, None);
         assert!(config.auto_detect_language);
         assert_eq!(config.performance_mode, PerformanceMode::Balanced);
     }
 
+    #[test]
+    fn test_transcription_config_from_settings() {
+        let mut settings = AppSettings {
+            model: ModelId::new("small.en"),
+            ..AppSettings::default()
+        };
+
+        let config = TranscriptionConfig::from_settings(&settings);
+        assert_eq!(config.model.as_str(), "small.en");
+        assert_eq!(config.language, None);
+        assert!(config.auto_detect_language);
+
+        settings.language = "de".to_string();
+        let config = TranscriptionConfig::from_settings(&settings);
+        assert_eq!(config.language, Some("de".to_string()));
+        assert!(!config.auto_detect_language);
+    }
+
+    #[test]
+    fn test_initial_prompt_from_vocabulary() {
+        let settings = AppSettings {
+            vocabulary: vec![
+                " Kubernetes ".to_string(),
+                String::new(),
+                "Dr. Okonkwo".to_string(),
+            ],
+            ..AppSettings::default()
+        };
+        assert_eq!(
+            settings.initial_prompt().as_deref(),
+            Some("Kubernetes, Dr. Okonkwo")
+        );
+        assert_eq!(
+            TranscriptionConfig::from_settings(&settings).initial_prompt,
+            settings.initial_prompt()
+        );
+        assert_eq!(AppSettings::default().initial_prompt(), None);
+    }
+
+    #[test]
+    fn test_settings_reject_oversized_vocabulary() {
+        let settings = AppSettings {
+            vocabulary: vec!["x".repeat(MAX_VOCABULARY_PROMPT_CHARS + 1)],
+            ..AppSettings::default()
+        };
+        assert!(settings.validate().is_err());
+    }
+
     #[test]
     fn test_transcription_config_serialization() {
         let config = TranscriptionConfig {
-            model_size: ModelSize::Large,
+            model: ModelId::new("large-v3"),
             language: Some("en".to_string()),
             auto_detect_language: false,
             performance_mode: PerformanceMode::Accuracy,
+            initial_prompt: Some("Speakr".to_string()),
         };
 
         let json = serde_json::to_string(&config).expect("Config should serialize to JSON");
@@ -1370,13 +6482,52 @@ mod tests {
         assert_eq!(config, deserialized);
     }
 
+    #[test]
+    fn test_microphone_test_verdict() {
+        let test = |rms_dbfs, clipping| MicrophoneTest {
+            device: None,
+            peak_dbfs: 0.0,
+            rms_dbfs,
+            clipping,
+        };
+        assert!(test(-24.0, false).passed());
+        assert!(!test(-24.0, true).passed());
+        assert!(!test(SILENCE_DBFS, false).passed());
+        assert!(test(SILENCE_DBFS, false).summary().starts_with("Too quiet"));
+    }
+
+    #[test]
+    fn test_disk_space_check() {
+        let check = DiskSpaceCheck {
+            required_bytes: 1741 * 1024 * 1024,
+            available_bytes: Some(900 * 1024 * 1024),
+        };
+        assert!(!check.is_sufficient());
+        assert_eq!(
+            check.shortfall_message("ggml-medium.bin"),
+            "Not enough disk space to download ggml-medium.bin: it needs 1.7 GB free, but only \
+             900 MB is available."
+        );
+        assert!(DiskSpaceCheck {
+            available_bytes: None,
+            ..check
+        }
+        .is_sufficient());
+    }
+
     #[test]
     fn test_transcription_error_display() {
         let error = TranscriptionError::ModelNotFound {
-            model_size: ModelSize::Large,
+            model: ModelId::new("large-v3"),
         };
         assert!(error.to_string().contains("Model not found"));
-        assert!(error.to_string().contains("Large"));
+        assert!(error.to_string().contains("large-v3"));
+
+        let corrupt = TranscriptionError::CorruptModel {
+            model: ModelId::new("base.en"),
+        };
+        assert!(corrupt.user_message().contains("base.en"));
+        assert!(!corrupt.suggestions().is_empty());
 
         let error2 = TranscriptionError::ProcessingFailed("Audio too short".to_string());
         assert_eq!(
@@ -1392,6 +6543,7 @@ mod tests {
             start_time: Duration::from_millis(0),
             end_time: Duration::from_millis(1000),
             confidence: 0.95,
+            words: vec![],
         };
 
         assert_eq!(segment.text, "Hello world");
@@ -1406,9 +6558,10 @@ mod tests {
             text: "Hello world".to_string(),
             language: Some("en".to_string()),
             confidence: 0.95,
+            avg_logprob: None,
             processing_time: Duration::from_millis(500),
             memory_delta_bytes: 0,
-            model_used: ModelSize::Medium,
+            model_used: ModelId::new("medium"),
             segments: vec![],
         };
 
@@ -1416,7 +6569,7 @@ mod tests {
         assert_eq!(result.language, Some("en".to_string()));
         assert_eq!(result.confidence, 0.95);
         assert_eq!(result.processing_time, Duration::from_millis(500));
-        assert_eq!(result.model_used, ModelSize::Medium);
+        assert_eq!(result.model_used, ModelId::new("medium"));
         assert!(result.segments.is_empty());
     }
 
@@ -1427,15 +6580,17 @@ mod tests {
             start_time: Duration::from_millis(0),
             end_time: Duration::from_millis(500),
             confidence: 0.98,
+            words: vec![],
         };
 
         let result = TranscriptionResult {
             text: "Hello".to_string(),
             language: Some("en".to_string()),
             confidence: 0.98,
+            avg_logprob: None,
             processing_time: Duration::from_millis(200),
             memory_delta_bytes: 0,
-            model_used: ModelSize::Small,
+            model_used: ModelId::new("small"),
             segments: vec![segment.clone()],
         };
 
@@ -1443,6 +6598,68 @@ mod tests {
         assert_eq!(result.segments[0], segment);
     }
 
+    #[test]
+    fn test_low_confidence_words_span_segments() {
+        let word = |text: &str, confidence: f32| TranscriptionWord {
+            text: text.to_string(),
+            start_time: Duration::ZERO,
+            end_time: Duration::ZERO,
+            confidence,
+        };
+        let segment = |words: Vec<TranscriptionWord>| TranscriptionSegment {
+            text: String::new(),
+            start_time: Duration::ZERO,
+            end_time: Duration::ZERO,
+            confidence: 0.8,
+            words,
+        };
+        let result = TranscriptionResult {
+            text: "Hello wurld, bye now".to_string(),
+            language: None,
+            confidence: 0.8,
+            avg_logprob: Some(-0.4),
+            processing_time: Duration::ZERO,
+            memory_delta_bytes: 0,
+            model_used: ModelId::new("small"),
+            segments: vec![
+                segment(vec![word("Hello", 0.9), word("wurld,", 0.2)]),
+                segment(vec![word("bye", 0.4), word("now", 0.7)]),
+            ],
+        };
+
+        let uncertain: Vec<&str> = result
+            .low_confidence_words(LOW_CONFIDENCE_THRESHOLD)
+            .map(|word| word.text.as_str())
+            .collect();
+        assert_eq!(uncertain, ["wurld,", "bye"]);
+        assert_eq!(result.words().count(), 4);
+    }
+
+    #[test]
+    fn test_batch_progress_round_trip() {
+        let progress = BatchProgress {
+            job_id: 7,
+            file: "/tmp/a.wav".to_string(),
+            status: BatchFileStatus::Failed("corrupt".to_string()),
+            completed: 1,
+            total: 2,
+        };
+
+        let json = serde_json::to_string(&progress).expect("should serialize");
+        let restored: BatchProgress = serde_json::from_str(&json).expect("should deserialize");
+        assert_eq!(restored, progress);
+        assert!(!restored.is_finished());
+    }
+
+    #[test]
+    fn test_transcription_result_without_word_timings_deserializes() {
+        let json = r#"{"text":"hi","language":null,"confidence":0.9,"processing_time":{"secs":0,"nanos":0},"memory_delta_bytes":0,"model_used":"Small","segments":[{"text":"hi","start_time":{"secs":0,"nanos":0},"end_time":{"secs":1,"nanos":0},"confidence":0.9}]}"#;
+
+        let result: TranscriptionResult = serde_json::from_str(json).expect("should deserialize");
+        assert_eq!(result.avg_logprob, None);
+        assert!(result.segments[0].words.is_empty());
+    }
+
     #[test]
     fn test_transcription_types_serialization() {
         // Test PerformanceMode serialization
@@ -1461,6 +6678,53 @@ mod tests {
             serde_json::from_str(&json).expect("JSON should deserialize to TranscriptionError");
         assert_eq!(error, deserialized);
     }
+
+    #[test]
+    fn test_storage_usage_totals_categories() {
+        let usage = StorageUsage {
+            categories: StorageCategory::ALL
+                .into_iter()
+                .map(|category| StorageCategoryUsage {
+                    category,
+                    directory: String::new(),
+                    total_bytes: 100,
+                    file_count: 1,
+                })
+                .collect(),
+        };
+        assert_eq!(usage.total_bytes(), 400);
+        assert_eq!(StorageUsage::default().total_bytes(), 0);
+
+        let cleanable = StorageCategory::ALL
+            .iter()
+            .filter(|category| category.cleanup_label().is_some())
+            .count();
+        assert_eq!(cleanable, 3);
+    }
+
+    #[test]
+    fn test_hotkey_event_payload_is_optional_overrides() {
+        use crate::events::{AudioCaptureCompleted, WorkflowError};
+
+        // The main hot-key sends no overrides, language hot-keys send theirs
+        let none = serde_json::to_string(&None::<DictationOverrides>).unwrap();
+        assert_eq!(none, "null");
+        let overrides = DictationOverrides {
+            language: Some("de".to_string()),
+        };
+        let json = serde_json::to_string(&Some(&overrides)).unwrap();
+        let parsed: Option<DictationOverrides> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, Some(overrides));
+
+        assert_eq!(
+            serde_json::to_string(&WorkflowError::new("Failed")).unwrap(),
+            r#"{"message":"Failed"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&AudioCaptureCompleted { sample_count: 3 }).unwrap(),
+            r#"{"sample_count":3}"#
+        );
+    }
 }
 
 // ===========================================================================
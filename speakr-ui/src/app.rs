@@ -1,30 +1,46 @@
 use leptos::prelude::*;
-use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local;
 
-use crate::settings::SettingsPanel;
-use speakr_types::BackendStatus;
+use crate::corrupt_model::CorruptModelNotice;
+use crate::crash_notice::CrashReportNotice;
+use crate::empty_state::DictationEmptyState;
+use crate::file_transcription::FileTranscription;
+use crate::history::HistoryPanel;
+use crate::i18n::I18n;
+use crate::latency_popover::LatencyPopover;
+use crate::onboarding::OnboardingWizard;
+use crate::pending::PendingDictations;
+use crate::pipeline_status::PipelineStatus;
+use crate::privacy::PrivacyIndicator;
+use crate::settings::{SettingsManager, SettingsPanel};
+use crate::settings_recovery::SettingsRecoveryNotice;
+use crate::status_store::{ServiceHealth, StatusStore};
+use crate::theme::track_appearance;
 
 #[cfg(debug_assertions)]
 use crate::debug::DebugPanel;
 
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
-    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+/// Local-storage key remembering whether the debug panel was open.
+#[cfg(debug_assertions)]
+const LAST_VIEW_KEY: &str = "speakr.last-view";
+
+/// Returns whether the debug panel was the last view shown.
+#[cfg(debug_assertions)]
+fn load_last_view_is_debug() -> bool {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(LAST_VIEW_KEY).ok().flatten())
+        .is_some_and(|view| view == "debug")
 }
 
-/// Helper function to invoke Tauri commands that return backend status
-async fn get_backend_status() -> Result<BackendStatus, String> {
-    let args = JsValue::NULL;
-    let result = invoke("get_backend_status", args).await;
-    let json_str = js_sys::JSON::stringify(&result)
-        .map_err(|_| "Failed to stringify response".to_string())?
-        .as_string()
-        .ok_or("Failed to convert to string".to_string())?;
-
-    serde_json::from_str::<BackendStatus>(&json_str)
-        .map_err(|e| format!("Failed to parse backend status: {e}"))
+/// Remembers the current view so it is shown again on the next launch.
+#[cfg(debug_assertions)]
+fn save_last_view_is_debug(debug: bool) {
+    if let Some(storage) =
+        web_sys::window().and_then(|window| window.local_storage().ok().flatten())
+    {
+        let _ = storage.set_item(LAST_VIEW_KEY, if debug { "debug" } else { "home" });
+    }
 }
 
 /// Main application view focused on settings configuration.
@@ -32,49 +48,42 @@ async fn get_backend_status() -> Result<BackendStatus, String> {
 #[component]
 pub fn App() -> impl IntoView {
     #[cfg(debug_assertions)]
-    let (show_debug_panel, set_show_debug_panel) = signal(false);
+    let (show_debug_panel, set_show_debug_panel) = signal(load_last_view_is_debug());
 
-    // Backend status state
-    let (backend_status, set_backend_status) = signal(BackendStatus::new_starting());
+    // Backend status, kept in sync as services change state
+    let status_store = StatusStore::provide();
 
-    // Load initial backend status
-    Effect::new(move || {
-        spawn_local(async move {
-            match get_backend_status().await {
-                Ok(status) => {
-                    set_backend_status.set(status);
-                }
-                Err(e) => {
-                    web_sys::console::error_1(
-                        &format!("Failed to load backend status: {e}").into(),
-                    );
-                }
-            }
-        });
-    });
+    // Interface language, switched live when the setting changes
+    let i18n = I18n::provide();
 
-    // TODO: Add event listener for "speakr-status-changed" events from backend
-    // This would update the status in real-time when services change state
+    // Light or dark palette, following the system appearance if asked to,
+    // and whether animations are turned off
+    let appearance = track_appearance();
 
     view! {
-        <div class="app">
+        <div
+            class="app"
+            data-theme=appearance.theme
+            data-reduce-motion=move || appearance.reduce_motion.get().to_string()
+        >
             // Header with app branding
             <header class="app-header">
                 <div class="header-content">
                     <div class="brand">
                         <div class="brand-text">
                             <h1 class="brand-title">"Speakr"</h1>
-                            <p class="brand-subtitle">"Privacy-first dictation"</p>
+                            <p class="brand-subtitle">{move || i18n.t("app.subtitle")}</p>
                         </div>
                     </div>
                     <div class="header-status">
+                        <PrivacyIndicator />
                         {move || {
-                            let status = backend_status.get();
+                            let status = status_store.status();
                             let status_class = if status.is_ready() { "ready" } else { "starting" };
                             let status_text = if status.is_ready() {
-                                "Ready"
+                                i18n.t("app.status.ready")
                             } else {
-                                "Starting..."
+                                i18n.t("app.status.starting")
                             };
 
                             view! {
@@ -92,7 +101,10 @@ pub fn App() -> impl IntoView {
                                 view! {
                                     <button
                                         class="debug-toggle-btn"
-                                        on:click=move |_| set_show_debug_panel.update(|show| *show = !*show)
+                                        on:click=move |_| {
+                                            set_show_debug_panel.update(|show| *show = !*show);
+                                            save_last_view_is_debug(show_debug_panel.get_untracked());
+                                        }
                                         title="Toggle Debug Panel (Debug Build Only)"
                                     >
                                         {move || if show_debug_panel.get() { "🛠️ Hide Debug" } else { "🛠️ Debug" }}
@@ -117,24 +129,75 @@ pub fn App() -> impl IntoView {
                             if show_debug_panel.get() {
                                 view! { <DebugPanel /> }.into_any()
                             } else {
-                                view! { <SettingsPanel /> }.into_any()
+                                view! { <HomeView /> }.into_any()
                             }
                         }
                         #[cfg(not(debug_assertions))]
                         {
-                            view! { <SettingsPanel /> }.into_any()
+                            view! { <HomeView /> }.into_any()
                         }
                     }}
                 </div>
             </main>
 
+            <LatencyPopover />
+            <SettingsRecoveryNotice />
+            <CorruptModelNotice />
+            <CrashReportNotice />
+
             // Footer with version info
             <footer class="app-footer">
                 <div class="footer-content">
                     <span class="version-info">"Speakr v0.1.0"</span>
-                    <span class="privacy-note">"✓ All processing happens locally on your device"</span>
+                    <span class="privacy-note">{move || i18n.t("app.privacy_note")}</span>
                 </div>
             </footer>
         </div>
     }
 }
+
+/// Main view: live dictation progress, service health, a hint on how to
+/// dictate, file transcription, and the settings. The onboarding wizard is shown instead until it has been
+/// completed once.
+#[component]
+fn HomeView() -> impl IntoView {
+    let i18n = I18n::expect();
+
+    // `None` until settings have loaded
+    let (onboarding_completed, set_onboarding_completed) = signal::<Option<bool>>(None);
+
+    Effect::new(move || {
+        spawn_local(async move {
+            match SettingsManager::load().await {
+                Ok(settings) => set_onboarding_completed.set(Some(settings.onboarding_completed)),
+                Err(e) => {
+                    web_sys::console::error_1(&e.into());
+                    set_onboarding_completed.set(Some(true));
+                }
+            }
+        });
+    });
+
+    move || match onboarding_completed.get() {
+        None => ().into_any(),
+        Some(false) => view! {
+            <OnboardingWizard on_finish=Callback::new(move |_| {
+                set_onboarding_completed.set(Some(true))
+            }) />
+        }
+        .into_any(),
+        Some(true) => view! {
+            <PipelineStatus />
+            <ServiceHealth />
+            <DictationEmptyState
+                title=Signal::derive(move || i18n.t("home.empty.title"))
+                message=Signal::derive(move || i18n.t("home.empty.message"))
+            />
+            <PendingDictations />
+            <HistoryPanel />
+            <FileTranscription />
+            <SettingsPanel />
+        }
+        .into_any(),
+    }
+}
@@ -0,0 +1,94 @@
+//! Notice shown when the selected model's file is corrupt.
+//!
+//! Each time the backend loads a model it checks the file against its
+//! published checksum. A mismatch arrives on the `corrupt-model` event with
+//! the model's id; [`CorruptModelNotice`] explains the problem and offers to
+//! download the model again, replacing the damaged file.
+
+use leptos::prelude::*;
+use speakr_types::{ModelId, CORRUPT_MODEL_EVENT};
+use wasm_bindgen_futures::spawn_local;
+
+use crate::ipc::listen_typed_with;
+use crate::settings::SettingsManager;
+
+/// Explanation shown to the user for a corrupt model.
+pub fn corrupt_model_explanation(model: &ModelId) -> String {
+    format!(
+        "The {model} model file ({}) does not match its published checksum, so it may have been \
+         damaged on disk or during download. Download it again to keep dictating.",
+        model.filename()
+    )
+}
+
+/// Dialog offering to re-download a corrupt model.
+#[component]
+pub fn CorruptModelNotice() -> impl IntoView {
+    let (corrupt, set_corrupt) = signal::<Option<ModelId>>(None);
+    let (downloading, set_downloading) = signal(false);
+    let (error_message, set_error_message) = signal::<Option<String>>(None);
+
+    listen_typed_with(CORRUPT_MODEL_EVENT, move |model: ModelId| {
+        if !downloading.get_untracked() {
+            set_error_message.set(None);
+            set_corrupt.set(Some(model));
+        }
+    });
+
+    let redownload = move |_| {
+        let Some(model) = corrupt.get_untracked() else {
+            return;
+        };
+        set_downloading.set(true);
+        set_error_message.set(None);
+        spawn_local(async move {
+            match SettingsManager::download_model(&model.filename()).await {
+                Ok(()) => set_corrupt.set(None),
+                Err(e) => set_error_message.set(Some(e)),
+            }
+            set_downloading.set(false);
+        });
+    };
+
+    move || {
+        corrupt.get().map(|model| {
+            view! {
+                <div class="settings-recovery corrupt-model" role="alertdialog">
+                    <h3>"⚠️ Model file is damaged"</h3>
+                    <p>{corrupt_model_explanation(&model)}</p>
+                    {move || error_message.get().map(|message| view! {
+                        <div class="error-message" role="alert">{message}</div>
+                    })}
+                    <div class="settings-recovery-actions">
+                        <button
+                            class="btn-secondary"
+                            disabled=move || downloading.get()
+                            on:click=move |_| set_corrupt.set(None)
+                        >
+                            "Later"
+                        </button>
+                        <button
+                            class="btn-primary"
+                            disabled=move || downloading.get()
+                            on:click=redownload
+                        >
+                            {move || if downloading.get() { "Downloading…" } else { "Re-download" }}
+                        </button>
+                    </div>
+                </div>
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_corrupt_model_explanation_names_the_file() {
+        let text = corrupt_model_explanation(&ModelId::new("base.en"));
+        assert!(text.contains("base.en model"));
+        assert!(text.contains("ggml-base.en.bin"));
+    }
+}
@@ -0,0 +1,97 @@
+//! Notice shown when the previous run of Speakr crashed.
+//!
+//! When Speakr panics or quits unexpectedly, the backend writes a redacted
+//! crash report to its local `crashes/` directory and records a
+//! [`CrashNotice`]. On the next launch [`CrashReportNotice`] says so and
+//! offers to open the report; it is shown until dismissed. Reports never
+//! leave the Mac. The notice is fetched on load and also arrives on the
+//! `crash-reported` event.
+
+use leptos::prelude::*;
+use speakr_types::{CrashNotice, CRASH_REPORTED_EVENT};
+use wasm_bindgen_futures::spawn_local;
+
+use crate::ipc::listen_typed_with;
+use crate::settings::SettingsManager;
+
+/// Explanation shown to the user for a crash.
+pub fn crash_explanation(notice: &CrashNotice) -> String {
+    format!(
+        "Speakr stopped unexpectedly last time ({}). A crash report was saved at {}. \
+         It holds no audio or dictated text and is never uploaded; you can attach it to a bug \
+         report if you like.",
+        notice.summary, notice.report_path
+    )
+}
+
+/// One-time dialog about a crash in the previous run.
+#[component]
+pub fn CrashReportNotice() -> impl IntoView {
+    let (notice, set_notice) = signal::<Option<CrashNotice>>(None);
+    let (error_message, set_error_message) = signal::<Option<String>>(None);
+
+    spawn_local(async move {
+        match SettingsManager::get_crash_notice().await {
+            Ok(loaded) => set_notice.set(loaded),
+            Err(e) => web_sys::console::error_1(&e.into()),
+        }
+    });
+    listen_typed_with(CRASH_REPORTED_EVENT, move |crash: CrashNotice| {
+        set_notice.set(Some(crash))
+    });
+
+    let open_report = move |_| {
+        spawn_local(async move {
+            if let Err(e) = SettingsManager::open_crash_report().await {
+                set_error_message.set(Some(e));
+            }
+        });
+    };
+    let dismiss = move |_| {
+        spawn_local(async move {
+            match SettingsManager::dismiss_crash_notice().await {
+                Ok(()) => set_notice.set(None),
+                Err(e) => set_error_message.set(Some(e)),
+            }
+        });
+    };
+
+    move || {
+        notice.get().map(|notice| {
+            view! {
+                <div class="settings-recovery crash-notice" role="alertdialog">
+                    <h3>"💥 Speakr crashed"</h3>
+                    <p>{crash_explanation(&notice)}</p>
+                    {move || error_message.get().map(|message| view! {
+                        <div class="error-message" role="alert">{message}</div>
+                    })}
+                    <div class="settings-recovery-actions">
+                        <button class="btn-secondary" on:click=open_report>
+                            "Open crash report"
+                        </button>
+                        <button class="btn-primary" on:click=dismiss>
+                            "Got it"
+                        </button>
+                    </div>
+                </div>
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crash_explanation_names_the_report() {
+        let notice = CrashNotice {
+            report_path: "/config/crashes/crash-1.txt".to_string(),
+            summary: "Panic at src/workflow.rs:42".to_string(),
+            crashed_at: 1,
+        };
+        let text = crash_explanation(&notice);
+        assert!(text.contains("/config/crashes/crash-1.txt"));
+        assert!(text.contains("(Panic at src/workflow.rs:42)"));
+    }
+}
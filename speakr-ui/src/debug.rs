@@ -2,8 +2,9 @@
 //!
 //! This module provides debugging utilities and testing interfaces
 //! that are only available in debug builds. It includes:
-//! - Audio recording test interface
+//! - Audio recording test interface with a dBFS input meter
 //! - Debug information display
+//! - Per-subsystem log verbosity controls
 //! - Development-only controls
 //!
 //! The module is conditionally compiled using `#[cfg(debug_assertions)]`
@@ -11,9 +12,25 @@
 
 use leptos::prelude::*;
 use serde::{Deserialize, Serialize};
+use speakr_types::{
+    dbfs_to_level, LatencyStats, LogSubsystem, LogVerbosity, PerformanceMetrics, ProcessingStage,
+    SubsystemLogLevel, DICTATION_LATENCY_EVENT,
+};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local;
 
+use crate::ipc::{
+    describe_command_error, invoke_command, invoke_command_no_args, listen_typed_with,
+};
+use crate::latency_popover::format_us;
+use crate::pipeline_status::{format_dbfs, level_percent};
+
+/// Whether a key held on the push-to-talk button records, like holding the
+/// mouse button down: Space or Enter.
+pub fn is_hold_key(code: &str) -> bool {
+    matches!(code, "Space" | "Enter" | "NumpadEnter")
+}
+
 /// Log level for filtering console messages
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum LogLevel {
@@ -106,32 +123,63 @@ impl LogMessage {
     }
 }
 
-/// External bindings to Tauri APIs
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_namespace = ["window", "__TAURI_INTERNALS__"], js_name = invoke)]
-    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+/// Number of log messages the console fetches at a time
+pub const LOG_PAGE_SIZE: usize = 200;
+
+/// Which log messages to fetch; the backend pages them newest first
+#[derive(Debug, Clone, Serialize)]
+pub struct LogQuery {
+    pub min_level: Option<LogLevel>,
+    pub offset: usize,
+    pub limit: Option<usize>,
+}
+
+/// One page of log messages from the backend
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LogPage {
+    pub messages: Vec<LogMessage>,
+    pub total: usize,
+    pub dropped: u64,
+}
+
+/// Summary line under the log console, e.g. "Showing 200 of 950 messages"
+fn log_page_summary(shown: usize, total: usize, dropped: u64) -> String {
+    let mut summary = format!("Showing {shown} of {total} messages");
+    if dropped > 0 {
+        summary.push_str(&format!(" ({dropped} older messages dropped)"));
+    }
+    summary
+}
+
+/// Summary of a fixture saved by the backend fixture recorder
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecordedFixture {
+    pub name: String,
+    pub transcript: String,
+}
+
+/// Transcripts of one recording with and without noise suppression
+#[derive(Debug, Clone, Deserialize)]
+pub struct DenoiseComparison {
+    pub original: String,
+    pub denoised: String,
 }
 
 /// Helper function to invoke Tauri commands
-#[allow(dead_code)]
 async fn tauri_invoke<T: for<'de> Deserialize<'de>, U: Serialize>(
     cmd: &str,
     args: &U,
 ) -> Result<T, String> {
-    let js_args =
-        serde_wasm_bindgen::to_value(args).map_err(|e| format!("Failed to serialize args: {e}"))?;
-
-    let result = invoke(cmd, js_args).await;
-
-    serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to deserialize result: {e}"))
+    invoke_command(cmd, args)
+        .await
+        .map_err(|e| describe_command_error(&e))
 }
 
 /// Helper function for commands without arguments
 async fn tauri_invoke_no_args<T: for<'de> Deserialize<'de>>(cmd: &str) -> Result<T, String> {
-    let result = invoke(cmd, JsValue::NULL).await;
-
-    serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to deserialize result: {e}"))
+    invoke_command_no_args(cmd)
+        .await
+        .map_err(|e| describe_command_error(&e))
 }
 
 /// Filters log messages and returns them in reverse chronological order (newest first)
@@ -186,6 +234,13 @@ fn filter_messages_by_level_hierarchy(
 pub struct DebugManager;
 
 impl DebugManager {
+    /// Fetches the latency statistics of recent dictations
+    pub async fn get_performance_metrics() -> Result<PerformanceMetrics, String> {
+        tauri_invoke_no_args("get_performance_metrics")
+            .await
+            .map_err(|e| format!("Failed to load performance metrics: {e}"))
+    }
+
     /// Tests audio recording functionality
     pub async fn test_audio_recording() -> Result<String, String> {
         tauri_invoke_no_args("debug_test_audio_recording")
@@ -207,9 +262,144 @@ impl DebugManager {
             .map_err(|e| format!("Failed to stop recording: {e}"))
     }
 
-    /// Gets recent log messages from the backend
-    pub async fn get_log_messages() -> Result<Vec<LogMessage>, String> {
-        tauri_invoke_no_args("debug_get_log_messages")
+    /// Reads the input level of the push-to-talk recording, in dBFS
+    pub async fn input_level() -> Result<Option<f32>, String> {
+        tauri_invoke_no_args("debug_input_level")
+            .await
+            .map_err(|e| format!("Failed to read input level: {e}"))
+    }
+
+    /// Fetches the last captured audio as a WAV data URL
+    pub async fn play_last_recording() -> Result<String, String> {
+        tauri_invoke_no_args("debug_play_last_recording")
+            .await
+            .map_err(|e| format!("Failed to load last recording: {e}"))
+    }
+
+    /// Replays a saved WAV file through the full dictation workflow
+    pub async fn replay_wav(path: &str, sandboxed: bool) -> Result<String, String> {
+        #[derive(serde::Serialize)]
+        struct ReplayWavArgs {
+            path: String,
+            sandboxed: bool,
+        }
+
+        let args = ReplayWavArgs {
+            path: path.to_string(),
+            sandboxed,
+        };
+
+        tauri_invoke("debug_replay_wav", &args)
+            .await
+            .map_err(|e| format!("Failed to replay WAV file: {e}"))
+    }
+
+    /// Transcribes a saved WAV file with and without noise suppression
+    pub async fn compare_denoise(path: &str) -> Result<DenoiseComparison, String> {
+        #[derive(serde::Serialize)]
+        struct CompareDenoiseArgs {
+            path: String,
+        }
+
+        let args = CompareDenoiseArgs {
+            path: path.to_string(),
+        };
+
+        tauri_invoke("debug_compare_denoise", &args)
+            .await
+            .map_err(|e| format!("Failed to compare noise suppression: {e}"))
+    }
+
+    /// Records a real dictation and saves it as a test fixture pair
+    pub async fn record_fixture(output_dir: &str) -> Result<RecordedFixture, String> {
+        #[derive(serde::Serialize)]
+        struct RecordFixtureArgs {
+            #[serde(rename = "outputDir")]
+            output_dir: String,
+        }
+
+        let args = RecordFixtureArgs {
+            output_dir: output_dir.to_string(),
+        };
+
+        tauri_invoke("debug_record_fixture", &args)
+            .await
+            .map_err(|e| format!("Failed to record fixture: {e}"))
+    }
+
+    /// Starts recording pipeline events and timing for bug reproduction
+    pub async fn start_session_recording() -> Result<(), String> {
+        tauri_invoke_no_args::<()>("debug_start_session_recording")
+            .await
+            .map_err(|e| format!("Failed to start session recording: {e}"))
+    }
+
+    /// Stops the session recording and saves it, returning the file path
+    pub async fn stop_session_recording(output_dir: &str) -> Result<String, String> {
+        #[derive(serde::Serialize)]
+        struct StopSessionRecordingArgs {
+            #[serde(rename = "outputDir")]
+            output_dir: String,
+        }
+
+        let args = StopSessionRecordingArgs {
+            output_dir: output_dir.to_string(),
+        };
+
+        tauri_invoke("debug_stop_session_recording", &args)
+            .await
+            .map_err(|e| format!("Failed to save session: {e}"))
+    }
+
+    /// Gets the per-subsystem log verbosity overrides
+    pub async fn get_log_levels() -> Result<Vec<SubsystemLogLevel>, String> {
+        tauri_invoke_no_args("get_log_levels")
+            .await
+            .map_err(|e| format!("Failed to get log levels: {e}"))
+    }
+
+    /// Sets the log verbosity for one subsystem (`None` follows `RUST_LOG`)
+    pub async fn set_log_level(
+        subsystem: LogSubsystem,
+        level: Option<LogVerbosity>,
+    ) -> Result<Vec<SubsystemLogLevel>, String> {
+        #[derive(serde::Serialize)]
+        struct SetLogLevelArgs {
+            subsystem: LogSubsystem,
+            level: Option<LogVerbosity>,
+        }
+
+        let args = SetLogLevelArgs { subsystem, level };
+
+        tauri_invoke("set_log_level", &args)
+            .await
+            .map_err(|e| format!("Failed to set log level: {e}"))
+    }
+
+    /// Gets the path of the JSON log file
+    pub async fn get_log_file_path() -> Result<String, String> {
+        tauri_invoke_no_args("get_log_file_path")
+            .await
+            .map_err(|e| format!("Failed to get log file path: {e}"))
+    }
+
+    /// Opens the folder holding the log files
+    pub async fn open_logs_folder() -> Result<(), String> {
+        tauri_invoke_no_args::<()>("open_logs_folder")
+            .await
+            .map_err(|e| format!("Failed to open logs folder: {e}"))
+    }
+
+    /// Gets one page of recent log messages from the backend
+    pub async fn get_log_messages(query: LogQuery) -> Result<LogPage, String> {
+        #[derive(serde::Serialize)]
+        struct GetLogMessagesArgs {
+            query: LogQuery,
+        }
+
+        let args = GetLogMessagesArgs { query };
+
+        tauri_invoke("debug_get_log_messages", &args)
             .await
             .map_err(|e| format!("Failed to get log messages: {e}"))
     }
@@ -226,6 +416,9 @@ impl DebugManager {
 #[component]
 pub fn LoggingConsole() -> impl IntoView {
     let (log_messages, set_log_messages) = signal::<Vec<LogMessage>>(Vec::new());
+    let (log_total, set_log_total) = signal(0usize);
+    let (log_dropped, set_log_dropped) = signal(0u64);
+    let (log_limit, set_log_limit) = signal(LOG_PAGE_SIZE);
     let (selected_level, set_selected_level) = signal::<Option<LogLevel>>(None);
     let (auto_scroll, set_auto_scroll) = signal(true);
 
@@ -234,10 +427,17 @@ pub fn LoggingConsole() -> impl IntoView {
 
     // Function to refresh log messages - create a reusable function
     let do_refresh = move || {
+        let query = LogQuery {
+            min_level: selected_level.get_untracked(),
+            offset: 0,
+            limit: Some(log_limit.get_untracked()),
+        };
         spawn_local(async move {
-            match DebugManager::get_log_messages().await {
-                Ok(messages) => {
-                    set_log_messages.set(messages);
+            match DebugManager::get_log_messages(query).await {
+                Ok(page) => {
+                    set_log_total.set(page.total);
+                    set_log_dropped.set(page.dropped);
+                    set_log_messages.set(page.messages);
                 }
                 Err(_) => {
                     // Silently fail - we don't want to spam errors in a debug console
@@ -262,15 +462,19 @@ pub fn LoggingConsole() -> impl IntoView {
                             message: "Mock warning message".to_string(),
                         },
                     ];
+                    set_log_total.set(mock_messages.len());
                     set_log_messages.set(mock_messages);
                 }
             }
         });
     };
 
-    // Initial load of log messages
+    // Load log messages initially and whenever the level filter or page
+    // count changes; the backend does the filtering
     Effect::new({
         move || {
+            selected_level.track();
+            log_limit.track();
             do_refresh();
         }
     });
@@ -323,6 +527,8 @@ pub fn LoggingConsole() -> impl IntoView {
         spawn_local(async move {
             let _ = DebugManager::clear_log_messages().await;
             set_log_messages.set(Vec::new());
+            set_log_total.set(0);
+            set_log_dropped.set(0);
         });
     };
 
@@ -336,8 +542,10 @@ pub fn LoggingConsole() -> impl IntoView {
                 <div class="console-controls">
                     <select
                         class="log-level-filter"
+                        aria-label="Show log level"
                         on:change=move |e| {
                             let value = event_target_value(&e);
+                            set_log_limit.set(LOG_PAGE_SIZE);
                             if value == "all" {
                                 set_selected_level.set(None);
                             } else {
@@ -399,7 +607,13 @@ pub fn LoggingConsole() -> impl IntoView {
                 </div>
             </div>
 
-            <div class="console-messages" class:auto-scroll={move || auto_scroll.get()}>
+            <div
+                class="console-messages"
+                class:auto-scroll={move || auto_scroll.get()}
+                role="log"
+                aria-label="Log messages"
+                tabindex="0"
+            >
                 {move || {
                     let messages = filtered_messages();
                     if messages.is_empty() {
@@ -424,6 +638,20 @@ pub fn LoggingConsole() -> impl IntoView {
                     }
                 }}
             </div>
+
+            <div class="console-footer">
+                <span class="console-summary" aria-live="polite">
+                    {move || log_page_summary(log_messages.get().len(), log_total.get(), log_dropped.get())}
+                </span>
+                {move || (log_messages.get().len() < log_total.get()).then(|| view! {
+                    <button
+                        class="load-older-logs-btn"
+                        on:click=move |_| set_log_limit.update(|limit| *limit += LOG_PAGE_SIZE)
+                    >
+                        "⬇️ Load older"
+                    </button>
+                })}
+            </div>
         </div>
     }
 }
@@ -454,6 +682,33 @@ fn event_target_checked(event: &web_sys::Event) -> bool {
         .checked()
 }
 
+/// Table cells for one latency: last, min, mean, p95 and max.
+pub fn latency_cells(stats: &LatencyStats) -> [String; 5] {
+    [
+        stats.last_ms,
+        stats.min_ms,
+        stats.mean_ms,
+        stats.p95_ms,
+        stats.max_ms,
+    ]
+    .map(|ms| ms.to_string())
+}
+
+/// Table cells for one post-processing step: name, time and how many
+/// segments ran concurrently.
+pub fn pipeline_stage_cells(stage: &ProcessingStage) -> [String; 3] {
+    let concurrency = if stage.segments > 1 {
+        format!("{} threads", stage.segments)
+    } else {
+        "—".to_string()
+    };
+    [
+        stage.name.clone(),
+        format_us(stage.duration_us),
+        concurrency,
+    ]
+}
+
 /// Debug panel component for development builds only.
 ///
 /// This component provides debugging tools and test interfaces
@@ -463,6 +718,74 @@ pub fn DebugPanel() -> impl IntoView {
     // Debug state
     let (debug_message, set_debug_message) = signal::<Option<String>>(None);
     let (is_recording, set_is_recording) = signal(false);
+    let (input_level, set_input_level) = signal::<Option<f32>>(None);
+
+    // Playback of the last captured audio
+    let (playback_url, set_playback_url) = signal::<Option<String>>(None);
+
+    // WAV replay state
+    let (replay_path, set_replay_path) = signal(String::new());
+    let (replay_sandboxed, set_replay_sandboxed) = signal(true);
+    let (is_replaying, set_is_replaying) = signal(false);
+
+    // Fixture recorder state
+    let (fixture_dir, set_fixture_dir) = signal(String::new());
+
+    // Session recorder state
+    let (session_dir, set_session_dir) = signal(String::new());
+    let (is_recording_session, set_is_recording_session) = signal(false);
+
+    // Per-subsystem log verbosity
+    let (log_levels, set_log_levels) = signal::<Vec<SubsystemLogLevel>>(Vec::new());
+
+    // Dictation latency, refreshed after every dictation
+    let (performance, set_performance) = signal(PerformanceMetrics::default());
+    let refresh_performance = move || {
+        spawn_local(async move {
+            match DebugManager::get_performance_metrics().await {
+                Ok(metrics) => set_performance.set(metrics),
+                Err(e) => set_debug_message.set(Some(format!("❌ {e}"))),
+            }
+        });
+    };
+    refresh_performance();
+    listen_typed_with(DICTATION_LATENCY_EVENT, move |_: serde_json::Value| {
+        refresh_performance()
+    });
+
+    Effect::new(move || {
+        spawn_local(async move {
+            match DebugManager::get_log_levels().await {
+                Ok(levels) => set_log_levels.set(levels),
+                Err(e) => set_debug_message.set(Some(format!("❌ {e}"))),
+            }
+        });
+    });
+
+    // Log file written by the backend
+    let (log_file_path, set_log_file_path) = signal::<Option<String>>(None);
+    spawn_local(async move {
+        match DebugManager::get_log_file_path().await {
+            Ok(path) => set_log_file_path.set(Some(path)),
+            Err(e) => set_debug_message.set(Some(format!("❌ {e}"))),
+        }
+    });
+    let open_logs_folder = move || {
+        spawn_local(async move {
+            if let Err(e) = DebugManager::open_logs_folder().await {
+                set_debug_message.set(Some(format!("❌ {e}")));
+            }
+        });
+    };
+
+    let set_log_level = move |subsystem: LogSubsystem, level: Option<LogVerbosity>| {
+        spawn_local(async move {
+            match DebugManager::set_log_level(subsystem, level).await {
+                Ok(levels) => set_log_levels.set(levels),
+                Err(e) => set_debug_message.set(Some(format!("❌ {e}"))),
+            }
+        });
+    };
 
     // Test audio recording function (legacy - for compatibility)
     let test_audio_recording = move || {
@@ -491,6 +814,14 @@ pub fn DebugPanel() -> impl IntoView {
             match DebugManager::start_recording().await {
                 Ok(result) => {
                     set_debug_message.set(Some(format!("🎙️ Recording: {result}")));
+                    // Poll the level until the recording is stopped
+                    while is_recording.get_untracked() {
+                        if let Ok(Some(dbfs)) = DebugManager::input_level().await {
+                            set_input_level.set(Some(dbfs));
+                        }
+                        gloo_timers::future::TimeoutFuture::new(100).await;
+                    }
+                    set_input_level.set(None);
                 }
                 Err(e) => {
                     set_debug_message.set(Some(format!("❌ Failed to start recording: {e}")));
@@ -516,18 +847,115 @@ pub fn DebugPanel() -> impl IntoView {
         }
     };
 
+    let play_last_recording = move || {
+        spawn_local(async move {
+            match DebugManager::play_last_recording().await {
+                Ok(url) => set_playback_url.set(Some(url)),
+                Err(e) => set_debug_message.set(Some(format!("❌ {e}"))),
+            }
+        });
+    };
+
+    let replay_wav = move || {
+        let path = replay_path.get();
+        let sandboxed = replay_sandboxed.get();
+        set_is_replaying.set(true);
+        set_debug_message.set(Some(format!("🔁 Replaying {path}...")));
+
+        spawn_local(async move {
+            match DebugManager::replay_wav(&path, sandboxed).await {
+                Ok(text) => {
+                    set_debug_message.set(Some(format!("✅ Replay transcription: {text}")));
+                }
+                Err(e) => {
+                    set_debug_message.set(Some(format!("❌ Replay failed: {e}")));
+                }
+            }
+            set_is_replaying.set(false);
+        });
+    };
+
+    let compare_denoise = move || {
+        let path = replay_path.get();
+        set_is_replaying.set(true);
+        set_debug_message.set(Some(format!("🔇 Comparing noise suppression on {path}...")));
+
+        spawn_local(async move {
+            match DebugManager::compare_denoise(&path).await {
+                Ok(comparison) => {
+                    set_debug_message.set(Some(format!(
+                        "✅ Original: {} | Denoised: {}",
+                        comparison.original, comparison.denoised
+                    )));
+                }
+                Err(e) => {
+                    set_debug_message.set(Some(format!("❌ Comparison failed: {e}")));
+                }
+            }
+            set_is_replaying.set(false);
+        });
+    };
+
+    let record_fixture = move || {
+        let output_dir = fixture_dir.get();
+        set_is_recording.set(true);
+        set_debug_message.set(Some("🧪 Recording fixture...".to_string()));
+
+        spawn_local(async move {
+            match DebugManager::record_fixture(&output_dir).await {
+                Ok(fixture) => {
+                    set_debug_message.set(Some(format!(
+                        "✅ Saved {} → {}: {}",
+                        fixture.name, output_dir, fixture.transcript
+                    )));
+                }
+                Err(e) => {
+                    set_debug_message.set(Some(format!("❌ Fixture recording failed: {e}")));
+                }
+            }
+            set_is_recording.set(false);
+        });
+    };
+
+    let toggle_session_recording = move || {
+        if is_recording_session.get_untracked() {
+            let output_dir = session_dir.get_untracked();
+            spawn_local(async move {
+                match DebugManager::stop_session_recording(&output_dir).await {
+                    Ok(path) => {
+                        set_is_recording_session.set(false);
+                        set_debug_message.set(Some(format!("✅ Session saved → {path}")));
+                    }
+                    Err(e) => set_debug_message.set(Some(format!("❌ {e}"))),
+                }
+            });
+        } else {
+            spawn_local(async move {
+                match DebugManager::start_session_recording().await {
+                    Ok(()) => {
+                        set_is_recording_session.set(true);
+                        set_debug_message.set(Some(
+                            "🎬 Recording session — reproduce the bug, then stop".to_string(),
+                        ));
+                    }
+                    Err(e) => set_debug_message.set(Some(format!("❌ {e}"))),
+                }
+            });
+        }
+    };
+
     view! {
-        <div class="debug-panel">
+        <section class="debug-panel" aria-labelledby="debug-title">
             <div class="debug-header">
-                <h2>"🛠️ Debug Panel"</h2>
+                <h2 id="debug-title">"🛠️ Debug Panel"</h2>
                 <p class="debug-description">"Development tools and testing interface"</p>
                 <div class="debug-badge">"DEBUG BUILD"</div>
             </div>
 
             <div class="debug-content">
                 // Audio Testing Section
-                <div class="debug-group">
-                    <h3>"🎙️ Audio Testing"</h3>
+                <div class="debug-group" role="group" aria-labelledby="debug-audio">
+                    <h3 id="debug-audio">"🎙️ Audio Testing"</h3>
                     <p class="debug-description">
                         "Test audio recording functionality with push-to-talk interface"
                     </p>
@@ -554,6 +982,22 @@ pub fn DebugPanel() -> impl IntoView {
                             on:mouseleave=move |_| stop_recording()
                             on:touchstart=move |_| start_recording()
                             on:touchend=move |_| stop_recording()
+                            on:keydown=move |e: leptos::ev::KeyboardEvent| {
+                                if is_hold_key(&e.code()) {
+                                    e.prevent_default();
+                                    if !e.repeat() && !is_recording.get_untracked() {
+                                        start_recording();
+                                    }
+                                }
+                            }
+                            on:keyup=move |e: leptos::ev::KeyboardEvent| {
+                                if is_hold_key(&e.code()) {
+                                    stop_recording();
+                                }
+                            }
+                            on:blur=move |_| stop_recording()
+                            aria-pressed=move || is_recording.get().to_string()
+                            aria-label="Hold to record. Hold Space or Enter while focused."
                         >
                             {move || if is_recording.get() {
                                 "🔴 Recording... (Release to stop)"
@@ -561,16 +1005,292 @@ pub fn DebugPanel() -> impl IntoView {
                                 "🎙️ Hold to Record"
                             }}
                         </button>
+
+                        <button
+                            class="debug-btn-secondary"
+                            on:click=move |_| play_last_recording()
+                            disabled={move || is_recording.get()}
+                            title="Listen to the last dictation or push-to-talk recording"
+                        >
+                            "🔊 Play Last Recording"
+                        </button>
+                    </div>
+
+                    {move || playback_url.get().map(|url| view! {
+                        <audio class="debug-playback" controls=true autoplay=true src=url></audio>
+                    })}
+
+                    {move || input_level.get().map(|dbfs| view! {
+                        <div
+                        class="debug-level"
+                        role="meter"
+                        aria-label="Input level"
+                        aria-valuemin="0"
+                        aria-valuemax="100"
+                        aria-valuenow=level_percent(dbfs_to_level(dbfs))
+                        aria-valuetext=format_dbfs(dbfs)
+                    >
+                            <div class="level-meter">
+                                <div
+                                    class="level-fill"
+                                    style=format!("width: {}%", level_percent(dbfs_to_level(dbfs)))
+                                ></div>
+                            </div>
+                            <span class="level-readout">{format_dbfs(dbfs)}</span>
+                        </div>
+                    })}
+                </div>
+
+                // WAV Replay Section
+                <div class="debug-group" role="group" aria-labelledby="debug-replay">
+                    <h3 id="debug-replay">"🔁 Replay Recording"</h3>
+                    <p class="debug-description">
+                        "Push a saved WAV file through transcription and injection exactly as a live recording would"
+                    </p>
+
+                    <div class="debug-controls">
+                        <input
+                            type="text"
+                            class="replay-path-input"
+                            aria-label="WAV file to replay"
+                            placeholder="/path/to/recording.wav"
+                            prop:value={move || replay_path.get()}
+                            on:input=move |e| set_replay_path.set(event_target_value(&e))
+                        />
+                        <label class="replay-sandbox-toggle">
+                            <input
+                                type="checkbox"
+                                checked={move || replay_sandboxed.get()}
+                                on:change=move |e| set_replay_sandboxed.set(event_target_checked(&e))
+                            />
+                            "Sandbox (skip injection)"
+                        </label>
+                        <button
+                            class="debug-btn-secondary"
+                            on:click=move |_| replay_wav()
+                            disabled={move || is_replaying.get() || replay_path.get().is_empty()}
+                        >
+                            {move || if is_replaying.get() { "🔄 Replaying..." } else { "▶️ Replay" }}
+                        </button>
+                        <button
+                            class="debug-btn-secondary"
+                            on:click=move |_| compare_denoise()
+                            disabled={move || is_replaying.get() || replay_path.get().is_empty()}
+                            title="Transcribe with and without noise suppression"
+                        >
+                            "🔇 A/B Denoise"
+                        </button>
+                    </div>
+                </div>
+
+                // Fixture Recorder Section
+                <div class="debug-group" role="group" aria-labelledby="debug-fixture">
+                    <h3 id="debug-fixture">"🧪 Fixture Recorder"</h3>
+                    <p class="debug-description">
+                        "Capture a real dictation and save the audio plus engine output as a regression test fixture"
+                    </p>
+
+                    <div class="debug-controls">
+                        <input
+                            type="text"
+                            class="fixture-dir-input"
+                            aria-label="Fixture folder"
+                            placeholder="/path/to/fixtures"
+                            prop:value={move || fixture_dir.get()}
+                            on:input=move |e| set_fixture_dir.set(event_target_value(&e))
+                        />
+                        <button
+                            class="debug-btn-secondary"
+                            on:click=move |_| record_fixture()
+                            disabled={move || is_recording.get() || fixture_dir.get().is_empty()}
+                        >
+                            "🧪 Record Fixture"
+                        </button>
+                    </div>
+                </div>
+
+                // Session Recorder Section
+                <div class="debug-group" role="group" aria-labelledby="debug-session">
+                    <h3 id="debug-session">"🎬 Session Recorder"</h3>
+                    <p class="debug-description">
+                        "Record pipeline events, timing and a settings snapshot (no audio) to a JSON file that tests can replay"
+                    </p>
+
+                    <div class="debug-controls">
+                        <input
+                            type="text"
+                            class="fixture-dir-input"
+                            aria-label="Session folder"
+                            placeholder="/path/to/sessions"
+                            prop:value={move || session_dir.get()}
+                            on:input=move |e| set_session_dir.set(event_target_value(&e))
+                        />
+                        <button
+                            class="debug-btn-secondary"
+                            aria-pressed={move || is_recording_session.get().to_string()}
+                            on:click=move |_| toggle_session_recording()
+                            disabled={move || is_recording_session.get() && session_dir.get().is_empty()}
+                        >
+                            {move || if is_recording_session.get() {
+                                "⏹️ Stop & Save Session"
+                            } else {
+                                "🎬 Record Session"
+                            }}
+                        </button>
                     </div>
                 </div>
 
+                // Log Verbosity Section
+                <div class="debug-group" role="group" aria-labelledby="debug-log-verbosity">
+                    <h3 id="debug-log-verbosity">"🎚️ Log Verbosity"</h3>
+                    <p class="debug-description">
+                        "Raise logging for just the misbehaving subsystem. \"Default\" follows RUST_LOG."
+                    </p>
+
+                    <div class="debug-info-grid">
+                        {move || log_levels.get().into_iter().map(|entry| {
+                            let subsystem = entry.subsystem;
+                            view! {
+                                <div class="debug-info-item">
+                                    <span class="debug-info-label">{subsystem.display_name()}</span>
+                                    <select
+                                        class="log-level-select"
+                                        aria-label=format!("{} log level", subsystem.display_name())
+                                        on:change=move |e| {
+                                            let level = LogVerbosity::from_directive(&event_target_value(&e));
+                                            set_log_level(subsystem, level);
+                                        }
+                                    >
+                                        <option value="" selected=entry.level.is_none()>"Default"</option>
+                                        {LogVerbosity::all().into_iter().map(|level| {
+                                            view! {
+                                                <option
+                                                    value=level.as_directive()
+                                                    selected=entry.level == Some(level)
+                                                >
+                                                    {level.as_directive().to_uppercase()}
+                                                </option>
+                                            }
+                                        }).collect::<Vec<_>>()}
+                                    </select>
+                                </div>
+                            }
+                        }).collect::<Vec<_>>()}
+                    </div>
+
+                    <div class="debug-controls">
+                        <button class="debug-btn-secondary" on:click=move |_| open_logs_folder()>
+                            "📂 Open Logs Folder"
+                        </button>
+                    </div>
+                    {move || log_file_path.get().map(|path| view! {
+                        <p class="debug-description">{format!("JSON log file: {path}")}</p>
+                    })}
+                </div>
+
+                // Performance Section
+                <div class="debug-group" role="group" aria-labelledby="debug-performance">
+                    <h3 id="debug-performance">"⏱️ Performance"</h3>
+                    <p class="debug-description">
+                        {move || {
+                            let metrics = performance.get();
+                            format!(
+                                "Latency over the last {} of up to {} dictations, in milliseconds.",
+                                metrics.samples, metrics.window
+                            )
+                        }}
+                    </p>
+                    <table class="performance-table">
+                        <thead>
+                            <tr>
+                                <th>"Stage"</th>
+                                <th>"Last"</th>
+                                <th>"Min"</th>
+                                <th>"Mean"</th>
+                                <th>"p95"</th>
+                                <th>"Max"</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {move || {
+                                let metrics = performance.get();
+                                [
+                                    ("Hot-key → recording", metrics.hotkey_to_record_start),
+                                    ("Recording stop → first text", metrics.record_stop_to_first_token),
+                                    ("End to end", metrics.end_to_end),
+                                ]
+                                .into_iter()
+                                .map(|(stage, stats)| view! {
+                                    <tr>
+                                        <td>{stage}</td>
+                                        {latency_cells(&stats)
+                                            .into_iter()
+                                            .map(|cell| view! { <td>{cell}</td> })
+                                            .collect::<Vec<_>>()}
+                                    </tr>
+                                })
+                                .collect::<Vec<_>>()
+                            }}
+                        </tbody>
+                    </table>
+                    <h4>"Post-processing pipeline"</h4>
+                    {move || {
+                        let stages = performance.get().processing_stages;
+                        if stages.is_empty() {
+                            return view! {
+                                <p class="debug-description">"No dictation post-processed yet."</p>
+                            }
+                            .into_any();
+                        }
+                        let total = stages.iter().map(|s| s.duration_us).sum::<u64>().max(1);
+                        view! {
+                            <table class="performance-table pipeline-table">
+                                <thead>
+                                    <tr>
+                                        <th>"Step"</th>
+                                        <th>"Cost"</th>
+                                        <th>"Time"</th>
+                                        <th>"Concurrency"</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    {stages
+                                        .iter()
+                                        .map(|stage| {
+                                            let [name, time, concurrency] = pipeline_stage_cells(stage);
+                                            let width =
+                                                format!("width: {}%", stage.duration_us * 100 / total);
+                                            view! {
+                                                <tr class:unchanged=!stage.changed>
+                                                    <td>{name}</td>
+                                                    <td>
+                                                        <div class="latency-bar">
+                                                            <div class="latency-bar-fill" style=width></div>
+                                                        </div>
+                                                    </td>
+                                                    <td>{time}</td>
+                                                    <td>{concurrency}</td>
+                                                </tr>
+                                            }
+                                        })
+                                        .collect::<Vec<_>>()}
+                                </tbody>
+                            </table>
+                        }
+                        .into_any()
+                    }}
+                    <button class="debug-btn-secondary" on:click=move |_| refresh_performance()>
+                        "🔄 Refresh"
+                    </button>
+                </div>
+
                 // Debug Messages Section
-                <div class="debug-group">
-                    <h3>"📝 Debug Output"</h3>
+                <div class="debug-group" role="group" aria-labelledby="debug-output">
+                    <h3 id="debug-output">"📝 Debug Output"</h3>
                     {move || {
                         if let Some(message) = debug_message.get() {
                             view! {
-                                <div class="debug-output">
+                                <div class="debug-output" role="status">
                                     <pre>{message}</pre>
                                 </div>
                             }.into_any()
@@ -585,8 +1305,8 @@ pub fn DebugPanel() -> impl IntoView {
                 </div>
 
                 // System Information Section
-                <div class="debug-group">
-                    <h3>"ℹ️ System Info"</h3>
+                <div class="debug-group" role="group" aria-labelledby="debug-system-info">
+                    <h3 id="debug-system-info">"ℹ️ System Info"</h3>
                     <div class="debug-info-grid">
                         <div class="debug-info-item">
                             <span class="debug-info-label">"Build Type:"</span>
@@ -610,7 +1330,7 @@ pub fn DebugPanel() -> impl IntoView {
                     <LoggingConsole />
                 </div>
             </div>
-        </div>
+        </section>
     }
 }
 
@@ -618,6 +1338,43 @@ pub fn DebugPanel() -> impl IntoView {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_space_and_enter_hold_the_record_button() {
+        assert!(is_hold_key("Space"));
+        assert!(is_hold_key("Enter"));
+        assert!(!is_hold_key("Tab"));
+        assert!(!is_hold_key("KeyR"));
+    }
+
+    #[test]
+    fn test_latency_cells_order() {
+        let stats = LatencyStats {
+            last_ms: 5,
+            min_ms: 1,
+            mean_ms: 3,
+            p95_ms: 9,
+            max_ms: 10,
+        };
+        assert_eq!(latency_cells(&stats), ["5", "1", "3", "9", "10"]);
+    }
+
+    #[test]
+    fn test_pipeline_stage_cells_show_concurrency() {
+        let mut stage = ProcessingStage {
+            name: "Strip filler words".to_string(),
+            duration_us: 1_250,
+            changed: true,
+            segments: 1,
+        };
+        assert_eq!(
+            pipeline_stage_cells(&stage),
+            ["Strip filler words", "1.2 ms", "—"]
+        );
+
+        stage.segments = 4;
+        assert_eq!(pipeline_stage_cells(&stage)[2], "4 threads");
+    }
+
     #[test]
     fn test_debug_manager_exists() {
         // RED: Test that DebugManager type exists
@@ -748,6 +1505,15 @@ mod tests {
         assert!(!filtered.iter().any(|m| matches!(m.level, LogLevel::Info)));
     }
 
+    #[test]
+    fn test_log_page_summary_mentions_dropped_messages() {
+        assert_eq!(log_page_summary(2, 2, 0), "Showing 2 of 2 messages");
+        assert_eq!(
+            log_page_summary(200, 950, 50),
+            "Showing 200 of 950 messages (50 older messages dropped)"
+        );
+    }
+
     #[test]
     fn test_log_level_hierarchy_error_shows_only_error() {
         // RED: This test should fail initially
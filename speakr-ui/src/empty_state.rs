@@ -0,0 +1,192 @@
+//! Dictation empty-state component for Speakr application.
+//!
+//! Shown wherever dictated text would appear before there is any, so new
+//! users learn how to start a dictation:
+//! - The configured hot-key, kept live via the `settings-changed` event
+//! - A "Try it now" button that runs a simulated dictation when no model has
+//!   been downloaded yet
+//! - A "Cancel" button while a dictation is in flight
+//! - The text of the most recent dictation, once one has completed
+
+use leptos::prelude::*;
+use speakr_types::events::{
+    WorkflowError, DICTATION_CANCELLED_EVENT, SETTINGS_CHANGED_EVENT, TRANSCRIPTION_PROGRESS_EVENT,
+    WORKFLOW_COMPLETED_EVENT, WORKFLOW_ERROR_EVENT, WORKFLOW_STARTED_EVENT,
+};
+use speakr_types::AppSettings;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::ipc::{listen_acknowledged_with, listen_typed, listen_typed_with};
+use crate::settings::SettingsManager;
+
+/// Splits a Tauri accelerator string into display labels, one per key.
+///
+/// `CmdOrCtrl` is shown as `Cmd/Ctrl` since the same settings are used on
+/// every platform.
+pub fn hotkey_keys(hot_key: &str) -> Vec<String> {
+    hot_key
+        .split('+')
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .map(|key| match key {
+            "CmdOrCtrl" | "CommandOrControl" => "Cmd/Ctrl".to_string(),
+            other => other.to_string(),
+        })
+        .collect()
+}
+
+/// Empty-state hint showing how to start a dictation.
+///
+/// # Arguments
+///
+/// * `title` - Heading shown above the hint
+/// * `message` - Explains what will appear here once the user dictates
+#[component]
+pub fn DictationEmptyState(
+    #[prop(into)] title: Signal<String>,
+    #[prop(into)] message: Signal<String>,
+) -> impl IntoView {
+    let (hot_key, set_hot_key) = signal(AppSettings::default().hot_key);
+    let (has_model, set_has_model) = signal(true);
+    let (is_simulating, set_is_simulating) = signal(false);
+    let (error_message, set_error_message) = signal::<Option<String>>(None);
+
+    let refresh = move |settings: AppSettings| {
+        set_hot_key.set(settings.hot_key);
+        spawn_local(async move {
+            match SettingsManager::check_model_availability(&settings.model).await {
+                Ok(available) => set_has_model.set(available),
+                Err(e) => web_sys::console::error_1(
+                    &format!("Failed to check model availability: {e}").into(),
+                ),
+            }
+        });
+    };
+
+    Effect::new(move || {
+        spawn_local(async move {
+            match SettingsManager::load().await {
+                Ok(settings) => refresh(settings),
+                Err(e) => web_sys::console::error_1(&e.into()),
+            }
+        });
+    });
+
+    let settings_update = listen_typed::<AppSettings>(SETTINGS_CHANGED_EVENT);
+    Effect::new(move || {
+        if let Some(settings) = settings_update.get() {
+            refresh(settings);
+        }
+    });
+
+    let last_dictation = listen_typed::<String>(WORKFLOW_COMPLETED_EVENT);
+
+    let (is_dictating, set_is_dictating) = signal(false);
+    let (progress, set_progress) = signal::<Option<u8>>(None);
+    listen_acknowledged_with(TRANSCRIPTION_PROGRESS_EVENT, move |percent: u8| {
+        set_progress.set(Some(percent))
+    });
+    listen_typed_with(WORKFLOW_STARTED_EVENT, move |_: ()| {
+        set_progress.set(None);
+        set_is_dictating.set(true);
+    });
+    listen_typed_with(WORKFLOW_COMPLETED_EVENT, move |_: String| {
+        set_is_dictating.set(false)
+    });
+    listen_typed_with(WORKFLOW_ERROR_EVENT, move |_: WorkflowError| {
+        set_is_dictating.set(false)
+    });
+    listen_typed_with(DICTATION_CANCELLED_EVENT, move |_: ()| {
+        set_is_dictating.set(false)
+    });
+
+    let cancel = move |_| {
+        spawn_local(async move {
+            if let Err(e) = SettingsManager::cancel_dictation().await {
+                set_error_message.set(Some(e));
+            }
+        });
+    };
+
+    let try_it_now = move |_| {
+        set_is_simulating.set(true);
+        set_error_message.set(None);
+        spawn_local(async move {
+            if let Err(e) = SettingsManager::simulate_dictation().await {
+                set_error_message.set(Some(e));
+            }
+            set_is_simulating.set(false);
+        });
+    };
+
+    view! {
+        <div class="setting-group dictation-empty-state">
+            <h3>"🎙️ " {title}</h3>
+            <p class="setting-description">{message}</p>
+
+            <div class="hotkey-hint">
+                <span>"Press"</span>
+                {move || {
+                    hotkey_keys(&hot_key.get())
+                        .into_iter()
+                        .map(|key| view! { <kbd>{key}</kbd> })
+                        .collect::<Vec<_>>()
+                }}
+                <span>"anywhere, speak, and your words are typed where the cursor is."</span>
+            </div>
+
+            <Show when=move || is_dictating.get()>
+                <div class="try-it-now">
+                    <p class="checkbox-help">
+                        {move || match progress.get() {
+                            Some(percent) if percent < 100 => format!("Transcribing… {percent}%"),
+                            _ => "Dictating… press Esc or the hot-key again to cancel.".to_string(),
+                        }}
+                    </p>
+                    <button class="btn-secondary" on:click=cancel>"Cancel"</button>
+                </div>
+            </Show>
+
+            <Show when=move || !has_model.get()>
+                <div class="try-it-now">
+                    <p class="checkbox-help">
+                        "No model downloaded yet? Preview a dictation with sample text."
+                    </p>
+                    <button
+                        class="btn-primary"
+                        disabled=move || is_simulating.get()
+                        on:click=try_it_now
+                    >
+                        {move || if is_simulating.get() { "Running..." } else { "Try it now" }}
+                    </button>
+                </div>
+            </Show>
+
+            {move || error_message.get().map(|error| view! {
+                <div class="error-message" role="alert">{error}</div>
+            })}
+
+            {move || last_dictation.get().map(|text| view! {
+                <div class="last-dictation">
+                    <span class="checkbox-help">"Last dictation"</span>
+                    <p>{text}</p>
+                </div>
+            })}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hotkey_keys_splits_accelerator() {
+        assert_eq!(
+            hotkey_keys("CmdOrCtrl+Alt+Space"),
+            vec!["Cmd/Ctrl", "Alt", "Space"]
+        );
+        assert_eq!(hotkey_keys("F5"), vec!["F5"]);
+        assert!(hotkey_keys("").is_empty());
+    }
+}
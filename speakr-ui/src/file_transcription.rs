@@ -0,0 +1,410 @@
+//! File transcription panel for Speakr application.
+//!
+//! Offline transcription of existing recordings:
+//! - Drop a WAV, MP3 or M4A file onto the window to transcribe it
+//! - The result is shown here instead of being typed into another app
+//! - Words the model was unsure about are highlighted for review
+//! - Drop a folder to transcribe every recording in it as a batch, with
+//!   progress shown here and `.txt`/`.srt` files saved next to each one
+//! - Save a single result as `.srt` or `.vtt` captions next to the recording
+//!
+//! Dropped files arrive through Tauri's `tauri://drag-drop` event, which
+//! carries their full paths (unlike the browser's drop event).
+
+use leptos::prelude::*;
+use serde::Deserialize;
+use speakr_types::{
+    BatchFileStatus, BatchJob, BatchProgress, SubtitleFormat, TranscriptionResult,
+    BATCH_PROGRESS_EVENT, LOW_CONFIDENCE_THRESHOLD,
+};
+use wasm_bindgen_futures::spawn_local;
+
+use crate::ipc::listen_typed_with;
+use crate::settings::SettingsManager;
+
+/// File extensions the backend can decode, in lower case.
+const SUPPORTED_EXTENSIONS: [&str; 3] = ["wav", "mp3", "m4a"];
+
+/// Payload of Tauri's `tauri://drag-drop` event.
+#[derive(Debug, Deserialize)]
+struct DragDropPayload {
+    paths: Vec<String>,
+}
+
+/// What the user dropped onto the window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Dropped {
+    /// A supported audio file, transcribed on its own.
+    File(String),
+    /// A folder, transcribed as a batch.
+    Folder(String),
+    /// Only files that cannot be transcribed.
+    Unsupported,
+}
+
+/// Works out what to transcribe from the dropped paths.
+///
+/// The first supported audio file wins. Otherwise a path whose name has no
+/// extension is taken to be a folder; the backend reports an error if it is
+/// not one.
+pub fn classify_drop(paths: &[String]) -> Dropped {
+    let extension = |path: &str| {
+        file_name(path)
+            .rsplit_once('.')
+            .map(|(_, extension)| extension.to_ascii_lowercase())
+    };
+
+    if let Some(file) = paths.iter().find(|path| {
+        extension(path).is_some_and(|extension| SUPPORTED_EXTENSIONS.contains(&extension.as_str()))
+    }) {
+        return Dropped::File(file.clone());
+    }
+    match paths.iter().find(|path| extension(path).is_none()) {
+        Some(folder) => Dropped::Folder(folder.clone()),
+        None => Dropped::Unsupported,
+    }
+}
+
+/// Returns the file name of a path, for display.
+pub fn file_name(path: &str) -> &str {
+    path.trim_end_matches(['/', '\\'])
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or(path)
+}
+
+/// Returns the path of the caption file saved next to a recording.
+pub fn subtitle_path(recording: &str, format: SubtitleFormat) -> String {
+    let name = file_name(recording);
+    let stem_len = name.rfind('.').unwrap_or(name.len());
+    let folder_len = recording.len() - name.len();
+    format!(
+        "{}.{}",
+        &recording[..folder_len + stem_len],
+        format.extension()
+    )
+}
+
+/// Summarises a batch for display, e.g. "3 of 10 files".
+pub fn batch_summary(progress: &BatchProgress, failed: usize) -> String {
+    let mut summary = format!("{} of {} files", progress.completed, progress.total);
+    if failed > 0 {
+        summary.push_str(&format!(", {failed} failed"));
+    }
+    summary
+}
+
+/// Splits a transcription into words, flagging the low-confidence ones.
+///
+/// Falls back to the plain text, unflagged, when the engine did not report
+/// word timings.
+pub fn highlighted_words(result: &TranscriptionResult) -> Vec<(String, bool)> {
+    if result.words().next().is_none() {
+        return vec![(result.text.clone(), false)];
+    }
+    result
+        .words()
+        .map(|word| {
+            (
+                word.text.clone(),
+                word.is_low_confidence(LOW_CONFIDENCE_THRESHOLD),
+            )
+        })
+        .collect()
+}
+
+/// Drop zone that transcribes existing recordings.
+#[component]
+pub fn FileTranscription() -> impl IntoView {
+    let (hovering, set_hovering) = signal(false);
+    // Name of the file being transcribed
+    let (transcribing, set_transcribing) = signal::<Option<String>>(None);
+    let (result, set_result) = signal::<Option<TranscriptionResult>>(None);
+    // Recording the result came from, and the caption file last saved for it
+    let (source, set_source) = signal::<Option<String>>(None);
+    let (exported, set_exported) = signal::<Option<String>>(None);
+    let (error_message, set_error_message) = signal::<Option<String>>(None);
+    // The batch in flight or last finished, with its latest progress and the
+    // files that failed
+    let (batch, set_batch) = signal::<Option<BatchJob>>(None);
+    let (batch_progress, set_batch_progress) = signal::<Option<BatchProgress>>(None);
+    let (batch_failures, set_batch_failures) = signal(Vec::<(String, String)>::new());
+
+    let is_busy = move || {
+        transcribing.get_untracked().is_some()
+            || batch_progress
+                .get_untracked()
+                .is_some_and(|progress| !progress.is_finished())
+            || (batch.get_untracked().is_some() && batch_progress.get_untracked().is_none())
+    };
+
+    let transcribe_file = move |path: String| {
+        set_result.set(None);
+        set_exported.set(None);
+        set_source.set(Some(path.clone()));
+        set_transcribing.set(Some(file_name(&path).to_string()));
+        spawn_local(async move {
+            match SettingsManager::transcribe_file(&path).await {
+                Ok(transcription) => set_result.set(Some(transcription)),
+                Err(e) => set_error_message.set(Some(e)),
+            }
+            set_transcribing.set(None);
+        });
+    };
+
+    let export = move |format: SubtitleFormat| {
+        let (Some(transcription), Some(recording)) =
+            (result.get_untracked(), source.get_untracked())
+        else {
+            return;
+        };
+        let path = subtitle_path(&recording, format);
+        spawn_local(async move {
+            match SettingsManager::export_transcription(&transcription, format, &path).await {
+                Ok(()) => set_exported.set(Some(file_name(&path).to_string())),
+                Err(e) => set_error_message.set(Some(e)),
+            }
+        });
+    };
+
+    let transcribe_folder = move |path: String| {
+        set_batch_progress.set(None);
+        set_batch_failures.set(Vec::new());
+        spawn_local(async move {
+            match SettingsManager::transcribe_folder(&path).await {
+                Ok(job) => set_batch.set(Some(job)),
+                Err(e) => {
+                    set_batch.set(None);
+                    set_error_message.set(Some(e));
+                }
+            }
+        });
+    };
+
+    listen_typed_with(BATCH_PROGRESS_EVENT, move |progress: BatchProgress| {
+        if batch
+            .get_untracked()
+            .is_none_or(|job| job.id != progress.job_id)
+        {
+            return;
+        }
+        if let BatchFileStatus::Failed(reason) = &progress.status {
+            set_batch_failures
+                .update(|failures| failures.push((progress.file.clone(), reason.clone())));
+        }
+        set_batch_progress.set(Some(progress));
+    });
+
+    listen_typed_with("tauri://drag-enter", move |_: serde_json::Value| {
+        set_hovering.set(true)
+    });
+    listen_typed_with("tauri://drag-leave", move |_: serde_json::Value| {
+        set_hovering.set(false)
+    });
+    listen_typed_with("tauri://drag-drop", move |payload: DragDropPayload| {
+        set_hovering.set(false);
+        if payload.paths.is_empty() || is_busy() {
+            return;
+        }
+
+        set_error_message.set(None);
+        match classify_drop(&payload.paths) {
+            Dropped::File(path) => transcribe_file(path),
+            Dropped::Folder(path) => transcribe_folder(path),
+            Dropped::Unsupported => set_error_message.set(Some(
+                "Only WAV, MP3 and M4A files, or folders of them, can be transcribed".to_string(),
+            )),
+        }
+    });
+
+    view! {
+        <div class="setting-group">
+            <h3>"🎧 Transcribe a Recording"</h3>
+            <p class="setting-description">
+                "Transcribe an existing recording with your current model and language. Nothing leaves this device."
+            </p>
+
+            <div class=move || format!("drop-zone {}", if hovering.get() { "hovering" } else { "" })>
+                {move || match transcribing.get() {
+                    Some(name) => format!("Transcribing {name}…"),
+                    None => "Drop a WAV, MP3 or M4A file, or a folder of them, here".to_string(),
+                }}
+            </div>
+
+            {move || result.get().map(|result| view! {
+                <p class="transcription-result">
+                    {highlighted_words(&result)
+                        .into_iter()
+                        .map(|(word, uncertain)| view! {
+                            <span class:low-confidence=uncertain>{word}</span>" "
+                        })
+                        .collect::<Vec<_>>()}
+                </p>
+                <span class="checkbox-help">
+                    {format!(
+                        "{} · {:.1} s",
+                        result.model_used,
+                        result.processing_time.as_secs_f32()
+                    )}
+                </span>
+                <div class="export-actions">
+                    <button class="btn-secondary" on:click=move |_| export(SubtitleFormat::Srt)>
+                        "Save .srt"
+                    </button>
+                    <button class="btn-secondary" on:click=move |_| export(SubtitleFormat::WebVtt)>
+                        "Save .vtt"
+                    </button>
+                    {move || exported.get().map(|name| view! {
+                        <span class="checkbox-help">{format!("Saved {name}")}</span>
+                    })}
+                </div>
+            })}
+
+            {move || batch.get().map(|job| {
+                let progress = batch_progress.get();
+                let percent = progress
+                    .as_ref()
+                    .map_or(0, |progress| progress.completed * 100 / progress.total.max(1));
+                let finished = progress.as_ref().is_some_and(BatchProgress::is_finished);
+                let summary = progress.as_ref().map_or_else(
+                    || format!("0 of {} files", job.files.len()),
+                    |progress| batch_summary(progress, batch_failures.get().len()),
+                );
+                view! {
+                    <div class="batch-progress">
+                        <span class="checkbox-label-text">
+                            {format!(
+                                "{} {}: {summary}",
+                                if finished { "Transcribed" } else { "Transcribing" },
+                                file_name(&job.folder),
+                            )}
+                        </span>
+                        <div class="level-meter">
+                            <div class="level-fill" style=format!("width: {percent}%")></div>
+                        </div>
+                        <Show when=move || finished>
+                            <span class="checkbox-help">
+                                "Transcripts were saved next to each recording as .txt and .srt files."
+                            </span>
+                        </Show>
+                        <ul class="batch-failures">
+                            {batch_failures
+                                .get()
+                                .into_iter()
+                                .map(|(file, reason)| view! {
+                                    <li>{format!("{}: {reason}", file_name(&file))}</li>
+                                })
+                                .collect::<Vec<_>>()}
+                        </ul>
+                    </div>
+                }
+            })}
+
+            {move || error_message.get().map(|error| view! {
+                <div class="error-message" role="alert">{error}</div>
+            })}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use speakr_types::{ModelId, TranscriptionSegment, TranscriptionWord};
+    use std::time::Duration;
+
+    #[test]
+    fn test_classify_drop_prefers_audio_files_then_folders() {
+        let paths = |paths: &[&str]| {
+            paths
+                .iter()
+                .map(|path| path.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            classify_drop(&paths(&[
+                "/tmp/notes.txt",
+                "/tmp/Memo.M4A",
+                "/tmp/call.mp3"
+            ])),
+            Dropped::File("/tmp/Memo.M4A".to_string())
+        );
+        assert_eq!(
+            classify_drop(&paths(&["/tmp/notes.txt", "/Users/me/Recordings"])),
+            Dropped::Folder("/Users/me/Recordings".to_string())
+        );
+        assert_eq!(
+            classify_drop(&paths(&["/tmp/notes.txt"])),
+            Dropped::Unsupported
+        );
+        assert_eq!(file_name("/tmp/Memo.M4A"), "Memo.M4A");
+        assert_eq!(file_name("/Users/me/Recordings/"), "Recordings");
+    }
+
+    #[test]
+    fn test_subtitle_path_replaces_the_extension() {
+        assert_eq!(
+            subtitle_path("/Users/me/Memo.M4A", SubtitleFormat::Srt),
+            "/Users/me/Memo.srt"
+        );
+        assert_eq!(
+            subtitle_path("C:\\rec\\call.v2.mp3", SubtitleFormat::WebVtt),
+            "C:\\rec\\call.v2.vtt"
+        );
+        assert_eq!(
+            subtitle_path("/tmp/take", SubtitleFormat::WebVtt),
+            "/tmp/take.vtt"
+        );
+    }
+
+    #[test]
+    fn test_batch_summary_mentions_failures() {
+        let progress = BatchProgress {
+            job_id: 1,
+            file: "/r/a.wav".to_string(),
+            status: BatchFileStatus::Done,
+            completed: 3,
+            total: 10,
+        };
+
+        assert_eq!(batch_summary(&progress, 0), "3 of 10 files");
+        assert_eq!(batch_summary(&progress, 2), "3 of 10 files, 2 failed");
+    }
+
+    #[test]
+    fn test_highlighted_words_flag_low_confidence() {
+        let word = |text: &str, confidence: f32| TranscriptionWord {
+            text: text.to_string(),
+            start_time: Duration::ZERO,
+            end_time: Duration::ZERO,
+            confidence,
+        };
+        let mut result = TranscriptionResult {
+            text: "Hello wurld".to_string(),
+            language: None,
+            confidence: 0.7,
+            avg_logprob: None,
+            processing_time: Duration::ZERO,
+            memory_delta_bytes: 0,
+            model_used: ModelId::new("small"),
+            segments: vec![],
+        };
+        assert_eq!(
+            highlighted_words(&result),
+            vec![("Hello wurld".to_string(), false)]
+        );
+
+        result.segments.push(TranscriptionSegment {
+            text: "Hello wurld".to_string(),
+            start_time: Duration::ZERO,
+            end_time: Duration::ZERO,
+            confidence: 0.7,
+            words: vec![word("Hello", 0.9), word("wurld", 0.1)],
+        });
+        assert_eq!(
+            highlighted_words(&result),
+            vec![("Hello".to_string(), false), ("wurld".to_string(), true)]
+        );
+    }
+}
@@ -0,0 +1,178 @@
+//! Dictation history with inline corrections.
+//!
+//! [`HistoryPanel`] lists recent dictations, newest first, kept live via the
+//! `history-changed` event. Each transcript can be edited in place; the
+//! backend keeps the original next to the correction. Words the user keeps
+//! correcting are offered as vocabulary terms, added with one click.
+
+use leptos::prelude::*;
+use speakr_types::events::SETTINGS_CHANGED_EVENT;
+use speakr_types::{HistoryEntry, VocabularySuggestion, HISTORY_CHANGED_EVENT};
+use wasm_bindgen_futures::spawn_local;
+
+use crate::hotkey_status::describe_elapsed;
+use crate::ipc::listen_typed_with;
+use crate::settings::SettingsManager;
+
+/// Question offering a frequently corrected word as a vocabulary term.
+pub fn suggestion_prompt(suggestion: &VocabularySuggestion) -> String {
+    format!(
+        "You often correct “{}” — add it to your vocabulary?",
+        suggestion.term
+    )
+}
+
+/// Adds `term` to the saved vocabulary, keeping the other settings.
+async fn add_to_vocabulary(term: String) -> Result<(), String> {
+    let mut settings = SettingsManager::load().await?;
+    if !settings
+        .vocabulary
+        .iter()
+        .any(|known| known.eq_ignore_ascii_case(&term))
+    {
+        settings.vocabulary.push(term);
+    }
+    SettingsManager::save(&settings).await
+}
+
+/// Recent dictations, editable in place, with vocabulary suggestions.
+#[component]
+pub fn HistoryPanel() -> impl IntoView {
+    let (history, set_history) = signal(Vec::<HistoryEntry>::new());
+    let (suggestions, set_suggestions) = signal(Vec::<VocabularySuggestion>::new());
+    let (error_message, set_error_message) = signal::<Option<String>>(None);
+
+    let refresh_suggestions = move || {
+        spawn_local(async move {
+            match SettingsManager::get_vocabulary_suggestions().await {
+                Ok(loaded) => set_suggestions.set(loaded),
+                Err(e) => web_sys::console::error_1(&e.into()),
+            }
+        });
+    };
+
+    spawn_local(async move {
+        match SettingsManager::list_history().await {
+            Ok(loaded) => set_history.set(loaded),
+            Err(e) => web_sys::console::error_1(&e.into()),
+        }
+    });
+    refresh_suggestions();
+    listen_typed_with(HISTORY_CHANGED_EVENT, move |updated: Vec<HistoryEntry>| {
+        set_history.set(updated);
+        refresh_suggestions();
+    });
+    listen_typed_with(SETTINGS_CHANGED_EVENT, move |_: serde_json::Value| {
+        refresh_suggestions()
+    });
+
+    let apply = move |result: Result<Vec<HistoryEntry>, String>| match result {
+        Ok(updated) => {
+            set_error_message.set(None);
+            set_history.set(updated);
+        }
+        Err(e) => set_error_message.set(Some(e)),
+    };
+
+    move || {
+        let entries = history.get();
+        if entries.is_empty() {
+            return ().into_any();
+        }
+        let now = js_sys::Date::now() as u64;
+        view! {
+            <div class="setting-group history-panel">
+                <h3>"🕘 History"</h3>
+                {move || error_message.get().map(|message| view! {
+                    <div class="error-message" role="alert">{message}</div>
+                })}
+                {move || {
+                    suggestions
+                        .get()
+                        .into_iter()
+                        .map(|suggestion| {
+                            let prompt = suggestion_prompt(&suggestion);
+                            let term = suggestion.term;
+                            view! {
+                                <div class="vocabulary-suggestion">
+                                    <span>{prompt}</span>
+                                    <button
+                                        class="btn-primary"
+                                        on:click=move |_| {
+                                            let term = term.clone();
+                                            spawn_local(async move {
+                                                if let Err(e) = add_to_vocabulary(term).await {
+                                                    set_error_message.set(Some(e));
+                                                }
+                                            });
+                                        }
+                                    >
+                                        "Add"
+                                    </button>
+                                </div>
+                            }
+                        })
+                        .collect_view()
+                }}
+                <ul class="history-list">
+                    {entries
+                        .into_iter()
+                        .map(|entry| {
+                            let id = entry.id;
+                            let value = entry.current_text().to_string();
+                            view! {
+                                <li class="history-item">
+                                    <div class="history-meta">
+                                        <span>{describe_elapsed(now, entry.created_at)}</span>
+                                        {entry.corrected_text.is_some().then(|| view! {
+                                            <span class="history-edited" title=entry.text.clone()>
+                                                "Edited"
+                                            </span>
+                                        })}
+                                        <button
+                                            class="vocabulary-remove"
+                                            title="Delete from history"
+                                            on:click=move |_| spawn_local(async move {
+                                                apply(SettingsManager::delete_history(id).await);
+                                            })
+                                        >
+                                            "×"
+                                        </button>
+                                    </div>
+                                    <textarea
+                                        class="history-text"
+                                        prop:value=value
+                                        on:change=move |e| {
+                                            let text = event_target_value(&e);
+                                            spawn_local(async move {
+                                                apply(SettingsManager::correct_history(id, text).await);
+                                            });
+                                        }
+                                    ></textarea>
+                                </li>
+                            }
+                        })
+                        .collect_view()}
+                </ul>
+            </div>
+        }
+        .into_any()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggestion_prompt_names_the_term() {
+        let suggestion = VocabularySuggestion {
+            term: "Kubernetes".to_string(),
+            corrections: 3,
+        };
+        assert_eq!(
+            suggestion_prompt(&suggestion),
+            "You often correct “Kubernetes” — add it to your vocabulary?"
+        );
+    }
+}
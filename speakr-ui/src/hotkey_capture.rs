@@ -0,0 +1,247 @@
+//! Hot-key capture component for Speakr application.
+//!
+//! Records a shortcut by listening to the keys the user presses, instead of
+//! having them type Tauri's shortcut syntax by hand:
+//! - Held modifiers are previewed as they are pressed (`CmdOrCtrl+Alt+…`)
+//! - The first non-modifier key completes the combination
+//! - Keys are read by physical position (`KeyboardEvent.code`), so the
+//!   shortcut does not change with the keyboard layout
+//!
+//! The Cmd key is recorded as `CmdOrCtrl`, matching the default hot-key.
+//!
+//! So keyboard users are not trapped in the field, Tab and Shift+Tab move
+//! focus as usual and Escape leaves the field; neither is recorded unless
+//! Cmd, Ctrl or Alt is held.
+
+use leptos::ev::KeyboardEvent;
+use leptos::prelude::*;
+
+/// Modifier keys held while a key is pressed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HeldModifiers {
+    pub meta: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+}
+
+impl HeldModifiers {
+    fn from_event(event: &KeyboardEvent) -> Self {
+        Self {
+            meta: event.meta_key(),
+            ctrl: event.ctrl_key(),
+            alt: event.alt_key(),
+            shift: event.shift_key(),
+        }
+    }
+
+    /// Returns the held modifiers in Tauri shortcut syntax, in canonical order.
+    pub fn names(self) -> Vec<&'static str> {
+        [
+            (self.meta, "CmdOrCtrl"),
+            (self.ctrl, "Ctrl"),
+            (self.alt, "Alt"),
+            (self.shift, "Shift"),
+        ]
+        .into_iter()
+        .filter_map(|(held, name)| held.then_some(name))
+        .collect()
+    }
+}
+
+/// Converts a `KeyboardEvent.code` to a Tauri shortcut key name.
+///
+/// # Returns
+///
+/// `None` for modifier keys, which cannot end a shortcut.
+pub fn shortcut_key(code: &str) -> Option<String> {
+    const MODIFIER_CODES: [&str; 10] = [
+        "MetaLeft",
+        "MetaRight",
+        "OSLeft",
+        "OSRight",
+        "ControlLeft",
+        "ControlRight",
+        "AltLeft",
+        "AltRight",
+        "ShiftLeft",
+        "ShiftRight",
+    ];
+    if code.is_empty() || MODIFIER_CODES.contains(&code) {
+        return None;
+    }
+
+    let key = code
+        .strip_prefix("Key")
+        .or_else(|| code.strip_prefix("Digit"))
+        .unwrap_or(code);
+    Some(key.to_string())
+}
+
+/// Whether a key press navigates away from the field instead of being
+/// recorded: Tab or Escape, optionally with Shift.
+pub fn is_navigation_key(modifiers: HeldModifiers, code: &str) -> bool {
+    !(modifiers.meta || modifiers.ctrl || modifiers.alt) && matches!(code, "Tab" | "Escape")
+}
+
+/// Builds a shortcut from the held modifiers and the pressed key.
+///
+/// # Returns
+///
+/// `None` while only modifiers are held.
+pub fn shortcut_from_keys(modifiers: HeldModifiers, code: &str) -> Option<String> {
+    let key = shortcut_key(code)?;
+    let mut parts = modifiers.names();
+    parts.push(&key);
+    Some(parts.join("+"))
+}
+
+/// Field that records a hot-key from the keys the user presses.
+///
+/// # Arguments
+///
+/// * `value` - The shortcut currently shown
+/// * `invalid` - Whether to mark the shortcut as invalid
+/// * `on_capture` - Called with each completed shortcut
+/// * `autofocus` - Whether the field takes focus when it is shown
+#[component]
+pub fn HotkeyCapture(
+    #[prop(into)] value: Signal<String>,
+    #[prop(into)] invalid: Signal<bool>,
+    on_capture: Callback<String>,
+    #[prop(optional)] autofocus: bool,
+) -> impl IntoView {
+    // Modifiers held while a combination is being pressed
+    let (held, set_held) = signal::<Option<HeldModifiers>>(None);
+    let field = NodeRef::<leptos::html::Div>::new();
+
+    if autofocus {
+        Effect::new(move || {
+            if let Some(field) = field.get() {
+                let _ = field.focus();
+            }
+        });
+    }
+
+    let on_keydown = move |event: KeyboardEvent| {
+        let modifiers = HeldModifiers::from_event(&event);
+        if is_navigation_key(modifiers, &event.code()) {
+            set_held.set(None);
+            if event.code() == "Escape" {
+                if let Some(field) = field.get_untracked() {
+                    let _ = field.blur();
+                }
+            }
+            return;
+        }
+        event.prevent_default();
+        event.stop_propagation();
+
+        match shortcut_from_keys(modifiers, &event.code()) {
+            Some(shortcut) => {
+                set_held.set(None);
+                on_capture.run(shortcut);
+            }
+            None => set_held.set(Some(modifiers)),
+        }
+    };
+
+    let on_keyup = move |event: KeyboardEvent| {
+        let modifiers = HeldModifiers::from_event(&event);
+        set_held.set((modifiers != HeldModifiers::default()).then_some(modifiers));
+    };
+
+    let preview = move || match held.get() {
+        Some(modifiers) => {
+            let mut parts = modifiers.names();
+            parts.push("…");
+            parts.join("+")
+        }
+        None if value.get().is_empty() => "Press keys to record".to_string(),
+        None => value.get(),
+    };
+
+    view! {
+        <div
+            class=move || format!(
+                "hotkey-input hotkey-capture {}",
+                if invalid.get() { "invalid" } else { "" },
+            )
+            node_ref=field
+            tabindex="0"
+            role="textbox"
+            aria-label="Press the new hot-key. Tab moves on, Escape leaves the field."
+            aria-invalid=move || invalid.get().to_string()
+            on:keydown=on_keydown
+            on:keyup=on_keyup
+            on:blur=move |_| set_held.set(None)
+        >
+            <code>{preview}</code>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shortcut_from_keys_uses_tauri_syntax() {
+        let modifiers = HeldModifiers {
+            meta: true,
+            alt: true,
+            ..HeldModifiers::default()
+        };
+
+        assert_eq!(
+            shortcut_from_keys(modifiers, "Space").as_deref(),
+            Some("CmdOrCtrl+Alt+Space")
+        );
+        assert_eq!(
+            shortcut_from_keys(modifiers, "KeyD").as_deref(),
+            Some("CmdOrCtrl+Alt+D")
+        );
+        assert_eq!(
+            shortcut_from_keys(HeldModifiers::default(), "Digit5").as_deref(),
+            Some("5")
+        );
+    }
+
+    #[test]
+    fn test_modifier_keys_do_not_complete_a_shortcut() {
+        let shift = HeldModifiers {
+            shift: true,
+            ..HeldModifiers::default()
+        };
+
+        for code in [
+            "ShiftLeft",
+            "MetaRight",
+            "ControlLeft",
+            "AltRight",
+            "OSLeft",
+            "",
+        ] {
+            assert_eq!(shortcut_from_keys(shift, code), None, "{code}");
+        }
+        assert_eq!(shortcut_key("F13").as_deref(), Some("F13"));
+    }
+
+    #[test]
+    fn test_tab_and_escape_navigate_unless_a_modifier_is_held() {
+        let shift = HeldModifiers {
+            shift: true,
+            ..HeldModifiers::default()
+        };
+        let alt = HeldModifiers {
+            alt: true,
+            ..HeldModifiers::default()
+        };
+
+        assert!(is_navigation_key(HeldModifiers::default(), "Tab"));
+        assert!(is_navigation_key(shift, "Tab"));
+        assert!(is_navigation_key(HeldModifiers::default(), "Escape"));
+        assert!(!is_navigation_key(alt, "Tab"));
+        assert!(!is_navigation_key(HeldModifiers::default(), "KeyA"));
+    }
+}
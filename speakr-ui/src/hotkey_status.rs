@@ -0,0 +1,121 @@
+//! Live hot-key registration details for the settings page.
+//!
+//! [`HotkeyStatusPanel`] shows the shortcuts the backend has registered, as
+//! reported by `get_hotkey_status`, with when each was registered and last
+//! pressed and any fallback in effect. It reloads whenever a hot-key fires,
+//! settings change or the backend status changes (e.g. dictation is paused).
+
+use leptos::prelude::*;
+use speakr_types::events::{HOTKEY_TRIGGERED_EVENT, SETTINGS_CHANGED_EVENT};
+use speakr_types::{HotkeyStatus, STATUS_CHANGED_EVENT};
+use wasm_bindgen_futures::spawn_local;
+
+use crate::ipc::{describe_command_error, invoke_command_no_args, listen_typed_with};
+
+/// Fetches the registered hot-keys.
+async fn get_hotkey_status() -> Result<HotkeyStatus, String> {
+    invoke_command_no_args("get_hotkey_status")
+        .await
+        .map_err(|e| {
+            format!(
+                "Failed to load hotkey status: {}",
+                describe_command_error(&e)
+            )
+        })
+}
+
+/// Describes how long ago `then_ms` was, relative to `now_ms`.
+pub fn describe_elapsed(now_ms: u64, then_ms: u64) -> String {
+    let secs = now_ms.saturating_sub(then_ms) / 1000;
+    match secs {
+        0..=59 => "just now".to_string(),
+        60..=3599 => format!("{} min ago", secs / 60),
+        3600..=86_399 => format!("{} h ago", secs / 3600),
+        _ => format!("{} d ago", secs / 86_400),
+    }
+}
+
+/// Registered hot-keys, refreshed as they change or fire.
+#[component]
+pub fn HotkeyStatusPanel() -> impl IntoView {
+    let (status, set_status) = signal(HotkeyStatus::default());
+    let refresh = move || {
+        spawn_local(async move {
+            match get_hotkey_status().await {
+                Ok(loaded) => set_status.set(loaded),
+                Err(e) => {
+                    web_sys::console::error_1(&format!("Failed to load hotkey status: {e}").into())
+                }
+            }
+        });
+    };
+
+    refresh();
+    for event in [
+        HOTKEY_TRIGGERED_EVENT,
+        SETTINGS_CHANGED_EVENT,
+        STATUS_CHANGED_EVENT,
+    ] {
+        listen_typed_with(event, move |_: serde_json::Value| refresh());
+    }
+
+    move || {
+        let status = status.get();
+        let now = js_sys::Date::now() as u64;
+        view! {
+            <div class="hotkey-status">
+                {status.paused.then(|| view! {
+                    <p class="hotkey-status-notice">"⏸️ Dictation is paused, so no hot-keys are registered."</p>
+                })}
+                {status.fallback.map(|fallback| view! {
+                    <p class="hotkey-status-notice fallback">{format!("⚠️ {fallback}")}</p>
+                })}
+                {(status.hotkeys.is_empty() && !status.paused).then(|| view! {
+                    <p class="checkbox-help">"No hot-keys are registered."</p>
+                })}
+                <ul class="hotkey-status-list">
+                    {status
+                        .hotkeys
+                        .into_iter()
+                        .map(|hotkey| {
+                            let last_used = hotkey.last_triggered_at.map_or_else(
+                                || "never used".to_string(),
+                                |then| format!("last used {}", describe_elapsed(now, then)),
+                            );
+                            view! {
+                                <li class="hotkey-status-item">
+                                    <kbd>{hotkey.shortcut}</kbd>
+                                    <span class="hotkey-status-action">{hotkey.action}</span>
+                                    <span class="hotkey-status-times">
+                                        {format!(
+                                            "registered {}, {last_used}",
+                                            describe_elapsed(now, hotkey.registered_at)
+                                        )}
+                                    </span>
+                                </li>
+                            }
+                        })
+                        .collect_view()}
+                </ul>
+            </div>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_elapsed_units() {
+        assert_eq!(describe_elapsed(30_000, 0), "just now");
+        assert_eq!(describe_elapsed(5 * 60_000, 0), "5 min ago");
+        assert_eq!(describe_elapsed(2 * 3_600_000, 0), "2 h ago");
+        assert_eq!(describe_elapsed(3 * 86_400_000, 0), "3 d ago");
+    }
+
+    #[test]
+    fn test_describe_elapsed_clock_skew() {
+        assert_eq!(describe_elapsed(0, 10_000), "just now");
+    }
+}
@@ -0,0 +1,56 @@
+//! One-click hot-key suggestions.
+//!
+//! [`HotkeySuggestions`] asks the backend for shortcuts that are free right
+//! now (`suggest_available_hotkeys`: not a system shortcut, not another of
+//! Speakr's hot-keys, and registered cleanly on trial) and shows each as a
+//! button. Used by the hot-key editor in settings and by the last step of
+//! the onboarding wizard.
+
+use leptos::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::settings::SettingsManager;
+
+/// Buttons for hot-keys that are free to use.
+///
+/// Renders nothing until suggestions arrive, or if there are none.
+///
+/// # Arguments
+///
+/// * `on_pick` - Called with the hot-key the user clicked
+#[component]
+pub fn HotkeySuggestions(on_pick: Callback<String>) -> impl IntoView {
+    let (suggestions, set_suggestions) = signal(Vec::<String>::new());
+
+    spawn_local(async move {
+        match SettingsManager::suggest_available_hotkeys().await {
+            Ok(found) => set_suggestions.set(found),
+            Err(e) => web_sys::console::error_1(&e.into()),
+        }
+    });
+
+    move || {
+        let suggestions = suggestions.get();
+        (!suggestions.is_empty()).then(|| {
+            view! {
+                <div class="hotkey-suggestions" role="group" aria-label="Suggested hot-keys">
+                    <span class="checkbox-help">"Free to use:"</span>
+                    {suggestions
+                        .into_iter()
+                        .map(|hot_key| {
+                            let picked = hot_key.clone();
+                            view! {
+                                <button
+                                    class="btn-secondary hotkey-suggestion"
+                                    on:click=move |_| on_pick.run(picked.clone())
+                                >
+                                    <kbd>{hot_key}</kbd>
+                                </button>
+                            }
+                        })
+                        .collect_view()}
+                </div>
+            }
+        })
+    }
+}
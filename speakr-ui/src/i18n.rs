@@ -0,0 +1,155 @@
+//! Translations of the interface.
+//!
+//! Strings are looked up by key in flat JSON catalogs under `locales/`, one
+//! per [`UiLanguage`], which are compiled into the UI. A key missing from a
+//! catalog falls back to English, then to the key itself, so untranslated
+//! strings never show up blank.
+//!
+//! [`I18n`] holds the language in a signal shared through context: it follows
+//! the `ui_language` setting, resolving "system" against the browser's
+//! language, and switches live when the setting changes.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use leptos::prelude::*;
+use speakr_types::events::SETTINGS_CHANGED_EVENT;
+use speakr_types::{AppSettings, UiLanguage};
+use wasm_bindgen_futures::spawn_local;
+
+use crate::ipc::listen_typed_with;
+use crate::settings::SettingsManager;
+
+/// Catalogs as `(language, JSON)` pairs.
+const CATALOG_SOURCES: [(UiLanguage, &str); 4] = [
+    (UiLanguage::English, include_str!("../locales/en.json")),
+    (UiLanguage::German, include_str!("../locales/de.json")),
+    (UiLanguage::French, include_str!("../locales/fr.json")),
+    (UiLanguage::Spanish, include_str!("../locales/es.json")),
+];
+
+type Catalog = HashMap<String, String>;
+
+/// Parsed catalogs, keyed by language.
+fn catalogs() -> &'static HashMap<UiLanguage, Catalog> {
+    static CATALOGS: OnceLock<HashMap<UiLanguage, Catalog>> = OnceLock::new();
+    CATALOGS.get_or_init(|| {
+        CATALOG_SOURCES
+            .into_iter()
+            .map(|(language, json)| {
+                let catalog = serde_json::from_str(json).unwrap_or_else(|e| {
+                    panic!("Invalid {} catalog: {e}", language.code());
+                });
+                (language, catalog)
+            })
+            .collect()
+    })
+}
+
+/// Returns the text for `key` in `language`.
+///
+/// Falls back to English, then to the key itself.
+pub fn translate(language: UiLanguage, key: &str) -> String {
+    let catalogs = catalogs();
+    [language, UiLanguage::English]
+        .iter()
+        .find_map(|language| catalogs.get(language)?.get(key))
+        .cloned()
+        .unwrap_or_else(|| key.to_string())
+}
+
+/// The browser's preferred language tag, e.g. "de-DE".
+fn system_locale() -> Option<String> {
+    web_sys::window().and_then(|window| window.navigator().language())
+}
+
+/// Shared, reactive interface language.
+#[derive(Clone, Copy)]
+pub struct I18n {
+    language: ReadSignal<UiLanguage>,
+}
+
+impl I18n {
+    /// Creates the store, follows the `ui_language` setting and provides it
+    /// to descendant components.
+    pub fn provide() -> Self {
+        let locale = system_locale();
+        let (language, set_language) =
+            signal(UiLanguage::resolve(UiLanguage::System, locale.as_deref()));
+        let apply = move |settings: AppSettings| {
+            set_language.set(UiLanguage::resolve(settings.ui_language, locale.as_deref()));
+        };
+
+        listen_typed_with(SETTINGS_CHANGED_EVENT, apply.clone());
+        spawn_local(async move {
+            match SettingsManager::load().await {
+                Ok(settings) => apply(settings),
+                Err(e) => web_sys::console::error_1(&e.into()),
+            }
+        });
+
+        let i18n = Self { language };
+        provide_context(i18n);
+        i18n
+    }
+
+    /// Returns the store provided by an ancestor component.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no ancestor called [`I18n::provide`].
+    pub fn expect() -> Self {
+        expect_context::<Self>()
+    }
+
+    /// Returns the text for `key` in the current language.
+    ///
+    /// Reading it inside a closure re-renders the text when the language
+    /// changes.
+    pub fn t(&self, key: &str) -> String {
+        translate(self.language.get(), key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_catalog_has_the_english_keys() {
+        let english = &catalogs()[&UiLanguage::English];
+        for language in UiLanguage::TRANSLATED {
+            let catalog = &catalogs()[&language];
+            let mut missing: Vec<_> = english
+                .keys()
+                .filter(|k| !catalog.contains_key(*k))
+                .collect();
+            let mut extra: Vec<_> = catalog
+                .keys()
+                .filter(|k| !english.contains_key(*k))
+                .collect();
+            missing.sort();
+            extra.sort();
+            assert!(
+                missing.is_empty(),
+                "{} is missing {missing:?}",
+                language.code()
+            );
+            assert!(
+                extra.is_empty(),
+                "{} has unknown {extra:?}",
+                language.code()
+            );
+        }
+    }
+
+    #[test]
+    fn test_translate_falls_back_to_english_then_the_key() {
+        assert_eq!(
+            translate(UiLanguage::German, "settings.title"),
+            "Einstellungen"
+        );
+        assert_eq!(translate(UiLanguage::System, "settings.title"), "Settings");
+        assert_eq!(translate(UiLanguage::French, "no.such.key"), "no.such.key");
+    }
+}
@@ -0,0 +1,322 @@
+// ============================================================================
+//! Frontend ↔ Backend IPC helpers
+//!
+//! Typed wrappers around Tauri's JavaScript event API. Components subscribe to
+//! backend events with [`listen_typed`] and receive deserialised payloads in a
+//! Leptos signal, instead of unpacking `JsValue`s by hand. A payload that does
+//! not match the expected type is logged to the console (with the event name)
+//! rather than silently producing default values.
+//!
+//! Listeners are tied to the reactive owner that created them and are
+//! unregistered automatically when that owner is cleaned up (e.g. when the
+//! component unmounts).
+//!
+//! High-frequency events are throttled by the backend; subscribing with
+//! [`listen_acknowledged_with`] tells it when each payload has been handled so
+//! the next one can be sent.
+//!
+//! Commands are called with [`invoke_command`], which decodes a rejection
+//! into the backend's [`CommandError`] envelope; [`describe_command_error`]
+//! turns one into the text shown to the user, based on its code.
+// ============================================================================
+
+use leptos::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use speakr_types::CommandError;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "event"], js_name = listen, catch)]
+    async fn tauri_listen(event: &str, handler: &js_sys::Function) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI_INTERNALS__"], js_name = invoke, catch)]
+    async fn tauri_invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+}
+
+/// Code of errors raised on the frontend side of a command call, e.g. an
+/// unknown command or a result that does not match the expected type.
+pub const IPC_ERROR_CODE: &str = "E_IPC";
+
+/// A registered event handler and the function that unregisters it.
+struct Listener {
+    _handler: Closure<dyn FnMut(JsValue)>,
+    unlisten: Option<js_sys::Function>,
+}
+
+thread_local! {
+    // JS handles are not `Send`, so they live here and cleanup closures only
+    // capture the listener id.
+    static LISTENERS: RefCell<HashMap<u64, Listener>> = RefCell::new(HashMap::new());
+    static NEXT_LISTENER_ID: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Subscribes to a backend event and exposes its latest payload as a signal.
+///
+/// # Arguments
+///
+/// * `event_name` - The Tauri event to listen for (e.g. `"status-changed"`)
+///
+/// # Returns
+///
+/// A signal holding `None` until the first event arrives, then the most
+/// recently received payload.
+///
+/// # Examples
+///
+/// ```ignore
+/// let status = listen_typed::<BackendStatus>(STATUS_CHANGED_EVENT);
+/// Effect::new(move || {
+///     if let Some(status) = status.get() {
+///         // react to the new status
+///     }
+/// });
+/// ```
+pub fn listen_typed<T>(event_name: &str) -> ReadSignal<Option<T>>
+where
+    T: DeserializeOwned + Send + Sync + 'static,
+{
+    let (payload, set_payload) = signal::<Option<T>>(None);
+    listen_typed_with(event_name, move |value: T| set_payload.set(Some(value)));
+    payload
+}
+
+/// Subscribes to a backend event and calls `on_event` with each typed payload.
+///
+/// Use this instead of [`listen_typed`] when every event matters (not just
+/// the latest one), e.g. when appending to a list.
+///
+/// # Arguments
+///
+/// * `event_name` - The Tauri event to listen for
+/// * `on_event` - Called with each successfully deserialised payload
+pub fn listen_typed_with<T, F>(event_name: &str, on_event: F)
+where
+    T: DeserializeOwned + 'static,
+    F: Fn(T) + 'static,
+{
+    let id = NEXT_LISTENER_ID.with(|next| {
+        let id = next.get();
+        next.set(id + 1);
+        id
+    });
+
+    let name = event_name.to_string();
+    let handler = Closure::<dyn FnMut(JsValue)>::new(move |event: JsValue| {
+        match decode_payload::<T>(&event) {
+            Ok(value) => on_event(value),
+            Err(e) => web_sys::console::error_1(
+                &format!("Ignoring '{name}' event with unexpected payload: {e}").into(),
+            ),
+        }
+    });
+    let js_handler = handler.as_ref().unchecked_ref::<js_sys::Function>().clone();
+
+    LISTENERS.with(|listeners| {
+        listeners.borrow_mut().insert(
+            id,
+            Listener {
+                _handler: handler,
+                unlisten: None,
+            },
+        );
+    });
+
+    let name = event_name.to_string();
+    spawn_local(async move {
+        match tauri_listen(&name, &js_handler).await {
+            Ok(unlisten) => {
+                let unlisten = unlisten.dyn_into::<js_sys::Function>().ok();
+                // If the owner was cleaned up while registering, unlisten now
+                let orphaned =
+                    LISTENERS.with(|listeners| match listeners.borrow_mut().get_mut(&id) {
+                        Some(listener) => {
+                            listener.unlisten = unlisten;
+                            None
+                        }
+                        None => unlisten,
+                    });
+                if let Some(unlisten) = orphaned {
+                    let _ = unlisten.call0(&JsValue::NULL);
+                }
+            }
+            Err(e) => {
+                web_sys::console::error_2(
+                    &format!("Failed to listen for '{name}' events:").into(),
+                    &e,
+                );
+                LISTENERS.with(|listeners| listeners.borrow_mut().remove(&id));
+            }
+        }
+    });
+
+    on_cleanup(move || {
+        let listener = LISTENERS.with(|listeners| listeners.borrow_mut().remove(&id));
+        if let Some(unlisten) = listener.and_then(|l| l.unlisten) {
+            let _ = unlisten.call0(&JsValue::NULL);
+        }
+    });
+}
+
+/// Like [`listen_typed_with`], but acknowledges each payload after
+/// `on_event` has handled it, so a backend throttling the event by
+/// acknowledgement can send the next one.
+///
+/// # Arguments
+///
+/// * `event_name` - The throttled Tauri event to listen for
+/// * `on_event` - Called with each successfully deserialised payload
+pub fn listen_acknowledged_with<T, F>(event_name: &str, on_event: F)
+where
+    T: DeserializeOwned + 'static,
+    F: Fn(T) + 'static,
+{
+    let name = event_name.to_string();
+    listen_typed_with(event_name, move |value: T| {
+        on_event(value);
+        acknowledge(&name);
+    });
+}
+
+/// Tells the backend the latest payload of `event_name` has been handled.
+fn acknowledge(event_name: &str) {
+    #[derive(serde::Serialize)]
+    struct AckArgs<'a> {
+        event: &'a str,
+    }
+
+    let Ok(args) = serde_wasm_bindgen::to_value(&AckArgs { event: event_name }) else {
+        return;
+    };
+    spawn_local(async move {
+        if let Err(e) = tauri_invoke("ack_event", args).await {
+            web_sys::console::error_2(&"Failed to acknowledge event:".into(), &e);
+        }
+    });
+}
+
+/// Extracts and deserialises the `payload` field of a Tauri event object.
+fn decode_payload<T: DeserializeOwned>(event: &JsValue) -> Result<T, String> {
+    let payload = js_sys::Reflect::get(event, &JsValue::from_str("payload"))
+        .map_err(|_| "event has no payload".to_string())?;
+    serde_wasm_bindgen::from_value(payload).map_err(|e| e.to_string())
+}
+
+/// Calls a backend command and deserialises its result.
+///
+/// # Arguments
+///
+/// * `cmd` - The Tauri command name, e.g. `"load_settings"`
+/// * `args` - The command arguments, serialised to a JavaScript object
+///
+/// # Errors
+///
+/// Returns the [`CommandError`] the command rejected with, or one with code
+/// [`IPC_ERROR_CODE`] if the call itself failed.
+pub async fn invoke_command<T, A>(cmd: &str, args: &A) -> Result<T, CommandError>
+where
+    T: DeserializeOwned,
+    A: Serialize + ?Sized,
+{
+    let args = serde_wasm_bindgen::to_value(args)
+        .map_err(|e| ipc_error(format!("Failed to serialize args: {e}")))?;
+    invoke_raw(cmd, args).await
+}
+
+/// Like [`invoke_command`], for commands without arguments.
+///
+/// # Errors
+///
+/// See [`invoke_command`].
+pub async fn invoke_command_no_args<T: DeserializeOwned>(cmd: &str) -> Result<T, CommandError> {
+    invoke_raw(cmd, JsValue::NULL).await
+}
+
+async fn invoke_raw<T: DeserializeOwned>(cmd: &str, args: JsValue) -> Result<T, CommandError> {
+    let result = tauri_invoke(cmd, args)
+        .await
+        .map_err(decode_command_error)?;
+    serde_wasm_bindgen::from_value(result)
+        .map_err(|e| ipc_error(format!("Failed to deserialize result: {e}")))
+}
+
+/// Reads the value a command rejected with.
+///
+/// Commands reject with a [`CommandError`]; anything else (Tauri rejects
+/// unknown commands with a plain string) becomes an [`IPC_ERROR_CODE`] error.
+fn decode_command_error(rejection: JsValue) -> CommandError {
+    if let Ok(error) = serde_wasm_bindgen::from_value::<CommandError>(rejection.clone()) {
+        return error;
+    }
+    let message = rejection
+        .as_string()
+        .or_else(|| {
+            js_sys::JSON::stringify(&rejection)
+                .ok()
+                .and_then(|json| json.as_string())
+        })
+        .unwrap_or_else(|| "Unknown error".to_string());
+    ipc_error(message)
+}
+
+fn ipc_error(message: String) -> CommandError {
+    CommandError::new(IPC_ERROR_CODE, message, false)
+}
+
+/// Text shown to the user for a failed command.
+///
+/// Errors the user can act on get a hint on what to do; the others carry
+/// their code so it can be quoted in a bug report.
+pub fn describe_command_error(error: &CommandError) -> String {
+    let hint = match error.code.as_str() {
+        // The message already names the clash and suggests alternatives
+        "E_HOT_KEY_CONFLICT" => return error.message.clone(),
+        "E_HOT_KEY_NOT_FOUND" => "The shortcut is no longer registered.",
+        "E_IO_NOT_FOUND" => "Check that the file or folder still exists.",
+        "E_IO_PERMISSION_DENIED" => "Check that Speakr is allowed to access this location.",
+        "E_AUDIO_CAPTURE" => "Check that a microphone is connected and Speakr may use it.",
+        "E_TEXT_INJECTION" => "Check that Speakr has Accessibility access.",
+        _ if error.recoverable => "Please try again.",
+        code => return format!("{} ({code})", error.message),
+    };
+    format!("{}. {hint}", error.message.trim_end_matches('.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use speakr_types::AppError;
+
+    #[test]
+    fn test_describe_command_error_switches_on_code() {
+        let conflict = CommandError::from(AppError::HotKeyConflict(
+            "Cmd+Space is used by macOS for Spotlight search.".to_string(),
+        ));
+        assert_eq!(
+            describe_command_error(&conflict),
+            "Hot-key conflict: Cmd+Space is used by macOS for Spotlight search."
+        );
+
+        let missing = CommandError::from(AppError::HotKeyNotFound("Cmd+F1".to_string()));
+        assert_eq!(
+            describe_command_error(&missing),
+            "Hot-key not found: Cmd+F1. The shortcut is no longer registered."
+        );
+
+        let transcription = CommandError::from(AppError::Transcription("timeout".to_string()));
+        assert!(describe_command_error(&transcription).ends_with("Please try again."));
+
+        let broken = CommandError::from(AppError::Command("no runtime".to_string()));
+        assert_eq!(
+            describe_command_error(&broken),
+            "Command error: no runtime (E_COMMAND)"
+        );
+        assert!(
+            describe_command_error(&ipc_error("unknown command".to_string())).ends_with("(E_IPC)")
+        );
+    }
+}
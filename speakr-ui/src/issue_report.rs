@@ -0,0 +1,139 @@
+//! Issue report composition for Speakr application.
+//!
+//! When a dictation fails, the user can open a prefilled GitHub issue with:
+//! - The error code and a redacted error message
+//! - The app version
+//! - The stages the dictation went through before it failed
+//!
+//! The report is composed locally into a "new issue" URL and opened in the
+//! browser, where the user reviews and submits it; nothing is sent by the
+//! app itself. Home directories and quoted text, which may contain user
+//! names or dictated words, are redacted first.
+
+use speakr_types::AppError;
+
+/// Page for opening a new issue in the Speakr repository.
+const NEW_ISSUE_URL: &str = "https://github.com/djessup/speakr/issues/new";
+
+/// Placeholder for redacted text.
+const REDACTED: &str = "…";
+
+/// A bug report for a failed dictation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IssueReport {
+    /// The error the dictation failed with.
+    pub error: AppError,
+    /// The app version.
+    pub version: String,
+    /// Names of the pipeline stages reached, in order.
+    pub trace: Vec<String>,
+}
+
+impl IssueReport {
+    /// Returns the issue title.
+    pub fn title(&self) -> String {
+        format!("Dictation failed: {}", self.error.code())
+    }
+
+    /// Returns the issue body as Markdown.
+    pub fn body(&self) -> String {
+        let trace = if self.trace.is_empty() {
+            "(none)".to_string()
+        } else {
+            self.trace.join(" → ")
+        };
+
+        format!(
+            "## What happened\n\n<!-- What were you doing when dictation failed? -->\n\n\
+             ## Details\n\n\
+             - Error code: `{}`\n\
+             - Error: {}\n\
+             - Speakr version: {}\n\
+             - Stages: {}\n",
+            self.error.code(),
+            redact(&self.error.to_string()),
+            self.version,
+            trace,
+        )
+    }
+
+    /// Returns the URL of a new issue prefilled with this report.
+    pub fn url(&self) -> String {
+        format!(
+            "{NEW_ISSUE_URL}?title={}&body={}",
+            percent_encode(&self.title()),
+            percent_encode(&self.body())
+        )
+    }
+}
+
+/// Removes personal details from an error message.
+///
+/// Home directories (`/Users/<name>`, `/home/<name>`) become `~`, and text
+/// between double quotes, such as a transcript, is replaced with `…`.
+pub fn redact(message: &str) -> String {
+    let mut redacted = message.to_string();
+    for home in ["/Users/", "/home/"] {
+        while let Some(start) = redacted.find(home) {
+            let name_start = start + home.len();
+            let name_end = redacted[name_start..]
+                .find(|c: char| c == '/' || c.is_whitespace())
+                .map_or(redacted.len(), |offset| name_start + offset);
+            redacted.replace_range(start..name_end, "~");
+        }
+    }
+
+    let mut parts = redacted.split('"');
+    let mut result = parts.next().unwrap_or_default().to_string();
+    let mut quoted = true;
+    for part in parts {
+        result.push('"');
+        result.push_str(if quoted { REDACTED } else { part });
+        quoted = !quoted;
+    }
+    result
+}
+
+/// Percent-encodes text for use in a URL query parameter.
+fn percent_encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_removes_user_names_and_quoted_text() {
+        assert_eq!(
+            redact(r#"Failed to open /Users/jane/Library/model.bin: "hello world""#),
+            r#"Failed to open ~/Library/model.bin: "…""#
+        );
+        assert_eq!(redact("/home/sam"), "~");
+        assert_eq!(redact("No microphone"), "No microphone");
+    }
+
+    #[test]
+    fn test_url_is_prefilled_and_encoded() {
+        let report = IssueReport {
+            error: AppError::Transcription("model missing".to_string()),
+            version: "0.1.0".to_string(),
+            trace: vec!["Recording".to_string(), "Transcribing".to_string()],
+        };
+
+        assert!(report.body().contains("Stages: Recording → Transcribing"));
+        let url = report.url();
+        assert!(url.starts_with(
+            "https://github.com/djessup/speakr/issues/new?title=Dictation%20failed%3A%20E_TRANSCRIPTION&body="
+        ));
+        assert!(!url.contains(' '));
+        assert!(url.contains("Speakr%20version%3A%200.1.0"));
+    }
+}
@@ -0,0 +1,204 @@
+//! Latency breakdown popover for Speakr application.
+//!
+//! Opt-in via the `show_latency_breakdown` setting. After each completed
+//! dictation the backend emits a [`DictationLatency`] with the time spent in
+//! each stage; this popover shows it briefly, with the model and microphone
+//! that were used, so users can see where the time goes when tuning model
+//! size or post-processing. The post-processing steps (replacements, filler
+//! words, plugins, …) are listed in the order they ran with their own cost,
+//! dimmed when they left the text unchanged.
+
+use leptos::prelude::*;
+use speakr_types::events::SETTINGS_CHANGED_EVENT;
+use speakr_types::{AppSettings, DictationLatency, DICTATION_LATENCY_EVENT};
+use std::time::Duration;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::ipc::listen_typed_with;
+use crate::settings::SettingsManager;
+
+/// How long the popover stays open after a dictation.
+const POPOVER_DURATION: Duration = Duration::from_secs(10);
+
+/// Formats a stage duration, switching to seconds from one second up.
+pub fn format_ms(ms: u64) -> String {
+    if ms < 1_000 {
+        format!("{ms} ms")
+    } else {
+        format!("{:.1} s", ms as f64 / 1_000.0)
+    }
+}
+
+/// Formats a post-processing step duration given in microseconds.
+pub fn format_us(us: u64) -> String {
+    if us < 1_000 {
+        format!("{us} µs")
+    } else if us < 1_000_000 {
+        format!("{:.1} ms", us as f64 / 1_000.0)
+    } else {
+        format_ms(us / 1_000)
+    }
+}
+
+/// Returns the stages of a dictation with their durations, in order.
+pub fn latency_stages(latency: &DictationLatency) -> [(&'static str, u64); 4] {
+    [
+        ("Capture", latency.capture_ms),
+        ("Transcribe", latency.transcribe_ms),
+        ("Process", latency.process_ms),
+        ("Inject", latency.inject_ms),
+    ]
+}
+
+/// Popover showing where the time of the last dictation went.
+#[component]
+pub fn LatencyPopover() -> impl IntoView {
+    let (enabled, set_enabled) = signal(false);
+    let (latency, set_latency) = signal::<Option<DictationLatency>>(None);
+    // Identifies the latest dictation so an older timer does not close it
+    let (shown, set_shown) = signal(0_u64);
+
+    Effect::new(move || {
+        spawn_local(async move {
+            if let Ok(settings) = SettingsManager::load().await {
+                set_enabled.set(settings.show_latency_breakdown);
+            }
+        });
+    });
+    listen_typed_with(SETTINGS_CHANGED_EVENT, move |settings: AppSettings| {
+        set_enabled.set(settings.show_latency_breakdown);
+        if !settings.show_latency_breakdown {
+            set_latency.set(None);
+        }
+    });
+
+    listen_typed_with(DICTATION_LATENCY_EVENT, move |update: DictationLatency| {
+        if !enabled.get_untracked() {
+            return;
+        }
+        let id = shown.get_untracked() + 1;
+        set_shown.set(id);
+        set_latency.set(Some(update));
+        set_timeout(
+            move || {
+                if shown.get_untracked() == id {
+                    set_latency.set(None);
+                }
+            },
+            POPOVER_DURATION,
+        );
+    });
+
+    move || {
+        latency.get().map(|latency| {
+            let total = latency.total_ms().max(1);
+            view! {
+                <div class="latency-popover" role="status">
+                    <div class="latency-header">
+                        <span class="latency-title">
+                            {format!("⏱️ {}", format_ms(latency.total_ms()))}
+                        </span>
+                        <span class="checkbox-help">
+                            {match &latency.input_device {
+                                Some(device) => format!("{} · {device}", latency.model),
+                                None => latency.model.to_string(),
+                            }}
+                        </span>
+                        <button
+                            class="latency-close"
+                            title="Close"
+                            on:click=move |_| set_latency.set(None)
+                        >
+                            "×"
+                        </button>
+                    </div>
+                    {latency_stages(&latency)
+                        .into_iter()
+                        .map(|(stage, ms)| {
+                            let width = format!("width: {}%", ms * 100 / total);
+                            view! {
+                                <div class="latency-stage">
+                                    <span class="latency-stage-name">{stage}</span>
+                                    <div class="latency-bar">
+                                        <div class="latency-bar-fill" style=width></div>
+                                    </div>
+                                    <span class="latency-stage-time">{format_ms(ms)}</span>
+                                </div>
+                            }
+                        })
+                        .collect::<Vec<_>>()}
+                    {(!latency.processing_stages.is_empty()).then(|| {
+                        let stages = latency.processing_stages.clone();
+                        let total = stages.iter().map(|s| s.duration_us).sum::<u64>().max(1);
+                        view! {
+                            <details class="latency-processing">
+                                <summary>{format!("Post-processing ({} steps)", stages.len())}</summary>
+                                {stages
+                                    .into_iter()
+                                    .map(|stage| {
+                                        let width = format!("width: {}%", stage.duration_us * 100 / total);
+                                        view! {
+                                            <div
+                                                class="latency-stage latency-substage"
+                                                class:unchanged=!stage.changed
+                                                title=if stage.changed { "Changed the text" } else { "Left the text unchanged" }
+                                            >
+                                                <span class="latency-stage-name">{stage.name}</span>
+                                                <div class="latency-bar">
+                                                    <div class="latency-bar-fill" style=width></div>
+                                                </div>
+                                                <span class="latency-stage-time">
+                                                    {format_us(stage.duration_us)}
+                                                </span>
+                                            </div>
+                                        }
+                                    })
+                                    .collect::<Vec<_>>()}
+                            </details>
+                        }
+                    })}
+                </div>
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use speakr_types::ModelId;
+
+    #[test]
+    fn test_format_ms_switches_to_seconds() {
+        assert_eq!(format_ms(0), "0 ms");
+        assert_eq!(format_ms(999), "999 ms");
+        assert_eq!(format_ms(4_260), "4.3 s");
+    }
+
+    #[test]
+    fn test_format_us_scales_units() {
+        assert_eq!(format_us(850), "850 µs");
+        assert_eq!(format_us(1_250), "1.2 ms");
+        assert_eq!(format_us(2_500_000), "2.5 s");
+    }
+
+    #[test]
+    fn test_latency_stages_are_in_pipeline_order() {
+        let latency = DictationLatency {
+            capture_ms: 1,
+            transcribe_ms: 2,
+            process_ms: 3,
+            inject_ms: 4,
+            model: ModelId::new("small"),
+            input_device: None,
+            processing_stages: Vec::new(),
+        };
+
+        let stages = latency_stages(&latency);
+        assert_eq!(
+            stages.map(|(name, _)| name),
+            ["Capture", "Transcribe", "Process", "Inject"]
+        );
+        assert_eq!(stages.map(|(_, ms)| ms), [1, 2, 3, 4]);
+    }
+}
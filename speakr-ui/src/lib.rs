@@ -27,7 +27,29 @@
 // Module Declarations
 // =========================
 mod app;
+mod corrupt_model;
+mod crash_notice;
+mod empty_state;
+mod file_transcription;
+mod history;
+mod hotkey_capture;
+mod hotkey_status;
+mod hotkey_suggestions;
+mod i18n;
+mod ipc;
+mod issue_report;
+mod latency_popover;
+mod model_catalogue;
+mod onboarding;
+mod pending;
+mod pipeline_status;
+mod privacy;
+mod replacements;
 mod settings;
+mod settings_recovery;
+mod status_store;
+mod theme;
+mod voice_commands;
 
 // Debug-only UI panels
 #[cfg(debug_assertions)]
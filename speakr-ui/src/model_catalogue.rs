@@ -0,0 +1,105 @@
+//! Helpers for the transcription model picker.
+//!
+//! The picker lists every model in the catalogue returned by the
+//! `list_model_catalogue` command, grouped by family (tiny, base, small,
+//! medium, large), with the download size and checksum the catalogue
+//! publishes.
+
+use speakr_types::{CatalogueModel, ModelId};
+
+/// Returns the family a model belongs to, e.g. "base" for `base.en-q5_1`.
+pub fn model_family(id: &ModelId) -> &str {
+    id.as_str().split(['.', '-']).next().unwrap_or_default()
+}
+
+/// Groups catalogue models by family, keeping the catalogue order.
+pub fn group_by_family(catalogue: &[CatalogueModel]) -> Vec<(String, Vec<CatalogueModel>)> {
+    let mut groups: Vec<(String, Vec<CatalogueModel>)> = Vec::new();
+    for model in catalogue {
+        let family = model_family(&model.id);
+        match groups.iter_mut().find(|(name, _)| name == family) {
+            Some((_, models)) => models.push(model.clone()),
+            None => groups.push((family.to_string(), vec![model.clone()])),
+        }
+    }
+    groups
+}
+
+/// Describes a model's variant, e.g. "English only, quantised".
+///
+/// Returns "Multilingual" for full-precision multilingual models.
+pub fn model_variant(id: &ModelId) -> String {
+    let mut traits = vec![if id.is_english_only() {
+        "English only"
+    } else {
+        "Multilingual"
+    }];
+    if id.is_quantised() {
+        traits.push("quantised");
+    }
+    traits.join(", ")
+}
+
+/// Text of a model's entry in the picker, e.g. "tiny.en-q5_1 — 31 MB ✓".
+pub fn model_option_label(model: &CatalogueModel) -> String {
+    let installed = if model.installed { " ✓" } else { "" };
+    format!("{} — {} MB{installed}", model.id, model.size_mb())
+}
+
+/// Returns the first seven characters of a checksum.
+pub fn short_sha(sha: &str) -> &str {
+    sha.char_indices()
+        .nth(7)
+        .map_or(sha, |(end, _)| &sha[..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model(id: &str, installed: bool) -> CatalogueModel {
+        CatalogueModel {
+            id: ModelId::new(id),
+            size_bytes: 32 * 1024 * 1024,
+            sha: "2827a03e495b1ed3048ef28a6a4620537db4ee51".to_string(),
+            memory_mb: 100,
+            installed,
+        }
+    }
+
+    #[test]
+    fn test_model_family() {
+        assert_eq!(model_family(&ModelId::new("base.en-q5_1")), "base");
+        assert_eq!(model_family(&ModelId::new("large-v3-turbo")), "large");
+        assert_eq!(model_family(&ModelId::new("tiny")), "tiny");
+    }
+
+    #[test]
+    fn test_group_by_family_keeps_catalogue_order() {
+        let catalogue = [
+            model("tiny", false),
+            model("tiny.en", false),
+            model("base", true),
+        ];
+        let groups = group_by_family(&catalogue);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0, "tiny");
+        assert_eq!(groups[0].1.len(), 2);
+        assert_eq!(groups[1].1[0].id.as_str(), "base");
+    }
+
+    #[test]
+    fn test_labels() {
+        assert_eq!(
+            model_variant(&ModelId::new("tiny.en-q5_1")),
+            "English only, quantised"
+        );
+        assert_eq!(model_variant(&ModelId::new("medium")), "Multilingual");
+        assert_eq!(
+            model_option_label(&model("tiny.en-q5_1", true)),
+            "tiny.en-q5_1 — 32 MB ✓"
+        );
+        assert_eq!(short_sha("2827a03e495b"), "2827a03");
+        assert_eq!(short_sha("abc"), "abc");
+    }
+}
@@ -0,0 +1,246 @@
+//! First-run onboarding wizard for Speakr application.
+//!
+//! Shown instead of the main view until the user has been through it once.
+//! Walks through the macOS permissions dictation needs:
+//! - Microphone access, to record
+//! - Accessibility access, to type into other applications
+//!
+//! Each permission step shows whether it has been granted, deep-links to the
+//! matching System Settings pane and re-checks on request. The backend also
+//! watches for the permission being granted and sends
+//! `PERMISSION_GRANTED_EVENT`, so the step updates by itself once the user
+//! has enabled Speakr in System Settings. Steps can be skipped; finishing
+//! the wizard records `onboarding_completed` in settings.
+//!
+//! The last step shows the dictation hot-key with a few free alternatives;
+//! clicking one registers and saves it straight away.
+
+use leptos::prelude::*;
+use speakr_types::{PermissionKind, ServiceStatus, PERMISSION_GRANTED_EVENT};
+use wasm_bindgen_futures::spawn_local;
+
+use crate::hotkey_suggestions::HotkeySuggestions;
+use crate::ipc::listen_typed_with;
+use crate::settings::{GlobalShortcutManager, SettingsManager};
+
+/// A page of the onboarding wizard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnboardingStep {
+    Welcome,
+    Permission(PermissionKind),
+    Done,
+}
+
+impl OnboardingStep {
+    /// Returns the page that follows this one.
+    pub fn next(self) -> Self {
+        match self {
+            OnboardingStep::Welcome => OnboardingStep::Permission(PermissionKind::Microphone),
+            OnboardingStep::Permission(PermissionKind::Microphone) => {
+                OnboardingStep::Permission(PermissionKind::Accessibility)
+            }
+            OnboardingStep::Permission(PermissionKind::Accessibility) | OnboardingStep::Done => {
+                OnboardingStep::Done
+            }
+        }
+    }
+}
+
+/// Explains why Speakr needs a permission.
+fn permission_reason(kind: PermissionKind) -> &'static str {
+    match kind {
+        PermissionKind::Microphone => {
+            "Speakr records your voice only while you hold a dictation, and never sends it anywhere."
+        }
+        PermissionKind::Accessibility => {
+            "Speakr types the transcribed text into the app you are using, which macOS treats as controlling your computer."
+        }
+    }
+}
+
+/// First-run wizard that helps the user grant the permissions Speakr needs.
+///
+/// # Arguments
+///
+/// * `on_finish` - Called once the wizard has been completed and saved
+#[component]
+pub fn OnboardingWizard(on_finish: Callback<()>) -> impl IntoView {
+    let (step, set_step) = signal(OnboardingStep::Welcome);
+    let (status, set_status) = signal::<Option<ServiceStatus>>(None);
+    let (error_message, set_error_message) = signal::<Option<String>>(None);
+    let (hot_key, set_hot_key) = signal::<Option<String>>(None);
+
+    spawn_local(async move {
+        if let Ok(settings) = SettingsManager::load().await {
+            set_hot_key.set(Some(settings.hot_key));
+        }
+    });
+
+    let check = move |kind: PermissionKind| {
+        set_status.set(None);
+        spawn_local(async move {
+            match SettingsManager::check_permission(kind).await {
+                Ok(checked) => set_status.set(Some(checked)),
+                Err(e) => set_error_message.set(Some(e)),
+            }
+        });
+    };
+
+    listen_typed_with(PERMISSION_GRANTED_EVENT, move |granted: PermissionKind| {
+        if step.get_untracked() == OnboardingStep::Permission(granted) {
+            set_status.set(Some(ServiceStatus::Ready));
+        }
+    });
+
+    let go_to = move |next: OnboardingStep| {
+        set_error_message.set(None);
+        set_step.set(next);
+        if let OnboardingStep::Permission(kind) = next {
+            check(kind);
+        }
+    };
+
+    let open_settings = move |kind: PermissionKind| {
+        spawn_local(async move {
+            if let Err(e) = SettingsManager::open_permission_settings(kind).await {
+                set_error_message.set(Some(e));
+            }
+        });
+    };
+
+    let pick_hot_key = move |picked: String| {
+        set_error_message.set(None);
+        spawn_local(async move {
+            let saved = match SettingsManager::load().await {
+                Ok(mut settings) => {
+                    let _ = GlobalShortcutManager::unregister(&settings.hot_key).await;
+                    match GlobalShortcutManager::register(&picked).await {
+                        Ok(()) => {
+                            settings.hot_key = picked.clone();
+                            SettingsManager::save(&settings).await
+                        }
+                        Err(e) => {
+                            // Keep the old hot-key working
+                            let _ = GlobalShortcutManager::register(&settings.hot_key).await;
+                            Err(e)
+                        }
+                    }
+                }
+                Err(e) => Err(e),
+            };
+            match saved {
+                Ok(()) => set_hot_key.set(Some(picked)),
+                Err(e) => set_error_message.set(Some(e)),
+            }
+        });
+    };
+
+    let finish = move |_| {
+        spawn_local(async move {
+            let saved = match SettingsManager::load().await {
+                Ok(mut settings) => {
+                    settings.onboarding_completed = true;
+                    SettingsManager::save(&settings).await
+                }
+                Err(e) => Err(e),
+            };
+            match saved {
+                Ok(()) => on_finish.run(()),
+                Err(e) => set_error_message.set(Some(e)),
+            }
+        });
+    };
+
+    view! {
+        <div class="setting-group onboarding">
+            {move || match step.get() {
+                OnboardingStep::Welcome => view! {
+                    <h3>"👋 Welcome to Speakr"</h3>
+                    <p class="setting-description">
+                        "Speakr turns your speech into text right where your cursor is. Everything runs on this Mac. First, let's give it the two permissions it needs."
+                    </p>
+                    <div class="onboarding-actions">
+                        <button class="btn-primary" on:click=move |_| go_to(step.get_untracked().next())>
+                            "Get started"
+                        </button>
+                    </div>
+                }.into_any(),
+                OnboardingStep::Permission(kind) => view! {
+                    <h3>{format!("🔐 {} access", kind.display_name())}</h3>
+                    <p class="setting-description">{permission_reason(kind)}</p>
+
+                    <div class="onboarding-status">
+                        {move || match status.get() {
+                            None => view! { <span class="checkbox-help">"Checking…"</span> }.into_any(),
+                            Some(ServiceStatus::Ready) => view! {
+                                <span class="onboarding-granted">"✓ Granted"</span>
+                            }.into_any(),
+                            Some(_) => view! {
+                                <span class="onboarding-missing">
+                                    "Not granted yet. Enable Speakr in System Settings; this updates as soon as it is granted."
+                                </span>
+                            }.into_any(),
+                        }}
+                    </div>
+
+                    <div class="onboarding-actions">
+                        <Show when=move || status.get() != Some(ServiceStatus::Ready)>
+                            <button class="btn-secondary" on:click=move |_| open_settings(kind)>
+                                "Open System Settings"
+                            </button>
+                            <button class="btn-secondary" on:click=move |_| check(kind)>
+                                "Check again"
+                            </button>
+                        </Show>
+                        <button class="btn-primary" on:click=move |_| go_to(step.get_untracked().next())>
+                            {move || if status.get() == Some(ServiceStatus::Ready) { "Continue" } else { "Skip for now" }}
+                        </button>
+                    </div>
+                }.into_any(),
+                OnboardingStep::Done => view! {
+                    <h3>"🎉 You're all set"</h3>
+                    <p class="setting-description">
+                        "Put the cursor in any text field and use your hot-key to start dictating. You can change permissions later in System Settings."
+                    </p>
+                    {move || hot_key.get().map(|hot_key| view! {
+                        <p class="onboarding-status">
+                            "Your hot-key is " <kbd>{hot_key}</kbd> ". Prefer another one?"
+                        </p>
+                    })}
+                    <HotkeySuggestions on_pick=Callback::new(pick_hot_key) />
+                    <div class="onboarding-actions">
+                        <button class="btn-primary" on:click=finish>"Start dictating"</button>
+                    </div>
+                }.into_any(),
+            }}
+
+            {move || error_message.get().map(|error| view! {
+                <div class="error-message" role="alert">{error}</div>
+            })}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steps_cover_both_permissions_in_order() {
+        let mut step = OnboardingStep::Welcome;
+        let mut visited = Vec::new();
+        while step != OnboardingStep::Done {
+            step = step.next();
+            visited.push(step);
+        }
+
+        assert_eq!(
+            visited,
+            vec![
+                OnboardingStep::Permission(PermissionKind::Microphone),
+                OnboardingStep::Permission(PermissionKind::Accessibility),
+                OnboardingStep::Done,
+            ]
+        );
+    }
+}
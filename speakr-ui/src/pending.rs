@@ -0,0 +1,121 @@
+//! Pending dictations waiting for review.
+//!
+//! Dictations taken in dry-run mode, and those whose text could not be typed,
+//! are held by the backend instead of being lost. [`PendingDictations`] lists
+//! them so users can edit the text, type it into the app they are working in
+//! or discard it. The list is kept live via the `pending-changed` event and
+//! is hidden while nothing is pending.
+
+use leptos::prelude::*;
+use speakr_types::{PendingDictation, PENDING_CHANGED_EVENT};
+use wasm_bindgen_futures::spawn_local;
+
+use crate::hotkey_status::describe_elapsed;
+use crate::ipc::listen_typed_with;
+use crate::settings::SettingsManager;
+
+/// Heading for a queue of `count` dictations.
+pub fn pending_title(count: usize) -> String {
+    match count {
+        1 => "1 dictation waiting for review".to_string(),
+        n => format!("{n} dictations waiting for review"),
+    }
+}
+
+/// Dictations held for review, with edit, inject and discard actions.
+#[component]
+pub fn PendingDictations() -> impl IntoView {
+    let (pending, set_pending) = signal(Vec::<PendingDictation>::new());
+    let (error_message, set_error_message) = signal::<Option<String>>(None);
+
+    spawn_local(async move {
+        match SettingsManager::list_pending().await {
+            Ok(loaded) => set_pending.set(loaded),
+            Err(e) => web_sys::console::error_1(&e.into()),
+        }
+    });
+    listen_typed_with(
+        PENDING_CHANGED_EVENT,
+        move |updated: Vec<PendingDictation>| set_pending.set(updated),
+    );
+
+    let apply = move |result: Result<Vec<PendingDictation>, String>| match result {
+        Ok(updated) => {
+            set_error_message.set(None);
+            set_pending.set(updated);
+        }
+        Err(e) => set_error_message.set(Some(e)),
+    };
+
+    move || {
+        let items = pending.get();
+        if items.is_empty() {
+            return ().into_any();
+        }
+        let now = js_sys::Date::now() as u64;
+        view! {
+            <div class="setting-group pending-dictations">
+                <h3>{format!("📥 {}", pending_title(items.len()))}</h3>
+                {move || error_message.get().map(|message| view! {
+                    <div class="error-message" role="alert">{message}</div>
+                })}
+                <ul class="pending-list">
+                    {items
+                        .into_iter()
+                        .map(|item| {
+                            let id = item.id;
+                            view! {
+                                <li class="pending-item">
+                                    <div class="pending-meta">
+                                        <span class="pending-reason">{item.reason.description()}</span>
+                                        <span class="pending-time">{describe_elapsed(now, item.created_at)}</span>
+                                    </div>
+                                    <textarea
+                                        class="pending-text"
+                                        prop:value=item.text
+                                        on:change=move |e| {
+                                            let text = event_target_value(&e);
+                                            spawn_local(async move {
+                                                apply(SettingsManager::update_pending(id, text).await);
+                                            });
+                                        }
+                                    ></textarea>
+                                    <div class="pending-actions">
+                                        <button
+                                            class="btn-primary"
+                                            on:click=move |_| spawn_local(async move {
+                                                apply(SettingsManager::inject_pending(id).await);
+                                            })
+                                        >
+                                            "⌨️ Inject"
+                                        </button>
+                                        <button
+                                            class="btn-secondary btn-danger"
+                                            on:click=move |_| spawn_local(async move {
+                                                apply(SettingsManager::discard_pending(id).await);
+                                            })
+                                        >
+                                            "🗑️ Discard"
+                                        </button>
+                                    </div>
+                                </li>
+                            }
+                        })
+                        .collect_view()}
+                </ul>
+            </div>
+        }
+        .into_any()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pending_title_pluralises() {
+        assert_eq!(pending_title(1), "1 dictation waiting for review");
+        assert_eq!(pending_title(3), "3 dictations waiting for review");
+    }
+}
@@ -0,0 +1,184 @@
+//! Live dictation progress for Speakr application.
+//!
+//! Subscribes to the backend's structured [`PipelineEvent`]s and shows the
+//! current stage of a dictation, an input level meter while recording, and
+//! the transcribed text once it is available. Events are acknowledged as
+//! they are handled so the backend can throttle level updates to what the
+//! WebView keeps up with.
+//!
+//! When a dictation fails, a "Report problem" button opens a prefilled
+//! GitHub issue built from the error and the stages the dictation reached.
+
+use leptos::prelude::*;
+use speakr_types::events::{DICTATION_CANCELLED_EVENT, WORKFLOW_COMPLETED_EVENT};
+use speakr_types::{level_to_dbfs, AppError, PipelineEvent, PIPELINE_EVENT, SILENCE_DBFS};
+use wasm_bindgen_futures::spawn_local;
+
+use crate::ipc::{listen_acknowledged_with, listen_typed_with};
+use crate::issue_report::IssueReport;
+use crate::settings::SettingsManager;
+
+/// Converts an RMS input level into a meter width in percent.
+///
+/// Speech rarely exceeds an RMS of 0.3, so the level is square-rooted to
+/// make quiet input visible.
+pub fn level_percent(level: f32) -> u32 {
+    (level.clamp(0.0, 1.0).sqrt() * 100.0).round() as u32
+}
+
+/// Formats an input level in dBFS for display next to a level meter.
+pub fn format_dbfs(dbfs: f32) -> String {
+    if dbfs <= SILENCE_DBFS {
+        "Silence".to_string()
+    } else {
+        format!("{dbfs:.0} dBFS")
+    }
+}
+
+/// Progress panel for the dictation in flight.
+///
+/// Hidden until a dictation starts; cleared when a dictation is cancelled or
+/// completes without inserting text (e.g. a simulated run).
+#[component]
+pub fn PipelineStatus() -> impl IntoView {
+    let (stage, set_stage) = signal::<Option<PipelineEvent>>(None);
+    let (level, set_level) = signal(0.0_f32);
+    let (text, set_text) = signal::<Option<String>>(None);
+    // Stages reached by the current dictation, for problem reports
+    let (trace, set_trace) = signal(Vec::<String>::new());
+    let (report_error, set_report_error) = signal::<Option<String>>(None);
+
+    let record_stage = move |event: &PipelineEvent| {
+        let name = event.display_name().to_string();
+        set_trace.update(|trace| {
+            if trace.last() != Some(&name) {
+                trace.push(name);
+            }
+        });
+    };
+
+    listen_acknowledged_with(PIPELINE_EVENT, move |event: PipelineEvent| match event {
+        PipelineEvent::RecordingLevel(value) => set_level.set(value),
+        PipelineEvent::RecordingStarted => {
+            set_level.set(0.0);
+            set_text.set(None);
+            set_trace.set(Vec::new());
+            set_report_error.set(None);
+            record_stage(&event);
+            set_stage.set(Some(event));
+        }
+        PipelineEvent::PartialText(partial) => {
+            record_stage(&PipelineEvent::PartialText(String::new()));
+            set_text.set(Some(partial));
+        }
+        other => {
+            record_stage(&other);
+            set_stage.set(Some(other));
+        }
+    });
+
+    let report_problem = move |error: AppError| {
+        let report = IssueReport {
+            error,
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            trace: trace.get_untracked(),
+        };
+        spawn_local(async move {
+            if let Err(e) = SettingsManager::open_url(&report.url()).await {
+                set_report_error.set(Some(e));
+            }
+        });
+    };
+
+    let clear_unfinished = move || {
+        if !stage
+            .get_untracked()
+            .is_some_and(|event| event.is_finished())
+        {
+            set_stage.set(None);
+        }
+    };
+    listen_typed_with(WORKFLOW_COMPLETED_EVENT, move |_: String| {
+        clear_unfinished()
+    });
+    listen_typed_with(DICTATION_CANCELLED_EVENT, move |_: ()| set_stage.set(None));
+
+    view! {
+        {move || stage.get().map(|event| {
+            let is_recording = matches!(event, PipelineEvent::RecordingStarted);
+            let error = match &event {
+                PipelineEvent::Failed(error) => Some(error.clone()),
+                _ => None,
+            };
+            let suppressed = match &event {
+                PipelineEvent::InjectionSuppressed(reason) => Some(reason.clone()),
+                _ => None,
+            };
+
+            view! {
+                <div class="setting-group pipeline-status">
+                    <span class=format!("pipeline-stage {}", event.display_name().to_lowercase())>
+                        {event.display_name().to_string()}
+                    </span>
+
+                    <Show when=move || is_recording>
+                        <div class="level-meter">
+                            <div
+                                class="level-fill"
+                                style=move || format!("width: {}%", level_percent(level.get()))
+                            ></div>
+                        </div>
+                        <span class="level-readout">
+                            {move || format_dbfs(level_to_dbfs(level.get()))}
+                        </span>
+                    </Show>
+
+                    {suppressed.map(|reason| view! {
+                        <p class="pipeline-suppressed" role="status">
+                            {format!("{reason}. It is waiting in Pending until Speakr quits.")}
+                        </p>
+                    })}
+
+                    {error.map(|error| view! {
+                        <div class="error-message" role="alert">{error.to_string()}</div>
+                        <button
+                            class="btn-secondary"
+                            title="Opens a prefilled GitHub issue for you to review"
+                            on:click=move |_| report_problem(error.clone())
+                        >
+                            "Report problem"
+                        </button>
+                        {move || report_error.get().map(|e| view! {
+                            <p class="checkbox-help">{e}</p>
+                        })}
+                    })}
+
+                    {move || text.get().map(|text| view! {
+                        <p class="pipeline-text">{text}</p>
+                    })}
+                </div>
+            }
+        })}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_percent_is_clamped_and_scaled() {
+        assert_eq!(level_percent(0.0), 0);
+        assert_eq!(level_percent(0.25), 50);
+        assert_eq!(level_percent(1.0), 100);
+        assert_eq!(level_percent(4.0), 100);
+        assert_eq!(level_percent(-1.0), 0);
+    }
+
+    #[test]
+    fn test_format_dbfs_rounds_and_names_silence() {
+        assert_eq!(format_dbfs(-23.4), "-23 dBFS");
+        assert_eq!(format_dbfs(0.0), "0 dBFS");
+        assert_eq!(format_dbfs(SILENCE_DBFS), "Silence");
+    }
+}
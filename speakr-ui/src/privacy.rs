@@ -0,0 +1,81 @@
+//! Indicator shown while privacy mode is on.
+//!
+//! Privacy mode keeps transcripts out of the history and the logs and stops
+//! recordings being saved. It is usually toggled from the tray or the privacy
+//! hot-key, so [`PrivacyIndicator`] follows the `privacy-mode-changed` event
+//! and shows when it ends; clicking it ends privacy mode straight away.
+
+use leptos::prelude::*;
+use speakr_types::{PrivacyMode, PRIVACY_MODE_CHANGED_EVENT};
+use wasm_bindgen_futures::spawn_local;
+
+use crate::i18n::I18n;
+use crate::ipc::listen_typed_with;
+use crate::settings::SettingsManager;
+
+/// Formats a time of day as `HH:MM`.
+pub fn format_clock(hours: u32, minutes: u32) -> String {
+    format!("{hours:02}:{minutes:02}")
+}
+
+/// Local time of day of a Unix timestamp in milliseconds, e.g. "14:32".
+fn local_clock(timestamp_ms: u64) -> String {
+    let date = js_sys::Date::new(&(timestamp_ms as f64).into());
+    format_clock(date.get_hours(), date.get_minutes())
+}
+
+/// Header badge shown while privacy mode is on.
+#[component]
+pub fn PrivacyIndicator() -> impl IntoView {
+    let i18n = I18n::expect();
+    let (mode, set_mode) = signal(PrivacyMode::default());
+
+    spawn_local(async move {
+        match SettingsManager::get_privacy_mode().await {
+            Ok(current) => set_mode.set(current),
+            Err(e) => web_sys::console::error_1(&e.into()),
+        }
+    });
+    listen_typed_with(PRIVACY_MODE_CHANGED_EVENT, move |changed: PrivacyMode| {
+        set_mode.set(changed)
+    });
+
+    let end = move |_| {
+        spawn_local(async move {
+            match SettingsManager::set_privacy_mode(false).await {
+                Ok(current) => set_mode.set(current),
+                Err(e) => web_sys::console::error_1(&e.into()),
+            }
+        });
+    };
+
+    move || {
+        let mode = mode.get();
+        mode.active.then(|| {
+            let until = mode
+                .expires_at
+                .map(|expires_at| format!(" · {}", local_clock(expires_at)))
+                .unwrap_or_default();
+            view! {
+                <button
+                    class="privacy-indicator"
+                    title=move || i18n.t("app.privacy.end")
+                    on:click=end
+                >
+                    "🔒 " {move || i18n.t("app.privacy.active")} {until}
+                </button>
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_clock_pads_to_two_digits() {
+        assert_eq!(format_clock(9, 5), "09:05");
+        assert_eq!(format_clock(14, 32), "14:32");
+    }
+}
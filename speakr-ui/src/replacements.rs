@@ -0,0 +1,291 @@
+//! Editor for literal text replacements.
+//!
+//! Replacements are [`TextTransform::Replace`] steps in the post-processing
+//! chain: "speakr" becomes "Speakr", "new line" becomes a line break. They
+//! run in the order listed, which users change with the arrow buttons.
+//! Line breaks and tabs are shown and entered as `\n` and `\t`, since a
+//! single-line input cannot hold them.
+
+use leptos::prelude::*;
+use speakr_types::TextTransform;
+
+/// Shows line breaks, tabs and backslashes as `\n`, `\t` and `\\`.
+pub fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\\' => escaped.push_str("\\\\"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Turns `\n`, `\t` and `\\` typed by the user back into the characters.
+///
+/// Any other backslash is kept as typed.
+pub fn unescape_text(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.peek() {
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some('\\') => unescaped.push('\\'),
+            _ => {
+                unescaped.push('\\');
+                continue;
+            }
+        }
+        chars.next();
+    }
+    unescaped
+}
+
+/// Appends a rule, keeping capitalisation as the final step.
+pub fn add_replacement_rule(chain: &mut Vec<TextTransform>, rule: TextTransform) {
+    let position = chain
+        .iter()
+        .position(|t| matches!(t, TextTransform::CapitaliseSentences))
+        .unwrap_or(chain.len());
+    chain.insert(position, rule);
+}
+
+/// Swaps the replacement at `index` with the previous or next replacement.
+///
+/// Other transforms in the chain keep their positions.
+///
+/// # Returns
+///
+/// Returns `false` if there is no replacement to swap with.
+pub fn move_replacement(chain: &mut [TextTransform], index: usize, up: bool) -> bool {
+    let is_replacement = |t: &TextTransform| matches!(t, TextTransform::Replace { .. });
+    let other = if up {
+        chain[..index].iter().rposition(is_replacement)
+    } else {
+        chain
+            .iter()
+            .skip(index + 1)
+            .position(is_replacement)
+            .map(|offset| index + 1 + offset)
+    };
+    match other {
+        Some(other) => {
+            chain.swap(index, other);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Table of replacements with inline editing, ordering and removal.
+#[component]
+pub fn ReplacementEditor(
+    /// The post-processing chain holding the replacements
+    chain: Signal<Vec<TextTransform>>,
+    /// Called with the full updated chain whenever a replacement changes
+    on_change: Callback<Vec<TextTransform>>,
+) -> impl IntoView {
+    let (new_from, set_new_from) = signal(String::new());
+    let (new_to, set_new_to) = signal(String::new());
+
+    let update = move |index: usize, edit: &dyn Fn(&mut String, &mut String, &mut bool)| {
+        let mut updated = chain.get_untracked();
+        if let Some(TextTransform::Replace {
+            from,
+            to,
+            case_sensitive,
+        }) = updated.get_mut(index)
+        {
+            edit(from, to, case_sensitive);
+            on_change.run(updated);
+        }
+    };
+
+    let add = move || {
+        let from = new_from.get_untracked().trim().to_string();
+        if from.is_empty() {
+            return;
+        }
+        let mut updated = chain.get_untracked();
+        add_replacement_rule(
+            &mut updated,
+            TextTransform::Replace {
+                from,
+                to: unescape_text(&new_to.get_untracked()),
+                case_sensitive: false,
+            },
+        );
+        set_new_from.set(String::new());
+        set_new_to.set(String::new());
+        on_change.run(updated);
+    };
+
+    view! {
+        <div class="replacement-rules">
+            {move || {
+                chain.get().into_iter().enumerate().filter_map(|(index, transform)| {
+                    let TextTransform::Replace { from, to, case_sensitive } = transform else {
+                        return None;
+                    };
+                    Some(view! {
+                        <div class="replacement-rule text-replacement">
+                            <input
+                                type="text"
+                                class="rule-input"
+                                aria-label="Replace"
+                                prop:value=from
+                                on:change=move |e| {
+                                    let value = event_target_value(&e).trim().to_string();
+                                    if !value.is_empty() {
+                                        update(index, &|from, _, _| *from = value.clone());
+                                    }
+                                }
+                            />
+                            " → "
+                            <input
+                                type="text"
+                                class="rule-input"
+                                aria-label="With"
+                                prop:value=escape_text(&to)
+                                on:change=move |e| {
+                                    let value = unescape_text(&event_target_value(&e));
+                                    update(index, &|_, to, _| *to = value.clone());
+                                }
+                            />
+                            <label class="replacement-case" title="Match letter case exactly">
+                                <input
+                                    type="checkbox"
+                                    aria-label="Match letter case exactly"
+                                    checked=case_sensitive
+                                    on:change=move |e| {
+                                        let checked = event_target_checked(&e);
+                                        update(index, &|_, _, case_sensitive| *case_sensitive = checked);
+                                    }
+                                />
+                                "Aa"
+                            </label>
+                            <div class="replacement-actions">
+                                <button
+                                    class="btn-secondary"
+                                    title="Move up"
+                                    aria-label="Move up"
+                                    on:click=move |_| {
+                                        let mut updated = chain.get_untracked();
+                                        if move_replacement(&mut updated, index, true) {
+                                            on_change.run(updated);
+                                        }
+                                    }
+                                >
+                                    "↑"
+                                </button>
+                                <button
+                                    class="btn-secondary"
+                                    title="Move down"
+                                    aria-label="Move down"
+                                    on:click=move |_| {
+                                        let mut updated = chain.get_untracked();
+                                        if move_replacement(&mut updated, index, false) {
+                                            on_change.run(updated);
+                                        }
+                                    }
+                                >
+                                    "↓"
+                                </button>
+                                <button
+                                    class="btn-secondary"
+                                    on:click=move |_| {
+                                        let mut updated = chain.get_untracked();
+                                        updated.remove(index);
+                                        on_change.run(updated);
+                                    }
+                                >
+                                    "Remove"
+                                </button>
+                            </div>
+                        </div>
+                    })
+                }).collect::<Vec<_>>()
+            }}
+
+            <div class="replacement-rule-editor">
+                <input
+                    type="text"
+                    class="rule-input"
+                    placeholder="Replace, e.g. new line"
+                    prop:value=move || new_from.get()
+                    on:input=move |e| set_new_from.set(event_target_value(&e))
+                />
+                <input
+                    type="text"
+                    class="rule-input"
+                    placeholder="With, e.g. \\n"
+                    prop:value=move || new_to.get()
+                    on:input=move |e| set_new_to.set(event_target_value(&e))
+                    on:keydown=move |e| {
+                        if e.key() == "Enter" {
+                            add();
+                        }
+                    }
+                />
+                <button
+                    class="btn-primary"
+                    disabled=move || new_from.get().trim().is_empty()
+                    on:click=move |_| add()
+                >
+                    "Add Replacement"
+                </button>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replace(from: &str) -> TextTransform {
+        TextTransform::Replace {
+            from: from.to_string(),
+            to: String::new(),
+            case_sensitive: false,
+        }
+    }
+
+    #[test]
+    fn test_escape_round_trip() {
+        let text = "line\nbreak\ttab \\ slash";
+        assert_eq!(escape_text(text), r"line\nbreak\ttab \\ slash");
+        assert_eq!(unescape_text(&escape_text(text)), text);
+        assert_eq!(unescape_text(r"C:\path\n"), "C:\\path\n");
+    }
+
+    #[test]
+    fn test_move_replacement_skips_other_transforms() {
+        let mut chain = vec![
+            replace("a"),
+            TextTransform::TrimWhitespace,
+            replace("b"),
+            TextTransform::CapitaliseSentences,
+        ];
+        assert!(move_replacement(&mut chain, 2, true));
+        assert_eq!(chain[0], replace("b"));
+        assert_eq!(chain[2], replace("a"));
+        assert_eq!(chain[1], TextTransform::TrimWhitespace);
+        assert!(!move_replacement(&mut chain, 0, true));
+        assert!(!move_replacement(&mut chain, 2, false));
+    }
+
+    #[test]
+    fn test_add_replacement_rule_keeps_capitalisation_last() {
+        let mut chain = vec![TextTransform::CapitaliseSentences];
+        add_replacement_rule(&mut chain, replace("a"));
+        assert_eq!(chain.last(), Some(&TextTransform::CapitaliseSentences));
+    }
+}
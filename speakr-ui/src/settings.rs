@@ -2,49 +2,77 @@
 //!
 //! This module provides the Settings Panel component for configuring:
 //! - Global hot-key combinations
-//! - Whisper model selection (small, medium, large)
+//! - Whisper model selection (small, medium, large) and idle unloading
+//! - Model downloads with live speed and ETA
+//! - Installed model storage (sizes, checksums, deletion)
+//! - Transcription language (or automatic detection)
+//! - Output mode (type into the app, copy to the clipboard, or both)
+//! - Per-application profiles (punctuation, casing, injection method)
 //! - Auto-launch on system startup
+//! - Opt-in local API for other tools
+//! - Post-processing plugins (install, enable, remove)
 //! - Settings persistence via Tauri commands and local storage
 //!
 //! All settings management follows Tauri v2 plugin architecture with
 //! tauri-plugin-global-shortcut for hot-key functionality.
 
+use crate::hotkey_capture::HotkeyCapture;
+use crate::hotkey_status::HotkeyStatusPanel;
+use crate::hotkey_suggestions::HotkeySuggestions;
+use crate::i18n::I18n;
+use crate::ipc::{
+    describe_command_error, invoke_command, invoke_command_no_args, listen_typed_with,
+};
+use crate::model_catalogue::{group_by_family, model_option_label, model_variant, short_sha};
+use crate::pipeline_status::{format_dbfs, level_percent};
+use crate::replacements::{add_replacement_rule, ReplacementEditor};
+use crate::voice_commands::VoiceCommandEditor;
 use leptos::prelude::*;
 use serde::{Deserialize, Serialize};
-use speakr_types::{AppSettings, ModelSize};
+use speakr_types::events::{HOTKEY_TRIGGERED_EVENT, SETTINGS_CHANGED_EVENT};
+use speakr_types::{
+    dbfs_to_level, input_gain_for, AppProfile, AppSettings, AudioDuration, AutoGain, BatchJob,
+    CatalogueModel, CrashNotice, DiskSpaceCheck, DownloadProgress, HistoryEntry, InjectionMethod,
+    InputGain, InstalledModel, LanguageHotkey, LanguageOption, MicrophoneTest, ModelId,
+    ModelsDiskUsage, NewParagraphCommand, OutputMode, ParagraphBreak, PendingDictation,
+    PermissionKind, PluginInfo, PrivacyMode, ServiceStatus, SettingsProfiles, SettingsRecovery,
+    StopPhrase, StorageCategory, StorageUsage, SubtitleFormat, TextTransform, Theme, TrailingText,
+    TranscriptionResult, UiLanguage, VocabularySuggestion, VoiceCommand,
+    CONNECTIVITY_CHANGED_EVENT, DEFAULT_API_PORT, DEFAULT_LANGUAGE, DEFAULT_LONG_FORM_MINS,
+    DEFAULT_RECORDING_FILENAME_TEMPLATE, DEFAULT_SESSION_WINDOW_SECS,
+    DEFAULT_STOP_PHRASE_CONFIDENCE, DEFAULT_TYPING_DELAY_MS, DENOISE_MODEL_FILENAME,
+    MAX_AGC_TARGET_DBFS, MAX_INPUT_GAIN, MAX_LONG_FORM_MINS, MAX_SESSION_WINDOW_SECS,
+    MAX_TYPING_DELAY_MS, MIN_AGC_TARGET_DBFS, MIN_INPUT_GAIN, MODEL_DOWNLOAD_PROGRESS_EVENT,
+};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local;
 
-/// External bindings to Tauri APIs
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_namespace = ["window", "__TAURI_INTERNALS__"], js_name = invoke)]
-    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
-}
-
 /// Helper function to invoke Tauri commands
 async fn tauri_invoke<T: for<'de> Deserialize<'de>, U: Serialize>(
     cmd: &str,
     args: &U,
 ) -> Result<T, String> {
-    let js_args =
-        serde_wasm_bindgen::to_value(args).map_err(|e| format!("Failed to serialize args: {e}"))?;
-
-    let result = invoke(cmd, js_args).await;
-
-    serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to deserialize result: {e}"))
+    invoke_command(cmd, args)
+        .await
+        .map_err(|e| describe_command_error(&e))
 }
 
 /// Helper function for commands without arguments
 async fn tauri_invoke_no_args<T: for<'de> Deserialize<'de>>(cmd: &str) -> Result<T, String> {
-    let result = invoke(cmd, JsValue::NULL).await;
-
-    serde_wasm_bindgen::from_value(result).map_err(|e| format!("Failed to deserialize result: {e}"))
+    invoke_command_no_args(cmd)
+        .await
+        .map_err(|e| describe_command_error(&e))
 }
 
 /// Error type for settings operations
 pub type SettingsError = String;
 
+/// Arguments of the profile commands
+#[derive(serde::Serialize)]
+struct ProfileNameArgs<'a> {
+    name: &'a str,
+}
+
 // All types now centralized in speakr-types crate
 
 /// Settings manager that handles persistence and Tauri integration.
@@ -100,22 +128,518 @@ impl SettingsManager {
             .map_err(|e| format!("Invalid hot-key: {e}"))
     }
 
+    /// Lists hot-keys that are free to use right now, best first
+    pub async fn suggest_available_hotkeys() -> Result<Vec<String>, SettingsError> {
+        tauri_invoke_no_args("suggest_available_hotkeys")
+            .await
+            .map_err(|e| format!("Failed to suggest hot-keys: {e}"))
+    }
+
     /// Checks model availability
-    pub async fn check_model_availability(model_size: &str) -> Result<bool, SettingsError> {
+    pub async fn check_model_availability(model: &ModelId) -> Result<bool, SettingsError> {
         // Tauri commands expect parameters wrapped in an object with the parameter name as key
         #[derive(serde::Serialize)]
         struct CheckModelArgs {
-            #[serde(rename = "modelSize")]
-            model_size: String,
+            model: ModelId,
         }
 
         let args = CheckModelArgs {
-            model_size: model_size.to_string(),
+            model: model.clone(),
         };
 
         tauri_invoke("check_model_availability", &args).await
     }
 
+    /// Lists every model in the catalogue with its size and install state
+    pub async fn list_model_catalogue() -> Result<Vec<CatalogueModel>, SettingsError> {
+        tauri_invoke_no_args("list_model_catalogue")
+            .await
+            .map_err(|e| format!("Failed to list models: {e}"))
+    }
+
+    /// Lists model files installed in the model directory
+    pub async fn list_installed_models(
+        verify_checksums: bool,
+    ) -> Result<Vec<InstalledModel>, SettingsError> {
+        #[derive(serde::Serialize)]
+        struct ListInstalledModelsArgs {
+            #[serde(rename = "verifyChecksums")]
+            verify_checksums: bool,
+        }
+
+        let args = ListInstalledModelsArgs { verify_checksums };
+
+        tauri_invoke("list_installed_models", &args)
+            .await
+            .map_err(|e| format!("Failed to list installed models: {e}"))
+    }
+
+    /// Deletes an installed model file
+    pub async fn delete_model(filename: &str) -> Result<(), SettingsError> {
+        #[derive(serde::Serialize)]
+        struct DeleteModelArgs {
+            filename: String,
+        }
+
+        let args = DeleteModelArgs {
+            filename: filename.to_string(),
+        };
+
+        tauri_invoke::<(), _>("delete_model", &args)
+            .await
+            .map_err(|e| format!("Failed to delete model: {e}"))
+    }
+
+    /// Checks whether there is enough disk space to download a model
+    pub async fn check_disk_space_for_model(
+        filename: &str,
+    ) -> Result<DiskSpaceCheck, SettingsError> {
+        #[derive(serde::Serialize)]
+        struct CheckDiskSpaceArgs {
+            filename: String,
+        }
+
+        let args = CheckDiskSpaceArgs {
+            filename: filename.to_string(),
+        };
+
+        tauri_invoke("check_disk_space_for_model", &args)
+            .await
+            .map_err(|e| format!("Failed to check disk space: {e}"))
+    }
+
+    /// Downloads a model from the catalogue
+    ///
+    /// Resolves once the download has finished and been verified; progress
+    /// arrives separately as `model-download-progress` events.
+    pub async fn download_model(filename: &str) -> Result<(), SettingsError> {
+        #[derive(serde::Serialize)]
+        struct DownloadModelArgs {
+            filename: String,
+        }
+
+        let args = DownloadModelArgs {
+            filename: filename.to_string(),
+        };
+
+        tauri_invoke::<(), _>("download_model", &args)
+            .await
+            .map_err(|e| format!("Failed to download model: {e}"))
+    }
+
+    /// Reports the disk space used by each kind of data Speakr keeps
+    pub async fn get_storage_usage() -> Result<StorageUsage, SettingsError> {
+        tauri_invoke_no_args("get_storage_usage")
+            .await
+            .map_err(|e| format!("Failed to check storage usage: {e}"))
+    }
+
+    /// Frees the disk space used by one kind of data
+    pub async fn clean_up_storage(
+        category: StorageCategory,
+    ) -> Result<StorageUsage, SettingsError> {
+        #[derive(serde::Serialize)]
+        struct CleanUpStorageArgs {
+            category: StorageCategory,
+        }
+
+        tauri_invoke("clean_up_storage", &CleanUpStorageArgs { category })
+            .await
+            .map_err(|e| format!("Failed to clean up storage: {e}"))
+    }
+
+    /// Lists recent dictations, newest first
+    pub async fn list_history() -> Result<Vec<HistoryEntry>, SettingsError> {
+        tauri_invoke_no_args("list_history")
+            .await
+            .map_err(|e| format!("Failed to load history: {e}"))
+    }
+
+    /// Stores the user's correction of a dictation in the history
+    pub async fn correct_history(
+        id: u64,
+        text: String,
+    ) -> Result<Vec<HistoryEntry>, SettingsError> {
+        #[derive(serde::Serialize)]
+        struct CorrectHistoryArgs {
+            id: u64,
+            text: String,
+        }
+
+        tauri_invoke("correct_history", &CorrectHistoryArgs { id, text })
+            .await
+            .map_err(|e| format!("Failed to save correction: {e}"))
+    }
+
+    /// Deletes a dictation from the history
+    pub async fn delete_history(id: u64) -> Result<Vec<HistoryEntry>, SettingsError> {
+        #[derive(serde::Serialize)]
+        struct DeleteHistoryArgs {
+            id: u64,
+        }
+
+        tauri_invoke("delete_history", &DeleteHistoryArgs { id })
+            .await
+            .map_err(|e| format!("Failed to delete history entry: {e}"))
+    }
+
+    /// Suggests vocabulary terms from words the user often corrects
+    pub async fn get_vocabulary_suggestions() -> Result<Vec<VocabularySuggestion>, SettingsError> {
+        tauri_invoke_no_args("get_vocabulary_suggestions")
+            .await
+            .map_err(|e| format!("Failed to load vocabulary suggestions: {e}"))
+    }
+
+    /// Lists the dictations waiting for review, oldest first
+    pub async fn list_pending() -> Result<Vec<PendingDictation>, SettingsError> {
+        tauri_invoke_no_args("list_pending")
+            .await
+            .map_err(|e| format!("Failed to load pending dictations: {e}"))
+    }
+
+    /// Saves the edited text of a pending dictation
+    pub async fn update_pending(
+        id: u64,
+        text: String,
+    ) -> Result<Vec<PendingDictation>, SettingsError> {
+        #[derive(serde::Serialize)]
+        struct UpdatePendingArgs {
+            id: u64,
+            text: String,
+        }
+
+        tauri_invoke("update_pending", &UpdatePendingArgs { id, text })
+            .await
+            .map_err(|e| format!("Failed to save pending dictation: {e}"))
+    }
+
+    /// Types a pending dictation into the app the user was working in
+    pub async fn inject_pending(id: u64) -> Result<Vec<PendingDictation>, SettingsError> {
+        #[derive(serde::Serialize)]
+        struct PendingArgs {
+            id: u64,
+        }
+
+        tauri_invoke("inject_pending", &PendingArgs { id })
+            .await
+            .map_err(|e| format!("Failed to type pending dictation: {e}"))
+    }
+
+    /// Discards a pending dictation
+    pub async fn discard_pending(id: u64) -> Result<Vec<PendingDictation>, SettingsError> {
+        #[derive(serde::Serialize)]
+        struct PendingArgs {
+            id: u64,
+        }
+
+        tauri_invoke("discard_pending", &PendingArgs { id })
+            .await
+            .map_err(|e| format!("Failed to discard pending dictation: {e}"))
+    }
+
+    /// Types the most recent dictation again into the app the user is working in
+    pub async fn reinject_last_transcription() -> Result<(), SettingsError> {
+        tauri_invoke_no_args("reinject_last_transcription")
+            .await
+            .map_err(|e| format!("Failed to type the last dictation again: {e}"))
+    }
+
+    /// Gets whether privacy mode is on, and until when
+    pub async fn get_privacy_mode() -> Result<PrivacyMode, SettingsError> {
+        tauri_invoke_no_args("get_privacy_mode")
+            .await
+            .map_err(|e| format!("Failed to check privacy mode: {e}"))
+    }
+
+    /// Switches privacy mode on or off
+    pub async fn set_privacy_mode(active: bool) -> Result<PrivacyMode, SettingsError> {
+        #[derive(serde::Serialize)]
+        struct SetPrivacyModeArgs {
+            active: bool,
+        }
+
+        tauri_invoke("set_privacy_mode", &SetPrivacyModeArgs { active })
+            .await
+            .map_err(|e| format!("Failed to switch privacy mode: {e}"))
+    }
+
+    /// Gets the notice shown when unreadable settings were reset to defaults
+    pub async fn get_settings_recovery() -> Result<Option<SettingsRecovery>, SettingsError> {
+        tauri_invoke_no_args("get_settings_recovery")
+            .await
+            .map_err(|e| format!("Failed to check settings recovery: {e}"))
+    }
+
+    /// Dismisses the settings recovery notice
+    pub async fn dismiss_settings_recovery() -> Result<(), SettingsError> {
+        tauri_invoke_no_args("dismiss_settings_recovery")
+            .await
+            .map_err(|e| format!("Failed to dismiss notice: {e}"))
+    }
+
+    /// Opens the quarantined settings file in the default editor
+    pub async fn open_quarantined_settings() -> Result<(), SettingsError> {
+        tauri_invoke_no_args("open_quarantined_settings")
+            .await
+            .map_err(|e| format!("Failed to open settings file: {e}"))
+    }
+
+    /// Gets the notice shown when the previous run crashed
+    pub async fn get_crash_notice() -> Result<Option<CrashNotice>, SettingsError> {
+        tauri_invoke_no_args("get_crash_notice")
+            .await
+            .map_err(|e| format!("Failed to check for a crash report: {e}"))
+    }
+
+    /// Dismisses the crash notice
+    pub async fn dismiss_crash_notice() -> Result<(), SettingsError> {
+        tauri_invoke_no_args("dismiss_crash_notice")
+            .await
+            .map_err(|e| format!("Failed to dismiss notice: {e}"))
+    }
+
+    /// Opens the latest crash report in the default editor
+    pub async fn open_crash_report() -> Result<(), SettingsError> {
+        tauri_invoke_no_args("open_crash_report")
+            .await
+            .map_err(|e| format!("Failed to open crash report: {e}"))
+    }
+
+    /// Lists the names of the connected microphones, system default first
+    pub async fn list_input_devices() -> Result<Vec<String>, SettingsError> {
+        tauri_invoke_no_args("list_input_devices")
+            .await
+            .map_err(|e| format!("Failed to list microphones: {e}"))
+    }
+
+    /// Records a couple of seconds from the microphone and measures its levels
+    pub async fn test_microphone() -> Result<MicrophoneTest, SettingsError> {
+        tauri_invoke_no_args("test_microphone")
+            .await
+            .map_err(|e| format!("Microphone test failed: {e}"))
+    }
+
+    /// Starts a live level preview of a microphone at an input gain
+    pub async fn start_input_preview(device: String, gain: f32) -> Result<(), SettingsError> {
+        #[derive(serde::Serialize)]
+        struct InputPreviewArgs {
+            device: String,
+            gain: f32,
+        }
+
+        tauri_invoke::<(), _>("start_input_preview", &InputPreviewArgs { device, gain })
+            .await
+            .map_err(|e| format!("Failed to preview microphone: {e}"))
+    }
+
+    /// Reads the level of the input gain preview, in dBFS
+    pub async fn input_preview_level() -> Result<Option<f32>, SettingsError> {
+        tauri_invoke_no_args("input_preview_level")
+            .await
+            .map_err(|e| format!("Failed to read input level: {e}"))
+    }
+
+    /// Stops the input gain preview
+    pub async fn stop_input_preview() -> Result<(), SettingsError> {
+        tauri_invoke_no_args("stop_input_preview")
+            .await
+            .map_err(|e| format!("Failed to stop microphone preview: {e}"))
+    }
+
+    /// Checks whether the RNNoise weights are downloaded
+    pub async fn check_denoise_model() -> Result<bool, SettingsError> {
+        tauri_invoke_no_args("check_denoise_model")
+            .await
+            .map_err(|e| format!("Failed to check noise suppression model: {e}"))
+    }
+
+    /// Fetches the disk space used by installed models
+    pub async fn get_models_disk_usage() -> Result<ModelsDiskUsage, SettingsError> {
+        tauri_invoke_no_args("get_models_disk_usage")
+            .await
+            .map_err(|e| format!("Failed to read model disk usage: {e}"))
+    }
+
+    /// Lists installed post-processing plugins
+    pub async fn list_plugins() -> Result<Vec<PluginInfo>, SettingsError> {
+        tauri_invoke_no_args("list_plugins")
+            .await
+            .map_err(|e| format!("Failed to list plugins: {e}"))
+    }
+
+    /// Installs a WebAssembly plugin from a file path
+    pub async fn install_plugin(path: &str) -> Result<PluginInfo, SettingsError> {
+        #[derive(serde::Serialize)]
+        struct InstallPluginArgs {
+            path: String,
+        }
+
+        let args = InstallPluginArgs {
+            path: path.to_string(),
+        };
+
+        tauri_invoke("install_plugin", &args)
+            .await
+            .map_err(|e| format!("Failed to install plugin: {e}"))
+    }
+
+    /// Removes an installed plugin
+    pub async fn uninstall_plugin(name: &str) -> Result<(), SettingsError> {
+        #[derive(serde::Serialize)]
+        struct UninstallPluginArgs {
+            name: String,
+        }
+
+        let args = UninstallPluginArgs {
+            name: name.to_string(),
+        };
+
+        tauri_invoke::<(), _>("uninstall_plugin", &args)
+            .await
+            .map_err(|e| format!("Failed to remove plugin: {e}"))
+    }
+
+    /// Lists the saved settings profiles
+    pub async fn list_profiles() -> Result<SettingsProfiles, SettingsError> {
+        tauri_invoke_no_args("list_profiles")
+            .await
+            .map_err(|e| format!("Failed to list profiles: {e}"))
+    }
+
+    /// Saves the current hot-key, model and vocabulary as a named profile
+    pub async fn save_profile(name: &str) -> Result<SettingsProfiles, SettingsError> {
+        tauri_invoke("save_profile", &ProfileNameArgs { name })
+            .await
+            .map_err(|e| format!("Failed to save profile: {e}"))
+    }
+
+    /// Deletes a saved profile
+    pub async fn delete_profile(name: &str) -> Result<SettingsProfiles, SettingsError> {
+        tauri_invoke("delete_profile", &ProfileNameArgs { name })
+            .await
+            .map_err(|e| format!("Failed to delete profile: {e}"))
+    }
+
+    /// Switches to a saved profile, returning the settings now in effect
+    pub async fn switch_profile(name: &str) -> Result<AppSettings, SettingsError> {
+        tauri_invoke("switch_profile", &ProfileNameArgs { name })
+            .await
+            .map_err(|e| format!("Failed to switch profile: {e}"))
+    }
+
+    /// Fetches the transcription languages supported by the backend
+    pub async fn get_supported_languages() -> Result<Vec<LanguageOption>, SettingsError> {
+        tauri_invoke_no_args("get_supported_languages")
+            .await
+            .map_err(|e| format!("Failed to load languages: {e}"))
+    }
+
+    /// Runs a simulated dictation that needs no microphone or model
+    pub async fn simulate_dictation() -> Result<String, SettingsError> {
+        tauri_invoke_no_args("simulate_dictation")
+            .await
+            .map_err(|e| format!("Failed to run simulated dictation: {e}"))
+    }
+
+    /// Cancels the dictation in flight, returning whether one was running
+    pub async fn cancel_dictation() -> Result<bool, SettingsError> {
+        tauri_invoke_no_args("cancel_dictation")
+            .await
+            .map_err(|e| format!("Failed to cancel dictation: {e}"))
+    }
+
+    /// Checks whether a macOS privacy permission has been granted
+    pub async fn check_permission(kind: PermissionKind) -> Result<ServiceStatus, SettingsError> {
+        let command = match kind {
+            PermissionKind::Microphone => "check_microphone_permission",
+            PermissionKind::Accessibility => "check_accessibility_permission",
+        };
+
+        tauri_invoke_no_args(command)
+            .await
+            .map_err(|e| format!("Failed to check {} permission: {e}", kind.display_name()))
+    }
+
+    /// Opens the System Settings pane where a permission is granted
+    pub async fn open_permission_settings(kind: PermissionKind) -> Result<(), SettingsError> {
+        #[derive(serde::Serialize)]
+        struct OpenPermissionSettingsArgs {
+            kind: PermissionKind,
+        }
+
+        let args = OpenPermissionSettingsArgs { kind };
+
+        tauri_invoke::<(), _>("open_permission_settings", &args)
+            .await
+            .map_err(|e| format!("Failed to open System Settings: {e}"))
+    }
+
+    /// Transcribes an existing recording (WAV, MP3 or M4A)
+    pub async fn transcribe_file(path: &str) -> Result<TranscriptionResult, SettingsError> {
+        #[derive(serde::Serialize)]
+        struct TranscribeFileArgs<'a> {
+            path: &'a str,
+        }
+
+        let args = TranscribeFileArgs { path };
+
+        tauri_invoke("transcribe_file", &args)
+            .await
+            .map_err(|e| format!("Failed to transcribe file: {e}"))
+    }
+
+    /// Queues a folder of recordings for batch transcription
+    pub async fn transcribe_folder(path: &str) -> Result<BatchJob, SettingsError> {
+        #[derive(serde::Serialize)]
+        struct TranscribeFolderArgs<'a> {
+            path: &'a str,
+        }
+
+        let args = TranscribeFolderArgs { path };
+
+        tauri_invoke("transcribe_folder", &args)
+            .await
+            .map_err(|e| format!("Failed to transcribe folder: {e}"))
+    }
+
+    /// Saves a transcription as SubRip or WebVTT captions
+    pub async fn export_transcription(
+        result: &TranscriptionResult,
+        format: SubtitleFormat,
+        path: &str,
+    ) -> Result<(), SettingsError> {
+        #[derive(serde::Serialize)]
+        struct ExportTranscriptionArgs<'a> {
+            result: &'a TranscriptionResult,
+            format: SubtitleFormat,
+            path: &'a str,
+        }
+
+        let args = ExportTranscriptionArgs {
+            result,
+            format,
+            path,
+        };
+
+        tauri_invoke::<(), _>("export_transcription", &args)
+            .await
+            .map_err(|e| format!("Failed to export transcription: {e}"))
+    }
+
+    /// Opens a web page in the default browser
+    pub async fn open_url(url: &str) -> Result<(), SettingsError> {
+        #[derive(serde::Serialize)]
+        struct OpenUrlArgs<'a> {
+            url: &'a str,
+        }
+
+        let args = OpenUrlArgs { url };
+
+        tauri_invoke::<(), _>("plugin:opener|open_url", &args)
+            .await
+            .map_err(|e| format!("Failed to open browser: {e}"))
+    }
+
     /// Sets auto-launch preference
     pub async fn set_auto_launch(enable: bool) -> Result<(), SettingsError> {
         // Tauri commands expect parameters wrapped in an object with the parameter name as key
@@ -128,6 +652,13 @@ impl SettingsManager {
 
         tauri_invoke::<(), _>("set_auto_launch", &args).await
     }
+
+    /// Checks whether Speakr is actually registered to start at login
+    pub async fn get_auto_launch_status() -> Result<bool, SettingsError> {
+        tauri_invoke_no_args("get_auto_launch_status")
+            .await
+            .map_err(|e| format!("Failed to check auto-launch: {e}"))
+    }
 }
 
 /// Global shortcut manager using Tauri v2 plugin APIs.
@@ -150,7 +681,7 @@ impl GlobalShortcutManager {
                             console.log("Global shortcut triggered:", event);
                             // Forward to the Rust backend so it can start the dictation workflow
                             try {{
-                                window.__TAURI__?.event?.emit("hotkey-triggered", null);
+                                window.__TAURI__?.event?.emit("{HOTKEY_TRIGGERED_EVENT}", null);
                             }} catch (e) {{
                                 console.error("Failed to emit hotkey-triggered event", e);
                             }}
@@ -269,6 +800,8 @@ impl GlobalShortcutManager {
 /// - Real-time settings persistence
 #[component]
 pub fn SettingsPanel() -> impl IntoView {
+    let i18n = I18n::expect();
+
     // Settings state
     let (settings, set_settings) = signal(AppSettings::default());
     let (loading, set_loading) = signal(true);
@@ -279,10 +812,116 @@ pub fn SettingsPanel() -> impl IntoView {
     let (editing_hotkey, set_editing_hotkey) = signal(false);
     let (temp_hotkey, set_temp_hotkey) = signal(String::new());
     let (hotkey_valid, set_hotkey_valid) = signal(true);
+    let (hotkey_error, set_hotkey_error) = signal::<Option<String>>(None);
+
+    // Model catalogue, and a model that is shown but not yet downloaded
+    let (catalogue, set_catalogue) = signal::<Vec<CatalogueModel>>(Vec::new());
+    let (picked_model, set_picked_model) = signal::<Option<ModelId>>(None);
+    let picked_model_id = move || picked_model.get().unwrap_or_else(|| settings.get().model);
+
+    // Installed model storage state
+    let (installed_models, set_installed_models) = signal::<Vec<InstalledModel>>(Vec::new());
+    let (models_disk_usage, set_models_disk_usage) = signal::<Option<ModelsDiskUsage>>(None);
+    let (storage_usage, set_storage_usage) = signal::<Option<StorageUsage>>(None);
+    let (verifying_models, set_verifying_models) = signal(false);
+
+    // Model download in flight and its latest progress
+    let (downloading_model, set_downloading_model) = signal::<Option<String>>(None);
+    let (download_progress, set_download_progress) = signal::<Option<DownloadProgress>>(None);
+    listen_typed_with(
+        MODEL_DOWNLOAD_PROGRESS_EVENT,
+        move |progress: DownloadProgress| {
+            set_download_progress.set(Some(progress));
+        },
+    );
+
+    // Whether downloads can reach the model host
+    let (online, set_online) = signal(true);
+    listen_typed_with(CONNECTIVITY_CHANGED_EVENT, move |is_online: bool| {
+        set_online.set(is_online);
+    });
 
-    // Model availability state
-    let (model_availability, set_model_availability) =
-        signal(std::collections::HashMap::<String, bool>::new());
+    // Follow settings saved elsewhere, e.g. a vocabulary term added from the
+    // history
+    listen_typed_with(SETTINGS_CHANGED_EVENT, move |saved: AppSettings| {
+        set_settings.set(saved);
+    });
+
+    // Saved settings profiles and the name of one being created
+    let (profiles, set_profiles) = signal(SettingsProfiles::default());
+    let (new_profile_name, set_new_profile_name) = signal(String::new());
+
+    // Installed plugins and the path of a plugin being installed
+    let (plugins, set_plugins) = signal::<Vec<PluginInfo>>(Vec::new());
+    let (new_plugin_path, set_new_plugin_path) = signal(String::new());
+
+    // Supported transcription languages
+    let (languages, set_languages) = signal::<Vec<LanguageOption>>(Vec::new());
+
+    // New replacement rule being entered
+    let (new_pattern, set_new_pattern) = signal(String::new());
+    let (new_replacement, set_new_replacement) = signal(String::new());
+
+    // Per-app profile editor state
+    let (new_app_id, set_new_app_id) = signal(String::new());
+
+    // Language hot-key being added
+    let (new_language_shortcut, set_new_language_shortcut) = signal(String::new());
+    let (connected_devices, set_connected_devices) = signal::<Vec<String>>(Vec::new());
+    let (new_input_device, set_new_input_device) = signal(String::new());
+    let (testing_microphone, set_testing_microphone) = signal(false);
+    let (microphone_test, set_microphone_test) = signal::<Option<MicrophoneTest>>(None);
+    let (new_language_hotkey_language, set_new_language_hotkey_language) = signal(String::new());
+
+    // Live level of a microphone while its input gain is adjusted; each
+    // start bumps the run so an earlier polling loop winds down
+    let (previewing_device, set_previewing_device) = signal::<Option<String>>(None);
+    let (preview_level, set_preview_level) = signal::<Option<f32>>(None);
+    let (preview_run, set_preview_run) = signal(0_u32);
+    let start_preview = move |device: String, gain: f32| {
+        set_preview_run.update(|run| *run += 1);
+        let run = preview_run.get_untracked();
+        set_previewing_device.set(Some(device.clone()));
+        spawn_local(async move {
+            if let Err(e) = SettingsManager::start_input_preview(device, gain).await {
+                if preview_run.get_untracked() == run {
+                    set_previewing_device.set(None);
+                }
+                set_error_message.set(Some(e));
+                return;
+            }
+            // Poll the level until the preview is stopped, restarted or ends
+            while preview_run.get_untracked() == run {
+                match SettingsManager::input_preview_level().await {
+                    Ok(Some(dbfs)) if preview_run.get_untracked() == run => {
+                        set_preview_level.set(Some(dbfs));
+                    }
+                    Ok(None) if preview_run.get_untracked() == run => {
+                        set_previewing_device.set(None);
+                        set_preview_level.set(None);
+                        break;
+                    }
+                    _ => {}
+                }
+                gloo_timers::future::TimeoutFuture::new(100).await;
+            }
+        });
+    };
+    let stop_preview = move || {
+        set_preview_run.update(|run| *run += 1);
+        set_previewing_device.set(None);
+        set_preview_level.set(None);
+        spawn_local(async move {
+            if let Err(e) = SettingsManager::stop_input_preview().await {
+                web_sys::console::error_1(&e.into());
+            }
+        });
+    };
+    on_cleanup(move || {
+        if previewing_device.get_untracked().is_some() {
+            stop_preview();
+        }
+    });
 
     // Load settings on mount
     Effect::new(move || {
@@ -291,6 +930,12 @@ pub fn SettingsPanel() -> impl IntoView {
                 Ok(loaded_settings) => {
                     set_settings.set(loaded_settings);
                     set_loading.set(false);
+
+                    // The checkbox shows the login item as the system has it
+                    match SettingsManager::get_auto_launch_status().await {
+                        Ok(enabled) => set_settings.update(|s| s.auto_launch = enabled),
+                        Err(e) => web_sys::console::error_1(&e.into()),
+                    }
                 }
                 Err(e) => {
                     set_error_message.set(Some(format!("Failed to load settings: {e}")));
@@ -300,30 +945,119 @@ pub fn SettingsPanel() -> impl IntoView {
         });
     });
 
-    // Check model availability when settings change
+    // Load connected microphones on mount
     Effect::new(move || {
-        let _current_settings = settings.get();
         spawn_local(async move {
-            // FIXME: Get model details from the backend
-            let model_sizes = vec!["small", "medium", "large"];
-            let mut availability = std::collections::HashMap::new();
-
-            for size in model_sizes {
-                match SettingsManager::check_model_availability(size).await {
-                    Ok(available) => {
-                        availability.insert(size.to_string(), available);
-                    }
-                    Err(_) => {
-                        availability.insert(size.to_string(), false);
-                    }
-                }
+            match SettingsManager::list_input_devices().await {
+                Ok(devices) => set_connected_devices.set(devices),
+                Err(e) => web_sys::console::error_1(&e.into()),
             }
-
-            set_model_availability.set(availability);
         });
     });
 
-    // Save settings function
+    // Load supported languages on mount
+    Effect::new(move || {
+        spawn_local(async move {
+            match SettingsManager::get_supported_languages().await {
+                Ok(list) => set_languages.set(list),
+                Err(e) => {
+                    web_sys::console::error_1(&e.into());
+                }
+            }
+        });
+    });
+
+    // Reload installed models and their disk usage
+    let refresh_installed_models = move |verify_checksums: bool| {
+        spawn_local(async move {
+            set_verifying_models.set(verify_checksums);
+            match SettingsManager::list_installed_models(verify_checksums).await {
+                Ok(list) => set_installed_models.set(list),
+                Err(e) => set_error_message.set(Some(e)),
+            }
+            match SettingsManager::list_model_catalogue().await {
+                Ok(list) => set_catalogue.set(list),
+                Err(e) => web_sys::console::error_1(&e.into()),
+            }
+            match SettingsManager::get_models_disk_usage().await {
+                Ok(usage) => set_models_disk_usage.set(Some(usage)),
+                Err(e) => web_sys::console::error_1(&e.into()),
+            }
+            match SettingsManager::get_storage_usage().await {
+                Ok(usage) => set_storage_usage.set(Some(usage)),
+                Err(e) => web_sys::console::error_1(&e.into()),
+            }
+            set_verifying_models.set(false);
+        });
+    };
+
+    // Load installed models on mount
+    Effect::new(move || refresh_installed_models(false));
+
+    // Download a model, tracking progress until it finishes
+    let download_model = move |filename: String| {
+        set_downloading_model.set(Some(filename.clone()));
+        set_download_progress.set(None);
+        spawn_local(async move {
+            // Refuse up front rather than fail once the disk fills up
+            if let Ok(space) = SettingsManager::check_disk_space_for_model(&filename).await {
+                if !space.is_sufficient() {
+                    set_error_message.set(Some(space.shortfall_message(&filename)));
+                    set_downloading_model.set(None);
+                    return;
+                }
+            }
+            match SettingsManager::download_model(&filename).await {
+                Ok(()) => {
+                    set_success_message.set(Some(format!("Downloaded {filename}")));
+                    refresh_installed_models(false);
+                }
+                Err(e) => set_error_message.set(Some(e)),
+            }
+            set_downloading_model.set(None);
+            set_download_progress.set(None);
+        });
+    };
+
+    // Turn noise suppression on or off, fetching the RNNoise weights if needed
+    let set_denoise = move |enabled: bool| {
+        set_settings.update(|s| s.denoise = enabled);
+        if enabled {
+            spawn_local(async move {
+                if !SettingsManager::check_denoise_model()
+                    .await
+                    .unwrap_or(false)
+                {
+                    download_model(DENOISE_MODEL_FILENAME.to_string());
+                }
+            });
+        }
+    };
+
+    // Reload installed plugins
+    let refresh_plugins = move || {
+        spawn_local(async move {
+            match SettingsManager::list_plugins().await {
+                Ok(list) => set_plugins.set(list),
+                Err(e) => web_sys::console::error_1(&e.into()),
+            }
+        });
+    };
+
+    // Load installed plugins on mount
+    Effect::new(refresh_plugins);
+
+    // Load saved profiles on mount
+    Effect::new(move || {
+        spawn_local(async move {
+            match SettingsManager::list_profiles().await {
+                Ok(list) => set_profiles.set(list),
+                Err(e) => web_sys::console::error_1(&e.into()),
+            }
+        });
+    });
+
+    // Save settings function
     let save_settings = move || {
         spawn_local(async move {
             // Add debug logging
@@ -359,6 +1093,8 @@ pub fn SettingsPanel() -> impl IntoView {
     // Start editing hot-key
     let start_editing_hotkey = move || {
         set_temp_hotkey.set(settings.get().hot_key.clone());
+        set_hotkey_valid.set(true);
+        set_hotkey_error.set(None);
         set_editing_hotkey.set(true);
         set_error_message.set(None);
 
@@ -374,6 +1110,7 @@ pub fn SettingsPanel() -> impl IntoView {
         set_editing_hotkey.set(false);
         set_temp_hotkey.set(String::new());
         set_hotkey_valid.set(true);
+        set_hotkey_error.set(None);
 
         // Re-register the original shortcut
         let original_hotkey = settings.get().hot_key.clone();
@@ -382,6 +1119,25 @@ pub fn SettingsPanel() -> impl IntoView {
         });
     };
 
+    // Validate each recorded combination; Save stays disabled until it passes
+    let capture_hotkey = move |hot_key: String| {
+        set_temp_hotkey.set(hot_key.clone());
+        set_hotkey_valid.set(false);
+        set_hotkey_error.set(None);
+
+        spawn_local(async move {
+            let result = SettingsManager::validate_hot_key(&hot_key).await;
+            // Ignore results for a combination that has since been replaced
+            if temp_hotkey.get_untracked() != hot_key {
+                return;
+            }
+            match result {
+                Ok(()) => set_hotkey_valid.set(true),
+                Err(e) => set_hotkey_error.set(Some(e)),
+            }
+        });
+    };
+
     // Save hot-key changes
     let save_hotkey = move || {
         let new_hotkey = temp_hotkey.get();
@@ -431,14 +1187,14 @@ pub fn SettingsPanel() -> impl IntoView {
     };
 
     view! {
-        <div class="settings-panel">
+        <section class="settings-panel" aria-labelledby="settings-title">
             <div class="settings-header">
-                <h2>"Settings"</h2>
-                <p class="setting-description">"Configure Speakr for your perfect dictation experience"</p>
+                <h2 id="settings-title">{move || i18n.t("settings.title")}</h2>
+                <p class="setting-description">{move || i18n.t("settings.description")}</p>
                 {move || loading.get().then(|| view! {
-                    <div class="loading-indicator">
+                    <div class="loading-indicator" role="status">
                         <div class="spinner"></div>
-                        <span>"Loading settings..."</span>
+                        <span>{move || i18n.t("settings.loading")}</span>
                     </div>
                 })}
             </div>
@@ -446,15 +1202,15 @@ pub fn SettingsPanel() -> impl IntoView {
             {move || {
                 if let Some(error) = error_message.get() {
                     view! {
-                        <div class="error-message">
-                            <span>"⚠️"</span>
+                        <div class="error-message" role="alert">
+                            <span aria-hidden="true">"⚠️"</span>
                             <span>{error}</span>
                         </div>
                     }.into_any()
                 } else if let Some(success) = success_message.get() {
                     view! {
-                        <div class="success-message">
-                            <span>"✅"</span>
+                        <div class="success-message" role="status">
+                            <span aria-hidden="true">"✅"</span>
                             <span>{success}</span>
                         </div>
                     }.into_any()
@@ -464,9 +1220,107 @@ pub fn SettingsPanel() -> impl IntoView {
             }}
 
             <div class="settings-content">
+                // Profiles Section
+                <div class="setting-group" role="group" aria-labelledby="settings-profiles">
+                    <h3 id="settings-profiles">"🗂️ " {move || i18n.t("settings.section.profiles")}</h3>
+                    <p class="setting-description">
+                        "Keep a hot-key, model and vocabulary for each situation, such as work or meetings, and switch between them here or from the menu bar."
+                    </p>
+
+                    <div class="settings-profiles">
+                        {move || {
+                            let saved = profiles.get();
+                            if saved.profiles.is_empty() {
+                                return view! {
+                                    <p class="checkbox-help">"No profiles yet. Save the current settings to create one."</p>
+                                }.into_any();
+                            }
+
+                            let active = saved.active.clone().unwrap_or_default();
+                            saved.profiles.into_iter().map(|profile| {
+                                let is_active = profile.name.eq_ignore_ascii_case(&active);
+                                let switch_name = profile.name.clone();
+                                let delete_name = profile.name.clone();
+                                view! {
+                                    <div class="settings-profile" class:active=is_active>
+                                        <div class="checkbox-content">
+                                            <span class="checkbox-label-text">{profile.name}</span>
+                                            <span class="checkbox-help">
+                                                {format!("{} · {} model", profile.hot_key, profile.model)}
+                                            </span>
+                                        </div>
+                                        <button
+                                            class="btn-secondary"
+                                            disabled=is_active
+                                            on:click=move |_| {
+                                                let name = switch_name.clone();
+                                                spawn_local(async move {
+                                                    match SettingsManager::switch_profile(&name).await {
+                                                        Ok(switched) => {
+                                                            set_settings.set(switched);
+                                                            set_profiles.update(|p| p.active = Some(name.clone()));
+                                                            set_success_message.set(Some(format!("Switched to {name}")));
+                                                        }
+                                                        Err(e) => set_error_message.set(Some(e)),
+                                                    }
+                                                });
+                                            }
+                                        >
+                                            {if is_active { "Active" } else { "Switch" }}
+                                        </button>
+                                        <button
+                                            class="btn-secondary btn-danger"
+                                            on:click=move |_| {
+                                                let name = delete_name.clone();
+                                                spawn_local(async move {
+                                                    match SettingsManager::delete_profile(&name).await {
+                                                        Ok(list) => set_profiles.set(list),
+                                                        Err(e) => set_error_message.set(Some(e)),
+                                                    }
+                                                });
+                                            }
+                                        >
+                                            "Delete"
+                                        </button>
+                                    </div>
+                                }
+                            }).collect::<Vec<_>>().into_any()
+                        }}
+                    </div>
+
+                    <div class="replacement-rule-editor">
+                        <input
+                            type="text"
+                            class="rule-input"
+                            placeholder="Profile name, e.g. Meetings"
+                            prop:value=move || new_profile_name.get()
+                            on:input=move |e| set_new_profile_name.set(event_target_value(&e))
+                        />
+                        <button
+                            class="btn-primary"
+                            disabled=move || new_profile_name.get().trim().is_empty()
+                            on:click=move |_| {
+                                let name = new_profile_name.get().trim().to_string();
+                                spawn_local(async move {
+                                    match SettingsManager::save_profile(&name).await {
+                                        Ok(list) => {
+                                            set_new_profile_name.set(String::new());
+                                            set_profiles.set(list);
+                                            set_success_message.set(Some(format!("Saved profile {name}")));
+                                        }
+                                        Err(e) => set_error_message.set(Some(e)),
+                                    }
+                                });
+                            }
+                        >
+                            "Save Current"
+                        </button>
+                    </div>
+                </div>
+
                 // Hot-key Configuration Section
-                <div class="setting-group">
-                    <h3>"⌨️ Global Hot-key"</h3>
+                <div class="setting-group" role="group" aria-labelledby="settings-hot-key">
+                    <h3 id="settings-hot-key">"⌨️ " {move || i18n.t("settings.section.hot_key")}</h3>
                     <p class="setting-description">
                         "Keyboard shortcut to activate Speakr from anywhere on your system. Press this combination to start dictating."
                     </p>
@@ -476,21 +1330,23 @@ pub fn SettingsPanel() -> impl IntoView {
                             if editing_hotkey.get() {
                                 view! {
                                     <div class="hotkey-editor">
-                                        <input
-                                            type="text"
-                                            class={move || format!("hotkey-input {}", if hotkey_valid.get() { "" } else { "invalid" })}
-                                            placeholder="e.g., CmdOrCtrl+Alt+Space"
-                                            prop:value={move || temp_hotkey.get()}
-                                            on:input=move |e| {
-                                                set_temp_hotkey.set(event_target_value(&e));
-                                                set_hotkey_valid.set(true);
-                                            }
+                                        <HotkeyCapture
+                                            value=temp_hotkey
+                                            invalid=Signal::derive(move || hotkey_error.get().is_some())
+                                            on_capture=Callback::new(capture_hotkey)
+                                            autofocus=true
                                         />
+                                        <p class="checkbox-help">
+                                            {move || hotkey_error.get().unwrap_or_else(|| {
+                                                "Click the field, then press the new combination.".to_string()
+                                            })}
+                                        </p>
+                                        <HotkeySuggestions on_pick=Callback::new(capture_hotkey) />
                                         <div class="hotkey-actions">
                                             <button
                                                 class="btn-primary"
                                                 on:click=move |_| save_hotkey()
-                                                disabled={move || temp_hotkey.get().is_empty()}
+                                                disabled={move || temp_hotkey.get().is_empty() || !hotkey_valid.get()}
                                             >
                                                 "💾 Save"
                                             </button>
@@ -520,65 +1376,352 @@ pub fn SettingsPanel() -> impl IntoView {
                             }
                         }}
                     </div>
+
+                    <HotkeyStatusPanel />
                 </div>
 
                 // Model Selection Section
-                <div class="setting-group">
-                    <h3>"🧠 Transcription Model"</h3>
+                <div class="setting-group" role="group" aria-labelledby="settings-model">
+                    <h3 id="settings-model">"🧠 " {move || i18n.t("settings.section.model")}</h3>
                     <p class="setting-description">
-                        "Choose the Whisper model size based on your accuracy and speed preferences. Larger models are more accurate but require more resources."
+                        "Choose any model from the Whisper catalogue. Larger models are more accurate but need more memory and time. Quantised models are smaller and faster at a small cost in accuracy; English-only models are more accurate for English."
                     </p>
 
-                    <div class="model-options">
-                        {move || {
-                            let model_sizes = vec![ModelSize::Small, ModelSize::Medium, ModelSize::Large];
-                            let current_model = &settings.get().model_size;
-                            let availability = model_availability.get();
-
-                            model_sizes.into_iter().map(|model| {
-                                let model_key = model.to_string_value();
-                                let is_selected = current_model == model_key;
-                                let is_available = availability.get(model_key).unwrap_or(&false);
-
-                                let (icon, description) = match model {
-                                    ModelSize::Small => ("⚡", "Fast processing, good for quick notes"),
-                                    ModelSize::Medium => ("⚖️", "Balanced accuracy and speed"),
-                                    ModelSize::Large => ("🎯", "Highest accuracy, best for professional use"),
-                                };
+                    <div class="model-picker">
+                        <select
+                            id="model"
+                            aria-label="Transcription model"
+                            aria-describedby="model-details"
+                            on:change=move |e| {
+                                let model = ModelId::new(event_target_select_value(&e));
+                                let installed = catalogue
+                                    .get_untracked()
+                                    .iter()
+                                    .any(|entry| entry.id == model && entry.installed);
+                                if installed {
+                                    set_picked_model.set(None);
+                                    set_settings.update(|s| s.model = model);
+                                    save_settings();
+                                } else {
+                                    set_picked_model.set(Some(model));
+                                }
+                            }
+                        >
+                            {move || {
+                                let picked = picked_model_id();
+                                group_by_family(&catalogue.get()).into_iter().map(|(family, models)| view! {
+                                    <optgroup label=family>
+                                        {models.into_iter().map(|model| {
+                                            let selected = model.id == picked;
+                                            view! {
+                                                <option value=model.id.to_string() selected=selected>
+                                                    {model_option_label(&model)}
+                                                </option>
+                                            }
+                                        }).collect::<Vec<_>>()}
+                                    </optgroup>
+                                }).collect::<Vec<_>>()
+                            }}
+                        </select>
 
+                        {move || {
+                            let picked = picked_model_id();
+                            let current = settings.get().model;
+                            catalogue.get().into_iter().find(|model| model.id == picked).map(|model| {
+                                let is_current = model.id == current;
+                                let download_filename = model.id.filename();
+                                let use_model = model.id.clone();
                                 view! {
-                                    <div class={format!("model-option {} {}",
-                                        if is_selected { "selected" } else { "" },
-                                        if *is_available { "available" } else { "unavailable" }
-                                    )}>
-                                        <input
-                                            type="radio"
-                                            name="model_size"
-                                            id={format!("model_{model_key}")}
-                                            value={model_key}
-                                            checked=is_selected
-                                            disabled={!is_available}
-                                            on:change=move |_| {
-                                                set_settings.update(|s| s.model_size = model_key.to_string());
-                                                save_settings();
-                                            }
-                                        />
-                                        <label for={format!("model_{model_key}")} class="model-label">
+                                    <div
+                                        id="model-details"
+                                        class={format!("model-option selected {}",
+                                            if model.installed { "available" } else { "unavailable" }
+                                        )}
+                                        aria-live="polite"
+                                    >
+                                        <div class="model-label">
                                             <div class="model-info">
                                                 <div class="model-header">
-                                                    <span class="model-icon">{icon}</span>
-                                                    <div class="model-name">{model.display_name()}</div>
+                                                    <div class="model-name">{model.id.to_string()}</div>
+                                                </div>
+                                                <div class="model-description">
+                                                    {format!(
+                                                        "{} · {} MB download · about {} MB of memory",
+                                                        model_variant(&model.id),
+                                                        model.size_mb(),
+                                                        model.memory_mb
+                                                    )}
+                                                </div>
+                                                <div class="model-description" title=model.sha.clone()>
+                                                    {format!("SHA {}", short_sha(&model.sha))}
                                                 </div>
-                                                <div class="model-description">{description}</div>
                                             </div>
                                             <div class="model-status">
-                                                {if *is_available {
+                                                {if is_current {
+                                                    "✅ In use"
+                                                } else if model.installed {
                                                     "✅ Available"
                                                 } else {
                                                     "❌ Not Downloaded"
                                                 }}
                                             </div>
-                                        </label>
+                                        </div>
+                                        {(model.installed && !is_current).then(|| view! {
+                                            <button
+                                                class="btn-primary"
+                                                on:click=move |_| {
+                                                    set_picked_model.set(None);
+                                                    set_settings.update(|s| s.model = use_model.clone());
+                                                    save_settings();
+                                                }
+                                            >
+                                                "Use this model"
+                                            </button>
+                                        })}
+                                        {(!model.installed).then(|| view! {
+                                            <div class="model-download">
+                                                {move || {
+                                                    let filename = download_filename.clone();
+                                                    let is_downloading = downloading_model.get().as_deref() == Some(filename.as_str());
+                                                    if is_downloading && !online.get() {
+                                                        view! {
+                                                            <span class="download-progress download-offline" role="status">
+                                                                {OFFLINE_DOWNLOAD_MESSAGE}
+                                                            </span>
+                                                        }.into_any()
+                                                    } else if is_downloading {
+                                                        let status = download_progress
+                                                            .get()
+                                                            .filter(|progress| progress.filename == filename)
+                                                            .map(|progress| progress.summary())
+                                                            .unwrap_or_else(|| "Starting download…".to_string());
+                                                        view! {
+                                                            <span class="download-progress" role="status" aria-live="polite">{status}</span>
+                                                        }.into_any()
+                                                    } else {
+                                                        view! {
+                                                            <button
+                                                                class="btn-secondary"
+                                                                disabled=move || downloading_model.get().is_some()
+                                                                on:click=move |_| download_model(filename.clone())
+                                                            >
+                                                                "Download"
+                                                            </button>
+                                                        }.into_any()
+                                                    }
+                                                }}
+                                            </div>
+                                        })}
+                                    </div>
+                                }
+                            })
+                        }}
+                    </div>
+
+                    <div class="model-idle-unload">
+                        <label for="model_idle_unload">"Free memory when idle"</label>
+                        <select
+                            id="model_idle_unload"
+                            on:change=move |e| {
+                                let value = event_target_select_value(&e).parse::<u32>().ok();
+                                set_settings.update(|s| s.model_idle_unload_mins = value);
+                                save_settings();
+                            }
+                        >
+                            {move || {
+                                let current = settings.get().model_idle_unload_mins;
+                                MODEL_IDLE_UNLOAD_CHOICES.iter().map(|&(mins, label)| {
+                                    let value = mins.map(|m| m.to_string()).unwrap_or_else(|| "never".to_string());
+                                    view! {
+                                        <option value=value selected={current == mins}>{label}</option>
+                                    }
+                                }).collect::<Vec<_>>()
+                            }}
+                        </select>
+                        <p class="checkbox-help">
+                            "The model stays loaded between dictations so they start instantly. Unloading it frees memory; the next dictation then takes a few seconds longer."
+                        </p>
+                    </div>
+                </div>
+
+                // Model Storage Section
+                <div class="setting-group" role="group" aria-labelledby="settings-model-storage">
+                    <h3 id="settings-model-storage">"💾 " {move || i18n.t("settings.section.model_storage")}</h3>
+                    <Show when=move || !online.get()>
+                        <p class="offline-notice">{OFFLINE_DOWNLOAD_MESSAGE}</p>
+                    </Show>
+                    <p class="setting-description">
+                        {move || match models_disk_usage.get() {
+                            Some(usage) => format!(
+                                "{} model file(s) using {} MB in {}",
+                                usage.model_count,
+                                usage.total_bytes / (1024 * 1024),
+                                usage.directory
+                            ),
+                            None => "Models installed on this computer.".to_string(),
+                        }}
+                    </p>
+
+                    <div class="installed-models">
+                        {move || {
+                            let models = installed_models.get();
+                            if models.is_empty() {
+                                return view! {
+                                    <p class="checkbox-help">"No models installed yet."</p>
+                                }.into_any();
+                            }
+
+                            models.into_iter().map(|model| {
+                                let size_mb = model.size_mb();
+                                let (checksum_class, checksum_label) = match model.checksum_valid {
+                                    Some(true) => ("checksum-valid", "✅ Verified"),
+                                    Some(false) => ("checksum-invalid", "⚠️ Checksum mismatch"),
+                                    None => ("checksum-unknown", "Not verified"),
+                                };
+                                let upstream_changed = model.upstream_changed();
+                                let catalogue_label = model.source.as_ref().map_or_else(
+                                    || "Catalogue unknown".to_string(),
+                                    |source| format!("Catalogue {}", source.short_ref()),
+                                );
+                                let catalogue_title = model
+                                    .source
+                                    .as_ref()
+                                    .map(|source| source.git_ref.clone())
+                                    .unwrap_or_default();
+                                let filename = model.filename.clone();
+                                let redownload_filename = model.filename.clone();
+
+                                view! {
+                                    <div class="installed-model">
+                                        <code>{model.filename}</code>
+                                        <span class="installed-model-size">{format!("{size_mb} MB")}</span>
+                                        <span class={format!("installed-model-checksum {checksum_class}")}>
+                                            {checksum_label}
+                                        </span>
+                                        <span class="installed-model-catalogue" title=catalogue_title>
+                                            {catalogue_label}
+                                        </span>
+                                        {upstream_changed.then(|| view! {
+                                            <span
+                                                class="installed-model-upstream"
+                                                title="The model catalogue now publishes a different file under this name"
+                                            >
+                                                "⚠️ Updated upstream"
+                                            </span>
+                                            <button
+                                                class="btn-secondary"
+                                                on:click=move |_| {
+                                                    let filename = redownload_filename.clone();
+                                                    spawn_local(async move {
+                                                        match SettingsManager::download_model(&filename).await {
+                                                            Ok(()) => {
+                                                                set_success_message.set(Some(format!("Downloaded {filename} again")));
+                                                                refresh_installed_models(false);
+                                                            }
+                                                            Err(e) => set_error_message.set(Some(e)),
+                                                        }
+                                                    });
+                                                }
+                                            >
+                                                "Re-download"
+                                            </button>
+                                        })}
+                                        <button
+                                            class="btn-secondary btn-danger"
+                                            on:click=move |_| {
+                                                let filename = filename.clone();
+                                                let confirmed = web_sys::window()
+                                                    .and_then(|w| {
+                                                        w.confirm_with_message(&format!("Delete {filename}?")).ok()
+                                                    })
+                                                    .unwrap_or(false);
+                                                if !confirmed {
+                                                    return;
+                                                }
+                                                spawn_local(async move {
+                                                    match SettingsManager::delete_model(&filename).await {
+                                                        Ok(()) => {
+                                                            set_success_message.set(Some(format!("Deleted {filename}")));
+                                                            refresh_installed_models(false);
+                                                        }
+                                                        Err(e) => set_error_message.set(Some(e)),
+                                                    }
+                                                });
+                                            }
+                                        >
+                                            "Delete"
+                                        </button>
+                                    </div>
+                                }
+                            }).collect::<Vec<_>>().into_any()
+                        }}
+                    </div>
+
+                    <button
+                        class="btn-secondary"
+                        disabled=move || verifying_models.get() || installed_models.get().is_empty()
+                        on:click=move |_| refresh_installed_models(true)
+                    >
+                        {move || if verifying_models.get() { "Verifying…" } else { "Verify Checksums" }}
+                    </button>
+                </div>
+
+                // Storage Usage Section
+                <div class="setting-group" role="group" aria-labelledby="settings-storage-usage">
+                    <h3 id="settings-storage-usage">"📊 " {move || i18n.t("settings.section.storage_usage")}</h3>
+                    <p class="setting-description">
+                        {move || match storage_usage.get() {
+                            Some(usage) => format!(
+                                "Speakr is using {} MB of disk space.",
+                                usage.total_bytes() / (1024 * 1024)
+                            ),
+                            None => "Disk space used by Speakr on this computer.".to_string(),
+                        }}
+                    </p>
+
+                    <div class="storage-usage">
+                        {move || {
+                            storage_usage.get().unwrap_or_default().categories.into_iter().map(|usage| {
+                                let category = usage.category;
+                                view! {
+                                    <div class="storage-category" title=usage.directory>
+                                        <span class="storage-category-name">{category.display_name()}</span>
+                                        <span class="storage-category-size">
+                                            {format!(
+                                                "{} MB · {} file(s)",
+                                                usage.total_bytes / (1024 * 1024),
+                                                usage.file_count
+                                            )}
+                                        </span>
+                                        {category.cleanup_label().map(|label| view! {
+                                            <button
+                                                class="btn-secondary btn-danger"
+                                                disabled=usage.file_count == 0
+                                                on:click=move |_| {
+                                                    let confirmed = web_sys::window()
+                                                        .and_then(|w| w.confirm_with_message(&format!("{label}?")).ok())
+                                                        .unwrap_or(false);
+                                                    if !confirmed {
+                                                        return;
+                                                    }
+                                                    spawn_local(async move {
+                                                        match SettingsManager::clean_up_storage(category).await {
+                                                            Ok(usage) => {
+                                                                set_storage_usage.set(Some(usage));
+                                                                set_success_message.set(Some(format!(
+                                                                    "Cleaned up {}",
+                                                                    category.display_name().to_lowercase()
+                                                                )));
+                                                                refresh_installed_models(false);
+                                                            }
+                                                            Err(e) => set_error_message.set(Some(e)),
+                                                        }
+                                                    });
+                                                }
+                                            >
+                                                {label}
+                                            </button>
+                                        })}
                                     </div>
                                 }
                             }).collect::<Vec<_>>()
@@ -586,77 +1729,1854 @@ pub fn SettingsPanel() -> impl IntoView {
                     </div>
                 </div>
 
-                // Auto-launch Section
-                <div class="setting-group">
-                    <h3>"🚀 Auto-launch"</h3>
+                // Language Section
+                <div class="setting-group" role="group" aria-labelledby="settings-language">
+                    <h3 id="settings-language">"🌐 " {move || i18n.t("settings.section.language")}</h3>
                     <p class="setting-description">
-                        "Automatically start Speakr when you log in to your computer, so it's always ready when you need it."
+                        "The language you dictate in. Auto-detect works well for most people; pick a language if detection guesses wrong."
+                    </p>
+
+                    <select
+                        class="language-select"
+                        on:change=move |e| {
+                            let value = event_target_select_value(&e);
+                            set_settings.update(|s| s.language = value);
+                            save_settings();
+                        }
+                    >
+                        <option
+                            value=DEFAULT_LANGUAGE
+                            selected={move || settings.get().language == DEFAULT_LANGUAGE}
+                        >
+                            "Auto-detect"
+                        </option>
+                        {move || {
+                            let current = settings.get().language;
+                            languages.get().into_iter().map(|lang| {
+                                let is_selected = lang.code == current;
+                                view! {
+                                    <option value={lang.code.clone()} selected=is_selected>
+                                        {lang.name}
+                                    </option>
+                                }
+                            }).collect::<Vec<_>>()
+                        }}
+                    </select>
+                </div>
+
+                // Interface Language Section
+                <div class="setting-group" role="group" aria-labelledby="settings-ui-language">
+                    <h3 id="settings-ui-language">"🈯 " {move || i18n.t("settings.section.ui_language")}</h3>
+                    <p class="setting-description">
+                        {move || i18n.t("settings.ui_language.description")}
                     </p>
 
+                    <select
+                        class="language-select"
+                        on:change=move |e| {
+                            let choice = UiLanguage::from_code(&event_target_select_value(&e));
+                            if let Some(language) = choice {
+                                set_settings.update(|s| s.ui_language = language);
+                                save_settings();
+                            }
+                        }
+                    >
+                        <option
+                            value=UiLanguage::System.code()
+                            selected={move || settings.get().ui_language == UiLanguage::System}
+                        >
+                            {move || i18n.t("settings.ui_language.system")}
+                        </option>
+                        {UiLanguage::TRANSLATED.into_iter().map(|language| view! {
+                            <option
+                                value=language.code()
+                                selected={move || settings.get().ui_language == language}
+                            >
+                                {language.native_name()}
+                            </option>
+                        }).collect::<Vec<_>>()}
+                    </select>
+                </div>
+
+                // Appearance Section
+                <div class="setting-group" role="group" aria-labelledby="settings-theme">
+                    <h3 id="settings-theme">"🌓 " {move || i18n.t("settings.section.theme")}</h3>
+                    <p class="setting-description">
+                        {move || i18n.t("settings.theme.description")}
+                    </p>
+
+                    <select
+                        class="language-select"
+                        aria-labelledby="settings-theme"
+                        on:change=move |e| {
+                            if let Some(theme) = Theme::from_code(&event_target_select_value(&e)) {
+                                set_settings.update(|s| s.theme = theme);
+                                save_settings();
+                            }
+                        }
+                    >
+                        {Theme::ALL.into_iter().map(|theme| view! {
+                            <option value=theme.code() selected={move || settings.get().theme == theme}>
+                                {move || i18n.t(&format!("settings.theme.{}", theme.code()))}
+                            </option>
+                        }).collect::<Vec<_>>()}
+                    </select>
+
                     <label class="checkbox-label">
                         <input
                             type="checkbox"
-                            class="auto-launch-checkbox"
-                            checked={move || settings.get().auto_launch}
+                            checked={move || settings.get().reduce_motion}
                             on:change=move |e| {
                                 let enabled = event_target_checked(&e);
-                                set_settings.update(|s| s.auto_launch = enabled);
-
-                                spawn_local(async move {
-                                    match SettingsManager::set_auto_launch(enabled).await {
-                                        Ok(_) => save_settings(),
-                                        Err(e) => {
-                                            set_error_message.set(Some(format!("Failed to update auto-launch: {e}")));
-                                        }
-                                    }
-                                });
+                                set_settings.update(|s| s.reduce_motion = enabled);
+                                save_settings();
                             }
                         />
                         <div class="checkbox-content">
-                            <span class="checkbox-label-text">"Start Speakr on login"</span>
-                            <span class="checkbox-help">"Recommended for seamless workflow integration"</span>
+                            <span class="checkbox-label-text">"Reduce motion"</span>
+                            <span class="checkbox-help">
+                                "Turn off animations and transitions. They are also off when reduced motion is on in the system's accessibility settings."
+                            </span>
                         </div>
                     </label>
                 </div>
 
-                // Quick Tips Section
-                <div class="setting-group">
-                    <h3>"💡 Quick Tips"</h3>
-                    <div class="tips-list">
-                        <div class="tip-item">
-                            <span class="tip-icon">"🎙️"</span>
-                            <div class="tip-content">
-                                <div class="tip-title">"Clear Audio"</div>
-                                <div class="tip-description">"Speak clearly and reduce background noise for better accuracy"</div>
-                            </div>
-                        </div>
-                        <div class="tip-item">
-                            <span class="tip-icon">"⏸️"</span>
-                            <div class="tip-content">
-                                <div class="tip-title">"Natural Pauses"</div>
-                                <div class="tip-description">"Pause briefly between sentences for better punctuation"</div>
-                            </div>
-                        </div>
-                        <div class="tip-item">
-                            <span class="tip-icon">"🔒"</span>
-                            <div class="tip-content">
-                                <div class="tip-title">"Privacy First"</div>
-                                <div class="tip-description">"All processing happens locally - your voice never leaves your device"</div>
-                            </div>
+                // Language Hot-keys Section
+                <div class="setting-group" role="group" aria-labelledby="settings-language-hotkeys">
+                    <h3 id="settings-language-hotkeys">"🗣️ " {move || i18n.t("settings.section.language_hotkeys")}</h3>
+                    <p class="setting-description">
+                        "Extra hot-keys that start a dictation in a particular language, for when you switch between languages. The main hot-key keeps the language above."
+                    </p>
+
+                    <div class="language-hotkeys">
+                        {move || {
+                            let names = languages.get();
+                            settings.get().language_hotkeys.into_iter().enumerate().map(|(index, binding)| {
+                                let language = names
+                                    .iter()
+                                    .find(|lang| lang.code == binding.language)
+                                    .map(|lang| lang.name.clone())
+                                    .unwrap_or(binding.language);
+                                view! {
+                                    <div class="language-hotkey">
+                                        <code>{binding.shortcut}</code>
+                                        <span>{language}</span>
+                                        <button
+                                            class="btn-secondary"
+                                            on:click=move |_| {
+                                                set_settings.update(|s| {
+                                                    s.language_hotkeys.remove(index);
+                                                });
+                                                save_settings();
+                                            }
+                                        >
+                                            "Remove"
+                                        </button>
+                                    </div>
+                                }
+                            }).collect::<Vec<_>>()
+                        }}
+
+                        <div class="replacement-rule-editor">
+                            <HotkeyCapture
+                                value=new_language_shortcut
+                                invalid=Signal::derive(|| false)
+                                on_capture=Callback::new(move |shortcut: String| set_new_language_shortcut.set(shortcut))
+                            />
+                            <select
+                                class="language-select"
+                                on:change=move |e| set_new_language_hotkey_language.set(event_target_select_value(&e))
+                            >
+                                <option value="" selected={move || new_language_hotkey_language.get().is_empty()}>
+                                    "Choose a language"
+                                </option>
+                                {move || {
+                                    let current = new_language_hotkey_language.get();
+                                    languages.get().into_iter().map(|lang| {
+                                        let is_selected = lang.code == current;
+                                        view! {
+                                            <option value={lang.code.clone()} selected=is_selected>
+                                                {lang.name}
+                                            </option>
+                                        }
+                                    }).collect::<Vec<_>>()
+                                }}
+                            </select>
+                            <button
+                                class="btn-primary"
+                                disabled=move || {
+                                    new_language_shortcut.get().is_empty()
+                                        || new_language_hotkey_language.get().is_empty()
+                                }
+                                on:click=move |_| {
+                                    let mut added = false;
+                                    set_settings.update(|s| {
+                                        added = add_language_hotkey(
+                                            s,
+                                            &new_language_shortcut.get(),
+                                            &new_language_hotkey_language.get(),
+                                        );
+                                    });
+                                    if added {
+                                        set_new_language_shortcut.set(String::new());
+                                        set_new_language_hotkey_language.set(String::new());
+                                        save_settings();
+                                    } else {
+                                        set_error_message.set(Some("That hot-key is already in use.".to_string()));
+                                    }
+                                }
+                            >
+                                "Add Hot-key"
+                            </button>
                         </div>
                     </div>
                 </div>
-            </div>
-        </div>
-    }
-}
 
-// Helper functions for event handling
-fn event_target_value(event: &web_sys::Event) -> String {
-    event
-        .target()
-        .unwrap()
-        .dyn_into::<web_sys::HtmlInputElement>()
+                // Vocabulary Section
+                <div class="setting-group" role="group" aria-labelledby="settings-vocabulary">
+                    <h3 id="settings-vocabulary">"📖 " {move || i18n.t("settings.section.vocabulary")}</h3>
+                    <p class="setting-description">
+                        "Names, jargon and acronyms you use often. They are given to the model as context so it spells them correctly."
+                    </p>
+
+                    <TermsEditor
+                        terms=Signal::derive(move || settings.get().vocabulary)
+                        on_change=Callback::new(move |terms: Vec<String>| {
+                            set_settings.update(|s| s.vocabulary = terms);
+                            save_settings();
+                        })
+                    />
+
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={move || settings.get().vocabulary_suggestions}
+                            on:change=move |e| {
+                                let enabled = event_target_checked(&e);
+                                set_settings.update(|s| s.vocabulary_suggestions = enabled);
+                                save_settings();
+                            }
+                        />
+                        <div class="checkbox-content">
+                            <span class="checkbox-label-text">"Suggest terms from my corrections"</span>
+                            <span class="checkbox-help">"Words you correct more than once in the history are offered as vocabulary terms"</span>
+                        </div>
+                    </label>
+                </div>
+
+                // Text Clean-up Section
+                <div class="setting-group" role="group" aria-labelledby="settings-text-cleanup">
+                    <h3 id="settings-text-cleanup">"✍️ " {move || i18n.t("settings.section.text_cleanup")}</h3>
+                    <p class="setting-description">
+                        "Tidy up transcribed text before it is typed. Replacements and rules run in the order listed."
+                    </p>
+
+                    {[
+                        (TextTransform::TrimWhitespace, "Trim extra whitespace"),
+                        (TextTransform::default_filler_words(), "Remove filler words (um, uh, …)"),
+                        (TextTransform::CapitaliseSentences, "Capitalise sentences"),
+                    ].into_iter().map(|(transform, label)| {
+                        let toggled = transform.clone();
+                        view! {
+                            <label class="checkbox-label">
+                                <input
+                                    type="checkbox"
+                                    checked={move || has_transform(&settings.get().post_processing, &transform)}
+                                    on:change=move |e| {
+                                        let enabled = event_target_checked(&e);
+                                        set_settings.update(|s| {
+                                            set_transform_enabled(&mut s.post_processing, &toggled, enabled)
+                                        });
+                                        save_settings();
+                                    }
+                                />
+                                <div class="checkbox-content">
+                                    <span class="checkbox-label-text">{label}</span>
+                                </div>
+                            </label>
+                        }
+                    }).collect::<Vec<_>>()}
+
+                    <div class="replacement-rules">
+                        {move || {
+                            settings.get().post_processing.into_iter().enumerate().filter_map(|(index, transform)| {
+                                match transform {
+                                    TextTransform::RegexReplace { pattern, replacement } => Some(view! {
+                                        <div class="replacement-rule">
+                                            <code class="rule-pattern">{pattern}</code>
+                                            " → "
+                                            <code class="rule-replacement">{replacement}</code>
+                                            <button
+                                                class="btn-secondary"
+                                                on:click=move |_| {
+                                                    set_settings.update(|s| {
+                                                        s.post_processing.remove(index);
+                                                    });
+                                                    save_settings();
+                                                }
+                                            >
+                                                "Remove"
+                                            </button>
+                                        </div>
+                                    }),
+                                    _ => None,
+                                }
+                            }).collect::<Vec<_>>()
+                        }}
+
+                        <div class="replacement-rule-editor">
+                            <input
+                                type="text"
+                                class="rule-input"
+                                placeholder="Pattern, e.g. \\bteh\\b"
+                                prop:value=move || new_pattern.get()
+                                on:input=move |e| set_new_pattern.set(event_target_value(&e))
+                            />
+                            <input
+                                type="text"
+                                class="rule-input"
+                                placeholder="Replacement"
+                                prop:value=move || new_replacement.get()
+                                on:input=move |e| set_new_replacement.set(event_target_value(&e))
+                            />
+                            <button
+                                class="btn-primary"
+                                disabled=move || new_pattern.get().is_empty()
+                                on:click=move |_| {
+                                    let rule = TextTransform::RegexReplace {
+                                        pattern: new_pattern.get(),
+                                        replacement: new_replacement.get(),
+                                    };
+                                    set_settings.update(|s| add_replacement_rule(&mut s.post_processing, rule));
+                                    set_new_pattern.set(String::new());
+                                    set_new_replacement.set(String::new());
+                                    save_settings();
+                                }
+                            >
+                                "Add Rule"
+                            </button>
+                        </div>
+                    </div>
+
+                    <p class="setting-description">
+                        "Replacements swap words or phrases for exact text, e.g. \"em dash\" → —. Type \\n for a line break. Case is ignored unless Aa is ticked."
+                    </p>
+                    <ReplacementEditor
+                        chain=Signal::derive(move || settings.get().post_processing)
+                        on_change=Callback::new(move |chain: Vec<TextTransform>| {
+                            set_settings.update(|s| s.post_processing = chain);
+                            save_settings();
+                        })
+                    />
+                </div>
+
+                // Output Section
+                <div class="setting-group" role="group" aria-labelledby="settings-output">
+                    <h3 id="settings-output">"📋 " {move || i18n.t("settings.section.output")}</h3>
+                    <p class="setting-description">
+                        "Choose what happens with dictated text. Copying to the clipboard never types anything, so you decide where to paste."
+                    </p>
+
+                    <select
+                        on:change=move |e| {
+                            let mode = match event_target_select_value(&e).as_str() {
+                                "clipboard" => OutputMode::Clipboard,
+                                "both" => OutputMode::Both,
+                                _ => OutputMode::Inject,
+                            };
+                            set_settings.update(|s| s.output_mode = mode);
+                            save_settings();
+                        }
+                    >
+                        <option value="inject" selected={move || settings.get().output_mode == OutputMode::Inject}>
+                            "Type into the focused app"
+                        </option>
+                        <option value="clipboard" selected={move || settings.get().output_mode == OutputMode::Clipboard}>
+                            "Copy to the clipboard only"
+                        </option>
+                        <option value="both" selected={move || settings.get().output_mode == OutputMode::Both}>
+                            "Type and copy to the clipboard"
+                        </option>
+                    </select>
+
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={move || settings.get().clipboard_notification}
+                            disabled={move || !settings.get().output_mode.copies()}
+                            on:change=move |e| {
+                                let enabled = event_target_checked(&e);
+                                set_settings.update(|s| s.clipboard_notification = enabled);
+                                save_settings();
+                            }
+                        />
+                        <div class="checkbox-content">
+                            <span class="checkbox-label-text">"Notify when text is copied"</span>
+                            <span class="checkbox-help">"The notification shows the start of the copied text"</span>
+                        </div>
+                    </label>
+
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={move || settings.get().streaming_injection}
+                            disabled={move || {
+                                let s = settings.get();
+                                !s.output_mode.injects()
+                                    || s.session_window_secs.is_some()
+                                    || s.long_form_mins.is_some()
+                            }}
+                            on:change=move |e| {
+                                let enabled = event_target_checked(&e);
+                                set_settings.update(|s| s.streaming_injection = enabled);
+                                save_settings();
+                            }
+                        />
+                        <div class="checkbox-content">
+                            <span class="checkbox-label-text">"Type words while speaking"</span>
+                            <span class="checkbox-help">"Words appear as they are recognised and are corrected with backspace if the transcript changes. Uses more CPU while recording."</span>
+                        </div>
+                    </label>
+
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={move || settings.get().session_window_secs.is_some()}
+                            on:change=move |e| {
+                                let window = event_target_checked(&e).then_some(DEFAULT_SESSION_WINDOW_SECS);
+                                set_settings.update(|s| s.session_window_secs = window);
+                                save_settings();
+                            }
+                        />
+                        <div class="checkbox-content">
+                            <span class="checkbox-label-text">"Continue from the previous dictation"</span>
+                            <span class="checkbox-help">"A dictation started soon after the last one, in the same app, carries on the same text with the right spacing and capitalisation. End the block early from the menu-bar icon. Words are not typed while speaking."</span>
+                        </div>
+                    </label>
+                    <Show when=move || settings.get().session_window_secs.is_some()>
+                        <label class="session-window">
+                            <span class="checkbox-help">"Within"</span>
+                            <input
+                                type="number"
+                                class="rule-input"
+                                min="1"
+                                max=MAX_SESSION_WINDOW_SECS
+                                prop:value=move || settings.get().session_window_secs.unwrap_or(DEFAULT_SESSION_WINDOW_SECS).to_string()
+                                on:change=move |e| {
+                                    if let Ok(secs) = event_target_value(&e).parse::<u32>() {
+                                        let secs = secs.clamp(1, MAX_SESSION_WINDOW_SECS);
+                                        set_settings.update(|s| s.session_window_secs = Some(secs));
+                                        save_settings();
+                                    }
+                                }
+                            />
+                            <span class="checkbox-help">"seconds"</span>
+                        </label>
+                    </Show>
+
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={move || settings.get().dry_run}
+                            on:change=move |e| {
+                                let enabled = event_target_checked(&e);
+                                set_settings.update(|s| s.dry_run = enabled);
+                                save_settings();
+                            }
+                        />
+                        <div class="checkbox-content">
+                            <span class="checkbox-label-text">"Review before typing (dry run)"</span>
+                            <span class="checkbox-help">"Dictations wait in Pending instead of being typed or copied, so you can edit them first"</span>
+                        </div>
+                    </label>
+
+                    <div class="output-newlines">
+                        <label for="paragraph_break">"Paragraph breaks"</label>
+                        <select
+                            id="paragraph_break"
+                            on:change=move |e| {
+                                let paragraph_break = match event_target_select_value(&e).as_str() {
+                                    "single" => ParagraphBreak::SingleNewline,
+                                    "none" => ParagraphBreak::NoBreak,
+                                    _ => ParagraphBreak::DoubleNewline,
+                                };
+                                set_settings.update(|s| s.newlines.paragraph_break = paragraph_break);
+                                save_settings();
+                            }
+                        >
+                            <option value="double" selected={move || settings.get().newlines.paragraph_break == ParagraphBreak::DoubleNewline}>
+                                "Blank line between paragraphs"
+                            </option>
+                            <option value="single" selected={move || settings.get().newlines.paragraph_break == ParagraphBreak::SingleNewline}>
+                                "Single line break"
+                            </option>
+                            <option value="none" selected={move || settings.get().newlines.paragraph_break == ParagraphBreak::NoBreak}>
+                                "None (join into one line)"
+                            </option>
+                        </select>
+
+                        <label for="new_paragraph_command">"Saying \"new paragraph\""</label>
+                        <select
+                            id="new_paragraph_command"
+                            on:change=move |e| {
+                                let command = match event_target_select_value(&e).as_str() {
+                                    "line" => NewParagraphCommand::LineBreak,
+                                    "literal" => NewParagraphCommand::Literal,
+                                    _ => NewParagraphCommand::ParagraphBreak,
+                                };
+                                set_settings.update(|s| s.newlines.new_paragraph_command = command);
+                                save_settings();
+                            }
+                        >
+                            <option value="paragraph" selected={move || settings.get().newlines.new_paragraph_command == NewParagraphCommand::ParagraphBreak}>
+                                "Starts a new paragraph"
+                            </option>
+                            <option value="line" selected={move || settings.get().newlines.new_paragraph_command == NewParagraphCommand::LineBreak}>
+                                "Inserts a line break"
+                            </option>
+                            <option value="literal" selected={move || settings.get().newlines.new_paragraph_command == NewParagraphCommand::Literal}>
+                                "Types the words"
+                            </option>
+                        </select>
+                        <p class="checkbox-help">
+                            "Applied just before the text is typed or copied. Whisper rarely splits paragraphs by itself, so saying \"new paragraph\" is the usual way to start one."
+                        </p>
+                    </div>
+
+                    <div class="replacement-rule-editor reinject-hotkey">
+                        <button
+                            class="btn-secondary"
+                            on:click=move |_| {
+                                spawn_local(async move {
+                                    if let Err(e) = SettingsManager::reinject_last_transcription().await {
+                                        set_error_message.set(Some(e));
+                                    }
+                                });
+                            }
+                        >
+                            "Type last dictation again"
+                        </button>
+                        <HotkeyCapture
+                            value=Signal::derive(move || settings.get().reinject_hotkey.unwrap_or_default())
+                            invalid=Signal::derive(|| false)
+                            on_capture=Callback::new(move |shortcut: String| {
+                                set_settings.update(|s| s.reinject_hotkey = Some(shortcut));
+                                save_settings();
+                            })
+                        />
+                        <button
+                            class="btn-secondary"
+                            disabled=move || settings.get().reinject_hotkey.is_none()
+                            on:click=move |_| {
+                                set_settings.update(|s| s.reinject_hotkey = None);
+                                save_settings();
+                            }
+                        >
+                            "Remove hot-key"
+                        </button>
+                    </div>
+                    <p class="checkbox-help">
+                        "When a dictation went to the wrong window, focus the right one and press the hot-key to type it there."
+                    </p>
+                </div>
+
+                // App Profiles Section
+                <div class="setting-group" role="group" aria-labelledby="settings-app-profiles">
+                    <h3 id="settings-app-profiles">"🧩 " {move || i18n.t("settings.section.app_profiles")}</h3>
+                    <p class="setting-description">
+                        "Code editors and terminals usually shouldn't get punctuation or sentence casing, and some apps drop fast keystrokes. Adjust how text reaches each app; identify apps by bundle identifier (e.g. com.microsoft.VSCode)."
+                    </p>
+
+                    <div class="app-profiles">
+                        <div class="app-profile app-profile-header">
+                            <span>"Application"</span>
+                            <span>"Punctuation"</span>
+                            <span>"Capitalisation"</span>
+                            <span>"Insert by"</span>
+                            <span>"Key delay (ms)"</span>
+                            <span>"Then add"</span>
+                            <span></span>
+                        </div>
+                        {move || {
+                            settings.get().app_profiles.into_iter().enumerate().map(|(index, profile)| view! {
+                                <div class="app-profile">
+                                    <code>{profile.app_id}</code>
+                                    <input
+                                        type="checkbox"
+                                        title="Keep punctuation"
+                                        checked=profile.punctuation
+                                        on:change=move |e| {
+                                            let enabled = event_target_checked(&e);
+                                            set_settings.update(|s| {
+                                                if let Some(p) = s.app_profiles.get_mut(index) {
+                                                    p.punctuation = enabled;
+                                                }
+                                            });
+                                            save_settings();
+                                        }
+                                    />
+                                    <input
+                                        type="checkbox"
+                                        title="Capitalise sentences"
+                                        checked=profile.capitalisation
+                                        on:change=move |e| {
+                                            let enabled = event_target_checked(&e);
+                                            set_settings.update(|s| {
+                                                if let Some(p) = s.app_profiles.get_mut(index) {
+                                                    p.capitalisation = enabled;
+                                                }
+                                            });
+                                            save_settings();
+                                        }
+                                    />
+                                    <select
+                                        title="How text is inserted"
+                                        on:change=move |e| {
+                                            let method = match event_target_select_value(&e).as_str() {
+                                                "clipboard" => InjectionMethod::Clipboard,
+                                                _ => InjectionMethod::Keystrokes,
+                                            };
+                                            set_settings.update(|s| {
+                                                if let Some(p) = s.app_profiles.get_mut(index) {
+                                                    p.injection.method = method;
+                                                }
+                                            });
+                                            save_settings();
+                                        }
+                                    >
+                                        <option value="keystrokes" selected={profile.injection.method == InjectionMethod::Keystrokes}>
+                                            "Typing"
+                                        </option>
+                                        <option value="clipboard" selected={profile.injection.method == InjectionMethod::Clipboard}>
+                                            "Pasting"
+                                        </option>
+                                    </select>
+                                    <input
+                                        type="number"
+                                        class="typing-delay-input"
+                                        title="Delay between keystrokes"
+                                        min="0"
+                                        max=MAX_TYPING_DELAY_MS
+                                        prop:value=profile.injection.typing_delay_ms
+                                        disabled={profile.injection.method == InjectionMethod::Clipboard}
+                                        on:change=move |e| {
+                                            let delay = event_target_value(&e)
+                                                .parse::<u32>()
+                                                .unwrap_or(DEFAULT_TYPING_DELAY_MS)
+                                                .min(MAX_TYPING_DELAY_MS);
+                                            set_settings.update(|s| {
+                                                if let Some(p) = s.app_profiles.get_mut(index) {
+                                                    p.injection.typing_delay_ms = delay;
+                                                }
+                                            });
+                                            save_settings();
+                                        }
+                                    />
+                                    <select
+                                        title="Text added after each dictation"
+                                        on:change=move |e| {
+                                            let trailing = match event_target_select_value(&e).as_str() {
+                                                "space" => TrailingText::Space,
+                                                "newline" => TrailingText::Newline,
+                                                _ => TrailingText::Nothing,
+                                            };
+                                            set_settings.update(|s| {
+                                                if let Some(p) = s.app_profiles.get_mut(index) {
+                                                    p.injection.trailing = trailing;
+                                                }
+                                            });
+                                            save_settings();
+                                        }
+                                    >
+                                        <option value="nothing" selected={profile.injection.trailing == TrailingText::Nothing}>
+                                            "Nothing"
+                                        </option>
+                                        <option value="space" selected={profile.injection.trailing == TrailingText::Space}>
+                                            "Space"
+                                        </option>
+                                        <option value="newline" selected={profile.injection.trailing == TrailingText::Newline}>
+                                            "New line"
+                                        </option>
+                                    </select>
+                                    <button
+                                        class="btn-secondary"
+                                        on:click=move |_| {
+                                            set_settings.update(|s| {
+                                                s.app_profiles.remove(index);
+                                            });
+                                            save_settings();
+                                        }
+                                    >
+                                        "Remove"
+                                    </button>
+                                </div>
+                            }).collect::<Vec<_>>()
+                        }}
+
+                        <div class="replacement-rule-editor">
+                            <input
+                                type="text"
+                                class="rule-input"
+                                placeholder="Bundle identifier, e.g. com.apple.Terminal"
+                                prop:value=move || new_app_id.get()
+                                on:input=move |e| set_new_app_id.set(event_target_value(&e))
+                            />
+                            <button
+                                class="btn-primary"
+                                disabled=move || new_app_id.get().trim().is_empty()
+                                on:click=move |_| {
+                                    let mut added = false;
+                                    set_settings.update(|s| {
+                                        added = add_app_profile(&mut s.app_profiles, &new_app_id.get());
+                                    });
+                                    if added {
+                                        set_new_app_id.set(String::new());
+                                        save_settings();
+                                    } else {
+                                        set_error_message.set(Some("That application already has a profile.".to_string()));
+                                    }
+                                }
+                            >
+                                "Add App"
+                            </button>
+                        </div>
+                    </div>
+                </div>
+
+                // Injection Blocklist Section
+                <div class="setting-group" role="group" aria-labelledby="settings-injection-blocklist">
+                    <h3 id="settings-injection-blocklist">"🚫 " {move || i18n.t("settings.section.injection_blocklist")}</h3>
+                    <p class="setting-description">
+                        "Speakr never types into these apps, such as password managers. Dictations aimed at them are copied to the clipboard instead, with a notification. Identify apps by bundle identifier (e.g. com.bitwarden.desktop)."
+                    </p>
+
+                    <TermsEditor
+                        terms=Signal::derive(move || settings.get().injection_blocklist)
+                        placeholder="e.g. com.1password.1password, com.apple.Terminal"
+                        on_change=Callback::new(move |app_ids: Vec<String>| {
+                            set_settings.update(|s| s.injection_blocklist = app_ids);
+                            save_settings();
+                        })
+                    />
+                </div>
+
+                // Auto-launch Section
+                <div class="setting-group" role="group" aria-labelledby="settings-auto-launch">
+                    <h3 id="settings-auto-launch">"🚀 " {move || i18n.t("settings.section.auto_launch")}</h3>
+                    <p class="setting-description">
+                        "Automatically start Speakr when you log in to your computer, so it's always ready when you need it."
+                    </p>
+
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            class="auto-launch-checkbox"
+                            checked={move || settings.get().auto_launch}
+                            on:change=move |e| {
+                                let enabled = event_target_checked(&e);
+                                set_settings.update(|s| s.auto_launch = enabled);
+
+                                spawn_local(async move {
+                                    match SettingsManager::set_auto_launch(enabled).await {
+                                        Ok(_) => save_settings(),
+                                        Err(e) => {
+                                            set_error_message.set(Some(format!("Failed to update auto-launch: {e}")));
+                                        }
+                                    }
+                                });
+                            }
+                        />
+                        <div class="checkbox-content">
+                            <span class="checkbox-label-text">"Start Speakr on login"</span>
+                            <span class="checkbox-help">"Recommended for seamless workflow integration"</span>
+                        </div>
+                    </label>
+                </div>
+
+                // Recording Indicator Section
+                <div class="setting-group" role="group" aria-labelledby="settings-recording-indicator">
+                    <h3 id="settings-recording-indicator">"🔴 " {move || i18n.t("settings.section.recording_indicator")}</h3>
+                    <p class="setting-description">
+                        "Show a badge on the Dock icon while Speakr is recording or transcribing."
+                    </p>
+
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={move || settings.get().dock_badge}
+                            on:change=move |e| {
+                                let enabled = event_target_checked(&e);
+                                set_settings.update(|s| s.dock_badge = enabled);
+                                save_settings();
+                            }
+                        />
+                        <div class="checkbox-content">
+                            <span class="checkbox-label-text">"Badge the Dock icon"</span>
+                            <span class="checkbox-help">"● while recording, … while transcribing"</span>
+                        </div>
+                    </label>
+                </div>
+
+                // Notifications Section
+                <div class="setting-group" role="group" aria-labelledby="settings-notifications">
+                    <h3 id="settings-notifications">"🔔 " {move || i18n.t("settings.section.notifications")}</h3>
+                    <p class="setting-description">
+                        "Get a system notification when a dictation finishes while Speakr is in the background, or when a dictation fails."
+                    </p>
+
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={move || settings.get().notifications}
+                            on:change=move |e| {
+                                let enabled = event_target_checked(&e);
+                                set_settings.update(|s| s.notifications = enabled);
+                                save_settings();
+                            }
+                        />
+                        <div class="checkbox-content">
+                            <span class="checkbox-label-text">"Notify when a dictation finishes or fails"</span>
+                        </div>
+                    </label>
+
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={move || settings.get().notification_text}
+                            disabled={move || !settings.get().notifications}
+                            on:change=move |e| {
+                                let enabled = event_target_checked(&e);
+                                set_settings.update(|s| s.notification_text = enabled);
+                                save_settings();
+                            }
+                        />
+                        <div class="checkbox-content">
+                            <span class="checkbox-label-text">"Include the dictated text"</span>
+                            <span class="checkbox-help">"Otherwise only the number of words is shown. Never shown in privacy mode."</span>
+                        </div>
+                    </label>
+                </div>
+
+                // Latency Breakdown Section
+                <div class="setting-group" role="group" aria-labelledby="settings-latency">
+                    <h3 id="settings-latency">"⏱️ " {move || i18n.t("settings.section.latency")}</h3>
+                    <p class="setting-description">
+                        "See how long each step of a dictation took, to help choose a model size and clean-up rules that feel fast enough."
+                    </p>
+
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={move || settings.get().show_latency_breakdown}
+                            on:change=move |e| {
+                                let enabled = event_target_checked(&e);
+                                set_settings.update(|s| s.show_latency_breakdown = enabled);
+                                save_settings();
+                            }
+                        />
+                        <div class="checkbox-content">
+                            <span class="checkbox-label-text">"Show timings after each dictation"</span>
+                            <span class="checkbox-help">"Capture, transcription, processing and injection times, with the model used"</span>
+                        </div>
+                    </label>
+                </div>
+
+                // Stop Phrase Section
+                <div class="setting-group" role="group" aria-labelledby="settings-stop-phrase">
+                    <h3 id="settings-stop-phrase">"🛑 " {move || i18n.t("settings.section.stop_phrase")}</h3>
+                    <p class="setting-description">
+                        "Say this phrase to end a recording without touching the keyboard. It is removed from the dictated text. Leave blank to turn it off."
+                    </p>
+
+                    <input
+                        type="text"
+                        class="rule-input"
+                        placeholder="e.g. stop dictation"
+                        prop:value=move || {
+                            settings.get().stop_phrase.map(|stop| stop.phrase).unwrap_or_default()
+                        }
+                        on:change=move |e| {
+                            let phrase = event_target_value(&e);
+                            set_settings.update(|s| {
+                                s.stop_phrase = with_stop_phrase_text(s.stop_phrase.take(), &phrase)
+                            });
+                            save_settings();
+                        }
+                    />
+
+                    <Show when=move || settings.get().stop_phrase.is_some()>
+                        <label class="stop-phrase-confidence">
+                            <span class="checkbox-help">
+                                {move || {
+                                    let confidence = settings
+                                        .get()
+                                        .stop_phrase
+                                        .map_or(DEFAULT_STOP_PHRASE_CONFIDENCE, |stop| stop.min_confidence);
+                                    format!("Required confidence: {:.0}%", confidence * 100.0)
+                                }}
+                            </span>
+                            <input
+                                type="range"
+                                min="0.3"
+                                max="0.95"
+                                step="0.05"
+                                prop:value=move || {
+                                    settings
+                                        .get()
+                                        .stop_phrase
+                                        .map_or(DEFAULT_STOP_PHRASE_CONFIDENCE, |stop| stop.min_confidence)
+                                        .to_string()
+                                }
+                                on:change=move |e| {
+                                    if let Ok(confidence) = event_target_value(&e).parse::<f32>() {
+                                        set_settings.update(|s| {
+                                            if let Some(stop) = s.stop_phrase.as_mut() {
+                                                stop.min_confidence = confidence;
+                                            }
+                                        });
+                                        save_settings();
+                                    }
+                                }
+                            />
+                        </label>
+                    </Show>
+                </div>
+
+                // Voice Commands Section
+                <div class="setting-group" role="group" aria-labelledby="settings-voice-commands">
+                    <h3 id="settings-voice-commands">"🗣️ " {move || i18n.t("settings.section.voice_commands")}</h3>
+                    <p class="setting-description">
+                        "Say punctuation and editing commands such as \"comma\", \"new paragraph\" or \"delete that\" instead of having them typed as words."
+                    </p>
+
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={move || settings.get().voice_commands.enabled}
+                            on:change=move |e| {
+                                let enabled = event_target_checked(&e);
+                                set_settings.update(|s| s.voice_commands.enabled = enabled);
+                                save_settings();
+                            }
+                        />
+                        <div class="checkbox-content">
+                            <span class="checkbox-label-text">"Interpret voice commands"</span>
+                            <span class="checkbox-help">"Words such as \"period\" are then never typed literally"</span>
+                        </div>
+                    </label>
+
+                    <Show when=move || settings.get().voice_commands.enabled>
+                        <VoiceCommandEditor
+                            custom=Signal::derive(move || settings.get().voice_commands.custom)
+                            on_change=Callback::new(move |custom: Vec<VoiceCommand>| {
+                                set_settings.update(|s| s.voice_commands.custom = custom);
+                                save_settings();
+                            })
+                        />
+                    </Show>
+                </div>
+
+                // Microphones Section
+                <div class="setting-group" role="group" aria-labelledby="settings-microphones">
+                    <h3 id="settings-microphones">"🎙️ " {move || i18n.t("settings.section.microphones")}</h3>
+                    <p class="setting-description">
+                        "Order the microphones you use. Each dictation records from the highest one that is connected, or the system default if none are."
+                    </p>
+
+                    <div class="input-devices">
+                        {move || {
+                            let preferred = settings.get().input_devices;
+                            let count = preferred.len();
+                            let connected = connected_devices.get();
+                            preferred.into_iter().enumerate().map(|(index, device)| {
+                                let status = if connected.contains(&device) { "Connected" } else { "Not connected" };
+                                view! {
+                                    <div class="input-device">
+                                        <span>{format!("{}. {device}", index + 1)}</span>
+                                        <span class="checkbox-help">{status}</span>
+                                        <div class="input-device-actions">
+                                            <button
+                                                class="btn-secondary"
+                                                title="Move up"
+                                                aria-label="Move up"
+                                                disabled=index == 0
+                                                on:click=move |_| {
+                                                    set_settings.update(|s| s.input_devices.swap(index - 1, index));
+                                                    save_settings();
+                                                }
+                                            >
+                                                "↑"
+                                            </button>
+                                            <button
+                                                class="btn-secondary"
+                                                title="Move down"
+                                                aria-label="Move down"
+                                                disabled=index + 1 == count
+                                                on:click=move |_| {
+                                                    set_settings.update(|s| s.input_devices.swap(index, index + 1));
+                                                    save_settings();
+                                                }
+                                            >
+                                                "↓"
+                                            </button>
+                                            <button
+                                                class="btn-secondary"
+                                                on:click=move |_| {
+                                                    set_settings.update(|s| {
+                                                        s.input_devices.remove(index);
+                                                    });
+                                                    save_settings();
+                                                }
+                                            >
+                                                "Remove"
+                                            </button>
+                                        </div>
+                                    </div>
+                                }
+                            }).collect::<Vec<_>>()
+                        }}
+
+                        <div class="replacement-rule-editor">
+                            <select
+                                class="language-select"
+                                on:change=move |e| set_new_input_device.set(event_target_select_value(&e))
+                            >
+                                <option value="" selected={move || new_input_device.get().is_empty()}>
+                                    "Choose a microphone"
+                                </option>
+                                {move || {
+                                    let preferred = settings.get().input_devices;
+                                    let current = new_input_device.get();
+                                    connected_devices
+                                        .get()
+                                        .into_iter()
+                                        .filter(|device| !preferred.contains(device))
+                                        .map(|device| {
+                                            let is_selected = device == current;
+                                            let value = device.clone();
+                                            view! {
+                                                <option value=value selected=is_selected>
+                                                    {device}
+                                                </option>
+                                            }
+                                        })
+                                        .collect::<Vec<_>>()
+                                }}
+                            </select>
+                            <button
+                                class="btn-primary"
+                                disabled=move || new_input_device.get().is_empty()
+                                on:click=move |_| {
+                                    let device = new_input_device.get();
+                                    set_settings.update(|s| {
+                                        if !s.input_devices.contains(&device) {
+                                            s.input_devices.push(device);
+                                        }
+                                    });
+                                    set_new_input_device.set(String::new());
+                                    save_settings();
+                                }
+                            >
+                                "Add Microphone"
+                            </button>
+                        </div>
+                    </div>
+
+                    <div class="microphone-test">
+                        <button
+                            class="btn-secondary"
+                            disabled=move || testing_microphone.get()
+                            on:click=move |_| {
+                                spawn_local(async move {
+                                    set_testing_microphone.set(true);
+                                    set_microphone_test.set(None);
+                                    match SettingsManager::test_microphone().await {
+                                        Ok(test) => set_microphone_test.set(Some(test)),
+                                        Err(e) => set_error_message.set(Some(e)),
+                                    }
+                                    set_testing_microphone.set(false);
+                                });
+                            }
+                        >
+                            {move || if testing_microphone.get() { "Listening… say something" } else { "Test Microphone" }}
+                        </button>
+                        {move || microphone_test.get().map(|test| {
+                            let passed = test.passed();
+                            let details = format!(
+                                "{}Peak {:.1} dBFS · average {:.1} dBFS",
+                                test.device.as_deref().map(|device| format!("{device}: ")).unwrap_or_default(),
+                                test.peak_dbfs,
+                                test.rms_dbfs,
+                            );
+                            view! {
+                                <div class="microphone-test-result" class:passed=passed role="status">
+                                    <span class="microphone-test-verdict">{if passed { "✓ Pass" } else { "✗ Fail" }}</span>
+                                    <span>{test.summary()}</span>
+                                    <span class="checkbox-help">{details}</span>
+                                </div>
+                            }
+                        })}
+                    </div>
+                </div>
+
+                // Pre-record Buffer Section
+                <div class="setting-group" role="group" aria-labelledby="settings-pre-record">
+                    <h3 id="settings-pre-record">"🎧 " {move || i18n.t("settings.section.pre_record")}</h3>
+                    <p class="setting-description">
+                        "Keep the last couple of seconds of audio so the first words aren't cut off."
+                    </p>
+
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={move || settings.get().pre_buffer}
+                            on:change=move |e| {
+                                let enabled = event_target_checked(&e);
+                                set_settings.update(|s| s.pre_buffer = enabled);
+                                save_settings();
+                            }
+                        />
+                        <div class="checkbox-content">
+                            <span class="checkbox-label-text">"Capture audio before the hot-key"</span>
+                            <span class="checkbox-help">
+                                "Keeps the microphone open while idle. Buffered audio stays in memory only and is never saved or sent anywhere."
+                            </span>
+                        </div>
+                    </label>
+                </div>
+
+                // Recording Length Section
+                <div class="setting-group" role="group" aria-labelledby="settings-recording-length">
+                    <h3 id="settings-recording-length">"⏱️ " {move || i18n.t("settings.section.recording_length")}</h3>
+                    <p class="setting-description">
+                        "Dictation stops automatically after this long, even if you keep talking."
+                    </p>
+
+                    <Show when=move || settings.get().long_form_mins.is_none()>
+                        <label class="recording-length">
+                            <span class="checkbox-help">
+                                {move || format!("Stop after {}", settings.get().audio_duration)}
+                            </span>
+                            <input
+                                type="range"
+                                min=AudioDuration::MIN.as_secs().to_string()
+                                max=AudioDuration::MAX.as_secs().to_string()
+                                step="1"
+                                prop:value=move || settings.get().audio_duration.as_secs().to_string()
+                                on:change=move |e| {
+                                    if let Ok(secs) = event_target_value(&e).parse::<u32>() {
+                                        set_settings.update(|s| s.audio_duration = AudioDuration::clamped(secs));
+                                        save_settings();
+                                    }
+                                }
+                            />
+                        </label>
+                    </Show>
+
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={move || settings.get().long_form_mins.is_some()}
+                            on:change=move |e| {
+                                let mins = event_target_checked(&e).then_some(DEFAULT_LONG_FORM_MINS);
+                                set_settings.update(|s| s.long_form_mins = mins);
+                                save_settings();
+                            }
+                        />
+                        <div class="checkbox-content">
+                            <span class="checkbox-label-text">"Long-form dictation"</span>
+                            <span class="checkbox-help">"Record for several minutes. The audio is transcribed in chunks while you speak, so the text is ready soon after you stop. Words are not typed while speaking."</span>
+                        </div>
+                    </label>
+                    <Show when=move || settings.get().long_form_mins.is_some()>
+                        <label class="long-form-limit">
+                            <span class="checkbox-help">"Stop after"</span>
+                            <input
+                                type="number"
+                                class="rule-input"
+                                min="1"
+                                max=MAX_LONG_FORM_MINS
+                                prop:value=move || settings.get().long_form_mins.unwrap_or(DEFAULT_LONG_FORM_MINS).to_string()
+                                on:change=move |e| {
+                                    if let Ok(mins) = event_target_value(&e).parse::<u32>() {
+                                        let mins = mins.clamp(1, MAX_LONG_FORM_MINS);
+                                        set_settings.update(|s| s.long_form_mins = Some(mins));
+                                        save_settings();
+                                    }
+                                }
+                            />
+                            <span class="checkbox-help">"minutes"</span>
+                        </label>
+                    </Show>
+                </div>
+
+                // Saved Recordings Section
+                <div class="setting-group" role="group" aria-labelledby="settings-saved-recordings">
+                    <h3 id="settings-saved-recordings">"💾 " {move || i18n.t("settings.section.saved_recordings")}</h3>
+                    <p class="setting-description">
+                        "Keep the audio of each dictation as a WAV file tagged with its date, duration, model and target app."
+                    </p>
+
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={move || settings.get().save_recordings}
+                            on:change=move |e| {
+                                let enabled = event_target_checked(&e);
+                                set_settings.update(|s| s.save_recordings = enabled);
+                                save_settings();
+                            }
+                        />
+                        <div class="checkbox-content">
+                            <span class="checkbox-label-text">"Save dictation recordings"</span>
+                            <span class="checkbox-help">
+                                "Recordings are saved to Documents/Speakr/debug_recordings and count towards Storage Usage."
+                            </span>
+                        </div>
+                    </label>
+
+                    <Show when=move || settings.get().save_recordings>
+                        <label class="recording-template">
+                            <span class="checkbox-help">
+                                "File name template; use {date}, {time}, {model}, {app} and {duration}."
+                            </span>
+                            <input
+                                type="text"
+                                class="rule-input"
+                                placeholder=DEFAULT_RECORDING_FILENAME_TEMPLATE
+                                prop:value=move || settings.get().recording_filename_template
+                                on:change=move |e| {
+                                    let template = event_target_value(&e);
+                                    set_settings.update(|s| s.recording_filename_template = template);
+                                    save_settings();
+                                }
+                            />
+                        </label>
+                    </Show>
+                </div>
+
+                // Privacy Mode Section
+                <div class="setting-group" role="group" aria-labelledby="settings-privacy-mode">
+                    <h3 id="settings-privacy-mode">"🔒 " {move || i18n.t("settings.section.privacy_mode")}</h3>
+                    <p class="setting-description">
+                        "For dictating something sensitive: while privacy mode is on, transcripts are not added to the history or written to the logs, and no recordings are saved. Switch it on from the menu-bar icon, the hot-key below, or here."
+                    </p>
+
+                    <div class="privacy-mode">
+                        <button
+                            class="btn-secondary"
+                            on:click=move |_| {
+                                spawn_local(async move {
+                                    if let Err(e) = SettingsManager::set_privacy_mode(true).await {
+                                        set_error_message.set(Some(e));
+                                    }
+                                });
+                            }
+                        >
+                            "Start privacy mode"
+                        </button>
+                        <label class="privacy-duration">
+                            <span class="checkbox-help">"Ends by itself after"</span>
+                            <input
+                                type="number"
+                                class="rule-input"
+                                min="1"
+                                prop:value=move || settings.get().privacy_mode_mins.to_string()
+                                on:change=move |e| {
+                                    if let Ok(minutes) = event_target_value(&e).parse::<u32>() {
+                                        set_settings.update(|s| s.privacy_mode_mins = minutes.max(1));
+                                        save_settings();
+                                    }
+                                }
+                            />
+                            <span class="checkbox-help">"minutes"</span>
+                        </label>
+                    </div>
+
+                    <div class="replacement-rule-editor privacy-hotkey">
+                        <HotkeyCapture
+                            value=Signal::derive(move || settings.get().privacy_hotkey.unwrap_or_default())
+                            invalid=Signal::derive(|| false)
+                            on_capture=Callback::new(move |shortcut: String| {
+                                set_settings.update(|s| s.privacy_hotkey = Some(shortcut));
+                                save_settings();
+                            })
+                        />
+                        <button
+                            class="btn-secondary"
+                            disabled=move || settings.get().privacy_hotkey.is_none()
+                            on:click=move |_| {
+                                set_settings.update(|s| s.privacy_hotkey = None);
+                                save_settings();
+                            }
+                        >
+                            "Remove hot-key"
+                        </button>
+                    </div>
+
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={move || settings.get().encrypt_history}
+                            on:change=move |e| {
+                                let enabled = event_target_checked(&e);
+                                set_settings.update(|s| s.encrypt_history = enabled);
+                                save_settings();
+                            }
+                        />
+                        <div class="checkbox-content">
+                            <span class="checkbox-label-text">"Encrypt the history on disk"</span>
+                            <span class="checkbox-help">
+                                "The history is encrypted with a key kept in the macOS Keychain. Existing dictations are encrypted when you switch this on, and decrypted again when you switch it off."
+                            </span>
+                        </div>
+                    </label>
+                </div>
+
+                // Noise Suppression Section
+                <div class="setting-group" role="group" aria-labelledby="settings-noise-suppression">
+                    <h3 id="settings-noise-suppression">"🔇 " {move || i18n.t("settings.section.noise_suppression")}</h3>
+                    <p class="setting-description">
+                        "Filter out fans, keyboards and other background noise before transcription."
+                    </p>
+
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={move || settings.get().denoise}
+                            on:change=move |e| {
+                                set_denoise(event_target_checked(&e));
+                                save_settings();
+                            }
+                        />
+                        <div class="checkbox-content">
+                            <span class="checkbox-label-text">"Reduce background noise"</span>
+                            <span class="checkbox-help">
+                                {move || if downloading_model.get().as_deref() == Some(DENOISE_MODEL_FILENAME) {
+                                    "Downloading the RNNoise model..."
+                                } else {
+                                    "Uses the small RNNoise model, downloaded once and run locally."
+                                }}
+                            </span>
+                        </div>
+                    </label>
+                </div>
+
+                // Input Gain Section
+                <div class="setting-group" role="group" aria-labelledby="settings-input-gain">
+                    <h3 id="settings-input-gain">"🎚️ " {move || i18n.t("settings.section.input_gain")}</h3>
+                    <p class="setting-description">
+                        "Bring quiet microphones up to a consistent level before transcription."
+                    </p>
+
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={move || settings.get().auto_gain.is_some()}
+                            on:change=move |e| {
+                                let enabled = event_target_checked(&e);
+                                set_settings.update(|s| s.auto_gain = enabled.then(AutoGain::default));
+                                save_settings();
+                            }
+                        />
+                        <div class="checkbox-content">
+                            <span class="checkbox-label-text">"Automatic gain control"</span>
+                            <span class="checkbox-help">"Pauses are ignored when measuring, and loud input is never clipped."</span>
+                        </div>
+                    </label>
+
+                    <Show when=move || settings.get().auto_gain.is_some()>
+                        <label class="auto-gain-target">
+                            <span class="checkbox-help">
+                                {move || {
+                                    let target = settings.get().auto_gain.unwrap_or_default().target_dbfs;
+                                    format!("Target loudness: {target:.0} dBFS")
+                                }}
+                            </span>
+                            <input
+                                type="range"
+                                min=MIN_AGC_TARGET_DBFS.to_string()
+                                max=MAX_AGC_TARGET_DBFS.to_string()
+                                step="1"
+                                prop:value=move || {
+                                    settings.get().auto_gain.unwrap_or_default().target_dbfs.to_string()
+                                }
+                                on:change=move |e| {
+                                    if let Ok(target_dbfs) = event_target_value(&e).parse::<f32>() {
+                                        set_settings.update(|s| {
+                                            if let Some(auto_gain) = s.auto_gain.as_mut() {
+                                                auto_gain.target_dbfs = target_dbfs;
+                                            }
+                                        });
+                                        save_settings();
+                                    }
+                                }
+                            />
+                        </label>
+                    </Show>
+
+                    <div class="input-gains">
+                        <span class="checkbox-label-text">"Microphone gain"</span>
+                        <span class="checkbox-help">
+                            "Amplify a quiet microphone as it records. Loud input is softly limited instead of clipping."
+                        </span>
+                        {move || {
+                            let input_gains = settings.get().input_gains;
+                            connected_devices.get().into_iter().map(|device| {
+                                let gain = input_gain_for(&input_gains, &device);
+                                let slider_device = device.clone();
+                                let preview_device = device.clone();
+                                let meter_device = device.clone();
+                                let is_previewing = {
+                                    let device = device.clone();
+                                    move || previewing_device.get().as_ref() == Some(&device)
+                                };
+                                let button_previewing = is_previewing.clone();
+                                view! {
+                                    <div class="input-gain">
+                                        <span>{device.clone()}</span>
+                                        <span class="checkbox-help">{format_input_gain(gain)}</span>
+                                        <input
+                                            type="range"
+                                            aria-label=format!("Gain for {device}")
+                                            min=MIN_INPUT_GAIN.to_string()
+                                            max=MAX_INPUT_GAIN.to_string()
+                                            step="0.05"
+                                            prop:value=gain.to_string()
+                                            on:change=move |e| {
+                                                if let Ok(gain) = event_target_value(&e).parse::<f32>() {
+                                                    set_settings.update(|s| set_input_gain(&mut s.input_gains, &slider_device, gain));
+                                                    save_settings();
+                                                    // The preview's gain is fixed when it starts
+                                                    if previewing_device.get_untracked().as_ref() == Some(&slider_device) {
+                                                        start_preview(slider_device.clone(), gain);
+                                                    }
+                                                }
+                                            }
+                                        />
+                                        <button
+                                            class="btn-secondary"
+                                            on:click=move |_| {
+                                                if button_previewing() {
+                                                    stop_preview();
+                                                } else {
+                                                    let gain = input_gain_for(&settings.get_untracked().input_gains, &preview_device);
+                                                    start_preview(preview_device.clone(), gain);
+                                                }
+                                            }
+                                        >
+                                            {move || if is_previewing() { "Stop" } else { "Preview" }}
+                                        </button>
+                                        {move || (previewing_device.get().as_ref() == Some(&meter_device)).then(|| {
+                                            let dbfs = preview_level.get().unwrap_or(f32::NEG_INFINITY);
+                                            let percent = level_percent(dbfs_to_level(dbfs));
+                                            view! {
+                                                <div
+                                                    class="input-gain-level"
+                                                    role="meter"
+                                                    aria-label="Input level"
+                                                    aria-valuemin="0"
+                                                    aria-valuemax="100"
+                                                    aria-valuenow=percent
+                                                    aria-valuetext=format_dbfs(dbfs)
+                                                >
+                                                    <div class="level-meter">
+                                                        <div class="level-fill" style=format!("width: {percent}%")></div>
+                                                    </div>
+                                                    <span class="level-readout">{format_dbfs(dbfs)}</span>
+                                                </div>
+                                            }
+                                        })}
+                                    </div>
+                                }
+                            }).collect::<Vec<_>>()
+                        }}
+                    </div>
+                </div>
+
+                // Plugins Section
+                <div class="setting-group" role="group" aria-labelledby="settings-plugins">
+                    <h3 id="settings-plugins">"🧱 " {move || i18n.t("settings.section.plugins")}</h3>
+                    <p class="setting-description">
+                        "WebAssembly plugins transform the transcript after the clean-up rules, in the order they were enabled. Plugins run sandboxed with no file or network access."
+                    </p>
+
+                    <div class="installed-plugins">
+                        {move || {
+                            let list = plugins.get();
+                            if list.is_empty() {
+                                return view! {
+                                    <p class="checkbox-help">"No plugins installed."</p>
+                                }.into_any();
+                            }
+
+                            list.into_iter().map(|plugin| {
+                                let name = plugin.name.clone();
+                                let toggle_name = plugin.name.clone();
+                                let is_enabled = settings.get().plugins.contains(&plugin.name);
+                                let is_valid = plugin.error.is_none();
+
+                                view! {
+                                    <div class="installed-plugin">
+                                        <label class="checkbox-label">
+                                            <input
+                                                type="checkbox"
+                                                checked=is_enabled
+                                                disabled=!is_valid
+                                                on:change=move |e| {
+                                                    let enabled = event_target_checked(&e);
+                                                    set_settings.update(|s| {
+                                                        set_plugin_enabled(&mut s.plugins, &toggle_name, enabled);
+                                                    });
+                                                    save_settings();
+                                                }
+                                            />
+                                            <div class="checkbox-content">
+                                                <span class="checkbox-label-text">{plugin.name}</span>
+                                                <span class="checkbox-help">
+                                                    {plugin.error.unwrap_or_else(|| format!("{} KB", plugin.size_bytes.div_ceil(1024)))}
+                                                </span>
+                                            </div>
+                                        </label>
+                                        <button
+                                            class="btn-secondary btn-danger"
+                                            on:click=move |_| {
+                                                let name = name.clone();
+                                                spawn_local(async move {
+                                                    match SettingsManager::uninstall_plugin(&name).await {
+                                                        Ok(()) => {
+                                                            set_settings.update(|s| {
+                                                                set_plugin_enabled(&mut s.plugins, &name, false);
+                                                            });
+                                                            save_settings();
+                                                            refresh_plugins();
+                                                        }
+                                                        Err(e) => set_error_message.set(Some(e)),
+                                                    }
+                                                });
+                                            }
+                                        >
+                                            "Remove"
+                                        </button>
+                                    </div>
+                                }
+                            }).collect::<Vec<_>>().into_any()
+                        }}
+                    </div>
+
+                    <div class="replacement-rule-editor">
+                        <input
+                            type="text"
+                            class="rule-input"
+                            placeholder="Path to a .wasm plugin, e.g. ~/Downloads/title-case.wasm"
+                            prop:value=move || new_plugin_path.get()
+                            on:input=move |e| set_new_plugin_path.set(event_target_value(&e))
+                        />
+                        <button
+                            class="btn-primary"
+                            disabled=move || new_plugin_path.get().trim().is_empty()
+                            on:click=move |_| {
+                                let path = new_plugin_path.get().trim().to_string();
+                                spawn_local(async move {
+                                    match SettingsManager::install_plugin(&path).await {
+                                        Ok(plugin) => {
+                                            set_new_plugin_path.set(String::new());
+                                            set_success_message.set(Some(format!("Installed plugin {}", plugin.name)));
+                                            refresh_plugins();
+                                        }
+                                        Err(e) => set_error_message.set(Some(e)),
+                                    }
+                                });
+                            }
+                        >
+                            "Install"
+                        </button>
+                    </div>
+                </div>
+
+                // Local API Section
+                <div class="setting-group" role="group" aria-labelledby="settings-local-api">
+                    <h3 id="settings-local-api">"🔌 " {move || i18n.t("settings.section.local_api")}</h3>
+                    <p class="setting-description">
+                        "Let other tools on this Mac start dictations and read transcripts (e.g. via the speakr-client crate). Only local connections are accepted."
+                    </p>
+
+                    <label class="checkbox-label">
+                        <input
+                            type="checkbox"
+                            checked={move || settings.get().local_api}
+                            on:change=move |e| {
+                                let enabled = event_target_checked(&e);
+                                set_settings.update(|s| s.local_api = enabled);
+                                save_settings();
+                            }
+                        />
+                        <div class="checkbox-content">
+                            <span class="checkbox-label-text">"Enable the local API"</span>
+                            <span class="checkbox-help">
+                                {format!("Listens on 127.0.0.1:{DEFAULT_API_PORT}; takes effect after restarting Speakr")}
+                            </span>
+                        </div>
+                    </label>
+                </div>
+
+                // Quick Tips Section
+                <div class="setting-group" role="group" aria-labelledby="settings-quick-tips">
+                    <h3 id="settings-quick-tips">"💡 " {move || i18n.t("settings.section.quick_tips")}</h3>
+                    <div class="tips-list">
+                        <div class="tip-item">
+                            <span class="tip-icon">"🎙️"</span>
+                            <div class="tip-content">
+                                <div class="tip-title">"Clear Audio"</div>
+                                <div class="tip-description">"Speak clearly and reduce background noise for better accuracy"</div>
+                            </div>
+                        </div>
+                        <div class="tip-item">
+                            <span class="tip-icon">"⏸️"</span>
+                            <div class="tip-content">
+                                <div class="tip-title">"Natural Pauses"</div>
+                                <div class="tip-description">"Pause briefly between sentences for better punctuation"</div>
+                            </div>
+                        </div>
+                        <div class="tip-item">
+                            <span class="tip-icon">"🔒"</span>
+                            <div class="tip-content">
+                                <div class="tip-title">"Privacy First"</div>
+                                <div class="tip-description">"All processing happens locally - your voice never leaves your device"</div>
+                            </div>
+                        </div>
+                    </div>
+                </div>
+            </div>
+        </section>
+    }
+}
+
+/// Editor for a list of terms, such as the user's custom vocabulary.
+///
+/// Terms are shown as removable chips. Several terms can be added at once by
+/// separating them with commas.
+#[component]
+fn TermsEditor(
+    /// Current terms
+    terms: Signal<Vec<String>>,
+    /// Example input shown in the empty text field
+    #[prop(default = "e.g. Kubernetes, gRPC, Dr. Okonkwo")]
+    placeholder: &'static str,
+    /// Called with the full updated list whenever a term is added or removed
+    on_change: Callback<Vec<String>>,
+) -> impl IntoView {
+    let (new_terms, set_new_terms) = signal(String::new());
+
+    let add_terms = move || {
+        let mut updated = terms.get_untracked();
+        for term in new_terms.get_untracked().split(',').map(str::trim) {
+            if !term.is_empty() && !updated.iter().any(|t| t == term) {
+                updated.push(term.to_string());
+            }
+        }
+        set_new_terms.set(String::new());
+        on_change.run(updated);
+    };
+
+    view! {
+        <div class="vocabulary-editor">
+            <div class="vocabulary-terms">
+                {move || {
+                    terms.get().into_iter().enumerate().map(|(index, term)| {
+                        view! {
+                            <span class="vocabulary-term">
+                                {term}
+                                <button
+                                    class="vocabulary-remove"
+                                    title="Remove"
+                                    on:click=move |_| {
+                                        let mut updated = terms.get_untracked();
+                                        updated.remove(index);
+                                        on_change.run(updated);
+                                    }
+                                >
+                                    "✕"
+                                </button>
+                            </span>
+                        }
+                    }).collect::<Vec<_>>()
+                }}
+            </div>
+
+            <div class="replacement-rule-editor">
+                <input
+                    type="text"
+                    class="rule-input"
+                    placeholder=placeholder
+                    prop:value=move || new_terms.get()
+                    on:input=move |e| set_new_terms.set(event_target_value(&e))
+                    on:keydown=move |e| {
+                        if e.key() == "Enter" {
+                            add_terms();
+                        }
+                    }
+                />
+                <button
+                    class="btn-primary"
+                    disabled=move || new_terms.get().trim().is_empty()
+                    on:click=move |_| add_terms()
+                >
+                    "Add"
+                </button>
+            </div>
+        </div>
+    }
+}
+
+// Helper functions for the post-processing chain
+
+/// Checks whether a chain contains a transform of the same kind.
+fn has_transform(chain: &[TextTransform], transform: &TextTransform) -> bool {
+    let kind = std::mem::discriminant(transform);
+    chain.iter().any(|t| std::mem::discriminant(t) == kind)
+}
+
+/// Enables or disables a built-in transform.
+///
+/// Built-ins are kept in a fixed order (trim, filler removal, replacement
+/// rules, capitalisation) so that, for example, a sentence that started with
+/// "um" is still capitalised once the filler is removed.
+fn set_transform_enabled(chain: &mut Vec<TextTransform>, transform: &TextTransform, enabled: bool) {
+    let kind = std::mem::discriminant(transform);
+    chain.retain(|t| std::mem::discriminant(t) != kind);
+    if !enabled {
+        return;
+    }
+
+    let position = match transform {
+        TextTransform::TrimWhitespace => 0,
+        TextTransform::StripFillerWords { .. } => chain
+            .iter()
+            .take_while(|t| matches!(t, TextTransform::TrimWhitespace))
+            .count(),
+        _ => chain.len(),
+    };
+    chain.insert(position, transform.clone());
+}
+
+/// Adds a profile for `app_id` with punctuation and capitalisation off.
+///
+/// Returns `false` if the id is empty or already has a profile.
+fn add_app_profile(profiles: &mut Vec<AppProfile>, app_id: &str) -> bool {
+    let app_id = app_id.trim();
+    if app_id.is_empty()
+        || profiles
+            .iter()
+            .any(|p| p.app_id.eq_ignore_ascii_case(app_id))
+    {
+        return false;
+    }
+
+    profiles.push(AppProfile {
+        punctuation: false,
+        capitalisation: false,
+        ..AppProfile::new(app_id)
+    });
+    true
+}
+
+/// Adds a hot-key that dictates in `language`.
+///
+/// Returns `false` if the shortcut is empty or already used by the main
+/// hot-key or another language hot-key.
+fn add_language_hotkey(settings: &mut AppSettings, shortcut: &str, language: &str) -> bool {
+    let shortcut = shortcut.trim();
+    if shortcut.is_empty()
+        || shortcut == settings.hot_key
+        || settings
+            .language_hotkeys
+            .iter()
+            .any(|binding| binding.shortcut == shortcut)
+    {
+        return false;
+    }
+
+    settings.language_hotkeys.push(LanguageHotkey {
+        shortcut: shortcut.to_string(),
+        language: language.to_string(),
+    });
+    true
+}
+
+/// Shown in place of download progress while the model host is unreachable.
+const OFFLINE_DOWNLOAD_MESSAGE: &str = "You're offline — downloads will resume automatically";
+
+/// Idle-unload choices offered in the model section (`None` = never).
+const MODEL_IDLE_UNLOAD_CHOICES: &[(Option<u32>, &str)] = &[
+    (Some(5), "After 5 minutes"),
+    (Some(15), "After 15 minutes"),
+    (Some(30), "After 30 minutes"),
+    (Some(60), "After 1 hour"),
+    (None, "Never"),
+];
+
+/// Adds or removes `name` from the enabled plugins.
+///
+/// Newly enabled plugins run last, so the list keeps the order they were
+/// enabled in.
+fn set_plugin_enabled(enabled_plugins: &mut Vec<String>, name: &str, enabled: bool) {
+    let position = enabled_plugins.iter().position(|plugin| plugin == name);
+    match (enabled, position) {
+        (true, None) => enabled_plugins.push(name.to_string()),
+        (false, Some(index)) => {
+            enabled_plugins.remove(index);
+        }
+        _ => {}
+    }
+}
+
+/// Sets the input gain of `device`; unity gain removes its entry.
+fn set_input_gain(input_gains: &mut Vec<InputGain>, device: &str, gain: f32) {
+    input_gains.retain(|entry| entry.device != device);
+    if gain != 1.0 {
+        input_gains.push(InputGain {
+            device: device.to_string(),
+            gain,
+        });
+    }
+}
+
+/// Formats an input gain as a multiplier and in decibels, e.g. `2.00× (+6.0 dB)`.
+fn format_input_gain(gain: f32) -> String {
+    format!("{gain:.2}× ({:+.1} dB)", 20.0 * gain.log10())
+}
+
+/// Updates the stop phrase text, keeping its confidence threshold.
+///
+/// A blank phrase turns the stop phrase off.
+fn with_stop_phrase_text(current: Option<StopPhrase>, phrase: &str) -> Option<StopPhrase> {
+    let phrase = phrase.trim();
+    if phrase.is_empty() {
+        return None;
+    }
+
+    Some(StopPhrase {
+        phrase: phrase.to_string(),
+        ..current.unwrap_or_else(|| StopPhrase::new(""))
+    })
+}
+
+// Helper functions for event handling
+fn event_target_value(event: &web_sys::Event) -> String {
+    event
+        .target()
+        .unwrap()
+        .dyn_into::<web_sys::HtmlInputElement>()
+        .unwrap()
+        .value()
+}
+
+fn event_target_select_value(event: &web_sys::Event) -> String {
+    event
+        .target()
+        .unwrap()
+        .dyn_into::<web_sys::HtmlSelectElement>()
         .unwrap()
         .value()
 }
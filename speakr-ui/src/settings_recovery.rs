@@ -0,0 +1,96 @@
+//! Notice shown when unreadable settings were reset to defaults.
+//!
+//! If `settings.json` and its backup could not be read, the backend starts
+//! with default settings, quarantines the broken file and reports a
+//! [`SettingsRecovery`]. [`SettingsRecoveryNotice`] explains what happened
+//! and offers to open the quarantined file; it is shown until dismissed. The
+//! notice is fetched on load and also arrives on the `settings-recovered`
+//! event.
+
+use leptos::prelude::*;
+use speakr_types::{SettingsRecovery, SETTINGS_RECOVERED_EVENT};
+use wasm_bindgen_futures::spawn_local;
+
+use crate::ipc::listen_typed_with;
+use crate::settings::SettingsManager;
+
+/// Explanation shown to the user for a recovery.
+pub fn recovery_explanation(recovery: &SettingsRecovery) -> String {
+    format!(
+        "Your settings file could not be read ({}), so Speakr started with the default settings. \
+         The broken file was kept at {} so you can recover anything you need from it.",
+        recovery.error, recovery.quarantined_path
+    )
+}
+
+/// One-time dialog about settings that were reset to defaults.
+#[component]
+pub fn SettingsRecoveryNotice() -> impl IntoView {
+    let (recovery, set_recovery) = signal::<Option<SettingsRecovery>>(None);
+    let (error_message, set_error_message) = signal::<Option<String>>(None);
+
+    spawn_local(async move {
+        match SettingsManager::get_settings_recovery().await {
+            Ok(loaded) => set_recovery.set(loaded),
+            Err(e) => web_sys::console::error_1(&e.into()),
+        }
+    });
+    listen_typed_with(SETTINGS_RECOVERED_EVENT, move |notice: SettingsRecovery| {
+        set_recovery.set(Some(notice))
+    });
+
+    let open_file = move |_| {
+        spawn_local(async move {
+            if let Err(e) = SettingsManager::open_quarantined_settings().await {
+                set_error_message.set(Some(e));
+            }
+        });
+    };
+    let dismiss = move |_| {
+        spawn_local(async move {
+            match SettingsManager::dismiss_settings_recovery().await {
+                Ok(()) => set_recovery.set(None),
+                Err(e) => set_error_message.set(Some(e)),
+            }
+        });
+    };
+
+    move || {
+        recovery.get().map(|recovery| {
+            view! {
+                <div class="settings-recovery" role="alertdialog">
+                    <h3>"⚠️ Settings were reset"</h3>
+                    <p>{recovery_explanation(&recovery)}</p>
+                    {move || error_message.get().map(|message| view! {
+                        <div class="error-message" role="alert">{message}</div>
+                    })}
+                    <div class="settings-recovery-actions">
+                        <button class="btn-secondary" on:click=open_file>
+                            "Open broken file"
+                        </button>
+                        <button class="btn-primary" on:click=dismiss>
+                            "Got it"
+                        </button>
+                    </div>
+                </div>
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recovery_explanation_names_the_file_and_error() {
+        let recovery = SettingsRecovery {
+            quarantined_path: "/config/settings.corrupt-1.json".to_string(),
+            error: "expected value".to_string(),
+            recovered_at: 1,
+        };
+        let text = recovery_explanation(&recovery);
+        assert!(text.contains("/config/settings.corrupt-1.json"));
+        assert!(text.contains("(expected value)"));
+    }
+}
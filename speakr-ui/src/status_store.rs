@@ -0,0 +1,223 @@
+//! Reactive backend status for Speakr application.
+//!
+//! [`StatusStore`] holds the latest [`BackendStatus`] in a signal shared
+//! through Leptos context. It loads the status once with
+//! `get_backend_status`, then follows the backend's
+//! [`STATUS_CHANGED_EVENT`] instead of polling. [`ServiceHealth`] renders
+//! the status of each service from the store, and a notice for each service
+//! the backend watchdog gave up restarting.
+
+use leptos::prelude::*;
+use speakr_types::{
+    BackendStatus, ServiceRecovery, ServiceStatus, SERVICE_GAVE_UP_EVENT, SERVICE_RECOVERED_EVENT,
+    STATUS_CHANGED_EVENT,
+};
+use wasm_bindgen_futures::spawn_local;
+
+use crate::ipc::{describe_command_error, invoke_command_no_args, listen_typed_with};
+
+/// Fetches the current backend status.
+async fn get_backend_status() -> Result<BackendStatus, String> {
+    invoke_command_no_args("get_backend_status")
+        .await
+        .map_err(|e| {
+            format!(
+                "Failed to load backend status: {}",
+                describe_command_error(&e)
+            )
+        })
+}
+
+/// Returns `true` if `incoming` should replace `current`.
+///
+/// The initial fetch can resolve after a newer status has already arrived as
+/// an event, so older snapshots are ignored.
+pub fn is_newer(current: Option<&BackendStatus>, incoming: &BackendStatus) -> bool {
+    current.is_none_or(|current| incoming.timestamp >= current.timestamp)
+}
+
+/// CSS modifier for a service's status.
+pub fn status_class(status: &ServiceStatus) -> &'static str {
+    match status {
+        ServiceStatus::Ready => "ready",
+        ServiceStatus::Starting => "starting",
+        ServiceStatus::Error(_) => "error",
+        ServiceStatus::Unavailable => "unavailable",
+    }
+}
+
+/// Notice for a service the watchdog could not restart.
+pub fn gave_up_message(recovery: &ServiceRecovery) -> String {
+    let attempts = match recovery.attempts {
+        1 => "1 restart".to_string(),
+        n => format!("{n} restarts"),
+    };
+    match &recovery.error {
+        Some(error) => format!("{} still fails after {attempts}: {error}", recovery.service),
+        None => format!("{} still fails after {attempts}", recovery.service),
+    }
+}
+
+/// Shared, reactive backend status.
+#[derive(Clone, Copy)]
+pub struct StatusStore {
+    latest: ReadSignal<Option<BackendStatus>>,
+}
+
+impl StatusStore {
+    /// Creates the store, starts following status changes and provides it
+    /// to descendant components.
+    pub fn provide() -> Self {
+        let (latest, set_latest) = signal::<Option<BackendStatus>>(None);
+        let update = move |status: BackendStatus| {
+            set_latest.update(|latest| {
+                if is_newer(latest.as_ref(), &status) {
+                    *latest = Some(status);
+                }
+            });
+        };
+
+        listen_typed_with(STATUS_CHANGED_EVENT, update);
+        spawn_local(async move {
+            match get_backend_status().await {
+                Ok(status) => update(status),
+                Err(e) => {
+                    web_sys::console::error_1(&format!("Failed to load backend status: {e}").into())
+                }
+            }
+        });
+
+        let store = Self { latest };
+        provide_context(store);
+        store
+    }
+
+    /// Returns the store provided by an ancestor component.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no ancestor called [`StatusStore::provide`].
+    pub fn expect() -> Self {
+        expect_context::<Self>()
+    }
+
+    /// The latest status; every service is starting until one has arrived.
+    pub fn status(&self) -> BackendStatus {
+        self.latest
+            .get()
+            .unwrap_or_else(BackendStatus::new_starting)
+    }
+}
+
+/// Status of each backend service, updated as it changes.
+#[component]
+pub fn ServiceHealth() -> impl IntoView {
+    let store = StatusStore::expect();
+    let (gave_up, set_gave_up) = signal(Vec::<ServiceRecovery>::new());
+
+    listen_typed_with(SERVICE_GAVE_UP_EVENT, move |recovery: ServiceRecovery| {
+        set_gave_up.update(|gave_up| {
+            gave_up.retain(|other| other.service != recovery.service);
+            gave_up.push(recovery);
+        });
+    });
+    listen_typed_with(SERVICE_RECOVERED_EVENT, move |recovery: ServiceRecovery| {
+        set_gave_up.update(|gave_up| gave_up.retain(|other| other.service != recovery.service));
+    });
+
+    move || {
+        let status = store.status();
+        let services = [
+            ("Microphone", status.audio_capture),
+            ("Transcription", status.transcription),
+            ("Text insertion", status.text_injection),
+        ];
+        view! {
+            <ul class="service-health">
+                {services
+                    .into_iter()
+                    .map(|(name, service)| {
+                        let detail = match &service {
+                            ServiceStatus::Error(message) => message.clone(),
+                            _ => String::new(),
+                        };
+                        view! {
+                            <li class=format!("service-health-item {}", status_class(&service)) title=detail>
+                                <span class="service-health-dot"></span>
+                                <span class="service-health-name">{name}</span>
+                                <span class="service-health-state">{service.display_name().to_string()}</span>
+                            </li>
+                        }
+                    })
+                    .collect_view()}
+            </ul>
+            {gave_up
+                .get()
+                .iter()
+                .map(|recovery| view! {
+                    <p class="service-gave-up">{format!("⚠️ {}", gave_up_message(recovery))}</p>
+                })
+                .collect_view()}
+            {status
+                .limitations
+                .iter()
+                .map(|limitation| view! {
+                    <p class="service-limitation">{format!("ℹ️ {}", limitation.description())}</p>
+                })
+                .collect_view()}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status_at(timestamp: u64) -> BackendStatus {
+        BackendStatus {
+            timestamp,
+            ..BackendStatus::new_ready()
+        }
+    }
+
+    #[test]
+    fn test_older_snapshots_are_ignored() {
+        assert!(is_newer(None, &status_at(5)));
+        assert!(is_newer(Some(&status_at(5)), &status_at(6)));
+        assert!(is_newer(Some(&status_at(5)), &status_at(5)));
+        assert!(!is_newer(Some(&status_at(6)), &status_at(5)));
+    }
+
+    #[test]
+    fn test_status_class_per_state() {
+        assert_eq!(status_class(&ServiceStatus::Ready), "ready");
+        assert_eq!(status_class(&ServiceStatus::Starting), "starting");
+        assert_eq!(
+            status_class(&ServiceStatus::Error("denied".to_string())),
+            "error"
+        );
+        assert_eq!(status_class(&ServiceStatus::Unavailable), "unavailable");
+    }
+
+    #[test]
+    fn test_gave_up_message_includes_last_error() {
+        let recovery = ServiceRecovery {
+            service: "Transcription".to_string(),
+            attempts: 5,
+            error: Some("Model file missing".to_string()),
+        };
+        assert_eq!(
+            gave_up_message(&recovery),
+            "Transcription still fails after 5 restarts: Model file missing"
+        );
+        let once = ServiceRecovery {
+            attempts: 1,
+            error: None,
+            ..recovery
+        };
+        assert_eq!(
+            gave_up_message(&once),
+            "Transcription still fails after 1 restart"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+//! Light and dark appearance, and reduced motion.
+//!
+//! The colours in `styles.css` are CSS custom properties; setting
+//! `data-theme="dark"` on the app root swaps in the dark palette.
+//! [`track_appearance`] follows the `theme` setting and, while it is
+//! [`Theme::System`], the system appearance, which the webview reports
+//! through the `prefers-color-scheme` media query as macOS switches between
+//! light and dark.
+//!
+//! It also follows the `reduce_motion` setting, which the app root carries as
+//! `data-reduce-motion` to turn animations off. The system's reduced-motion
+//! preference is honoured by a media query in `styles.css` either way.
+
+use leptos::prelude::*;
+use speakr_types::events::SETTINGS_CHANGED_EVENT;
+use speakr_types::{AppSettings, Theme};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::ipc::listen_typed_with;
+use crate::settings::SettingsManager;
+
+/// Media query matching a dark system appearance.
+const DARK_SCHEME_QUERY: &str = "(prefers-color-scheme: dark)";
+
+/// Value of the `data-theme` attribute for a theme.
+pub fn theme_attribute(theme: Theme, system_dark: bool) -> &'static str {
+    if theme.is_dark(system_dark) {
+        "dark"
+    } else {
+        "light"
+    }
+}
+
+/// Watches the system appearance, calling `on_change` whenever it switches.
+///
+/// # Returns
+///
+/// Returns whether the system appearance is dark now.
+fn watch_system_dark(on_change: impl Fn(bool) + 'static) -> bool {
+    let Some(query) =
+        web_sys::window().and_then(|window| window.match_media(DARK_SCHEME_QUERY).ok().flatten())
+    else {
+        return false;
+    };
+    let dark_now = query.matches();
+    let watched = query.clone();
+    let listener = Closure::<dyn Fn()>::new(move || on_change(watched.matches()));
+    if query
+        .add_event_listener_with_callback("change", listener.as_ref().unchecked_ref())
+        .is_ok()
+    {
+        // The app root lives as long as the window
+        listener.forget();
+    }
+    dark_now
+}
+
+/// Attributes to set on the app root.
+#[derive(Clone, Copy)]
+pub struct Appearance {
+    /// Value of `data-theme`: "light" or "dark".
+    pub theme: Signal<&'static str>,
+    /// Value of `data-reduce-motion`.
+    pub reduce_motion: Signal<bool>,
+}
+
+/// Follows the `theme` and `reduce_motion` settings and the system
+/// appearance.
+pub fn track_appearance() -> Appearance {
+    let (theme, set_theme) = signal(Theme::default());
+    let (reduce_motion, set_reduce_motion) = signal(false);
+    let (system_dark, set_system_dark) = signal(false);
+    set_system_dark.set(watch_system_dark(move |dark| set_system_dark.set(dark)));
+
+    let apply = move |settings: AppSettings| {
+        set_theme.set(settings.theme);
+        set_reduce_motion.set(settings.reduce_motion);
+    };
+    listen_typed_with(SETTINGS_CHANGED_EVENT, apply);
+    spawn_local(async move {
+        match SettingsManager::load().await {
+            Ok(settings) => apply(settings),
+            Err(e) => web_sys::console::error_1(&e.into()),
+        }
+    });
+
+    Appearance {
+        theme: Signal::derive(move || theme_attribute(theme.get(), system_dark.get())),
+        reduce_motion: reduce_motion.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_attribute() {
+        assert_eq!(theme_attribute(Theme::System, true), "dark");
+        assert_eq!(theme_attribute(Theme::System, false), "light");
+        assert_eq!(theme_attribute(Theme::Light, true), "light");
+        assert_eq!(theme_attribute(Theme::Dark, false), "dark");
+    }
+}
@@ -0,0 +1,196 @@
+//! Editor for spoken punctuation and editing commands.
+//!
+//! Lists the built-in commands ("comma", "new paragraph", "delete that", …)
+//! and lets users add their own. A custom command with the same phrase as a
+//! built-in one replaces it.
+
+use leptos::prelude::*;
+use speakr_types::{VoiceCommand, VoiceCommandAction};
+
+use crate::replacements::{escape_text, unescape_text};
+
+/// Actions offered when adding a command, as `(value, label)` pairs.
+const ACTION_CHOICES: [(&str, &str); 6] = [
+    ("punctuation", "Punctuation"),
+    ("insert", "Insert text"),
+    ("new_line", "New line"),
+    ("new_paragraph", "New paragraph"),
+    ("delete", "Delete last phrase"),
+    ("all_caps", "All caps next word"),
+];
+
+/// Describes what a command does, e.g. `Punctuation ","`.
+pub fn action_label(action: &VoiceCommandAction) -> String {
+    match action {
+        VoiceCommandAction::Punctuation { text } => {
+            format!("Punctuation \"{}\"", escape_text(text))
+        }
+        VoiceCommandAction::Insert { text } => format!("Insert \"{}\"", escape_text(text)),
+        VoiceCommandAction::NewLine => "New line".to_string(),
+        VoiceCommandAction::NewParagraph => "New paragraph".to_string(),
+        VoiceCommandAction::DeleteLastPhrase => "Delete last phrase".to_string(),
+        VoiceCommandAction::AllCapsNextWord => "All caps next word".to_string(),
+    }
+}
+
+/// Builds the action chosen in the editor.
+///
+/// # Returns
+///
+/// Returns `None` for an unknown choice, or if punctuation or inserted text
+/// is chosen without any text.
+pub fn action_from_choice(choice: &str, text: &str) -> Option<VoiceCommandAction> {
+    let text = unescape_text(text);
+    match choice {
+        "punctuation" | "insert" if text.is_empty() => None,
+        "punctuation" => Some(VoiceCommandAction::Punctuation { text }),
+        "insert" => Some(VoiceCommandAction::Insert { text }),
+        "new_line" => Some(VoiceCommandAction::NewLine),
+        "new_paragraph" => Some(VoiceCommandAction::NewParagraph),
+        "delete" => Some(VoiceCommandAction::DeleteLastPhrase),
+        "all_caps" => Some(VoiceCommandAction::AllCapsNextWord),
+        _ => None,
+    }
+}
+
+/// Whether the chosen action needs text.
+fn choice_takes_text(choice: &str) -> bool {
+    matches!(choice, "punctuation" | "insert")
+}
+
+/// Built-in commands and an editable list of custom ones.
+#[component]
+pub fn VoiceCommandEditor(
+    /// User-defined commands
+    custom: Signal<Vec<VoiceCommand>>,
+    /// Called with the full updated list whenever a command is added or removed
+    on_change: Callback<Vec<VoiceCommand>>,
+) -> impl IntoView {
+    let (new_phrase, set_new_phrase) = signal(String::new());
+    let (new_choice, set_new_choice) = signal("punctuation".to_string());
+    let (new_text, set_new_text) = signal(String::new());
+
+    let new_command = move || {
+        let phrase = new_phrase.get().trim().to_string();
+        if phrase.is_empty() {
+            return None;
+        }
+        action_from_choice(&new_choice.get(), &new_text.get())
+            .map(|action| VoiceCommand::new(phrase, action))
+    };
+
+    let add = move || {
+        let Some(command) = new_command() else {
+            return;
+        };
+        let mut updated = custom.get_untracked();
+        updated.retain(|c| !c.phrase.eq_ignore_ascii_case(&command.phrase));
+        updated.push(command);
+        set_new_phrase.set(String::new());
+        set_new_text.set(String::new());
+        on_change.run(updated);
+    };
+
+    view! {
+        <div class="voice-commands">
+            <details class="voice-commands-built-in">
+                <summary>"Built-in commands"</summary>
+                <ul>
+                    {VoiceCommand::built_in().into_iter().map(|command| view! {
+                        <li>
+                            <code>{command.phrase}</code>
+                            " → "
+                            {action_label(&command.action)}
+                        </li>
+                    }).collect::<Vec<_>>()}
+                </ul>
+            </details>
+
+            <div class="replacement-rules">
+                {move || {
+                    custom.get().into_iter().enumerate().map(|(index, command)| {
+                        view! {
+                            <div class="replacement-rule">
+                                <code class="rule-pattern">{command.phrase}</code>
+                                " → "
+                                <span>{action_label(&command.action)}</span>
+                                <button
+                                    class="btn-secondary"
+                                    on:click=move |_| {
+                                        let mut updated = custom.get_untracked();
+                                        updated.remove(index);
+                                        on_change.run(updated);
+                                    }
+                                >
+                                    "Remove"
+                                </button>
+                            </div>
+                        }
+                    }).collect::<Vec<_>>()
+                }}
+
+                <div class="replacement-rule-editor">
+                    <input
+                        type="text"
+                        class="rule-input"
+                        placeholder="Phrase, e.g. smiley"
+                        prop:value=move || new_phrase.get()
+                        on:input=move |e| set_new_phrase.set(event_target_value(&e))
+                    />
+                    <select on:change=move |e| set_new_choice.set(event_target_value(&e))>
+                        {ACTION_CHOICES.into_iter().map(|(value, label)| view! {
+                            <option value=value selected=move || new_choice.get() == value>
+                                {label}
+                            </option>
+                        }).collect::<Vec<_>>()}
+                    </select>
+                    <Show when=move || choice_takes_text(&new_choice.get())>
+                        <input
+                            type="text"
+                            class="rule-input"
+                            placeholder="Text, e.g. :-)"
+                            prop:value=move || new_text.get()
+                            on:input=move |e| set_new_text.set(event_target_value(&e))
+                        />
+                    </Show>
+                    <button
+                        class="btn-primary"
+                        disabled=move || new_command().is_none()
+                        on:click=move |_| add()
+                    >
+                        "Add Command"
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_from_choice() {
+        assert_eq!(
+            action_from_choice("insert", r"\n-"),
+            Some(VoiceCommandAction::Insert {
+                text: "\n-".to_string()
+            })
+        );
+        assert_eq!(action_from_choice("punctuation", ""), None);
+        assert_eq!(
+            action_from_choice("delete", ""),
+            Some(VoiceCommandAction::DeleteLastPhrase)
+        );
+        assert_eq!(action_from_choice("unknown", "x"), None);
+    }
+
+    #[test]
+    fn test_every_choice_builds_an_action() {
+        for (choice, label) in ACTION_CHOICES {
+            let action = action_from_choice(choice, "x").unwrap();
+            assert!(action_label(&action).starts_with(label.split(' ').next().unwrap()));
+        }
+    }
+}